@@ -3,18 +3,21 @@
 //! This module provides the `ApiClient` struct for making authenticated
 //! API requests to fetch scout, event, and advancement data.
 
+use std::future::Future;
 use std::sync::Arc;
 use std::time::Duration;
 
 use anyhow::{Context, Result};
-use chrono::Utc;
-use reqwest::{header, Client};
+use chrono::{NaiveDate, Utc};
+use futures::stream::{self, StreamExt, TryStreamExt};
+use reqwest::{header, Client, StatusCode};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
 use tracing::{debug, warn};
 
 use crate::auth::SessionData;
 use crate::models::{
-    Adult, AdvancementDashboard, Award, Event, EventGuest, LeadershipPosition, MeritBadgeProgress,
+    ActivityLog, Adult, AdvancementDashboard, Award, Event, EventGuest, LeadershipPosition, MeritBadgeProgress,
     MeritBadgeRequirement, MeritBadgeWithRequirements, OrgAdultsResponse, OrgYouthsResponse,
     Parent, ParentResponse, Patrol, RankProgress, RankRequirement, RankWithRequirements,
     RanksResponse, ReadyToAward, UnitYouthsResponse, Youth,
@@ -23,6 +26,8 @@ use crate::models::{
 };
 use crate::models::advancement::CounselorInfo;
 
+use super::conditional::{Validated, ValidatorStore};
+use super::pagination::{merge_page, PageInfo, MAX_PAGES};
 use super::ApiError;
 
 // ============================================================================
@@ -41,7 +46,7 @@ const REQUEST_TIMEOUT_SECS: u64 = 30;
 
 /// Number of days to look back for events.
 /// 30 days captures recent events without overwhelming the list.
-const EVENT_LOOKBACK_DAYS: i64 = 30;
+pub const EVENT_LOOKBACK_DAYS: i64 = 30;
 
 /// Number of days to look ahead for events.
 /// 6 months captures upcoming events including summer camp planning.
@@ -55,6 +60,10 @@ const MAX_RATE_LIMIT_RETRIES: u32 = 3;
 /// 1 second is polite to the server while not making users wait too long.
 const INITIAL_BACKOFF_MS: u64 = 1000;
 
+/// Pages of one paginated response fetched at the same time.
+/// Kept low so a big district roster doesn't trip the rate limit.
+const MAX_CONCURRENT_PAGES: usize = 4;
+
 #[derive(Debug, Deserialize)]
 struct AuthResponse {
     token: String,
@@ -78,11 +87,13 @@ struct RenewalRelationship {
 }
 
 /// API client for Scouting.org.
-/// Clone is cheap - both reqwest::Client and token use Arc internally.
+/// Clone is cheap - reqwest::Client, the token, and the validator store use Arc internally.
 #[derive(Clone)]
 pub struct ApiClient {
     client: Client,
     token: Option<Arc<String>>,
+    /// Where GET responses are kept for conditional requests, if anywhere
+    validators: Option<Arc<dyn ValidatorStore>>,
 }
 
 impl ApiClient {
@@ -95,6 +106,7 @@ impl ApiClient {
         Ok(Self {
             client,
             token: None,
+            validators: None,
         })
     }
 
@@ -111,9 +123,17 @@ impl ApiClient {
         Self {
             client: self.client.clone(), // Cheap clone, shares connection pool
             token: Some(token),          // Cheap clone, just Arc pointer copy
+            validators: self.validators.clone(),
         }
     }
 
+    /// Make GET requests conditional, keeping responses in `store` so
+    /// unchanged ones aren't downloaded again.
+    pub fn with_validators(mut self, store: Arc<dyn ValidatorStore>) -> Self {
+        self.validators = Some(store);
+        self
+    }
+
     /// Authenticate with the API and return session data
     pub async fn authenticate(&self, username: &str, password: &str) -> Result<SessionData> {
         let url = format!("{}/users/{}/authenticate", AUTH_BASE_URL, username);
@@ -214,49 +234,86 @@ impl ApiClient {
     /// Returns Ok(Some(response)) for success, Ok(None) for rate limit (should retry),
     /// or Err for other errors.
     async fn check_response_for_retry(response: reqwest::Response) -> Result<Option<reqwest::Response>> {
-        if response.status().is_success() {
-            Ok(Some(response))
-        } else if response.status().as_u16() == 429 {
+        if response.status().as_u16() == 429 {
             // Rate limited - signal to retry
             Ok(None)
         } else {
-            let status = response.status();
-            let body = response.text().await.unwrap_or_default();
-            Err(ApiError::from_status(status, &body).into())
+            Self::check_response(response).await.map(Some)
         }
     }
 
     /// Check if response is successful, returning an error with body if not.
+    /// An HTML page, even with a success status, is the site's maintenance
+    /// or outage page rather than API data.
     async fn check_response(response: reqwest::Response) -> Result<reqwest::Response> {
-        if response.status().is_success() {
-            Ok(response)
-        } else {
-            let status = response.status();
-            let url = response.url().to_string();
-            let body = response.text().await.unwrap_or_default();
-            warn!(status = %status, url = %url, body_len = body.len(), "API request failed");
-            Err(ApiError::from_status(status, &body).into())
+        let is_html = response
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .is_some_and(|v| v.starts_with("text/html"));
+        if response.status().is_success() && !is_html {
+            return Ok(response);
         }
+        let status = response.status();
+        let url = response.url().to_string();
+        let body = response.text().await.unwrap_or_default();
+        let error = ApiError::from_response(status, is_html, &body);
+        warn!(status = %status, url = %url, body_len = body.len(), error = %error, "API request failed");
+        Err(error.into())
     }
 
-    async fn get<T: DeserializeOwned>(&self, url: &str) -> Result<T> {
+    /// Send the request built by `request`, retrying with backoff while rate
+    /// limited, and return the JSON body.
+    async fn send_with_retry(
+        &self,
+        url: &str,
+        request: impl Fn() -> Result<reqwest::RequestBuilder>,
+    ) -> Result<Value> {
+        let response = self.send_checked(url, request).await?;
+        Self::read_json(url, response).await
+    }
+
+    async fn read_json(url: &str, response: reqwest::Response) -> Result<Value> {
+        let status = response.status();
+        let body = response.text().await
+            .with_context(|| format!("Failed to read response from {}", url))?;
+        match serde_json::from_str(&body) {
+            Ok(value) => Ok(value),
+            // Maintenance pages don't always say they're HTML
+            Err(_) if body.trim_start().starts_with('<') => {
+                Err(ApiError::from_response(status, true, &body).into())
+            }
+            Err(e) => Err(ApiError::SchemaMismatch(format!("{} from {}", e, url)).into()),
+        }
+    }
+
+    /// Decode a JSON response into `T`, as a `SchemaMismatch` if its shape
+    /// isn't what was expected.
+    fn decode<T: DeserializeOwned>(url: &str, value: Value) -> Result<T> {
+        serde_json::from_value(value).map_err(|e| ApiError::SchemaMismatch(format!("{} from {}", e, url)).into())
+    }
+
+    /// Send the request built by `request`, retrying with backoff while rate
+    /// limited. Returns a successful or `304 Not Modified` response.
+    async fn send_checked(
+        &self,
+        url: &str,
+        request: impl Fn() -> Result<reqwest::RequestBuilder>,
+    ) -> Result<reqwest::Response> {
         let mut retries = 0;
         let mut backoff_ms = INITIAL_BACKOFF_MS;
 
         loop {
-            let response = self
-                .client
-                .get(url)
-                .headers(self.auth_headers()?)
+            let response = request()?
                 .send()
                 .await
-                .with_context(|| format!("Failed to send GET request to {}", url))?;
+                .with_context(|| format!("Failed to send request to {}", url))?;
+            if response.status() == StatusCode::NOT_MODIFIED {
+                return Ok(response);
+            }
 
             match Self::check_response_for_retry(response).await? {
-                Some(response) => {
-                    return response.json().await
-                        .with_context(|| format!("Failed to parse JSON response from {}", url));
-                }
+                Some(response) => return Ok(response),
                 None => {
                     // Rate limited
                     retries += 1;
@@ -271,37 +328,109 @@ impl ApiClient {
         }
     }
 
-    async fn post<T: DeserializeOwned, B: Serialize>(&self, url: &str, body: &B) -> Result<T> {
-        let mut retries = 0;
-        let mut backoff_ms = INITIAL_BACKOFF_MS;
+    /// If `first` is the first page of a paginated response, fetch the rest
+    /// with `fetch_page` (a few at a time, each with its own rate-limit
+    /// retries) and merge their lists into it.
+    async fn with_remaining_pages<F, Fut>(url: &str, mut first: Value, fetch_page: F) -> Result<Value>
+    where
+        F: Fn((&'static str, u64)) -> Fut,
+        Fut: Future<Output = Result<Value>>,
+    {
+        let Some(info) = PageInfo::from_response(&first) else {
+            return Ok(first);
+        };
+        if info.is_truncated() {
+            warn!(url = url, pages = info.total_pages, max = MAX_PAGES, "Response has too many pages, keeping the first ones");
+        }
+        debug!(url = url, pages = info.total_pages, "Fetching remaining pages");
 
-        loop {
-            let response = self
-                .client
-                .post(url)
-                .headers(self.auth_headers()?)
-                .json(body)
-                .send()
-                .await
-                .with_context(|| format!("Failed to send POST request to {}", url))?;
+        let pages: Vec<Value> = stream::iter(info.remaining())
+            .map(|page| fetch_page((info.param, page)))
+            .buffered(MAX_CONCURRENT_PAGES)
+            .try_collect()
+            .await?;
+        for page in pages {
+            merge_page(&mut first, page);
+        }
+        Ok(first)
+    }
 
-            match Self::check_response_for_retry(response).await? {
-                Some(response) => {
-                    return response.json().await
-                        .with_context(|| format!("Failed to parse JSON response from {}", url));
+    async fn get_page(&self, url: &str, page: Option<(&str, u64)>) -> Result<Value> {
+        self.send_with_retry(url, || {
+            let request = self.client.get(url).headers(self.auth_headers()?);
+            Ok(match page {
+                Some(page) => request.query(&[page]),
+                None => request,
+            })
+        })
+        .await
+    }
+
+    async fn post_page(&self, url: &str, body: &Value, page: Option<(&str, u64)>) -> Result<Value> {
+        let mut body = body.clone();
+        if let (Some((field, number)), Some(fields)) = (page, body.as_object_mut()) {
+            fields.insert(field.to_string(), number.into());
+        }
+        self.send_with_retry(url, || Ok(self.client.post(url).headers(self.auth_headers()?).json(&body)))
+            .await
+    }
+
+    /// GET `url`, following any further pages. With a validator store the
+    /// request is conditional, and an unchanged response is read from it.
+    async fn get<T: DeserializeOwned>(&self, url: &str) -> Result<T> {
+        let value = match &self.validators {
+            Some(store) => self.get_conditional(url, store.as_ref()).await?,
+            None => {
+                let first = self.get_page(url, None).await?;
+                Self::with_remaining_pages(url, first, |page| self.get_page(url, Some(page))).await?
+            }
+        };
+        Self::decode(url, value)
+    }
+
+    async fn get_conditional(&self, url: &str, store: &dyn ValidatorStore) -> Result<Value> {
+        // Anything stored from a paged response can't be checked with one request
+        let stored = store.load_validated(url).filter(|s| !s.is_paged());
+        let response = self
+            .send_checked(url, || {
+                let mut request = self.client.get(url).headers(self.auth_headers()?);
+                if let Some(etag) = stored.as_ref().and_then(|s| s.etag.as_deref()) {
+                    request = request.header(header::IF_NONE_MATCH, etag);
                 }
-                None => {
-                    // Rate limited
-                    retries += 1;
-                    if retries > MAX_RATE_LIMIT_RETRIES {
-                        return Err(ApiError::RateLimited.into());
-                    }
-                    warn!(url = url, retry = retries, backoff_ms = backoff_ms, "Rate limited, backing off");
-                    tokio::time::sleep(Duration::from_millis(backoff_ms)).await;
-                    backoff_ms *= 2; // Exponential backoff
+                if let Some(modified) = stored.as_ref().and_then(|s| s.last_modified.as_deref()) {
+                    request = request.header(header::IF_MODIFIED_SINCE, modified);
                 }
-            }
+                Ok(request)
+            })
+            .await?;
+        if response.status() == StatusCode::NOT_MODIFIED {
+            let Some(stored) = stored else {
+                // Nothing was stored to match, so ask again without validators
+                warn!(url = url, "Not modified with no stored response, fetching again");
+                let first = self.get_page(url, None).await?;
+                return Self::with_remaining_pages(url, first, |page| self.get_page(url, Some(page))).await;
+            };
+            debug!(url = url, "Not modified, using stored response");
+            return Ok(stored.body);
+        }
+
+        let validator = |name| response.headers().get(name).and_then(|v| v.to_str().ok()).map(str::to_string);
+        let (etag, last_modified) = (validator(header::ETAG), validator(header::LAST_MODIFIED));
+        let first = Self::read_json(url, response).await?;
+        let body = Self::with_remaining_pages(url, first, |page| self.get_page(url, Some(page))).await?;
+        let validated = Validated { etag, last_modified, body };
+        if validated.has_validators() && !validated.is_paged() {
+            store.save_validated(url, &validated);
         }
+        Ok(validated.body)
+    }
+
+    /// POST `body` to `url`, following any further pages.
+    async fn post<T: DeserializeOwned, B: Serialize>(&self, url: &str, body: &B) -> Result<T> {
+        let body = serde_json::to_value(body).context("Failed to encode request body")?;
+        let first = self.post_page(url, &body, None).await?;
+        let value = Self::with_remaining_pages(url, first, |page| self.post_page(url, &body, Some(page))).await?;
+        Self::decode(url, value)
     }
 
     // ===== Data Fetching Methods =====
@@ -310,19 +439,7 @@ impl ApiClient {
     pub async fn fetch_youth(&self, org_guid: &str) -> Result<Vec<Youth>> {
         // Fetch from GET endpoint for patrol/rank data
         let url1 = format!("{}/organizations/v2/units/{}/youths", API_BASE_URL, org_guid);
-        let response1 = self
-            .client
-            .get(&url1)
-            .headers(self.auth_headers()?)
-            .send()
-            .await
-            .context("Failed to fetch youth list")?;
-
-        let response1 = Self::check_response(response1).await?;
-
-        let text1 = response1.text().await.context("Failed to read youth response body")?;
-        let parsed1: UnitYouthsResponse = serde_json::from_str(&text1)
-            .context("Failed to parse units youth response")?;
+        let parsed1: UnitYouthsResponse = self.get(&url1).await?;
 
         let mut youth_list: Vec<Youth> = parsed1.users.iter().map(|u| u.to_youth()).collect();
 
@@ -334,19 +451,8 @@ impl ApiClient {
             "includeExpired": false
         });
 
-        let response2 = self
-            .client
-            .post(&url2)
-            .headers(self.auth_headers()?)
-            .json(&body)
-            .send()
-            .await?;
-
-        if response2.status().is_success() {
-            let text2 = response2.text().await?;
-            debug!("Youth POST response received");
-
-            if let Ok(parsed2) = serde_json::from_str::<OrgYouthsResponse>(&text2) {
+        match self.post::<OrgYouthsResponse, _>(&url2, &body).await {
+            Ok(parsed2) => {
                 debug!("Parsed {} members from orgYouths", parsed2.members.len());
                 // Merge registration data and grade by personGuid
                 for youth in &mut youth_list {
@@ -363,9 +469,8 @@ impl ApiClient {
                         }
                     }
                 }
-            } else {
-                warn!("Failed to parse orgYouths response");
             }
+            Err(e) => warn!(error = %e, "Failed to fetch orgYouths registration details"),
         }
 
         Ok(youth_list)
@@ -389,20 +494,7 @@ impl ApiClient {
             API_BASE_URL, org_guid
         );
 
-        let response = self
-            .client
-            .get(&url)
-            .headers(self.auth_headers()?)
-            .send()
-            .await?;
-
-        let response = Self::check_response(response).await?;
-
-        let text = response.text().await?;
-        debug!("Parents response received");
-
-        let parsed: Vec<ParentResponse> = serde_json::from_str(&text)
-            .context("Failed to parse parents response")?;
+        let parsed: Vec<ParentResponse> = self.get(&url).await?;
 
         // Convert to Parent structs
         Ok(parsed.iter().map(|p| p.to_parent()).collect())
@@ -533,6 +625,17 @@ impl ApiClient {
         Ok(awards)
     }
 
+    /// Fetch camping, hiking, and service log totals for a specific youth member
+    pub async fn fetch_youth_activity(&self, user_id: i64) -> Result<ActivityLog> {
+        let url = format!(
+            "{}/advancements/v2/{}/userActivitySummary",
+            API_BASE_URL, user_id
+        );
+        let value: Value = self.get(&url).await?;
+        debug!("Activity summary response received");
+        serde_json::from_value(value).context("Failed to parse activity summary response")
+    }
+
     /// Fetch requirements for a specific rank for a youth member
     pub async fn fetch_rank_requirements(&self, user_id: i64, rank_id: i64) -> Result<Vec<RankRequirement>> {
         // Try the requirements endpoint first
@@ -563,6 +666,47 @@ impl ApiClient {
         Ok(rank.requirements)
     }
 
+    /// Record a rank requirement as completed on `date`, with leader approval.
+    pub async fn complete_rank_requirement(
+        &self,
+        user_id: i64,
+        rank_id: i64,
+        requirement_id: i64,
+        date: NaiveDate,
+    ) -> Result<()> {
+        let url = format!(
+            "{}/advancements/v2/youth/{}/ranks/{}/requirements",
+            API_BASE_URL, user_id, rank_id
+        );
+        let date = date.format("%Y-%m-%d").to_string();
+        let body = serde_json::json!([{
+            "id": requirement_id,
+            "completed": true,
+            "markedCompletedDate": date,
+            "dateCompleted": date,
+            "leaderApprovedDate": date,
+        }]);
+
+        self.send_checked(&url, || Ok(self.client.post(&url).headers(self.auth_headers()?).json(&body)))
+            .await?;
+        debug!(user_id, rank_id, requirement_id, "Rank requirement marked complete");
+        Ok(())
+    }
+
+    /// Move a scout into a patrol, taking them out of their current one.
+    pub async fn move_youth_to_patrol(&self, user_id: i64, patrol_guid: &str) -> Result<()> {
+        let url = format!(
+            "{}/organizations/v2/subUnits/{}/members",
+            API_BASE_URL, patrol_guid
+        );
+        let body = serde_json::json!([{ "memberId": user_id }]);
+
+        self.send_checked(&url, || Ok(self.client.post(&url).headers(self.auth_headers()?).json(&body)))
+            .await?;
+        debug!(user_id, patrol_guid, "Scout moved to patrol");
+        Ok(())
+    }
+
     /// Fetch badge requirements only (no counselor info). Single API call.
     /// Use this for bulk/offline caching where counselor data isn't needed.
     pub async fn fetch_badge_requirements_only(&self, user_id: i64, badge_id: i64) -> Result<(Vec<MeritBadgeRequirement>, Option<String>)> {
@@ -1123,6 +1267,7 @@ struct OrgProfileApiResponse {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::api::ApiErrorKind;
 
     #[test]
     fn test_is_valid_guid() {
@@ -1164,4 +1309,76 @@ mod tests {
         assert_eq!(domain_commissioner.full_name(), "Jane Doe");
         assert_eq!(domain_commissioner.position_display(), "District Commissioner");
     }
+
+    #[test]
+    fn test_decode_failure_is_schema_mismatch() {
+        let err = ApiClient::decode::<Vec<i64>>("https://example.test/youths", serde_json::json!({ "users": [] }))
+            .unwrap_err();
+        assert!(matches!(err.downcast_ref::<ApiError>(), Some(ApiError::SchemaMismatch(_))));
+        assert_eq!(ApiError::classify(&err), ApiErrorKind::SchemaMismatch);
+    }
+
+    #[derive(Default)]
+    struct MemoryValidators(std::sync::Mutex<std::collections::HashMap<String, Validated>>);
+
+    impl ValidatorStore for MemoryValidators {
+        fn load_validated(&self, url: &str) -> Option<Validated> {
+            self.0.lock().unwrap().get(url).cloned()
+        }
+
+        fn save_validated(&self, url: &str, response: &Validated) {
+            self.0.lock().unwrap().insert(url.to_string(), response.clone());
+        }
+    }
+
+    #[test]
+    fn test_conditional_get_refetches_paged_responses() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap();
+        runtime.block_on(async {
+            // Page 1 answers 304 to a matching ETag; page 2 has changed since
+            let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let url = format!("http://{}/youths", listener.local_addr().unwrap());
+            tokio::spawn(async move {
+                loop {
+                    let (mut socket, _) = listener.accept().await.unwrap();
+                    let mut request = vec![0; 4096];
+                    let n = socket.read(&mut request).await.unwrap();
+                    let request = String::from_utf8_lossy(&request[..n]).to_lowercase();
+                    let (status, body) = if request.starts_with("get /youths?pagenumber=2 ") {
+                        ("200 OK", r#"{"pageNumber": 2, "totalPages": 2, "users": [3]}"#)
+                    } else if request.contains("if-none-match: \"v1\"") {
+                        ("304 Not Modified", "")
+                    } else {
+                        ("200 OK", r#"{"pageNumber": 1, "totalPages": 2, "users": [1]}"#)
+                    };
+                    let response = format!(
+                        "HTTP/1.1 {}\r\nETag: \"v1\"\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        status,
+                        body.len(),
+                        body
+                    );
+                    socket.write_all(response.as_bytes()).await.unwrap();
+                }
+            });
+
+            let store = MemoryValidators::default();
+            let stale = Validated {
+                etag: Some("\"v1\"".to_string()),
+                last_modified: None,
+                body: serde_json::json!({ "pageNumber": 1, "totalPages": 2, "users": [1, 2] }),
+            };
+            let client = ApiClient::new().unwrap();
+
+            let body = client.get_conditional(&url, &store).await.unwrap();
+            assert_eq!(body["users"], serde_json::json!([1, 3]));
+            assert!(store.load_validated(&url).is_none(), "paged responses aren't kept");
+
+            // Even a paged body stored before is never trusted on page 1's word
+            store.save_validated(&url, &stale);
+            let body = client.get_conditional(&url, &store).await.unwrap();
+            assert_eq!(body["users"], serde_json::json!([1, 3]));
+        });
+    }
 }
@@ -0,0 +1,42 @@
+//! Conditional GET requests.
+//!
+//! When a response carries an `ETag` or `Last-Modified` header, it's kept
+//! with its body in a `ValidatorStore`. The next GET of that URL sends them
+//! back as `If-None-Match` / `If-Modified-Since`, and a `304 Not Modified`
+//! answer reuses the stored body instead of downloading it again.
+//!
+//! Only the first page of a response is sent with validators, so a 304 for
+//! it says nothing about the pages after it. Responses that span several
+//! pages are always downloaded in full and never kept.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::pagination::PageInfo;
+
+/// A response body with the validators the server sent for it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Validated {
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+    /// The whole response, with any further pages merged in
+    pub body: Value,
+}
+
+impl Validated {
+    /// Worth keeping only if there's something to send back.
+    pub fn has_validators(&self) -> bool {
+        self.etag.is_some() || self.last_modified.is_some()
+    }
+
+    /// Whether the body was merged from more than one page.
+    pub fn is_paged(&self) -> bool {
+        PageInfo::from_response(&self.body).is_some_and(|info| info.remaining().next().is_some())
+    }
+}
+
+/// Where validated responses are kept between refreshes, keyed by URL.
+pub trait ValidatorStore: Send + Sync {
+    fn load_validated(&self, url: &str) -> Option<Validated>;
+    fn save_validated(&self, url: &str, response: &Validated);
+}
@@ -0,0 +1,330 @@
+//! A stand-in for Scoutbook, backed by bundled fixtures.
+//!
+//! `DemoApi` serves a small made-up troop from `fixtures/demo.json` so the
+//! TUI can be run without Scoutbook credentials. Any username and password
+//! sign in. Writes are kept in memory for the session, so a completed
+//! requirement or a patrol move shows up when the data is fetched again.
+//! Event dates move with the calendar, keeping the fixtures' upcoming
+//! events upcoming; everything else is dated as written.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use anyhow::{anyhow, Context, Result};
+use chrono::{Local, NaiveDate, Utc};
+use serde::Deserialize;
+
+use crate::auth::SessionData;
+use crate::models::advancement::CounselorInfo;
+use crate::models::{
+    ActivityLog, Adult, AdvancementDashboard, Award, Commissioner, Event, EventGuest, Key3Leaders, LeadershipPosition,
+    MeritBadgeCatalogEntry, MeritBadgeProgress, MeritBadgeRequirement, OrgProfile, Parent, Patrol, RankProgress,
+    RankRequirement, ReadyToAward, UnitInfo, Youth,
+};
+
+use super::ScoutingApi;
+
+const DEMO_FIXTURES: &str = include_str!("fixtures/demo.json");
+
+/// The demo troop's organization.
+pub const DEMO_ORG_GUID: &str = "DEMO-TROOP-42";
+
+/// The leader the demo signs in as.
+pub const DEMO_USER_ID: i64 = 9101;
+
+/// Everything the demo serves. Ranks, badges, and the other per-scout
+/// lists are keyed by user ID; requirements by rank or badge ID, shared
+/// by every scout.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct DemoData {
+    /// When the fixtures were written
+    pub as_of: Option<NaiveDate>,
+    pub youth: Vec<Youth>,
+    pub adults: Vec<Adult>,
+    pub parents: Vec<Parent>,
+    pub patrols: Vec<Patrol>,
+    pub events: Vec<Event>,
+    pub ranks: HashMap<i64, Vec<RankProgress>>,
+    pub merit_badges: HashMap<i64, Vec<MeritBadgeProgress>>,
+    pub leadership: HashMap<i64, Vec<LeadershipPosition>>,
+    pub awards: HashMap<i64, Vec<Award>>,
+    pub activity: HashMap<i64, ActivityLog>,
+    pub rank_requirements: HashMap<i64, Vec<RankRequirement>>,
+    pub badge_requirements: HashMap<i64, Vec<MeritBadgeRequirement>>,
+    pub catalog: Vec<MeritBadgeCatalogEntry>,
+    pub ready_to_award: Vec<ReadyToAward>,
+    pub key3: Key3Leaders,
+    pub unit: UnitInfo,
+    pub org_profile: OrgProfile,
+    pub commissioners: Vec<Commissioner>,
+    /// Requirements completed this session, by (user, rank, requirement)
+    #[serde(skip)]
+    pub completed: HashMap<(i64, i64, i64), NaiveDate>,
+}
+
+impl DemoData {
+    /// The bundled demo troop.
+    pub fn bundled() -> Result<Self> {
+        serde_json::from_str(DEMO_FIXTURES).context("Failed to parse the demo fixtures")
+    }
+
+    /// `events`, moved so the fixtures' dates fall the same distance from
+    /// `today` as from `as_of`.
+    fn events_as_of(&self, today: NaiveDate) -> Vec<Event> {
+        let shift = self.as_of.map(|d| today - d).unwrap_or_default();
+        let move_date = |date: &mut Option<String>| {
+            let Some(text) = date.as_mut() else { return };
+            let Some(day) = text.get(..10).and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok()) else {
+                return;
+            };
+            text.replace_range(..10, &(day + shift).format("%Y-%m-%d").to_string());
+        };
+        let mut events = self.events.clone();
+        for event in &mut events {
+            move_date(&mut event.start_date);
+            move_date(&mut event.end_date);
+        }
+        events
+    }
+}
+
+/// The demo backend. Clones share the same data.
+#[derive(Debug, Clone, Default)]
+pub struct DemoApi {
+    data: Arc<Mutex<DemoData>>,
+}
+
+impl DemoApi {
+    /// Serve the bundled demo troop.
+    pub fn new() -> Result<Self> {
+        Ok(Self::from_data(DemoData::bundled()?))
+    }
+
+    /// Serve `data` instead of the bundled troop.
+    pub fn from_data(data: DemoData) -> Self {
+        Self { data: Arc::new(Mutex::new(data)) }
+    }
+
+    fn data(&self) -> MutexGuard<'_, DemoData> {
+        self.data.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn per_scout<T: Clone + Default>(&self, user_id: i64, list: impl Fn(&DemoData) -> &HashMap<i64, T>) -> T {
+        list(&self.data()).get(&user_id).cloned().unwrap_or_default()
+    }
+}
+
+impl ScoutingApi for DemoApi {
+    async fn authenticate(&self, username: &str, _password: &str) -> Result<SessionData> {
+        Ok(SessionData {
+            token: "demo".to_string(),
+            user_id: DEMO_USER_ID,
+            person_guid: format!("DEMO-PERSON-{}", DEMO_USER_ID),
+            organization_guid: DEMO_ORG_GUID.to_string(),
+            username: username.to_string(),
+            created_at: Utc::now(),
+        })
+    }
+
+    async fn fetch_youth(&self, _org_guid: &str) -> Result<Vec<Youth>> {
+        Ok(self.data().youth.clone())
+    }
+
+    async fn fetch_adults(&self, _org_guid: &str) -> Result<Vec<Adult>> {
+        Ok(self.data().adults.clone())
+    }
+
+    async fn fetch_parents(&self, _org_guid: &str) -> Result<Vec<Parent>> {
+        Ok(self.data().parents.clone())
+    }
+
+    async fn fetch_patrols(&self, _org_guid: &str) -> Result<Vec<Patrol>> {
+        Ok(self.data().patrols.clone())
+    }
+
+    async fn fetch_advancement_dashboard(&self, _org_guid: &str) -> Result<AdvancementDashboard> {
+        let data = self.data();
+        Ok(AdvancementDashboard {
+            active_youth_count: Some(data.youth.len() as i32),
+            ready_to_award_count: Some(data.ready_to_award.len() as i32),
+            ..Default::default()
+        })
+    }
+
+    async fn fetch_ready_to_award(&self, _org_guid: &str) -> Result<Vec<ReadyToAward>> {
+        Ok(self.data().ready_to_award.clone())
+    }
+
+    async fn fetch_key3(&self, _org_guid: &str) -> Result<Key3Leaders> {
+        Ok(self.data().key3.clone())
+    }
+
+    async fn fetch_unit_pin(&self, _org_guid: &str) -> Result<UnitInfo> {
+        Ok(self.data().unit.clone().with_computed_fields())
+    }
+
+    async fn fetch_org_profile(&self, _org_guid: &str) -> Result<OrgProfile> {
+        Ok(self.data().org_profile.clone())
+    }
+
+    async fn fetch_commissioners(&self, _org_guid: &str) -> Result<Vec<Commissioner>> {
+        Ok(self.data().commissioners.clone())
+    }
+
+    async fn fetch_youth_ranks(&self, user_id: i64) -> Result<Vec<RankProgress>> {
+        Ok(self.per_scout(user_id, |d| &d.ranks))
+    }
+
+    async fn fetch_youth_merit_badges(&self, user_id: i64) -> Result<Vec<MeritBadgeProgress>> {
+        Ok(self.per_scout(user_id, |d| &d.merit_badges))
+    }
+
+    async fn fetch_youth_leadership(&self, user_id: i64) -> Result<Vec<LeadershipPosition>> {
+        Ok(self.per_scout(user_id, |d| &d.leadership))
+    }
+
+    async fn fetch_youth_awards(&self, user_id: i64) -> Result<Vec<Award>> {
+        Ok(self.per_scout(user_id, |d| &d.awards))
+    }
+
+    async fn fetch_youth_activity(&self, user_id: i64) -> Result<ActivityLog> {
+        Ok(self.per_scout(user_id, |d| &d.activity))
+    }
+
+    async fn fetch_rank_requirements(&self, user_id: i64, rank_id: i64) -> Result<Vec<RankRequirement>> {
+        let data = self.data();
+        let mut requirements = data.rank_requirements.get(&rank_id).cloned().unwrap_or_default();
+        for req in &mut requirements {
+            if let Some(date) = req.id.and_then(|id| data.completed.get(&(user_id, rank_id, id))) {
+                req.mark_completed(*date);
+            }
+        }
+        Ok(requirements)
+    }
+
+    async fn fetch_badge_requirements_only(
+        &self,
+        _user_id: i64,
+        badge_id: i64,
+    ) -> Result<(Vec<MeritBadgeRequirement>, Option<String>)> {
+        Ok((self.data().badge_requirements.get(&badge_id).cloned().unwrap_or_default(), None))
+    }
+
+    async fn fetch_badge_requirements(
+        &self,
+        user_id: i64,
+        badge_id: i64,
+    ) -> Result<(Vec<MeritBadgeRequirement>, Option<String>, Option<CounselorInfo>)> {
+        let (requirements, version) = self.fetch_badge_requirements_only(user_id, badge_id).await?;
+        let counselor = self
+            .per_scout(user_id, |d| &d.merit_badges)
+            .into_iter()
+            .find(|b| b.id == badge_id)
+            .and_then(|b| b.assigned_counselor);
+        Ok((requirements, version, counselor))
+    }
+
+    async fn fetch_merit_badge_catalog(&self) -> Result<Vec<MeritBadgeCatalogEntry>> {
+        Ok(self.data().catalog.clone())
+    }
+
+    async fn fetch_events(&self, _user_id: i64) -> Result<Vec<Event>> {
+        Ok(self.data().events_as_of(Local::now().date_naive()))
+    }
+
+    async fn fetch_event_detail(&self, event_id: i64) -> Result<Event> {
+        self.data()
+            .events_as_of(Local::now().date_naive())
+            .into_iter()
+            .find(|e| e.id == event_id)
+            .ok_or_else(|| anyhow!("No demo event {}", event_id))
+    }
+
+    async fn fetch_event_guests(&self, _event_id: i64) -> Result<Vec<EventGuest>> {
+        Ok(Vec::new())
+    }
+
+    async fn complete_rank_requirement(
+        &self,
+        user_id: i64,
+        rank_id: i64,
+        requirement_id: i64,
+        date: NaiveDate,
+    ) -> Result<()> {
+        self.data().completed.insert((user_id, rank_id, requirement_id), date);
+        Ok(())
+    }
+
+    async fn move_youth_to_patrol(&self, user_id: i64, patrol_guid: &str) -> Result<()> {
+        let mut data = self.data();
+        let patrol = data
+            .patrols
+            .iter()
+            .find(|p| p.guid.as_deref() == Some(patrol_guid))
+            .map(|p| p.name.clone())
+            .ok_or_else(|| anyhow!("No demo patrol {}", patrol_guid))?;
+        let youth = data
+            .youth
+            .iter_mut()
+            .find(|y| y.user_id == Some(user_id))
+            .ok_or_else(|| anyhow!("No demo scout {}", user_id))?;
+        let from = youth.patrol_guid.replace(patrol_guid.to_string());
+        youth.patrol_name = Some(patrol);
+        for p in &mut data.patrols {
+            let change = if p.guid.as_deref() == Some(patrol_guid) {
+                1
+            } else if p.guid.is_some() && p.guid == from {
+                -1
+            } else {
+                0
+            };
+            p.member_count = p.member_count.map(|n| n + change);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundled_fixtures_and_writes() {
+        let demo = DemoApi::new().unwrap();
+        let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+        runtime.block_on(async {
+            let session = demo.authenticate("anyone", "anything").await.unwrap();
+            assert_eq!(session.organization_guid, DEMO_ORG_GUID);
+
+            let youth = demo.fetch_youth(DEMO_ORG_GUID).await.unwrap();
+            assert!(!youth.is_empty());
+            for scout in &youth {
+                let id = scout.user_id.unwrap();
+                assert!(!demo.fetch_youth_ranks(id).await.unwrap().is_empty(), "{} has ranks", scout.full_name());
+            }
+            assert!(!demo.fetch_adults(DEMO_ORG_GUID).await.unwrap().is_empty());
+            assert!(demo.fetch_unit_pin(DEMO_ORG_GUID).await.unwrap().charter_status_display.is_some());
+
+            // Event dates keep their distance from today
+            let today = NaiveDate::from_ymd_opt(2027, 1, 1).unwrap();
+            let events = demo.data().events_as_of(today);
+            assert!(events.iter().any(|e| e.start_date.as_deref().unwrap().starts_with("2027-01-09")));
+
+            // Writes show up on the next fetch
+            let date = NaiveDate::from_ymd_opt(2026, 10, 15).unwrap();
+            demo.complete_rank_requirement(9003, 2, 201, date).await.unwrap();
+            let reqs = demo.fetch_rank_requirements(9003, 2).await.unwrap();
+            assert!(reqs.iter().find(|r| r.id == Some(201)).unwrap().is_completed());
+            assert!(!demo.fetch_rank_requirements(9005, 2).await.unwrap()[0].is_completed());
+
+            demo.move_youth_to_patrol(9003, "DEMO-PATROL-FOX").await.unwrap();
+            let moved = demo.fetch_youth(DEMO_ORG_GUID).await.unwrap();
+            assert_eq!(moved.iter().find(|y| y.user_id == Some(9003)).unwrap().patrol_name.as_deref(), Some("Fox Patrol"));
+            let counts: Vec<Option<i32>> =
+                demo.fetch_patrols(DEMO_ORG_GUID).await.unwrap().iter().map(|p| p.member_count).collect();
+            assert_eq!(counts, [Some(2), Some(4)]);
+            assert!(demo.move_youth_to_patrol(9003, "NOWHERE").await.is_err());
+        });
+    }
+}
@@ -2,21 +2,31 @@ use thiserror::Error;
 
 #[derive(Error, Debug)]
 pub enum ApiError {
-    #[error("Access denied: {0}")]
-    AccessDenied(String),
+    /// 401 - the token is missing, expired, or the credentials were rejected
+    #[error("Unauthorized - session expired or credentials rejected")]
+    AuthExpired,
 
-    #[error("Unauthorized - token may be expired")]
-    Unauthorized,
+    /// 403 - the account does not hold a unit role that grants access
+    #[error("Forbidden - insufficient role: {0}")]
+    Forbidden(String),
 
     #[error("Resource not found: {0}")]
     NotFound(String),
 
+    /// The response body did not match the structure we expected to parse
+    #[error("Unexpected response format: {0}")]
+    SchemaMismatch(String),
+
     #[error("Rate limited - please wait before retrying")]
     RateLimited,
 
     #[error("Server error ({1}): {0}")]
     ServerError(String, u16),
 
+    /// The site is serving a maintenance or outage page instead of the API
+    #[error("Scoutbook is down for maintenance (status {0})")]
+    Maintenance(u16),
+
     #[error("Network error: {0}")]
     NetworkError(#[from] reqwest::Error),
 
@@ -24,9 +34,49 @@ pub enum ApiError {
     InvalidResponse(String),
 }
 
+/// Coarse classification of an API failure, used to decide how the UI reacts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiErrorKind {
+    /// Session expired or credentials rejected - prompt for login
+    AuthExpired,
+    /// Account lacks the role needed for this data
+    Forbidden,
+    /// Requested resource does not exist
+    NotFound,
+    /// Response could not be parsed
+    SchemaMismatch,
+    /// Server asked us to slow down
+    RateLimited,
+    /// Server-side failure (5xx)
+    Server,
+    /// Site down for maintenance or an outage
+    Maintenance,
+    /// Connection, DNS, or timeout failure
+    Network,
+    /// Anything else
+    Other,
+}
+
+impl ApiErrorKind {
+    /// Whether retrying the same request later could plausibly succeed.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            ApiErrorKind::RateLimited
+                | ApiErrorKind::Server
+                | ApiErrorKind::Maintenance
+                | ApiErrorKind::Network
+                | ApiErrorKind::Other
+        )
+    }
+}
+
 /// Maximum length for error response bodies in error messages
 const MAX_ERROR_BODY_LENGTH: usize = 500;
 
+/// Phrases in an error page that mean the site is down on purpose
+const MAINTENANCE_MARKERS: &[&str] = &["maintenance", "temporarily unavailable", "scheduled outage", "be back soon"];
+
 impl ApiError {
     /// Truncate a response body to avoid logging excessive data
     fn truncate_body(body: &str) -> String {
@@ -42,12 +92,141 @@ impl ApiError {
     pub fn from_status(status: reqwest::StatusCode, body: &str) -> Self {
         let truncated = Self::truncate_body(body);
         match status.as_u16() {
-            401 => ApiError::Unauthorized,
-            403 => ApiError::AccessDenied(truncated),
+            401 => ApiError::AuthExpired,
+            403 => ApiError::Forbidden(truncated),
             404 => ApiError::NotFound(truncated),
             429 => ApiError::RateLimited,
             500..=599 => ApiError::ServerError(truncated, status.as_u16()),
             _ => ApiError::InvalidResponse(format!("Status {}: {}", status, truncated)),
         }
     }
+
+    /// Classify a failed or non-JSON response. `is_html` is whether the
+    /// server said it was sending HTML, which the API never does; it's how
+    /// maintenance and outage pages show up, sometimes with a 200 status.
+    pub fn from_response(status: reqwest::StatusCode, is_html: bool, body: &str) -> Self {
+        if Self::is_maintenance(status, is_html, body) {
+            return ApiError::Maintenance(status.as_u16());
+        }
+        if status.is_success() {
+            return ApiError::InvalidResponse(format!("Expected JSON: {}", Self::truncate_body(body)));
+        }
+        Self::from_status(status, body)
+    }
+
+    fn is_maintenance(status: reqwest::StatusCode, is_html: bool, body: &str) -> bool {
+        let looks_like_page = is_html || body.trim_start().starts_with('<');
+        let mentions_maintenance = || {
+            let body = body.to_lowercase();
+            MAINTENANCE_MARKERS.iter().any(|m| body.contains(m))
+        };
+        status == reqwest::StatusCode::SERVICE_UNAVAILABLE
+            || (looks_like_page && status.is_success())
+            || ((looks_like_page || status.is_server_error()) && mentions_maintenance())
+    }
+
+    /// Get the classification of this error.
+    pub fn kind(&self) -> ApiErrorKind {
+        match self {
+            ApiError::AuthExpired => ApiErrorKind::AuthExpired,
+            ApiError::Forbidden(_) => ApiErrorKind::Forbidden,
+            ApiError::NotFound(_) => ApiErrorKind::NotFound,
+            ApiError::SchemaMismatch(_) => ApiErrorKind::SchemaMismatch,
+            ApiError::RateLimited => ApiErrorKind::RateLimited,
+            ApiError::ServerError(..) => ApiErrorKind::Server,
+            ApiError::Maintenance(_) => ApiErrorKind::Maintenance,
+            ApiError::NetworkError(e) => Self::classify_reqwest(e),
+            ApiError::InvalidResponse(_) => ApiErrorKind::Other,
+        }
+    }
+
+    /// Classify an error returned by `ApiClient`.
+    ///
+    /// Walks the error chain so that context-wrapped failures are still recognized:
+    /// an `ApiError` anywhere in the chain wins, then raw `reqwest` transport/decode
+    /// errors, then `serde_json` parse errors.
+    pub fn classify(err: &anyhow::Error) -> ApiErrorKind {
+        for cause in err.chain() {
+            if let Some(api_err) = cause.downcast_ref::<ApiError>() {
+                return api_err.kind();
+            }
+            if let Some(req_err) = cause.downcast_ref::<reqwest::Error>() {
+                return Self::classify_reqwest(req_err);
+            }
+            if cause.is::<serde_json::Error>() {
+                return ApiErrorKind::SchemaMismatch;
+            }
+        }
+        ApiErrorKind::Other
+    }
+
+    fn classify_reqwest(err: &reqwest::Error) -> ApiErrorKind {
+        if err.is_decode() {
+            ApiErrorKind::SchemaMismatch
+        } else if let Some(status) = err.status() {
+            ApiError::from_status(status, "").kind()
+        } else {
+            ApiErrorKind::Network
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::StatusCode;
+
+    #[test]
+    fn test_from_status_kinds() {
+        assert_eq!(ApiError::from_status(StatusCode::UNAUTHORIZED, "").kind(), ApiErrorKind::AuthExpired);
+        assert_eq!(ApiError::from_status(StatusCode::FORBIDDEN, "").kind(), ApiErrorKind::Forbidden);
+        assert_eq!(ApiError::from_status(StatusCode::NOT_FOUND, "").kind(), ApiErrorKind::NotFound);
+        assert_eq!(ApiError::from_status(StatusCode::TOO_MANY_REQUESTS, "").kind(), ApiErrorKind::RateLimited);
+        assert_eq!(ApiError::from_status(StatusCode::BAD_GATEWAY, "").kind(), ApiErrorKind::Server);
+        assert_eq!(ApiError::from_status(StatusCode::IM_A_TEAPOT, "").kind(), ApiErrorKind::Other);
+    }
+
+    #[test]
+    fn test_from_response_maintenance() {
+        let page = "<html><body>Scoutbook is undergoing scheduled maintenance</body></html>";
+        let kind = |status, is_html, body| ApiError::from_response(status, is_html, body).kind();
+        assert_eq!(kind(StatusCode::SERVICE_UNAVAILABLE, false, ""), ApiErrorKind::Maintenance);
+        assert_eq!(kind(StatusCode::OK, true, page), ApiErrorKind::Maintenance);
+        assert_eq!(kind(StatusCode::OK, false, page), ApiErrorKind::Maintenance);
+        assert_eq!(kind(StatusCode::INTERNAL_SERVER_ERROR, false, r#"{"message":"Down for maintenance"}"#), ApiErrorKind::Maintenance);
+        // Ordinary failures keep their own kinds
+        assert_eq!(kind(StatusCode::BAD_GATEWAY, true, "<html>Bad gateway</html>"), ApiErrorKind::Server);
+        assert_eq!(kind(StatusCode::UNAUTHORIZED, true, "<html>Sign in</html>"), ApiErrorKind::AuthExpired);
+        assert_eq!(kind(StatusCode::OK, false, "not json"), ApiErrorKind::Other);
+        assert!(ApiErrorKind::Maintenance.is_retryable());
+    }
+
+    #[test]
+    fn test_classify_through_context() {
+        let err = anyhow::Error::from(ApiError::Forbidden("no role".to_string()))
+            .context("Failed to fetch parents");
+        assert_eq!(ApiError::classify(&err), ApiErrorKind::Forbidden);
+    }
+
+    #[test]
+    fn test_classify_json_error() {
+        let json_err = serde_json::from_str::<Vec<i64>>("{").unwrap_err();
+        let err = anyhow::Error::from(json_err).context("Failed to parse youth");
+        assert_eq!(ApiError::classify(&err), ApiErrorKind::SchemaMismatch);
+    }
+
+    #[test]
+    fn test_classify_unknown() {
+        let err = anyhow::anyhow!("something else");
+        assert_eq!(ApiError::classify(&err), ApiErrorKind::Other);
+    }
+
+    #[test]
+    fn test_retryable() {
+        assert!(ApiErrorKind::Network.is_retryable());
+        assert!(ApiErrorKind::RateLimited.is_retryable());
+        assert!(!ApiErrorKind::AuthExpired.is_retryable());
+        assert!(!ApiErrorKind::Forbidden.is_retryable());
+        assert!(!ApiErrorKind::SchemaMismatch.is_retryable());
+    }
 }
@@ -4,10 +4,21 @@
 //! Scouting.org API to fetch roster, event, and advancement data.
 //!
 //! The API uses JWT bearer token authentication obtained through
-//! the my.scouting.org authentication endpoint.
+//! the my.scouting.org authentication endpoint. GET responses can be
+//! revalidated instead of downloaded again, under `conditional`.
+//!
+//! The calls themselves make up the `ScoutingApi` trait, which `DemoApi`
+//! also implements from bundled fixtures for running without an account.
 
 pub mod client;
+pub mod conditional;
+pub mod demo;
 pub mod error;
+pub mod pagination;
+pub mod scouting;
 
-pub use client::ApiClient;
-pub use error::ApiError;
+pub use client::{ApiClient, EVENT_LOOKBACK_DAYS};
+pub use demo::{DemoApi, DemoData, DEMO_ORG_GUID, DEMO_USER_ID};
+pub use scouting::{Backend, ScoutingApi};
+pub use conditional::{Validated, ValidatorStore};
+pub use error::{ApiError, ApiErrorKind};
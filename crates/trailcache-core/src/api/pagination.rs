@@ -0,0 +1,99 @@
+//! Paging through large responses.
+//!
+//! Some org endpoints split big units (linked troops, districts) across
+//! pages, reporting the page number and page count beside the member list.
+//! `PageInfo` reads those fields from the first page, and `merge_page` folds
+//! later pages' lists into it. A response is only treated as paged when it
+//! gives an explicit page count: a bare `page` or `total` field is too
+//! common in ordinary responses to go on.
+
+use serde_json::{Map, Value};
+
+/// Names used for the current page number, also sent to request a page
+const PAGE_FIELDS: &[&str] = &["pageNumber", "page", "currentPage"];
+
+/// Names used for the number of pages
+const PAGE_COUNT_FIELDS: &[&str] = &["totalPages", "pageCount"];
+
+/// Most pages fetched for one request, in case a server reports nonsense
+pub const MAX_PAGES: u64 = 200;
+
+/// Paging details from the first page of a response.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageInfo {
+    /// Field that carries the page number, used to ask for the other pages
+    pub param: &'static str,
+    /// Number of the page this response is
+    pub current: u64,
+    /// How many pages there are in all
+    pub total_pages: u64,
+}
+
+impl PageInfo {
+    /// Paging details from `page`, or None if it doesn't say how many pages
+    /// there are.
+    pub fn from_response(page: &Value) -> Option<Self> {
+        let object = page.as_object()?;
+        let (param, current) = first_number(object, PAGE_FIELDS)?;
+        let (_, total_pages) = first_number(object, PAGE_COUNT_FIELDS)?;
+        Some(Self { param, current, total_pages })
+    }
+
+    /// Page numbers still to fetch after this one, at most `MAX_PAGES` in all.
+    pub fn remaining(&self) -> impl Iterator<Item = u64> {
+        let last = self.current + self.total_pages.min(MAX_PAGES);
+        (self.current + 1)..last
+    }
+
+    pub fn is_truncated(&self) -> bool {
+        self.total_pages > MAX_PAGES
+    }
+}
+
+/// Append the lists in `page` to the matching lists in `into`.
+pub fn merge_page(into: &mut Value, page: Value) {
+    let (Some(into), Value::Object(page)) = (into.as_object_mut(), page) else {
+        return;
+    };
+    for (key, value) in page {
+        if let (Some(Value::Array(existing)), Value::Array(items)) = (into.get_mut(&key), value) {
+            existing.extend(items);
+        }
+    }
+}
+
+fn first_number(object: &Map<String, Value>, fields: &[&'static str]) -> Option<(&'static str, u64)> {
+    fields.iter().find_map(|&field| Some((field, object.get(field)?.as_u64()?)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_page_info() {
+        let counted = json!({"pageNumber": 1, "totalPages": 3, "members": [1, 2]});
+        let info = PageInfo::from_response(&counted).unwrap();
+        assert_eq!(info, PageInfo { param: "pageNumber", current: 1, total_pages: 3 });
+        assert_eq!(info.remaining().collect::<Vec<_>>(), [2, 3]);
+
+        let info = PageInfo::from_response(&json!({"page": 0, "pageCount": 3, "users": [1, 2]})).unwrap();
+        assert_eq!(info.remaining().collect::<Vec<_>>(), [1, 2]);
+
+        // Page, total, and size fields alone aren't enough to page through
+        assert_eq!(PageInfo::from_response(&json!({"page": 0, "total": 5, "users": [1, 2]})), None);
+        assert_eq!(PageInfo::from_response(&json!({"page": 1, "pageSize": 25, "totalCount": 60, "members": []})), None);
+        assert_eq!(PageInfo::from_response(&json!({"members": [1]})), None);
+        assert_eq!(PageInfo::from_response(&json!([{"page": 1}])), None);
+    }
+
+    #[test]
+    fn test_merge_page() {
+        let mut first = json!({"pageNumber": 1, "organizationInfo": {"name": "Troop 1"}, "members": [1, 2]});
+        merge_page(&mut first, json!({"pageNumber": 2, "members": [3], "extra": [9]}));
+        assert_eq!(first["members"], json!([1, 2, 3]));
+        assert_eq!(first["organizationInfo"]["name"], "Troop 1");
+        assert!(first.get("extra").is_none());
+    }
+}
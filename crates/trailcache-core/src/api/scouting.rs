@@ -0,0 +1,158 @@
+//! The calls trailcache makes against Scoutbook, as a trait.
+//!
+//! `ApiClient` implements it over HTTP and `DemoApi` from bundled fixtures,
+//! so the frontends and the offline cache can run against either one.
+//! `Backend` is whichever of the two was chosen at startup.
+
+use std::future::Future;
+use std::sync::Arc;
+
+use anyhow::Result;
+use chrono::NaiveDate;
+
+use crate::auth::SessionData;
+use crate::models::advancement::CounselorInfo;
+use crate::models::{
+    ActivityLog, Adult, AdvancementDashboard, Award, Commissioner, Event, EventGuest, Key3Leaders, LeadershipPosition,
+    MeritBadgeCatalogEntry, MeritBadgeProgress, MeritBadgeRequirement, OrgProfile, Parent, Patrol, RankProgress,
+    RankRequirement, ReadyToAward, UnitInfo, Youth,
+};
+
+use super::conditional::ValidatorStore;
+use super::{ApiClient, DemoApi};
+
+/// Everything trailcache reads from or writes to Scoutbook. The futures are
+/// `Send` so calls can be made from spawned tasks.
+pub trait ScoutingApi: Clone + Send + Sync + 'static {
+    /// Sign in, returning the session to keep.
+    fn authenticate(&self, username: &str, password: &str) -> impl Future<Output = Result<SessionData>> + Send;
+
+    fn fetch_youth(&self, org_guid: &str) -> impl Future<Output = Result<Vec<Youth>>> + Send;
+    fn fetch_adults(&self, org_guid: &str) -> impl Future<Output = Result<Vec<Adult>>> + Send;
+    fn fetch_parents(&self, org_guid: &str) -> impl Future<Output = Result<Vec<Parent>>> + Send;
+    fn fetch_patrols(&self, org_guid: &str) -> impl Future<Output = Result<Vec<Patrol>>> + Send;
+    fn fetch_advancement_dashboard(&self, org_guid: &str) -> impl Future<Output = Result<AdvancementDashboard>> + Send;
+    fn fetch_ready_to_award(&self, org_guid: &str) -> impl Future<Output = Result<Vec<ReadyToAward>>> + Send;
+    fn fetch_key3(&self, org_guid: &str) -> impl Future<Output = Result<Key3Leaders>> + Send;
+    fn fetch_unit_pin(&self, org_guid: &str) -> impl Future<Output = Result<UnitInfo>> + Send;
+    fn fetch_org_profile(&self, org_guid: &str) -> impl Future<Output = Result<OrgProfile>> + Send;
+    fn fetch_commissioners(&self, org_guid: &str) -> impl Future<Output = Result<Vec<Commissioner>>> + Send;
+
+    fn fetch_youth_ranks(&self, user_id: i64) -> impl Future<Output = Result<Vec<RankProgress>>> + Send;
+    fn fetch_youth_merit_badges(&self, user_id: i64) -> impl Future<Output = Result<Vec<MeritBadgeProgress>>> + Send;
+    fn fetch_youth_leadership(&self, user_id: i64) -> impl Future<Output = Result<Vec<LeadershipPosition>>> + Send;
+    fn fetch_youth_awards(&self, user_id: i64) -> impl Future<Output = Result<Vec<Award>>> + Send;
+    fn fetch_youth_activity(&self, user_id: i64) -> impl Future<Output = Result<ActivityLog>> + Send;
+    fn fetch_rank_requirements(
+        &self,
+        user_id: i64,
+        rank_id: i64,
+    ) -> impl Future<Output = Result<Vec<RankRequirement>>> + Send;
+    /// Requirements and version, without the counselor.
+    fn fetch_badge_requirements_only(
+        &self,
+        user_id: i64,
+        badge_id: i64,
+    ) -> impl Future<Output = Result<(Vec<MeritBadgeRequirement>, Option<String>)>> + Send;
+    /// Requirements, version, and counselor.
+    #[allow(clippy::type_complexity)]
+    fn fetch_badge_requirements(
+        &self,
+        user_id: i64,
+        badge_id: i64,
+    ) -> impl Future<Output = Result<(Vec<MeritBadgeRequirement>, Option<String>, Option<CounselorInfo>)>> + Send;
+    fn fetch_merit_badge_catalog(&self) -> impl Future<Output = Result<Vec<MeritBadgeCatalogEntry>>> + Send;
+
+    fn fetch_events(&self, user_id: i64) -> impl Future<Output = Result<Vec<Event>>> + Send;
+    fn fetch_event_detail(&self, event_id: i64) -> impl Future<Output = Result<Event>> + Send;
+    fn fetch_event_guests(&self, event_id: i64) -> impl Future<Output = Result<Vec<EventGuest>>> + Send;
+
+    /// Record a rank requirement as completed on `date`, with leader approval.
+    fn complete_rank_requirement(
+        &self,
+        user_id: i64,
+        rank_id: i64,
+        requirement_id: i64,
+        date: NaiveDate,
+    ) -> impl Future<Output = Result<()>> + Send;
+    /// Move a scout into a patrol, taking them out of their current one.
+    fn move_youth_to_patrol(&self, user_id: i64, patrol_guid: &str) -> impl Future<Output = Result<()>> + Send;
+}
+
+/// Scoutbook itself, or the demo data under `--demo`.
+#[derive(Clone)]
+pub enum Backend {
+    Live(ApiClient),
+    Demo(DemoApi),
+}
+
+impl Backend {
+    pub fn is_demo(&self) -> bool {
+        matches!(self, Backend::Demo(_))
+    }
+
+    /// Set the bearer token. The demo doesn't check it.
+    pub fn set_token(&mut self, token: impl Into<Arc<String>>) {
+        if let Backend::Live(api) = self {
+            api.set_token(token);
+        }
+    }
+
+    /// Make live GET requests conditional, keeping responses in `store`.
+    pub fn with_validators(self, store: Arc<dyn ValidatorStore>) -> Self {
+        match self {
+            Backend::Live(api) => Backend::Live(api.with_validators(store)),
+            demo => demo,
+        }
+    }
+}
+
+/// Implement the trait for `ApiClient` by calling its own methods, and for
+/// `Backend` by passing each call to the chosen client.
+macro_rules! forward {
+    ($($name:ident($($arg:ident: $ty:ty),*) -> $out:ty;)*) => {
+        impl ScoutingApi for ApiClient {
+            $(fn $name(&self, $($arg: $ty),*) -> impl Future<Output = Result<$out>> + Send {
+                ApiClient::$name(self, $($arg),*)
+            })*
+        }
+
+        impl ScoutingApi for Backend {
+            $(async fn $name(&self, $($arg: $ty),*) -> Result<$out> {
+                match self {
+                    Backend::Live(api) => api.$name($($arg),*).await,
+                    Backend::Demo(api) => api.$name($($arg),*).await,
+                }
+            })*
+        }
+    };
+}
+
+forward! {
+    authenticate(username: &str, password: &str) -> SessionData;
+    fetch_youth(org_guid: &str) -> Vec<Youth>;
+    fetch_adults(org_guid: &str) -> Vec<Adult>;
+    fetch_parents(org_guid: &str) -> Vec<Parent>;
+    fetch_patrols(org_guid: &str) -> Vec<Patrol>;
+    fetch_advancement_dashboard(org_guid: &str) -> AdvancementDashboard;
+    fetch_ready_to_award(org_guid: &str) -> Vec<ReadyToAward>;
+    fetch_key3(org_guid: &str) -> Key3Leaders;
+    fetch_unit_pin(org_guid: &str) -> UnitInfo;
+    fetch_org_profile(org_guid: &str) -> OrgProfile;
+    fetch_commissioners(org_guid: &str) -> Vec<Commissioner>;
+    fetch_youth_ranks(user_id: i64) -> Vec<RankProgress>;
+    fetch_youth_merit_badges(user_id: i64) -> Vec<MeritBadgeProgress>;
+    fetch_youth_leadership(user_id: i64) -> Vec<LeadershipPosition>;
+    fetch_youth_awards(user_id: i64) -> Vec<Award>;
+    fetch_youth_activity(user_id: i64) -> ActivityLog;
+    fetch_rank_requirements(user_id: i64, rank_id: i64) -> Vec<RankRequirement>;
+    fetch_badge_requirements_only(user_id: i64, badge_id: i64) -> (Vec<MeritBadgeRequirement>, Option<String>);
+    fetch_badge_requirements(user_id: i64, badge_id: i64)
+        -> (Vec<MeritBadgeRequirement>, Option<String>, Option<CounselorInfo>);
+    fetch_merit_badge_catalog() -> Vec<MeritBadgeCatalogEntry>;
+    fetch_events(user_id: i64) -> Vec<Event>;
+    fetch_event_detail(event_id: i64) -> Event;
+    fetch_event_guests(event_id: i64) -> Vec<EventGuest>;
+    complete_rank_requirement(user_id: i64, rank_id: i64, requirement_id: i64, date: NaiveDate) -> ();
+    move_youth_to_patrol(user_id: i64, patrol_guid: &str) -> ();
+}
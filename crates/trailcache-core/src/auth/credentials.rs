@@ -5,6 +5,12 @@ const SERVICE_NAME: &str = "trailcache";
 
 pub struct CredentialStore;
 
+/// Keychain entry name for an organization's cache key, kept apart from
+/// usernames
+fn cache_key_entry(org_guid: &str) -> String {
+    format!("cache-key:{}", org_guid)
+}
+
 impl CredentialStore {
     /// Store username and password in the OS keychain
     pub fn store(username: &str, password: &str) -> Result<()> {
@@ -36,6 +42,35 @@ impl CredentialStore {
         Ok(())
     }
 
+    /// The cache key kept in the keychain for an organization, if any.
+    pub fn get_cache_key(org_guid: &str) -> Result<Option<String>> {
+        let entry = Entry::new(SERVICE_NAME, &cache_key_entry(org_guid))
+            .context("Failed to create keyring entry")?;
+        match entry.get_password() {
+            Ok(key) => Ok(Some(key)),
+            Err(keyring::Error::NoEntry) => Ok(None),
+            Err(e) => Err(e).context("Failed to retrieve cache key from keychain"),
+        }
+    }
+
+    /// Keep an organization's cache key in the keychain
+    pub fn store_cache_key(org_guid: &str, key: &str) -> Result<()> {
+        let entry = Entry::new(SERVICE_NAME, &cache_key_entry(org_guid))
+            .context("Failed to create keyring entry")?;
+        entry
+            .set_password(key)
+            .context("Failed to store cache key in keychain")
+    }
+
+    /// Remove an organization's cache key from the keychain
+    pub fn delete_cache_key(org_guid: &str) -> Result<()> {
+        let entry = Entry::new(SERVICE_NAME, &cache_key_entry(org_guid))
+            .context("Failed to create keyring entry")?;
+        entry
+            .delete_credential()
+            .context("Failed to delete cache key from keychain")
+    }
+
     /// Check if credentials exist for a username
     pub fn has_credentials(username: &str) -> bool {
         if let Ok(entry) = Entry::new(SERVICE_NAME, username) {
@@ -0,0 +1,179 @@
+//! Backups of data authored in trailcache.
+//!
+//! Only cache sections that can't be fetched again from Scoutbook are
+//! included. The archive is JSON with a plain header (format version,
+//! creation time, section names) and a payload encrypted with the cache key,
+//! so restoring needs the same password and organization.
+
+use std::collections::BTreeMap;
+
+use anyhow::{anyhow, bail, Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::manager::CacheManager;
+
+const BACKUP_FORMAT: &str = "trailcache-backup";
+
+/// Current archive version. Archives from newer versions are refused.
+pub const BACKUP_VERSION: u32 = 1;
+
+/// Cache sections holding data that exists only on this machine: locally
+/// authored records and archived events Scoutbook no longer returns.
+pub const LOCAL_SECTIONS: [&str; 29] = [
+    "activity_feed",
+    "attendance",
+    "nominations",
+    "adult_awards",
+    "onboarding",
+    "committee_roles",
+    "event_templates",
+    "minutes",
+    "todos",
+    "library",
+    "closet",
+    "high_adventure",
+    "eagle_projects",
+    "clinic",
+    "historian_log",
+    "debriefs",
+    "activity_plans",
+    "consents",
+    "accommodations",
+    "den_chiefs",
+    "skills",
+    "adult_skills",
+    "position_plan",
+    "event_archive",
+    "event_attendance",
+    "membership",
+    "departures",
+    "retention_audit",
+    "date_corrections",
+];
+
+/// Local sections holding health information. Left out of backups unless
+/// the caller asks for them.
+pub const MEDICAL_SECTIONS: [&str; 1] = ["medication_log"];
+
+/// A backup archive as written to disk.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupArchive {
+    pub format: String,
+    pub version: u32,
+    pub created_at: DateTime<Utc>,
+    pub app_version: String,
+    pub sections: Vec<String>,
+    /// Hex-encoded, encrypted JSON object of section name to data
+    payload: String,
+}
+
+pub(super) fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+pub(super) fn from_hex(hex: &str) -> Result<Vec<u8>> {
+    hex.as_bytes()
+        .chunks(2)
+        .map(|pair| {
+            std::str::from_utf8(pair)
+                .ok()
+                .filter(|s| s.len() == 2)
+                .and_then(|s| u8::from_str_radix(s, 16).ok())
+                .ok_or_else(|| anyhow!("Backup payload is corrupt"))
+        })
+        .collect()
+}
+
+impl BackupArchive {
+    /// Read an archive, checking it's one this version can restore.
+    pub fn parse(json: &str) -> Result<Self> {
+        let archive: Self = serde_json::from_str(json).context("Not a trailcache backup")?;
+        if archive.format != BACKUP_FORMAT {
+            bail!("Not a trailcache backup");
+        }
+        if archive.version > BACKUP_VERSION {
+            bail!(
+                "Backup is format version {}; this trailcache reads up to version {}. Upgrade to restore it.",
+                archive.version,
+                BACKUP_VERSION
+            );
+        }
+        Ok(archive)
+    }
+}
+
+impl CacheManager {
+    /// Collect every local section that has data into an archive, plus the
+    /// medical sections when `include_medical` is set.
+    pub fn create_backup(&self, app_version: &str, include_medical: bool) -> Result<BackupArchive> {
+        let medical = if include_medical { &MEDICAL_SECTIONS[..] } else { &[] };
+        let mut sections = BTreeMap::new();
+        for &name in LOCAL_SECTIONS.iter().chain(medical) {
+            if let Some(cached) = self.load::<serde_json::Value>(name)? {
+                sections.insert(name.to_string(), cached.data);
+            }
+        }
+        let payload = self.seal(&serde_json::to_vec(&sections)?)?;
+        Ok(BackupArchive {
+            format: BACKUP_FORMAT.to_string(),
+            version: BACKUP_VERSION,
+            created_at: Utc::now(),
+            app_version: app_version.to_string(),
+            sections: sections.into_keys().collect(),
+            payload: to_hex(&payload),
+        })
+    }
+
+    /// Write the archive's sections over the local ones. Sections this
+    /// version doesn't know are skipped. Returns the names restored.
+    pub fn restore_backup(&self, archive: &BackupArchive) -> Result<Vec<String>> {
+        let plaintext = self
+            .unseal(&from_hex(&archive.payload)?)
+            .map_err(|_| anyhow!("Can't decrypt the backup: it was made with a different password or organization"))?;
+        let sections: BTreeMap<String, serde_json::Value> = serde_json::from_slice(&plaintext)?;
+        let mut restored = Vec::new();
+        for (name, data) in sections {
+            if LOCAL_SECTIONS.contains(&name.as_str()) || MEDICAL_SECTIONS.contains(&name.as_str()) {
+                self.save(&name, &data)?;
+                restored.push(name);
+            }
+        }
+        Ok(restored)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::TodoList;
+
+    #[test]
+    fn test_backup_round_trip_skips_api_data() {
+        let dir = std::env::temp_dir().join(format!("trailcache-backup-test-{}", std::process::id()));
+        let mut cache = CacheManager::new_without_encryption(dir.join("a")).unwrap();
+        cache.set_password("secret", "org-guid-1234");
+        let mut todos = TodoList::default();
+        todos.add(crate::models::TodoSubject::Unit, "Renew charter");
+        cache.save_todos(&todos).unwrap();
+        cache.save_youth(&[]).unwrap();
+        cache.save_medication_log(&crate::models::MedicationLog::default()).unwrap();
+
+        assert_eq!(cache.create_backup("1.0", true).unwrap().sections, ["medication_log", "todos"]);
+        let archive = cache.create_backup("1.0", false).unwrap();
+        assert_eq!(archive.sections, ["todos"]);
+        let json = serde_json::to_string(&archive).unwrap();
+
+        let mut other = CacheManager::new_without_encryption(dir.join("b")).unwrap();
+        other.set_password("different", "org-guid-1234");
+        assert!(other.restore_backup(&BackupArchive::parse(&json).unwrap()).is_err());
+
+        other.set_password("secret", "org-guid-1234");
+        assert_eq!(other.restore_backup(&BackupArchive::parse(&json).unwrap()).unwrap(), ["todos"]);
+        assert_eq!(other.load_todos().unwrap().unwrap().data.items.len(), 1);
+
+        let newer = json.replace("\"version\":1", "\"version\":9");
+        assert!(BackupArchive::parse(&newer).unwrap_err().to_string().contains("version 9"));
+        std::fs::remove_dir_all(dir).ok();
+    }
+}
@@ -0,0 +1,158 @@
+//! How old cached data may get before views warn about it.
+//!
+//! Ordinary staleness (an hour) only means a background refresh is due. This
+//! is for data that hasn't been refreshed in weeks, like a laptop that sat in
+//! a closet since last season: views showing it get a warning banner, and
+//! past a second, optional limit, contact details and other personal
+//! information are hidden until the data is refreshed. Each view can have its
+//! own limits, keyed by its lowercase name (`scouts`, `adults`, `events`, ...).
+
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use super::manager::{CacheManager, CachedData};
+
+/// Days before a view warns about its data unless configured otherwise
+pub const DEFAULT_WARN_AFTER_DAYS: u32 = 30;
+
+/// Age limits for one view's data.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FreshnessRule {
+    /// Days after the last refresh before a warning banner is shown
+    /// (None never warns)
+    pub warn_after_days: Option<u32>,
+    /// Days after the last refresh before personal information is hidden
+    /// (None always shows it)
+    pub hide_personal_after_days: Option<u32>,
+}
+
+impl Default for FreshnessRule {
+    fn default() -> Self {
+        Self { warn_after_days: Some(DEFAULT_WARN_AFTER_DAYS), hide_personal_after_days: None }
+    }
+}
+
+impl FreshnessRule {
+    /// How `cached_at` measures up at `now`.
+    pub fn check(&self, cached_at: DateTime<Utc>, now: DateTime<Utc>) -> Freshness {
+        let days = (now - cached_at).num_days().max(0);
+        let past = |limit: Option<u32>| limit.is_some_and(|limit| days >= i64::from(limit));
+        if past(self.hide_personal_after_days) {
+            Freshness::Expired(days)
+        } else if past(self.warn_after_days) {
+            Freshness::Outdated(days)
+        } else {
+            Freshness::Current
+        }
+    }
+}
+
+/// Age limits for all views, with overrides for some.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FreshnessPolicy {
+    #[serde(flatten)]
+    pub default: FreshnessRule,
+    /// Per-view limits, replacing the default for that view
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub views: BTreeMap<String, FreshnessRule>,
+}
+
+impl FreshnessPolicy {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    pub fn rule_for(&self, view: &str) -> &FreshnessRule {
+        self.views.get(&view.to_lowercase()).unwrap_or(&self.default)
+    }
+}
+
+/// Whether data is recent enough to rely on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Freshness {
+    Current,
+    /// Old enough to warn about (days since refresh)
+    Outdated(i64),
+    /// Too old to show personal information (days since refresh)
+    Expired(i64),
+}
+
+impl Freshness {
+    pub fn hides_personal_info(&self) -> bool {
+        matches!(self, Freshness::Expired(_))
+    }
+
+    /// Days since refresh, if old enough to mention.
+    pub fn days(&self) -> Option<i64> {
+        match self {
+            Freshness::Current => None,
+            Freshness::Outdated(days) | Freshness::Expired(days) => Some(*days),
+        }
+    }
+}
+
+/// When each unit-wide dataset was last cached.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheTimes {
+    pub youth: Option<DateTime<Utc>>,
+    pub adults: Option<DateTime<Utc>>,
+    pub parents: Option<DateTime<Utc>>,
+    pub events: Option<DateTime<Utc>>,
+}
+
+impl CacheManager {
+    pub fn cache_times(&self) -> CacheTimes {
+        CacheTimes {
+            youth: self.cached_at("youth", || self.load_youth()),
+            adults: self.cached_at("adults", || self.load_adults()),
+            parents: self.cached_at("parents", || self.load_parents()),
+            events: self.cached_at("events", || self.load_events()),
+        }
+    }
+
+    fn cached_at<T>(&self, name: &str, loader: impl FnOnce() -> Result<Option<CachedData<T>>>) -> Option<DateTime<Utc>> {
+        match loader() {
+            Ok(cached) => cached.map(|c| c.cached_at),
+            Err(e) => {
+                debug!(cache = name, error = %e, "Failed to load cache for freshness check");
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_freshness_rules() {
+        let now = Utc::now();
+        let policy: FreshnessPolicy = serde_json::from_value(serde_json::json!({
+            "views": { "adults": { "warn_after_days": 14, "hide_personal_after_days": 60 } }
+        }))
+        .unwrap();
+        assert_eq!(policy.default, FreshnessRule::default());
+
+        let scouts = policy.rule_for("Scouts");
+        assert_eq!(scouts.check(now - Duration::days(3), now), Freshness::Current);
+        assert_eq!(scouts.check(now - Duration::days(45), now), Freshness::Outdated(45));
+        assert_eq!(scouts.check(now - Duration::days(400), now), Freshness::Outdated(400));
+
+        let adults = policy.rule_for("adults");
+        assert_eq!(adults.check(now - Duration::days(20), now), Freshness::Outdated(20));
+        assert!(adults.check(now - Duration::days(60), now).hides_personal_info());
+        // Clock skew isn't an error
+        assert_eq!(adults.check(now + Duration::days(1), now), Freshness::Current);
+
+        assert!(FreshnessPolicy::default().is_default());
+        assert!(!policy.is_default());
+    }
+}
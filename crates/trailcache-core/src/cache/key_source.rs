@@ -0,0 +1,120 @@
+//! Keychain-held cache keys.
+//!
+//! By default the cache key is derived from the password and organization,
+//! so anyone who knows the password can read a copied cache directory. With
+//! `keyring_cache_key` on, the key also mixes in a random secret kept in the
+//! OS keychain: the cache is only readable on this computer's account, and
+//! the password is still checked when logging in offline.
+//!
+//! Switching the option re-encrypts the existing cache files, so nothing has
+//! to be fetched again. A file the old key can't read stops the switch
+//! before anything is rewritten.
+
+use anyhow::{anyhow, Result};
+use rand::RngCore;
+use tracing::info;
+
+use super::backup::{from_hex, to_hex};
+use super::manager::CacheManager;
+use crate::auth::CredentialStore;
+
+/// The password-derived key combined with the keychain secret.
+fn mix(password_key: &[u8; 32], secret: &[u8; 32]) -> [u8; 32] {
+    std::array::from_fn(|i| password_key[i] ^ secret[i])
+}
+
+fn parse_secret(hex: &str) -> Result<[u8; 32]> {
+    from_hex(hex)
+        .ok()
+        .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+        .ok_or_else(|| anyhow!("The cache key in the keychain is corrupt"))
+}
+
+/// Set up `cache`'s key for the `keyring_cache_key` setting. Call right
+/// after `set_password`. Turning the setting on creates the keychain secret
+/// and re-encrypts the cache; turning it off re-encrypts the cache with the
+/// password key and removes the secret.
+pub fn apply_key_source(cache: &mut CacheManager, org_guid: &str, use_keyring: bool) -> Result<()> {
+    let password_key = cache.key();
+    let stored = CredentialStore::get_cache_key(org_guid)?.map(|hex| parse_secret(&hex)).transpose()?;
+    match (use_keyring, stored) {
+        (true, Some(secret)) => cache.set_key(mix(&password_key, &secret)),
+        (true, None) => {
+            let mut secret = [0u8; 32];
+            rand::thread_rng().fill_bytes(&mut secret);
+            // Saved before re-encrypting, so a crash can't strand the cache,
+            // and taken back out if re-encrypting fails
+            CredentialStore::store_cache_key(org_guid, &to_hex(&secret))?;
+            match cache.rekey(mix(&password_key, &secret)) {
+                Ok(count) => info!(files = count, "Cache re-encrypted with the keychain key"),
+                Err(e) => {
+                    CredentialStore::delete_cache_key(org_guid)?;
+                    return Err(e);
+                }
+            }
+        }
+        (false, Some(secret)) => {
+            cache.set_key(mix(&password_key, &secret));
+            let count = cache.rekey(password_key)?;
+            // Only once every file is under the password key
+            CredentialStore::delete_cache_key(org_guid)?;
+            info!(files = count, "Cache re-encrypted with the password key");
+        }
+        (false, None) => {}
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::TodoList;
+
+    #[test]
+    fn test_rekey_with_keychain_secret() {
+        let dir = std::env::temp_dir().join(format!("trailcache-key-source-test-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let mut cache = CacheManager::new_without_encryption(dir.clone()).unwrap();
+        cache.set_password("secret", "org-guid-1234");
+        let password_key = cache.key();
+        let mut todos = TodoList::default();
+        todos.add(crate::models::TodoSubject::Unit, "Renew charter");
+        cache.save_todos(&todos).unwrap();
+
+        let secret = parse_secret(&to_hex(&[7u8; 32])).unwrap();
+        assert_eq!(cache.rekey(mix(&password_key, &secret)).unwrap(), 1);
+
+        let mut reader = CacheManager::new_without_encryption(dir.clone()).unwrap();
+        reader.set_password("secret", "org-guid-1234");
+        assert!(reader.load_todos().unwrap().is_none(), "the password alone can't read it");
+        reader.set_key(mix(&reader.key(), &secret));
+        assert_eq!(reader.load_todos().unwrap().unwrap().data.items.len(), 1);
+
+        reader.rekey(password_key).unwrap();
+        cache.set_password("secret", "org-guid-1234");
+        assert!(cache.load_todos().unwrap().is_some());
+        assert!(parse_secret("abcd").is_err());
+        std::fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    fn test_rekey_stops_at_unreadable_file() {
+        let dir = std::env::temp_dir().join(format!("trailcache-rekey-corrupt-test-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let mut cache = CacheManager::new_without_encryption(dir.clone()).unwrap();
+        cache.set_password("secret", "org-guid-1234");
+        let password_key = cache.key();
+        let mut todos = TodoList::default();
+        todos.add(crate::models::TodoSubject::Unit, "Renew charter");
+        cache.save_todos(&todos).unwrap();
+        std::fs::write(dir.join("corrupt.enc"), b"not a cache file").unwrap();
+
+        let err = cache.rekey(mix(&password_key, &[7u8; 32])).unwrap_err();
+        assert!(err.to_string().contains("corrupt.enc"), "{}", err);
+        assert_eq!(cache.key(), password_key, "key unchanged");
+        assert!(cache.load_todos().unwrap().is_some(), "files untouched");
+        let leftovers = std::fs::read_dir(&dir).unwrap().filter(|e| e.as_ref().unwrap().path().to_string_lossy().ends_with(".tmp"));
+        assert_eq!(leftovers.count(), 0);
+        std::fs::remove_dir_all(dir).ok();
+    }
+}
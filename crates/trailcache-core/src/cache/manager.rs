@@ -11,10 +11,13 @@ use rand::RngCore;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use tracing::debug;
 
+use super::offline::PrefetchManifest;
+use crate::api::{Validated, ValidatorStore};
+use super::retention::{Departures, RetentionAudit};
 use crate::models::{
-    Adult, AdvancementDashboard, Award, Commissioner, Event, Key3Leaders, LeadershipPosition,
-    MeritBadgeProgress, MeritBadgeRequirement, OrgProfile, Parent, Patrol, RankProgress,
-    ReadyToAward, UnitInfo, Youth,
+    Accommodations, ActivityFeed, ActivityPlans, ActivityLog, Adult, AdultAwardChecklists, AdultSkills, AdvancementDashboard, AttendanceHistory, Award, Clinic, Commissioner, CommitteeMinutes, CommitteeRoles, Consents, DateCorrectionLog, Debriefs, DenChiefLinks, EagleProjects, Event, EventArchive, EventAttendance, EventGuest, EventTemplates, HighAdventure, HistorianLog, Key3Leaders, Onboarding, Library, LeadershipPosition, LinkedData,
+    MedicationLog, MembershipHistory, MeritBadgeProgress, ScoutTags, MeritBadgeRequirement, Nominations, OrgProfile, Parent, Patrol, PositionPlan, RankProgress,
+    ReadyToAward, SkillMatrix, TodoList, UniformCloset, UnitData, UnitInfo, Youth,
 };
 
 /// Badge requirements with optional version string, as stored in cache.
@@ -86,6 +89,10 @@ fn decrypt_data(data: &[u8], key: &[u8; 32]) -> Result<Vec<u8>> {
 /// Balances freshness with reducing unnecessary API calls for slowly-changing data.
 const CACHE_STALE_MINUTES: i64 = 60;
 
+/// Drop cached event guest lists after 14 days.
+/// Guest lists are per-event files that would otherwise pile up as events pass.
+const EVENT_GUESTS_TTL_MINUTES: i64 = 14 * 24 * 60;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CachedData<T> {
     pub data: T,
@@ -175,11 +182,65 @@ impl CacheManager {
         }
     }
 
+    /// Encrypt with `key` from now on, for a key not derived from the password alone.
+    pub(super) fn set_key(&mut self, key: [u8; 32]) {
+        self.encryption_key = key;
+    }
+
+    pub(super) fn key(&self) -> [u8; 32] {
+        self.encryption_key
+    }
+
+    /// Re-encrypt every cache file under `key`, and use `key` from now on.
+    /// Every file is decrypted before any is rewritten, so a file the
+    /// current key can't read is an error and nothing changes. Each file is
+    /// written to a temporary file, synced, and renamed over the original.
+    /// Returns how many files were re-encrypted.
+    pub(super) fn rekey(&mut self, key: [u8; 32]) -> Result<usize> {
+        let mut files = Vec::new();
+        for entry in std::fs::read_dir(&self.cache_dir)? {
+            let path = entry?.path();
+            if path.extension().is_none_or(|e| e != "enc") {
+                continue;
+            }
+            let plaintext = decrypt_data(&std::fs::read(&path)?, &self.encryption_key)
+                .with_context(|| format!("{} can't be read with the current key; remove it and try again", path.display()))?;
+            files.push((path, plaintext));
+        }
+        for (path, plaintext) in &files {
+            let temp = path.with_extension("enc.tmp");
+            let written = (|| -> Result<()> {
+                let mut file = std::fs::File::create(&temp)?;
+                std::io::Write::write_all(&mut file, &encrypt_data(plaintext, &key)?)?;
+                file.sync_all()?;
+                std::fs::rename(&temp, path)?;
+                Ok(())
+            })();
+            if let Err(e) = written {
+                let _ = std::fs::remove_file(&temp);
+                return Err(e.context(format!("Re-encrypting {}", path.display())));
+            }
+        }
+        if let Ok(dir) = std::fs::File::open(&self.cache_dir) {
+            let _ = dir.sync_all();
+        }
+        self.encryption_key = key;
+        Ok(files.len())
+    }
+
     fn cache_path(&self, name: &str) -> PathBuf {
         self.cache_dir.join(format!("{}.enc", name))
     }
 
-    fn load<T: DeserializeOwned>(&self, name: &str) -> Result<Option<CachedData<T>>> {
+    fn remove(&self, name: &str) -> Result<()> {
+        let path = self.cache_path(name);
+        if path.exists() {
+            std::fs::remove_file(path)?;
+        }
+        Ok(())
+    }
+
+    pub(super) fn load<T: DeserializeOwned>(&self, name: &str) -> Result<Option<CachedData<T>>> {
         let path = self.cache_path(name);
         if !path.exists() {
             return Ok(None);
@@ -203,7 +264,7 @@ impl CacheManager {
         Ok(Some(cached))
     }
 
-    fn save<T: Serialize>(&self, name: &str, data: &T) -> Result<()> {
+    pub(super) fn save<T: Serialize>(&self, name: &str, data: &T) -> Result<()> {
         let cached = CachedData::new(data);
         let path = self.cache_path(name);
         let plaintext = serde_json::to_vec(&cached)?;
@@ -212,6 +273,15 @@ impl CacheManager {
         Ok(())
     }
 
+    /// Encrypt with the cache key, for data kept outside the cache directory.
+    pub(super) fn seal(&self, plaintext: &[u8]) -> Result<Vec<u8>> {
+        encrypt_data(plaintext, &self.encryption_key)
+    }
+
+    pub(super) fn unseal(&self, data: &[u8]) -> Result<Vec<u8>> {
+        decrypt_data(data, &self.encryption_key)
+    }
+
     // ===== Youth =====
 
     pub fn load_youth(&self) -> Result<Option<CachedData<Vec<Youth>>>> {
@@ -282,6 +352,56 @@ impl CacheManager {
         self.save("events", &events)
     }
 
+    // ===== Event Archive =====
+
+    pub fn load_event_archive(&self) -> Result<Option<CachedData<EventArchive>>> {
+        self.load("event_archive")
+    }
+
+    pub fn save_event_archive(&self, archive: &EventArchive) -> Result<()> {
+        self.save("event_archive", archive)
+    }
+
+    // ===== Event Attendance =====
+
+    pub fn load_event_attendance(&self) -> Result<Option<CachedData<EventAttendance>>> {
+        self.load("event_attendance")
+    }
+
+    pub fn save_event_attendance(&self, attendance: &EventAttendance) -> Result<()> {
+        self.save("event_attendance", attendance)
+    }
+
+    // ===== Membership History =====
+
+    pub fn load_membership(&self) -> Result<Option<CachedData<MembershipHistory>>> {
+        self.load("membership")
+    }
+
+    pub fn save_membership(&self, history: &MembershipHistory) -> Result<()> {
+        self.save("membership", history)
+    }
+
+    // ===== Event Guests =====
+
+    /// Load the guest list for one event.
+    /// Lists older than the TTL are removed and treated as a cache miss.
+    pub fn load_event_guests(&self, event_id: i64) -> Result<Option<CachedData<Vec<EventGuest>>>> {
+        let name = format!("event_guests_{}", event_id);
+        match self.load::<Vec<EventGuest>>(&name)? {
+            Some(cached) if cached.age_minutes() > EVENT_GUESTS_TTL_MINUTES => {
+                debug!(event_id, "Event guests cache expired, removing");
+                let _ = std::fs::remove_file(self.cache_path(&name));
+                Ok(None)
+            }
+            cached => Ok(cached),
+        }
+    }
+
+    pub fn save_event_guests(&self, event_id: i64, guests: &[EventGuest]) -> Result<()> {
+        self.save(&format!("event_guests_{}", event_id), &guests)
+    }
+
     // ===== Individual Youth Progress =====
 
     pub fn load_youth_ranks(&self, user_id: i64) -> Result<Option<CachedData<Vec<RankProgress>>>> {
@@ -337,6 +457,14 @@ impl CacheManager {
         self.save(&format!("awards_{}", user_id), &awards)
     }
 
+    pub fn load_youth_activity(&self, user_id: i64) -> Result<Option<CachedData<ActivityLog>>> {
+        self.load(&format!("activity_log_{}", user_id))
+    }
+
+    pub fn save_youth_activity(&self, user_id: i64, log: &ActivityLog) -> Result<()> {
+        self.save(&format!("activity_log_{}", user_id), log)
+    }
+
     // ===== Unit Info =====
 
     pub fn load_unit_info(&self) -> Result<Option<CachedData<UnitInfo>>> {
@@ -347,6 +475,282 @@ impl CacheManager {
         self.save("unit_info", info)
     }
 
+    // ===== Activity Feed =====
+
+    pub fn load_activity_feed(&self) -> Result<Option<CachedData<ActivityFeed>>> {
+        self.load("activity_feed")
+    }
+
+    pub fn save_activity_feed(&self, feed: &ActivityFeed) -> Result<()> {
+        self.save("activity_feed", feed)
+    }
+
+    // ===== Meeting Attendance =====
+
+    pub fn load_attendance(&self) -> Result<Option<CachedData<AttendanceHistory>>> {
+        self.load("attendance")
+    }
+
+    pub fn save_attendance(&self, history: &AttendanceHistory) -> Result<()> {
+        self.save("attendance", history)
+    }
+
+    // ===== Award Nominations =====
+
+    pub fn load_nominations(&self) -> Result<Option<CachedData<Nominations>>> {
+        self.load("nominations")
+    }
+
+    pub fn save_nominations(&self, nominations: &Nominations) -> Result<()> {
+        self.save("nominations", nominations)
+    }
+
+    // ===== Adult Award Checklists =====
+
+    pub fn load_adult_awards(&self) -> Result<Option<CachedData<AdultAwardChecklists>>> {
+        self.load("adult_awards")
+    }
+
+    pub fn save_adult_awards(&self, checklists: &AdultAwardChecklists) -> Result<()> {
+        self.save("adult_awards", checklists)
+    }
+
+    // ===== Committee Roles =====
+
+    pub fn load_committee_roles(&self) -> Result<Option<CachedData<CommitteeRoles>>> {
+        self.load("committee_roles")
+    }
+
+    pub fn save_committee_roles(&self, roles: &CommitteeRoles) -> Result<()> {
+        self.save("committee_roles", roles)
+    }
+
+    // ===== Event Templates =====
+
+    pub fn load_event_templates(&self) -> Result<Option<CachedData<EventTemplates>>> {
+        self.load("event_templates")
+    }
+
+    pub fn save_event_templates(&self, templates: &EventTemplates) -> Result<()> {
+        self.save("event_templates", templates)
+    }
+
+    // ===== Committee Minutes =====
+
+    pub fn load_minutes(&self) -> Result<Option<CachedData<CommitteeMinutes>>> {
+        self.load("minutes")
+    }
+
+    pub fn save_minutes(&self, minutes: &CommitteeMinutes) -> Result<()> {
+        self.save("minutes", minutes)
+    }
+
+    // ===== To-do List =====
+
+    pub fn load_todos(&self) -> Result<Option<CachedData<TodoList>>> {
+        self.load("todos")
+    }
+
+    pub fn save_todos(&self, todos: &TodoList) -> Result<()> {
+        self.save("todos", todos)
+    }
+
+    // ===== Leader Onboarding =====
+
+    pub fn load_onboarding(&self) -> Result<Option<CachedData<Onboarding>>> {
+        self.load("onboarding")
+    }
+
+    pub fn save_onboarding(&self, onboarding: &Onboarding) -> Result<()> {
+        self.save("onboarding", onboarding)
+    }
+
+    // ===== Merit Badge Clinic =====
+
+    pub fn load_clinic(&self) -> Result<Option<CachedData<Clinic>>> {
+        self.load("clinic")
+    }
+
+    pub fn save_clinic(&self, clinic: &Clinic) -> Result<()> {
+        self.save("clinic", clinic)
+    }
+
+    // ===== Eagle Projects =====
+
+    pub fn load_eagle_projects(&self) -> Result<Option<CachedData<EagleProjects>>> {
+        self.load("eagle_projects")
+    }
+
+    pub fn save_eagle_projects(&self, projects: &EagleProjects) -> Result<()> {
+        self.save("eagle_projects", projects)
+    }
+
+    // ===== High Adventure =====
+
+    pub fn load_high_adventure(&self) -> Result<Option<CachedData<HighAdventure>>> {
+        self.load("high_adventure")
+    }
+
+    pub fn save_high_adventure(&self, high_adventure: &HighAdventure) -> Result<()> {
+        self.save("high_adventure", high_adventure)
+    }
+
+    // ===== Uniform Closet =====
+
+    pub fn load_closet(&self) -> Result<Option<CachedData<UniformCloset>>> {
+        self.load("closet")
+    }
+
+    pub fn save_closet(&self, closet: &UniformCloset) -> Result<()> {
+        self.save("closet", closet)
+    }
+
+    // ===== Adult Skills =====
+
+    pub fn load_adult_skills(&self) -> Result<Option<CachedData<AdultSkills>>> {
+        self.load("adult_skills")
+    }
+
+    pub fn save_adult_skills(&self, skills: &AdultSkills) -> Result<()> {
+        self.save("adult_skills", skills)
+    }
+
+    // ===== Library =====
+
+    pub fn load_library(&self) -> Result<Option<CachedData<Library>>> {
+        self.load("library")
+    }
+
+    pub fn save_library(&self, library: &Library) -> Result<()> {
+        self.save("library", library)
+    }
+
+    // ===== Historian Log =====
+
+    pub fn load_historian_log(&self) -> Result<Option<CachedData<HistorianLog>>> {
+        self.load("historian_log")
+    }
+
+    pub fn save_historian_log(&self, log: &HistorianLog) -> Result<()> {
+        self.save("historian_log", log)
+    }
+
+    // ===== Debriefs =====
+
+    pub fn load_debriefs(&self) -> Result<Option<CachedData<Debriefs>>> {
+        self.load("debriefs")
+    }
+
+    pub fn save_debriefs(&self, debriefs: &Debriefs) -> Result<()> {
+        self.save("debriefs", debriefs)
+    }
+
+    // ===== Activity Plans =====
+
+    pub fn load_activity_plans(&self) -> Result<Option<CachedData<ActivityPlans>>> {
+        self.load("activity_plans")
+    }
+
+    pub fn save_activity_plans(&self, plans: &ActivityPlans) -> Result<()> {
+        self.save("activity_plans", plans)
+    }
+
+    // ===== Consent Forms =====
+
+    pub fn load_consents(&self) -> Result<Option<CachedData<Consents>>> {
+        self.load("consents")
+    }
+
+    pub fn save_consents(&self, consents: &Consents) -> Result<()> {
+        self.save("consents", consents)
+    }
+
+    // ===== Accommodations =====
+
+    pub fn load_accommodations(&self) -> Result<Option<CachedData<Accommodations>>> {
+        self.load("accommodations")
+    }
+
+    pub fn save_accommodations(&self, accommodations: &Accommodations) -> Result<()> {
+        self.save("accommodations", accommodations)
+    }
+
+    // ===== Scout Tags =====
+
+    pub fn load_scout_tags(&self) -> Result<Option<CachedData<ScoutTags>>> {
+        self.load("scout_tags")
+    }
+
+    pub fn save_scout_tags(&self, tags: &ScoutTags) -> Result<()> {
+        self.save("scout_tags", tags)
+    }
+
+    // ===== Medication Log =====
+
+    pub fn load_medication_log(&self) -> Result<Option<CachedData<MedicationLog>>> {
+        self.load("medication_log")
+    }
+
+    pub fn save_medication_log(&self, log: &MedicationLog) -> Result<()> {
+        self.save("medication_log", log)
+    }
+
+    // ===== Den Chiefs =====
+
+    pub fn load_den_chiefs(&self) -> Result<Option<CachedData<DenChiefLinks>>> {
+        self.load("den_chiefs")
+    }
+
+    pub fn save_den_chiefs(&self, links: &DenChiefLinks) -> Result<()> {
+        self.save("den_chiefs", links)
+    }
+
+    // ===== Skill Certifications =====
+
+    pub fn load_skills(&self) -> Result<Option<CachedData<SkillMatrix>>> {
+        self.load("skills")
+    }
+
+    pub fn save_skills(&self, skills: &SkillMatrix) -> Result<()> {
+        self.save("skills", skills)
+    }
+
+    // ===== Position Plan =====
+
+    pub fn load_position_plan(&self) -> Result<Option<CachedData<PositionPlan>>> {
+        self.load("position_plan")
+    }
+
+    pub fn save_position_plan(&self, plan: &PositionPlan) -> Result<()> {
+        self.save("position_plan", plan)
+    }
+
+    // ===== Retention =====
+
+    pub fn load_departures(&self) -> Result<Option<CachedData<Departures>>> {
+        self.load("departures")
+    }
+
+    pub fn save_departures(&self, departures: &Departures) -> Result<()> {
+        self.save("departures", departures)
+    }
+
+    pub fn load_date_corrections(&self) -> Result<Option<CachedData<DateCorrectionLog>>> {
+        self.load("date_corrections")
+    }
+
+    pub fn save_date_corrections(&self, log: &DateCorrectionLog) -> Result<()> {
+        self.save("date_corrections", log)
+    }
+
+    pub fn load_retention_audit(&self) -> Result<Option<CachedData<RetentionAudit>>> {
+        self.load("retention_audit")
+    }
+
+    pub fn save_retention_audit(&self, audit: &RetentionAudit) -> Result<()> {
+        self.save("retention_audit", audit)
+    }
+
     // ===== Key3 =====
 
     pub fn load_key3(&self) -> Result<Option<CachedData<Key3Leaders>>> {
@@ -367,6 +771,54 @@ impl CacheManager {
         self.save("org_profile", profile)
     }
 
+    // ===== District Units =====
+
+    /// What a district unit's cache holds for the district dashboard.
+    /// Missing or unreadable files are left empty.
+    pub fn load_unit_data(&self) -> UnitData {
+        let profile = self.load_org_profile().ok().flatten();
+        let adults = self.load_adults().ok().flatten();
+        UnitData {
+            fetched_at: profile.as_ref().map(|c| c.cached_at).or(adults.as_ref().map(|c| c.cached_at)),
+            profile: profile.map(|c| c.data),
+            info: self.load_unit_info().ok().flatten().map(|c| c.data),
+            key3: self.load_key3().ok().flatten().map(|c| c.data),
+            adults: adults.map(|c| c.data).unwrap_or_default(),
+        }
+    }
+
+    /// Save a district unit's fetched data.
+    pub fn save_unit_data(&self, data: &UnitData) -> Result<()> {
+        if let Some(ref profile) = data.profile {
+            self.save_org_profile(profile)?;
+        }
+        if let Some(ref info) = data.info {
+            self.save_unit_info(info)?;
+        }
+        if let Some(ref key3) = data.key3 {
+            self.save_key3(key3)?;
+        }
+        self.save_adults(&data.adults)
+    }
+
+    /// Load a linked troop's cached profile and rosters.
+    pub fn load_linked_data(&self) -> LinkedData {
+        LinkedData {
+            profile: self.load_org_profile().ok().flatten().map(|c| c.data),
+            youth: self.load_youth().ok().flatten().map(|c| c.data).unwrap_or_default(),
+            adults: self.load_adults().ok().flatten().map(|c| c.data).unwrap_or_default(),
+        }
+    }
+
+    /// Save a linked troop's fetched profile and rosters.
+    pub fn save_linked_data(&self, data: &LinkedData) -> Result<()> {
+        if let Some(ref profile) = data.profile {
+            self.save_org_profile(profile)?;
+        }
+        self.save_youth(&data.youth)?;
+        self.save_adults(&data.adults)
+    }
+
     // ===== Commissioners =====
 
     pub fn load_commissioners(&self) -> Result<Option<CachedData<Vec<Commissioner>>>> {
@@ -377,6 +829,21 @@ impl CacheManager {
         self.save("commissioners", &commissioners)
     }
 
+    // ===== Offline Prefetch Manifest =====
+
+    pub fn load_prefetch_manifest(&self) -> Result<Option<CachedData<PrefetchManifest>>> {
+        self.load("prefetch_manifest")
+    }
+
+    pub fn save_prefetch_manifest(&self, manifest: &PrefetchManifest) -> Result<()> {
+        self.save("prefetch_manifest", manifest)
+    }
+
+    /// Remove the manifest once a prefetch has finished.
+    pub fn clear_prefetch_manifest(&self) -> Result<()> {
+        self.remove("prefetch_manifest")
+    }
+
     // ===== Rank Requirements =====
 
     pub fn load_rank_requirements(
@@ -396,6 +863,13 @@ impl CacheManager {
         self.save(&format!("rank_reqs_{}_{}", user_id, rank_id), &requirements)
     }
 
+    /// Drop a youth's cached rank progress and one rank's requirements,
+    /// after they've been changed on the server.
+    pub fn invalidate_rank_progress(&self, user_id: i64, rank_id: i64) -> Result<()> {
+        self.remove(&format!("ranks_{}", user_id))?;
+        self.remove(&format!("rank_reqs_{}_{}", user_id, rank_id))
+    }
+
     // ===== Badge Requirements =====
 
     pub fn load_badge_requirements(
@@ -505,6 +979,33 @@ impl CacheManager {
     }
 }
 
+/// Cache file for a URL's conditional-request validators. URLs can be
+/// long and contain slashes, so the name is a stable FNV-1a hash of it.
+fn validated_name(url: &str) -> String {
+    let hash = url.bytes().fold(0xcbf29ce484222325u64, |h, b| {
+        (h ^ b as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("http_{:016x}", hash)
+}
+
+impl ValidatorStore for CacheManager {
+    fn load_validated(&self, url: &str) -> Option<Validated> {
+        match self.load::<Validated>(&validated_name(url)) {
+            Ok(cached) => cached.map(|c| c.data),
+            Err(e) => {
+                debug!(url = url, error = %e, "Stored response unreadable, fetching in full");
+                None
+            }
+        }
+    }
+
+    fn save_validated(&self, url: &str, response: &Validated) {
+        if let Err(e) = self.save(&validated_name(url), response) {
+            debug!(url = url, error = %e, "Failed to store response validators");
+        }
+    }
+}
+
 #[derive(Debug, Default, serde::Serialize)]
 #[cfg_attr(feature = "ts", derive(ts_rs::TS))]
 #[cfg_attr(feature = "ts", ts(export))]
@@ -599,6 +1100,85 @@ mod tests {
         assert_eq!(ages.last_updated(), "5m ago");
     }
 
+    fn temp_cache(name: &str) -> CacheManager {
+        let dir = std::env::temp_dir().join(format!("trailcache_test_{}_{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        CacheManager::new_without_encryption(dir).unwrap()
+    }
+
+    fn guest(user_id: i64) -> EventGuest {
+        serde_json::from_value(serde_json::json!({
+            "userId": user_id,
+            "firstName": "Pat",
+            "lastName": "Guest",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_validated_responses_round_trip() {
+        let cache = temp_cache("validated_round_trip");
+        let url = "https://api.scouting.org/organizations/v2/units/abc/youths";
+        let response = Validated {
+            etag: Some("\"v1\"".to_string()),
+            last_modified: None,
+            body: serde_json::json!([{ "userId": 7 }]),
+        };
+        cache.save_validated(url, &response);
+
+        assert_eq!(cache.load_validated(url), Some(response));
+        assert_eq!(cache.load_validated(&format!("{}?page=2", url)), None);
+
+        let _ = std::fs::remove_dir_all(cache.cache_dir());
+    }
+
+    #[test]
+    fn test_event_guests_round_trip() {
+        let cache = temp_cache("guests_round_trip");
+        cache.save_event_guests(42, &[guest(7)]).unwrap();
+
+        let loaded = cache.load_event_guests(42).unwrap().unwrap();
+        assert_eq!(loaded.data.len(), 1);
+        assert_eq!(loaded.data[0].user_id, 7);
+        assert!(cache.load_event_guests(43).unwrap().is_none());
+
+        let _ = std::fs::remove_dir_all(cache.cache_dir());
+    }
+
+    #[test]
+    fn test_event_guests_expire() {
+        let cache = temp_cache("guests_expire");
+        let mut cached = CachedData::new(vec![guest(7)]);
+        cached.cached_at = Utc::now() - Duration::minutes(EVENT_GUESTS_TTL_MINUTES + 1);
+        let ciphertext = encrypt_data(&serde_json::to_vec(&cached).unwrap(), &cache.encryption_key).unwrap();
+        let path = cache.cache_path("event_guests_42");
+        std::fs::write(&path, ciphertext).unwrap();
+
+        assert!(cache.load_event_guests(42).unwrap().is_none());
+        assert!(!path.exists());
+
+        let _ = std::fs::remove_dir_all(cache.cache_dir());
+    }
+
+    #[test]
+    fn test_invalidate_rank_progress() {
+        let cache = temp_cache("invalidate_rank_progress");
+        let requirement: crate::models::RankRequirement =
+            serde_json::from_value(serde_json::json!({"id": 11, "name": "Tie a square knot"})).unwrap();
+        cache.save_youth_ranks(1, &[]).unwrap();
+        cache.save_rank_requirements(1, 3, std::slice::from_ref(&requirement)).unwrap();
+        cache.save_rank_requirements(1, 4, &[requirement]).unwrap();
+
+        cache.invalidate_rank_progress(1, 3).unwrap();
+        assert!(cache.load_youth_ranks(1).unwrap().is_none());
+        assert!(cache.load_rank_requirements(1, 3).unwrap().is_none());
+        assert!(cache.load_rank_requirements(1, 4).unwrap().is_some());
+        // Nothing left to remove is fine
+        cache.invalidate_rank_progress(1, 3).unwrap();
+
+        let _ = std::fs::remove_dir_all(cache.cache_dir());
+    }
+
     #[test]
     fn test_cache_ages_last_updated_empty() {
         let ages = CacheAges::default();
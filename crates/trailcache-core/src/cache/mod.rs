@@ -9,13 +9,31 @@
 //! - Events
 //! - Advancement dashboard and progress data
 //! - Patrols
+//!
+//! Locally authored data can be backed up and restored with `backup`.
+//! The cache key can also mix in a secret kept in the OS keychain, under
+//! `key_source`.
+//! Data about scouts who have left the unit is purged under `retention`.
+//! Views warn about, and can hide personal details in, very old data under
+//! `freshness`.
 
+pub mod backup;
 pub mod fetch;
+pub mod freshness;
+pub mod key_source;
 pub mod manager;
 pub mod offline;
 pub mod refresh;
+pub mod retention;
 
+pub use backup::{BackupArchive, BACKUP_VERSION, LOCAL_SECTIONS, MEDICAL_SECTIONS};
 pub use fetch::fetch_with_cache;
+pub use freshness::{CacheTimes, Freshness, FreshnessPolicy, FreshnessRule, DEFAULT_WARN_AFTER_DAYS};
+pub use key_source::apply_key_source;
 pub use manager::{CacheAges, CacheManager};
-pub use offline::{cache_all_for_offline, CacheProgress};
+pub use offline::{
+    audit_offline_cache, cache_all_for_offline, cache_missing_for_offline, cache_scoped_for_offline,
+    estimate_offline_requests, AuditItem, CacheProgress, OfflineAudit, OfflineScope, PrefetchManifest,
+};
 pub use refresh::{refresh_base_data, RefreshResult as BaseRefreshResult};
+pub use retention::{Departure, Departures, PurgeRecord, RetentionAudit, RetentionPolicy, SUGGESTED_DEPARTED_RETENTION_MONTHS};
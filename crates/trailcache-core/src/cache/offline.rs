@@ -2,12 +2,24 @@
 //!
 //! Pre-fetches all data needed for full offline operation:
 //! base roster/event data, per-youth ranks/badges/requirements,
-//! and per-event RSVP details.
+//! and per-event RSVP details and guest lists. Also audits what is already cached so
+//! gaps can be shown and filled before going offline.
+//!
+//! The per-scout requirements prefetch is the slow part, so its progress is
+//! saved in a `PrefetchManifest` after each batch of scouts. An interrupted run
+//! picks up where it stopped, and requirement sets cached recently are skipped.
+
+use std::collections::{HashMap, HashSet};
 
-use tracing::warn;
+use chrono::{DateTime, Duration, Utc};
+use futures::future::join_all;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
 
-use crate::api::ApiClient;
+use crate::api::ScoutingApi;
+use crate::cache::manager::CachedData;
 use crate::cache::CacheManager;
+use crate::models::{Event, MeritBadgeProgress, RankProgress, Youth};
 
 /// Progress update sent during offline caching.
 #[derive(Debug, Clone)]
@@ -17,6 +29,110 @@ pub struct CacheProgress {
     pub description: String,
 }
 
+/// Number of base data sources fetched by `refresh_base_data`.
+const BASE_SOURCE_COUNT: u32 = 10;
+
+/// Assumed ranks per scout when estimating requests for uncached scouts.
+const ESTIMATED_RANKS_PER_SCOUT: u32 = 7;
+
+/// Assumed merit badges per scout when estimating requests for uncached scouts.
+const ESTIMATED_BADGES_PER_SCOUT: u32 = 8;
+
+/// Requirement sets cached within this many hours aren't fetched again, and an
+/// interrupted prefetch older than this starts over.
+const PREFETCH_FRESH_HOURS: i64 = 24;
+
+/// Which youth and requirements an offline cache run should cover.
+///
+/// The default scope is everything. Narrowing it keeps a pre-campout
+/// cache run short when only some scouts or active work matter.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OfflineScope {
+    /// Only cache advancement for youth in these patrols (None = all youth)
+    pub patrols: Option<Vec<String>>,
+    /// Skip requirements for ranks and badges that are already completed
+    pub active_only: bool,
+}
+
+impl OfflineScope {
+    pub fn includes_youth(&self, youth: &Youth) -> bool {
+        match &self.patrols {
+            Some(patrols) => youth
+                .patrol_name
+                .as_ref()
+                .is_some_and(|name| patrols.iter().any(|p| p == name)),
+            None => true,
+        }
+    }
+
+    pub fn includes_rank(&self, rank: &RankProgress) -> bool {
+        !self.active_only || !(rank.is_completed() || rank.is_awarded())
+    }
+
+    pub fn includes_badge(&self, badge: &MeritBadgeProgress) -> bool {
+        !self.active_only || !badge.is_completed()
+    }
+
+    /// Short description for display, e.g. "Eagles, active only".
+    pub fn description(&self) -> String {
+        let who = match &self.patrols {
+            Some(patrols) if patrols.is_empty() => "No patrols".to_string(),
+            Some(patrols) => patrols.join(", "),
+            None => "All scouts".to_string(),
+        };
+        if self.active_only {
+            format!("{}, active only", who)
+        } else {
+            who
+        }
+    }
+
+    fn youth_ids(&self, youth: &[Youth]) -> Vec<i64> {
+        youth
+            .iter()
+            .filter(|y| self.includes_youth(y))
+            .filter_map(|y| y.user_id)
+            .collect()
+    }
+}
+
+/// Progress of the per-scout advancement prefetch, saved so an interrupted
+/// run can resume.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PrefetchManifest {
+    pub started_at: DateTime<Utc>,
+    /// `OfflineScope::description()` of the run; a different scope starts over
+    pub scope: String,
+    /// Scouts whose lists and in-scope requirements are all cached
+    pub done_youth: HashSet<i64>,
+    /// (user_id, rank_id) requirement sets fetched so far
+    pub done_ranks: HashSet<(i64, i64)>,
+    /// (user_id, badge_id) requirement sets fetched so far
+    pub done_badges: HashSet<(i64, i64)>,
+}
+
+impl PrefetchManifest {
+    pub fn new(scope: &OfflineScope, now: DateTime<Utc>) -> Self {
+        Self {
+            started_at: now,
+            scope: scope.description(),
+            done_youth: HashSet::new(),
+            done_ranks: HashSet::new(),
+            done_badges: HashSet::new(),
+        }
+    }
+
+    /// Whether this manifest can be resumed by a run over `scope` at `now`.
+    pub fn is_resumable(&self, scope: &OfflineScope, now: DateTime<Utc>) -> bool {
+        self.scope == scope.description() && now - self.started_at < Duration::hours(PREFETCH_FRESH_HOURS)
+    }
+}
+
+/// Whether cached data is recent enough for the prefetch to skip it.
+fn is_fresh<T>(cached: Option<CachedData<T>>, now: DateTime<Utc>) -> bool {
+    cached.is_some_and(|c| now - c.cached_at < Duration::hours(PREFETCH_FRESH_HOURS))
+}
+
 /// Cache all data for offline use.
 ///
 /// Fetches base data (roster, events, advancement, etc.), then per-youth
@@ -25,11 +141,25 @@ pub struct CacheProgress {
 ///
 /// The `cache` parameter must already have its encryption key set.
 pub async fn cache_all_for_offline(
-    api: &ApiClient,
+    api: &impl ScoutingApi,
     cache: &CacheManager,
     org_guid: &str,
     user_id: i64,
     on_progress: impl Fn(CacheProgress),
+) -> anyhow::Result<String> {
+    cache_scoped_for_offline(api, cache, org_guid, user_id, &OfflineScope::default(), on_progress).await
+}
+
+/// Cache data for offline use, limiting per-youth advancement to `scope`.
+///
+/// Base data and event RSVP details are always fetched in full.
+pub async fn cache_scoped_for_offline(
+    api: &impl ScoutingApi,
+    cache: &CacheManager,
+    org_guid: &str,
+    user_id: i64,
+    scope: &OfflineScope,
+    on_progress: impl Fn(CacheProgress),
 ) -> anyhow::Result<String> {
     // Phase 1: Base data (10 sources, fetched in parallel)
     let base = super::refresh::refresh_base_data(api, cache, org_guid, user_id, &on_progress).await;
@@ -37,8 +167,8 @@ pub async fn cache_all_for_offline(
     let _errors = base.errors;
 
     let youth_ids: Vec<i64> = base.youth
-        .as_ref()
-        .map(|list| list.iter().filter_map(|y| y.user_id).collect())
+        .as_deref()
+        .map(|list| scope.youth_ids(list))
         .unwrap_or_default();
 
     let events = base.events;
@@ -50,206 +180,424 @@ pub async fn cache_all_for_offline(
         .unwrap_or_default();
 
     if !event_ids.is_empty() {
-        use futures::future::join_all;
-        use std::collections::HashMap;
+        cache_event_details(api, cache, events.unwrap_or_default(), &event_ids, &on_progress).await;
+    }
+
+    // Phase 3: Per-youth ranks, badges, and requirements (concurrent)
+    cache_youth_advancement(api, cache, &youth_ids, scope, &on_progress).await;
+
+    // Phase 4: Verify cached data is complete
+    Ok(finish_with_audit(
+        cache,
+        scope,
+        &on_progress,
+        format!("Cached all {} data sources + requirements + RSVP — verified complete", successes),
+    ))
+}
+
+/// Fetch only the pieces an audit found missing, so a nearly-complete offline
+/// bundle can be topped up without re-downloading everything.
+///
+/// Base data is refreshed when any source is missing (it is only ~10 requests);
+/// event details and per-youth advancement are fetched only for the events and
+/// youth listed in the audit.
+pub async fn cache_missing_for_offline(
+    api: &impl ScoutingApi,
+    cache: &CacheManager,
+    org_guid: &str,
+    user_id: i64,
+    scope: &OfflineScope,
+    audit: &OfflineAudit,
+    on_progress: impl Fn(CacheProgress),
+) -> anyhow::Result<String> {
+    // Refreshing base data rewrites the events file, so re-audit afterwards
+    let refreshed;
+    let audit = if audit.missing_base().is_empty() {
+        audit
+    } else {
+        super::refresh::refresh_base_data(api, cache, org_guid, user_id, &on_progress).await;
+        refreshed = audit_offline_cache(cache, scope);
+        &refreshed
+    };
+
+    if !audit.missing_event_details.is_empty() {
+        let events = cache.load_events()?.map(|c| c.data).unwrap_or_default();
+        cache_event_details(api, cache, events, &audit.missing_event_details, &on_progress).await;
+    }
+
+    cache_youth_advancement(api, cache, &audit.missing_youth, scope, &on_progress).await;
+
+    Ok(finish_with_audit(
+        cache,
+        scope,
+        &on_progress,
+        "Filled missing offline data — verified complete".to_string(),
+    ))
+}
+
+/// Run the final verification pass and build the summary message.
+fn finish_with_audit(
+    cache: &CacheManager,
+    scope: &OfflineScope,
+    on_progress: &impl Fn(CacheProgress),
+    complete_msg: String,
+) -> String {
+    on_progress(CacheProgress {
+        current: 1,
+        total: 1,
+        description: "Verifying cache...".into(),
+    });
+
+    let gaps = audit_offline_cache(cache, scope).gaps();
+
+    on_progress(CacheProgress {
+        current: 1,
+        total: 1,
+        description: if gaps.is_empty() {
+            "Caching complete".into()
+        } else {
+            format!("Caching complete ({} gaps)", gaps.len())
+        },
+    });
+
+    if gaps.is_empty() {
+        complete_msg
+    } else {
+        format!("Caching complete with gaps: {}", gaps.join("; "))
+    }
+}
+
+/// Fetch RSVP details and guest lists for the given events.
+/// Invitees are merged into `events` before saving; guests are saved per event.
+async fn cache_event_details(
+    api: &impl ScoutingApi,
+    cache: &CacheManager,
+    mut events: Vec<Event>,
+    event_ids: &[i64],
+    on_progress: &impl Fn(CacheProgress),
+) {
+    on_progress(CacheProgress {
+        current: 0,
+        total: event_ids.len() as u32,
+        description: "Caching event RSVP data...".into(),
+    });
+
+    // Fetch all event details concurrently in chunks
+    const MAX_CONCURRENT_EVENTS: usize = 10;
+    let mut rsvp_map: HashMap<i64, Vec<crate::models::event::InvitedUser>> = HashMap::new();
+
+    let mut completed = 0u32;
+    for chunk in event_ids.chunks(MAX_CONCURRENT_EVENTS) {
+        let futures: Vec<_> = chunk
+            .iter()
+            .map(|&eid| {
+                let api = api.clone();
+                async move {
+                    let (detail, guests) =
+                        futures::future::join(api.fetch_event_detail(eid), api.fetch_event_guests(eid)).await;
+                    (eid, detail.ok(), guests.ok())
+                }
+            })
+            .collect();
+
+        let results = join_all(futures).await;
+        for (eid, detail, guests) in results {
+            if let Some(detail) = detail {
+                rsvp_map.insert(eid, detail.invited_users);
+            }
+            if let Some(guests) = guests {
+                if let Err(e) = cache.save_event_guests(eid, &guests) {
+                    warn!("Failed to save guests for event {eid}: {e}");
+                }
+            }
+            completed += 1;
+        }
 
         on_progress(CacheProgress {
-            current: 0,
+            current: completed,
             total: event_ids.len() as u32,
             description: "Caching event RSVP data...".into(),
         });
+    }
 
-        // Fetch all event details concurrently in chunks
-        const MAX_CONCURRENT_EVENTS: usize = 10;
-        let mut rsvp_map: HashMap<i64, Vec<crate::models::event::InvitedUser>> = HashMap::new();
+    // Merge RSVP data into cached events and save
+    for ev in &mut events {
+        if let Some(users) = rsvp_map.remove(&ev.id) {
+            ev.invited_users = users;
+        }
+    }
+    if let Err(e) = cache.save_events(&events) {
+        warn!("Failed to save events to cache: {e}");
+    }
+}
 
-        let mut completed = 0u32;
-        for chunk in event_ids.chunks(MAX_CONCURRENT_EVENTS) {
-            let futures: Vec<_> = chunk
-                .iter()
-                .map(|&eid| {
-                    let api = api.clone();
-                    async move {
-                        let detail = api.fetch_event_detail(eid).await.ok();
-                        (eid, detail)
+/// Fetch and save ranks, badges, and their in-scope requirements for each youth.
+///
+/// Resumes from a saved `PrefetchManifest` for the same scope, skipping scouts
+/// already finished and requirement sets cached within `PREFETCH_FRESH_HOURS`.
+/// The manifest is saved after each chunk and removed once every scout is done.
+///
+/// Optimizations vs naive serial approach:
+/// - Process youth in concurrent chunks (5 at a time)
+/// - Fetch rank + badge lists concurrently per youth
+/// - Fetch all requirements concurrently per youth
+/// - Use fetch_badge_requirements_only (1 API call instead of 2)
+async fn cache_youth_advancement(
+    api: &impl ScoutingApi,
+    cache: &CacheManager,
+    youth_ids: &[i64],
+    scope: &OfflineScope,
+    on_progress: &impl Fn(CacheProgress),
+) {
+    if youth_ids.is_empty() {
+        return;
+    }
+
+    let youth_total = youth_ids.len() as u32;
+    const MAX_CONCURRENT_YOUTH: usize = 5;
+
+    let now = Utc::now();
+    let mut manifest = match cache.load_prefetch_manifest() {
+        Ok(Some(cached)) if cached.data.is_resumable(scope, now) => cached.data,
+        _ => PrefetchManifest::new(scope, now),
+    };
+    let pending: Vec<i64> = youth_ids.iter().copied().filter(|id| !manifest.done_youth.contains(id)).collect();
+
+    let mut completed = youth_total - pending.len() as u32;
+    if completed > 0 {
+        debug!(completed, total = youth_total, "Resuming advancement prefetch");
+        on_progress(CacheProgress {
+            current: completed,
+            total: youth_total,
+            description: format!("Resuming: {} of {} scouts already cached...", completed, youth_total),
+        });
+    }
+
+    for chunk in pending.chunks(MAX_CONCURRENT_YOUTH) {
+        let manifest_ref = &manifest;
+        let futures: Vec<_> = chunk
+            .iter()
+            .map(|&uid| {
+                let api = api.clone();
+                async move {
+                    // Fetch ranks and badges concurrently
+                    let (ranks_result, badges_result) = futures::future::join(
+                        api.fetch_youth_ranks(uid),
+                        api.fetch_youth_merit_badges(uid),
+                    )
+                    .await;
+
+                    let lists_ok = ranks_result.is_ok() && badges_result.is_ok();
+                    let ranks = ranks_result.unwrap_or_default();
+                    let badges = badges_result.unwrap_or_default();
+
+                    // Fetch requirements not already fetched this run or cached recently
+                    let rank_req_futures: Vec<_> = ranks
+                        .iter()
+                        .filter(|r| scope.includes_rank(r))
+                        .filter(|r| {
+                            !manifest_ref.done_ranks.contains(&(uid, r.rank_id))
+                                && !is_fresh(cache.load_rank_requirements(uid, r.rank_id).ok().flatten(), now)
+                        })
+                        .map(|r| {
+                            let api = api.clone();
+                            let rank_id = r.rank_id;
+                            async move {
+                                let reqs = api.fetch_rank_requirements(uid, rank_id).await.ok();
+                                (rank_id, reqs)
+                            }
+                        })
+                        .collect();
+
+                    let badge_req_futures: Vec<_> = badges
+                        .iter()
+                        .filter(|b| scope.includes_badge(b))
+                        .filter(|b| {
+                            !manifest_ref.done_badges.contains(&(uid, b.id))
+                                && !is_fresh(cache.load_badge_requirements(uid, b.id).ok().flatten(), now)
+                        })
+                        .map(|b| {
+                            let api = api.clone();
+                            let badge_id = b.id;
+                            async move {
+                                let reqs = api.fetch_badge_requirements_only(uid, badge_id).await.ok();
+                                (badge_id, reqs)
+                            }
+                        })
+                        .collect();
+
+                    let (rank_reqs, badge_reqs) = futures::future::join(
+                        join_all(rank_req_futures),
+                        join_all(badge_req_futures),
+                    )
+                    .await;
+
+                    (uid, lists_ok, ranks, badges, rank_reqs, badge_reqs)
+                }
+            })
+            .collect();
+
+        let results = join_all(futures).await;
+
+        // Save all results to cache
+        for (uid, lists_ok, ranks, badges, rank_reqs, badge_reqs) in results {
+            let mut all_saved = lists_ok;
+            if let Err(e) = cache.save_youth_ranks(uid, &ranks) {
+                warn!("Failed to save ranks for user {uid}: {e}");
+            }
+            if let Err(e) = cache.save_youth_merit_badges(uid, &badges) {
+                warn!("Failed to save badges for user {uid}: {e}");
+            }
+
+            for (rank_id, reqs) in rank_reqs {
+                match reqs.map(|reqs| cache.save_rank_requirements(uid, rank_id, &reqs)) {
+                    Some(Ok(())) => {
+                        manifest.done_ranks.insert((uid, rank_id));
+                    }
+                    Some(Err(e)) => {
+                        warn!("Failed to save rank requirements for user {uid}, rank {rank_id}: {e}");
+                        all_saved = false;
                     }
-                })
-                .collect();
+                    None => all_saved = false,
+                }
+            }
 
-            let results = join_all(futures).await;
-            for (eid, detail) in results {
-                if let Some(detail) = detail {
-                    rsvp_map.insert(eid, detail.invited_users);
+            for (badge_id, reqs) in badge_reqs {
+                match reqs.map(|(reqs, version)| cache.save_badge_requirements(uid, badge_id, &reqs, &version)) {
+                    Some(Ok(())) => {
+                        manifest.done_badges.insert((uid, badge_id));
+                    }
+                    Some(Err(e)) => {
+                        warn!("Failed to save badge requirements for user {uid}, badge {badge_id}: {e}");
+                        all_saved = false;
+                    }
+                    None => all_saved = false,
                 }
-                completed += 1;
             }
 
+            if all_saved {
+                manifest.done_youth.insert(uid);
+            }
+
+            completed += 1;
             on_progress(CacheProgress {
                 current: completed,
-                total: event_ids.len() as u32,
-                description: "Caching event RSVP data...".into(),
+                total: youth_total,
+                description: format!("Caching scout advancement ({}/{})...", completed, youth_total),
             });
         }
 
-        // Merge RSVP data into cached events and save
-        let mut cached_events = events.unwrap_or_default();
-        for ev in &mut cached_events {
-            if let Some(users) = rsvp_map.remove(&ev.id) {
-                ev.invited_users = users;
-            }
-        }
-        if let Err(e) = cache.save_events(&cached_events) {
-            warn!("Failed to save events to cache: {e}");
+        if let Err(e) = cache.save_prefetch_manifest(&manifest) {
+            warn!("Failed to save prefetch progress: {e}");
         }
     }
 
-    // Phase 3: Per-youth ranks, badges, and requirements (concurrent)
-    //
-    // Optimizations vs naive serial approach:
-    // - Process youth in concurrent chunks (5 at a time)
-    // - Fetch rank + badge lists concurrently per youth
-    // - Fetch all requirements concurrently per youth
-    // - Use fetch_badge_requirements_only (1 API call instead of 2)
-    if !youth_ids.is_empty() {
-        use futures::future::join_all;
-
-        let youth_total = youth_ids.len() as u32;
-        const MAX_CONCURRENT_YOUTH: usize = 5;
-
-        let mut completed = 0u32;
-        for chunk in youth_ids.chunks(MAX_CONCURRENT_YOUTH) {
-            let futures: Vec<_> = chunk
-                .iter()
-                .map(|&uid| {
-                    let api = api.clone();
-                    async move {
-                        // Fetch ranks and badges concurrently
-                        let (ranks_result, badges_result) = futures::future::join(
-                            api.fetch_youth_ranks(uid),
-                            api.fetch_youth_merit_badges(uid),
-                        )
-                        .await;
-
-                        let ranks = ranks_result.unwrap_or_default();
-                        let badges = badges_result.unwrap_or_default();
-
-                        // Fetch all requirements concurrently
-                        let rank_req_futures: Vec<_> = ranks
-                            .iter()
-                            .map(|r| {
-                                let api = api.clone();
-                                let rank_id = r.rank_id;
-                                async move {
-                                    let reqs = api.fetch_rank_requirements(uid, rank_id).await.ok();
-                                    (rank_id, reqs)
-                                }
-                            })
-                            .collect();
-
-                        let badge_req_futures: Vec<_> = badges
-                            .iter()
-                            .map(|b| {
-                                let api = api.clone();
-                                let badge_id = b.id;
-                                async move {
-                                    let reqs = api.fetch_badge_requirements_only(uid, badge_id).await.ok();
-                                    (badge_id, reqs)
-                                }
-                            })
-                            .collect();
-
-                        let (rank_reqs, badge_reqs) = futures::future::join(
-                            join_all(rank_req_futures),
-                            join_all(badge_req_futures),
-                        )
-                        .await;
-
-                        (uid, ranks, badges, rank_reqs, badge_reqs)
-                    }
-                })
-                .collect();
+    // Keep the manifest when some scouts failed so the next run retries just those
+    if youth_ids.iter().all(|id| manifest.done_youth.contains(id)) {
+        if let Err(e) = cache.clear_prefetch_manifest() {
+            warn!("Failed to remove prefetch manifest: {e}");
+        }
+    }
+}
 
-            let results = join_all(futures).await;
+// ============================================================================
+// Completeness Audit
+// ============================================================================
 
-            // Save all results to cache
-            for (uid, ranks, badges, rank_reqs, badge_reqs) in results {
-                if let Err(e) = cache.save_youth_ranks(uid, &ranks) {
-                    warn!("Failed to save ranks for user {uid}: {e}");
-                }
-                if let Err(e) = cache.save_youth_merit_badges(uid, &badges) {
-                    warn!("Failed to save badges for user {uid}: {e}");
-                }
+/// One line of the offline completeness checklist.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditItem {
+    pub label: String,
+    pub cached: u32,
+    pub expected: u32,
+}
 
-                for (rank_id, reqs) in rank_reqs {
-                    if let Some(reqs) = reqs {
-                        if let Err(e) = cache.save_rank_requirements(uid, rank_id, &reqs) {
-                            warn!("Failed to save rank requirements for user {uid}, rank {rank_id}: {e}");
-                        }
-                    }
-                }
+impl AuditItem {
+    pub fn new(label: &str, cached: u32, expected: u32) -> Self {
+        Self {
+            label: label.to_string(),
+            cached,
+            expected,
+        }
+    }
 
-                for (badge_id, reqs) in badge_reqs {
-                    if let Some((reqs, version)) = reqs {
-                        if let Err(e) = cache.save_badge_requirements(uid, badge_id, &reqs, &version) {
-                            warn!("Failed to save badge requirements for user {uid}, badge {badge_id}: {e}");
-                        }
-                    }
-                }
+    pub fn is_complete(&self) -> bool {
+        self.cached >= self.expected
+    }
 
-                completed += 1;
-                on_progress(CacheProgress {
-                    current: completed,
-                    total: youth_total,
-                    description: format!("Caching scout advancement ({}/{})...", completed, youth_total),
-                });
-            }
-        }
+    pub fn missing(&self) -> u32 {
+        self.expected.saturating_sub(self.cached)
     }
+}
 
-    // Phase 4: Verify cached data is complete
-    on_progress(CacheProgress {
-        current: 1,
-        total: 1,
-        description: "Verifying cache...".into(),
-    });
+/// What the offline bundle currently holds, checked against what it should hold.
+///
+/// Built entirely from the local cache (no network), so it can be shown
+/// before the user commits to going offline.
+#[derive(Debug, Clone, Default)]
+pub struct OfflineAudit {
+    /// One item per base data source (expected is always 1)
+    pub base: Vec<AuditItem>,
+    /// Per-youth and per-event coverage
+    pub details: Vec<AuditItem>,
+    /// Youth with a missing rank/badge list or any missing requirement set
+    pub missing_youth: Vec<i64>,
+    /// RSVP-enabled events whose invitee list has not been cached
+    pub missing_event_details: Vec<i64>,
+}
 
-    let gaps = verify_cache(cache, &youth_ids);
+impl OfflineAudit {
+    pub fn is_complete(&self) -> bool {
+        self.base.iter().chain(&self.details).all(AuditItem::is_complete)
+    }
 
-    on_progress(CacheProgress {
-        current: 1,
-        total: 1,
-        description: if gaps.is_empty() {
-            "Caching complete".into()
-        } else {
-            format!("Caching complete ({} gaps)", gaps.len())
-        },
-    });
+    /// Labels of base data sources that are missing or unreadable.
+    pub fn missing_base(&self) -> Vec<&str> {
+        self.base
+            .iter()
+            .filter(|item| !item.is_complete())
+            .map(|item| item.label.as_str())
+            .collect()
+    }
 
-    if gaps.is_empty() {
-        Ok(format!("Cached all {} data sources + requirements + RSVP — verified complete", successes))
-    } else {
-        let summary = gaps.join("; ");
-        Ok(format!(
-            "Caching complete with gaps: {}",
-            summary
-        ))
+    /// Checklist for display: base sources collapsed into one line, then details.
+    pub fn checklist(&self) -> Vec<AuditItem> {
+        let cached = self.base.iter().filter(|item| item.is_complete()).count() as u32;
+        let mut items = vec![AuditItem::new("Base data sources", cached, self.base.len() as u32)];
+        items.extend(self.details.iter().cloned());
+        items
+    }
+
+    /// Human-readable description of each gap.
+    pub fn gaps(&self) -> Vec<String> {
+        let mut gaps: Vec<String> = self
+            .missing_base()
+            .into_iter()
+            .map(|label| format!("{}: missing", label))
+            .collect();
+        gaps.extend(
+            self.details
+                .iter()
+                .filter(|item| !item.is_complete())
+                .map(|item| format!("{} missing {}", item.missing(), item.label.to_lowercase())),
+        );
+        gaps
     }
 }
 
-/// Verify that all expected offline data is present in the cache.
-/// Returns a list of human-readable descriptions of missing data.
-fn verify_cache(cache: &CacheManager, youth_ids: &[i64]) -> Vec<String> {
-    let mut gaps = Vec::new();
+/// Check which offline data within `scope` is present in the cache.
+///
+/// Event details are judged by whether an RSVP-enabled event has any cached
+/// invitees; the events list alone does not include them.
+pub fn audit_offline_cache(cache: &CacheManager, scope: &OfflineScope) -> OfflineAudit {
+    let mut audit = OfflineAudit::default();
 
     // Base data checks — verify each source was cached successfully
     macro_rules! check {
-        ($label:expr, $load:expr) => {
-            match $load {
-                Ok(Some(_)) => {}
-                Ok(None) => gaps.push(format!("{}: missing", $label)),
-                Err(_) => gaps.push(format!("{}: unreadable", $label)),
-            }
-        };
+        ($label:expr, $load:expr) => {{
+            let present = matches!($load, Ok(Some(_)));
+            audit.base.push(AuditItem::new($label, present as u32, 1));
+        }};
     }
 
     check!("Scouts", cache.load_youth());
@@ -264,57 +612,199 @@ fn verify_cache(cache: &CacheManager, youth_ids: &[i64]) -> Vec<String> {
     check!("Advancement", cache.load_advancement_dashboard());
 
     // Per-youth checks
-    let mut missing_ranks = 0u32;
-    let mut missing_badges = 0u32;
-    let mut missing_rank_reqs = 0u32;
-    let mut missing_badge_reqs = 0u32;
-
-    for &uid in youth_ids {
-        // Check ranks
-        let ranks = match cache.load_youth_ranks(uid) {
-            Ok(Some(cached)) => cached.data,
-            _ => {
-                missing_ranks += 1;
-                continue;
-            }
-        };
+    let youth_ids: Vec<i64> = cache
+        .load_youth()
+        .ok()
+        .flatten()
+        .map(|c| scope.youth_ids(&c.data))
+        .unwrap_or_default();
 
-        // Check rank requirements
-        for rank in &ranks {
-            if cache.load_rank_requirements(uid, rank.rank_id).ok().flatten().is_none() {
-                missing_rank_reqs += 1;
+    let mut rank_lists = 0u32;
+    let mut badge_lists = 0u32;
+    let mut rank_reqs = (0u32, 0u32);
+    let mut badge_reqs = (0u32, 0u32);
+
+    for &uid in &youth_ids {
+        let mut complete = true;
+
+        match cache.load_youth_ranks(uid) {
+            Ok(Some(cached)) => {
+                rank_lists += 1;
+                for rank in cached.data.iter().filter(|r| scope.includes_rank(r)) {
+                    rank_reqs.1 += 1;
+                    if cache.load_rank_requirements(uid, rank.rank_id).ok().flatten().is_some() {
+                        rank_reqs.0 += 1;
+                    } else {
+                        complete = false;
+                    }
+                }
             }
+            _ => complete = false,
         }
 
-        // Check badges
-        let badges = match cache.load_youth_merit_badges(uid) {
-            Ok(Some(cached)) => cached.data,
-            _ => {
-                missing_badges += 1;
-                continue;
+        match cache.load_youth_merit_badges(uid) {
+            Ok(Some(cached)) => {
+                badge_lists += 1;
+                for badge in cached.data.iter().filter(|b| scope.includes_badge(b)) {
+                    badge_reqs.1 += 1;
+                    if cache.load_badge_requirements(uid, badge.id).ok().flatten().is_some() {
+                        badge_reqs.0 += 1;
+                    } else {
+                        complete = false;
+                    }
+                }
             }
-        };
+            _ => complete = false,
+        }
 
-        // Check badge requirements
-        for badge in &badges {
-            if cache.load_badge_requirements(uid, badge.id).ok().flatten().is_none() {
-                missing_badge_reqs += 1;
-            }
+        if !complete {
+            audit.missing_youth.push(uid);
         }
     }
 
-    if missing_ranks > 0 {
-        gaps.push(format!("{} scouts missing rank data", missing_ranks));
+    let youth_total = youth_ids.len() as u32;
+    audit.details.push(AuditItem::new("Scout rank lists", rank_lists, youth_total));
+    audit.details.push(AuditItem::new("Scout badge lists", badge_lists, youth_total));
+    audit.details.push(AuditItem::new("Rank requirements", rank_reqs.0, rank_reqs.1));
+    audit.details.push(AuditItem::new("Badge requirements", badge_reqs.0, badge_reqs.1));
+
+    // Per-event checks
+    let events = cache.load_events().ok().flatten().map(|c| c.data).unwrap_or_default();
+    let rsvp_events: Vec<&Event> = events.iter().filter(|e| e.rsvp).collect();
+    audit.missing_event_details = rsvp_events
+        .iter()
+        .filter(|e| e.invited_users.is_empty())
+        .map(|e| e.id)
+        .collect();
+    audit.details.push(AuditItem::new(
+        "Event RSVP details",
+        (rsvp_events.len() - audit.missing_event_details.len()) as u32,
+        rsvp_events.len() as u32,
+    ));
+
+    audit
+}
+
+/// Estimate how many API requests a full cache run with `scope` will make.
+///
+/// Uses whatever rank and badge lists are already cached; scouts without
+/// cached lists are assumed to have a typical number of ranks and badges.
+/// This is a rough guide for the confirmation screen, not a hard number.
+pub fn estimate_offline_requests(cache: &CacheManager, scope: &OfflineScope) -> u32 {
+    let events = cache.load_events().ok().flatten().map(|c| c.data.len() as u32).unwrap_or(0);
+    let youth_ids: Vec<i64> = cache
+        .load_youth()
+        .ok()
+        .flatten()
+        .map(|c| scope.youth_ids(&c.data))
+        .unwrap_or_default();
+
+    let per_youth: u32 = youth_ids
+        .iter()
+        .map(|&uid| {
+            let ranks = match cache.load_youth_ranks(uid) {
+                Ok(Some(cached)) => cached.data.iter().filter(|r| scope.includes_rank(r)).count() as u32,
+                _ => ESTIMATED_RANKS_PER_SCOUT,
+            };
+            let badges = match cache.load_youth_merit_badges(uid) {
+                Ok(Some(cached)) => cached.data.iter().filter(|b| scope.includes_badge(b)).count() as u32,
+                _ => ESTIMATED_BADGES_PER_SCOUT,
+            };
+            // Rank list + badge list + one request per requirement set
+            2 + ranks + badges
+        })
+        .sum();
+
+    // Each event needs a detail request and a guest list request
+    BASE_SOURCE_COUNT + events * 2 + per_youth
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_audit_item_missing() {
+        let item = AuditItem::new("Rank requirements", 7, 10);
+        assert!(!item.is_complete());
+        assert_eq!(item.missing(), 3);
+        assert!(AuditItem::new("Event RSVP details", 0, 0).is_complete());
     }
-    if missing_badges > 0 {
-        gaps.push(format!("{} scouts missing badge data", missing_badges));
+
+    #[test]
+    fn test_offline_audit_gaps() {
+        let audit = OfflineAudit {
+            base: vec![AuditItem::new("Scouts", 1, 1), AuditItem::new("Key 3", 0, 1)],
+            details: vec![
+                AuditItem::new("Scout rank lists", 12, 12),
+                AuditItem::new("Badge requirements", 40, 42),
+            ],
+            missing_youth: vec![1001],
+            missing_event_details: Vec::new(),
+        };
+        assert!(!audit.is_complete());
+        assert_eq!(audit.missing_base(), vec!["Key 3"]);
+        assert_eq!(audit.gaps(), vec!["Key 3: missing", "2 missing badge requirements"]);
+
+        let checklist = audit.checklist();
+        assert_eq!(checklist.len(), 3);
+        assert_eq!((checklist[0].cached, checklist[0].expected), (1, 2));
+    }
+
+    #[test]
+    fn test_offline_audit_complete() {
+        let audit = OfflineAudit {
+            base: vec![AuditItem::new("Scouts", 1, 1)],
+            details: vec![AuditItem::new("Scout rank lists", 12, 12)],
+            ..Default::default()
+        };
+        assert!(audit.is_complete());
+        assert!(audit.gaps().is_empty());
+    }
+
+    fn youth_in(patrol: Option<&str>) -> Youth {
+        serde_json::from_value(serde_json::json!({
+            "firstName": "Sam",
+            "lastName": "Scout",
+            "userId": 1,
+            "subUnitName": patrol,
+        }))
+        .unwrap()
     }
-    if missing_rank_reqs > 0 {
-        gaps.push(format!("{} rank requirements missing", missing_rank_reqs));
+
+    #[test]
+    fn test_scope_patrol_filter() {
+        let scope = OfflineScope {
+            patrols: Some(vec!["Eagles".to_string()]),
+            active_only: false,
+        };
+        assert!(scope.includes_youth(&youth_in(Some("Eagles"))));
+        assert!(!scope.includes_youth(&youth_in(Some("Hawks"))));
+        assert!(!scope.includes_youth(&youth_in(None)));
+        assert!(OfflineScope::default().includes_youth(&youth_in(None)));
     }
-    if missing_badge_reqs > 0 {
-        gaps.push(format!("{} badge requirements missing", missing_badge_reqs));
+
+    #[test]
+    fn test_prefetch_manifest_resume() {
+        let now = Utc::now();
+        let scope = OfflineScope::default();
+        let manifest = PrefetchManifest::new(&scope, now - Duration::hours(2));
+        assert!(manifest.is_resumable(&scope, now));
+        assert!(!manifest.is_resumable(&OfflineScope { active_only: true, ..Default::default() }, now));
+        assert!(!manifest.is_resumable(&scope, now + Duration::hours(PREFETCH_FRESH_HOURS)));
+
+        assert!(is_fresh(Some(CachedData { data: (), cached_at: now - Duration::hours(1) }), now));
+        assert!(!is_fresh(Some(CachedData { data: (), cached_at: now - Duration::hours(30) }), now));
+        assert!(!is_fresh::<()>(None, now));
     }
 
-    gaps
+    #[test]
+    fn test_scope_description() {
+        assert_eq!(OfflineScope::default().description(), "All scouts");
+        let scope = OfflineScope {
+            patrols: Some(vec!["Eagles".to_string()]),
+            active_only: true,
+        };
+        assert_eq!(scope.description(), "Eagles, active only");
+    }
 }
@@ -5,7 +5,7 @@
 
 use tracing::warn;
 
-use crate::api::ApiClient;
+use crate::api::ScoutingApi;
 use crate::cache::CacheManager;
 use crate::cache::offline::CacheProgress;
 use crate::models::{
@@ -34,7 +34,7 @@ pub struct RefreshResult {
 /// Progress is reported via `on_progress` so each frontend can display it.
 /// Returns all fetched data so callers can update in-memory state without re-reading cache.
 pub async fn refresh_base_data(
-    api: &ApiClient,
+    api: &impl ScoutingApi,
     cache: &CacheManager,
     org_guid: &str,
     user_id: i64,
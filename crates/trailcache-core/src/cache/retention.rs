@@ -0,0 +1,349 @@
+//! Retention rules for data about scouts who have left the unit.
+//!
+//! Scouts with cached files who are missing from a roster refresh are
+//! recorded as departed, with the date they were first missed. Once the
+//! configured period has passed, their per-scout cache files and every
+//! local record keyed to them are deleted: consents, certifications,
+//! accommodations, medication doses, event and meeting attendance, returned
+//! closet checkouts, library loans, nominations, to-dos, planned positions,
+//! den chief links, feed entries, date corrections, physical checks, Eagle
+//! projects, clinic registrations, and tags. Each purge is added to an audit
+//! log kept in the cache. Nothing is purged unless a retention period is
+//! configured.
+
+use std::collections::{BTreeMap, HashMap};
+
+use anyhow::Result;
+use chrono::{Months, NaiveDate};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info};
+
+use super::manager::CacheManager;
+use crate::models::{
+    Accommodations, ActivityFeed, AttendanceHistory, Clinic, Consents, DateCorrectionLog, DenChiefLinks, EagleProjects,
+    EventAttendance, HighAdventure, Library, MedicationLog, Nominations, PositionPlan, ScoutTags, SkillMatrix, TodoList,
+    UniformCloset, Youth,
+};
+
+/// Retention period suggested when turning purging on
+pub const SUGGESTED_DEPARTED_RETENTION_MONTHS: u32 = 24;
+
+/// Per-scout cache files are named `<prefix><user id>`, or
+/// `<prefix><user id>_<rank or badge id>` for requirements.
+const PER_SCOUT_PREFIXES: [&str; 7] = ["ranks_", "merit_badges_", "leadership_", "awards_", "activity_log_", "rank_reqs_", "badge_reqs_"];
+
+/// How long data about departed scouts is kept. Purging deletes local
+/// records for good, so it's off until a period is set.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RetentionPolicy {
+    /// Months after leaving the roster before a scout's data is purged
+    /// (None, the default, keeps it indefinitely)
+    pub departed_scout_months: Option<u32>,
+}
+
+impl RetentionPolicy {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Scouts who left on or before this date are due to be purged.
+    pub fn purge_cutoff(&self, today: NaiveDate) -> Option<NaiveDate> {
+        today.checked_sub_months(Months::new(self.departed_scout_months?))
+    }
+}
+
+/// A scout who has dropped off the roster.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Departure {
+    pub name: String,
+    /// First refresh the scout was missing from
+    pub left_on: NaiveDate,
+}
+
+/// Departed scouts whose data hasn't been purged yet, keyed by user ID.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Departures {
+    pub scouts: BTreeMap<i64, Departure>,
+}
+
+impl Departures {
+    /// Record scouts in `known` who aren't on `roster` as leaving today, and
+    /// forget any departed scouts who are back on it.
+    pub fn update(&mut self, roster: &[Youth], known: &BTreeMap<i64, String>, today: NaiveDate) {
+        let on_roster = |id: i64| roster.iter().any(|y| y.user_id == Some(id));
+        self.scouts.retain(|&id, _| !on_roster(id));
+        for (&id, name) in known {
+            if !on_roster(id) {
+                self.scouts.entry(id).or_insert_with(|| Departure { name: name.clone(), left_on: today });
+            }
+        }
+    }
+
+    /// Scouts who left on or before `cutoff`.
+    pub fn due(&self, cutoff: NaiveDate) -> Vec<i64> {
+        self.scouts.iter().filter(|(_, d)| d.left_on <= cutoff).map(|(&id, _)| id).collect()
+    }
+}
+
+/// One scout's data removed under the retention policy.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PurgeRecord {
+    pub user_id: i64,
+    pub name: String,
+    pub left_on: NaiveDate,
+    pub purged_on: NaiveDate,
+    /// What was removed: cache file names, and local records with counts
+    pub removed: Vec<String>,
+}
+
+/// Every purge so far, oldest first.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RetentionAudit {
+    pub purges: Vec<PurgeRecord>,
+}
+
+/// Add "<what> (<count>)" to a purge's list of removals, if any were removed.
+fn note(removed: &mut Vec<String>, what: &str, count: usize) {
+    if count > 0 {
+        removed.push(format!("{} ({})", what, count));
+    }
+}
+
+/// The user ID a per-scout cache file belongs to.
+fn scout_file_owner(name: &str) -> Option<i64> {
+    PER_SCOUT_PREFIXES.iter().find_map(|prefix| name.strip_prefix(prefix)?.split('_').next()?.parse().ok())
+}
+
+impl CacheManager {
+    /// Per-scout cache files (without extension), grouped by user ID.
+    fn per_scout_files(&self) -> Result<BTreeMap<i64, Vec<String>>> {
+        let mut files: BTreeMap<i64, Vec<String>> = BTreeMap::new();
+        for entry in std::fs::read_dir(self.cache_dir())? {
+            let file_name = entry?.file_name().to_string_lossy().into_owned();
+            if let Some(name) = file_name.strip_suffix(".enc") {
+                if let Some(id) = scout_file_owner(name) {
+                    files.entry(id).or_default().push(name.to_string());
+                }
+            }
+        }
+        for names in files.values_mut() {
+            names.sort();
+        }
+        Ok(files)
+    }
+
+    /// Track scouts who have left `roster` (named from `previous` where
+    /// possible) and purge those gone longer than the policy allows.
+    /// Returns the scouts purged this time, which are also appended to the
+    /// audit log. An empty roster is ignored so a bad fetch can't purge
+    /// everyone.
+    pub fn enforce_retention(
+        &self,
+        policy: &RetentionPolicy,
+        previous: &[Youth],
+        roster: &[Youth],
+        today: NaiveDate,
+    ) -> Result<Vec<PurgeRecord>> {
+        if roster.is_empty() {
+            return Ok(Vec::new());
+        }
+        let mut files = self.per_scout_files()?;
+        let names: HashMap<i64, String> = previous.iter().filter_map(|y| Some((y.user_id?, y.short_name()))).collect();
+        let known: BTreeMap<i64, String> = files
+            .keys()
+            .chain(names.keys())
+            .map(|&id| (id, names.get(&id).cloned().unwrap_or_else(|| format!("Scout #{}", id))))
+            .collect();
+
+        let mut departures = self.load_departures()?.map(|c| c.data).unwrap_or_default();
+        departures.update(roster, &known, today);
+
+        let due = policy.purge_cutoff(today).map(|cutoff| departures.due(cutoff)).unwrap_or_default();
+        let mut purged = Vec::new();
+        if !due.is_empty() {
+            let mut consents: Consents = self.load_consents()?.map(|c| c.data).unwrap_or_default();
+            let mut skills: SkillMatrix = self.load_skills()?.map(|c| c.data).unwrap_or_default();
+            let mut tags: ScoutTags = self.load_scout_tags()?.map(|c| c.data).unwrap_or_default();
+            let mut accommodations: Accommodations = self.load_accommodations()?.map(|c| c.data).unwrap_or_default();
+            let mut attendance: EventAttendance = self.load_event_attendance()?.map(|c| c.data).unwrap_or_default();
+            let mut meetings: AttendanceHistory = self.load_attendance()?.map(|c| c.data).unwrap_or_default();
+            let mut closet: UniformCloset = self.load_closet()?.map(|c| c.data).unwrap_or_default();
+            let mut high_adventure: HighAdventure = self.load_high_adventure()?.map(|c| c.data).unwrap_or_default();
+            let mut eagle_projects: EagleProjects = self.load_eagle_projects()?.map(|c| c.data).unwrap_or_default();
+            let mut clinic: Clinic = self.load_clinic()?.map(|c| c.data).unwrap_or_default();
+            let mut medications: MedicationLog = self.load_medication_log()?.map(|c| c.data).unwrap_or_default();
+            let mut nominations: Nominations = self.load_nominations()?.map(|c| c.data).unwrap_or_default();
+            let mut library: Library = self.load_library()?.map(|c| c.data).unwrap_or_default();
+            let mut todos: TodoList = self.load_todos()?.map(|c| c.data).unwrap_or_default();
+            let mut plan: PositionPlan = self.load_position_plan()?.map(|c| c.data).unwrap_or_default();
+            let mut den_chiefs: DenChiefLinks = self.load_den_chiefs()?.map(|c| c.data).unwrap_or_default();
+            let mut feed: ActivityFeed = self.load_activity_feed()?.map(|c| c.data).unwrap_or_default();
+            let mut corrections: DateCorrectionLog = self.load_date_corrections()?.map(|c| c.data).unwrap_or_default();
+            for id in due {
+                let Some(departure) = departures.scouts.remove(&id) else { continue };
+                let mut removed = Vec::new();
+                for name in files.remove(&id).unwrap_or_default() {
+                    std::fs::remove_file(self.cache_dir().join(format!("{}.enc", name)))?;
+                    removed.push(name);
+                }
+                let before = consents.signed.len();
+                consents.signed.retain(|s| s.user_id != id);
+                note(&mut removed, "consents", before - consents.signed.len());
+                let before = skills.records.len();
+                skills.records.retain(|r| r.user_id != id);
+                note(&mut removed, "skills", before - skills.records.len());
+                let before = accommodations.entries.len();
+                accommodations.entries.retain(|a| a.user_id != id);
+                note(&mut removed, "accommodations", before - accommodations.entries.len());
+                note(&mut removed, "medication doses", medications.remove_scout(id));
+                note(&mut removed, "event attendance", attendance.remove_scout(id));
+                note(&mut removed, "meeting attendance", meetings.remove_scout(id));
+                note(&mut removed, "closet checkouts", closet.remove_scout(id));
+                note(&mut removed, "library loans", library.remove_scout(id));
+                note(&mut removed, "nominations", nominations.remove_scout(id));
+                note(&mut removed, "to-dos", todos.remove_scout(id));
+                note(&mut removed, "den chief links", den_chiefs.remove_scout(id));
+                note(&mut removed, "feed entries", feed.remove_scout(id));
+                note(&mut removed, "date corrections", corrections.remove_scout(id));
+                if plan.unassign(id) {
+                    removed.push("planned position".to_string());
+                }
+                if high_adventure.remove_person(id) {
+                    removed.push("physical checks".to_string());
+                }
+                if eagle_projects.remove_scout(id) {
+                    removed.push("Eagle project".to_string());
+                }
+                if clinic.remove_scout(id) {
+                    removed.push("clinic registrations".to_string());
+                }
+                if tags.tags.remove(&id).is_some() {
+                    removed.push("tags".to_string());
+                }
+                debug!(user_id = id, count = removed.len(), "Purged departed scout");
+                purged.push(PurgeRecord {
+                    user_id: id,
+                    name: departure.name,
+                    left_on: departure.left_on,
+                    purged_on: today,
+                    removed,
+                });
+            }
+            self.save_consents(&consents)?;
+            self.save_skills(&skills)?;
+            self.save_scout_tags(&tags)?;
+            self.save_accommodations(&accommodations)?;
+            self.save_event_attendance(&attendance)?;
+            self.save_attendance(&meetings)?;
+            self.save_closet(&closet)?;
+            self.save_high_adventure(&high_adventure)?;
+            self.save_eagle_projects(&eagle_projects)?;
+            self.save_clinic(&clinic)?;
+            self.save_medication_log(&medications)?;
+            self.save_nominations(&nominations)?;
+            self.save_library(&library)?;
+            self.save_todos(&todos)?;
+            self.save_position_plan(&plan)?;
+            self.save_den_chiefs(&den_chiefs)?;
+            self.save_activity_feed(&feed)?;
+            self.save_date_corrections(&corrections)?;
+
+            let mut audit = self.load_retention_audit()?.map(|c| c.data).unwrap_or_default();
+            audit.purges.extend(purged.iter().cloned());
+            self.save_retention_audit(&audit)?;
+            info!(count = purged.len(), "Purged data for departed scouts");
+        }
+        self.save_departures(&departures)?;
+        Ok(purged)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scouts(ids: &[i64]) -> Vec<Youth> {
+        let list: Vec<_> = ids
+            .iter()
+            .map(|id| serde_json::json!({ "userId": id, "firstName": "Scout", "lastName": id.to_string() }))
+            .collect();
+        serde_json::from_value(serde_json::Value::Array(list)).unwrap()
+    }
+
+    #[test]
+    fn test_scout_file_owner() {
+        assert_eq!(scout_file_owner("ranks_12"), Some(12));
+        assert_eq!(scout_file_owner("badge_reqs_12_135"), Some(12));
+        assert_eq!(scout_file_owner("activity_log_12"), Some(12));
+        assert_eq!(scout_file_owner("activity_feed"), None);
+        assert_eq!(scout_file_owner("adult_awards"), None);
+        assert_eq!(scout_file_owner("event_guests_12"), None);
+    }
+
+    #[test]
+    fn test_departed_scouts_purged_after_retention_period() {
+        let dir = std::env::temp_dir().join(format!("trailcache-retention-test-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let cache = CacheManager::new_without_encryption(dir.clone()).unwrap();
+        cache.save_youth_ranks(1, &[]).unwrap();
+        cache.save_youth_ranks(2, &[]).unwrap();
+        cache.save_rank_requirements(2, 7, &[]).unwrap();
+        let mut consents = Consents::default();
+        consents.sign(2, crate::models::ConsentForm::MediaRelease, NaiveDate::from_ymd_opt(2024, 1, 1).unwrap());
+        cache.save_consents(&consents).unwrap();
+        let mut medications = MedicationLog::default();
+        for user_id in [1, 2] {
+            medications.record(crate::models::MedicationDose {
+                user_id,
+                scout_name: format!("Scout {}", user_id),
+                medication: "Ibuprofen".to_string(),
+                dose: "200 mg".to_string(),
+                given_at: NaiveDate::from_ymd_opt(2025, 7, 1).unwrap().and_hms_opt(8, 0, 0).unwrap(),
+                given_by: "Nurse".to_string(),
+            });
+        }
+        cache.save_medication_log(&medications).unwrap();
+
+        let left = NaiveDate::from_ymd_opt(2026, 1, 15).unwrap();
+        let far_later = NaiveDate::from_ymd_opt(2036, 1, 15).unwrap();
+        let keep = RetentionPolicy::default();
+        assert!(cache.enforce_retention(&keep, &scouts(&[1, 2]), &scouts(&[1]), left).unwrap().is_empty());
+        assert!(cache.enforce_retention(&keep, &scouts(&[1]), &scouts(&[1]), far_later).unwrap().is_empty(), "off by default");
+        cache.save_departures(&Departures::default()).unwrap();
+
+        let policy = RetentionPolicy { departed_scout_months: Some(6) };
+        assert!(cache.enforce_retention(&policy, &scouts(&[1, 2]), &[], left).unwrap().is_empty());
+        assert!(cache.enforce_retention(&policy, &scouts(&[1, 2]), &scouts(&[1]), left).unwrap().is_empty());
+        let later = NaiveDate::from_ymd_opt(2026, 7, 14).unwrap();
+        assert!(cache.enforce_retention(&policy, &scouts(&[1]), &scouts(&[1]), later).unwrap().is_empty());
+
+        let due = NaiveDate::from_ymd_opt(2026, 7, 15).unwrap();
+        let purged = cache.enforce_retention(&policy, &scouts(&[1]), &scouts(&[1]), due).unwrap();
+        assert_eq!(purged.len(), 1);
+        assert_eq!(purged[0].name, "Scout 2");
+        assert_eq!(purged[0].left_on, left);
+        assert_eq!(purged[0].removed, ["rank_reqs_2_7", "ranks_2", "consents (1)", "medication doses (1)"]);
+        assert!(cache.load_youth_ranks(2).unwrap().is_none());
+        assert!(cache.load_youth_ranks(1).unwrap().is_some());
+        assert!(cache.load_consents().unwrap().unwrap().data.signed.is_empty());
+        let doses = cache.load_medication_log().unwrap().unwrap().data.doses;
+        assert_eq!(doses.iter().map(|d| d.user_id).collect::<Vec<_>>(), [1]);
+        assert_eq!(cache.load_retention_audit().unwrap().unwrap().data.purges, purged);
+        assert!(cache.load_departures().unwrap().unwrap().data.scouts.is_empty());
+
+        std::fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    fn test_rejoining_scout_is_forgotten() {
+        let mut departures = Departures::default();
+        let known = BTreeMap::from([(3, "Sam".to_string())]);
+        let today = NaiveDate::from_ymd_opt(2026, 3, 1).unwrap();
+        departures.update(&scouts(&[1]), &known, today);
+        assert_eq!(departures.due(today), [3]);
+        departures.update(&scouts(&[1, 3]), &known, today);
+        assert!(departures.scouts.is_empty());
+        assert_eq!(RetentionPolicy { departed_scout_months: None }.purge_cutoff(today), None);
+    }
+}
@@ -5,17 +5,68 @@
 //!
 //! Configuration is stored at `~/.config/trailcache/config.json`.
 
+use std::collections::{BTreeMap, HashMap};
 use std::path::PathBuf;
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 
+use crate::cache::{FreshnessPolicy, RetentionPolicy};
+use crate::integrations::EmailSettings;
+use crate::models::{AgendaItem, DistrictUnit, ForecastSettings, GradeRollover, LinkedUnit, PaceSettings};
+use crate::reports::{ExportProfile, UnitGoals};
+use crate::resources::WorkbookLink;
+use crate::utils::PhoneFormat;
+
 /// Application name used for config/cache directory paths
 const APP_NAME: &str = "trailcache";
 
 /// Config file name
 const CONFIG_FILE: &str = "config.json";
 
+/// How to get the user's attention when something happens in the background.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NotifyMode {
+    #[default]
+    Off,
+    /// Ring the terminal bell
+    Bell,
+    /// Show an OS desktop notification, falling back to the bell
+    Desktop,
+}
+
+/// Built-in key layout the `keybindings` section starts from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum KeyPreset {
+    /// Arrows plus j/k
+    #[default]
+    Vim,
+    /// Arrows plus Ctrl-N/P/F/B, Ctrl-V/Alt-V, and Alt-</Alt->
+    Emacs,
+    /// Arrows and Page/Home/End only
+    Arrows,
+}
+
+/// User key bindings for the main screen, e.g.
+/// `"keybindings": { "preset": "emacs", "down": ["ctrl-j"], "tab_1": ["F1"] }`.
+/// Keys listed for an action are added to the preset's.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyBindings {
+    #[serde(default)]
+    pub preset: KeyPreset,
+    /// Action name to keys
+    #[serde(flatten)]
+    pub keys: BTreeMap<String, Vec<String>>,
+}
+
+impl KeyBindings {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct Config {
     pub organization_guid: Option<String>,
@@ -23,6 +74,80 @@ pub struct Config {
     pub last_username: Option<String>,
     #[serde(default)]
     pub offline_mode: bool,
+    /// Encrypt the cache with a random key kept in the OS keychain instead
+    /// of one derived from the password.
+    #[serde(default)]
+    pub keyring_cache_key: bool,
+    /// Restricted mode for the Senior Patrol Leader and PLC: hides contact
+    /// details, birth dates, and member IDs, and leader-only tools.
+    #[serde(default)]
+    pub youth_leader_mode: bool,
+    /// Bell or desktop notification for long refreshes, failed saves, and
+    /// reminders while the terminal is in the background.
+    #[serde(default)]
+    pub notifications: NotifyMode,
+    /// Per-badge workbook/pamphlet link overrides, keyed by badge name.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub workbook_links: HashMap<String, WorkbookLink>,
+    /// Committee targets shown in the committee report.
+    #[serde(default, skip_serializing_if = "UnitGoals::is_empty")]
+    pub unit_goals: UnitGoals,
+    /// User IDs of scouts pinned as "mine" for the My Scouts view and `--my-scout`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub my_scouts: Vec<i64>,
+    /// Patrol duties for event duty rosters, replacing the defaults.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub patrol_duties: Vec<String>,
+    /// Agenda for the meeting timer, replacing the default troop meeting.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub meeting_agenda: Vec<AgendaItem>,
+    /// How long departed scouts' data is kept.
+    #[serde(default, skip_serializing_if = "RetentionPolicy::is_default")]
+    pub retention: RetentionPolicy,
+    /// How old cached data may get before views warn or hide personal details.
+    #[serde(default, skip_serializing_if = "FreshnessPolicy::is_default")]
+    pub freshness: FreshnessPolicy,
+    /// Export profile used unless another is picked when exporting.
+    #[serde(default, skip_serializing_if = "ExportProfile::is_default")]
+    pub export_profile: ExportProfile,
+    /// Units a commissioner serves, shown in the district dashboard.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub district_units: Vec<DistrictUnit>,
+    /// The other troop of a linked boys'/girls' pair.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub linked_unit: Option<LinkedUnit>,
+    /// Remapped navigation, sort, and tab-switch keys.
+    #[serde(default, skip_serializing_if = "KeyBindings::is_default")]
+    pub keybindings: KeyBindings,
+    /// Color theme: "dark" (the default), "light", "high-contrast",
+    /// "solarized", or a TOML theme file, relative to the config directory.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub theme: Option<String>,
+    /// How phone numbers are shown: "parens" (the default), "dashes",
+    /// "dots", or "international".
+    #[serde(default, skip_serializing_if = "PhoneFormat::is_default")]
+    pub phone_format: PhoneFormat,
+    /// The day school grades advance each year, for spotting grades that
+    /// haven't been updated in Scoutbook.
+    #[serde(default, skip_serializing_if = "GradeRollover::is_default")]
+    pub grade_rollover: GradeRollover,
+    /// Expected crossovers for the membership forecast on the Unit tab.
+    #[serde(default, skip_serializing_if = "ForecastSettings::is_default")]
+    pub forecast: ForecastSettings,
+    /// Months without recorded advancement before a scout is flagged in
+    /// alerts and the digest.
+    #[serde(default, skip_serializing_if = "PaceSettings::is_default")]
+    pub pace: PaceSettings,
+    /// URL to POST a JSON summary of changes to after each refresh, such as
+    /// a Slack or Discord incoming webhook.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_url: Option<String>,
+    /// SMTP server and recipients for `trailcache digest --send`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<EmailSettings>,
+    /// IDs of startup alerts dismissed in the alerts panel.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dismissed_alerts: Vec<String>,
     /// Explicit config directory override (for mobile platforms where `dirs` doesn't work).
     #[serde(skip)]
     pub config_dir_override: Option<PathBuf>,
@@ -74,6 +199,12 @@ impl Config {
         Ok(config_dir.join(APP_NAME).join(CONFIG_FILE))
     }
 
+    /// Path of a data file stored alongside `config.json`.
+    pub fn data_file_path(&self, file_name: &str) -> Result<PathBuf> {
+        let config_path = self.resolved_config_path()?;
+        Ok(config_path.with_file_name(file_name))
+    }
+
     fn resolved_config_path(&self) -> Result<PathBuf> {
         if let Some(ref dir) = self.config_dir_override {
             Ok(dir.join(APP_NAME).join(CONFIG_FILE))
@@ -82,6 +213,14 @@ impl Config {
         }
     }
 
+    /// Directory for exported reports (Documents/trailcache, or ~/trailcache).
+    pub fn export_dir(&self) -> Result<PathBuf> {
+        let base = dirs::document_dir()
+            .or_else(dirs::home_dir)
+            .ok_or_else(|| anyhow::anyhow!("Could not find documents directory"))?;
+        Ok(base.join(APP_NAME))
+    }
+
     pub fn cache_dir(&self) -> Result<PathBuf> {
         let base = if let Some(ref dir) = self.cache_dir_override {
             dir.clone()
@@ -97,8 +236,42 @@ impl Config {
         Ok(path)
     }
 
+    /// Cache directory for another organization, such as a unit in
+    /// district mode. Each organization's data is kept apart.
+    pub fn cache_dir_for(&self, org_guid: &str) -> Result<PathBuf> {
+        Self { organization_guid: Some(org_guid.to_string()), ..self.clone() }.cache_dir()
+    }
+
     /// Set an explicit cache directory (for mobile).
     pub fn set_cache_dir(&mut self, dir: PathBuf) {
         self.cache_dir_override = Some(dir);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_notifications_setting() {
+        let dir = std::env::temp_dir().join(format!("trailcache-config-test-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let path = dir.join(APP_NAME).join(CONFIG_FILE);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+
+        // Configs from before the setting stay quiet
+        std::fs::write(&path, r#"{"organization_guid": null}"#).unwrap();
+        assert_eq!(Config::load_from(dir.clone()).unwrap().notifications, NotifyMode::Off);
+
+        std::fs::write(&path, r#"{"notifications": "desktop"}"#).unwrap();
+        let mut config = Config::load_from(dir.clone()).unwrap();
+        assert_eq!(config.notifications, NotifyMode::Desktop);
+        config.notifications = NotifyMode::Bell;
+        config.save().unwrap();
+        assert_eq!(Config::load_from(dir.clone()).unwrap().notifications, NotifyMode::Bell);
+
+        std::fs::write(&path, r#"{"notifications": "loud"}"#).unwrap();
+        assert!(Config::load_from(dir.clone()).is_err());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,310 @@
+//! Sending email over SMTP.
+//!
+//! Just enough of SMTP to hand a plain-text message to the unit's mail
+//! provider: STARTTLS on the submission port (or TLS from the start, or a
+//! plain local relay), `AUTH PLAIN`, and one message to several recipients.
+//! The body is sent base64-encoded so names with accents survive servers
+//! without 8BITMIME.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{anyhow, bail, Context, Result};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+use tokio_rustls::client::TlsStream;
+use tokio_rustls::rustls::pki_types::ServerName;
+use tokio_rustls::rustls::{ClientConfig, RootCertStore};
+use tokio_rustls::TlsConnector;
+
+/// How long a whole conversation with the server may take.
+const SMTP_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Environment variable holding the SMTP password, for scheduled jobs.
+pub const SMTP_PASSWORD_ENV: &str = "TRAILCACHE_SMTP_PASSWORD";
+
+/// How the connection to the SMTP server is secured.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SmtpSecurity {
+    /// Upgrade with STARTTLS, usually on port 587
+    #[default]
+    Starttls,
+    /// TLS from the start, usually on port 465
+    Tls,
+    /// No encryption, for a relay on the local machine; never used to log in
+    None,
+}
+
+fn default_smtp_port() -> u16 {
+    587
+}
+
+/// The `email` section of the config: where digests are sent from and to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EmailSettings {
+    pub smtp_host: String,
+    #[serde(default = "default_smtp_port")]
+    pub smtp_port: u16,
+    #[serde(default)]
+    pub security: SmtpSecurity,
+    /// Login for the server; the password comes from the environment or a prompt
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    pub from: String,
+    pub recipients: Vec<String>,
+}
+
+/// A plain-text email.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Message {
+    pub from: String,
+    pub to: Vec<String>,
+    pub subject: String,
+    pub body: String,
+}
+
+/// Drop line breaks so a value can't start a header of its own.
+fn header_value(value: &str) -> String {
+    value.chars().filter(|c| *c != '\r' && *c != '\n').collect()
+}
+
+impl Message {
+    /// The message as sent after DATA, with CRLF line endings.
+    fn to_wire(&self, date: DateTime<Local>) -> String {
+        let subject = header_value(&self.subject);
+        let subject = if subject.is_ascii() { subject } else { format!("=?utf-8?B?{}?=", BASE64.encode(&subject)) };
+        let to: Vec<String> = self.to.iter().map(|t| header_value(t)).collect();
+
+        let mut out = String::new();
+        out.push_str(&format!("From: {}\r\n", header_value(&self.from)));
+        out.push_str(&format!("To: {}\r\n", to.join(", ")));
+        out.push_str(&format!("Subject: {}\r\n", subject));
+        out.push_str(&format!("Date: {}\r\n", date.to_rfc2822()));
+        out.push_str("MIME-Version: 1.0\r\n");
+        out.push_str("Content-Type: text/plain; charset=utf-8\r\n");
+        out.push_str("Content-Transfer-Encoding: base64\r\n\r\n");
+        let body = self.body.replace("\r\n", "\n").replace('\n', "\r\n");
+        let encoded = BASE64.encode(body);
+        for chunk in encoded.as_bytes().chunks(76) {
+            out.push_str(std::str::from_utf8(chunk).unwrap_or_default());
+            out.push_str("\r\n");
+        }
+        out
+    }
+}
+
+/// One conversation with an SMTP server over any stream.
+struct Smtp<S> {
+    stream: BufReader<S>,
+}
+
+impl<S: AsyncRead + AsyncWrite + Unpin> Smtp<S> {
+    fn new(stream: S) -> Self {
+        Self { stream: BufReader::new(stream) }
+    }
+
+    fn into_inner(self) -> S {
+        self.stream.into_inner()
+    }
+
+    /// Read a reply, joining the lines of a multi-line one.
+    async fn reply(&mut self) -> Result<(u16, String)> {
+        let mut text = String::new();
+        loop {
+            let mut line = String::new();
+            if self.stream.read_line(&mut line).await? == 0 {
+                bail!("The SMTP server closed the connection");
+            }
+            let code = line
+                .get(..3)
+                .and_then(|c| c.parse::<u16>().ok())
+                .ok_or_else(|| anyhow!("Unexpected SMTP reply: {}", line.trim_end()))?;
+            text.push_str(line.get(4..).unwrap_or_default().trim_end());
+            if line.as_bytes().get(3) != Some(&b'-') {
+                return Ok((code, text));
+            }
+            text.push(' ');
+        }
+    }
+
+    /// Read a reply in the same class (2xx, 3xx) as `expected`.
+    async fn expect(&mut self, expected: u16, what: &str) -> Result<()> {
+        let (code, text) = self.reply().await?;
+        if code / 100 != expected / 100 {
+            bail!("SMTP {} failed: {} {}", what, code, text);
+        }
+        Ok(())
+    }
+
+    /// Send a command line and check its reply. Errors name only the verb,
+    /// so a login never ends up in a message.
+    async fn command(&mut self, line: &str, expected: u16) -> Result<()> {
+        let stream = self.stream.get_mut();
+        stream.write_all(line.as_bytes()).await?;
+        stream.write_all(b"\r\n").await?;
+        stream.flush().await?;
+        self.expect(expected, line.split_whitespace().next().unwrap_or(line)).await
+    }
+
+    /// Everything after the greeting (and STARTTLS): introduce ourselves,
+    /// log in, and hand over the message.
+    async fn deliver(&mut self, settings: &EmailSettings, password: Option<&str>, message: &Message, secure: bool) -> Result<()> {
+        if settings.username.is_some() && !secure {
+            bail!("Not sending the SMTP password over an unencrypted connection (set security to starttls or tls)");
+        }
+        self.command("EHLO trailcache", 250).await?;
+        if let Some(username) = settings.username.as_deref() {
+            let password = password.ok_or_else(|| anyhow!("No SMTP password for {}", username))?;
+            let token = BASE64.encode(format!("\0{}\0{}", username, password));
+            self.command(&format!("AUTH PLAIN {}", token), 235).await?;
+        }
+        self.command(&format!("MAIL FROM:<{}>", header_value(&message.from)), 250).await?;
+        for to in &message.to {
+            self.command(&format!("RCPT TO:<{}>", header_value(to)), 250).await?;
+        }
+        self.command("DATA", 354).await?;
+        let stream = self.stream.get_mut();
+        stream.write_all(message.to_wire(Local::now()).as_bytes()).await?;
+        stream.write_all(b".\r\n").await?;
+        stream.flush().await?;
+        self.expect(250, "message").await?;
+        // The message is accepted; a failed goodbye doesn't matter
+        let _ = self.command("QUIT", 221).await;
+        Ok(())
+    }
+}
+
+async fn tls_connect(host: &str, stream: TcpStream) -> Result<TlsStream<TcpStream>> {
+    let mut roots = RootCertStore::empty();
+    roots.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+    let provider = Arc::new(tokio_rustls::rustls::crypto::ring::default_provider());
+    let config = ClientConfig::builder_with_provider(provider)
+        .with_safe_default_protocol_versions()?
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+    let name = ServerName::try_from(host.to_string()).with_context(|| format!("Invalid SMTP host {}", host))?;
+    Ok(TlsConnector::from(Arc::new(config)).connect(name, stream).await?)
+}
+
+/// Send `message` through the configured server.
+pub async fn send(settings: &EmailSettings, password: Option<&str>, message: &Message) -> Result<()> {
+    let host = settings.smtp_host.as_str();
+    let conversation = async {
+        let tcp = TcpStream::connect((host, settings.smtp_port))
+            .await
+            .with_context(|| format!("Couldn't connect to {}:{}", host, settings.smtp_port))?;
+        match settings.security {
+            SmtpSecurity::Tls => {
+                let mut smtp = Smtp::new(tls_connect(host, tcp).await?);
+                smtp.expect(220, "greeting").await?;
+                smtp.deliver(settings, password, message, true).await
+            }
+            SmtpSecurity::Starttls => {
+                let mut smtp = Smtp::new(tcp);
+                smtp.expect(220, "greeting").await?;
+                smtp.command("EHLO trailcache", 250).await?;
+                smtp.command("STARTTLS", 220).await?;
+                let mut smtp = Smtp::new(tls_connect(host, smtp.into_inner()).await?);
+                smtp.deliver(settings, password, message, true).await
+            }
+            SmtpSecurity::None => {
+                let mut smtp = Smtp::new(tcp);
+                smtp.expect(220, "greeting").await?;
+                smtp.deliver(settings, password, message, false).await
+            }
+        }
+    };
+    tokio::time::timeout(SMTP_TIMEOUT, conversation)
+        .await
+        .map_err(|_| anyhow!("Timed out sending email through {}", host))?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_smtp_conversation() {
+        let settings: EmailSettings = serde_json::from_value(serde_json::json!({
+            "smtp_host": "localhost", "security": "none",
+            "from": "scoutmaster@example.org", "recipients": ["cc@example.org"],
+        }))
+        .unwrap();
+        assert_eq!(settings.smtp_port, 587);
+        let message = Message {
+            from: settings.from.clone(),
+            to: vec!["cc@example.org".into(), "ac@example.org".into()],
+            subject: "Troop 12 digest\r\nBcc: someone@example.org".into(),
+            body: "Zoë Ortiz\nFirst Class".into(),
+        };
+
+        let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap();
+        let (client, server) = tokio::io::duplex(64 * 1024);
+        let transcript = runtime.block_on(async {
+            // A server that accepts everything and remembers what it was sent
+            let server = tokio::spawn(async move {
+                let mut server = BufReader::new(server);
+                let mut seen = Vec::new();
+                let mut data = false;
+                server.get_mut().write_all(b"220 mail.example.org ready\r\n").await.unwrap();
+                loop {
+                    let mut line = String::new();
+                    if server.read_line(&mut line).await.unwrap() == 0 {
+                        return seen;
+                    }
+                    let line = line.trim_end().to_string();
+                    let reply: &[u8] = match line.as_str() {
+                        _ if data && line == "." => {
+                            data = false;
+                            b"250 queued\r\n"
+                        }
+                        _ if data => {
+                            seen.push(line);
+                            continue;
+                        }
+                        l if l.starts_with("EHLO") => b"250-mail.example.org\r\n250 8BITMIME\r\n",
+                        "DATA" => {
+                            data = true;
+                            b"354 go ahead\r\n"
+                        }
+                        "QUIT" => b"221 bye\r\n",
+                        _ => b"250 ok\r\n",
+                    };
+                    seen.push(line);
+                    server.get_mut().write_all(reply).await.unwrap();
+                }
+            });
+
+            let mut smtp = Smtp::new(client);
+            smtp.expect(220, "greeting").await.unwrap();
+            smtp.deliver(&settings, None, &message, false).await.unwrap();
+            drop(smtp);
+            server.await.unwrap()
+        });
+
+        assert_eq!(&transcript[..5], [
+            "EHLO trailcache",
+            "MAIL FROM:<scoutmaster@example.org>",
+            "RCPT TO:<cc@example.org>",
+            "RCPT TO:<ac@example.org>",
+            "DATA",
+        ]);
+        assert!(transcript.contains(&"Subject: Troop 12 digestBcc: someone@example.org".to_string()), "no header injection");
+        assert!(!transcript.iter().any(|l| l.starts_with("Bcc")));
+        let body = transcript.iter().skip_while(|l| !l.is_empty()).skip(1).take_while(|l| *l != ".").cloned().collect::<String>();
+        assert_eq!(BASE64.decode(body).unwrap(), "Zoë Ortiz\r\nFirst Class".as_bytes());
+        assert_eq!(transcript.last().map(String::as_str), Some("QUIT"));
+
+        // A login is never sent in the clear
+        let mut with_login = settings.clone();
+        with_login.username = Some("scoutmaster".into());
+        let (client, _server) = tokio::io::duplex(1024);
+        let err = runtime.block_on(Smtp::new(client).deliver(&with_login, Some("secret"), &message, false));
+        assert!(err.unwrap_err().to_string().contains("unencrypted"));
+    }
+}
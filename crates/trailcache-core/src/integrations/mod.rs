@@ -0,0 +1,10 @@
+//! Connections to services outside Scoutbook.
+//!
+//! - `email`: SMTP delivery for the weekly digest
+//! - `webhook`: JSON push of what changed after each refresh
+
+pub mod email;
+pub mod webhook;
+
+pub use email::{EmailSettings, Message, SmtpSecurity, SMTP_PASSWORD_ENV};
+pub use webhook::{WebhookChange, WebhookPayload};
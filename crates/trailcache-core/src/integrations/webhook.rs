@@ -0,0 +1,113 @@
+//! JSON webhook sent after a refresh with what changed.
+//!
+//! The payload carries a readable summary as both `text` (Slack) and
+//! `content` (Discord), so either can take it as an incoming webhook
+//! directly, and the changes themselves for anything else.
+
+use std::time::Duration;
+
+use anyhow::{bail, Result};
+use chrono::{DateTime, Local};
+use serde::Serialize;
+
+use crate::models::{FeedCategory, FeedEntry};
+
+/// Discord rejects messages longer than 2000 characters.
+const MAX_SUMMARY_CHARS: usize = 1900;
+
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(15);
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WebhookChange {
+    pub category: &'static str,
+    pub user_id: Option<i64>,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WebhookPayload {
+    pub text: String,
+    pub content: String,
+    pub unit: String,
+    pub refreshed_at: String,
+    pub changes: Vec<WebhookChange>,
+}
+
+fn category_key(category: FeedCategory) -> &'static str {
+    match category {
+        FeedCategory::Rank => "rank",
+        FeedCategory::Requirement => "requirement",
+        FeedCategory::MeritBadge => "merit_badge",
+        FeedCategory::Rsvp => "rsvp",
+        FeedCategory::Roster => "roster",
+        FeedCategory::Event => "event",
+    }
+}
+
+impl WebhookPayload {
+    /// The changes from one refresh, ranks and badges first.
+    pub fn new(unit: &str, changes: &[FeedEntry], refreshed_at: DateTime<Local>) -> Self {
+        let mut changes: Vec<&FeedEntry> = changes.iter().collect();
+        changes.sort_by_key(|c| FeedCategory::ALL.iter().position(|k| *k == c.category));
+
+        let mut summary = format!("{}: {} update{}", unit, changes.len(), if changes.len() == 1 { "" } else { "s" });
+        for (i, change) in changes.iter().enumerate() {
+            let line = format!("\n• {}", change.text);
+            if summary.chars().count() + line.chars().count() > MAX_SUMMARY_CHARS {
+                summary.push_str(&format!("\n…and {} more", changes.len() - i));
+                break;
+            }
+            summary.push_str(&line);
+        }
+
+        Self {
+            text: summary.clone(),
+            content: summary,
+            unit: unit.to_string(),
+            refreshed_at: refreshed_at.to_rfc3339(),
+            changes: changes
+                .into_iter()
+                .map(|c| WebhookChange { category: category_key(c.category), user_id: c.user_id, text: c.text.clone() })
+                .collect(),
+        }
+    }
+}
+
+/// POST the payload to `url`.
+pub async fn post(url: &str, payload: &WebhookPayload) -> Result<()> {
+    let client = reqwest::Client::builder().timeout(WEBHOOK_TIMEOUT).build()?;
+    let response = client.post(url).json(payload).send().await?;
+    if !response.status().is_success() {
+        bail!("Webhook returned {}", response.status());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_payload_summary_and_changes() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 15).unwrap();
+        let entry = |category, text: &str| FeedEntry { date: today, category, user_id: Some(7), text: text.to_string() };
+        let changes = vec![
+            entry(FeedCategory::Event, "Food Drive added for Nov 7"),
+            entry(FeedCategory::MeritBadge, "S. Ortiz completed Camping"),
+        ];
+
+        let payload = WebhookPayload::new("Troop 12", &changes, Local::now());
+        assert_eq!(payload.text, "Troop 12: 2 updates\n• S. Ortiz completed Camping\n• Food Drive added for Nov 7");
+        assert_eq!(payload.content, payload.text);
+        assert_eq!(payload.changes[0].category, "merit_badge");
+        let json = serde_json::to_value(&payload).unwrap();
+        assert_eq!(json["changes"][1]["text"], "Food Drive added for Nov 7");
+
+        let many: Vec<FeedEntry> = (0..200).map(|i| entry(FeedCategory::Rsvp, &format!("Campout {} RSVP +1", i))).collect();
+        let payload = WebhookPayload::new("Troop 12", &many, Local::now());
+        assert!(payload.text.chars().count() < 2000);
+        assert!(payload.text.ends_with("more"));
+        assert_eq!(payload.changes.len(), 200);
+    }
+}
@@ -7,6 +7,10 @@ pub mod api;
 pub mod auth;
 pub mod cache;
 pub mod config;
+pub mod integrations;
 pub mod models;
+pub mod reports;
+pub mod resources;
+pub mod search;
 pub mod summaries;
 pub mod utils;
@@ -0,0 +1,98 @@
+//! Locally tracked alternate requirements and accommodations.
+//!
+//! A scout with a disability may be approved to do an alternate requirement,
+//! or to meet one with an accommodation like extra time or a spoken test.
+//! Scoutbook still shows the original requirement as open, so anyone
+//! reviewing progress can wrongly take the scout to be behind. Approvals are
+//! recorded here and shown next to the requirement they apply to.
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// The rank or merit badge a requirement belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AdvancementRef {
+    Rank(i64),
+    Badge(i64),
+}
+
+/// An approved alternate or accommodation for one requirement.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Accommodation {
+    pub user_id: i64,
+    pub advancement: AdvancementRef,
+    /// Requirement number including its parents ("7b", not "b")
+    pub requirement: String,
+    /// What was approved, e.g. "Swim 50 yards with a float belt instead"
+    pub note: String,
+    pub recorded_on: NaiveDate,
+}
+
+/// Persisted accommodations for the whole troop.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Accommodations {
+    pub entries: Vec<Accommodation>,
+}
+
+impl Accommodations {
+    pub fn get(&self, user_id: i64, advancement: AdvancementRef, requirement: &str) -> Option<&Accommodation> {
+        self.entries
+            .iter()
+            .find(|a| a.user_id == user_id && a.advancement == advancement && a.requirement == requirement)
+    }
+
+    /// Record `note` for a requirement, replacing any earlier one. An empty
+    /// note removes it. Returns whether anything changed.
+    pub fn set(&mut self, user_id: i64, advancement: AdvancementRef, requirement: &str, note: &str, today: NaiveDate) -> bool {
+        let note = note.trim();
+        if self.get(user_id, advancement, requirement).is_some_and(|a| a.note == note) {
+            return false;
+        }
+        let before = self.entries.len();
+        self.entries
+            .retain(|a| !(a.user_id == user_id && a.advancement == advancement && a.requirement == requirement));
+        if note.is_empty() {
+            return self.entries.len() != before;
+        }
+        self.entries.push(Accommodation {
+            user_id,
+            advancement,
+            requirement: requirement.to_string(),
+            note: note.to_string(),
+            recorded_on: today,
+        });
+        true
+    }
+
+    /// How many requirements of an advancement have an accommodation.
+    pub fn count(&self, user_id: i64, advancement: AdvancementRef) -> usize {
+        self.entries
+            .iter()
+            .filter(|a| a.user_id == user_id && a.advancement == advancement)
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_replace_and_clear() {
+        let today = NaiveDate::from_ymd_opt(2026, 5, 1).unwrap();
+        let swimming = AdvancementRef::Badge(3);
+        let mut accommodations = Accommodations::default();
+
+        assert!(accommodations.set(1, swimming, "5b", "Float belt approved", today));
+        assert!(!accommodations.set(1, swimming, "5b", " Float belt approved ", today), "unchanged");
+        assert!(accommodations.set(1, swimming, "5b", "Shallow end only", today));
+        assert_eq!(accommodations.get(1, swimming, "5b").unwrap().note, "Shallow end only");
+        assert!(accommodations.get(1, AdvancementRef::Rank(3), "5b").is_none());
+        assert!(accommodations.get(2, swimming, "5b").is_none());
+        assert_eq!(accommodations.count(1, swimming), 1);
+
+        assert!(accommodations.set(1, swimming, "5b", "", today));
+        assert!(accommodations.entries.is_empty());
+        assert!(!accommodations.set(1, swimming, "5b", "", today));
+    }
+}
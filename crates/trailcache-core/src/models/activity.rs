@@ -0,0 +1,152 @@
+//! Camping, hiking, and service activity logs.
+//!
+//! Scoutbook keeps a running log per scout of nights camped, miles hiked,
+//! and hours of service. The totals come back as one summary per scout.
+//! Nights camped also decide Order of the Arrow eligibility: a First Class
+//! scout with 15 nights of troop camping can be put up for election.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::advancement::ScoutRank;
+use super::person::Youth;
+
+/// Nights of camping needed for an Order of the Arrow election
+pub const OA_CAMPING_NIGHTS: u32 = 15;
+
+/// Lowest rank that can be elected to the Order of the Arrow
+pub const OA_MIN_RANK: ScoutRank = ScoutRank::FirstClass;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CampingLogs {
+    #[serde(default)]
+    pub total_number_of_days: Option<f32>,
+    #[serde(default)]
+    pub total_number_of_nights: Option<f32>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HikingLogs {
+    #[serde(default)]
+    pub total_number_of_miles: Option<f32>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServiceLogs {
+    #[serde(default)]
+    pub total_number_of_hours: Option<f32>,
+}
+
+/// A scout's activity log totals.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivityLog {
+    #[serde(default)]
+    pub camping_logs: Option<CampingLogs>,
+    #[serde(default)]
+    pub hiking_logs: Option<HikingLogs>,
+    #[serde(default)]
+    pub service_logs: Option<ServiceLogs>,
+}
+
+impl ActivityLog {
+    pub fn camping_nights(&self) -> u32 {
+        self.camping_logs.as_ref().and_then(|c| c.total_number_of_nights).unwrap_or(0.0) as u32
+    }
+
+    pub fn camping_days(&self) -> u32 {
+        self.camping_logs.as_ref().and_then(|c| c.total_number_of_days).unwrap_or(0.0) as u32
+    }
+
+    pub fn hiking_miles(&self) -> f32 {
+        self.hiking_logs.as_ref().and_then(|h| h.total_number_of_miles).unwrap_or(0.0)
+    }
+
+    pub fn service_hours(&self) -> f32 {
+        self.service_logs.as_ref().and_then(|s| s.total_number_of_hours).unwrap_or(0.0)
+    }
+}
+
+/// Where a scout stands for the Order of the Arrow.
+#[derive(Debug, Clone)]
+pub struct OaStanding<'a> {
+    pub youth: &'a Youth,
+    pub rank: ScoutRank,
+    /// `None` when the scout's activity log hasn't been fetched
+    pub nights: Option<u32>,
+}
+
+impl OaStanding<'_> {
+    pub fn rank_met(&self) -> bool {
+        self.rank >= OA_MIN_RANK
+    }
+
+    pub fn nights_short(&self) -> u32 {
+        OA_CAMPING_NIGHTS.saturating_sub(self.nights.unwrap_or(0))
+    }
+
+    pub fn is_eligible(&self) -> bool {
+        self.rank_met() && self.nights.is_some() && self.nights_short() == 0
+    }
+}
+
+pub fn oa_standing<'a>(youth: &'a Youth, log: Option<&ActivityLog>) -> OaStanding<'a> {
+    OaStanding {
+        youth,
+        rank: ScoutRank::parse(youth.current_rank.as_deref()),
+        nights: log.map(|l| l.camping_nights()),
+    }
+}
+
+/// Every scout's OA standing: eligible scouts first, then by nights camped.
+pub fn oa_standings<'a>(youth: &'a [Youth], logs: &HashMap<i64, ActivityLog>) -> Vec<OaStanding<'a>> {
+    let mut standings: Vec<OaStanding> =
+        youth.iter().map(|y| oa_standing(y, y.user_id.and_then(|id| logs.get(&id)))).collect();
+    standings.sort_by(|a, b| {
+        b.is_eligible()
+            .cmp(&a.is_eligible())
+            .then(b.nights.cmp(&a.nights))
+            .then_with(|| a.youth.last_name.to_lowercase().cmp(&b.youth.last_name.to_lowercase()))
+    });
+    standings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_oa_standings() {
+        let log: ActivityLog = serde_json::from_value(serde_json::json!({
+            "campingLogs": { "totalNumberOfDays": 20, "totalNumberOfNights": 16 },
+            "hikingLogs": { "totalNumberOfMiles": 12.5 },
+            "serviceLogs": null
+        }))
+        .unwrap();
+        assert_eq!(log.camping_nights(), 16);
+        assert_eq!(log.hiking_miles(), 12.5);
+        assert_eq!(log.service_hours(), 0.0);
+
+        let youth: Vec<Youth> = serde_json::from_value(serde_json::json!([
+            { "userId": 1, "firstName": "Alex", "lastName": "Reed", "currentRankName": "Tenderfoot" },
+            { "userId": 2, "firstName": "Sam", "lastName": "Ortiz", "currentRankName": "First Class" },
+            { "userId": 3, "firstName": "Jo", "lastName": "Park", "currentRankName": "Star" }
+        ]))
+        .unwrap();
+        let short = ActivityLog {
+            camping_logs: Some(CampingLogs { total_number_of_days: None, total_number_of_nights: Some(9.0) }),
+            ..Default::default()
+        };
+        let logs = HashMap::from([(1, log.clone()), (2, log), (3, short)]);
+        let standings = oa_standings(&youth, &logs);
+        assert_eq!(standings[0].youth.user_id, Some(2));
+        assert!(standings[0].is_eligible());
+        assert!(!standings[1].is_eligible(), "enough nights but below First Class");
+        assert!(!standings[1].rank_met());
+        assert_eq!(standings[2].nights_short(), 6);
+    }
+}
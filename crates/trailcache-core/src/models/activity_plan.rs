@@ -0,0 +1,240 @@
+//! Pre-activity checklists for events.
+//!
+//! Before an outing someone has to file the activity plan, pull permits,
+//! request insurance certificates, and check swim classifications and
+//! drivers. Which of those an event needs depends on what kind of event it
+//! is, so the list is built from its type, name, and location. Items are
+//! checked off locally, except swim classifications, which count as done
+//! once every scout going has a current swimmer classification in the skill
+//! matrix.
+
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use super::event::{Event, RsvpStatus};
+use super::skills::{Certification, SkillMatrix};
+
+/// Words in an event's type, name, or location that mean time on the water.
+const AQUATICS_WORDS: [&str; 11] =
+    ["swim", "canoe", "kayak", "raft", "aquatic", "paddl", "boat", "sail", "snorkel", "lake", "beach"];
+
+/// One thing to take care of before an activity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum ActivityPlanItem {
+    ActivityPlan,
+    Permits,
+    InsuranceCertificate,
+    PermissionSlips,
+    HealthForms,
+    SwimClassification,
+    DriverQualifications,
+}
+
+impl ActivityPlanItem {
+    pub const ALL: [ActivityPlanItem; 7] = [
+        ActivityPlanItem::ActivityPlan,
+        ActivityPlanItem::Permits,
+        ActivityPlanItem::InsuranceCertificate,
+        ActivityPlanItem::PermissionSlips,
+        ActivityPlanItem::HealthForms,
+        ActivityPlanItem::SwimClassification,
+        ActivityPlanItem::DriverQualifications,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ActivityPlanItem::ActivityPlan => "Activity plan filed with council",
+            ActivityPlanItem::Permits => "Campsite or land-use permits",
+            ActivityPlanItem::InsuranceCertificate => "Certificate of insurance requested",
+            ActivityPlanItem::PermissionSlips => "Permission slips collected",
+            ActivityPlanItem::HealthForms => "Health forms checked",
+            ActivityPlanItem::SwimClassification => "Swim classifications checked",
+            ActivityPlanItem::DriverQualifications => "Driver licenses and insurance checked",
+        }
+    }
+
+    /// Whether the item can come from the skill matrix.
+    pub fn has_record(&self) -> bool {
+        matches!(self, ActivityPlanItem::SwimClassification)
+    }
+
+    /// Whether the skill matrix shows the item done: every scout going is
+    /// classified a swimmer on the day the event starts.
+    pub fn from_record(&self, event: &Event, skills: &SkillMatrix, today: NaiveDate) -> bool {
+        self.has_record() && unclassified_swimmers(event, skills, today).is_empty()
+    }
+
+    /// The items an event needs, in checklist order. Meetings need nothing
+    /// unless Scoutbook asks for permission slips.
+    pub fn for_event(event: &Event) -> Vec<ActivityPlanItem> {
+        let kind = event.derived_type().to_lowercase();
+        let name = event.name.to_lowercase();
+        let camping = kind.contains("camp");
+        let trail = camping || kind.contains("hike") || kind.contains("outdoor");
+        let service = kind.contains("service");
+        let aquatics = is_aquatics(event);
+        let meeting = !trail && !service && !aquatics && (kind.contains("meeting") || name.contains("court of honor"));
+        let overnight = camping || event.end_date.as_deref().and_then(|d| d.get(..10)).is_some_and(|end| {
+            event.start_date.as_deref().and_then(|d| d.get(..10)).is_some_and(|start| end > start)
+        });
+
+        ActivityPlanItem::ALL
+            .into_iter()
+            .filter(|item| match item {
+                ActivityPlanItem::ActivityPlan | ActivityPlanItem::Permits => trail || aquatics,
+                ActivityPlanItem::InsuranceCertificate => service || camping,
+                ActivityPlanItem::PermissionSlips => event.slips_required || !meeting,
+                ActivityPlanItem::HealthForms => overnight || aquatics,
+                ActivityPlanItem::SwimClassification => aquatics,
+                ActivityPlanItem::DriverQualifications => !meeting,
+            })
+            .collect()
+    }
+}
+
+/// Whether an event looks like it involves swimming or boating.
+pub fn is_aquatics(event: &Event) -> bool {
+    [Some(event.derived_type()), Some(event.name.as_str()), event.location.as_deref()]
+        .into_iter()
+        .flatten()
+        .map(str::to_lowercase)
+        .any(|text| AQUATICS_WORDS.iter().any(|w| text.contains(w)))
+}
+
+/// Scouts going to an event without a current swimmer classification on the
+/// day it starts, or `today` if it has no date.
+pub fn unclassified_swimmers(event: &Event, skills: &SkillMatrix, today: NaiveDate) -> Vec<i64> {
+    let day = event.start_day().unwrap_or(today);
+    event
+        .invited_users
+        .iter()
+        .filter(|u| !u.is_adult && u.status() == RsvpStatus::Going)
+        .filter(|u| !skills.get(u.user_id, Certification::SwimTest).is_some_and(|r| r.qualifies(day)))
+        .map(|u| u.user_id)
+        .collect()
+}
+
+/// One event's checklist.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActivityChecklist {
+    pub event_id: i64,
+    /// Items checked off locally, and when
+    #[serde(default)]
+    pub checked: BTreeMap<ActivityPlanItem, NaiveDate>,
+}
+
+/// Persisted pre-activity checklists, one per event that has any item
+/// checked.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ActivityPlans {
+    pub checklists: Vec<ActivityChecklist>,
+}
+
+impl ActivityPlans {
+    pub fn checklist(&self, event_id: i64) -> Option<&ActivityChecklist> {
+        self.checklists.iter().find(|c| c.event_id == event_id)
+    }
+
+    pub fn is_done(&self, event: &Event, item: ActivityPlanItem, skills: &SkillMatrix, today: NaiveDate) -> bool {
+        item.from_record(event, skills, today)
+            || self.checklist(event.id).is_some_and(|c| c.checked.contains_key(&item))
+    }
+
+    /// Check or uncheck an item locally. Returns false when the event doesn't
+    /// need the item, or the skill matrix already shows it done.
+    pub fn toggle(&mut self, event: &Event, item: ActivityPlanItem, skills: &SkillMatrix, today: NaiveDate) -> bool {
+        if !ActivityPlanItem::for_event(event).contains(&item) || item.from_record(event, skills, today) {
+            return false;
+        }
+        let pos = match self.checklists.iter().position(|c| c.event_id == event.id) {
+            Some(pos) => pos,
+            None => {
+                self.checklists.push(ActivityChecklist { event_id: event.id, checked: BTreeMap::new() });
+                self.checklists.len() - 1
+            }
+        };
+        let checklist = &mut self.checklists[pos];
+        if checklist.checked.remove(&item).is_none() {
+            checklist.checked.insert(item, today);
+        }
+        if checklist.checked.is_empty() {
+            self.checklists.remove(pos);
+        }
+        true
+    }
+
+    /// Items done and total, if the event needs any.
+    pub fn progress(&self, event: &Event, skills: &SkillMatrix, today: NaiveDate) -> Option<(usize, usize)> {
+        let items = ActivityPlanItem::for_event(event);
+        if items.is_empty() {
+            return None;
+        }
+        let done = items.iter().filter(|&&i| self.is_done(event, i, skills, today)).count();
+        Some((done, items.len()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{SkillRecord, SwimLevel};
+
+    fn event(json: serde_json::Value) -> Event {
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn test_checklist_tailored_by_event_type() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 15).unwrap();
+        let meeting = event(serde_json::json!({ "id": 1, "name": "Troop Meeting" }));
+        assert!(ActivityPlanItem::for_event(&meeting).is_empty());
+
+        let service = event(serde_json::json!({ "id": 2, "name": "Food Drive", "eventType": "Service Project" }));
+        assert_eq!(
+            ActivityPlanItem::for_event(&service),
+            [
+                ActivityPlanItem::InsuranceCertificate,
+                ActivityPlanItem::PermissionSlips,
+                ActivityPlanItem::DriverQualifications
+            ]
+        );
+
+        let canoe = event(serde_json::json!({
+            "id": 3, "name": "Canoe Campout", "startDate": "2026-10-23T17:00:00",
+            "invitedUsers": [
+                { "userId": 1, "firstName": "Sam", "lastName": "Ortiz", "rsvpCode": "Y", "isAdult": false },
+                { "userId": 2, "firstName": "Ana", "lastName": "Diaz", "rsvpCode": "Y", "isAdult": false },
+                { "userId": 10, "firstName": "Dana", "lastName": "Ortiz", "rsvpCode": "Y", "isAdult": true },
+            ]
+        }));
+        assert_eq!(ActivityPlanItem::for_event(&canoe), ActivityPlanItem::ALL);
+
+        let mut skills = SkillMatrix::default();
+        let swim = |user_id, level| SkillRecord {
+            user_id,
+            certification: Certification::SwimTest,
+            earned_on: Some(NaiveDate::from_ymd_opt(2026, 6, 1).unwrap()),
+            card_number: None,
+            swim_level: Some(level),
+        };
+        skills.record(swim(1, SwimLevel::Swimmer));
+        skills.record(swim(2, SwimLevel::Beginner));
+        assert_eq!(unclassified_swimmers(&canoe, &skills, today), [2]);
+
+        let mut plans = ActivityPlans::default();
+        assert_eq!(plans.progress(&meeting, &skills, today), None);
+        assert!(!plans.toggle(&service, ActivityPlanItem::SwimClassification, &skills, today));
+        assert!(plans.toggle(&canoe, ActivityPlanItem::Permits, &skills, today));
+        assert!(plans.toggle(&canoe, ActivityPlanItem::SwimClassification, &skills, today));
+        assert_eq!(plans.progress(&canoe, &skills, today), Some((2, 7)));
+
+        // Once Ana passes as a swimmer the matrix covers it
+        skills.record(swim(2, SwimLevel::Swimmer));
+        assert!(!plans.toggle(&canoe, ActivityPlanItem::SwimClassification, &skills, today));
+        assert!(plans.toggle(&canoe, ActivityPlanItem::Permits, &skills, today));
+        assert_eq!(plans.progress(&canoe, &skills, today), Some((1, 7)));
+        assert_eq!(plans.checklists.len(), 1);
+    }
+}
@@ -0,0 +1,206 @@
+//! Adult leader award checklists (Scouter's Training Award, Scouter's Key).
+//!
+//! Scoutbook doesn't track progress toward adult recognitions, so each
+//! requirement is checked off locally. Position-specific training comes
+//! from the adult's Scoutbook record instead.
+
+use serde::{Deserialize, Serialize};
+
+use super::person::Adult;
+
+/// Adult recognitions tracked with a checklist.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AdultAward {
+    ScoutersTrainingAward,
+    ScoutersKey,
+}
+
+/// What part of an award a requirement belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RequirementKind {
+    Tenure,
+    Training,
+    Performance,
+}
+
+impl RequirementKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            RequirementKind::Tenure => "Tenure",
+            RequirementKind::Training => "Training",
+            RequirementKind::Performance => "Performance",
+        }
+    }
+}
+
+/// One checklist item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AwardRequirement {
+    pub kind: RequirementKind,
+    pub text: &'static str,
+    /// Satisfied by the adult being position trained in Scoutbook
+    pub from_training_record: bool,
+}
+
+const fn req(kind: RequirementKind, text: &'static str) -> AwardRequirement {
+    AwardRequirement { kind, text, from_training_record: false }
+}
+
+const POSITION_TRAINED: AwardRequirement = AwardRequirement {
+    kind: RequirementKind::Training,
+    text: "Complete position-specific training",
+    from_training_record: true,
+};
+
+const TRAINING_AWARD_REQUIREMENTS: &[AwardRequirement] = &[
+    req(RequirementKind::Tenure, "Two years as a registered troop Scouter"),
+    POSITION_TRAINED,
+    req(RequirementKind::Training, "Complete Youth Protection training"),
+    req(RequirementKind::Performance, "Attend a roundtable or other leader training"),
+    req(RequirementKind::Performance, "Help conduct a troop program planning conference"),
+    req(RequirementKind::Performance, "Help the troop earn Journey to Excellence"),
+];
+
+const SCOUTERS_KEY_REQUIREMENTS: &[AwardRequirement] = &[
+    req(RequirementKind::Tenure, "Three years as a registered troop Scouter"),
+    POSITION_TRAINED,
+    req(RequirementKind::Training, "Complete Wood Badge or equivalent advanced training"),
+    req(RequirementKind::Performance, "Conduct Scoutmaster or board of review sessions"),
+    req(RequirementKind::Performance, "Help the troop earn Journey to Excellence"),
+    req(RequirementKind::Performance, "Mentor a new unit leader"),
+];
+
+impl AdultAward {
+    pub const ALL: [AdultAward; 2] = [AdultAward::ScoutersTrainingAward, AdultAward::ScoutersKey];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            AdultAward::ScoutersTrainingAward => "Scouter's Training Award",
+            AdultAward::ScoutersKey => "Scouter's Key",
+        }
+    }
+
+    pub fn requirements(&self) -> &'static [AwardRequirement] {
+        match self {
+            AdultAward::ScoutersTrainingAward => TRAINING_AWARD_REQUIREMENTS,
+            AdultAward::ScoutersKey => SCOUTERS_KEY_REQUIREMENTS,
+        }
+    }
+}
+
+/// Requirements checked off for one adult and award.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AdultAwardChecklist {
+    pub person_guid: String,
+    pub award: AdultAward,
+    /// Indexes into `award.requirements()`
+    pub checked: Vec<usize>,
+}
+
+/// Persisted checklists for all adults.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AdultAwardChecklists {
+    pub entries: Vec<AdultAwardChecklist>,
+}
+
+impl AdultAwardChecklists {
+    /// Whether a requirement is met, from the checklist or the training record.
+    pub fn is_met(&self, adult: &Adult, award: AdultAward, index: usize) -> bool {
+        let Some(requirement) = award.requirements().get(index) else {
+            return false;
+        };
+        if requirement.from_training_record {
+            return adult.is_position_trained() == Some(true);
+        }
+        self.checklist(adult, award).is_some_and(|c| c.checked.contains(&index))
+    }
+
+    /// Check or uncheck a requirement. Requirements satisfied by the training
+    /// record can't be toggled; returns false for those.
+    pub fn toggle(&mut self, adult: &Adult, award: AdultAward, index: usize) -> bool {
+        let Some(guid) = adult.person_guid.as_deref() else {
+            return false;
+        };
+        if award.requirements().get(index).is_none_or(|r| r.from_training_record) {
+            return false;
+        }
+
+        let pos = match self.entries.iter().position(|c| c.person_guid == guid && c.award == award) {
+            Some(pos) => pos,
+            None => {
+                self.entries.push(AdultAwardChecklist { person_guid: guid.to_string(), award, checked: Vec::new() });
+                self.entries.len() - 1
+            }
+        };
+        let checked = &mut self.entries[pos].checked;
+        if let Some(i) = checked.iter().position(|&c| c == index) {
+            checked.remove(i);
+        } else {
+            checked.push(index);
+            checked.sort_unstable();
+        }
+        true
+    }
+
+    /// Requirements met and total for an award.
+    pub fn progress(&self, adult: &Adult, award: AdultAward) -> (usize, usize) {
+        let total = award.requirements().len();
+        let met = (0..total).filter(|&i| self.is_met(adult, award, i)).count();
+        (met, total)
+    }
+
+    pub fn is_eligible(&self, adult: &Adult, award: AdultAward) -> bool {
+        let (met, total) = self.progress(adult, award);
+        met == total
+    }
+
+    /// Adults who have met every requirement of an award.
+    pub fn eligible<'a>(&self, adults: &'a [Adult]) -> Vec<(&'a Adult, AdultAward)> {
+        adults
+            .iter()
+            .flat_map(|a| AdultAward::ALL.into_iter().map(move |award| (a, award)))
+            .filter(|(a, award)| self.is_eligible(a, *award))
+            .collect()
+    }
+
+    fn checklist(&self, adult: &Adult, award: AdultAward) -> Option<&AdultAwardChecklist> {
+        let guid = adult.person_guid.as_deref()?;
+        self.entries.iter().find(|c| c.person_guid == guid && c.award == award)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn adult(trained: &str) -> Adult {
+        serde_json::from_value(serde_json::json!({
+            "personGuid": "A1", "firstName": "Pat", "lastName": "Leader", "positionTrained": trained
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_checklist_progress_and_eligibility() {
+        let award = AdultAward::ScoutersTrainingAward;
+        let trained = adult("Trained");
+        let mut lists = AdultAwardChecklists::default();
+
+        assert_eq!(lists.progress(&trained, award), (1, 6), "training comes from the record");
+        assert!(!lists.toggle(&trained, award, 1), "record-backed items can't be toggled");
+
+        for i in [0, 2, 3, 4, 5] {
+            assert!(lists.toggle(&trained, award, i));
+        }
+        assert!(lists.is_eligible(&trained, award));
+        let eligible = lists.eligible(std::slice::from_ref(&trained));
+        assert_eq!(eligible.len(), 1);
+        assert_eq!(eligible[0].1, award);
+
+        let untrained = adult("Not Trained");
+        assert!(!lists.is_eligible(&untrained, award));
+
+        lists.toggle(&trained, award, 0);
+        assert_eq!(lists.progress(&trained, award), (5, 6));
+    }
+}
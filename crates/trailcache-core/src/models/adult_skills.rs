@@ -0,0 +1,144 @@
+//! Who-knows-whom directory of adult skills and certifications.
+//!
+//! Scoutbook only tracks BSA training, so qualifications an outing depends
+//! on, like Wilderness First Aid, a climbing instructor card, a CDL, or a
+//! lifeguard certificate, are tagged locally on each adult with an optional
+//! expiration date. Skills are typed as "skill, expires, note":
+//! "Wilderness First Aid, 2027-05-01, SOLO".
+
+use chrono::{Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+/// Offered as examples when tagging an adult; any skill can be typed.
+pub const COMMON_ADULT_SKILLS: [&str; 9] = [
+    "Wilderness First Aid",
+    "CPR/AED",
+    "Hazardous Weather",
+    "Lifeguard",
+    "Climbing Instructor",
+    "CDL",
+    "Safety Afloat",
+    "Swimming & Water Rescue",
+    "Range Safety Officer",
+];
+
+/// Skills expiring within this many days are flagged.
+pub const SKILL_EXPIRY_WARNING_DAYS: i64 = 60;
+
+/// One adult's skill or certification.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AdultSkill {
+    pub user_id: i64,
+    pub skill: String,
+    pub expires: Option<NaiveDate>,
+    #[serde(default)]
+    pub note: String,
+}
+
+impl AdultSkill {
+    pub fn is_current(&self, today: NaiveDate) -> bool {
+        self.expires.is_none_or(|d| d >= today)
+    }
+
+    /// Still current but lapsing within `SKILL_EXPIRY_WARNING_DAYS`.
+    pub fn expires_soon(&self, today: NaiveDate) -> bool {
+        self.expires.is_some_and(|d| d >= today && d <= today + Duration::days(SKILL_EXPIRY_WARNING_DAYS))
+    }
+}
+
+fn parse_expiry(text: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(text, "%Y-%m-%d")
+        .or_else(|_| NaiveDate::parse_from_str(text, "%m/%d/%Y"))
+        .ok()
+}
+
+/// Persisted skills for every adult.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AdultSkills {
+    pub skills: Vec<AdultSkill>,
+}
+
+impl AdultSkills {
+    /// Tag an adult with a skill typed as "skill, expires, note", replacing
+    /// the same skill if they already have it. The expiration date
+    /// (YYYY-MM-DD or MM/DD/YYYY) and note are optional. Returns `None`
+    /// without a skill name or with a date that can't be read.
+    pub fn add(&mut self, user_id: i64, input: &str) -> Option<&AdultSkill> {
+        let mut parts = input.splitn(3, ',').map(str::trim);
+        let skill = parts.next().filter(|s| !s.is_empty())?;
+        let expires = match parts.next().filter(|d| !d.is_empty()) {
+            Some(date) => Some(parse_expiry(date)?),
+            None => None,
+        };
+        let note = parts.next().unwrap_or_default();
+        self.remove(user_id, skill);
+        self.skills.push(AdultSkill { user_id, skill: skill.to_string(), expires, note: note.to_string() });
+        self.skills.last()
+    }
+
+    /// Drop one of an adult's skills, matched ignoring case.
+    pub fn remove(&mut self, user_id: i64, skill: &str) -> bool {
+        let before = self.skills.len();
+        self.skills.retain(|s| s.user_id != user_id || !s.skill.eq_ignore_ascii_case(skill));
+        self.skills.len() != before
+    }
+
+    /// An adult's skills, by name.
+    pub fn for_adult(&self, user_id: i64) -> Vec<&AdultSkill> {
+        let mut skills: Vec<&AdultSkill> = self.skills.iter().filter(|s| s.user_id == user_id).collect();
+        skills.sort_by_key(|s| s.skill.to_lowercase());
+        skills
+    }
+
+    /// Every distinct skill name, first spelling seen, alphabetically.
+    pub fn skill_names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = Vec::new();
+        for skill in &self.skills {
+            if !names.iter().any(|n| n.eq_ignore_ascii_case(&skill.skill)) {
+                names.push(&skill.skill);
+            }
+        }
+        names.sort_by_key(|n| n.to_lowercase());
+        names
+    }
+
+    /// Adults with a current skill whose name contains `query`, ignoring
+    /// case, e.g. everyone who can cover "first aid" on a trip.
+    pub fn qualified(&self, query: &str, today: NaiveDate) -> Vec<&AdultSkill> {
+        let query = query.to_lowercase();
+        self.skills
+            .iter()
+            .filter(|s| s.is_current(today) && s.skill.to_lowercase().contains(&query))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_search_and_expiry() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 15).unwrap();
+        let mut skills = AdultSkills::default();
+        assert!(skills.add(1, "Wilderness First Aid, 2027-05-01, SOLO").is_some());
+        assert!(skills.add(2, "wilderness first aid, 10/01/2026").is_some());
+        assert!(skills.add(2, "CDL").is_some());
+        assert!(skills.add(3, "Lifeguard, 2026-11-20").is_some());
+        assert!(skills.add(3, "Lifeguard, next spring").is_none(), "unreadable date");
+        assert!(skills.add(3, " , 2027-01-01").is_none());
+
+        let wfa = skills.qualified("first aid", today);
+        assert_eq!(wfa.len(), 1, "adult 2's card lapsed Oct 1");
+        assert_eq!(wfa[0].note, "SOLO");
+        assert!(skills.for_adult(3)[0].expires_soon(today));
+        assert_eq!(skills.skill_names(), ["CDL", "Lifeguard", "Wilderness First Aid"]);
+
+        // Renewing replaces the old record
+        skills.add(2, "Wilderness First Aid, 2028-10-01");
+        assert_eq!(skills.for_adult(2).len(), 2);
+        assert_eq!(skills.qualified("WILDERNESS", today).len(), 2);
+        assert!(skills.remove(2, "cdl"));
+        assert_eq!(skills.for_adult(2).len(), 1);
+    }
+}
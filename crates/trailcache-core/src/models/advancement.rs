@@ -6,6 +6,8 @@ use std::cmp::Ordering;
 use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 
+use crate::utils::{format_phone, PhoneFormat};
+
 // ============================================================================
 // Rank Ordering
 // ============================================================================
@@ -44,7 +46,7 @@ pub const STATUS_COUNSELOR_APPROVED: &str = "Counselor Approved";
 pub const DEFAULT_AWARD_STATUS: &str = "Unknown";
 
 /// Scout rank for sorting purposes
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum ScoutRank {
     Unknown = 0,
     Scout = 1,
@@ -522,6 +524,15 @@ impl RankRequirement {
             || matches!(self.status.as_deref(), Some(STATUS_LEADER_APPROVED) | Some(STATUS_AWARDED))
     }
 
+    /// Show as completed and leader approved on `date`, ahead of the server
+    /// confirming it.
+    pub fn mark_completed(&mut self, date: NaiveDate) {
+        let date = date.format("%Y-%m-%d").to_string();
+        self.completed = Some(true);
+        self.leader_approved_date = Some(date.clone());
+        self.date_completed = Some(date);
+    }
+
     pub fn number(&self) -> String {
         self.list_number.clone()
             .or_else(|| self.requirement_number.clone())
@@ -567,11 +578,12 @@ impl CounselorInfo {
         format!("{} {}", first, last).trim().to_string()
     }
 
-    /// Get the best phone number (prefer mobile, fall back to home)
-    pub fn phone(&self) -> Option<&str> {
+    /// Get the best phone number (prefer mobile, fall back to home), formatted
+    pub fn phone(&self, format: PhoneFormat) -> Option<String> {
         self.mobile_phone.as_deref()
             .filter(|s| !s.is_empty())
             .or_else(|| self.home_phone.as_deref().filter(|s| !s.is_empty()))
+            .map(|p| format_phone(p, format))
     }
 }
 
@@ -869,7 +881,7 @@ impl Award {
 }
 
 /// Number of Eagle-required merit badges in the Scouts BSA program.
-pub const EAGLE_REQUIRED_COUNT: usize = 13;
+pub const EAGLE_REQUIRED_COUNT: usize = super::eagle::EAGLE_REQUIRED_BADGES.len();
 
 /// Format a date string from "YYYY-MM-DD" to "Month DD, YYYY"
 pub fn format_date(date: Option<&str>) -> String {
@@ -927,7 +939,7 @@ mod tests {
 
         let counselor = badge.assigned_counselor.as_ref().unwrap();
         assert_eq!(counselor.full_name(), "John Smith");
-        assert_eq!(counselor.phone(), Some("5559876543"));
+        assert_eq!(counselor.phone(PhoneFormat::Parens).as_deref(), Some("(555) 987-6543"));
         assert_eq!(counselor.email.as_deref(), Some("john.smith@example.com"));
     }
 
@@ -0,0 +1,105 @@
+//! Locally recorded troop meeting attendance.
+//!
+//! Scoutbook tracks RSVPs for calendar events, but weekly meetings are usually
+//! not on the calendar. Meeting-night attendance is taken in the TUI and kept
+//! here, one record per meeting date.
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// Attendance for a single meeting.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MeetingRecord {
+    pub date: NaiveDate,
+    /// Scouts marked present (user IDs)
+    pub present: Vec<i64>,
+    /// Scouts on the roster when attendance was taken, so later roster
+    /// changes don't count new scouts as absent from old meetings
+    pub roster: Vec<i64>,
+}
+
+impl MeetingRecord {
+    pub fn was_present(&self, user_id: i64) -> bool {
+        self.present.contains(&user_id)
+    }
+
+    pub fn was_expected(&self, user_id: i64) -> bool {
+        self.roster.contains(&user_id)
+    }
+}
+
+/// All recorded meetings, newest first.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AttendanceHistory {
+    pub meetings: Vec<MeetingRecord>,
+}
+
+impl AttendanceHistory {
+    /// Take a scout off every meeting's record, e.g. when their data is
+    /// purged. Returns how many meetings listed them.
+    pub fn remove_scout(&mut self, user_id: i64) -> usize {
+        let mut count = 0;
+        for meeting in &mut self.meetings {
+            let listed = meeting.was_present(user_id) || meeting.was_expected(user_id);
+            meeting.present.retain(|&id| id != user_id);
+            meeting.roster.retain(|&id| id != user_id);
+            count += usize::from(listed);
+        }
+        count
+    }
+
+    pub fn meeting(&self, date: NaiveDate) -> Option<&MeetingRecord> {
+        self.meetings.iter().find(|m| m.date == date)
+    }
+
+    /// Add a meeting, replacing any earlier record for the same date.
+    pub fn record(&mut self, record: MeetingRecord) {
+        self.meetings.retain(|m| m.date != record.date);
+        self.meetings.push(record);
+        self.meetings.sort_by_key(|m| std::cmp::Reverse(m.date));
+    }
+
+    /// Meetings on or after `since`.
+    pub fn since(&self, since: NaiveDate) -> impl Iterator<Item = &MeetingRecord> {
+        self.meetings.iter().filter(move |m| m.date >= since)
+    }
+}
+
+/// One item of a meeting agenda, timed by the TUI's agenda timer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AgendaItem {
+    pub title: String,
+    pub minutes: u64,
+}
+
+/// A typical troop meeting, used when the config doesn't set an agenda.
+pub fn default_agenda() -> Vec<AgendaItem> {
+    [
+        ("Opening", 5),
+        ("Skills instruction", 20),
+        ("Patrol meetings", 15),
+        ("Interpatrol activity", 15),
+        ("Closing", 5),
+    ]
+    .into_iter()
+    .map(|(title, minutes)| AgendaItem { title: title.to_string(), minutes })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_replaces_same_date() {
+        let date = NaiveDate::from_ymd_opt(2026, 10, 6).unwrap();
+        let mut history = AttendanceHistory::default();
+        history.record(MeetingRecord { date, present: vec![1], roster: vec![1, 2] });
+        history.record(MeetingRecord { date, present: vec![1, 2], roster: vec![1, 2] });
+        history.record(MeetingRecord { date: date.pred_opt().unwrap(), present: vec![], roster: vec![1, 2] });
+
+        assert_eq!(history.meetings.len(), 2);
+        assert_eq!(history.meetings[0].date, date, "newest first");
+        assert!(history.meeting(date).unwrap().was_present(2));
+    }
+}
@@ -0,0 +1,176 @@
+//! Automation rules run after every refresh.
+//!
+//! Rules live in `automations.json` next to the config file. Each one picks
+//! scouts by a few conditions, then tags them, sends a notification, or
+//! writes the list to the export folder:
+//!
+//! ```json
+//! { "rules": [
+//!     { "name": "Inactive", "when": { "no_rsvp_days": 60 }, "tag": "inactive", "notify": true }
+//! ] }
+//! ```
+//!
+//! A rule's tag follows its matches, so scouts who stop matching lose it
+//! on the next run.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use chrono::{Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use super::event::{Event, RsvpStatus};
+use super::person::Youth;
+
+/// File holding the rules, looked up next to the config file
+pub const AUTOMATIONS_FILE: &str = "automations.json";
+
+/// Conditions a scout must meet to match a rule. All given conditions
+/// must hold; a rule with none matches everyone.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AutomationConditions {
+    /// No "going" RSVP or recorded attendance for any event in the last N days
+    pub no_rsvp_days: Option<i64>,
+    pub patrol: Option<String>,
+    pub rank: Option<String>,
+    pub min_age: Option<i32>,
+    pub max_age: Option<i32>,
+}
+
+impl AutomationConditions {
+    pub fn matches(&self, youth: &Youth, events: &[Event], today: NaiveDate) -> bool {
+        if let Some(days) = self.no_rsvp_days {
+            let since = today - Duration::days(days);
+            let responded = events
+                .iter()
+                .filter(|e| e.start_day().is_some_and(|d| d >= since && d <= today))
+                .flat_map(|e| &e.invited_users)
+                .any(|u| Some(u.user_id) == youth.user_id && (u.attended || u.status() == RsvpStatus::Going));
+            if responded {
+                return false;
+            }
+        }
+        if self.patrol.as_ref().is_some_and(|p| !youth.patrol().eq_ignore_ascii_case(p)) {
+            return false;
+        }
+        if self.rank.as_ref().is_some_and(|r| !youth.rank().eq_ignore_ascii_case(r)) {
+            return false;
+        }
+        let age = youth.age();
+        if self.min_age.is_some_and(|min| age.is_none_or(|a| a < min)) {
+            return false;
+        }
+        self.max_age.is_none_or(|max| age.is_some_and(|a| a <= max))
+    }
+}
+
+/// One rule: which scouts, and what to do with them.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AutomationRule {
+    pub name: String,
+    #[serde(default)]
+    pub when: AutomationConditions,
+    /// Tag given to matching scouts
+    pub tag: Option<String>,
+    /// Notify when scouts newly match
+    #[serde(default)]
+    pub notify: bool,
+    /// Write the matching scouts to a CSV in the export folder
+    #[serde(default)]
+    pub export: bool,
+}
+
+impl AutomationRule {
+    pub fn matching<'a>(&self, youth: &'a [Youth], events: &[Event], today: NaiveDate) -> Vec<&'a Youth> {
+        youth.iter().filter(|y| self.when.matches(y, events, today)).collect()
+    }
+}
+
+/// The rules file.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Automations {
+    #[serde(default)]
+    pub rules: Vec<AutomationRule>,
+}
+
+impl Automations {
+    /// Read the rules file. A missing file means no rules.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let contents = std::fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+        serde_json::from_str(&contents).with_context(|| format!("Failed to parse {}", path.display()))
+    }
+}
+
+/// Tags given to scouts by automation rules.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ScoutTags {
+    pub tags: BTreeMap<i64, BTreeSet<String>>,
+}
+
+impl ScoutTags {
+    pub fn tags_for(&self, user_id: i64) -> impl Iterator<Item = &str> {
+        self.tags.get(&user_id).into_iter().flatten().map(String::as_str)
+    }
+
+    /// Give `tag` to exactly `user_ids`, taking it from everyone else.
+    /// Returns the scouts who didn't have it before.
+    pub fn sync(&mut self, tag: &str, user_ids: &[i64]) -> Vec<i64> {
+        for (id, tags) in self.tags.iter_mut() {
+            if !user_ids.contains(id) {
+                tags.remove(tag);
+            }
+        }
+        let added = user_ids
+            .iter()
+            .copied()
+            .filter(|&id| self.tags.entry(id).or_default().insert(tag.to_string()))
+            .collect();
+        self.tags.retain(|_, tags| !tags.is_empty());
+        added
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inactive_rule_tags_and_untags() {
+        let automations: Automations = serde_json::from_str(
+            r#"{ "rules": [ { "name": "Inactive", "when": { "no_rsvp_days": 60 }, "tag": "inactive", "notify": true } ] }"#,
+        )
+        .unwrap();
+        assert!(serde_json::from_str::<Automations>(r#"{ "rules": [ { "name": "x", "when": { "no_rsvp": 1 } } ] }"#).is_err());
+        let rule = &automations.rules[0];
+
+        let youth: Vec<Youth> = serde_json::from_value(serde_json::json!([
+            { "userId": 1, "firstName": "Sam", "lastName": "Scout" },
+            { "userId": 2, "firstName": "Alex", "lastName": "Ranger" }
+        ]))
+        .unwrap();
+        let mut events: Vec<Event> = serde_json::from_value(serde_json::json!([
+            { "id": 1, "name": "Campout", "startDate": "2026-09-01T09:00:00",
+              "invitedUsers": [ { "userId": 1, "firstName": "Sam", "lastName": "Scout", "rsvpCode": "Y" } ] },
+            { "id": 2, "name": "Old Hike", "startDate": "2026-01-01T09:00:00",
+              "invitedUsers": [ { "userId": 2, "firstName": "Alex", "lastName": "Ranger", "rsvpCode": "Y" } ] }
+        ]))
+        .unwrap();
+        let today = NaiveDate::from_ymd_opt(2026, 10, 1).unwrap();
+
+        let ids = |youth: Vec<&Youth>| youth.iter().filter_map(|y| y.user_id).collect::<Vec<_>>();
+        let mut tags = ScoutTags::default();
+        assert_eq!(tags.sync("inactive", &ids(rule.matching(&youth, &events, today))), [2]);
+        assert_eq!(tags.tags_for(2).collect::<Vec<_>>(), ["inactive"]);
+
+        events[0].invited_users[0].user_id = 2;
+        assert_eq!(tags.sync("inactive", &ids(rule.matching(&youth, &events, today))), [1]);
+        assert_eq!(tags.tags_for(2).count(), 0, "scouts who stop matching lose the tag");
+    }
+}
@@ -0,0 +1,187 @@
+//! Special awards catalog and locally tracked nominations.
+//!
+//! Scoutbook reports special awards (religious emblems, 50-Miler, Mile Swim,
+//! Totin' Chip, ...) per scout. The catalog turns those per-scout lists into
+//! one entry per award with its holders. Nominations are kept locally because
+//! most of these awards are applied for outside Scoutbook and need a nudge to
+//! follow up on.
+
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use super::advancement::Award;
+
+/// Default days from nomination until its follow-up reminder
+pub const DEFAULT_REMINDER_DAYS: i64 = 14;
+
+/// Common special awards listed even when no scout holds them yet, so they
+/// can be nominated for.
+pub const COMMON_AWARDS: &[&str] = &[
+    "50-Miler",
+    "Mile Swim BSA",
+    "Totin' Chip",
+    "Firem'n Chit",
+    "Religious Emblem",
+    "World Conservation Award",
+    "National Outdoor Badge for Camping",
+    "Paul Bunyan Woodsman",
+];
+
+/// One award and who holds or is working on it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AwardCatalogEntry {
+    pub name: String,
+    /// Scouts who have been awarded it
+    pub holders: Vec<i64>,
+    /// Scouts with it started but not yet awarded
+    pub in_progress: Vec<i64>,
+}
+
+/// Build the catalog from each scout's awards, plus `COMMON_AWARDS`, sorted by name.
+pub fn award_catalog(awards_by_youth: &HashMap<i64, Vec<Award>>) -> Vec<AwardCatalogEntry> {
+    let mut by_name: HashMap<String, AwardCatalogEntry> = COMMON_AWARDS
+        .iter()
+        .map(|name| {
+            let entry = AwardCatalogEntry { name: name.to_string(), holders: Vec::new(), in_progress: Vec::new() };
+            (name.to_string(), entry)
+        })
+        .collect();
+
+    for (&user_id, awards) in awards_by_youth {
+        for award in awards.iter().filter(|a| a.name.is_some()) {
+            let entry = by_name.entry(award.name().to_string()).or_insert_with(|| AwardCatalogEntry {
+                name: award.name().to_string(),
+                holders: Vec::new(),
+                in_progress: Vec::new(),
+            });
+            if award.is_awarded() {
+                entry.holders.push(user_id);
+            } else {
+                entry.in_progress.push(user_id);
+            }
+        }
+    }
+
+    let mut catalog: Vec<AwardCatalogEntry> = by_name.into_values().collect();
+    for entry in &mut catalog {
+        entry.holders.sort_unstable();
+        entry.in_progress.sort_unstable();
+    }
+    catalog.sort_by_key(|e| e.name.to_lowercase());
+    catalog
+}
+
+/// A scout put forward for an award, awaiting paperwork or approval.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Nomination {
+    pub user_id: i64,
+    pub award: String,
+    pub nominated_on: NaiveDate,
+    /// Date to follow up on the nomination
+    pub remind_on: Option<NaiveDate>,
+}
+
+impl Nomination {
+    pub fn is_due(&self, today: NaiveDate) -> bool {
+        self.remind_on.is_some_and(|d| d <= today)
+    }
+}
+
+/// Persisted nominations, in the order they were made.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Nominations {
+    pub entries: Vec<Nomination>,
+}
+
+impl Nominations {
+    /// Nominate a scout, replacing any existing nomination for the same award.
+    pub fn nominate(&mut self, user_id: i64, award: &str, today: NaiveDate) {
+        self.withdraw(user_id, award);
+        self.entries.push(Nomination {
+            user_id,
+            award: award.to_string(),
+            nominated_on: today,
+            remind_on: Some(today + chrono::Duration::days(DEFAULT_REMINDER_DAYS)),
+        });
+    }
+
+    /// Remove a nomination. Returns false if there wasn't one.
+    pub fn withdraw(&mut self, user_id: i64, award: &str) -> bool {
+        let before = self.entries.len();
+        self.entries.retain(|n| !(n.user_id == user_id && n.award == award));
+        self.entries.len() != before
+    }
+
+    /// Drop every nomination for a scout. Returns how many were removed.
+    pub fn remove_scout(&mut self, user_id: i64) -> usize {
+        let before = self.entries.len();
+        self.entries.retain(|n| n.user_id != user_id);
+        before - self.entries.len()
+    }
+
+    pub fn for_award<'a>(&'a self, award: &'a str) -> impl Iterator<Item = &'a Nomination> {
+        self.entries.iter().filter(move |n| n.award == award)
+    }
+
+    /// Nominations whose reminder date has arrived.
+    pub fn due(&self, today: NaiveDate) -> Vec<&Nomination> {
+        self.entries.iter().filter(|n| n.is_due(today)).collect()
+    }
+
+    /// Drop nominations for awards the scout now holds.
+    pub fn prune_awarded(&mut self, catalog: &[AwardCatalogEntry]) {
+        self.entries.retain(|n| {
+            !catalog
+                .iter()
+                .any(|e| e.name == n.award && e.holders.contains(&n.user_id))
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn award(name: &str, awarded: bool) -> Award {
+        Award { name: Some(name.to_string()), awarded: Some(awarded), ..Default::default() }
+    }
+
+    #[test]
+    fn test_award_catalog_groups_holders() {
+        let mut by_youth = HashMap::new();
+        by_youth.insert(1, vec![award("50-Miler", true), award("Ad Altare Dei", false)]);
+        by_youth.insert(2, vec![award("50-Miler", true)]);
+
+        let catalog = award_catalog(&by_youth);
+        let fifty = catalog.iter().find(|e| e.name == "50-Miler").unwrap();
+        assert_eq!(fifty.holders, vec![1, 2]);
+
+        let emblem = catalog.iter().find(|e| e.name == "Ad Altare Dei").unwrap();
+        assert_eq!(emblem.in_progress, vec![1]);
+
+        assert!(catalog.iter().any(|e| e.name == "Mile Swim BSA" && e.holders.is_empty()));
+        assert_eq!(catalog[0].name, "50-Miler", "sorted by name");
+    }
+
+    #[test]
+    fn test_nominations_due_and_pruned() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 1).unwrap();
+        let mut noms = Nominations::default();
+        noms.nominate(1, "Mile Swim BSA", today);
+        noms.nominate(1, "Mile Swim BSA", today);
+        noms.nominate(2, "50-Miler", today);
+        assert_eq!(noms.entries.len(), 2, "re-nominating replaces");
+
+        assert!(noms.due(today).is_empty());
+        assert_eq!(noms.due(today + chrono::Duration::days(DEFAULT_REMINDER_DAYS)).len(), 2);
+
+        let mut by_youth = HashMap::new();
+        by_youth.insert(2, vec![award("50-Miler", true)]);
+        noms.prune_awarded(&award_catalog(&by_youth));
+        assert_eq!(noms.entries.len(), 1);
+        assert!(noms.withdraw(1, "Mile Swim BSA"));
+        assert!(!noms.withdraw(1, "Mile Swim BSA"));
+    }
+}
@@ -0,0 +1,119 @@
+//! Buddy pairs for swims and hikes.
+//!
+//! Scouts are paired within their patrol when they're close in age, and
+//! everyone left over is paired across patrols by age. An odd scout out
+//! joins the nearest-aged pair as a trio.
+
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+
+use super::person::Youth;
+
+/// Largest age gap for pairing two scouts from the same patrol
+pub const MAX_PATROL_AGE_GAP_DAYS: i64 = 2 * 365;
+
+/// Buddy groups of two (or three) scouts, by user ID.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BuddyGroups {
+    pub groups: Vec<Vec<i64>>,
+}
+
+fn close_in_age(a: Option<NaiveDate>, b: Option<NaiveDate>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => (a - b).num_days().abs() <= MAX_PATROL_AGE_GAP_DAYS,
+        _ => true,
+    }
+}
+
+fn age_gap(a: Option<NaiveDate>, b: Option<NaiveDate>) -> i64 {
+    match (a, b) {
+        (Some(a), Some(b)) => (a - b).num_days().abs(),
+        _ => i64::MAX,
+    }
+}
+
+impl BuddyGroups {
+    /// Pair up `scouts`. Scouts without a user ID are skipped.
+    pub fn pair(scouts: &[&Youth]) -> Self {
+        let mut by_patrol: BTreeMap<&str, Vec<(i64, Option<NaiveDate>)>> = BTreeMap::new();
+        for youth in scouts {
+            if let Some(id) = youth.user_id {
+                by_patrol.entry(youth.patrol_name.as_deref().unwrap_or_default()).or_default().push((id, youth.date_of_birth()));
+            }
+        }
+
+        let mut groups = Vec::new();
+        let mut pool = Vec::new();
+        for (_, mut members) in by_patrol {
+            members.sort_by_key(|&(id, dob)| (dob, id));
+            let mut rest = members.into_iter().peekable();
+            while let Some(a) = rest.next() {
+                match rest.peek() {
+                    Some(&b) if close_in_age(a.1, b.1) => {
+                        groups.push(vec![a, b]);
+                        rest.next();
+                    }
+                    _ => pool.push(a),
+                }
+            }
+        }
+
+        pool.sort_by_key(|&(id, dob)| (dob, id));
+        groups.extend(pool.chunks(2).map(|c| c.to_vec()));
+        if groups.len() > 1 && groups.last().is_some_and(|g| g.len() == 1) {
+            let lone = groups.pop().unwrap_or_default()[0];
+            if let Some(nearest) = groups.iter_mut().min_by_key(|g| g.iter().map(|m| age_gap(m.1, lone.1)).min()) {
+                nearest.push(lone);
+            }
+        }
+
+        Self { groups: groups.into_iter().map(|g| g.into_iter().map(|(id, _)| id).collect()).collect() }
+    }
+
+    /// Exchange two scouts between their groups.
+    pub fn swap(&mut self, a: i64, b: i64) {
+        for member in self.groups.iter_mut().flatten() {
+            if *member == a {
+                *member = b;
+            } else if *member == b {
+                *member = a;
+            }
+        }
+    }
+
+    /// Rows of (group number from 1, user ID), in group order.
+    pub fn rows(&self) -> Vec<(usize, i64)> {
+        self.groups
+            .iter()
+            .enumerate()
+            .flat_map(|(i, g)| g.iter().map(move |&id| (i + 1, id)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pairs_by_patrol_then_age() {
+        let youth: Vec<Youth> = serde_json::from_value(serde_json::json!([
+            { "userId": 1, "firstName": "A", "lastName": "A", "subUnitName": "Eagles", "registrarInfo": { "dateOfBirth": "2012-03-01" } },
+            { "userId": 2, "firstName": "B", "lastName": "B", "subUnitName": "Eagles", "registrarInfo": { "dateOfBirth": "2012-09-01" } },
+            { "userId": 3, "firstName": "C", "lastName": "C", "subUnitName": "Eagles", "registrarInfo": { "dateOfBirth": "2009-01-01" } },
+            { "userId": 4, "firstName": "D", "lastName": "D", "subUnitName": "Hawks", "registrarInfo": { "dateOfBirth": "2009-06-01" } },
+            { "userId": 5, "firstName": "E", "lastName": "E", "subUnitName": "Hawks", "registrarInfo": { "dateOfBirth": "2013-01-01" } }
+        ]))
+        .unwrap();
+        let scouts: Vec<&Youth> = youth.iter().collect();
+
+        let mut buddies = BuddyGroups::pair(&scouts);
+        // 3/4 are too old for their patrol mates; 5 is left over and joins
+        // the pair nearest in age
+        assert_eq!(buddies.groups, [vec![1, 2, 5], vec![3, 4]]);
+
+        buddies.swap(2, 4);
+        assert_eq!(buddies.rows(), [(1, 1), (1, 4), (1, 5), (2, 3), (2, 2)]);
+    }
+}
@@ -0,0 +1,161 @@
+//! Merit badge day / clinic planning.
+//!
+//! A clinic is a day of merit badge classes, each with a counselor and the
+//! scouts registered for it. Scouts who finish a class are marked complete
+//! so the day's completions can go out as sign-off sheets afterward. One
+//! clinic is planned at a time and kept locally.
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// Clinic name until one is typed
+pub const DEFAULT_CLINIC_NAME: &str = "Merit Badge Clinic";
+
+/// One merit badge class at the clinic.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClinicClass {
+    pub badge: String,
+    #[serde(default)]
+    pub counselor: String,
+    /// Registered scouts, in the order they signed up
+    #[serde(default)]
+    pub scouts: Vec<i64>,
+    /// Registered scouts who finished the badge at the clinic
+    #[serde(default)]
+    pub completed: Vec<i64>,
+}
+
+impl ClinicClass {
+    pub fn is_completed(&self, user_id: i64) -> bool {
+        self.completed.contains(&user_id)
+    }
+}
+
+/// The clinic being planned.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Clinic {
+    pub name: String,
+    #[serde(default)]
+    pub date: Option<NaiveDate>,
+    #[serde(default)]
+    pub classes: Vec<ClinicClass>,
+}
+
+impl Default for Clinic {
+    fn default() -> Self {
+        Self { name: DEFAULT_CLINIC_NAME.to_string(), date: None, classes: Vec::new() }
+    }
+}
+
+impl Clinic {
+    /// Add a class for `badge`. Returns its index, or `None` if the name is
+    /// blank or the badge is already offered.
+    pub fn add_class(&mut self, badge: &str) -> Option<usize> {
+        let badge = badge.trim();
+        if badge.is_empty() || self.classes.iter().any(|c| c.badge.eq_ignore_ascii_case(badge)) {
+            return None;
+        }
+        self.classes.push(ClinicClass {
+            badge: badge.to_string(),
+            counselor: String::new(),
+            scouts: Vec::new(),
+            completed: Vec::new(),
+        });
+        Some(self.classes.len() - 1)
+    }
+
+    pub fn remove_class(&mut self, index: usize) -> Option<ClinicClass> {
+        (index < self.classes.len()).then(|| self.classes.remove(index))
+    }
+
+    /// Register a scout for a class, or drop them if they're registered.
+    /// Returns whether they're registered now.
+    pub fn toggle_registration(&mut self, index: usize, user_id: i64) -> bool {
+        let Some(class) = self.classes.get_mut(index) else {
+            return false;
+        };
+        if class.scouts.contains(&user_id) {
+            class.scouts.retain(|&id| id != user_id);
+            class.completed.retain(|&id| id != user_id);
+            return false;
+        }
+        class.scouts.push(user_id);
+        true
+    }
+
+    /// Mark a registered scout as having finished the class, or not.
+    /// Returns whether they're complete, or `None` if they aren't registered.
+    pub fn toggle_completed(&mut self, index: usize, user_id: i64) -> Option<bool> {
+        let class = self.classes.get_mut(index).filter(|c| c.scouts.contains(&user_id))?;
+        if class.is_completed(user_id) {
+            class.completed.retain(|&id| id != user_id);
+            return Some(false);
+        }
+        class.completed.push(user_id);
+        Some(true)
+    }
+
+    /// Classes a scout is registered for.
+    pub fn classes_for(&self, user_id: i64) -> impl Iterator<Item = &ClinicClass> {
+        self.classes.iter().filter(move |c| c.scouts.contains(&user_id))
+    }
+
+    /// Every scout who finished a class, with the classes they finished.
+    pub fn completions(&self) -> Vec<(i64, Vec<&ClinicClass>)> {
+        let mut by_scout: Vec<(i64, Vec<&ClinicClass>)> = Vec::new();
+        for class in &self.classes {
+            for &user_id in &class.completed {
+                match by_scout.iter_mut().find(|(id, _)| *id == user_id) {
+                    Some((_, classes)) => classes.push(class),
+                    None => by_scout.push((user_id, vec![class])),
+                }
+            }
+        }
+        by_scout
+    }
+
+    pub fn remove_scout(&mut self, user_id: i64) -> bool {
+        let mut removed = false;
+        for class in &mut self.classes {
+            let before = class.scouts.len();
+            class.scouts.retain(|&id| id != user_id);
+            class.completed.retain(|&id| id != user_id);
+            removed |= class.scouts.len() != before;
+        }
+        removed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clinic_registration_and_completions() {
+        let mut clinic = Clinic::default();
+        assert_eq!(clinic.add_class("First Aid"), Some(0));
+        assert_eq!(clinic.add_class("Chess"), Some(1));
+        assert_eq!(clinic.add_class("first aid"), None, "already offered");
+        assert_eq!(clinic.add_class("  "), None);
+
+        assert!(clinic.toggle_registration(0, 7));
+        assert!(clinic.toggle_registration(1, 7));
+        assert!(clinic.toggle_registration(1, 8));
+        assert_eq!(clinic.toggle_completed(0, 8), None, "not registered");
+        assert_eq!(clinic.toggle_completed(0, 7), Some(true));
+        assert_eq!(clinic.toggle_completed(1, 7), Some(true));
+        assert_eq!(clinic.toggle_completed(1, 8), Some(true));
+        assert_eq!(clinic.classes_for(7).count(), 2);
+
+        let completions = clinic.completions();
+        assert_eq!(completions.len(), 2);
+        assert_eq!(completions[0].0, 7);
+        assert_eq!(completions[0].1.len(), 2);
+
+        assert!(!clinic.toggle_registration(1, 8), "dropping a scout clears their completion");
+        assert_eq!(clinic.completions().len(), 1);
+        assert!(clinic.remove_scout(7));
+        assert!(clinic.completions().is_empty());
+        assert_eq!(clinic.remove_class(1).map(|c| c.badge), Some("Chess".to_string()));
+    }
+}
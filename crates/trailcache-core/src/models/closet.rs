@@ -0,0 +1,152 @@
+//! Uniform closet and gear exchange.
+//!
+//! Families donate outgrown uniforms and gear, and the committee hands them
+//! on to scouts who need them. Each donated item is kept locally with its
+//! type and size, and every checkout is kept as history. Items are typed as
+//! "type, size, note": "Field shirt, Youth M, missing a button".
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// One time an item went out to a scout.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Checkout {
+    pub user_id: i64,
+    pub out_on: NaiveDate,
+    pub returned_on: Option<NaiveDate>,
+}
+
+/// A donated uniform part or piece of gear.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClosetItem {
+    pub id: u32,
+    /// What it is, e.g. "Field shirt" or "Hiking boots"
+    pub kind: String,
+    pub size: String,
+    #[serde(default)]
+    pub note: String,
+    pub donated_on: NaiveDate,
+    /// Oldest first
+    #[serde(default)]
+    pub checkouts: Vec<Checkout>,
+}
+
+impl ClosetItem {
+    /// The checkout still out, if the item isn't in the closet.
+    pub fn current(&self) -> Option<&Checkout> {
+        self.checkouts.last().filter(|c| c.returned_on.is_none())
+    }
+
+    pub fn is_available(&self) -> bool {
+        self.current().is_none()
+    }
+
+    /// Fields searched by the closet filter: type, size, note.
+    pub fn search_fields(&self) -> Vec<String> {
+        vec![self.kind.clone(), self.size.clone(), self.note.clone()]
+    }
+}
+
+/// Persisted closet inventory, in the order items were donated.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UniformCloset {
+    pub items: Vec<ClosetItem>,
+    #[serde(default)]
+    next_id: u32,
+}
+
+impl UniformCloset {
+    /// Add a donated item typed as "type, size, note". Returns its ID, or
+    /// `None` without a type and size.
+    pub fn add(&mut self, input: &str, today: NaiveDate) -> Option<u32> {
+        let mut parts = input.splitn(3, ',').map(str::trim);
+        let kind = parts.next().filter(|k| !k.is_empty())?;
+        let size = parts.next().filter(|s| !s.is_empty())?;
+        let note = parts.next().unwrap_or_default();
+        self.next_id = self.next_id.max(self.items.iter().map(|i| i.id).max().unwrap_or(0)) + 1;
+        self.items.push(ClosetItem {
+            id: self.next_id,
+            kind: kind.to_string(),
+            size: size.to_string(),
+            note: note.to_string(),
+            donated_on: today,
+            checkouts: Vec::new(),
+        });
+        Some(self.next_id)
+    }
+
+    pub fn get(&self, id: u32) -> Option<&ClosetItem> {
+        self.items.iter().find(|i| i.id == id)
+    }
+
+    /// Give an item in the closet to a scout. Returns false if it's out.
+    pub fn check_out(&mut self, id: u32, user_id: i64, today: NaiveDate) -> bool {
+        match self.items.iter_mut().find(|i| i.id == id) {
+            Some(item) if item.is_available() => {
+                item.checkouts.push(Checkout { user_id, out_on: today, returned_on: None });
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Put an item back in the closet. Returns who had it.
+    pub fn check_in(&mut self, id: u32, today: NaiveDate) -> Option<i64> {
+        let checkout = self.items.iter_mut().find(|i| i.id == id)?.checkouts.last_mut().filter(|c| c.returned_on.is_none())?;
+        checkout.returned_on = Some(today);
+        Some(checkout.user_id)
+    }
+
+    /// Remove an item outright, e.g. when it's worn out.
+    pub fn remove(&mut self, id: u32) -> bool {
+        let before = self.items.len();
+        self.items.retain(|i| i.id != id);
+        self.items.len() != before
+    }
+
+    /// Forget a scout's returned checkouts, e.g. when their data is purged.
+    /// Items they still have stay recorded. Returns how many were removed.
+    pub fn remove_scout(&mut self, user_id: i64) -> usize {
+        let mut count = 0;
+        for item in &mut self.items {
+            let before = item.checkouts.len();
+            item.checkouts.retain(|c| c.user_id != user_id || c.returned_on.is_none());
+            count += before - item.checkouts.len();
+        }
+        count
+    }
+
+    /// Items a scout has now.
+    pub fn held_by(&self, user_id: i64) -> impl Iterator<Item = &ClosetItem> {
+        self.items.iter().filter(move |i| i.current().is_some_and(|c| c.user_id == user_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_closet_checkouts() {
+        let today = NaiveDate::from_ymd_opt(2026, 9, 1).unwrap();
+        let mut closet = UniformCloset::default();
+        assert_eq!(closet.add("Field shirt, Youth M, missing a button", today), Some(1));
+        assert_eq!(closet.add("Hiking boots, 9", today), Some(2));
+        assert_eq!(closet.add("Neckerchief", today), None, "size is required");
+        assert_eq!(closet.get(1).unwrap().note, "missing a button");
+
+        assert!(closet.check_out(1, 7, today));
+        assert!(!closet.check_out(1, 8, today), "already out");
+        assert_eq!(closet.held_by(7).count(), 1);
+        assert_eq!(closet.check_in(1, today), Some(7));
+        assert_eq!(closet.check_in(1, today), None);
+        assert!(closet.check_out(1, 8, today));
+        assert_eq!(closet.get(1).unwrap().checkouts.len(), 2, "history is kept");
+
+        assert_eq!(closet.remove_scout(7), 1);
+        assert_eq!(closet.remove_scout(8), 0, "still has the shirt");
+
+        assert!(closet.remove(2));
+        assert_eq!(closet.add("Belt, Adult L", today), Some(3), "IDs aren't reused");
+    }
+}
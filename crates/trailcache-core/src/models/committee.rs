@@ -0,0 +1,187 @@
+//! Troop committee role matrix.
+//!
+//! A troop committee should have someone covering each of its functions.
+//! Scoutbook positions fill most of the matrix ("Treasurer", "Advancement
+//! Chair"), but many committees split the work informally, so an adult can
+//! be assigned to a role locally, or taken off one their position implies.
+
+use serde::{Deserialize, Serialize};
+
+use super::person::Adult;
+
+/// A committee function that should have someone assigned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum CommitteeRole {
+    Chair,
+    Secretary,
+    Treasurer,
+    Advancement,
+    Outdoor,
+    Membership,
+    Fundraising,
+    Training,
+}
+
+impl CommitteeRole {
+    pub const ALL: [CommitteeRole; 8] = [
+        CommitteeRole::Chair,
+        CommitteeRole::Secretary,
+        CommitteeRole::Treasurer,
+        CommitteeRole::Advancement,
+        CommitteeRole::Outdoor,
+        CommitteeRole::Membership,
+        CommitteeRole::Fundraising,
+        CommitteeRole::Training,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            CommitteeRole::Chair => "Committee Chair",
+            CommitteeRole::Secretary => "Secretary",
+            CommitteeRole::Treasurer => "Treasurer",
+            CommitteeRole::Advancement => "Advancement",
+            CommitteeRole::Outdoor => "Outdoor/Activities",
+            CommitteeRole::Membership => "Membership",
+            CommitteeRole::Fundraising => "Fundraising",
+            CommitteeRole::Training => "Training",
+        }
+    }
+
+    /// Column heading for the matrix.
+    pub fn abbreviation(&self) -> &'static str {
+        match self {
+            CommitteeRole::Chair => "Chr",
+            CommitteeRole::Secretary => "Sec",
+            CommitteeRole::Treasurer => "Trs",
+            CommitteeRole::Advancement => "Adv",
+            CommitteeRole::Outdoor => "Out",
+            CommitteeRole::Membership => "Mem",
+            CommitteeRole::Fundraising => "Fnd",
+            CommitteeRole::Training => "Trn",
+        }
+    }
+
+    /// Position-name fragments (lowercase) that cover the role.
+    fn keywords(&self) -> &'static [&'static str] {
+        match self {
+            CommitteeRole::Chair => &["committee chair"],
+            CommitteeRole::Secretary => &["secretary"],
+            CommitteeRole::Treasurer => &["treasurer"],
+            CommitteeRole::Advancement => &["advancement"],
+            CommitteeRole::Outdoor => &["outdoor", "activities"],
+            CommitteeRole::Membership => &["membership", "new member coordinator"],
+            CommitteeRole::Fundraising => &["fundrais", "popcorn"],
+            CommitteeRole::Training => &["training"],
+        }
+    }
+
+    /// Whether the adult's Scoutbook position covers the role.
+    pub fn from_position(&self, adult: &Adult) -> bool {
+        let Some(position) = adult.position.as_deref() else {
+            return false;
+        };
+        let position = position.to_lowercase();
+        self.keywords().iter().any(|k| position.contains(k))
+    }
+}
+
+/// A local change to what an adult's position implies.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoleOverride {
+    pub role: CommitteeRole,
+    pub person_guid: String,
+    /// Assigned locally, or taken off a role their position implies
+    pub assigned: bool,
+}
+
+/// Local committee role overrides.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CommitteeRoles {
+    pub overrides: Vec<RoleOverride>,
+}
+
+impl CommitteeRoles {
+    fn override_for(&self, role: CommitteeRole, adult: &Adult) -> Option<&RoleOverride> {
+        let guid = adult.person_guid.as_deref()?;
+        self.overrides.iter().find(|o| o.role == role && o.person_guid == guid)
+    }
+
+    pub fn is_assigned(&self, role: CommitteeRole, adult: &Adult) -> bool {
+        self.override_for(role, adult).map(|o| o.assigned).unwrap_or_else(|| role.from_position(adult))
+    }
+
+    /// Assign or unassign an adult. An override that matches their position
+    /// is dropped rather than kept. Returns whether they're assigned now, or
+    /// `None` if the adult has no person GUID.
+    pub fn toggle(&mut self, role: CommitteeRole, adult: &Adult) -> Option<bool> {
+        let guid = adult.person_guid.clone()?;
+        let assigned = !self.is_assigned(role, adult);
+        self.overrides.retain(|o| !(o.role == role && o.person_guid == guid));
+        if assigned != role.from_position(adult) {
+            self.overrides.push(RoleOverride { role, person_guid: guid, assigned });
+        }
+        Some(assigned)
+    }
+
+    /// Adults assigned to a role.
+    pub fn assigned<'a>(&self, role: CommitteeRole, adults: &'a [Adult]) -> Vec<&'a Adult> {
+        adults.iter().filter(|a| self.is_assigned(role, a)).collect()
+    }
+
+    /// Roles with nobody assigned.
+    pub fn unfilled(&self, adults: &[Adult]) -> Vec<CommitteeRole> {
+        CommitteeRole::ALL.into_iter().filter(|&r| !adults.iter().any(|a| self.is_assigned(r, a))).collect()
+    }
+
+    /// Adults shown in the matrix: committee members and anyone holding or
+    /// assigned a role.
+    pub fn members<'a>(&self, adults: &'a [Adult]) -> Vec<&'a Adult> {
+        adults
+            .iter()
+            .filter(|a| {
+                a.position.as_deref().is_some_and(|p| p.to_lowercase().contains("committee"))
+                    || CommitteeRole::ALL.iter().any(|&r| self.is_assigned(r, a))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn adult(guid: &str, position: &str) -> Adult {
+        serde_json::from_value(serde_json::json!({
+            "personGuid": guid, "firstName": "Pat", "lastName": guid, "position": position
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_committee_roles_from_positions_and_overrides() {
+        let adults = vec![
+            adult("A", "Committee Chair"),
+            adult("B", "Committee Member, Treasurer"),
+            adult("C", "Unit Advancement Chair"),
+            adult("D", "Assistant Scoutmaster"),
+        ];
+        let mut roles = CommitteeRoles::default();
+        assert!(roles.is_assigned(CommitteeRole::Treasurer, &adults[1]));
+        assert_eq!(roles.members(&adults).len(), 3);
+        assert_eq!(
+            roles.unfilled(&adults),
+            [CommitteeRole::Secretary, CommitteeRole::Outdoor, CommitteeRole::Membership, CommitteeRole::Fundraising, CommitteeRole::Training]
+        );
+
+        // The ASM handles outdoor planning; the advancement chair handed off
+        assert_eq!(roles.toggle(CommitteeRole::Outdoor, &adults[3]), Some(true));
+        assert_eq!(roles.toggle(CommitteeRole::Advancement, &adults[2]), Some(false));
+        assert_eq!(roles.members(&adults).len(), 3, "the ASM joins, the advancement chair leaves");
+        assert!(roles.unfilled(&adults).contains(&CommitteeRole::Advancement));
+        assert_eq!(roles.overrides.len(), 2);
+
+        assert_eq!(roles.toggle(CommitteeRole::Advancement, &adults[2]), Some(true));
+        assert_eq!(roles.overrides.len(), 1, "back to what the position says");
+        assert_eq!(roles.assigned(CommitteeRole::Outdoor, &adults).len(), 1);
+    }
+}
@@ -0,0 +1,102 @@
+//! Locally tracked annual consent forms.
+//!
+//! Families sign an activity consent and a media release each year. Scoutbook
+//! doesn't track either, so signatures are recorded here and checked when
+//! printing event emergency sheets or exporting rosters.
+
+use chrono::{Months, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+/// A signature counts for this long
+const CONSENT_VALID_MONTHS: u32 = 12;
+
+/// Annual forms a family signs for their scout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ConsentForm {
+    ActivityConsent,
+    MediaRelease,
+}
+
+impl ConsentForm {
+    pub const ALL: [ConsentForm; 2] = [ConsentForm::ActivityConsent, ConsentForm::MediaRelease];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ConsentForm::ActivityConsent => "Activity consent",
+            ConsentForm::MediaRelease => "Media release",
+        }
+    }
+}
+
+/// A form signed for one scout.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SignedConsent {
+    pub user_id: i64,
+    pub form: ConsentForm,
+    pub signed_on: NaiveDate,
+}
+
+/// Persisted consent signatures for the whole troop.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Consents {
+    pub signed: Vec<SignedConsent>,
+}
+
+impl Consents {
+    /// Record a signature, replacing any earlier one for the same form.
+    pub fn sign(&mut self, user_id: i64, form: ConsentForm, signed_on: NaiveDate) {
+        self.revoke(user_id, form);
+        self.signed.push(SignedConsent { user_id, form, signed_on });
+    }
+
+    pub fn revoke(&mut self, user_id: i64, form: ConsentForm) -> bool {
+        let before = self.signed.len();
+        self.signed.retain(|s| !(s.user_id == user_id && s.form == form));
+        self.signed.len() != before
+    }
+
+    pub fn signed_on(&self, user_id: i64, form: ConsentForm) -> Option<NaiveDate> {
+        self.signed
+            .iter()
+            .find(|s| s.user_id == user_id && s.form == form)
+            .map(|s| s.signed_on)
+    }
+
+    /// Whether the form was signed within the last year.
+    pub fn is_current(&self, user_id: i64, form: ConsentForm, today: NaiveDate) -> bool {
+        self.signed_on(user_id, form)
+            .and_then(|d| d.checked_add_months(Months::new(CONSENT_VALID_MONTHS)))
+            .is_some_and(|expires| expires > today)
+    }
+
+    /// Scouts among `user_ids` without a current signature for `form`.
+    pub fn missing(&self, form: ConsentForm, user_ids: impl IntoIterator<Item = i64>, today: NaiveDate) -> Vec<i64> {
+        user_ids
+            .into_iter()
+            .filter(|&id| !self.is_current(id, form, today))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_consent_expires_after_a_year() {
+        let signed = NaiveDate::from_ymd_opt(2025, 9, 15).unwrap();
+        let mut consents = Consents::default();
+        consents.sign(1, ConsentForm::MediaRelease, signed);
+        consents.sign(2, ConsentForm::ActivityConsent, signed);
+
+        let before = NaiveDate::from_ymd_opt(2026, 9, 14).unwrap();
+        let after = NaiveDate::from_ymd_opt(2026, 9, 15).unwrap();
+        assert_eq!(consents.missing(ConsentForm::MediaRelease, [1, 2], before), [2]);
+        assert_eq!(consents.missing(ConsentForm::MediaRelease, [1, 2], after), [1, 2]);
+
+        consents.sign(1, ConsentForm::MediaRelease, after);
+        assert_eq!(consents.signed.len(), 2, "re-signing replaces the old signature");
+        assert!(consents.revoke(2, ConsentForm::ActivityConsent));
+        assert!(!consents.is_current(2, ConsentForm::ActivityConsent, before));
+    }
+}
@@ -0,0 +1,198 @@
+//! Contact data checks.
+//!
+//! Scoutbook messages go to the emails on file, so a scout whose parents
+//! have no email, or an address with a typo, quietly misses announcements.
+//! This scans cached contact details for problems to fix in Scoutbook:
+//! scouts with no parent email, emails and phone numbers that can't be
+//! right, and one email shared by people in different families.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
+
+use super::member_id::{adult_match, parent_match, youth_match, MemberKind, MemberMatch};
+use super::person::{Adult, Parent, Youth};
+use crate::utils::phone::{PhoneFormat, PhoneNumber};
+
+/// What's wrong with someone's contact details.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContactIssue {
+    /// A scout none of whose parents has an email on file
+    NoParentEmail,
+    InvalidEmail(String),
+    InvalidPhone(String),
+    /// The email, and the people in other families who also have it
+    SharedEmail(String, Vec<String>),
+}
+
+impl fmt::Display for ContactIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ContactIssue::NoParentEmail => f.write_str("no parent email"),
+            ContactIssue::InvalidEmail(email) => write!(f, "bad email {}", email),
+            ContactIssue::InvalidPhone(phone) => write!(f, "bad phone {}", phone),
+            ContactIssue::SharedEmail(email, others) => write!(f, "{} shared with {}", email, others.join(", ")),
+        }
+    }
+}
+
+/// Whether `email` could be a deliverable address: one @, something before
+/// it, and a dotted domain after it.
+pub fn is_valid_email(email: &str) -> bool {
+    let email = email.trim();
+    let Some((local, domain)) = email.split_once('@') else {
+        return false;
+    };
+    !local.is_empty()
+        && !domain.contains('@')
+        && !email.chars().any(|c| c.is_whitespace() || c == ',' || c == ';')
+        && domain.contains('.')
+        && !domain.starts_with('.')
+        && !domain.ends_with('.')
+        && !domain.contains("..")
+}
+
+/// Whether `phone` could be a US number: ten digits, optionally after a 1
+/// and before an extension, with an area code that doesn't start with 0 or 1.
+pub fn is_valid_phone(phone: &str) -> bool {
+    PhoneNumber::parse(phone).is_some_and(|n| !n.area_code.starts_with(['0', '1']))
+}
+
+fn non_empty(value: Option<&str>) -> Option<&str> {
+    value.map(str::trim).filter(|v| !v.is_empty())
+}
+
+/// Everyone with a contact problem, scouts first, then adults and parents,
+/// then emails shared across families. Parents listed under several scouts
+/// are checked once.
+pub fn contact_problems(youth: &[Youth], adults: &[Adult], parents: &[Parent]) -> Vec<(MemberMatch, ContactIssue)> {
+    let mut problems = Vec::new();
+    let mut unique_parents: Vec<&Parent> = Vec::new();
+    for p in parents {
+        if p.user_id.is_none() || !unique_parents.iter().any(|u| u.user_id == p.user_id) {
+            unique_parents.push(p);
+        }
+    }
+
+    // Contact details as (person, email, phones). Only numbers that don't
+    // parse are reported, and those are kept as entered in any format.
+    let format = PhoneFormat::default();
+    let mut people: Vec<(MemberMatch, Option<String>, Vec<String>)> = Vec::new();
+    people.extend(youth.iter().map(|y| (youth_match(y), y.email(), y.phone(format).into_iter().collect())));
+    people.extend(adults.iter().map(|a| (adult_match(a), a.email(), a.phone(format).into_iter().collect())));
+    people.extend(unique_parents.iter().map(|p| {
+        let phones = [&p.mobile_phone, &p.home_phone].into_iter().filter_map(|ph| non_empty(ph.as_deref()).map(str::to_string)).collect();
+        (parent_match(p), p.email.clone(), phones)
+    }));
+
+    for y in youth {
+        let has_parent_email = parents
+            .iter()
+            .filter(|p| p.youth_user_id.is_some() && p.youth_user_id == y.user_id)
+            .any(|p| non_empty(p.email.as_deref()).is_some());
+        if !has_parent_email {
+            problems.push((youth_match(y), ContactIssue::NoParentEmail));
+        }
+    }
+    for (person, email, phones) in &people {
+        if let Some(email) = non_empty(email.as_deref()).filter(|e| !is_valid_email(e)) {
+            problems.push((person.clone(), ContactIssue::InvalidEmail(email.to_string())));
+        }
+        for phone in phones.iter().filter(|ph| !is_valid_phone(ph)) {
+            problems.push((person.clone(), ContactIssue::InvalidPhone(phone.clone())));
+        }
+    }
+    problems.sort_by_key(|(m, _)| m.kind as u8);
+
+    // Families: a parent's scouts, and a scout with their siblings. Adults
+    // who aren't also parents are a family of their own.
+    let mut scouts_of: HashMap<i64, BTreeSet<i64>> = HashMap::new();
+    for p in parents {
+        if let (Some(parent), Some(scout)) = (p.user_id, p.youth_user_id) {
+            scouts_of.entry(parent).or_default().insert(scout);
+        }
+    }
+    let family = |person: &MemberMatch| -> BTreeSet<i64> {
+        let id = person.user_id.unwrap_or_default();
+        match person.kind {
+            MemberKind::Youth => {
+                let mut family: BTreeSet<i64> = scouts_of.values().filter(|s| s.contains(&id)).flatten().copied().collect();
+                family.insert(id);
+                family
+            }
+            _ => scouts_of.get(&id).cloned().unwrap_or_else(|| BTreeSet::from([-id])),
+        }
+    };
+
+    let mut by_email: BTreeMap<String, Vec<(&MemberMatch, BTreeSet<i64>)>> = BTreeMap::new();
+    for (person, email, _) in &people {
+        if let Some(email) = non_empty(email.as_deref()).filter(|e| is_valid_email(e)) {
+            by_email.entry(email.to_lowercase()).or_default().push((person, family(person)));
+        }
+    }
+    for (email, holders) in &by_email {
+        for (person, fam) in holders {
+            let mut others: Vec<String> = holders
+                .iter()
+                .filter(|(other, other_fam)| other.user_id != person.user_id && fam.is_disjoint(other_fam))
+                .map(|(other, _)| other.name.clone())
+                .collect();
+            others.dedup();
+            if !others.is_empty() {
+                problems.push(((*person).clone(), ContactIssue::SharedEmail(email.clone(), others)));
+            }
+        }
+    }
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_email_and_phone_formats() {
+        assert!(is_valid_email("pat.smith@example.com"));
+        assert!(!is_valid_email("pat.smith@example"));
+        assert!(!is_valid_email("pat smith@example.com"));
+        assert!(!is_valid_email("pat@@example.com"));
+        assert!(!is_valid_email("@example.com"));
+        assert!(is_valid_phone("(555) 867-5309"));
+        assert!(is_valid_phone("1-555-867-5309"));
+        assert!(!is_valid_phone("867-5309"));
+        assert!(!is_valid_phone("(055) 867-5309"));
+        assert!(is_valid_phone("555-867-5309 x12"));
+    }
+
+    #[test]
+    fn test_contact_problems() {
+        let youth: Vec<Youth> = serde_json::from_value(serde_json::json!([
+            { "userId": 1, "firstName": "Sam", "lastName": "Smith", "email": "smiths@example.com" },
+            { "userId": 2, "firstName": "Sue", "lastName": "Smith" },
+            { "userId": 3, "firstName": "Al", "lastName": "Jones", "email": "smiths@example.com" },
+            { "userId": 4, "firstName": "Lee", "lastName": "Park" }
+        ]))
+        .unwrap();
+        let parents: Vec<Parent> = serde_json::from_value(serde_json::json!([
+            { "userId": 10, "firstName": "Pat", "lastName": "Smith", "email": "smiths@example.com",
+              "mobilePhone": "555-1234", "youthUserId": 1 },
+            { "userId": 10, "firstName": "Pat", "lastName": "Smith", "email": "smiths@example.com",
+              "mobilePhone": "555-1234", "youthUserId": 2 },
+            { "userId": 11, "firstName": "Kim", "lastName": "Jones", "email": "kim@jones", "youthUserId": 3 }
+        ]))
+        .unwrap();
+
+        let problems = contact_problems(&youth, &[], &parents);
+        let found: Vec<(Option<i64>, String)> = problems.iter().map(|(m, issue)| (m.user_id, issue.to_string())).collect();
+        assert_eq!(
+            found,
+            [
+                (Some(4), "no parent email".to_string()),
+                (Some(10), "bad phone 555-1234".to_string()),
+                (Some(11), "bad email kim@jones".to_string()),
+                (Some(1), "smiths@example.com shared with Jones, Al".to_string()),
+                (Some(3), "smiths@example.com shared with Smith, Sam, Smith, Pat".to_string()),
+                (Some(10), "smiths@example.com shared with Jones, Al".to_string()),
+            ]
+        );
+    }
+}
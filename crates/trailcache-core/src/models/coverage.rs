@@ -0,0 +1,149 @@
+//! Adult leadership coverage for an event, from its RSVPs.
+//!
+//! The Guide to Safe Scouting asks for two adults with current Youth
+//! Protection training on every outing, at least one of them a registered
+//! leader, a female adult whenever female youth attend, and someone trained
+//! in Hazardous Weather. Scoutbook doesn't record that last course where
+//! trailcache can read it, so it comes from the adult skills directory.
+
+use chrono::NaiveDate;
+
+use super::adult_skills::AdultSkills;
+use super::event::{Event, RsvpStatus};
+use super::person::{Adult, Youth};
+
+/// Adults needed for two-deep leadership
+pub const TWO_DEEP_ADULTS: usize = 2;
+
+/// The adult skill that covers the Hazardous Weather course.
+pub const HAZARDOUS_WEATHER_SKILL: &str = "Hazardous Weather";
+
+/// Something an event's adult coverage is missing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoverageGap {
+    /// Fewer than two adults going with current Youth Protection training
+    TwoDeep { trained: usize },
+    /// No one going from the adult roster
+    NoRegisteredLeader,
+    /// Female youth are going without a female adult
+    NoFemaleLeader,
+    /// No adult going with current Hazardous Weather training
+    NoHazardousWeather,
+}
+
+impl CoverageGap {
+    pub fn describe(&self) -> String {
+        match self {
+            CoverageGap::TwoDeep { trained } => {
+                format!("Two-deep: {} of {} adults with current YPT", trained, TWO_DEEP_ADULTS)
+            }
+            CoverageGap::NoRegisteredLeader => "No registered leader going".to_string(),
+            CoverageGap::NoFemaleLeader => "Female youth going without a female leader".to_string(),
+            CoverageGap::NoHazardousWeather => "No one going with Hazardous Weather training".to_string(),
+        }
+    }
+}
+
+fn is_female(gender: Option<&str>) -> bool {
+    gender.is_some_and(|g| g.eq_ignore_ascii_case("f") || g.eq_ignore_ascii_case("female"))
+}
+
+/// The adults going to an event and what their coverage is missing.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LeaderCoverage {
+    /// Names of the adults going
+    pub adults: Vec<String>,
+    pub gaps: Vec<CoverageGap>,
+}
+
+impl LeaderCoverage {
+    /// Check the RSVPs for `event` against the roster. Training has to be
+    /// current on the day the event starts, or `today` if it has no date.
+    pub fn check(event: &Event, youth: &[Youth], adults: &[Adult], skills: &AdultSkills, today: NaiveDate) -> Self {
+        let day = event.start_day().unwrap_or(today);
+        let going: Vec<_> = event.invited_users.iter().filter(|u| u.status() == RsvpStatus::Going).collect();
+        let going_adults: Vec<_> = going.iter().filter(|u| u.is_adult).collect();
+        let registered: Vec<&Adult> = going_adults
+            .iter()
+            .filter_map(|u| adults.iter().find(|a| a.user_id == Some(u.user_id)))
+            .collect();
+
+        let mut gaps = Vec::new();
+        let trained = registered.iter().filter(|a| a.ypt_expires().is_some_and(|d| d >= day)).count();
+        if trained < TWO_DEEP_ADULTS {
+            gaps.push(CoverageGap::TwoDeep { trained });
+        }
+        if registered.is_empty() {
+            gaps.push(CoverageGap::NoRegisteredLeader);
+        }
+        let female_youth = going
+            .iter()
+            .filter(|u| !u.is_adult)
+            .any(|u| youth.iter().any(|y| y.user_id == Some(u.user_id) && is_female(y.gender.as_deref())));
+        if female_youth && !registered.iter().any(|a| is_female(a.gender.as_deref())) {
+            gaps.push(CoverageGap::NoFemaleLeader);
+        }
+        let weather = skills.qualified(HAZARDOUS_WEATHER_SKILL, day);
+        if !going_adults.iter().any(|u| weather.iter().any(|s| s.user_id == u.user_id)) {
+            gaps.push(CoverageGap::NoHazardousWeather);
+        }
+
+        Self {
+            adults: going_adults.iter().map(|u| format!("{} {}", u.first_name, u.last_name)).collect(),
+            gaps,
+        }
+    }
+
+    pub fn is_covered(&self) -> bool {
+        self.gaps.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_leader_coverage() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 15).unwrap();
+        let youth: Vec<Youth> = serde_json::from_value(serde_json::json!([
+            { "userId": 1, "firstName": "Sam", "lastName": "Ortiz", "gender": "M" },
+            { "userId": 2, "firstName": "Ana", "lastName": "Diaz", "gender": "F" },
+        ]))
+        .unwrap();
+        let adults: Vec<Adult> = serde_json::from_value(serde_json::json!([
+            { "userId": 10, "firstName": "Dana", "lastName": "Ortiz", "gender": "M", "yptExpiredDate": "2027-02-10" },
+            { "userId": 11, "firstName": "Chris", "lastName": "Park", "gender": "M", "yptExpiredDate": "2026-10-20" },
+            { "userId": 12, "firstName": "Rosa", "lastName": "Diaz", "gender": "F", "yptExpiredDate": "2027-08-01" },
+        ]))
+        .unwrap();
+        let mut skills = AdultSkills::default();
+        skills.add(11, "Hazardous Weather, 2027-06-01");
+
+        let event = |going: &[i64]| -> Event {
+            let users: Vec<serde_json::Value> = going
+                .iter()
+                .map(|id| serde_json::json!({ "userId": id, "firstName": "F", "lastName": id.to_string(), "rsvpCode": "Y", "isAdult": *id >= 10 }))
+                .collect();
+            serde_json::from_value(serde_json::json!({
+                "id": 1, "name": "Campout", "startDate": "2026-10-23T17:00:00", "invitedUsers": users
+            }))
+            .unwrap()
+        };
+
+        // Chris's YPT lapses before the campout, and Ana needs a female leader
+        let gaps = LeaderCoverage::check(&event(&[1, 2, 10, 11]), &youth, &adults, &skills, today).gaps;
+        assert_eq!(gaps, [CoverageGap::TwoDeep { trained: 1 }, CoverageGap::NoFemaleLeader]);
+
+        let covered = LeaderCoverage::check(&event(&[1, 2, 10, 11, 12]), &youth, &adults, &skills, today);
+        assert_eq!(covered.adults.len(), 3);
+        assert!(!covered.gaps.contains(&CoverageGap::NoFemaleLeader));
+
+        let gaps = LeaderCoverage::check(&event(&[1, 10, 12]), &youth, &adults, &skills, today).gaps;
+        assert_eq!(gaps, [CoverageGap::NoHazardousWeather]);
+
+        let gaps = LeaderCoverage::check(&event(&[1]), &youth, &adults, &skills, today).gaps;
+        assert_eq!(gaps.len(), 3, "{:?}", gaps);
+        assert!(gaps.contains(&CoverageGap::NoRegisteredLeader));
+    }
+}
@@ -0,0 +1,188 @@
+//! Bulk correction of requirement completion dates.
+//!
+//! When a data-entry slip records a batch of completions on the wrong day,
+//! every cached rank requirement completed on that day is gathered for
+//! review. The ones left selected are recorded again in Scoutbook with the
+//! right date, and each attempt is kept in a local audit log.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::advancement::{RankProgress, RankRequirement};
+
+/// A completion that may be moved to another date.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CorrectionItem {
+    pub user_id: i64,
+    pub scout: String,
+    pub rank_id: i64,
+    pub rank: String,
+    pub requirement_id: i64,
+    /// Requirement number and text
+    pub requirement: String,
+    pub selected: bool,
+}
+
+/// Completions recorded on `from`, to be moved to `to`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateCorrection {
+    pub from: NaiveDate,
+    /// Not chosen until the completions have been found
+    pub to: Option<NaiveDate>,
+    pub items: Vec<CorrectionItem>,
+}
+
+impl DateCorrection {
+    pub fn new(from: NaiveDate) -> Self {
+        Self { from, to: None, items: Vec::new() }
+    }
+
+    /// Add a scout's requirements for one rank that were completed on
+    /// `from`, all selected. Requirements without an ID can't be written
+    /// back and are skipped.
+    pub fn add_rank(&mut self, user_id: i64, scout: &str, rank: &RankProgress, requirements: &[RankRequirement]) {
+        let day = self.from.format("%Y-%m-%d").to_string();
+        for req in requirements {
+            let Some(requirement_id) = req.id else { continue };
+            if req.date_completed.as_deref().and_then(|d| d.get(..10)) != Some(day.as_str()) {
+                continue;
+            }
+            self.items.push(CorrectionItem {
+                user_id,
+                scout: scout.to_string(),
+                rank_id: rank.rank_id,
+                rank: rank.rank_name.clone(),
+                requirement_id,
+                requirement: format!("{} {}", req.number(), req.text()),
+                selected: true,
+            });
+        }
+    }
+
+    pub fn toggle(&mut self, index: usize) {
+        if let Some(item) = self.items.get_mut(index) {
+            item.selected = !item.selected;
+        }
+    }
+
+    /// Select everything, or clear the selection if everything is selected.
+    pub fn toggle_all(&mut self) {
+        let select = self.items.iter().any(|i| !i.selected);
+        for item in &mut self.items {
+            item.selected = select;
+        }
+    }
+
+    pub fn selected(&self) -> impl Iterator<Item = &CorrectionItem> {
+        self.items.iter().filter(|i| i.selected)
+    }
+
+    /// How many different scouts the selected completions belong to.
+    pub fn selected_scouts(&self) -> usize {
+        let mut ids: Vec<i64> = self.selected().map(|i| i.user_id).collect();
+        ids.sort_unstable();
+        ids.dedup();
+        ids.len()
+    }
+}
+
+/// One attempted correction, as kept in the audit log.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CorrectionRecord {
+    pub corrected_at: DateTime<Utc>,
+    pub user_id: i64,
+    pub scout: String,
+    pub rank_id: i64,
+    pub rank: String,
+    pub requirement_id: i64,
+    pub requirement: String,
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    /// Why Scoutbook refused it; `None` if it was saved
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl CorrectionRecord {
+    pub fn new(item: &CorrectionItem, from: NaiveDate, to: NaiveDate, error: Option<String>) -> Self {
+        Self {
+            corrected_at: Utc::now(),
+            user_id: item.user_id,
+            scout: item.scout.clone(),
+            rank_id: item.rank_id,
+            rank: item.rank.clone(),
+            requirement_id: item.requirement_id,
+            requirement: item.requirement.clone(),
+            from,
+            to,
+            error,
+        }
+    }
+
+    pub fn saved(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// Every correction attempted, oldest first.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DateCorrectionLog {
+    pub records: Vec<CorrectionRecord>,
+}
+
+impl DateCorrectionLog {
+    /// Drop the records for a scout. Returns how many were removed.
+    pub fn remove_scout(&mut self, user_id: i64) -> usize {
+        let before = self.records.len();
+        self.records.retain(|r| r.user_id != user_id);
+        before - self.records.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_and_select_completions() {
+        let rank = RankProgress {
+            rank_id: 3,
+            version_id: None,
+            rank_name: "Tenderfoot".to_string(),
+            date_completed: None,
+            date_awarded: None,
+            requirements_completed: None,
+            requirements_total: None,
+            percent_completed: None,
+            level: None,
+        };
+        let reqs: Vec<RankRequirement> = serde_json::from_value(serde_json::json!([
+            { "id": 11, "listNumber": "1a", "short": "Pack for a campout", "dateCompleted": "2026-10-01T00:00:00" },
+            { "id": 12, "listNumber": "1b", "short": "Spend a night", "dateCompleted": "2026-09-12" },
+            { "id": 13, "listNumber": "2", "short": "Cook a meal", "dateCompleted": "2026-10-01" },
+            { "id": null, "listNumber": "3", "short": "Whip a rope", "dateCompleted": "2026-10-01" },
+            { "id": 14, "listNumber": "4", "short": "First aid" }
+        ]))
+        .unwrap();
+
+        let mut correction = DateCorrection::new(NaiveDate::from_ymd_opt(2026, 10, 1).unwrap());
+        correction.add_rank(1, "Sam Ortiz", &rank, &reqs);
+        correction.add_rank(2, "Ana Baker", &rank, &reqs[2..3]);
+        let found: Vec<&str> = correction.items.iter().map(|i| i.requirement.as_str()).collect();
+        assert_eq!(found, ["1a Pack for a campout", "2 Cook a meal", "2 Cook a meal"]);
+        assert_eq!(correction.selected_scouts(), 2);
+
+        correction.toggle(2);
+        assert_eq!(correction.selected_scouts(), 1);
+        correction.toggle_all();
+        assert_eq!(correction.selected().count(), 3);
+        correction.toggle_all();
+        assert_eq!(correction.selected().count(), 0);
+
+        let to = NaiveDate::from_ymd_opt(2026, 9, 30).unwrap();
+        let record = CorrectionRecord::new(&correction.items[0], correction.from, to, None);
+        assert!(record.saved());
+        let json = serde_json::to_string(&record).unwrap();
+        assert!(!json.contains("error"));
+    }
+}
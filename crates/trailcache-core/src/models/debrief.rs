@@ -0,0 +1,131 @@
+//! Post-event debriefs: what went well, what to change, and incidents,
+//! kept locally against the Scoutbook event they follow.
+
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use super::event::Event;
+
+/// Which part of the debrief a note belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DebriefKind {
+    WentWell,
+    ToChange,
+    Incident,
+}
+
+impl DebriefKind {
+    pub const ALL: [DebriefKind; 3] = [DebriefKind::WentWell, DebriefKind::ToChange, DebriefKind::Incident];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            DebriefKind::WentWell => "Went well",
+            DebriefKind::ToChange => "Change",
+            DebriefKind::Incident => "Incident",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            DebriefKind::WentWell => DebriefKind::ToChange,
+            DebriefKind::ToChange => DebriefKind::Incident,
+            DebriefKind::Incident => DebriefKind::WentWell,
+        }
+    }
+}
+
+/// One line of a debrief.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DebriefNote {
+    pub kind: DebriefKind,
+    pub text: String,
+    pub recorded_on: NaiveDate,
+}
+
+/// The debrief for one event.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventDebrief {
+    pub event_id: i64,
+    pub event_name: String,
+    pub date: NaiveDate,
+    #[serde(default)]
+    pub notes: Vec<DebriefNote>,
+}
+
+impl EventDebrief {
+    pub fn notes_of(&self, kind: DebriefKind) -> impl Iterator<Item = &DebriefNote> {
+        self.notes.iter().filter(move |n| n.kind == kind)
+    }
+}
+
+/// Persisted debriefs, kept in date order.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Debriefs {
+    pub debriefs: Vec<EventDebrief>,
+}
+
+impl Debriefs {
+    pub fn for_event(&self, event_id: i64) -> Option<&EventDebrief> {
+        self.debriefs.iter().find(|d| d.event_id == event_id)
+    }
+
+    /// Add a note to `event`'s debrief. Returns false if the event has no
+    /// start date to file it under.
+    pub fn add(&mut self, event: &Event, kind: DebriefKind, text: String, today: NaiveDate) -> bool {
+        let note = DebriefNote { kind, text, recorded_on: today };
+        if let Some(debrief) = self.debriefs.iter_mut().find(|d| d.event_id == event.id) {
+            debrief.notes.push(note);
+            return true;
+        }
+        let Some(date) = event.start_day() else {
+            return false;
+        };
+        self.debriefs.push(EventDebrief { event_id: event.id, event_name: event.name.clone(), date, notes: vec![note] });
+        self.debriefs.sort_by(|a, b| a.date.cmp(&b.date).then_with(|| a.event_name.cmp(&b.event_name)));
+        true
+    }
+
+    /// Remove the most recently added note for an event, dropping the debrief
+    /// once it's empty.
+    pub fn remove_last(&mut self, event_id: i64) -> Option<DebriefNote> {
+        let pos = self.debriefs.iter().position(|d| d.event_id == event_id)?;
+        let note = self.debriefs[pos].notes.pop();
+        if self.debriefs[pos].notes.is_empty() {
+            self.debriefs.remove(pos);
+        }
+        note
+    }
+
+    /// Debriefs from `year`, in date order.
+    pub fn year(&self, year: i32) -> impl Iterator<Item = &EventDebrief> {
+        self.debriefs.iter().filter(move |d| d.date.year() == year)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_and_remove_notes() {
+        let events: Vec<Event> = serde_json::from_value(serde_json::json!([
+            { "id": 1, "name": "Fall Camporee", "startDate": "2026-10-09T17:00:00" },
+            { "id": 2, "name": "No Date" }
+        ]))
+        .unwrap();
+        let today = NaiveDate::from_ymd_opt(2026, 10, 12).unwrap();
+        let mut debriefs = Debriefs::default();
+
+        assert!(debriefs.add(&events[0], DebriefKind::WentWell, "Patrol cooking".to_string(), today));
+        assert!(debriefs.add(&events[0], DebriefKind::Incident, "Minor burn, first aid given".to_string(), today));
+        assert!(!debriefs.add(&events[1], DebriefKind::ToChange, "Leave earlier".to_string(), today));
+
+        let debrief = debriefs.for_event(1).unwrap();
+        assert_eq!(debrief.notes_of(DebriefKind::Incident).count(), 1);
+        assert_eq!(debriefs.year(2026).count(), 1);
+
+        assert_eq!(debriefs.remove_last(1).unwrap().kind, DebriefKind::Incident);
+        debriefs.remove_last(1);
+        assert!(debriefs.for_event(1).is_none(), "empty debriefs are dropped");
+    }
+}
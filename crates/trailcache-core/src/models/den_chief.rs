@@ -0,0 +1,219 @@
+//! Den chiefs serving partner Cub Scout packs.
+//!
+//! Scoutbook records the Den Chief position but not which pack and den the
+//! scout serves, or when that den meets. Links are kept locally, ended links
+//! included, so their service counts toward position-of-responsibility
+//! tenure even when the position was never entered in Scoutbook.
+
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use serde::{Deserialize, Serialize};
+
+use super::advancement::LeadershipPosition;
+
+pub const POSITION_DEN_CHIEF: &str = "Den Chief";
+
+/// When a den meets: weekly on `weekday`, or on the nth `weekday` of each month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MeetingPattern {
+    pub weekday: Weekday,
+    pub week_of_month: Option<u32>,
+}
+
+impl MeetingPattern {
+    /// Parse "Tue" / "Tuesday" (weekly) or "3rd Thu" / "3 Thursday" (monthly).
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut words = text.split_whitespace();
+        let first = words.next()?;
+        let (week_of_month, day) = if first.starts_with(|c: char| c.is_ascii_digit()) {
+            let n: u32 = first.trim_end_matches(|c: char| c.is_alphabetic()).parse().ok()?;
+            if !(1..=5).contains(&n) {
+                return None;
+            }
+            (Some(n), words.next()?)
+        } else {
+            (None, first)
+        };
+        let weekday = day.get(..3)?.parse::<Weekday>().ok()?;
+        Some(Self { weekday, week_of_month })
+    }
+
+    pub fn label(&self) -> String {
+        let day = self.weekday.to_string();
+        match self.week_of_month {
+            Some(n) => {
+                let suffix = match n {
+                    1 => "st",
+                    2 => "nd",
+                    3 => "rd",
+                    _ => "th",
+                };
+                format!("{}{} {} monthly", n, suffix, day)
+            }
+            None => format!("{} weekly", day),
+        }
+    }
+
+    /// Meeting dates from `from` through `to`, inclusive.
+    pub fn dates_between(&self, from: NaiveDate, to: NaiveDate) -> Vec<NaiveDate> {
+        let offset = (7 + self.weekday.num_days_from_monday() - from.weekday().num_days_from_monday()) % 7;
+        let mut date = from + Duration::days(offset as i64);
+        let mut dates = Vec::new();
+        while date <= to {
+            if self.week_of_month.is_none_or(|n| (date.day() - 1) / 7 + 1 == n) {
+                dates.push(date);
+            }
+            date += Duration::days(7);
+        }
+        dates
+    }
+}
+
+/// A scout's service as den chief to one den.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DenChiefLink {
+    pub user_id: i64,
+    pub pack: String,
+    pub den: String,
+    pub meets: Option<MeetingPattern>,
+    pub started_on: NaiveDate,
+    pub ended_on: Option<NaiveDate>,
+}
+
+impl DenChiefLink {
+    pub fn is_active(&self) -> bool {
+        self.ended_on.is_none()
+    }
+
+    pub fn days_served(&self, today: NaiveDate) -> i64 {
+        (self.ended_on.unwrap_or(today) - self.started_on).num_days().max(0)
+    }
+
+    /// "Pack 123 Den 4"
+    pub fn label(&self) -> String {
+        format!("{} {}", self.pack, self.den).trim().to_string()
+    }
+
+    /// The service as a leadership position, for tenure counting.
+    pub fn as_leadership_position(&self, today: NaiveDate) -> LeadershipPosition {
+        LeadershipPosition {
+            position: Some(POSITION_DEN_CHIEF.to_string()),
+            start_date: Some(self.started_on.to_string()),
+            end_date: self.ended_on.map(|d| d.to_string()),
+            days_served: i32::try_from(self.days_served(today)).ok(),
+            patrol: None,
+            rank: None,
+        }
+    }
+}
+
+/// Persisted den chief links for the troop.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DenChiefLinks {
+    pub links: Vec<DenChiefLink>,
+}
+
+impl DenChiefLinks {
+    pub fn active(&self, user_id: i64) -> Option<&DenChiefLink> {
+        self.links.iter().find(|l| l.user_id == user_id && l.is_active())
+    }
+
+    /// Drop a scout's links, current and past. Returns how many were removed.
+    pub fn remove_scout(&mut self, user_id: i64) -> usize {
+        let before = self.links.len();
+        self.links.retain(|l| l.user_id != user_id);
+        before - self.links.len()
+    }
+
+    pub fn for_scout(&self, user_id: i64) -> impl Iterator<Item = &DenChiefLink> {
+        self.links.iter().filter(move |l| l.user_id == user_id)
+    }
+
+    /// Link a scout to a den. Changing the den or meeting day of the current
+    /// pack updates the link in place; moving to another pack ends the old
+    /// link today and starts a new one.
+    pub fn link(&mut self, user_id: i64, pack: &str, den: &str, meets: Option<MeetingPattern>, today: NaiveDate) {
+        if let Some(link) = self.links.iter_mut().find(|l| l.user_id == user_id && l.is_active()) {
+            if link.pack == pack {
+                link.den = den.to_string();
+                link.meets = meets;
+                return;
+            }
+            link.ended_on = Some(today);
+        }
+        self.links.push(DenChiefLink {
+            user_id,
+            pack: pack.to_string(),
+            den: den.to_string(),
+            meets,
+            started_on: today,
+            ended_on: None,
+        });
+    }
+
+    /// End the scout's current link. Returns false if there wasn't one.
+    pub fn end(&mut self, user_id: i64, today: NaiveDate) -> bool {
+        match self.links.iter_mut().find(|l| l.user_id == user_id && l.is_active()) {
+            Some(link) => {
+                link.ended_on = Some(today);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Scoutbook leadership history with den chief service added.
+    pub fn with_leadership(&self, user_id: i64, history: &[LeadershipPosition], today: NaiveDate) -> Vec<LeadershipPosition> {
+        let mut positions = history.to_vec();
+        positions.extend(self.for_scout(user_id).map(|l| l.as_leadership_position(today)));
+        positions
+    }
+
+    /// The scout's upcoming den meetings through `to`.
+    pub fn meetings(&self, user_id: i64, from: NaiveDate, to: NaiveDate) -> Vec<(NaiveDate, String)> {
+        let Some(link) = self.active(user_id) else {
+            return Vec::new();
+        };
+        let meets = link.meets.map(|m| m.dates_between(from, to)).unwrap_or_default();
+        meets.into_iter().map(|d| (d, format!("{} meeting", link.label()))).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::leadership_days;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn test_meeting_pattern() {
+        let third_thursday = MeetingPattern::parse("3rd Thursday").unwrap();
+        assert_eq!(third_thursday.label(), "3rd Thu monthly");
+        assert_eq!(third_thursday.dates_between(date("2026-10-01"), date("2026-11-30")), [date("2026-10-15"), date("2026-11-19")]);
+
+        let weekly = MeetingPattern::parse("tue").unwrap();
+        assert_eq!(weekly.dates_between(date("2026-10-14"), date("2026-10-27")), [date("2026-10-20"), date("2026-10-27")]);
+        assert_eq!(MeetingPattern::parse(&weekly.label()), Some(weekly));
+        assert_eq!(MeetingPattern::parse("6th Mon"), None);
+    }
+
+    #[test]
+    fn test_tenure_counts_overlap_once() {
+        let mut links = DenChiefLinks::default();
+        links.link(7, "Pack 12", "Den 3", None, date("2026-01-01"));
+        links.link(7, "Pack 12", "Den 4", None, date("2026-02-01"));
+        links.link(7, "Pack 40", "Den 1", None, date("2026-04-11"));
+        assert_eq!(links.for_scout(7).count(), 2, "den change kept the link; pack change started a new one");
+        assert!(links.end(7, date("2026-05-01")));
+
+        let history: Vec<LeadershipPosition> = serde_json::from_value(serde_json::json!([
+            { "position": "Den Chief", "startDate": "2026-03-01", "endDate": "2026-04-01" },
+            { "position": "Scribe", "numberOfDaysInPosition": 30 }
+        ]))
+        .unwrap();
+        let combined = links.with_leadership(7, &history, date("2026-10-14"));
+        assert_eq!(leadership_days(&combined, date("2026-10-14")), 120 + 30);
+    }
+}
@@ -0,0 +1,229 @@
+//! District mode: the units a commissioner serves and how each is doing.
+//!
+//! Each assigned unit is fetched into its own cache namespace, keyed by the
+//! unit's organization GUID like the commissioner's home unit. `UnitHealth`
+//! sums up one unit from that cache: charter status, Key 3 contacts,
+//! training compliance, and when the unit last came to roundtable.
+//! Roundtable attendance isn't in Scoutbook, so it's recorded locally.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::person::Adult;
+use super::stats::TrainingStats;
+use super::unit::{Key3Leaders, Leader, OrgProfile, UnitInfo};
+use crate::utils::{cmp_ignore_case, PhoneFormat};
+
+/// Charters expiring within this many days are flagged
+pub const CHARTER_WARNING_DAYS: i64 = 60;
+
+/// Units not seen at roundtable for this many days are flagged
+pub const ROUNDTABLE_STALE_DAYS: i64 = 90;
+
+/// A unit on a commissioner's list, saved in the config.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DistrictUnit {
+    pub organization_guid: String,
+    /// Name to show until the unit's profile has been fetched
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_roundtable: Option<NaiveDate>,
+}
+
+impl DistrictUnit {
+    pub fn new(organization_guid: impl Into<String>, name: Option<String>) -> Self {
+        Self { organization_guid: organization_guid.into(), name, last_roundtable: None }
+    }
+}
+
+/// One of a unit's Key 3, with contact details from its adult roster.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Key3Contact {
+    pub role: &'static str,
+    /// None when the position is vacant
+    pub name: Option<String>,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+}
+
+/// A unit's standing, from its cached data.
+#[derive(Debug, Clone)]
+pub struct UnitHealth {
+    pub organization_guid: String,
+    pub name: String,
+    pub charter_org: Option<String>,
+    pub charter_status: Option<String>,
+    pub charter_expires: Option<NaiveDate>,
+    pub key3: Vec<Key3Contact>,
+    pub adults: usize,
+    pub training: TrainingStats,
+    pub last_roundtable: Option<NaiveDate>,
+    /// When the unit's data was fetched, None if it never has been
+    pub fetched_at: Option<DateTime<Utc>>,
+}
+
+/// A unit's cached data, as loaded from its cache namespace.
+#[derive(Debug, Clone, Default)]
+pub struct UnitData {
+    pub profile: Option<OrgProfile>,
+    pub info: Option<UnitInfo>,
+    pub key3: Option<Key3Leaders>,
+    pub adults: Vec<Adult>,
+    pub fetched_at: Option<DateTime<Utc>>,
+}
+
+impl UnitHealth {
+    /// Summarize a unit's cached data; Key 3 phone numbers are shown in
+    /// `phone_format`.
+    pub fn new(unit: &DistrictUnit, data: &UnitData, phone_format: PhoneFormat) -> Self {
+        let profile = data.profile.as_ref();
+        let info = data.info.as_ref();
+        let name = profile
+            .and_then(|p| p.full_name.clone().or_else(|| p.name.clone()))
+            .or_else(|| info.and_then(|i| i.name.clone()))
+            .or_else(|| unit.name.clone())
+            .unwrap_or_else(|| unit.organization_guid.clone());
+        let charter_expires = profile
+            .and_then(|p| p.charter_exp_date.as_deref())
+            .or_else(|| info.and_then(|i| i.charter_expiry.as_deref()))
+            .and_then(|d| NaiveDate::parse_from_str(d.get(..10)?, "%Y-%m-%d").ok());
+
+        let key3 = data.key3.clone().unwrap_or_default();
+        let key3 = [
+            ("Scoutmaster", key3.scoutmaster),
+            ("Committee Chair", key3.committee_chair),
+            ("Chartered Org Rep", key3.charter_org_rep),
+        ]
+        .into_iter()
+        .map(|(role, leader)| key3_contact(role, leader.as_ref(), &data.adults, phone_format))
+        .collect();
+
+        Self {
+            organization_guid: unit.organization_guid.clone(),
+            name,
+            charter_org: profile
+                .and_then(|p| p.charter_org_name.clone())
+                .or_else(|| info.and_then(|i| i.charter_org_name.clone())),
+            charter_status: profile.and_then(|p| p.charter_status.clone()),
+            charter_expires,
+            key3,
+            adults: data.adults.len(),
+            training: TrainingStats::from_adults(&data.adults),
+            last_roundtable: unit.last_roundtable,
+            fetched_at: data.fetched_at,
+        }
+    }
+
+    /// Days until the charter expires, negative once it has.
+    pub fn charter_days_left(&self, today: NaiveDate) -> Option<i64> {
+        self.charter_expires.map(|d| (d - today).num_days())
+    }
+
+    /// Percent of adults who are position trained, if any have a status.
+    pub fn trained_percent(&self) -> Option<u32> {
+        let t = &self.training;
+        let known = t.position_trained + t.position_not_trained;
+        (known > 0).then(|| (t.position_trained * 100 / known) as u32)
+    }
+
+    /// Things a commissioner should follow up on, most pressing first.
+    pub fn concerns(&self, today: NaiveDate) -> Vec<String> {
+        let mut concerns = Vec::new();
+        if self.fetched_at.is_none() {
+            concerns.push("Not fetched yet".to_string());
+            return concerns;
+        }
+        match self.charter_days_left(today) {
+            Some(days) if days < 0 => concerns.push("Charter expired".to_string()),
+            Some(days) if days <= CHARTER_WARNING_DAYS => concerns.push(format!("Charter expires in {} days", days)),
+            _ => {}
+        }
+        for k in self.key3.iter().filter(|k| k.name.is_none()) {
+            concerns.push(format!("No {}", k.role));
+        }
+        if self.training.ypt_expired > 0 {
+            concerns.push(format!("{} with expired YPT", self.training.ypt_expired));
+        }
+        if self.training.position_not_trained > 0 {
+            concerns.push(format!("{} not position trained", self.training.position_not_trained));
+        }
+        match self.last_roundtable {
+            None => concerns.push("No roundtable recorded".to_string()),
+            Some(date) if (today - date).num_days() > ROUNDTABLE_STALE_DAYS => {
+                concerns.push(format!("Last at roundtable {}", date.format("%b %-d")))
+            }
+            _ => {}
+        }
+        concerns
+    }
+}
+
+/// Units in dashboard order: most concerns first, then by name.
+pub fn sort_by_concerns(units: &mut [UnitHealth], today: NaiveDate) {
+    units.sort_by(|a, b| {
+        b.concerns(today)
+            .len()
+            .cmp(&a.concerns(today).len())
+            .then_with(|| cmp_ignore_case(&a.name, &b.name))
+    });
+}
+
+fn key3_contact(role: &'static str, leader: Option<&Leader>, adults: &[Adult], phone_format: PhoneFormat) -> Key3Contact {
+    let Some(leader) = leader else {
+        return Key3Contact { role, name: None, email: None, phone: None };
+    };
+    let adult = adults.iter().find(|a| {
+        a.first_name.eq_ignore_ascii_case(&leader.first_name) && a.last_name.eq_ignore_ascii_case(&leader.last_name)
+    });
+    Key3Contact {
+        role,
+        name: Some(leader.full_name()),
+        email: adult.and_then(|a| a.email()),
+        phone: adult.and_then(|a| a.phone(phone_format)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unit_health_concerns() {
+        let today = NaiveDate::from_ymd_opt(2026, 3, 1).unwrap();
+        let mut unit = DistrictUnit::new("guid-1", Some("Troop 12".to_string()));
+
+        let never = UnitHealth::new(&unit, &UnitData::default(), PhoneFormat::Parens);
+        assert_eq!(never.name, "Troop 12");
+        assert_eq!(never.concerns(today), ["Not fetched yet"]);
+
+        unit.last_roundtable = NaiveDate::from_ymd_opt(2026, 2, 10);
+        let adult: Adult = serde_json::from_value(serde_json::json!({
+            "firstName": "Alex", "lastName": "Leader", "positionTrained": "Not Trained",
+            "primaryEmailInfo": { "emailAddress": "alex@example.com" }
+        }))
+        .unwrap();
+        let data = UnitData {
+            profile: Some(OrgProfile {
+                name: Some("Troop 0012".to_string()),
+                charter_exp_date: Some("2026-03-31T00:00:00".to_string()),
+                ..OrgProfile::default()
+            }),
+            key3: Some(Key3Leaders {
+                scoutmaster: Some(Leader { first_name: "Alex".to_string(), last_name: "Leader".to_string() }),
+                ..Key3Leaders::default()
+            }),
+            adults: vec![adult],
+            fetched_at: Some(Utc::now()),
+            ..UnitData::default()
+        };
+        let health = UnitHealth::new(&unit, &data, PhoneFormat::Parens);
+        assert_eq!(health.name, "Troop 0012");
+        assert_eq!(health.key3[0].email.as_deref(), Some("alex@example.com"));
+        assert_eq!(health.trained_percent(), Some(0));
+        assert_eq!(
+            health.concerns(today),
+            ["Charter expires in 30 days", "No Committee Chair", "No Chartered Org Rep", "1 not position trained"]
+        );
+    }
+}
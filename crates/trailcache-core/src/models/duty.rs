@@ -0,0 +1,38 @@
+//! Patrol duty rosters for campouts and meetings.
+//!
+//! Duties rotate by one patrol per event, so over a season every patrol
+//! takes every job in turn.
+
+/// Duties used when the config doesn't list its own
+pub const DEFAULT_DUTIES: [&str; 5] = ["Cooking", "Cleanup", "Water & Fire", "Flag Ceremony", "Program Setup"];
+
+/// Pair each duty with a patrol for the `rotation`th event of the season.
+/// With more duties than patrols, some patrols take more than one duty.
+pub fn duty_roster<'a>(duties: &'a [String], patrols: &'a [String], rotation: usize) -> Vec<(&'a str, &'a str)> {
+    if patrols.is_empty() {
+        return Vec::new();
+    }
+    duties
+        .iter()
+        .enumerate()
+        .map(|(i, duty)| (duty.as_str(), patrols[(i + rotation) % patrols.len()].as_str()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_duty_roster_rotates() {
+        let duties: Vec<String> = ["Cooking", "Cleanup", "Flags"].map(String::from).to_vec();
+        let patrols: Vec<String> = ["Eagles", "Hawks"].map(String::from).to_vec();
+
+        assert_eq!(
+            duty_roster(&duties, &patrols, 0),
+            [("Cooking", "Eagles"), ("Cleanup", "Hawks"), ("Flags", "Eagles")]
+        );
+        assert_eq!(duty_roster(&duties, &patrols, 1)[0], ("Cooking", "Hawks"));
+        assert!(duty_roster(&duties, &[], 0).is_empty());
+    }
+}
@@ -0,0 +1,304 @@
+//! Progress toward Eagle Scout.
+//!
+//! Built from the rank, merit badge, and leadership data already cached for
+//! every scout. Eagle needs 21 merit badges, 14 of them from the required
+//! list (some slots can be filled by one of several badges), six months in a
+//! position of responsibility while a Life Scout, and everything done before
+//! the scout turns 18. The Eagle service project isn't in Scoutbook's data;
+//! service hours are counted as the six each owed for Star and Life.
+
+use std::cmp::Ordering;
+
+use chrono::{Months, NaiveDate};
+
+use super::advancement::{LeadershipPosition, MeritBadgeProgress, RankProgress, ScoutRank};
+use super::person::Youth;
+use super::positions::leadership_days;
+use crate::utils::cmp_ignore_case;
+
+/// Merit badges needed for Eagle in total
+pub const EAGLE_TOTAL_BADGES: usize = 21;
+
+/// Eagle-required merit badges. Each slot is filled by any one of its badges.
+pub const EAGLE_REQUIRED_BADGES: [&[&str]; 14] = [
+    &["Camping"],
+    &["Citizenship in Community"],
+    &["Citizenship in Nation"],
+    &["Citizenship in Society"],
+    &["Citizenship in World"],
+    &["Communication"],
+    &["Cooking"],
+    &["Emergency Preparedness", "Lifesaving"],
+    &["Environmental Science", "Sustainability"],
+    &["Family Life"],
+    &["First Aid"],
+    &["Personal Fitness"],
+    &["Personal Management"],
+    &["Swimming", "Hiking", "Cycling"],
+];
+
+/// Days in a position of responsibility needed while a Life Scout
+pub const EAGLE_POR_DAYS: i64 = 183;
+
+/// Service hours owed for each of Star and Life
+pub const RANK_SERVICE_HOURS: u32 = 6;
+
+/// Age by which Eagle must be completed
+const EAGLE_AGE_LIMIT: u32 = 18;
+
+/// Badge names compared without case, punctuation, or "the", so
+/// "Citizenship in the World" matches "Citizenship in World".
+fn badge_key(name: &str) -> String {
+    name.split_whitespace()
+        .filter(|w| !w.eq_ignore_ascii_case("the"))
+        .flat_map(|w| w.chars().filter(|c| c.is_alphanumeric()))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn parse_date(date: Option<&str>) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(date?.get(..10)?, "%Y-%m-%d").ok()
+}
+
+/// One scout's standing against the Eagle requirements.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EagleProgress {
+    pub user_id: i64,
+    pub name: String,
+    /// Highest rank earned
+    pub rank: ScoutRank,
+    /// Ranks still to earn before Eagle, lowest first
+    pub ranks_remaining: Vec<ScoutRank>,
+    /// Percent of the Eagle rank's own requirements done
+    pub eagle_percent: Option<i32>,
+    /// Merit badges completed, required or not
+    pub badges_earned: usize,
+    /// Required slots not yet filled, alternatives joined with " or "
+    pub required_missing: Vec<String>,
+    /// When Life was earned
+    pub life_on: Option<NaiveDate>,
+    /// Days in leadership since earning Life
+    pub por_days: i64,
+    /// The scout's 18th birthday
+    pub deadline: Option<NaiveDate>,
+}
+
+impl EagleProgress {
+    pub fn new(
+        youth: &Youth,
+        ranks: &[RankProgress],
+        badges: &[MeritBadgeProgress],
+        leadership: &[LeadershipPosition],
+        today: NaiveDate,
+    ) -> Self {
+        let earned = |rank: ScoutRank| ranks.iter().find(|r| r.is_completed() && ScoutRank::parse(Some(&r.rank_name)) == rank);
+        let rank = ranks
+            .iter()
+            .filter(|r| r.is_completed())
+            .map(|r| ScoutRank::parse(Some(&r.rank_name)))
+            .chain([ScoutRank::parse(youth.current_rank.as_deref())])
+            .max()
+            .unwrap_or(ScoutRank::Unknown);
+        let ranks_remaining = [ScoutRank::Scout, ScoutRank::Tenderfoot, ScoutRank::SecondClass, ScoutRank::FirstClass, ScoutRank::Star, ScoutRank::Life, ScoutRank::Eagle]
+            .into_iter()
+            .filter(|&r| r > rank)
+            .collect();
+        let eagle_percent = ranks
+            .iter()
+            .find(|r| ScoutRank::parse(Some(&r.rank_name)) == ScoutRank::Eagle)
+            .and_then(|r| if r.is_completed() { Some(100) } else { r.progress_percent() });
+
+        let completed: Vec<String> = badges.iter().filter(|b| b.is_completed()).map(|b| badge_key(&b.name)).collect();
+        let required_missing = EAGLE_REQUIRED_BADGES
+            .iter()
+            .filter(|slot| !slot.iter().any(|name| completed.contains(&badge_key(name))))
+            .map(|slot| slot.join(" or "))
+            .collect();
+
+        let life_on = earned(ScoutRank::Life).and_then(|r| parse_date(r.date_completed.as_deref()));
+        let por_days = life_on.map(|since| leadership_days_since(leadership, since, today)).unwrap_or(0);
+        let deadline = youth
+            .date_of_birth()
+            .and_then(|dob| dob.checked_add_months(Months::new(EAGLE_AGE_LIMIT * 12)));
+
+        Self {
+            user_id: youth.user_id.unwrap_or_default(),
+            name: youth.display_name(),
+            rank,
+            ranks_remaining,
+            eagle_percent,
+            badges_earned: completed.len(),
+            required_missing,
+            life_on,
+            por_days,
+            deadline,
+        }
+    }
+
+    /// Merit badges still needed: every empty required slot, and enough
+    /// others to reach 21.
+    pub fn badges_remaining(&self) -> usize {
+        EAGLE_TOTAL_BADGES.saturating_sub(self.badges_earned).max(self.required_missing.len())
+    }
+
+    /// Days in a position of responsibility still needed as a Life Scout.
+    pub fn por_days_remaining(&self) -> i64 {
+        (EAGLE_POR_DAYS - self.por_days).max(0)
+    }
+
+    /// Service hours still owed for Star and Life.
+    pub fn service_hours_remaining(&self) -> u32 {
+        let owed = self.ranks_remaining.iter().filter(|r| matches!(r, ScoutRank::Star | ScoutRank::Life)).count();
+        owed as u32 * RANK_SERVICE_HOURS
+    }
+
+    pub fn is_eagle(&self) -> bool {
+        self.rank == ScoutRank::Eagle
+    }
+
+    /// Days left before the scout turns 18, if the birth date is known.
+    pub fn days_to_deadline(&self, today: NaiveDate) -> Option<i64> {
+        self.deadline.map(|d| (d - today).num_days())
+    }
+
+    /// Compare for the Eagle tab. `Closest` orders by ranks to go, then
+    /// badges, then leadership time, then progress on Eagle's own
+    /// requirements, with Eagles last.
+    pub fn cmp_by_column(a: &Self, b: &Self, column: EagleSortColumn) -> Ordering {
+        let name_cmp = || cmp_ignore_case(&a.name, &b.name);
+        match column {
+            EagleSortColumn::Closest => a
+                .is_eagle()
+                .cmp(&b.is_eagle())
+                .then_with(|| a.ranks_remaining.len().cmp(&b.ranks_remaining.len()))
+                .then_with(|| a.badges_remaining().cmp(&b.badges_remaining()))
+                .then_with(|| a.por_days_remaining().cmp(&b.por_days_remaining()))
+                .then_with(|| b.eagle_percent.cmp(&a.eagle_percent))
+                .then_with(name_cmp),
+            EagleSortColumn::Name => name_cmp(),
+            EagleSortColumn::Badges => a.badges_remaining().cmp(&b.badges_remaining()).then_with(name_cmp),
+            // Unknown birth dates last
+            EagleSortColumn::Deadline => a
+                .deadline
+                .is_none()
+                .cmp(&b.deadline.is_none())
+                .then_with(|| a.deadline.cmp(&b.deadline))
+                .then_with(name_cmp),
+        }
+    }
+}
+
+// Sorting options for the Eagle tab
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EagleSortColumn {
+    Closest,
+    Name,
+    Badges,
+    Deadline,
+}
+
+/// Days in leadership on or after `since`, with positions that started
+/// earlier counted from `since`. Undated positions can't be placed and are
+/// left out.
+fn leadership_days_since(history: &[LeadershipPosition], since: NaiveDate, today: NaiveDate) -> i64 {
+    let clipped: Vec<LeadershipPosition> = history
+        .iter()
+        .filter(|p| parse_date(p.start_date.as_deref()).is_some())
+        .filter(|p| parse_date(p.end_date.as_deref()).is_none_or(|end| end > since))
+        .map(|p| {
+            let start = parse_date(p.start_date.as_deref()).unwrap_or(since).max(since);
+            LeadershipPosition {
+                start_date: Some(start.format("%Y-%m-%d").to_string()),
+                ..p.clone()
+            }
+        })
+        .collect();
+    leadership_days(&clipped, today)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    fn rank(name: &str, completed: Option<&str>) -> RankProgress {
+        RankProgress {
+            rank_id: 0,
+            version_id: None,
+            rank_name: name.to_string(),
+            date_completed: completed.map(String::from),
+            date_awarded: None,
+            requirements_completed: None,
+            requirements_total: None,
+            percent_completed: None,
+            level: None,
+        }
+    }
+
+    fn badges(names: &[&str]) -> Vec<MeritBadgeProgress> {
+        let list: Vec<_> = names
+            .iter()
+            .map(|n| serde_json::json!({ "id": 1, "name": n, "status": "Awarded" }))
+            .collect();
+        serde_json::from_value(serde_json::Value::Array(list)).unwrap()
+    }
+
+    #[test]
+    fn test_eagle_progress() {
+        let youth: Youth = serde_json::from_value(serde_json::json!({
+            "userId": 7, "firstName": "Sam", "lastName": "Scout", "currentRankName": "Star",
+            "registrarInfo": { "dateOfBirth": "2010-03-01" }
+        }))
+        .unwrap();
+        let ranks = [rank("Star Scout", Some("2024-01-10")), rank("Life Scout", Some("2025-06-01"))];
+        let earned = badges(&["Camping", "Citizenship in the World", "Hiking", "Swimming", "Chess"]);
+        let leadership = [
+            LeadershipPosition {
+                position: Some("Scribe".to_string()),
+                start_date: Some("2025-01-01".to_string()),
+                end_date: Some("2025-07-01".to_string()),
+                days_served: None,
+                patrol: None,
+                rank: None,
+            },
+            LeadershipPosition {
+                position: Some("Quartermaster".to_string()),
+                start_date: Some("2025-09-01".to_string()),
+                end_date: None,
+                days_served: None,
+                patrol: None,
+                rank: None,
+            },
+        ];
+        let today = date("2025-10-01");
+        let progress = EagleProgress::new(&youth, &ranks, &earned, &leadership, today);
+
+        assert_eq!(progress.rank, ScoutRank::Life);
+        assert_eq!(progress.ranks_remaining, [ScoutRank::Eagle]);
+        assert_eq!(progress.badges_earned, 5);
+        assert_eq!(progress.required_missing.len(), 11);
+        assert!(!progress.required_missing.iter().any(|s| s.contains("Hiking")));
+        assert_eq!(progress.badges_remaining(), 16);
+        assert_eq!(progress.por_days, 30 + 30);
+        assert_eq!(progress.por_days_remaining(), EAGLE_POR_DAYS - 60);
+        assert_eq!(progress.service_hours_remaining(), 0);
+        assert_eq!(progress.deadline, Some(date("2028-03-01")));
+    }
+
+    #[test]
+    fn test_closest_to_eagle_first() {
+        let youth: Youth = serde_json::from_value(serde_json::json!({ "userId": 1, "firstName": "A", "lastName": "B" })).unwrap();
+        let today = date("2025-10-01");
+        let star = EagleProgress::new(&youth, &[rank("Star", Some("2025-01-01"))], &[], &[], today);
+        let life = EagleProgress::new(&youth, &[rank("Life", Some("2025-01-01"))], &[], &[], today);
+        let eagle = EagleProgress::new(&youth, &[rank("Eagle", Some("2025-01-01"))], &[], &[], today);
+        let mut rows = [eagle.clone(), star.clone(), life.clone()];
+        rows.sort_by(|a, b| EagleProgress::cmp_by_column(a, b, EagleSortColumn::Closest));
+        assert_eq!(rows, [life, star.clone(), eagle]);
+        assert_eq!(star.service_hours_remaining(), RANK_SERVICE_HOURS);
+        assert_eq!(badge_key("Citizenship in the Nation"), badge_key("Citizenship in Nation"));
+    }
+}
@@ -0,0 +1,328 @@
+//! Eagle service project tracking.
+//!
+//! Scoutbook knows a candidate is working on Eagle but not where their
+//! project stands. Each candidate's project is kept locally: the proposal
+//! stage, beneficiary, fundraising approval, workdays linked to troop
+//! calendar events, an hour log, and the district paperwork checklist.
+//! Milestones are dated as they're recorded, giving a per-candidate
+//! timeline.
+
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use super::event::Event;
+
+/// Where the project is, from idea to approved final report.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum ProjectStage {
+    #[default]
+    Idea,
+    ProposalWritten,
+    ProposalApproved,
+    InProgress,
+    ReportWritten,
+    ReportApproved,
+}
+
+impl ProjectStage {
+    pub const ALL: [ProjectStage; 6] = [
+        ProjectStage::Idea,
+        ProjectStage::ProposalWritten,
+        ProjectStage::ProposalApproved,
+        ProjectStage::InProgress,
+        ProjectStage::ReportWritten,
+        ProjectStage::ReportApproved,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ProjectStage::Idea => "Idea",
+            ProjectStage::ProposalWritten => "Proposal written",
+            ProjectStage::ProposalApproved => "Proposal approved",
+            ProjectStage::InProgress => "Work in progress",
+            ProjectStage::ReportWritten => "Final report written",
+            ProjectStage::ReportApproved => "Final report approved",
+        }
+    }
+
+    pub fn next(&self) -> Option<Self> {
+        Self::ALL.iter().position(|s| s == self).and_then(|i| Self::ALL.get(i + 1)).copied()
+    }
+
+    pub fn prev(&self) -> Option<Self> {
+        Self::ALL.iter().position(|s| s == self).and_then(|i| i.checked_sub(1)).map(|i| Self::ALL[i])
+    }
+}
+
+/// District paperwork a candidate turns in on the way to the board of review.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Paperwork {
+    ProposalSignatures,
+    FundraisingApplication,
+    FinalReportSignatures,
+    RankApplication,
+    References,
+    AmbitionsStatement,
+    CouncilVerification,
+    BoardScheduled,
+}
+
+impl Paperwork {
+    pub const ALL: [Paperwork; 8] = [
+        Paperwork::ProposalSignatures,
+        Paperwork::FundraisingApplication,
+        Paperwork::FinalReportSignatures,
+        Paperwork::RankApplication,
+        Paperwork::References,
+        Paperwork::AmbitionsStatement,
+        Paperwork::CouncilVerification,
+        Paperwork::BoardScheduled,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Paperwork::ProposalSignatures => "Proposal signed (beneficiary, unit, council/district)",
+            Paperwork::FundraisingApplication => "Fundraising application approved",
+            Paperwork::FinalReportSignatures => "Final report signed",
+            Paperwork::RankApplication => "Eagle Scout Rank Application",
+            Paperwork::References => "References contacted",
+            Paperwork::AmbitionsStatement => "Statement of ambitions and life purpose",
+            Paperwork::CouncilVerification => "Council verified application",
+            Paperwork::BoardScheduled => "Board of review scheduled",
+        }
+    }
+}
+
+/// Whether the project raises money, and where its approval stands.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Fundraising {
+    #[default]
+    NotNeeded,
+    Pending,
+    Approved,
+}
+
+impl Fundraising {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Fundraising::NotNeeded => "Not needed",
+            Fundraising::Pending => "Awaiting approval",
+            Fundraising::Approved => "Approved",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            Fundraising::NotNeeded => Fundraising::Pending,
+            Fundraising::Pending => Fundraising::Approved,
+            Fundraising::Approved => Fundraising::NotNeeded,
+        }
+    }
+}
+
+/// Hours someone worked on the project.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HourEntry {
+    pub date: NaiveDate,
+    pub hours: f32,
+    /// Who worked, e.g. "Candidate" or "Patrol helpers"
+    pub who: String,
+}
+
+/// A dated milestone.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Milestone {
+    pub date: NaiveDate,
+    pub text: String,
+}
+
+/// One candidate's project.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EagleProject {
+    pub user_id: i64,
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub beneficiary: String,
+    #[serde(default)]
+    pub stage: ProjectStage,
+    #[serde(default)]
+    pub fundraising: Fundraising,
+    /// Troop calendar events that are project workdays
+    #[serde(default)]
+    pub workdays: Vec<i64>,
+    #[serde(default)]
+    pub hours: Vec<HourEntry>,
+    /// Paperwork done, and when
+    #[serde(default)]
+    pub paperwork: BTreeMap<Paperwork, NaiveDate>,
+    /// Oldest first
+    #[serde(default)]
+    pub milestones: Vec<Milestone>,
+}
+
+impl EagleProject {
+    fn new(user_id: i64, today: NaiveDate) -> Self {
+        Self {
+            user_id,
+            title: String::new(),
+            beneficiary: String::new(),
+            stage: ProjectStage::Idea,
+            fundraising: Fundraising::NotNeeded,
+            workdays: Vec::new(),
+            hours: Vec::new(),
+            paperwork: BTreeMap::new(),
+            milestones: vec![Milestone { date: today, text: "Project tracking started".to_string() }],
+        }
+    }
+
+    fn note(&mut self, today: NaiveDate, text: String) {
+        self.milestones.push(Milestone { date: today, text });
+    }
+
+    pub fn total_hours(&self) -> f32 {
+        self.hours.iter().map(|h| h.hours).sum()
+    }
+
+    /// Paperwork items still to do. Fundraising paperwork only counts when
+    /// the project raises money.
+    pub fn paperwork_missing(&self) -> impl Iterator<Item = Paperwork> + '_ {
+        Paperwork::ALL.into_iter().filter(move |p| {
+            !self.paperwork.contains_key(p) && (*p != Paperwork::FundraisingApplication || self.fundraising != Fundraising::NotNeeded)
+        })
+    }
+
+    /// Milestones, workdays from the troop calendar, and logged hours,
+    /// oldest first.
+    pub fn timeline<'a>(&self, events: impl IntoIterator<Item = &'a Event>) -> Vec<Milestone> {
+        let mut timeline = self.milestones.clone();
+        for event in events.into_iter().filter(|e| self.workdays.contains(&e.id)) {
+            if let Some(date) = event.start_day() {
+                timeline.push(Milestone { date, text: format!("Workday: {}", event.name) });
+            }
+        }
+        for entry in &self.hours {
+            timeline.push(Milestone { date: entry.date, text: format!("{} hours - {}", entry.hours, entry.who) });
+        }
+        timeline.sort_by_key(|m| m.date);
+        timeline
+    }
+}
+
+/// Persisted Eagle projects, one per candidate.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EagleProjects {
+    pub projects: Vec<EagleProject>,
+}
+
+impl EagleProjects {
+    pub fn get(&self, user_id: i64) -> Option<&EagleProject> {
+        self.projects.iter().find(|p| p.user_id == user_id)
+    }
+
+    /// The candidate's project, started if they don't have one yet.
+    pub fn get_or_start(&mut self, user_id: i64, today: NaiveDate) -> &mut EagleProject {
+        match self.projects.iter().position(|p| p.user_id == user_id) {
+            Some(i) => &mut self.projects[i],
+            None => {
+                self.projects.push(EagleProject::new(user_id, today));
+                self.projects.last_mut().expect("just pushed")
+            }
+        }
+    }
+
+    /// Move the project a stage forward (or back), noting it on the timeline.
+    pub fn set_stage(&mut self, user_id: i64, forward: bool, today: NaiveDate) -> Option<ProjectStage> {
+        let project = self.get_or_start(user_id, today);
+        let stage = if forward { project.stage.next() } else { project.stage.prev() }?;
+        project.stage = stage;
+        project.note(today, stage.name().to_string());
+        Some(stage)
+    }
+
+    pub fn cycle_fundraising(&mut self, user_id: i64, today: NaiveDate) -> Fundraising {
+        let project = self.get_or_start(user_id, today);
+        project.fundraising = project.fundraising.next();
+        if project.fundraising == Fundraising::Approved {
+            project.note(today, "Fundraising approved".to_string());
+        }
+        project.fundraising
+    }
+
+    /// Check off a paperwork item, or uncheck it. Returns whether it's done.
+    pub fn toggle_paperwork(&mut self, user_id: i64, item: Paperwork, today: NaiveDate) -> bool {
+        let project = self.get_or_start(user_id, today);
+        if project.paperwork.remove(&item).is_some() {
+            return false;
+        }
+        project.paperwork.insert(item, today);
+        project.note(today, item.name().to_string());
+        true
+    }
+
+    /// Log hours typed as "3.5" or "3.5 patrol helpers". Returns the hours,
+    /// or `None` if `input` doesn't start with a positive number.
+    pub fn log_hours(&mut self, user_id: i64, input: &str, today: NaiveDate) -> Option<f32> {
+        let input = input.trim();
+        let (number, who) = input.split_once(char::is_whitespace).unwrap_or((input, ""));
+        let hours: f32 = number.parse().ok().filter(|h: &f32| *h > 0.0 && h.is_finite())?;
+        let who = if who.trim().is_empty() { "Candidate" } else { who.trim() };
+        self.get_or_start(user_id, today).hours.push(HourEntry { date: today, hours, who: who.to_string() });
+        Some(hours)
+    }
+
+    /// Link troop calendar events as workdays, skipping ones already linked.
+    /// Returns how many were added.
+    pub fn link_workdays(&mut self, user_id: i64, event_ids: impl IntoIterator<Item = i64>, today: NaiveDate) -> usize {
+        let project = self.get_or_start(user_id, today);
+        let before = project.workdays.len();
+        for id in event_ids {
+            if !project.workdays.contains(&id) {
+                project.workdays.push(id);
+            }
+        }
+        project.workdays.len() - before
+    }
+
+    pub fn remove_scout(&mut self, user_id: i64) -> bool {
+        let before = self.projects.len();
+        self.projects.retain(|p| p.user_id != user_id);
+        self.projects.len() != before
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_project_timeline() {
+        let day = |d| NaiveDate::from_ymd_opt(2026, 5, d).unwrap();
+        let mut projects = EagleProjects::default();
+        assert_eq!(projects.set_stage(7, false, day(1)), None, "already at the first stage");
+        assert_eq!(projects.set_stage(7, true, day(2)), Some(ProjectStage::ProposalWritten));
+        assert_eq!(projects.cycle_fundraising(7, day(3)), Fundraising::Pending);
+        assert!(projects.toggle_paperwork(7, Paperwork::ProposalSignatures, day(4)));
+        assert_eq!(projects.log_hours(7, "3.5 patrol helpers", day(9)), Some(3.5));
+        assert_eq!(projects.log_hours(7, "2", day(9)), Some(2.0));
+        assert_eq!(projects.log_hours(7, "lots", day(9)), None);
+
+        let events: Vec<Event> = serde_json::from_value(serde_json::json!([
+            { "id": 40, "name": "Park bench build", "startDate": "2026-05-08" }
+        ]))
+        .unwrap();
+        assert_eq!(projects.link_workdays(7, [40, 40], day(5)), 1);
+
+        let project = projects.get(7).unwrap();
+        assert_eq!(project.total_hours(), 5.5);
+        assert_eq!(project.paperwork_missing().count(), Paperwork::ALL.len() - 1);
+        let timeline: Vec<String> = project.timeline(&events).into_iter().map(|m| m.text).collect();
+        assert_eq!(timeline[1], "Proposal written");
+        assert_eq!(timeline[3], "Workday: Park bench build");
+        assert_eq!(timeline[4], "3.5 hours - patrol helpers");
+
+        assert!(projects.remove_scout(7));
+    }
+}
@@ -1,12 +1,13 @@
 use std::cmp::Ordering;
 
-use chrono::DateTime;
+use chrono::{DateTime, NaiveDate};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RsvpStatus {
     Going,
     NotGoing,
+    Maybe,
     NoResponse,
 }
 
@@ -15,6 +16,7 @@ impl std::fmt::Display for RsvpStatus {
         match self {
             RsvpStatus::Going => write!(f, "Going"),
             RsvpStatus::NotGoing => write!(f, "Not Going"),
+            RsvpStatus::Maybe => write!(f, "Maybe"),
             RsvpStatus::NoResponse => write!(f, "No Response"),
         }
     }
@@ -77,6 +79,9 @@ impl InvitedUser {
             if code.eq_ignore_ascii_case("n") || code.eq_ignore_ascii_case("no") {
                 return RsvpStatus::NotGoing;
             }
+            if code.eq_ignore_ascii_case("m") || code.eq_ignore_ascii_case("maybe") {
+                return RsvpStatus::Maybe;
+            }
         }
         // Fall back to rsvp field
         if let Some(rsvp) = &self.rsvp {
@@ -87,6 +92,9 @@ impl InvitedUser {
             if rsvp_lower == "not going" || rsvp_lower == "not_going" || rsvp_lower == "no" {
                 return RsvpStatus::NotGoing;
             }
+            if rsvp_lower == "maybe" {
+                return RsvpStatus::Maybe;
+            }
         }
         RsvpStatus::NoResponse
     }
@@ -277,6 +285,16 @@ impl Event {
         (going, not_going)
     }
 
+    /// The day the event starts, if it has a start date.
+    pub fn start_day(&self) -> Option<NaiveDate> {
+        NaiveDate::parse_from_str(self.start_date.as_deref()?.get(..10)?, "%Y-%m-%d").ok()
+    }
+
+    /// Whether the event started before `today`. Undated events count as upcoming.
+    pub fn is_past(&self, today: NaiveDate) -> bool {
+        self.start_day().is_some_and(|d| d < today)
+    }
+
     /// Check if this event matches a search query (case-insensitive).
     /// Query should already be lowercased.
     pub fn matches_search(&self, query_lowercase: &str) -> bool {
@@ -285,11 +303,18 @@ impl Event {
             || self.derived_type().to_lowercase().contains(query_lowercase)
     }
 
-    /// Invited users who have responded (Going or Not Going), split by adult/youth.
+    /// Fields searched by `SearchQuery`: name, location, type.
+    pub fn search_fields(&self) -> Vec<String> {
+        let mut fields = vec![self.name.clone(), self.derived_type().to_string()];
+        fields.extend(self.location.clone());
+        fields
+    }
+
+    /// Invited users who have responded (Going, Not Going, or Maybe), split by adult/youth.
     pub fn respondents(&self) -> (Vec<&InvitedUser>, Vec<&InvitedUser>) {
         let (mut adults, mut scouts) = (vec![], vec![]);
         for u in &self.invited_users {
-            if matches!(u.status(), RsvpStatus::Going | RsvpStatus::NotGoing) {
+            if !matches!(u.status(), RsvpStatus::NoResponse) {
                 if u.is_adult { adults.push(u); } else { scouts.push(u); }
             }
         }
@@ -364,6 +389,9 @@ impl EventGuest {
             if status_lower == "not going" || status_lower == "not_going" || status_lower == "no" {
                 return RsvpStatus::NotGoing;
             }
+            if status_lower == "maybe" {
+                return RsvpStatus::Maybe;
+            }
         }
         RsvpStatus::NoResponse
     }
@@ -379,10 +407,53 @@ pub enum EventSortColumn {
     Type,
 }
 
+/// Past events that have aged out of the refresh window, kept locally so
+/// attendance history survives after Scoutbook stops returning them.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EventArchive {
+    pub events: Vec<Event>,
+}
+
+impl EventArchive {
+    /// Archive events from `previous` that started before `cutoff` and are
+    /// missing from the `current` fetch, replacing older copies. Returns how
+    /// many were archived.
+    pub fn absorb(&mut self, previous: &[Event], current: &[Event], cutoff: NaiveDate) -> usize {
+        let mut count = 0;
+        for event in previous {
+            if event.start_day().is_none_or(|d| d >= cutoff) || current.iter().any(|e| e.id == event.id) {
+                continue;
+            }
+            self.events.retain(|e| e.id != event.id);
+            self.events.push(event.clone());
+            count += 1;
+        }
+        count
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn event(id: i64, start: &str) -> Event {
+        serde_json::from_value(serde_json::json!({ "id": id, "name": format!("Event {}", id), "startDate": start })).unwrap()
+    }
+
+    #[test]
+    fn test_archive_keeps_events_older_than_cutoff() {
+        let cutoff = NaiveDate::from_ymd_opt(2026, 9, 14).unwrap();
+        let previous = vec![event(1, "2026-09-01T19:00:00"), event(2, "2026-09-20T19:00:00"), event(3, "2026-08-15")];
+        let current = vec![event(2, "2026-09-20T19:00:00"), event(3, "2026-08-15")];
+
+        let mut archive = EventArchive::default();
+        assert_eq!(archive.absorb(&previous, &current, cutoff), 1, "dropped and past the cutoff");
+        assert_eq!(archive.events[0].id, 1);
+        assert_eq!(archive.absorb(&previous, &[], cutoff), 2, "re-archiving replaces the copy");
+        assert_eq!(archive.events.len(), 2);
+        assert!(archive.events[0].is_past(cutoff));
+    }
+
     #[test]
     fn test_event_matches_search() {
         let event = Event {
@@ -0,0 +1,198 @@
+//! Event attendance history.
+//!
+//! Scoutbook only returns who was invited to and attended an event while the
+//! event's details are cached, and a refresh briefly replaces them with the
+//! bare event list. Each past event's scout attendance is kept here as an
+//! `AttendanceRecord` so percentages don't jump around between refreshes and
+//! survive after the event ages out of Scoutbook's results.
+
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use super::event::Event;
+
+/// Which scouts were invited to and attended one past event.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AttendanceRecord {
+    pub event_id: i64,
+    pub name: String,
+    pub date: NaiveDate,
+    /// The event's derived type, e.g. "Campout"
+    pub event_type: String,
+    /// Scouts invited (user IDs)
+    pub invited: Vec<i64>,
+    /// Invited scouts marked as attended
+    pub attended: Vec<i64>,
+}
+
+impl AttendanceRecord {
+    /// A record of `event`'s scout attendance, if it has a date and any
+    /// scouts were invited.
+    pub fn from_event(event: &Event) -> Option<Self> {
+        let date = event.start_day()?;
+        let scouts = event.invited_users.iter().filter(|u| !u.is_adult);
+        let invited: Vec<i64> = scouts.clone().map(|u| u.user_id).collect();
+        if invited.is_empty() {
+            return None;
+        }
+        Some(AttendanceRecord {
+            event_id: event.id,
+            name: event.name.clone(),
+            date,
+            event_type: event.derived_type().to_string(),
+            invited,
+            attended: scouts.filter(|u| u.attended).map(|u| u.user_id).collect(),
+        })
+    }
+}
+
+/// Events attended out of events invited to.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AttendanceTally {
+    pub attended: usize,
+    pub invited: usize,
+}
+
+impl AttendanceTally {
+    fn add(&mut self, record: &AttendanceRecord, user_id: i64) {
+        if record.invited.contains(&user_id) {
+            self.invited += 1;
+            if record.attended.contains(&user_id) {
+                self.attended += 1;
+            }
+        }
+    }
+
+    /// Percentage attended, if the scout was invited to anything.
+    pub fn percent(&self) -> Option<u32> {
+        (self.invited > 0).then(|| ((self.attended as f64 / self.invited as f64) * 100.0).round() as u32)
+    }
+}
+
+/// Attendance records for every past event, newest first.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EventAttendance {
+    pub records: Vec<AttendanceRecord>,
+}
+
+impl EventAttendance {
+    /// Record attendance for events in `events` that started before `today`
+    /// and have invitee details, replacing older records for the same event.
+    /// Returns whether anything changed.
+    pub fn update<'a>(&mut self, events: impl IntoIterator<Item = &'a Event>, today: NaiveDate) -> bool {
+        let mut changed = false;
+        for event in events {
+            if !event.is_past(today) {
+                continue;
+            }
+            let Some(record) = AttendanceRecord::from_event(event) else { continue };
+            match self.records.iter_mut().find(|r| r.event_id == record.event_id) {
+                Some(existing) if *existing == record => {}
+                Some(existing) => {
+                    *existing = record;
+                    changed = true;
+                }
+                None => {
+                    self.records.push(record);
+                    changed = true;
+                }
+            }
+        }
+        if changed {
+            self.records.sort_by_key(|r| std::cmp::Reverse(r.date));
+        }
+        changed
+    }
+
+    /// One scout's attendance across all recorded events.
+    pub fn for_scout(&self, user_id: i64) -> AttendanceTally {
+        let mut tally = AttendanceTally::default();
+        for record in &self.records {
+            tally.add(record, user_id);
+        }
+        tally
+    }
+
+    /// Attendance by event type, for one scout or (with `None`) the troop as
+    /// a whole, counting each invitation. Types are in name order.
+    pub fn by_type(&self, user_id: Option<i64>) -> Vec<(String, AttendanceTally)> {
+        let mut types: BTreeMap<&str, AttendanceTally> = BTreeMap::new();
+        for record in &self.records {
+            let tally = types.entry(&record.event_type).or_default();
+            match user_id {
+                Some(id) => tally.add(record, id),
+                None => {
+                    tally.invited += record.invited.len();
+                    tally.attended += record.attended.len();
+                }
+            }
+        }
+        types
+            .into_iter()
+            .filter(|(_, t)| t.invited > 0)
+            .map(|(name, t)| (name.to_string(), t))
+            .collect()
+    }
+
+    /// Recorded events the scout was invited to, newest first.
+    pub fn events_for(&self, user_id: i64) -> impl Iterator<Item = &AttendanceRecord> {
+        self.records.iter().filter(move |r| r.invited.contains(&user_id))
+    }
+
+    /// Forget a scout, e.g. when their data is purged. Returns how many
+    /// records mentioned them.
+    pub fn remove_scout(&mut self, user_id: i64) -> usize {
+        let mut count = 0;
+        for record in &mut self.records {
+            if record.invited.contains(&user_id) {
+                record.invited.retain(|&id| id != user_id);
+                record.attended.retain(|&id| id != user_id);
+                count += 1;
+            }
+        }
+        self.records.retain(|r| !r.invited.is_empty());
+        count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_attendance() {
+        let events: Vec<Event> = serde_json::from_value(serde_json::json!([
+            { "id": 1, "name": "Fall Campout", "startDate": "2026-09-12", "eventType": "Campout", "invitedUsers": [
+                { "userId": 1, "firstName": "Sam", "lastName": "Smith", "attended": true },
+                { "userId": 2, "firstName": "Sue", "lastName": "Smith" },
+                { "userId": 9, "firstName": "Pat", "lastName": "Smith", "attended": true, "isAdult": true }
+            ]},
+            { "id": 2, "name": "Food Drive", "startDate": "2026-10-03", "eventType": "Service", "invitedUsers": [
+                { "userId": 1, "firstName": "Sam", "lastName": "Smith", "attended": true }
+            ]},
+            { "id": 3, "name": "Winter Campout", "startDate": "2026-12-05", "eventType": "Campout", "invitedUsers": [
+                { "userId": 1, "firstName": "Sam", "lastName": "Smith" }
+            ]},
+            { "id": 4, "name": "Court of Honor", "startDate": "2026-10-01" }
+        ]))
+        .unwrap();
+        let today = NaiveDate::from_ymd_opt(2026, 10, 15).unwrap();
+
+        let mut attendance = EventAttendance::default();
+        assert!(attendance.update(&events, today));
+        assert!(!attendance.update(&events, today), "unchanged");
+        assert_eq!(attendance.records.iter().map(|r| r.event_id).collect::<Vec<_>>(), [2, 1], "past events with scouts");
+
+        assert_eq!(attendance.for_scout(1), AttendanceTally { attended: 2, invited: 2 });
+        assert_eq!(attendance.for_scout(2).percent(), Some(0));
+        assert_eq!(attendance.for_scout(9).percent(), None, "adults aren't counted");
+        let troop = attendance.by_type(None);
+        assert_eq!(troop[0], ("Campout".to_string(), AttendanceTally { attended: 1, invited: 2 }));
+        assert_eq!(attendance.by_type(Some(2)).len(), 1);
+
+        assert_eq!(attendance.remove_scout(1), 2);
+        assert_eq!(attendance.records.len(), 1, "the service event had only scout 1");
+    }
+}
@@ -0,0 +1,166 @@
+//! Locally saved event templates.
+//!
+//! A template captures what repeats from one outing to the next: length,
+//! location, who gets invited, and what to pack. Scoutbook's API doesn't
+//! create events, so a template produces an `EventPlan` to enter there.
+
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+
+use super::event::Event;
+
+/// Packing list a new campout template starts with
+pub const DEFAULT_CAMPOUT_PACKING_LIST: [&str; 8] = [
+    "Tent and ground cloth",
+    "Sleeping bag and pad",
+    "Rain gear",
+    "Mess kit and water bottle",
+    "Flashlight or headlamp",
+    "Scout Handbook",
+    "Medications (give to adult in charge)",
+    "Signed activity consent",
+];
+
+/// Someone invited to the event the template was saved from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TemplateInvitee {
+    pub user_id: i64,
+    pub name: String,
+}
+
+/// A reusable event outline.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventTemplate {
+    pub name: String,
+    pub event_type: Option<String>,
+    pub duration_minutes: i64,
+    pub location: Option<String>,
+    pub invitees: Vec<TemplateInvitee>,
+    pub packing_list: Vec<String>,
+}
+
+/// A new event laid out from a template, ready to enter in Scoutbook.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventPlan {
+    pub name: String,
+    pub start: NaiveDateTime,
+    pub end: NaiveDateTime,
+    pub location: Option<String>,
+    pub invitees: Vec<TemplateInvitee>,
+    pub packing_list: Vec<String>,
+}
+
+/// Parse an API event date, with or without a time or UTC offset.
+pub fn parse_event_datetime(value: &str) -> Option<NaiveDateTime> {
+    if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+        return Some(dt.naive_local());
+    }
+    NaiveDateTime::parse_from_str(value.get(..19).unwrap_or(value), "%Y-%m-%dT%H:%M:%S")
+        .ok()
+        .or_else(|| NaiveDate::parse_from_str(value.get(..10)?, "%Y-%m-%d").ok()?.and_hms_opt(0, 0, 0))
+}
+
+impl EventTemplate {
+    /// Capture an existing event. Campouts start with the default packing list.
+    pub fn from_event(event: &Event) -> Self {
+        let start = event.start_date.as_deref().and_then(parse_event_datetime);
+        let end = event.end_date.as_deref().and_then(parse_event_datetime);
+        let duration_minutes = match (start, end) {
+            (Some(start), Some(end)) if end > start => (end - start).num_minutes(),
+            _ => 60,
+        };
+        let packing_list = if event.derived_type() == "Camping" {
+            DEFAULT_CAMPOUT_PACKING_LIST.iter().map(|s| s.to_string()).collect()
+        } else {
+            Vec::new()
+        };
+        Self {
+            name: event.name.clone(),
+            event_type: Some(event.derived_type().to_string()),
+            duration_minutes,
+            location: event.location.clone().filter(|l| !l.is_empty()),
+            invitees: event
+                .invited_users
+                .iter()
+                .map(|u| TemplateInvitee { user_id: u.user_id, name: format!("{} {}", u.first_name, u.last_name) })
+                .collect(),
+            packing_list,
+        }
+    }
+
+    /// Lay out a new event starting at `start`.
+    pub fn plan(&self, start: NaiveDateTime) -> EventPlan {
+        EventPlan {
+            name: self.name.clone(),
+            start,
+            end: start + Duration::minutes(self.duration_minutes),
+            location: self.location.clone(),
+            invitees: self.invitees.clone(),
+            packing_list: self.packing_list.clone(),
+        }
+    }
+
+    /// Duration for display, e.g. "2h 30m" or "2 days".
+    pub fn duration_label(&self) -> String {
+        let minutes = self.duration_minutes;
+        if minutes >= 24 * 60 && minutes % (24 * 60) == 0 {
+            format!("{} days", minutes / (24 * 60))
+        } else if minutes >= 60 {
+            match minutes % 60 {
+                0 => format!("{}h", minutes / 60),
+                m => format!("{}h {}m", minutes / 60, m),
+            }
+        } else {
+            format!("{}m", minutes)
+        }
+    }
+}
+
+/// Persisted templates, kept in name order.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EventTemplates {
+    pub templates: Vec<EventTemplate>,
+}
+
+impl EventTemplates {
+    /// Save a template, replacing one with the same name.
+    pub fn save(&mut self, template: EventTemplate) {
+        self.templates.retain(|t| t.name != template.name);
+        self.templates.push(template);
+        self.templates.sort_by(|a, b| a.name.cmp(&b.name));
+    }
+
+    pub fn remove(&mut self, index: usize) -> Option<EventTemplate> {
+        (index < self.templates.len()).then(|| self.templates.remove(index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_template_from_event_and_plan() {
+        let event: Event = serde_json::from_value(serde_json::json!({
+            "id": 1, "name": "Fall Campout", "location": "Camp Lowe",
+            "startDate": "2026-10-16T18:00:00", "endDate": "2026-10-18T12:00:00",
+            "invitedUsers": [{ "userId": 7, "firstName": "Sam", "lastName": "Scout", "rsvpCode": "Y" }]
+        }))
+        .unwrap();
+        let template = EventTemplate::from_event(&event);
+        assert_eq!(template.duration_minutes, 42 * 60);
+        assert_eq!(template.duration_label(), "42h");
+        assert_eq!(template.invitees[0].name, "Sam Scout");
+        assert_eq!(template.packing_list.len(), DEFAULT_CAMPOUT_PACKING_LIST.len());
+
+        let start = NaiveDate::from_ymd_opt(2027, 4, 9).unwrap().and_hms_opt(18, 0, 0).unwrap();
+        let plan = template.plan(start);
+        assert_eq!(plan.end.to_string(), "2027-04-11 12:00:00");
+
+        let mut templates = EventTemplates::default();
+        templates.save(template.clone());
+        templates.save(EventTemplate { duration_minutes: 90, ..template });
+        assert_eq!(templates.templates.len(), 1);
+        assert_eq!(templates.templates[0].duration_label(), "1h 30m");
+    }
+}
@@ -0,0 +1,345 @@
+//! Rolling activity feed derived from changes between data refreshes.
+//!
+//! Each refresh compares the newly fetched data against what was cached before
+//! and records what changed ("J. Smith completed Tenderfoot 4b", "Campout RSVP +3").
+//! The feed is persisted so it accumulates across sessions.
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use super::advancement::{MeritBadgeProgress, RankProgress, RankRequirement};
+use super::event::{Event, RsvpStatus};
+use super::person::Youth;
+
+/// Entries older than this are dropped when the feed is pruned
+pub const FEED_RETENTION_DAYS: i64 = 90;
+
+/// Upper bound on stored entries, newest kept
+pub const MAX_FEED_ENTRIES: usize = 500;
+
+/// Kind of change an entry records, used for filtering.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FeedCategory {
+    Rank,
+    Requirement,
+    MeritBadge,
+    Rsvp,
+    Roster,
+    Event,
+}
+
+impl FeedCategory {
+    pub const ALL: [FeedCategory; 6] = [
+        FeedCategory::Rank,
+        FeedCategory::Requirement,
+        FeedCategory::MeritBadge,
+        FeedCategory::Rsvp,
+        FeedCategory::Roster,
+        FeedCategory::Event,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            FeedCategory::Rank => "Ranks",
+            FeedCategory::Requirement => "Requirements",
+            FeedCategory::MeritBadge => "Merit Badges",
+            FeedCategory::Rsvp => "RSVPs",
+            FeedCategory::Roster => "Roster",
+            FeedCategory::Event => "Events",
+        }
+    }
+
+    /// Cycle a category filter: all -> each category -> all.
+    pub fn cycle(filter: Option<FeedCategory>) -> Option<FeedCategory> {
+        match filter {
+            None => Some(Self::ALL[0]),
+            Some(current) => {
+                let idx = Self::ALL.iter().position(|c| *c == current).unwrap_or(0);
+                Self::ALL.get(idx + 1).copied()
+            }
+        }
+    }
+}
+
+/// A single change observed during a refresh.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FeedEntry {
+    pub date: NaiveDate,
+    pub category: FeedCategory,
+    /// Scout the change belongs to, if any
+    pub user_id: Option<i64>,
+    pub text: String,
+}
+
+impl FeedEntry {
+    /// "Oct 3: J. Smith completed Tenderfoot 4b"
+    pub fn display(&self) -> String {
+        format!("{}: {}", self.date.format("%b %-d"), self.text)
+    }
+}
+
+/// Persisted list of feed entries, newest first.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ActivityFeed {
+    pub entries: Vec<FeedEntry>,
+}
+
+impl ActivityFeed {
+    /// Add new entries to the top of the feed and prune old ones.
+    pub fn record(&mut self, mut entries: Vec<FeedEntry>, today: NaiveDate) {
+        if entries.is_empty() {
+            return;
+        }
+        entries.append(&mut self.entries);
+        self.entries = entries;
+        self.prune(today);
+    }
+
+    /// Drop entries past the retention window or beyond the size cap.
+    pub fn prune(&mut self, today: NaiveDate) {
+        self.entries.retain(|e| (today - e.date).num_days() <= FEED_RETENTION_DAYS);
+        self.entries.truncate(MAX_FEED_ENTRIES);
+    }
+
+    /// Drop the entries about a scout. Returns how many were removed.
+    pub fn remove_scout(&mut self, user_id: i64) -> usize {
+        let before = self.entries.len();
+        self.entries.retain(|e| e.user_id != Some(user_id));
+        before - self.entries.len()
+    }
+
+    /// Entries matching an optional category and scout filter.
+    pub fn filtered(&self, category: Option<FeedCategory>, user_id: Option<i64>) -> Vec<&FeedEntry> {
+        self.entries
+            .iter()
+            .filter(|e| category.is_none_or(|c| e.category == c))
+            .filter(|e| user_id.is_none_or(|id| e.user_id == Some(id)))
+            .collect()
+    }
+}
+
+/// Name used in feed text: first initial and last name ("J. Smith").
+pub fn feed_name(youth: &Youth) -> String {
+    match youth.first_name.chars().next() {
+        Some(initial) => format!("{}. {}", initial, youth.last_name),
+        None => youth.last_name.clone(),
+    }
+}
+
+/// Ranks newly completed or awarded since the previous fetch.
+pub fn diff_ranks(
+    user_id: i64,
+    scout: &str,
+    old: &[RankProgress],
+    new: &[RankProgress],
+    today: NaiveDate,
+) -> Vec<FeedEntry> {
+    let mut entries = Vec::new();
+    for rank in new {
+        let before = old.iter().find(|r| r.rank_id == rank.rank_id);
+        let was_completed = before.is_some_and(|r| r.is_completed());
+        let was_awarded = before.is_some_and(|r| r.is_awarded());
+        let text = if rank.is_awarded() && !was_awarded {
+            format!("{} was awarded {}", scout, rank.rank_name)
+        } else if rank.is_completed() && !was_completed {
+            format!("{} completed {}", scout, rank.rank_name)
+        } else {
+            continue;
+        };
+        entries.push(FeedEntry { date: today, category: FeedCategory::Rank, user_id: Some(user_id), text });
+    }
+    entries
+}
+
+/// Rank requirements newly completed since the previous fetch.
+pub fn diff_rank_requirements(
+    user_id: i64,
+    scout: &str,
+    rank_name: &str,
+    old: &[RankRequirement],
+    new: &[RankRequirement],
+    today: NaiveDate,
+) -> Vec<FeedEntry> {
+    new.iter()
+        .filter(|req| req.is_completed())
+        .filter(|req| {
+            !old.iter()
+                .any(|o| o.number() == req.number() && o.is_completed())
+        })
+        .map(|req| FeedEntry {
+            date: today,
+            category: FeedCategory::Requirement,
+            user_id: Some(user_id),
+            text: format!("{} completed {} {}", scout, rank_name, req.number()),
+        })
+        .collect()
+}
+
+/// Merit badges newly started, completed, or awarded since the previous fetch.
+pub fn diff_merit_badges(
+    user_id: i64,
+    scout: &str,
+    old: &[MeritBadgeProgress],
+    new: &[MeritBadgeProgress],
+    today: NaiveDate,
+) -> Vec<FeedEntry> {
+    let mut entries = Vec::new();
+    for badge in new.iter().filter(|b| !b.name.is_empty()) {
+        let before = old.iter().find(|b| b.name == badge.name);
+        let text = match before {
+            None if badge.is_completed() => format!("{} completed {}", scout, badge.name),
+            None => format!("{} started {}", scout, badge.name),
+            Some(b) if badge.is_awarded() && !b.is_awarded() => {
+                format!("{} was awarded {}", scout, badge.name)
+            }
+            Some(b) if badge.is_completed() && !b.is_completed() => {
+                format!("{} completed {}", scout, badge.name)
+            }
+            Some(_) => continue,
+        };
+        entries.push(FeedEntry { date: today, category: FeedCategory::MeritBadge, user_id: Some(user_id), text });
+    }
+    entries
+}
+
+/// Changes in the number of "going" RSVPs per event.
+///
+/// Only events present in both lists with invitee data are compared, so an
+/// event list refreshed without RSVP details doesn't register as cancellations.
+pub fn diff_event_rsvps(old: &[Event], new: &[Event], today: NaiveDate) -> Vec<FeedEntry> {
+    let going = |e: &Event| {
+        e.invited_users
+            .iter()
+            .filter(|u| u.status() == RsvpStatus::Going)
+            .count() as i64
+    };
+
+    new.iter()
+        .filter(|e| !e.invited_users.is_empty())
+        .filter_map(|event| {
+            let before = old.iter().find(|o| o.id == event.id && !o.invited_users.is_empty())?;
+            let delta = going(event) - going(before);
+            (delta != 0).then(|| FeedEntry {
+                date: today,
+                category: FeedCategory::Rsvp,
+                user_id: None,
+                text: format!("{} RSVP {:+}", event.name, delta),
+            })
+        })
+        .collect()
+}
+
+/// Upcoming events added to the calendar since the previous fetch. Nothing
+/// on the first fetch, when every event would be new.
+pub fn diff_new_events(old: &[Event], new: &[Event], today: NaiveDate) -> Vec<FeedEntry> {
+    if old.is_empty() {
+        return Vec::new();
+    }
+    new.iter()
+        .filter(|e| !e.is_past(today) && !old.iter().any(|o| o.id == e.id))
+        .map(|e| FeedEntry {
+            date: today,
+            category: FeedCategory::Event,
+            user_id: None,
+            text: match e.start_day() {
+                Some(day) => format!("{} added for {}", e.name, day.format("%b %-d")),
+                None => format!("{} added", e.name),
+            },
+        })
+        .collect()
+}
+
+/// Scouts who joined or left the roster since the previous fetch.
+pub fn diff_roster(old: &[Youth], new: &[Youth], today: NaiveDate) -> Vec<FeedEntry> {
+    let has = |list: &[Youth], y: &Youth| list.iter().any(|o| o.user_id.is_some() && o.user_id == y.user_id);
+
+    let joined = new.iter().filter(|y| y.user_id.is_some() && !has(old, y)).map(|y| (y, "joined the troop"));
+    let left = old.iter().filter(|y| y.user_id.is_some() && !has(new, y)).map(|y| (y, "left the roster"));
+
+    joined
+        .chain(left)
+        .map(|(y, what)| FeedEntry {
+            date: today,
+            category: FeedCategory::Roster,
+            user_id: y.user_id,
+            text: format!("{} {}", feed_name(y), what),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 10, d).unwrap()
+    }
+
+    fn rank(id: i64, name: &str, completed: Option<&str>) -> RankProgress {
+        RankProgress {
+            rank_id: id,
+            version_id: None,
+            rank_name: name.to_string(),
+            date_completed: completed.map(str::to_string),
+            date_awarded: None,
+            requirements_completed: None,
+            requirements_total: None,
+            percent_completed: None,
+            level: Some(id as i32),
+        }
+    }
+
+    #[test]
+    fn test_diff_ranks_reports_new_completions() {
+        let old = vec![rank(1, "Scout", Some("2026-01-01")), rank(2, "Tenderfoot", None)];
+        let new = vec![rank(1, "Scout", Some("2026-01-01")), rank(2, "Tenderfoot", Some("2026-10-02"))];
+
+        let entries = diff_ranks(7, "J. Smith", &old, &new, day(3));
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].display(), "Oct 3: J. Smith completed Tenderfoot");
+        assert_eq!(entries[0].user_id, Some(7));
+    }
+
+    #[test]
+    fn test_diff_new_events() {
+        let events = |list: serde_json::Value| -> Vec<Event> { serde_json::from_value(list).unwrap() };
+        let old = events(serde_json::json!([{ "id": 1, "name": "Campout", "startDate": "2026-10-17" }]));
+        let new = events(serde_json::json!([
+            { "id": 1, "name": "Campout", "startDate": "2026-10-17" },
+            { "id": 2, "name": "Food Drive", "startDate": "2026-11-07T09:00:00" },
+            { "id": 3, "name": "Last Month", "startDate": "2026-09-01" },
+        ]));
+
+        let entries = diff_new_events(&old, &new, day(3));
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].text, "Food Drive added for Nov 7");
+        assert!(diff_new_events(&[], &new, day(3)).is_empty(), "first fetch");
+    }
+
+    #[test]
+    fn test_feed_record_and_filter() {
+        let mut feed = ActivityFeed::default();
+        let entry = |date, category, user_id: Option<i64>| FeedEntry { date, category, user_id, text: String::new() };
+
+        feed.record(vec![entry(day(1), FeedCategory::Rsvp, None)], day(1));
+        feed.record(vec![entry(day(2), FeedCategory::Rank, Some(7))], day(2));
+
+        assert_eq!(feed.entries[0].date, day(2), "newest first");
+        assert_eq!(feed.filtered(Some(FeedCategory::Rank), None).len(), 1);
+        assert_eq!(feed.filtered(None, Some(7)).len(), 1);
+        assert_eq!(feed.filtered(None, None).len(), 2);
+
+        feed.prune(day(2) + chrono::Duration::days(FEED_RETENTION_DAYS + 1));
+        assert!(feed.entries.is_empty());
+    }
+
+    #[test]
+    fn test_category_cycle_wraps_to_all() {
+        let mut filter = None;
+        for _ in 0..FeedCategory::ALL.len() {
+            filter = FeedCategory::cycle(filter);
+            assert!(filter.is_some());
+        }
+        assert_eq!(FeedCategory::cycle(filter), None);
+    }
+}
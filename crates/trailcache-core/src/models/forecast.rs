@@ -0,0 +1,234 @@
+//! Membership history and a year-ahead roster forecast.
+//!
+//! Each roster refresh is compared with the one before it and the scouts
+//! who joined, left, and aged out are counted by month. Only counts are
+//! kept, so nothing about departed scouts outlives the retention policy.
+//! The forecast starts from today's roster, drops scouts on their 18th
+//! birthday, loses the rest at the historical attrition rate, and adds new
+//! scouts: the configured crossover class in its month, or the historical
+//! join rate spread across the year.
+
+use chrono::{Datelike, Duration, Months, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use super::person::Youth;
+
+/// How far ahead the forecast runs.
+pub const FORECAST_MONTHS: u32 = 12;
+
+/// Months of history needed before attrition and joins are estimated.
+pub const MIN_HISTORY_MONTHS: usize = 3;
+
+/// Months of history kept.
+const HISTORY_MONTHS: usize = 36;
+
+const AGE_OUT: u32 = 18;
+
+/// Expected crossovers for the forecast, e.g.
+/// `"forecast": { "expected_crossovers": 8, "crossover_month": 3 }`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ForecastSettings {
+    /// Arrow of Light scouts expected to join; the join rate seen in past
+    /// refreshes is used when unset
+    #[serde(default)]
+    pub expected_crossovers: Option<u32>,
+    /// Month (1-12) they usually cross over
+    #[serde(default = "default_crossover_month")]
+    pub crossover_month: u32,
+}
+
+fn default_crossover_month() -> u32 {
+    3
+}
+
+impl Default for ForecastSettings {
+    fn default() -> Self {
+        Self { expected_crossovers: None, crossover_month: default_crossover_month() }
+    }
+}
+
+impl ForecastSettings {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Roster changes seen during one month.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MembershipMonth {
+    /// First of the month
+    pub month: NaiveDate,
+    /// Roster size at the last refresh that month
+    pub members: usize,
+    pub joined: usize,
+    /// Left before turning 18
+    pub left: usize,
+    pub aged_out: usize,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MembershipHistory {
+    pub months: Vec<MembershipMonth>,
+}
+
+fn first_of_month(date: NaiveDate) -> NaiveDate {
+    date.with_day(1).unwrap_or(date)
+}
+
+fn eighteenth_birthday(youth: &Youth) -> Option<NaiveDate> {
+    youth.date_of_birth()?.checked_add_months(Months::new(12 * AGE_OUT))
+}
+
+impl MembershipHistory {
+    /// Count the changes from `previous` to `roster`. The first roster seen,
+    /// and an empty one from a failed fetch, only set the month's size.
+    pub fn record(&mut self, previous: &[Youth], roster: &[Youth], today: NaiveDate) {
+        if roster.is_empty() {
+            return;
+        }
+        let month = first_of_month(today);
+        if self.months.last().is_none_or(|m| m.month != month) {
+            self.months.push(MembershipMonth { month, members: 0, joined: 0, left: 0, aged_out: 0 });
+        }
+        let current = self.months.last_mut().expect("pushed above");
+        current.members = roster.len();
+
+        if !previous.is_empty() {
+            let on = |list: &[Youth], id: Option<i64>| list.iter().any(|y| y.user_id == id);
+            current.joined += roster.iter().filter(|y| !on(previous, y.user_id)).count();
+            for gone in previous.iter().filter(|y| !on(roster, y.user_id)) {
+                // A month's grace for the roster to catch up with a birthday
+                let aged_out = eighteenth_birthday(gone).is_some_and(|d| d <= today + Duration::days(31));
+                if aged_out {
+                    current.aged_out += 1;
+                } else {
+                    current.left += 1;
+                }
+            }
+        }
+
+        let excess = self.months.len().saturating_sub(HISTORY_MONTHS);
+        self.months.drain(..excess);
+    }
+
+    /// The last year's months, once there are enough to go on.
+    fn recent(&self, today: NaiveDate) -> Option<&[MembershipMonth]> {
+        let cutoff = first_of_month(today).checked_sub_months(Months::new(11))?;
+        let start = self.months.iter().position(|m| m.month >= cutoff)?;
+        let recent = &self.months[start..];
+        (recent.len() >= MIN_HISTORY_MONTHS).then_some(recent)
+    }
+
+    /// Share of scouts who leave in a year, not counting age-outs.
+    pub fn attrition_rate(&self, today: NaiveDate) -> Option<f64> {
+        let recent = self.recent(today)?;
+        let left: usize = recent.iter().map(|m| m.left).sum();
+        let average = recent.iter().map(|m| m.members).sum::<usize>() as f64 / recent.len() as f64;
+        if average == 0.0 {
+            return None;
+        }
+        Some((left as f64 / average * 12.0 / recent.len() as f64).min(1.0))
+    }
+
+    /// New scouts in a year at the recent rate.
+    pub fn joins_per_year(&self, today: NaiveDate) -> Option<f64> {
+        let recent = self.recent(today)?;
+        let joined: usize = recent.iter().map(|m| m.joined).sum();
+        Some(joined as f64 * 12.0 / recent.len() as f64)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MembershipForecast {
+    /// Projected roster size at the end of this month and each of the next
+    /// `FORECAST_MONTHS`
+    pub months: Vec<(NaiveDate, u32)>,
+    pub aging_out: usize,
+    /// None until there is enough history
+    pub attrition_rate: Option<f64>,
+    pub expected_joins: f64,
+}
+
+impl MembershipForecast {
+    pub fn new(roster: &[Youth], history: &MembershipHistory, settings: ForecastSettings, today: NaiveDate) -> Self {
+        let attrition_rate = history.attrition_rate(today);
+        let monthly_stay = (1.0 - attrition_rate.unwrap_or(0.0)).powf(1.0 / 12.0);
+        let spread_joins = match settings.expected_crossovers {
+            Some(_) => 0.0,
+            None => history.joins_per_year(today).unwrap_or(0.0) / 12.0,
+        };
+
+        let this_month = first_of_month(today);
+        let mut months = Vec::new();
+        let mut joiners = 0.0;
+        let mut expected_joins = 0.0;
+        let mut aging_out = 0;
+        for i in 0..=FORECAST_MONTHS {
+            let Some(month) = this_month.checked_add_months(Months::new(i)) else { break };
+            let end = month.checked_add_months(Months::new(1)).unwrap_or(month);
+            let staying = roster.iter().filter(|y| eighteenth_birthday(y).is_none_or(|d| d >= end)).count();
+            if i == FORECAST_MONTHS {
+                aging_out = roster.len() - staying;
+            }
+            if i > 0 {
+                joiners *= monthly_stay;
+                let arriving = match settings.expected_crossovers {
+                    Some(n) if month.month() == settings.crossover_month => n as f64,
+                    Some(_) => 0.0,
+                    None => spread_joins,
+                };
+                joiners += arriving;
+                expected_joins += arriving;
+            }
+            let projected = staying as f64 * monthly_stay.powi(i as i32) + joiners;
+            months.push((month, projected.round() as u32));
+        }
+
+        Self { months, aging_out, attrition_rate, expected_joins }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scouts(ids: &[(i64, &str)]) -> Vec<Youth> {
+        let json: Vec<_> = ids
+            .iter()
+            .map(|(id, dob)| serde_json::json!({ "userId": id, "firstName": "S", "lastName": id.to_string(), "registrarInfo": { "dateOfBirth": dob } }))
+            .collect();
+        serde_json::from_value(serde_json::Value::Array(json)).unwrap()
+    }
+
+    #[test]
+    fn test_history_and_forecast() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let young: Vec<(i64, &str)> = (1..=20).map(|id| (id, "2013-05-01")).collect();
+        let mut roster = scouts(&young);
+        roster.extend(scouts(&[(21, "2008-10-20")]));
+
+        let mut history = MembershipHistory::default();
+        history.record(&[], &roster, date(2026, 7, 3));
+        assert_eq!(history.months[0].joined, 0, "first roster isn't all joins");
+        let mut later = roster[2..].to_vec();
+        later.extend(scouts(&[(30, "2014-01-01")]));
+        history.record(&roster, &later, date(2026, 8, 10));
+        assert_eq!(history.attrition_rate(date(2026, 8, 20)), None, "two months isn't enough");
+        history.record(&later, &later, date(2026, 9, 10));
+
+        let rate = history.attrition_rate(date(2026, 9, 20)).unwrap();
+        assert!((rate - 2.0 / (61.0 / 3.0) * 4.0).abs() < 1e-9, "two left from about 20 in three months: {}", rate);
+        assert_eq!(history.joins_per_year(date(2026, 9, 20)), Some(4.0));
+
+        // Scout 21 turns 18 on Oct 20, 2026
+        let settings = ForecastSettings { expected_crossovers: Some(6), crossover_month: 3 };
+        let forecast = MembershipForecast::new(&later, &MembershipHistory::default(), settings, date(2026, 9, 20));
+        assert_eq!(forecast.months.len(), 13);
+        assert_eq!(forecast.months[0], (date(2026, 9, 1), 20));
+        assert_eq!(forecast.months[1].1, 19, "ages out in October");
+        assert_eq!(forecast.months[6], (date(2027, 3, 1), 25));
+        assert_eq!(forecast.aging_out, 1);
+        assert_eq!(forecast.expected_joins, 6.0);
+        assert_eq!(forecast.attrition_rate, None);
+    }
+}
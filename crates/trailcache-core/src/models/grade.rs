@@ -0,0 +1,119 @@
+//! School grades that have gone stale since the school year turned over.
+//!
+//! Scoutbook only knows the grade a parent or leader last entered, so it
+//! lags a year once school starts. Each scout's expected grade is worked out
+//! from their birth date: children who are five by September 1 start
+//! kindergarten that fall, the most common cutoff. A recorded grade below
+//! the expected one is treated as not yet rolled over.
+
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use super::person::Youth;
+
+/// Kindergarten age cutoff (month, day).
+const KINDERGARTEN_CUTOFF: (u32, u32) = (9, 1);
+
+const HIGHEST_GRADE: i32 = 12;
+
+/// The day grades advance each year, e.g. `{ "month": 8, "day": 15 }`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GradeRollover {
+    pub month: u32,
+    pub day: u32,
+}
+
+impl Default for GradeRollover {
+    fn default() -> Self {
+        Self { month: 8, day: 1 }
+    }
+}
+
+impl GradeRollover {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// The year the current school year started in.
+    pub fn school_year(&self, today: NaiveDate) -> i32 {
+        if (today.month(), today.day()) >= (self.month, self.day) {
+            today.year()
+        } else {
+            today.year() - 1
+        }
+    }
+
+    /// The grade a child born on `dob` is usually in this school year
+    /// (0 for kindergarten), capped at 12.
+    pub fn expected_grade(&self, dob: NaiveDate, today: NaiveDate) -> i32 {
+        let year = self.school_year(today);
+        let mut age = year - dob.year();
+        if (dob.month(), dob.day()) > KINDERGARTEN_CUTOFF {
+            age -= 1;
+        }
+        (age - 5).min(HIGHEST_GRADE)
+    }
+}
+
+/// A recorded grade that looks a year or more behind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StaleGrade {
+    /// What Scoutbook has
+    pub recorded: i32,
+    /// What the birth date suggests
+    pub expected: i32,
+}
+
+impl Youth {
+    /// The scout's grade if Scoutbook's looks out of date, from their birth
+    /// date. None when it looks current or either is missing.
+    pub fn stale_grade(&self, rollover: GradeRollover, today: NaiveDate) -> Option<StaleGrade> {
+        let recorded = self.grade?;
+        let expected = rollover.expected_grade(self.date_of_birth()?, today);
+        (recorded < expected).then_some(StaleGrade { recorded, expected })
+    }
+
+    /// The grade to show: the expected one with a trailing "*" when the
+    /// recorded grade looks stale.
+    pub fn display_grade(&self, rollover: GradeRollover, today: NaiveDate) -> String {
+        match self.stale_grade(rollover, today) {
+            Some(stale) => format!("{}*", stale.expected),
+            None => self.grade_str(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scout(grade: i32, dob: &str) -> Youth {
+        serde_json::from_value(serde_json::json!({
+            "firstName": "Sam", "lastName": "Ortiz", "grade": grade,
+            "registrarInfo": { "dateOfBirth": dob }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_stale_grade() {
+        let rollover = GradeRollover::default();
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        // Five by Sept 1 2019, so kindergarten in 2019-20 and 7th grade in 2026-27
+        let born = date(2014, 8, 15);
+        assert_eq!(rollover.expected_grade(born, date(2026, 10, 15)), 7);
+        assert_eq!(rollover.expected_grade(born, date(2026, 7, 31)), 6, "before the rollover");
+        assert_eq!(rollover.expected_grade(date(2014, 9, 2), date(2026, 10, 15)), 6);
+        assert_eq!(rollover.expected_grade(date(2007, 1, 1), date(2026, 10, 15)), 12);
+
+        let today = date(2026, 10, 15);
+        let stale = scout(6, "2014-08-15");
+        assert_eq!(stale.stale_grade(rollover, today), Some(StaleGrade { recorded: 6, expected: 7 }));
+        assert_eq!(stale.display_grade(rollover, today), "7*");
+        assert_eq!(scout(7, "2014-08-15").stale_grade(rollover, today), None);
+        assert_eq!(scout(8, "2014-08-15").display_grade(rollover, today), "8", "ahead is left alone");
+
+        let late = GradeRollover { month: 11, day: 1 };
+        assert_eq!(stale.stale_grade(late, today), None, "school year hasn't turned over");
+    }
+}
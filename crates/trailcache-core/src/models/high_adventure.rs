@@ -0,0 +1,318 @@
+//! High-adventure trek eligibility screening.
+//!
+//! Philmont, Sea Base, Northern Tier, and the Summit each set a minimum age
+//! for youth, and troops often add a minimum rank. Physical requirements
+//! (a current medical exam, the base's height/weight chart) aren't in
+//! Scoutbook, so they're recorded here per person. Screening a target trek
+//! date gives every scout and adult their gaps, if any.
+
+use std::collections::BTreeMap;
+
+use chrono::{Datelike, Months, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use super::advancement::ScoutRank;
+use super::person::{Adult, Youth};
+use super::skills::{Certification, SkillMatrix};
+
+/// Medical exams (Parts A-C) count for this long
+const MEDICAL_VALID_MONTHS: u32 = 12;
+
+/// Adults leading a crew have to be at least this old
+pub const CREW_ADVISOR_AGE: i32 = 21;
+
+/// Every crew needs at least this many adults of `CREW_ADVISOR_AGE`
+pub const CREW_ADVISORS_REQUIRED: usize = 2;
+
+/// A national high-adventure base.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Base {
+    #[default]
+    Philmont,
+    SeaBase,
+    NorthernTier,
+    Summit,
+}
+
+impl Base {
+    pub const ALL: [Base; 4] = [Base::Philmont, Base::SeaBase, Base::NorthernTier, Base::Summit];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Base::Philmont => "Philmont",
+            Base::SeaBase => "Sea Base",
+            Base::NorthernTier => "Northern Tier",
+            Base::Summit => "Summit",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        let i = Self::ALL.iter().position(|b| b == self).unwrap_or(0);
+        Self::ALL[(i + 1) % Self::ALL.len()]
+    }
+
+    /// Youth minimum age, and the date it's measured on. These are the
+    /// usual published rules; check the base's current participant guide.
+    fn youth_age_rule(&self, trek: NaiveDate) -> (i32, NaiveDate) {
+        let year = trek.year();
+        match self {
+            Base::Philmont | Base::NorthernTier => (14, NaiveDate::from_ymd_opt(year, 1, 1).unwrap_or(trek)),
+            Base::SeaBase => (13, NaiveDate::from_ymd_opt(year, 9, 1).unwrap_or(trek)),
+            Base::Summit => (13, trek),
+        }
+    }
+
+    /// Whether a year younger is allowed once the scout finishes eighth grade.
+    fn allows_eighth_grade(&self) -> bool {
+        matches!(self, Base::Philmont | Base::NorthernTier | Base::SeaBase)
+    }
+
+    /// Sea Base and Northern Tier treks are on the water.
+    pub fn requires_swimmer(&self) -> bool {
+        matches!(self, Base::SeaBase | Base::NorthernTier)
+    }
+}
+
+/// The trek being planned.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrekPlan {
+    pub base: Base,
+    pub date: NaiveDate,
+    /// Troop policy; `ScoutRank::Unknown` for none
+    pub min_rank: ScoutRank,
+}
+
+/// Physical requirements recorded for one person.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PhysicalRecord {
+    /// Date of the medical exam (Part C)
+    pub exam_on: Option<NaiveDate>,
+    /// Whether they meet the base's height/weight chart, once checked
+    pub weight_ok: Option<bool>,
+}
+
+/// Persisted trek plan and physical records for scouts and adults.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HighAdventure {
+    pub plan: Option<TrekPlan>,
+    #[serde(default)]
+    pub physicals: BTreeMap<i64, PhysicalRecord>,
+}
+
+/// Something keeping a person off the trek.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Gap {
+    AgeUnknown,
+    TooYoung { age: i32, needed: i32 },
+    RankBelow { rank: ScoutRank, needed: ScoutRank },
+    NoMedical,
+    MedicalExpires(NaiveDate),
+    WeightUnchecked,
+    OverWeight,
+    NotSwimmer,
+    YptExpires(Option<NaiveDate>),
+}
+
+impl Gap {
+    pub fn describe(&self) -> String {
+        match self {
+            Gap::AgeUnknown => "No birth date".to_string(),
+            Gap::TooYoung { age, needed } => format!("Age {} (needs {})", age, needed),
+            Gap::RankBelow { rank, needed } => format!("{} (needs {})", rank.display_name(), needed.display_name()),
+            Gap::NoMedical => "No medical exam recorded".to_string(),
+            Gap::MedicalExpires(date) => format!("Medical exam expires {}", date.format("%b %-d, %Y")),
+            Gap::WeightUnchecked => "Height/weight not checked".to_string(),
+            Gap::OverWeight => "Over height/weight chart".to_string(),
+            Gap::NotSwimmer => "Not a current swimmer".to_string(),
+            Gap::YptExpires(Some(date)) => format!("YPT expires {}", date.format("%b %-d, %Y")),
+            Gap::YptExpires(None) => "No YPT on record".to_string(),
+        }
+    }
+}
+
+/// One person's screening result. No gaps means eligible.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Screening {
+    pub user_id: i64,
+    pub name: String,
+    pub is_adult: bool,
+    /// Age on the trek date
+    pub age: Option<i32>,
+    pub gaps: Vec<Gap>,
+}
+
+impl Screening {
+    pub fn is_eligible(&self) -> bool {
+        self.gaps.is_empty()
+    }
+}
+
+fn age_on(dob: NaiveDate, date: NaiveDate) -> i32 {
+    let mut age = date.year() - dob.year();
+    if (date.month(), date.day()) < (dob.month(), dob.day()) {
+        age -= 1;
+    }
+    age
+}
+
+/// School year a date falls in, counted by the year it starts (August).
+fn school_year(date: NaiveDate) -> i32 {
+    if date.month() >= 8 { date.year() } else { date.year() - 1 }
+}
+
+/// Whether a scout in `grade` today has finished eighth grade by `trek`.
+/// School lets out by June, so a summer trek counts the year just ended.
+fn finished_eighth_grade(grade: i32, today: NaiveDate, trek: NaiveDate) -> bool {
+    let grade_at_trek = grade + school_year(trek) - school_year(today);
+    grade_at_trek >= 9 || (grade_at_trek == 8 && (6..8).contains(&trek.month()))
+}
+
+impl HighAdventure {
+    pub fn physical(&self, user_id: i64) -> PhysicalRecord {
+        self.physicals.get(&user_id).cloned().unwrap_or_default()
+    }
+
+    pub fn set_exam(&mut self, user_id: i64, exam_on: Option<NaiveDate>) {
+        self.physicals.entry(user_id).or_default().exam_on = exam_on;
+    }
+
+    /// Move the height/weight check along: unchecked, meets, over.
+    pub fn cycle_weight(&mut self, user_id: i64) -> Option<bool> {
+        let record = self.physicals.entry(user_id).or_default();
+        record.weight_ok = match record.weight_ok {
+            None => Some(true),
+            Some(true) => Some(false),
+            Some(false) => None,
+        };
+        record.weight_ok
+    }
+
+    pub fn remove_person(&mut self, user_id: i64) -> bool {
+        self.physicals.remove(&user_id).is_some()
+    }
+
+    fn physical_gaps(&self, user_id: i64, trek: NaiveDate) -> Vec<Gap> {
+        let record = self.physical(user_id);
+        let mut gaps = Vec::new();
+        match record.exam_on.and_then(|d| d.checked_add_months(Months::new(MEDICAL_VALID_MONTHS))) {
+            None => gaps.push(Gap::NoMedical),
+            Some(expires) if expires <= trek => gaps.push(Gap::MedicalExpires(expires)),
+            Some(_) => {}
+        }
+        match record.weight_ok {
+            None => gaps.push(Gap::WeightUnchecked),
+            Some(false) => gaps.push(Gap::OverWeight),
+            Some(true) => {}
+        }
+        gaps
+    }
+
+    /// Screen every scout and adult against `plan`. Scouts come first, each
+    /// group by name.
+    pub fn screen(&self, plan: &TrekPlan, youth: &[Youth], adults: &[Adult], skills: &SkillMatrix, today: NaiveDate) -> Vec<Screening> {
+        let swimmer = |user_id: i64| {
+            skills.get(user_id, Certification::SwimTest).is_some_and(|r| r.qualifies(plan.date))
+        };
+        let mut results = Vec::new();
+
+        for y in youth {
+            let Some(user_id) = y.user_id else { continue };
+            let mut gaps = Vec::new();
+            let age = y.date_of_birth().map(|dob| age_on(dob, plan.date));
+            match y.date_of_birth() {
+                None => gaps.push(Gap::AgeUnknown),
+                Some(dob) => {
+                    let (needed, as_of) = plan.base.youth_age_rule(plan.date);
+                    let age_as_of = age_on(dob, as_of);
+                    let eighth_grade = plan.base.allows_eighth_grade()
+                        && age_on(dob, plan.date) >= needed - 1
+                        && y.grade.is_some_and(|g| finished_eighth_grade(g, today, plan.date));
+                    if age_as_of < needed && !eighth_grade {
+                        gaps.push(Gap::TooYoung { age: age_as_of, needed });
+                    }
+                }
+            }
+            let rank = ScoutRank::parse(y.current_rank.as_deref());
+            if rank.order() < plan.min_rank.order() {
+                gaps.push(Gap::RankBelow { rank, needed: plan.min_rank });
+            }
+            gaps.extend(self.physical_gaps(user_id, plan.date));
+            if plan.base.requires_swimmer() && !swimmer(user_id) {
+                gaps.push(Gap::NotSwimmer);
+            }
+            results.push(Screening { user_id, name: y.display_name(), is_adult: false, age, gaps });
+        }
+        results.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut adult_results = Vec::new();
+        for a in adults {
+            let Some(user_id) = a.user_id else { continue };
+            let mut gaps = Vec::new();
+            let age = a.date_of_birth().map(|dob| age_on(dob, plan.date));
+            let ypt = a.ypt_expires();
+            if ypt.is_none_or(|d| d <= plan.date) {
+                gaps.push(Gap::YptExpires(ypt));
+            }
+            gaps.extend(self.physical_gaps(user_id, plan.date));
+            if plan.base.requires_swimmer() && !swimmer(user_id) {
+                gaps.push(Gap::NotSwimmer);
+            }
+            adult_results.push(Screening { user_id, name: a.display_name(), is_adult: true, age, gaps });
+        }
+        adult_results.sort_by(|a, b| a.name.cmp(&b.name));
+        results.extend(adult_results);
+        results
+    }
+}
+
+/// Eligible adults old enough to lead a crew.
+pub fn crew_advisors(results: &[Screening]) -> usize {
+    results
+        .iter()
+        .filter(|s| s.is_adult && s.is_eligible() && s.age.is_some_and(|a| a >= CREW_ADVISOR_AGE))
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scout(id: i64, dob: &str, grade: i32, rank: &str) -> Youth {
+        serde_json::from_value(serde_json::json!({
+            "userId": id, "firstName": "Scout", "lastName": id.to_string(), "grade": grade,
+            "currentRankName": rank, "registrarInfo": { "dateOfBirth": dob }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_screen_youth() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 1).unwrap();
+        let trek = NaiveDate::from_ymd_opt(2027, 7, 10).unwrap();
+        let plan = TrekPlan { base: Base::Philmont, date: trek, min_rank: ScoutRank::FirstClass };
+        let youth = [
+            // 14 by Jan 1
+            scout(1, "2012-06-01", 9, "Star"),
+            // 13, finishing eighth grade before the trek
+            scout(2, "2013-09-01", 8, "First Class"),
+            // 13 and only finishing seventh grade
+            scout(3, "2013-09-01", 7, "Tenderfoot"),
+        ];
+        let mut ha = HighAdventure::default();
+        for id in [1, 2, 3] {
+            ha.set_exam(id, Some(NaiveDate::from_ymd_opt(2027, 1, 15).unwrap()));
+            assert_eq!(ha.cycle_weight(id), Some(true));
+        }
+        ha.set_exam(2, Some(NaiveDate::from_ymd_opt(2026, 6, 1).unwrap()));
+
+        let results = ha.screen(&plan, &youth, &[], &SkillMatrix::default(), today);
+        assert!(results[0].is_eligible());
+        assert_eq!(results[1].gaps, [Gap::MedicalExpires(NaiveDate::from_ymd_opt(2027, 6, 1).unwrap())]);
+        assert_eq!(results[2].gaps, [Gap::TooYoung { age: 13, needed: 14 }, Gap::RankBelow { rank: ScoutRank::Tenderfoot, needed: ScoutRank::FirstClass }]);
+
+        let sea_base = TrekPlan { base: Base::SeaBase, ..plan };
+        let results = ha.screen(&sea_base, &youth[..1], &[], &SkillMatrix::default(), today);
+        assert_eq!(results[0].gaps, [Gap::NotSwimmer]);
+        assert_eq!(crew_advisors(&results), 0);
+    }
+}
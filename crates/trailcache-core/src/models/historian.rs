@@ -0,0 +1,89 @@
+//! The troop historian's log: a write-up for each event, kept locally.
+//!
+//! Entries hold a summary, highlights, and the folder where the event's
+//! photos live, and are keyed to the Scoutbook event they describe.
+
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use super::event::Event;
+
+/// The historian's write-up of one event.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub event_id: i64,
+    pub event_name: String,
+    pub date: NaiveDate,
+    #[serde(default)]
+    pub summary: String,
+    #[serde(default)]
+    pub highlights: Vec<String>,
+    pub photo_folder: Option<String>,
+}
+
+impl HistoryEntry {
+    pub fn is_empty(&self) -> bool {
+        self.summary.is_empty() && self.highlights.is_empty() && self.photo_folder.is_none()
+    }
+}
+
+/// Persisted historian log, kept in date order.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HistorianLog {
+    pub entries: Vec<HistoryEntry>,
+}
+
+impl HistorianLog {
+    /// Position of the entry for `event`, adding an empty one if there isn't
+    /// one yet. `None` if the event has no start date.
+    pub fn entry_for_event(&mut self, event: &Event) -> Option<usize> {
+        if let Some(i) = self.entries.iter().position(|e| e.event_id == event.id) {
+            return Some(i);
+        }
+        let date = NaiveDate::parse_from_str(event.start_date.as_deref()?.get(..10)?, "%Y-%m-%d").ok()?;
+        self.entries.push(HistoryEntry {
+            event_id: event.id,
+            event_name: event.name.clone(),
+            date,
+            summary: String::new(),
+            highlights: Vec::new(),
+            photo_folder: None,
+        });
+        self.entries.sort_by(|a, b| a.date.cmp(&b.date).then_with(|| a.event_name.cmp(&b.event_name)));
+        self.entries.iter().position(|e| e.event_id == event.id)
+    }
+
+    /// Drop entries that were opened but never written.
+    pub fn prune_empty(&mut self) {
+        self.entries.retain(|e| !e.is_empty());
+    }
+
+    /// Entries from `year`, in date order.
+    pub fn year(&self, year: i32) -> impl Iterator<Item = &HistoryEntry> {
+        self.entries.iter().filter(move |e| e.date.year() == year)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entries_stay_chronological() {
+        let events: Vec<Event> = serde_json::from_value(serde_json::json!([
+            { "id": 1, "name": "Summer Camp", "startDate": "2026-07-12T09:00:00" },
+            { "id": 2, "name": "Klondike", "startDate": "2026-01-24" },
+            { "id": 3, "name": "No Date" }
+        ]))
+        .unwrap();
+        let mut log = HistorianLog::default();
+        assert_eq!(log.entry_for_event(&events[0]), Some(0));
+        assert_eq!(log.entry_for_event(&events[1]), Some(0), "earlier event sorts first");
+        assert_eq!(log.entry_for_event(&events[0]), Some(1), "existing entry reused");
+        assert_eq!(log.entry_for_event(&events[2]), None);
+
+        log.entries[1].summary = "Week at camp".to_string();
+        log.prune_empty();
+        assert_eq!(log.year(2026).map(|e| e.event_name.as_str()).collect::<Vec<_>>(), ["Summer Camp"]);
+    }
+}
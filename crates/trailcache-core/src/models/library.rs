@@ -0,0 +1,175 @@
+//! Troop library lending.
+//!
+//! Many troops keep merit badge pamphlets and other books for scouts to
+//! borrow. The library is kept locally: each title has a number of copies,
+//! and each copy lent out records the scout and when it's due back. Titles
+//! are typed with an optional copy count: "Camping x3".
+
+use chrono::{Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+/// Days a scout keeps a borrowed item
+pub const LOAN_DAYS: i64 = 28;
+
+/// One copy lent to a scout.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Loan {
+    pub user_id: i64,
+    pub borrowed_on: NaiveDate,
+    pub due: NaiveDate,
+}
+
+impl Loan {
+    pub fn is_overdue(&self, today: NaiveDate) -> bool {
+        today > self.due
+    }
+}
+
+/// A pamphlet or book the troop owns, and the copies out on loan.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LibraryItem {
+    pub title: String,
+    pub copies: u32,
+    #[serde(default)]
+    pub loans: Vec<Loan>,
+}
+
+impl LibraryItem {
+    /// Copies on the shelf.
+    pub fn available(&self) -> u32 {
+        self.copies.saturating_sub(self.loans.len() as u32)
+    }
+}
+
+/// Persisted troop library, in title order.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Library {
+    pub items: Vec<LibraryItem>,
+}
+
+/// Split "Title x3" into the title and copy count (1 when not given).
+fn parse_title(input: &str) -> Option<(String, u32)> {
+    let input = input.trim();
+    let (title, copies) = match input.rsplit_once(' ') {
+        Some((title, count)) => match count.strip_prefix(['x', 'X']).and_then(|n| n.parse::<u32>().ok()) {
+            Some(n) => (title.trim(), n),
+            None => (input, 1),
+        },
+        None => (input, 1),
+    };
+    (!title.is_empty() && copies > 0).then(|| (title.to_string(), copies))
+}
+
+impl Library {
+    /// Add copies of a title typed as "Title" or "Title x3", to the item
+    /// with the same title if there is one. Returns the item's index, or
+    /// `None` if `input` has no title.
+    pub fn add(&mut self, input: &str) -> Option<usize> {
+        let (title, copies) = parse_title(input)?;
+        match self.items.iter_mut().find(|i| i.title.eq_ignore_ascii_case(&title)) {
+            Some(item) => item.copies += copies,
+            None => {
+                self.items.push(LibraryItem { title: title.clone(), copies, loans: Vec::new() });
+                self.items.sort_by_key(|i| i.title.to_lowercase());
+            }
+        }
+        self.items.iter().position(|i| i.title.eq_ignore_ascii_case(&title))
+    }
+
+    /// Take a copy on the shelf out of the library, removing the item with
+    /// its last copy. Returns false if every copy is on loan.
+    pub fn remove_copy(&mut self, index: usize) -> bool {
+        let Some(item) = self.items.get_mut(index) else { return false };
+        if item.available() == 0 {
+            return false;
+        }
+        item.copies -= 1;
+        if item.copies == 0 {
+            self.items.remove(index);
+        }
+        true
+    }
+
+    /// Lend a copy to a scout for `LOAN_DAYS`. Returns false if none are on
+    /// the shelf or the scout already has one.
+    pub fn check_out(&mut self, index: usize, user_id: i64, today: NaiveDate) -> bool {
+        let Some(item) = self.items.get_mut(index) else { return false };
+        if item.available() == 0 || item.loans.iter().any(|l| l.user_id == user_id) {
+            return false;
+        }
+        item.loans.push(Loan { user_id, borrowed_on: today, due: today + Duration::days(LOAN_DAYS) });
+        true
+    }
+
+    /// Check a copy back in: the scout's, or with no scout the copy due
+    /// soonest. Returns the finished loan.
+    pub fn check_in(&mut self, index: usize, user_id: Option<i64>) -> Option<Loan> {
+        let item = self.items.get_mut(index)?;
+        let position = match user_id {
+            Some(id) => item.loans.iter().position(|l| l.user_id == id),
+            None => item.loans.iter().enumerate().min_by_key(|(_, l)| l.due).map(|(i, _)| i),
+        }?;
+        Some(item.loans.remove(position))
+    }
+
+    /// Drop a scout's loans, e.g. when their data is purged; the copies
+    /// count as back on the shelf. Returns how many were removed.
+    pub fn remove_scout(&mut self, user_id: i64) -> usize {
+        let mut count = 0;
+        for item in &mut self.items {
+            let before = item.loans.len();
+            item.loans.retain(|l| l.user_id != user_id);
+            count += before - item.loans.len();
+        }
+        count
+    }
+
+    /// Everything a scout has borrowed, soonest due first.
+    pub fn loans_for(&self, user_id: i64) -> Vec<(&LibraryItem, &Loan)> {
+        let mut loans: Vec<(&LibraryItem, &Loan)> =
+            self.items.iter().flat_map(|i| i.loans.iter().filter(move |l| l.user_id == user_id).map(move |l| (i, l))).collect();
+        loans.sort_by_key(|(_, l)| l.due);
+        loans
+    }
+
+    /// Loans past their due date, most overdue first.
+    pub fn overdue(&self, today: NaiveDate) -> Vec<(&LibraryItem, &Loan)> {
+        let mut loans: Vec<(&LibraryItem, &Loan)> =
+            self.items.iter().flat_map(|i| i.loans.iter().filter(|l| l.is_overdue(today)).map(move |l| (i, l))).collect();
+        loans.sort_by_key(|(_, l)| l.due);
+        loans
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lending() {
+        let today = NaiveDate::from_ymd_opt(2026, 9, 1).unwrap();
+        let mut library = Library::default();
+        assert_eq!(library.add("Camping x2"), Some(0));
+        assert_eq!(library.add("Aviation"), Some(0), "sorted by title");
+        assert_eq!(library.add("camping X1"), Some(1), "same title adds copies");
+        assert_eq!(library.add("  "), None);
+        assert_eq!(library.items[1].copies, 3);
+
+        assert!(library.check_out(1, 7, today));
+        assert!(!library.check_out(1, 7, today), "one copy per scout");
+        assert!(library.check_out(1, 8, today + Duration::days(10)));
+        assert_eq!(library.items[1].available(), 1);
+        assert_eq!(library.loans_for(7)[0].0.title, "Camping");
+
+        let later = today + Duration::days(LOAN_DAYS + 1);
+        assert_eq!(library.overdue(later).len(), 1);
+        assert_eq!(library.check_in(1, None).map(|l| l.user_id), Some(7), "soonest due");
+        assert!(library.overdue(later).is_empty());
+
+        assert!(library.remove_copy(1));
+        assert!(library.remove_copy(1));
+        assert!(!library.remove_copy(1), "last copy is on loan");
+        assert!(library.remove_copy(0));
+        assert_eq!(library.items.len(), 1, "no copies left of Aviation");
+    }
+}
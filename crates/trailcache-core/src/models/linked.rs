@@ -0,0 +1,182 @@
+//! Linked troops: a boys' troop and a girls' troop that share a charter,
+//! adult leaders, and a calendar.
+//!
+//! The linked troop is fetched into its own cache namespace, keyed by its
+//! organization GUID like a district unit. Its scouts are listed alongside
+//! the home troop's, adults registered with both appear once, and events
+//! are credited to whichever troops' scouts are invited.
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use super::event::Event;
+use super::person::{Adult, Youth};
+use super::unit::OrgProfile;
+use crate::utils::cmp_ignore_case;
+
+/// The other troop of a linked pair, saved in the config.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LinkedUnit {
+    pub organization_guid: String,
+    /// Name to show until the troop's profile has been fetched
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// The linked troop's data, as loaded from its cache namespace.
+#[derive(Debug, Clone, Default)]
+pub struct LinkedData {
+    pub profile: Option<OrgProfile>,
+    pub youth: Vec<Youth>,
+    pub adults: Vec<Adult>,
+}
+
+/// Which of the linked troops to show.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TroopFilter {
+    #[default]
+    Both,
+    Home,
+    Linked,
+}
+
+impl TroopFilter {
+    pub fn next(self) -> Self {
+        match self {
+            TroopFilter::Both => TroopFilter::Home,
+            TroopFilter::Home => TroopFilter::Linked,
+            TroopFilter::Linked => TroopFilter::Both,
+        }
+    }
+}
+
+/// Which troops someone or something belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Troops {
+    pub home: bool,
+    pub linked: bool,
+}
+
+impl Troops {
+    pub const HOME: Troops = Troops { home: true, linked: false };
+    pub const LINKED: Troops = Troops { home: false, linked: true };
+    pub const BOTH: Troops = Troops { home: true, linked: true };
+
+    pub fn shown_by(self, filter: TroopFilter) -> bool {
+        match filter {
+            TroopFilter::Both => true,
+            TroopFilter::Home => self.home,
+            TroopFilter::Linked => self.linked,
+        }
+    }
+}
+
+/// A scout, adult, or event with the troops it belongs to.
+#[derive(Debug, Clone, Copy)]
+pub struct LinkedRow<'a, T> {
+    pub item: &'a T,
+    pub troops: Troops,
+}
+
+/// Both troops' scouts, by name.
+pub fn linked_youth<'a>(home: &'a [Youth], linked: &'a [Youth]) -> Vec<LinkedRow<'a, Youth>> {
+    let mut rows: Vec<_> = home
+        .iter()
+        .map(|item| LinkedRow { item, troops: Troops::HOME })
+        .chain(linked.iter().map(|item| LinkedRow { item, troops: Troops::LINKED }))
+        .collect();
+    rows.sort_by(|a, b| {
+        cmp_ignore_case(&a.item.last_name, &b.item.last_name).then_with(|| cmp_ignore_case(&a.item.first_name, &b.item.first_name))
+    });
+    rows
+}
+
+/// Both troops' adults, by name. Adults registered with both troops are
+/// listed once, from the home troop's roster.
+pub fn linked_adults<'a>(home: &'a [Adult], linked: &'a [Adult]) -> Vec<LinkedRow<'a, Adult>> {
+    let linked_guids: HashSet<&str> = linked.iter().filter_map(|a| a.person_guid.as_deref()).collect();
+    let home_guids: HashSet<&str> = home.iter().filter_map(|a| a.person_guid.as_deref()).collect();
+    let mut rows: Vec<_> = home
+        .iter()
+        .map(|item| {
+            let shared = item.person_guid.as_deref().is_some_and(|g| linked_guids.contains(g));
+            LinkedRow { item, troops: if shared { Troops::BOTH } else { Troops::HOME } }
+        })
+        .chain(
+            linked
+                .iter()
+                .filter(|a| a.person_guid.as_deref().is_none_or(|g| !home_guids.contains(g)))
+                .map(|item| LinkedRow { item, troops: Troops::LINKED }),
+        )
+        .collect();
+    rows.sort_by(|a, b| {
+        cmp_ignore_case(&a.item.last_name, &b.item.last_name).then_with(|| cmp_ignore_case(&a.item.first_name, &b.item.first_name))
+    });
+    rows
+}
+
+/// The shared calendar, soonest first. An event belongs to each troop whose
+/// scouts are invited; one inviting neither is the home troop's.
+pub fn linked_events<'a>(events: &'a [Event], home_youth: &[Youth], linked_youth: &[Youth]) -> Vec<LinkedRow<'a, Event>> {
+    let ids = |youth: &[Youth]| -> HashSet<i64> { youth.iter().filter_map(|y| y.user_id).collect() };
+    let (home_ids, linked_ids) = (ids(home_youth), ids(linked_youth));
+    let mut rows: Vec<_> = events
+        .iter()
+        .map(|item| {
+            let invites = |ids: &HashSet<i64>| item.invited_users.iter().any(|u| ids.contains(&u.user_id));
+            let linked = invites(&linked_ids);
+            LinkedRow { item, troops: Troops { home: invites(&home_ids) || !linked, linked } }
+        })
+        .collect();
+    rows.sort_by_key(|row| row.item.start_day());
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_linked_rosters_and_calendar() {
+        let home: Vec<Youth> = serde_json::from_value(serde_json::json!([
+            { "userId": 1, "firstName": "Sam", "lastName": "Ortiz" }
+        ]))
+        .unwrap();
+        let girls: Vec<Youth> = serde_json::from_value(serde_json::json!([
+            { "userId": 2, "firstName": "Ana", "lastName": "Baker" }
+        ]))
+        .unwrap();
+        let youth = linked_youth(&home, &girls);
+        assert_eq!(youth[0].item.first_name, "Ana");
+        assert_eq!(youth[0].troops, Troops::LINKED);
+        assert!(!youth[0].troops.shown_by(TroopFilter::Home));
+
+        let adults = |list: serde_json::Value| -> Vec<Adult> { serde_json::from_value(list).unwrap() };
+        let home_adults = adults(serde_json::json!([
+            { "personGuid": "A", "firstName": "Pat", "lastName": "Lee", "position": "Scoutmaster" },
+            { "personGuid": "B", "firstName": "Jo", "lastName": "Kim" }
+        ]));
+        let girl_adults = adults(serde_json::json!([
+            { "personGuid": "A", "firstName": "Pat", "lastName": "Lee", "position": "Assistant Scoutmaster" },
+            { "personGuid": "C", "firstName": "Ray", "lastName": "Diaz" }
+        ]));
+        let shared = linked_adults(&home_adults, &girl_adults);
+        assert_eq!(shared.len(), 3, "Pat is listed once");
+        let pat = shared.iter().find(|r| r.item.first_name == "Pat").unwrap();
+        assert_eq!(pat.troops, Troops::BOTH);
+        assert_eq!(pat.item.position.as_deref(), Some("Scoutmaster"));
+        assert!(pat.troops.shown_by(TroopFilter::Linked));
+
+        let invitee = |id: i64| serde_json::json!({ "userId": id, "firstName": "X", "lastName": "Y" });
+        let events: Vec<Event> = serde_json::from_value(serde_json::json!([
+            { "id": 10, "name": "Joint Campout", "startDate": "2026-11-06", "invitedUsers": [invitee(1), invitee(2)] },
+            { "id": 11, "name": "Girls Court of Honor", "startDate": "2026-10-20", "invitedUsers": [invitee(2)] },
+            { "id": 12, "name": "Committee Meeting", "startDate": "2026-10-22" }
+        ]))
+        .unwrap();
+        let calendar = linked_events(&events, &home, &girls);
+        let troops: Vec<Troops> = calendar.iter().map(|r| r.troops).collect();
+        assert_eq!(troops, [Troops::LINKED, Troops::HOME, Troops::BOTH]);
+    }
+}
@@ -0,0 +1,110 @@
+//! Medication administration log for camp.
+//!
+//! Records each dose given to a scout: what, how much, when, and by whom.
+//! The log is health information, so it lives only on this machine and is
+//! left out of backups and exports unless they ask for it by name.
+
+use chrono::{NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+
+use crate::utils::format::csv_line;
+
+/// One dose given to a scout.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MedicationDose {
+    pub user_id: i64,
+    pub scout_name: String,
+    pub medication: String,
+    pub dose: String,
+    pub given_at: NaiveDateTime,
+    pub given_by: String,
+}
+
+/// Persisted medication log, kept in the order doses were given.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MedicationLog {
+    pub doses: Vec<MedicationDose>,
+}
+
+impl MedicationLog {
+    /// Add a dose, keeping the log in time order.
+    pub fn record(&mut self, dose: MedicationDose) {
+        let pos = self.doses.partition_point(|d| d.given_at <= dose.given_at);
+        self.doses.insert(pos, dose);
+    }
+
+    pub fn remove(&mut self, index: usize) -> Option<MedicationDose> {
+        (index < self.doses.len()).then(|| self.doses.remove(index))
+    }
+
+    /// Forget a scout's doses, e.g. when their data is purged. Returns how
+    /// many were removed.
+    pub fn remove_scout(&mut self, user_id: i64) -> usize {
+        let before = self.doses.len();
+        self.doses.retain(|d| d.user_id != user_id);
+        before - self.doses.len()
+    }
+
+    /// The last dose given to a scout, for spotting a repeat too soon.
+    pub fn last_for(&self, user_id: i64) -> Option<&MedicationDose> {
+        self.doses.iter().rev().find(|d| d.user_id == user_id)
+    }
+
+    /// Doses given on `day`.
+    pub fn on_day(&self, day: NaiveDate) -> impl Iterator<Item = &MedicationDose> {
+        self.doses.iter().filter(move |d| d.given_at.date() == day)
+    }
+
+    /// Name of whoever recorded the latest dose, to pre-fill the next one.
+    pub fn last_given_by(&self) -> Option<&str> {
+        self.doses.last().map(|d| d.given_by.as_str())
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut csv = csv_line(&["Date", "Time", "Scout", "Medication", "Dose", "Given by"]);
+        csv.push('\n');
+        for dose in &self.doses {
+            let date = dose.given_at.format("%Y-%m-%d").to_string();
+            let time = dose.given_at.format("%H:%M").to_string();
+            csv.push_str(&csv_line(&[&date, &time, &dose.scout_name, &dose.medication, &dose.dose, &dose.given_by]));
+            csv.push('\n');
+        }
+        csv
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dose(user_id: i64, at: &str, medication: &str) -> MedicationDose {
+        MedicationDose {
+            user_id,
+            scout_name: format!("Scout {}", user_id),
+            medication: medication.to_string(),
+            dose: "1 tablet, 200mg".to_string(),
+            given_at: NaiveDateTime::parse_from_str(at, "%Y-%m-%d %H:%M").unwrap(),
+            given_by: "J. Leader".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_log_keeps_time_order_and_exports() {
+        let mut log = MedicationLog::default();
+        log.record(dose(1, "2026-07-12 20:00", "Ibuprofen"));
+        log.record(dose(2, "2026-07-12 08:00", "Inhaler"));
+        log.record(dose(1, "2026-07-13 08:00", "Ibuprofen"));
+
+        let order: Vec<i64> = log.doses.iter().map(|d| d.user_id).collect();
+        assert_eq!(order, [2, 1, 1]);
+        assert_eq!(log.on_day(NaiveDate::from_ymd_opt(2026, 7, 12).unwrap()).count(), 2);
+        assert_eq!(log.last_for(1).unwrap().given_at.date(), NaiveDate::from_ymd_opt(2026, 7, 13).unwrap());
+
+        let csv = log.to_csv();
+        assert!(csv.starts_with("Date,Time,Scout,Medication,Dose,Given by\n"));
+        assert!(csv.contains("2026-07-12,08:00,Scout 2,Inhaler,\"1 tablet, 200mg\",J. Leader\n"));
+
+        assert_eq!(log.remove(0).unwrap().medication, "Inhaler");
+        assert!(log.remove(5).is_none());
+    }
+}
@@ -0,0 +1,189 @@
+//! BSA member ID checks and lookup.
+//!
+//! Member IDs are all digits. Council registrars sometimes enter them with
+//! spaces or dashes, so those are ignored. Scouts and leaders without a valid
+//! ID can't have advancement submitted for them.
+
+use std::fmt;
+
+use super::person::{Adult, Parent, Youth};
+
+/// Fewest digits a member ID can have
+pub const MIN_MEMBER_ID_DIGITS: usize = 6;
+
+/// Most digits a member ID can have
+pub const MAX_MEMBER_ID_DIGITS: usize = 12;
+
+/// What's wrong with a member ID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemberIdIssue {
+    Missing,
+    NotNumeric,
+    /// Number of digits found
+    WrongLength(usize),
+}
+
+impl fmt::Display for MemberIdIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MemberIdIssue::Missing => f.write_str("no BSA ID"),
+            MemberIdIssue::NotNumeric => f.write_str("BSA ID isn't a number"),
+            MemberIdIssue::WrongLength(n) => write!(
+                f,
+                "BSA ID has {} digits (expected {}-{})",
+                n, MIN_MEMBER_ID_DIGITS, MAX_MEMBER_ID_DIGITS
+            ),
+        }
+    }
+}
+
+/// Strip the spaces and dashes registrars put in member IDs.
+pub fn normalize_member_id(id: &str) -> String {
+    id.chars().filter(|c| !c.is_whitespace() && *c != '-').collect()
+}
+
+/// The problem with `id`, or None if it's a valid member ID.
+pub fn member_id_issue(id: Option<&str>) -> Option<MemberIdIssue> {
+    let id = normalize_member_id(id.unwrap_or_default());
+    if id.is_empty() {
+        Some(MemberIdIssue::Missing)
+    } else if !id.chars().all(|c| c.is_ascii_digit()) {
+        Some(MemberIdIssue::NotNumeric)
+    } else if !(MIN_MEMBER_ID_DIGITS..=MAX_MEMBER_ID_DIGITS).contains(&id.len()) {
+        Some(MemberIdIssue::WrongLength(id.len()))
+    } else {
+        None
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemberKind {
+    Youth,
+    Adult,
+    Parent,
+}
+
+impl MemberKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            MemberKind::Youth => "Scout",
+            MemberKind::Adult => "Adult",
+            MemberKind::Parent => "Parent",
+        }
+    }
+}
+
+/// A person found by member ID, or flagged for a missing or invalid one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MemberMatch {
+    pub kind: MemberKind,
+    pub name: String,
+    pub user_id: Option<i64>,
+    pub member_id: Option<String>,
+    /// For parents, the scout they're listed under
+    pub youth_user_id: Option<i64>,
+}
+
+pub(crate) fn youth_match(y: &Youth) -> MemberMatch {
+    MemberMatch {
+        kind: MemberKind::Youth,
+        name: y.display_name(),
+        user_id: y.user_id,
+        member_id: y.member_id.clone(),
+        youth_user_id: None,
+    }
+}
+
+pub(crate) fn adult_match(a: &Adult) -> MemberMatch {
+    MemberMatch {
+        kind: MemberKind::Adult,
+        name: a.display_name(),
+        user_id: a.user_id,
+        member_id: a.member_id.clone(),
+        youth_user_id: None,
+    }
+}
+
+pub(crate) fn parent_match(p: &Parent) -> MemberMatch {
+    MemberMatch {
+        kind: MemberKind::Parent,
+        name: p.display_name(),
+        user_id: p.user_id,
+        member_id: p.member_id.clone(),
+        youth_user_id: p.youth_user_id,
+    }
+}
+
+/// Everyone whose member ID starts with `query` (spaces and dashes ignored),
+/// exact matches first. Parents listed under several scouts appear once.
+pub fn find_by_member_id(query: &str, youth: &[Youth], adults: &[Adult], parents: &[Parent]) -> Vec<MemberMatch> {
+    let query = normalize_member_id(query);
+    if query.is_empty() {
+        return Vec::new();
+    }
+    let matches_query = |id: &Option<String>| id.as_deref().is_some_and(|id| normalize_member_id(id).starts_with(&query));
+
+    let mut found: Vec<MemberMatch> = youth.iter().filter(|y| matches_query(&y.member_id)).map(youth_match).collect();
+    found.extend(adults.iter().filter(|a| matches_query(&a.member_id)).map(adult_match));
+    for p in parents.iter().filter(|p| matches_query(&p.member_id)) {
+        if !found.iter().any(|m| m.kind == MemberKind::Parent && m.user_id.is_some() && m.user_id == p.user_id) {
+            found.push(parent_match(p));
+        }
+    }
+    found.sort_by_key(|m| m.member_id.as_deref().map(normalize_member_id) != Some(query.clone()));
+    found
+}
+
+/// Scouts and adults whose member ID is missing or invalid, scouts first.
+/// Parents aren't registered members, so they aren't checked.
+pub fn member_id_problems(youth: &[Youth], adults: &[Adult]) -> Vec<(MemberMatch, MemberIdIssue)> {
+    let mut problems: Vec<_> = youth
+        .iter()
+        .filter_map(|y| Some((youth_match(y), member_id_issue(y.member_id.as_deref())?)))
+        .collect();
+    problems.extend(adults.iter().filter_map(|a| Some((adult_match(a), member_id_issue(a.member_id.as_deref())?))));
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn youth(id: i64, member_id: Option<&str>) -> Youth {
+        serde_json::from_value(serde_json::json!({
+            "userId": id, "firstName": "Scout", "lastName": id.to_string(), "memberId": member_id
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_member_id_issue() {
+        assert_eq!(member_id_issue(Some("123456789")), None);
+        assert_eq!(member_id_issue(Some("123-456 789")), None);
+        assert_eq!(member_id_issue(None), Some(MemberIdIssue::Missing));
+        assert_eq!(member_id_issue(Some("  ")), Some(MemberIdIssue::Missing));
+        assert_eq!(member_id_issue(Some("12345A789")), Some(MemberIdIssue::NotNumeric));
+        assert_eq!(member_id_issue(Some("12345")), Some(MemberIdIssue::WrongLength(5)));
+    }
+
+    #[test]
+    fn test_find_by_member_id() {
+        let scouts = [youth(1, Some("123456789")), youth(2, Some("12345")), youth(3, None)];
+        let parent: Parent = serde_json::from_value(serde_json::json!({
+            "userId": 9, "firstName": "Pat", "lastName": "Parent", "memberId": "123-45",
+            "youthUserId": 2
+        }))
+        .unwrap();
+        let parents = [parent.clone(), Parent { youth_user_id: Some(1), ..parent }];
+
+        let found = find_by_member_id("12345", &scouts, &[], &parents);
+        assert_eq!(found.iter().map(|m| m.user_id).collect::<Vec<_>>(), [Some(2), Some(9), Some(1)]);
+        assert_eq!(found[1].youth_user_id, Some(2));
+        assert!(find_by_member_id(" ", &scouts, &[], &parents).is_empty());
+
+        let problems = member_id_problems(&scouts, &[]);
+        assert_eq!(problems.len(), 2);
+        assert_eq!(problems[0].1, MemberIdIssue::WrongLength(5));
+        assert_eq!(problems[1].1, MemberIdIssue::Missing);
+    }
+}
@@ -0,0 +1,162 @@
+//! Committee meeting minutes, kept locally.
+//!
+//! Minutes are bound to committee-type events. Attendees are filled in from
+//! the event's RSVPs, and action items stay open until someone closes them.
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use super::event::{Event, RsvpStatus};
+
+/// Whether an event looks like a committee meeting, by type or name.
+pub fn is_committee_event(event: &Event) -> bool {
+    event.event_type.iter().chain(std::iter::once(&event.name)).any(|s| s.to_lowercase().contains("committee"))
+}
+
+/// Something the committee agreed to do.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActionItem {
+    pub text: String,
+    pub owner: Option<String>,
+    pub due: Option<NaiveDate>,
+    pub closed_on: Option<NaiveDate>,
+}
+
+impl ActionItem {
+    /// Parse "Order patches @Jane Smith 2026-11-01": an owner after `@`, and a
+    /// trailing due date, are both optional.
+    pub fn parse(input: &str) -> Option<Self> {
+        let mut rest = input.trim();
+        let mut due = None;
+        if let Some((head, last)) = rest.rsplit_once(' ') {
+            if let Ok(date) = NaiveDate::parse_from_str(last, "%Y-%m-%d") {
+                due = Some(date);
+                rest = head.trim_end();
+            }
+        }
+        let (text, owner) = match rest.rsplit_once('@') {
+            Some((text, owner)) if !owner.trim().is_empty() => (text.trim(), Some(owner.trim().to_string())),
+            _ => (rest, None),
+        };
+        if text.is_empty() {
+            return None;
+        }
+        Some(Self { text: text.to_string(), owner, due, closed_on: None })
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.closed_on.is_none()
+    }
+
+    pub fn is_overdue(&self, today: NaiveDate) -> bool {
+        self.is_open() && self.due.is_some_and(|d| d < today)
+    }
+}
+
+/// Minutes of one committee meeting.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MeetingMinutes {
+    pub event_id: i64,
+    pub event_name: String,
+    pub date: NaiveDate,
+    pub attendees: Vec<String>,
+    #[serde(default)]
+    pub notes: Vec<String>,
+    #[serde(default)]
+    pub action_items: Vec<ActionItem>,
+}
+
+impl MeetingMinutes {
+    /// Fill attendees from the event: everyone marked attended or RSVP'd going.
+    pub fn fill_attendees(&mut self, event: &Event) {
+        let mut attendees: Vec<String> = event
+            .invited_users
+            .iter()
+            .filter(|u| u.attended || u.status() == RsvpStatus::Going)
+            .map(|u| format!("{} {}", u.first_name, u.last_name))
+            .collect();
+        attendees.sort();
+        self.attendees = attendees;
+    }
+}
+
+/// Persisted minutes for all committee meetings.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CommitteeMinutes {
+    pub meetings: Vec<MeetingMinutes>,
+}
+
+impl CommitteeMinutes {
+    /// Position of the minutes for `event`, starting them (with attendees from
+    /// RSVPs) if needed. `None` if the event has no start date.
+    pub fn minutes_for_event(&mut self, event: &Event) -> Option<usize> {
+        if let Some(i) = self.meetings.iter().position(|m| m.event_id == event.id) {
+            return Some(i);
+        }
+        let date = NaiveDate::parse_from_str(event.start_date.as_deref()?.get(..10)?, "%Y-%m-%d").ok()?;
+        let mut minutes = MeetingMinutes {
+            event_id: event.id,
+            event_name: event.name.clone(),
+            date,
+            attendees: Vec::new(),
+            notes: Vec::new(),
+            action_items: Vec::new(),
+        };
+        minutes.fill_attendees(event);
+        self.meetings.push(minutes);
+        Some(self.meetings.len() - 1)
+    }
+
+    /// Open action items from every meeting, soonest due first (undated last).
+    pub fn open_action_items(&self) -> Vec<(&MeetingMinutes, &ActionItem)> {
+        let mut items: Vec<_> = self
+            .meetings
+            .iter()
+            .flat_map(|m| m.action_items.iter().filter(|a| a.is_open()).map(move |a| (m, a)))
+            .collect();
+        items.sort_by_key(|(m, a)| (a.due.is_none(), a.due, m.date));
+        items
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_action_item_parse() {
+        let item = ActionItem::parse("Order patches @Jane Smith 2026-11-01").unwrap();
+        assert_eq!(item.text, "Order patches");
+        assert_eq!(item.owner.as_deref(), Some("Jane Smith"));
+        assert_eq!(item.due, NaiveDate::from_ymd_opt(2026, 11, 1));
+        assert!(item.is_overdue(NaiveDate::from_ymd_opt(2026, 11, 2).unwrap()));
+
+        let item = ActionItem::parse("Book campsite").unwrap();
+        assert_eq!((item.owner, item.due), (None, None));
+        assert_eq!(ActionItem::parse(" @Jane "), None);
+    }
+
+    #[test]
+    fn test_minutes_attendees_and_open_items() {
+        let event: Event = serde_json::from_value(serde_json::json!({
+            "id": 5, "name": "Troop Committee Meeting", "startDate": "2026-10-05T19:00:00",
+            "invitedUsers": [
+                { "userId": 1, "firstName": "Pat", "lastName": "Chair", "rsvpCode": "Y" },
+                { "userId": 2, "firstName": "Lee", "lastName": "Treasurer", "rsvpCode": "N" },
+                { "userId": 3, "firstName": "Kim", "lastName": "Advancement", "attended": true }
+            ]
+        }))
+        .unwrap();
+        assert!(is_committee_event(&event));
+
+        let mut minutes = CommitteeMinutes::default();
+        let i = minutes.minutes_for_event(&event).unwrap();
+        assert_eq!(minutes.meetings[i].attendees, ["Kim Advancement", "Pat Chair"]);
+
+        let items = ["Book campsite", "Order patches 2026-11-01"].map(|s| ActionItem::parse(s).unwrap());
+        minutes.meetings[i].action_items.extend(items);
+        minutes.meetings[i].action_items[1].closed_on = NaiveDate::from_ymd_opt(2026, 10, 20);
+        let open: Vec<&str> = minutes.open_action_items().iter().map(|(_, a)| a.text.as_str()).collect();
+        assert_eq!(open, ["Book campsite"]);
+    }
+}
@@ -4,20 +4,107 @@
 //! troop data including:
 //!
 //! - `Youth`, `Adult`, `Parent`: Person models with contact info
+//! - `GradeRollover`: Scouts whose Scoutbook grade hasn't advanced with the school year
+//! - `member_id`: BSA member ID checks and lookup by ID
+//! - `contact_check`: Missing parent emails, bad emails and phones, and emails shared across families
 //! - `Event`, `EventGuest`: Calendar events and RSVP tracking
+//! - `EventAttendance`: Per-scout attendance at past events, kept across refreshes
+//! - `EventTemplates`: Locally saved outlines for recurring events
+//! - `HistorianLog`: The troop historian's per-event write-ups
+//! - `Debriefs`: Local post-event notes on what went well, what to change, and incidents
+//! - `ActivityFeed`: Changes observed between refreshes
+//! - `MembershipHistory`, `MembershipForecast`: Monthly joins and departures, and the roster projected a year out
+//! - `ScoutPace`, `stalled_scouts`: Advancement per month, and scouts with no recorded progress lately
+//! - `UnitStats`, `ScoutVelocity`: Unit-wide aggregates, including each scout's advancement velocity
+//! - `ActivityLog`: Each scout's camping nights, hiking miles, and service hours, and OA eligibility
 //! - `Patrol`: Troop organization structure
+//! - `AttendanceHistory`: Locally recorded meeting attendance
+//! - `HighAdventure`: Trek plans and physical checks, screened against base age and rank rules
+//! - `Library`: Locally tracked pamphlets and books lent to scouts
+//! - `Clinic`: A merit badge clinic's classes, counselors, registrations, and completions
+//! - `UniformCloset`: Donated uniforms and gear, and who they went to
+//! - `TodoList`: Local to-dos attached to scouts, events, or the unit
+//! - `Automations`, `ScoutTags`: User-written rules run after each refresh, and the tags they give
+//! - `CommitteeMinutes`: Local committee meeting minutes and action items
+//! - `CommitteeRoles`: Committee functions covered by Scoutbook positions or local assignments
+//! - `AwardCatalogEntry`, `Nominations`: Special award holders and local nominations
+//! - `Onboarding`: New leader checklists combining Scoutbook training with local items
+//! - `AdultAwardChecklists`: Local progress toward adult leader awards
+//! - `ActivityPlans`: Pre-activity checklists tailored by event type, checked off locally
+//! - `Consents`: Locally recorded activity consent and media release forms
+//! - `Accommodations`: Approved alternate requirements and accommodations per scout
+//! - `MedicationLog`: Doses given at camp, kept local and out of backups by default
+//! - `PositionPlan`: Next term's planned youth leadership positions
+//! - `DenChiefLinks`: Locally tracked den chief service to partner packs
+//! - `duty_roster`: Rotating patrol duty assignments
+//! - `BuddyGroups`: Buddy pairs for swims and hikes
+//! - `SkillMatrix`: Locally tracked certifications (Totin' Chip, Firem'n Chit, swim)
+//! - `AdultSkills`: Adults' locally tagged skills and certifications, with expiration dates
 //! - Advancement types: `RankProgress`, `MeritBadgeProgress`, etc.
+//! - `DateCorrection`, `DateCorrectionLog`: Bulk fixes to requirement completion dates, and their audit log
+//! - `RequirementTree`: How requirement numbers like "7", "7a", "7b(1)" nest
+//! - `scout_timeline`: A scout's ranks, badges, positions, and awards in date order
+//! - `EagleProgress`: Each scout's remaining Eagle requirements
+//! - `EagleProjects`: Local Eagle service project tracking, hours, and district paperwork
 //! - Unit types: `Key3Leaders`, `UnitInfo`, `OrgProfile`, `Commissioner`
+//! - `DistrictUnit`, `UnitHealth`: Units a commissioner serves and a summary of each
+//! - `LinkedUnit`: A linked boys'/girls' troop, and both troops' rosters and calendar together
 
+pub mod accommodation;
+pub mod activity;
+pub mod activity_plan;
+pub mod adult_awards;
+pub mod adult_skills;
 pub mod advancement;
+pub mod attendance;
+pub mod automation;
+pub mod awards;
+pub mod buddy;
+pub mod clinic;
+pub mod committee;
+pub mod closet;
+pub mod consent;
+pub mod coverage;
+pub mod contact_check;
+pub mod date_correction;
+pub mod debrief;
+pub mod den_chief;
+pub mod district;
+pub mod duty;
+pub mod eagle;
+pub mod eagle_project;
 pub mod event;
+pub mod event_attendance;
+pub mod event_template;
+pub mod feed;
+pub mod forecast;
+pub mod grade;
+pub mod high_adventure;
+pub mod historian;
+pub mod library;
+pub mod linked;
+pub mod medication;
+pub mod member_id;
+pub mod onboarding;
 pub mod organization;
+pub mod pace;
+pub mod minutes;
 pub mod person;
 pub mod pivot;
+pub mod positions;
+pub mod requirement_tree;
+pub mod skills;
 pub mod sorting;
 pub mod stats;
+pub mod timeline;
+pub mod todo;
 pub mod unit;
 
+pub use accommodation::{Accommodation, Accommodations, AdvancementRef};
+pub use activity_plan::{is_aquatics, unclassified_swimmers, ActivityChecklist, ActivityPlanItem, ActivityPlans};
+pub use activity::{oa_standing, oa_standings, ActivityLog, OaStanding, OA_CAMPING_NIGHTS, OA_MIN_RANK};
+pub use adult_skills::{AdultSkill, AdultSkills, COMMON_ADULT_SKILLS, SKILL_EXPIRY_WARNING_DAYS};
+pub use adult_awards::{AdultAward, AdultAwardChecklists, AwardRequirement, RequirementKind};
 pub use advancement::{
     format_date, AdvancementDashboard, Award, BadgeSummary, DEFAULT_AWARD_STATUS,
     DEFAULT_BADGE_STATUS, EAGLE_REQUIRED_COUNT, LeadershipPosition, MeritBadgeCatalogEntry,
@@ -26,9 +113,45 @@ pub use advancement::{
     StatusCategory, STATUS_AWARDED, STATUS_COUNSELOR_APPROVED, STATUS_LEADER_APPROVED,
     UNKNOWN_DATE,
 };
-pub use event::{Event, EventGuest, EventSortColumn, RsvpStatus};
+pub use attendance::{default_agenda, AgendaItem, AttendanceHistory, MeetingRecord};
+pub use automation::{AutomationConditions, AutomationRule, Automations, ScoutTags, AUTOMATIONS_FILE};
+pub use awards::{award_catalog, AwardCatalogEntry, Nomination, Nominations};
+pub use buddy::{BuddyGroups, MAX_PATROL_AGE_GAP_DAYS};
+pub use closet::{Checkout, ClosetItem, UniformCloset};
+pub use contact_check::{contact_problems, is_valid_email, is_valid_phone, ContactIssue};
+pub use consent::{ConsentForm, Consents, SignedConsent};
+pub use coverage::{CoverageGap, LeaderCoverage, HAZARDOUS_WEATHER_SKILL, TWO_DEEP_ADULTS};
+pub use date_correction::{CorrectionItem, CorrectionRecord, DateCorrection, DateCorrectionLog};
+pub use debrief::{DebriefKind, DebriefNote, Debriefs, EventDebrief};
+pub use den_chief::{DenChiefLink, DenChiefLinks, MeetingPattern, POSITION_DEN_CHIEF};
+pub use duty::{duty_roster, DEFAULT_DUTIES};
+pub use eagle::{EagleProgress, EagleSortColumn, EAGLE_POR_DAYS, EAGLE_REQUIRED_BADGES, EAGLE_TOTAL_BADGES, RANK_SERVICE_HOURS};
+pub use clinic::{Clinic, ClinicClass, DEFAULT_CLINIC_NAME};
+pub use committee::{CommitteeRole, CommitteeRoles, RoleOverride};
+pub use eagle_project::{EagleProject, EagleProjects, Fundraising, HourEntry, Milestone, Paperwork, ProjectStage};
+pub use event::{Event, EventArchive, EventGuest, EventSortColumn, InvitedUser, RsvpStatus};
+pub use event_attendance::{AttendanceRecord, AttendanceTally, EventAttendance};
+pub use event_template::{parse_event_datetime, EventPlan, EventTemplate, EventTemplates, TemplateInvitee, DEFAULT_CAMPOUT_PACKING_LIST};
+pub use feed::{ActivityFeed, FeedCategory, FeedEntry};
+pub use forecast::{ForecastSettings, MembershipForecast, MembershipHistory, MembershipMonth, FORECAST_MONTHS};
+pub use grade::{GradeRollover, StaleGrade};
+pub use high_adventure::{crew_advisors, Base, Gap, HighAdventure, PhysicalRecord, Screening, TrekPlan, CREW_ADVISORS_REQUIRED, CREW_ADVISOR_AGE};
+pub use historian::{HistorianLog, HistoryEntry};
+pub use library::{Library, LibraryItem, Loan, LOAN_DAYS};
+pub use linked::{linked_adults, linked_events, linked_youth, LinkedData, LinkedRow, LinkedUnit, TroopFilter, Troops};
+pub use medication::{MedicationDose, MedicationLog};
+pub use member_id::{find_by_member_id, member_id_issue, member_id_problems, normalize_member_id, MemberIdIssue, MemberKind, MemberMatch};
+pub use minutes::{is_committee_event, ActionItem, CommitteeMinutes, MeetingMinutes};
+pub use onboarding::{Onboarding, OnboardingChecklist, OnboardingItem};
 pub use organization::Patrol;
+pub use pace::{progress_dates, stalled_scouts, PaceSettings, ScoutPace, PACE_WINDOW_MONTHS};
 pub use person::{Adult, AdultSortColumn, DEFAULT_ADULT_ROLE, DISPLAY_NOT_TRAINED, OrgAdultsResponse, OrgYouthsResponse, Parent, ParentResponse, PROGRAM_ID_SCOUTS_BSA, PROGRAM_SCOUTS_BSA, ScoutSortColumn, UnitYouthsResponse, Youth, youth_position_list, YOUTH_POSITION_PRIORITY};
+pub use positions::{eligibility_issues, leadership_days, plan_issues, PlannedAssignment, PositionPlan, PositionRule, POSITION_RULES};
+pub use requirement_tree::{RequirementNode, RequirementTree};
+pub use skills::{Certification, SkillMatrix, SkillRecord, SwimLevel};
 pub use sorting::{sort_requirements, HasRequirementNumber};
-pub use stats::{patrol_rank_breakdown, PatrolBreakdown, RenewalStats, TrainingStats};
+pub use stats::{advancement_velocity, completions_by_month, patrol_rank_breakdown, AttendanceStats, ScoutVelocity, PatrolBreakdown, RenewalStats, RsvpStats, RsvpTally, TrainingStats, UnitStats, UnitStatsInput, STATS_WINDOW_DAYS};
+pub use timeline::{scout_timeline, TimelineEntry, TimelineKind};
+pub use todo::{TodoItem, TodoList, TodoSubject};
+pub use district::{sort_by_concerns, DistrictUnit, Key3Contact, UnitData, UnitHealth};
 pub use unit::{Commissioner, Key3Leaders, Leader, MeetingLocation, OrgProfile, UnitContact, UnitInfo};
@@ -0,0 +1,191 @@
+//! New leader onboarding checklists.
+//!
+//! A new adult leader has a handful of things to finish before they're fully
+//! on board. Registration, Youth Protection, and position training come from
+//! their Scoutbook record; the background check, uniform, and Scoutbook
+//! access are checked off locally. An item counts as done if either says so,
+//! since Scoutbook can lag behind what the committee already knows.
+
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use super::person::Adult;
+
+/// One step of onboarding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum OnboardingItem {
+    Application,
+    BackgroundCheck,
+    YouthProtection,
+    PositionTraining,
+    Uniform,
+    ScoutbookAccess,
+}
+
+impl OnboardingItem {
+    pub const ALL: [OnboardingItem; 6] = [
+        OnboardingItem::Application,
+        OnboardingItem::BackgroundCheck,
+        OnboardingItem::YouthProtection,
+        OnboardingItem::PositionTraining,
+        OnboardingItem::Uniform,
+        OnboardingItem::ScoutbookAccess,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            OnboardingItem::Application => "Adult application processed",
+            OnboardingItem::BackgroundCheck => "Criminal background check",
+            OnboardingItem::YouthProtection => "Youth Protection training",
+            OnboardingItem::PositionTraining => "Position-specific training",
+            OnboardingItem::Uniform => "Uniform",
+            OnboardingItem::ScoutbookAccess => "Scoutbook access",
+        }
+    }
+
+    /// Short label for one-line summaries.
+    pub fn short_name(&self) -> &'static str {
+        match self {
+            OnboardingItem::Application => "application",
+            OnboardingItem::BackgroundCheck => "CBC",
+            OnboardingItem::YouthProtection => "YPT",
+            OnboardingItem::PositionTraining => "training",
+            OnboardingItem::Uniform => "uniform",
+            OnboardingItem::ScoutbookAccess => "Scoutbook",
+        }
+    }
+
+    /// Whether the item can come from the adult's Scoutbook record.
+    pub fn has_record(&self) -> bool {
+        matches!(
+            self,
+            OnboardingItem::Application | OnboardingItem::YouthProtection | OnboardingItem::PositionTraining
+        )
+    }
+
+    /// Whether the adult's Scoutbook record shows the item done as of `today`.
+    pub fn from_record(&self, adult: &Adult, today: NaiveDate) -> bool {
+        match self {
+            OnboardingItem::Application => adult.registration_expires_on().is_some_and(|d| d > today),
+            OnboardingItem::YouthProtection => adult.ypt_expires().is_some_and(|d| d > today),
+            OnboardingItem::PositionTraining => adult.is_position_trained() == Some(true),
+            _ => false,
+        }
+    }
+}
+
+/// One adult's checklist.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OnboardingChecklist {
+    pub person_guid: String,
+    pub started_on: NaiveDate,
+    /// Items checked off locally, and when
+    #[serde(default)]
+    pub checked: BTreeMap<OnboardingItem, NaiveDate>,
+}
+
+/// Persisted checklists for adults being onboarded.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Onboarding {
+    pub entries: Vec<OnboardingChecklist>,
+}
+
+impl Onboarding {
+    pub fn checklist(&self, adult: &Adult) -> Option<&OnboardingChecklist> {
+        let guid = adult.person_guid.as_deref()?;
+        self.entries.iter().find(|c| c.person_guid == guid)
+    }
+
+    /// Start a checklist for a new adult. Returns false if they already have
+    /// one or have no person GUID.
+    pub fn start(&mut self, adult: &Adult, today: NaiveDate) -> bool {
+        let Some(guid) = adult.person_guid.as_deref() else {
+            return false;
+        };
+        if self.checklist(adult).is_some() {
+            return false;
+        }
+        self.entries.push(OnboardingChecklist { person_guid: guid.to_string(), started_on: today, checked: BTreeMap::new() });
+        true
+    }
+
+    /// Drop an adult's checklist, e.g. once they're fully on board.
+    pub fn remove(&mut self, adult: &Adult) -> bool {
+        let before = self.entries.len();
+        self.entries.retain(|c| Some(c.person_guid.as_str()) != adult.person_guid.as_deref());
+        self.entries.len() != before
+    }
+
+    pub fn is_done(&self, adult: &Adult, item: OnboardingItem, today: NaiveDate) -> bool {
+        item.from_record(adult, today) || self.checklist(adult).is_some_and(|c| c.checked.contains_key(&item))
+    }
+
+    /// Check or uncheck an item locally. Returns false when there's no
+    /// checklist, or the Scoutbook record already shows the item done.
+    pub fn toggle(&mut self, adult: &Adult, item: OnboardingItem, today: NaiveDate) -> bool {
+        if item.from_record(adult, today) {
+            return false;
+        }
+        let Some(guid) = adult.person_guid.as_deref() else {
+            return false;
+        };
+        let Some(checklist) = self.entries.iter_mut().find(|c| c.person_guid == guid) else {
+            return false;
+        };
+        if checklist.checked.remove(&item).is_none() {
+            checklist.checked.insert(item, today);
+        }
+        true
+    }
+
+    /// Items done and total, if the adult is being onboarded.
+    pub fn progress(&self, adult: &Adult, today: NaiveDate) -> Option<(usize, usize)> {
+        self.checklist(adult)?;
+        let done = OnboardingItem::ALL.iter().filter(|&&i| self.is_done(adult, i, today)).count();
+        Some((done, OnboardingItem::ALL.len()))
+    }
+
+    /// Items still to do.
+    pub fn missing(&self, adult: &Adult, today: NaiveDate) -> Vec<OnboardingItem> {
+        OnboardingItem::ALL.into_iter().filter(|&i| !self.is_done(adult, i, today)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_onboarding_combines_record_and_local_items() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 1).unwrap();
+        let adult: Adult = serde_json::from_value(serde_json::json!({
+            "personGuid": "A1", "firstName": "Pat", "lastName": "Leader",
+            "positionTrained": "Not Trained", "yptExpiredDate": "2028-09-30",
+            "registrarInfo": { "registrationExpireDt": "2026-09-30" }
+        }))
+        .unwrap();
+        let mut onboarding = Onboarding::default();
+        assert_eq!(onboarding.progress(&adult, today), None);
+        assert!(!onboarding.toggle(&adult, OnboardingItem::Uniform, today), "no checklist yet");
+
+        assert!(onboarding.start(&adult, today));
+        assert!(!onboarding.start(&adult, today));
+        assert_eq!(onboarding.progress(&adult, today), Some((1, 6)), "YPT comes from the record");
+        assert!(!onboarding.toggle(&adult, OnboardingItem::YouthProtection, today));
+
+        // Registration lapsed in the record, but the new application is in
+        assert!(onboarding.toggle(&adult, OnboardingItem::Application, today));
+        assert!(onboarding.toggle(&adult, OnboardingItem::BackgroundCheck, today));
+        assert_eq!(onboarding.progress(&adult, today), Some((3, 6)));
+        assert_eq!(
+            onboarding.missing(&adult, today),
+            [OnboardingItem::PositionTraining, OnboardingItem::Uniform, OnboardingItem::ScoutbookAccess]
+        );
+
+        assert!(onboarding.toggle(&adult, OnboardingItem::BackgroundCheck, today));
+        assert_eq!(onboarding.progress(&adult, today), Some((2, 6)));
+        assert!(onboarding.remove(&adult));
+    }
+}
@@ -0,0 +1,167 @@
+//! Advancement pace: how often each scout records progress, and who has
+//! gone quiet.
+//!
+//! Progress is every dated completion on file: ranks, merit badges, and the
+//! rank requirements the activity feed has seen completed. Scouts with
+//! nothing dated yet, such as new crossovers, aren't flagged, since there's
+//! no telling how long they've been at it.
+
+use std::collections::HashMap;
+
+use chrono::{Months, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use super::advancement::{MeritBadgeProgress, RankProgress};
+use super::feed::{ActivityFeed, FeedCategory};
+use super::person::Youth;
+
+/// Pace is measured over this many months.
+pub const PACE_WINDOW_MONTHS: u32 = 12;
+
+/// When a scout counts as stalled, e.g. `"pace": { "stalled_months": 4 }`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PaceSettings {
+    /// Months without recorded progress; 0 turns the alerts off
+    pub stalled_months: u32,
+}
+
+impl Default for PaceSettings {
+    fn default() -> Self {
+        Self { stalled_months: 3 }
+    }
+}
+
+impl PaceSettings {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoutPace {
+    pub user_id: i64,
+    pub name: String,
+    /// Completions in the last `PACE_WINDOW_MONTHS`
+    pub recent: usize,
+    pub last_progress: Option<NaiveDate>,
+}
+
+fn parse_day(date: Option<&str>) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(date?.get(..10)?, "%Y-%m-%d").ok()
+}
+
+/// The dates of one scout's completed ranks, merit badges, and the
+/// requirements the feed has seen, up to `today`, in no particular order.
+pub fn progress_dates(
+    user_id: i64,
+    ranks: &[RankProgress],
+    badges: &[MeritBadgeProgress],
+    feed: &ActivityFeed,
+    today: NaiveDate,
+) -> Vec<NaiveDate> {
+    ranks
+        .iter()
+        .filter_map(|r| parse_day(r.date_completed.as_deref()))
+        .chain(badges.iter().filter(|b| b.is_completed()).filter_map(|b| parse_day(b.date_completed.as_deref())))
+        .chain(feed.filtered(Some(FeedCategory::Requirement), Some(user_id)).into_iter().map(|e| e.date))
+        .filter(|d| *d <= today)
+        .collect()
+}
+
+impl ScoutPace {
+    pub fn new(youth: &Youth, ranks: &[RankProgress], badges: &[MeritBadgeProgress], feed: &ActivityFeed, today: NaiveDate) -> Self {
+        let user_id = youth.get_user_id();
+        let dates = progress_dates(user_id, ranks, badges, feed, today);
+        let window_start = today.checked_sub_months(Months::new(PACE_WINDOW_MONTHS)).unwrap_or(today);
+        Self {
+            user_id,
+            name: youth.full_name(),
+            recent: dates.iter().filter(|d| **d > window_start).count(),
+            last_progress: dates.into_iter().max(),
+        }
+    }
+
+    /// Completions a month, averaged over the pace window.
+    pub fn per_month(&self) -> f64 {
+        self.recent as f64 / PACE_WINDOW_MONTHS as f64
+    }
+
+    /// Whether the scout's last progress is older than the settings allow.
+    pub fn is_stalled(&self, settings: PaceSettings, today: NaiveDate) -> bool {
+        let Some(last) = self.last_progress else { return false };
+        settings.stalled_months > 0
+            && today.checked_sub_months(Months::new(settings.stalled_months)).is_some_and(|cutoff| last < cutoff)
+    }
+}
+
+/// Scouts who have stalled, longest first. Scouts whose advancement hasn't
+/// been loaded are skipped.
+pub fn stalled_scouts(
+    youth: &[Youth],
+    ranks: &HashMap<i64, Vec<RankProgress>>,
+    badges: &HashMap<i64, Vec<MeritBadgeProgress>>,
+    feed: &ActivityFeed,
+    settings: PaceSettings,
+    today: NaiveDate,
+) -> Vec<ScoutPace> {
+    let mut stalled: Vec<ScoutPace> = youth
+        .iter()
+        .filter_map(|y| {
+            let id = y.user_id?;
+            let ranks = ranks.get(&id)?;
+            let badges = badges.get(&id).map(Vec::as_slice).unwrap_or_default();
+            Some(ScoutPace::new(y, ranks, badges, feed, today))
+        })
+        .filter(|p| p.is_stalled(settings, today))
+        .collect();
+    stalled.sort_by(|a, b| (a.last_progress, &a.name).cmp(&(b.last_progress, &b.name)));
+    stalled
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::FeedEntry;
+
+    #[test]
+    fn test_pace_and_stalled_scouts() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 15).unwrap();
+        let youth: Vec<Youth> = serde_json::from_value(serde_json::json!([
+            { "userId": 1, "firstName": "Sam", "lastName": "Ortiz" },
+            { "userId": 2, "firstName": "Ana", "lastName": "Diaz" },
+            { "userId": 3, "firstName": "Lee", "lastName": "Park" },
+            { "userId": 4, "firstName": "Kim", "lastName": "Cho" },
+        ]))
+        .unwrap();
+        let ranks: Vec<RankProgress> = serde_json::from_value(serde_json::json!([
+            { "rank_id": 1, "rank_name": "Scout", "date_completed": "2025-11-01" },
+            { "rank_id": 2, "rank_name": "Tenderfoot", "date_completed": "2026-05-20" },
+        ]))
+        .unwrap();
+        let badges: Vec<MeritBadgeProgress> = serde_json::from_value(serde_json::json!([
+            { "id": 10, "name": "Swimming", "status": "Awarded", "dateCompleted": "2026-06-01" },
+            { "id": 11, "name": "Cooking", "status": "Started", "dateStarted": "2026-09-01" },
+        ]))
+        .unwrap();
+        let feed = ActivityFeed {
+            entries: vec![FeedEntry {
+                date: NaiveDate::from_ymd_opt(2026, 9, 30).unwrap(),
+                category: FeedCategory::Requirement,
+                user_id: Some(2),
+                text: "A. Diaz completed Second Class 2a".to_string(),
+            }],
+        };
+
+        let pace = ScoutPace::new(&youth[0], &ranks, &badges, &feed, today);
+        assert_eq!(pace.recent, 3);
+        assert_eq!(pace.last_progress, NaiveDate::from_ymd_opt(2026, 6, 1));
+        assert!((pace.per_month() - 0.25).abs() < 1e-9);
+
+        let rank_map = HashMap::from([(1, ranks.clone()), (2, ranks.clone()), (3, vec![])]);
+        let badge_map = HashMap::from([(1, badges)]);
+        let stalled = stalled_scouts(&youth, &rank_map, &badge_map, &feed, PaceSettings::default(), today);
+        let names: Vec<&str> = stalled.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["Sam Ortiz"], "Ana has a recent requirement; Lee has nothing dated; Kim isn't loaded");
+        assert!(stalled_scouts(&youth, &rank_map, &badge_map, &feed, PaceSettings { stalled_months: 0 }, today).is_empty());
+    }
+}
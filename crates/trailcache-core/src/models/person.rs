@@ -7,6 +7,8 @@ use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 use chrono::{NaiveDate, Utc, Datelike};
 
+use crate::utils::{format_phone, PhoneFormat};
+
 pub const PROGRAM_SCOUTS_BSA: &str = "Scouts BSA";
 pub const PROGRAM_ID_SCOUTS_BSA: i32 = 2;
 pub const UNIT_TYPE_ID_SCOUTS_BSA: i32 = 2;
@@ -263,10 +265,10 @@ pub struct PrimaryPhoneInfo {
 }
 
 impl PrimaryPhoneInfo {
-    pub fn formatted(&self) -> Option<String> {
+    pub fn formatted(&self, format: PhoneFormat) -> Option<String> {
         match (&self.phone_area_code, &self.phone_prefix, &self.phone_line_number) {
             (Some(area), Some(prefix), Some(line)) if !area.is_empty() && !prefix.is_empty() && !line.is_empty() => {
-                Some(format!("({}) {}-{}", area, prefix, line))
+                Some(format_phone(&format!("{}{}{}", area, prefix, line), format))
             }
             _ => None,
         }
@@ -447,10 +449,10 @@ impl Youth {
         self.grade.map(|g| g.to_string()).unwrap_or_else(|| "-".to_string())
     }
 
-    pub fn phone(&self) -> Option<String> {
+    pub fn phone(&self, format: PhoneFormat) -> Option<String> {
         self.primary_phone_info.as_ref()
-            .and_then(|p| p.formatted())
-            .or_else(|| self.phone_number.clone())
+            .and_then(|p| p.formatted(format))
+            .or_else(|| self.phone_number.as_deref().filter(|p| !p.trim().is_empty()).map(|p| format_phone(p, format)))
     }
 
     pub fn email(&self) -> Option<String> {
@@ -495,6 +497,8 @@ pub struct Adult {
     pub last_name: String,
     #[serde(rename = "nickName")]
     pub nick_name: Option<String>,
+    #[serde(default)]
+    pub gender: Option<String>,
     pub position: Option<String>,
     #[serde(rename = "positionId")]
     pub position_id: Option<i64>,
@@ -583,10 +587,28 @@ impl Adult {
         self.user_id.unwrap_or(0)
     }
 
-    pub fn phone(&self) -> Option<String> {
+    pub fn date_of_birth(&self) -> Option<NaiveDate> {
+        self.registrar_info.as_ref()
+            .and_then(|r| r.date_of_birth.as_ref())
+            .and_then(|dob| NaiveDate::parse_from_str(dob, "%Y-%m-%d").ok())
+    }
+
+    /// When Youth Protection training expires.
+    pub fn ypt_expires(&self) -> Option<NaiveDate> {
+        self.ypt_expired_date.as_deref().and_then(|d| NaiveDate::parse_from_str(&d[..10.min(d.len())], "%Y-%m-%d").ok())
+    }
+
+    /// When the current registration expires.
+    pub fn registration_expires_on(&self) -> Option<NaiveDate> {
+        self.registrar_info.as_ref()
+            .and_then(|r| r.registration_expire_dt.as_deref())
+            .and_then(|d| NaiveDate::parse_from_str(&d[..10.min(d.len())], "%Y-%m-%d").ok())
+    }
+
+    pub fn phone(&self, format: PhoneFormat) -> Option<String> {
         self.primary_phone_info.as_ref()
-            .and_then(|p| p.formatted())
-            .or_else(|| self.phone_number.clone())
+            .and_then(|p| p.formatted(format))
+            .or_else(|| self.phone_number.as_deref().filter(|p| !p.trim().is_empty()).map(|p| format_phone(p, format)))
     }
 
     pub fn email(&self) -> Option<String> {
@@ -604,20 +626,17 @@ impl Adult {
     /// (e.g., "Assistant Scoutmaster, Committee Member").
     ///
     /// Adults without a person_guid are kept as separate entries.
-    /// Result is sorted by last_name, first_name.
+    /// Result is sorted by last_name, first_name; adults with the same name
+    /// stay in the order the API listed them.
     pub fn deduplicate(adults: Vec<Adult>) -> Vec<Adult> {
-        let mut by_guid: HashMap<String, Adult> = HashMap::new();
-        let mut no_guid_counter: usize = 0;
+        let mut result: Vec<Adult> = Vec::new();
+        let mut by_guid: HashMap<String, usize> = HashMap::new();
 
         for adult in adults {
             let guid = adult.person_guid.clone().unwrap_or_default();
-            if guid.is_empty() {
-                by_guid.insert(format!("_no_guid_{}", no_guid_counter), adult);
-                no_guid_counter += 1;
-                continue;
-            }
+            let index = if guid.is_empty() { None } else { by_guid.get(&guid).copied() };
 
-            if let Some(existing) = by_guid.get_mut(&guid) {
+            if let Some(existing) = index.map(|i| &mut result[i]) {
                 if let Some(new_pos) = &adult.position {
                     if let Some(existing_pos) = &existing.position {
                         let existing_positions: Vec<&str> = existing_pos
@@ -632,11 +651,13 @@ impl Adult {
                     }
                 }
             } else {
-                by_guid.insert(guid, adult);
+                if !guid.is_empty() {
+                    by_guid.insert(guid, result.len());
+                }
+                result.push(adult);
             }
         }
 
-        let mut result: Vec<Adult> = by_guid.into_values().collect();
         result.sort_by(|a, b| a.last_name.cmp(&b.last_name).then(a.first_name.cmp(&b.first_name)));
         result
     }
@@ -689,6 +710,7 @@ impl ParentResponse {
         Parent {
             user_id: Some(self.parent_user_id),
             person_guid: info.person_guid.clone(),
+            member_id: info.member_id.map(|id| id.to_string()),
             first_name: info.first_name.clone(),
             last_name: info.last_name.clone(),
             email: info.email.clone(),
@@ -713,6 +735,8 @@ pub struct Parent {
     pub user_id: Option<i64>,
     #[serde(rename = "personGuid")]
     pub person_guid: Option<String>,
+    #[serde(rename = "memberId", default)]
+    pub member_id: Option<String>,
     #[serde(rename = "firstName")]
     pub first_name: String,
     #[serde(rename = "lastName")]
@@ -750,10 +774,10 @@ impl Parent {
         format!("{}, {}", self.last_name, self.first_name)
     }
 
-    pub fn phone(&self) -> Option<String> {
+    pub fn phone(&self, format: PhoneFormat) -> Option<String> {
         self.mobile_phone.as_ref()
             .or(self.home_phone.as_ref())
-            .map(|p| crate::utils::format_phone(p))
+            .map(|p| format_phone(p, format))
     }
 
     pub fn address_line(&self) -> Option<String> {
@@ -884,14 +908,25 @@ impl Youth {
             || self.email().as_ref().map(|s| s.to_lowercase().contains(query_lowercase)).unwrap_or(false)
     }
 
+    /// Fields searched by `SearchQuery`: name, patrol, rank, email.
+    pub fn search_fields(&self) -> Vec<String> {
+        let mut fields = vec![self.first_name.clone(), self.last_name.clone(), self.full_name()];
+        fields.extend(self.patrol_name.clone());
+        fields.extend(self.current_rank.clone());
+        fields.extend(self.email());
+        fields
+    }
+
     /// Compare two youth by the given column, with name as tiebreaker.
     pub fn cmp_by_column(a: &Youth, b: &Youth, column: ScoutSortColumn) -> Ordering {
         use crate::utils::cmp_ignore_case;
         use super::advancement::ScoutRank;
 
+        // Scouts with the same name keep one order however the roster arrives
         let name_cmp = || {
             cmp_ignore_case(&a.last_name, &b.last_name)
                 .then_with(|| cmp_ignore_case(&a.first_name, &b.first_name))
+                .then_with(|| a.user_id.cmp(&b.user_id))
         };
 
         match column {
@@ -972,7 +1007,7 @@ mod tests {
         Adult {
             person_guid: None, member_id: None, person_full_name: None,
             first_name: "Jane".to_string(), middle_name: None, last_name: "Doe".to_string(),
-            nick_name: None, position: Some("Scoutmaster".to_string()), position_id: None,
+            nick_name: None, gender: None, position: Some("Scoutmaster".to_string()), position_id: None,
             key3: None, position_trained: position_trained.map(|s| s.to_string()),
             ypt_status: None, ypt_completed_date: None, ypt_expired_date: None,
             registrar_info: None, primary_email_info: None, primary_phone_info: None,
@@ -1059,4 +1094,103 @@ mod tests {
         assert!(adult.matches_search("scoutmaster"));
         assert!(!adult.matches_search("bob"));
     }
+
+    // -------------------------------------------------------------------------
+    // Property tests: random rosters drawn from names and GUIDs of the kinds
+    // the API sends, and some it shouldn't
+    // -------------------------------------------------------------------------
+
+    use rand::rngs::StdRng;
+    use rand::seq::SliceRandom;
+    use rand::{Rng, SeedableRng};
+
+    const CASES: u64 = 200;
+    const NAMES: &[&str] = &["", " ", "Smith", "smith", "SMITH", "Zoë", "Ødegård", "李", "O'Brien", "van der Berg", "🙂", "e\u{301}"];
+    const GUIDS: &[Option<&str>] = &[None, Some(""), Some("A1"), Some("a1"), Some("B2"), Some("C3")];
+    const POSITIONS: &[Option<&str>] = &[None, Some(""), Some("Scoutmaster"), Some("Committee Member"), Some("Chaplain, Treasurer"), Some("Sécrétaire")];
+
+    fn pick<T: Copy>(rng: &mut StdRng, from: &[T]) -> T {
+        *from.choose(rng).unwrap()
+    }
+
+    fn random_adults(rng: &mut StdRng) -> Vec<Adult> {
+        (0..rng.gen_range(0..16))
+            .map(|_| {
+                let mut adult = make_adult(None);
+                adult.first_name = pick(rng, NAMES).to_string();
+                adult.last_name = pick(rng, NAMES).to_string();
+                adult.person_guid = pick(rng, GUIDS).map(String::from);
+                adult.position = pick(rng, POSITIONS).map(String::from);
+                adult
+            })
+            .collect()
+    }
+
+    fn as_json(adults: &[Adult]) -> serde_json::Value {
+        serde_json::to_value(adults).unwrap()
+    }
+
+    #[test]
+    fn test_deduplicate_adults_properties() {
+        let mut rng = StdRng::seed_from_u64(1272);
+        for _ in 0..CASES {
+            let adults = random_adults(&mut rng);
+            let deduped = Adult::deduplicate(adults.clone());
+
+            let guids: Vec<&str> = deduped.iter().filter_map(|a| a.person_guid.as_deref()).filter(|g| !g.is_empty()).collect();
+            let unique: std::collections::HashSet<&str> = guids.iter().copied().collect();
+            assert_eq!(guids.len(), unique.len(), "one entry per GUID: {:?}", guids);
+            let without_guid = adults.iter().filter(|a| a.person_guid.as_deref().unwrap_or("").is_empty()).count();
+            assert_eq!(deduped.len(), unique.len() + without_guid, "adults without a GUID are all kept");
+
+            assert!(deduped.windows(2).all(|w| (&w[0].last_name, &w[0].first_name) <= (&w[1].last_name, &w[1].first_name)));
+            for adult in &adults {
+                let (Some(guid), Some(position)) = (adult.person_guid.as_deref().filter(|g| !g.is_empty()), &adult.position) else {
+                    continue;
+                };
+                let merged = deduped.iter().find(|a| a.person_guid.as_deref() == Some(guid)).unwrap();
+                assert!(merged.position.as_deref().unwrap_or("").contains(position.as_str()), "{} lost {:?}", guid, position);
+            }
+
+            assert_eq!(as_json(&Adult::deduplicate(adults.clone())), as_json(&deduped), "same input, same order");
+            assert_eq!(as_json(&Adult::deduplicate(deduped.clone())), as_json(&deduped), "deduplicating twice changes nothing");
+        }
+    }
+
+    #[test]
+    fn test_youth_sort_properties() {
+        // The ordering `get_sorted_youth` sorts the Scouts tab with
+        let columns = [ScoutSortColumn::Name, ScoutSortColumn::Patrol, ScoutSortColumn::Rank, ScoutSortColumn::Grade, ScoutSortColumn::Age];
+        let ranks = [None, Some(""), Some("Eagle"), Some("eagle"), Some("Second Class"), Some("Bobcat"), Some("🙂")];
+        let mut rng = StdRng::seed_from_u64(1272);
+        for _ in 0..CASES {
+            let youth: Vec<Youth> = (0..rng.gen_range(0..16))
+                .map(|id| {
+                    let mut y = make_youth(None, Some(pick(&mut rng, NAMES)));
+                    y.first_name = pick(&mut rng, NAMES).to_string();
+                    y.last_name = pick(&mut rng, NAMES).to_string();
+                    y.current_rank = pick(&mut rng, &ranks).map(String::from);
+                    y.grade = [None, Some(6), Some(12)].choose(&mut rng).copied().flatten();
+                    y.user_id = Some(id);
+                    y
+                })
+                .collect();
+
+            for column in columns {
+                for a in &youth {
+                    for b in &youth {
+                        assert_eq!(Youth::cmp_by_column(a, b, column), Youth::cmp_by_column(b, a, column).reverse());
+                    }
+                }
+                let sorted_ids = |list: &[Youth]| {
+                    let mut refs: Vec<&Youth> = list.iter().collect();
+                    refs.sort_by(|a, b| Youth::cmp_by_column(a, b, column));
+                    refs.iter().map(|y| y.user_id).collect::<Vec<_>>()
+                };
+                let mut shuffled = youth.clone();
+                shuffled.shuffle(&mut rng);
+                assert_eq!(sorted_ids(&youth), sorted_ids(&shuffled), "{:?} order depends on roster order", column);
+            }
+        }
+    }
 }
@@ -249,7 +249,7 @@ pub fn group_youth_by_badge(
             scouts,
         })
         .collect();
-    groups.sort_by(|a, b| a.badge_name.to_lowercase().cmp(&b.badge_name.to_lowercase()));
+    groups.sort_by_key(|g| g.badge_name.to_lowercase());
     groups
 }
 
@@ -296,7 +296,7 @@ pub fn badge_list(
                 .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
         });
     } else {
-        result.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+        result.sort_by_key(|b| b.name.to_lowercase());
     }
 
     result
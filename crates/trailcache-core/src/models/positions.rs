@@ -0,0 +1,197 @@
+//! Planning next term's youth leadership positions.
+//!
+//! The plan is kept locally and checked against troop eligibility rules:
+//! a minimum rank and days already served in leadership. Scoutbook's API
+//! doesn't accept position changes, so the finished plan is printed for
+//! entry there at the term change.
+
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use super::advancement::{LeadershipPosition, ScoutRank};
+use super::person::{Youth, POSITION_ASST_PATROL_LEADER, POSITION_PATROL_LEADER};
+
+/// Positions only one scout in the troop can hold
+const SINGLE_HOLDER_POSITIONS: [&str; 1] = ["Senior Patrol Leader"];
+
+/// Eligibility for a position: minimum rank and leadership days already served.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PositionRule {
+    pub position: &'static str,
+    pub min_rank: ScoutRank,
+    pub min_leadership_days: i64,
+}
+
+/// Default troop rules; positions not listed are open to any scout.
+pub const POSITION_RULES: [PositionRule; 5] = [
+    PositionRule { position: "Senior Patrol Leader", min_rank: ScoutRank::FirstClass, min_leadership_days: 180 },
+    PositionRule { position: "Assistant Senior Patrol Leader", min_rank: ScoutRank::FirstClass, min_leadership_days: 90 },
+    PositionRule { position: "Troop Guide", min_rank: ScoutRank::FirstClass, min_leadership_days: 0 },
+    PositionRule { position: "Junior Assistant Scoutmaster", min_rank: ScoutRank::Star, min_leadership_days: 180 },
+    PositionRule { position: "Patrol Leader", min_rank: ScoutRank::Tenderfoot, min_leadership_days: 0 },
+];
+
+/// One planned assignment.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlannedAssignment {
+    pub user_id: i64,
+    pub position: String,
+}
+
+/// Next term's planned positions, one per scout.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PositionPlan {
+    pub assignments: Vec<PlannedAssignment>,
+}
+
+impl PositionPlan {
+    /// Assign a position, replacing the scout's current planned one.
+    pub fn assign(&mut self, user_id: i64, position: &str) {
+        self.unassign(user_id);
+        self.assignments.push(PlannedAssignment { user_id, position: position.to_string() });
+    }
+
+    pub fn unassign(&mut self, user_id: i64) -> bool {
+        let before = self.assignments.len();
+        self.assignments.retain(|a| a.user_id != user_id);
+        self.assignments.len() != before
+    }
+
+    pub fn position_for(&self, user_id: i64) -> Option<&str> {
+        self.assignments.iter().find(|a| a.user_id == user_id).map(|a| a.position.as_str())
+    }
+
+    pub fn holders(&self, position: &str) -> Vec<i64> {
+        self.assignments.iter().filter(|a| a.position == position).map(|a| a.user_id).collect()
+    }
+}
+
+fn parse_date(date: Option<&str>) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(date?.get(..10)?, "%Y-%m-%d").ok()
+}
+
+/// Total days a scout has served in leadership positions as of `today`.
+/// Time in overlapping positions counts once; positions without a start date
+/// fall back to their recorded days served.
+pub fn leadership_days(history: &[LeadershipPosition], today: NaiveDate) -> i64 {
+    let mut undated = 0;
+    let mut spans = Vec::new();
+    for position in history {
+        match parse_date(position.start_date.as_deref()) {
+            Some(start) => {
+                let end = parse_date(position.end_date.as_deref()).unwrap_or(today);
+                spans.push((start, end.max(start)));
+            }
+            None => undated += position.days_served.map(i64::from).unwrap_or_default(),
+        }
+    }
+    spans.sort();
+
+    let mut days = 0;
+    let mut current: Option<(NaiveDate, NaiveDate)> = None;
+    for (start, end) in spans {
+        current = match current {
+            Some((s, e)) if start <= e => Some((s, e.max(end))),
+            Some((s, e)) => {
+                days += (e - s).num_days();
+                Some((start, end))
+            }
+            None => Some((start, end)),
+        };
+    }
+    if let Some((s, e)) = current {
+        days += (e - s).num_days();
+    }
+    days + undated
+}
+
+/// Why a scout can't hold a position under the troop rules; empty when eligible.
+pub fn eligibility_issues(position: &str, rank: ScoutRank, days_served: i64) -> Vec<String> {
+    let Some(rule) = POSITION_RULES.iter().find(|r| r.position == position) else {
+        return Vec::new();
+    };
+    let mut issues = Vec::new();
+    if rank.order() < rule.min_rank.order() {
+        issues.push(format!("{} requires {} rank", position, rule.min_rank.display_name()));
+    }
+    if days_served < rule.min_leadership_days {
+        issues.push(format!(
+            "{} requires {} days of prior leadership ({} served)",
+            position, rule.min_leadership_days, days_served
+        ));
+    }
+    issues
+}
+
+/// Every problem with the plan: ineligible scouts, and positions with too many
+/// holders (one Senior Patrol Leader; one Patrol Leader and Assistant per patrol).
+pub fn plan_issues(
+    plan: &PositionPlan,
+    youth: &[Youth],
+    leadership: &HashMap<i64, Vec<LeadershipPosition>>,
+    today: NaiveDate,
+) -> Vec<(i64, String)> {
+    let by_id: HashMap<i64, &Youth> = youth.iter().filter_map(|y| Some((y.user_id?, y))).collect();
+    let mut issues = Vec::new();
+
+    for assignment in &plan.assignments {
+        let Some(scout) = by_id.get(&assignment.user_id) else {
+            issues.push((assignment.user_id, "No longer on the roster".to_string()));
+            continue;
+        };
+        let rank = ScoutRank::parse(scout.current_rank.as_deref());
+        let days = leadership.get(&assignment.user_id).map(|h| leadership_days(h, today)).unwrap_or_default();
+        for issue in eligibility_issues(&assignment.position, rank, days) {
+            issues.push((assignment.user_id, issue));
+        }
+
+        let position = assignment.position.as_str();
+        let clashes = plan.holders(position).into_iter().filter(|&id| id != assignment.user_id).filter(|id| {
+            if position == POSITION_PATROL_LEADER || position == POSITION_ASST_PATROL_LEADER {
+                by_id.get(id).is_some_and(|other| other.patrol_name == scout.patrol_name)
+            } else {
+                SINGLE_HOLDER_POSITIONS.contains(&position)
+            }
+        });
+        if clashes.count() > 0 {
+            issues.push((assignment.user_id, format!("More than one {} planned", position)));
+        }
+    }
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plan_issues_for_rank_tenure_and_duplicates() {
+        let youth: Vec<Youth> = serde_json::from_value(serde_json::json!([
+            { "userId": 1, "firstName": "Sam", "lastName": "Scout", "currentRankName": "Star", "subUnitName": "Eagles" },
+            { "userId": 2, "firstName": "Alex", "lastName": "Ranger", "currentRankName": "Tenderfoot", "subUnitName": "Eagles" },
+            { "userId": 3, "firstName": "Pat", "lastName": "Hawk", "currentRankName": "Scout", "subUnitName": "Hawks" }
+        ]))
+        .unwrap();
+        let history: Vec<LeadershipPosition> =
+            serde_json::from_value(serde_json::json!([{ "position": "Patrol Leader", "numberOfDaysInPosition": 200 }]))
+                .unwrap();
+        let leadership = HashMap::from([(1, history)]);
+        let today = NaiveDate::from_ymd_opt(2026, 10, 14).unwrap();
+
+        let mut plan = PositionPlan::default();
+        plan.assign(1, "Senior Patrol Leader");
+        plan.assign(2, "Senior Patrol Leader");
+        plan.assign(3, "Patrol Leader");
+        let issues = plan_issues(&plan, &youth, &leadership, today);
+        let for_scout = |id: i64| issues.iter().filter(|(u, _)| *u == id).map(|(_, m)| m.as_str()).collect::<Vec<_>>();
+
+        assert_eq!(for_scout(1), ["More than one Senior Patrol Leader planned"]);
+        assert_eq!(for_scout(2).len(), 3, "rank, tenure, and duplicate");
+        assert_eq!(for_scout(3), ["Patrol Leader requires Tenderfoot rank"]);
+
+        plan.assign(2, "Patrol Leader");
+        assert!(plan_issues(&plan, &youth, &leadership, today).iter().all(|(id, _)| *id == 3), "different patrols");
+    }
+}
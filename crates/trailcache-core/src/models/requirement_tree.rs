@@ -0,0 +1,183 @@
+//! Parent/child structure of requirement numbers.
+//!
+//! Scoutbook lists requirements flat: "7", "7a", "7b", or just "a", "b"
+//! after a "7", and sometimes "7b(1)" or "(1)" below that. This works out
+//! which requirement each one belongs under so views can indent them and
+//! roll sub-requirement completion up to the parent.
+
+use std::collections::HashMap;
+
+use super::sorting::HasRequirementNumber;
+
+/// What kind of number segment: "7", "b", or "(1)".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+enum SegmentKind {
+    Number,
+    Letter,
+    Paren,
+}
+
+/// Split a requirement number into segments: "7b(1)" -> 7, b, (1).
+fn segments(num: &str) -> Vec<(SegmentKind, String)> {
+    let mut out = Vec::new();
+    let mut chars = num.trim().trim_end_matches('.').chars().peekable();
+    while let Some(&c) = chars.peek() {
+        if c == '(' {
+            chars.next();
+            let inner: String = chars.by_ref().take_while(|&c| c != ')').collect();
+            out.push((SegmentKind::Paren, inner.trim().to_lowercase()));
+        } else if c.is_ascii_digit() {
+            let mut digits = String::new();
+            while let Some(&d) = chars.peek().filter(|d| d.is_ascii_digit()) {
+                digits.push(d);
+                chars.next();
+            }
+            out.push((SegmentKind::Number, digits));
+        } else if c.is_alphabetic() {
+            let mut letters = String::new();
+            while let Some(&l) = chars.peek().filter(|l| l.is_alphabetic()) {
+                letters.extend(l.to_lowercase());
+                chars.next();
+            }
+            out.push((SegmentKind::Letter, letters));
+        } else {
+            chars.next();
+        }
+    }
+    out
+}
+
+/// One requirement's place in the tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequirementNode {
+    /// Number including its parents, e.g. "7b" for a "b" listed under "7"
+    pub number: String,
+    /// 0 for top-level requirements
+    pub depth: usize,
+    pub parent: Option<usize>,
+    pub children: Vec<usize>,
+}
+
+/// Nesting for a list of requirements, indexed like the list.
+#[derive(Debug, Clone, Default)]
+pub struct RequirementTree {
+    nodes: Vec<RequirementNode>,
+}
+
+impl RequirementTree {
+    /// Build from requirements in display order (see `sort_requirements`);
+    /// a bare "a" belongs to whichever requirement came before it.
+    pub fn build<T: HasRequirementNumber>(reqs: &[T]) -> Self {
+        let mut nodes: Vec<RequirementNode> = Vec::with_capacity(reqs.len());
+        let mut by_path: HashMap<Vec<(SegmentKind, String)>, usize> = HashMap::new();
+        let mut last_path: Vec<(SegmentKind, String)> = Vec::new();
+
+        for (i, req) in reqs.iter().enumerate() {
+            let own = segments(&req.requirement_number_str());
+            let path = match own.first() {
+                Some((SegmentKind::Number, _)) | None => own,
+                Some(&(kind, _)) => {
+                    // Relative number: keep the previous path above this level
+                    let mut path: Vec<_> = last_path.iter().take_while(|(k, _)| *k < kind).cloned().collect();
+                    path.extend(own);
+                    path
+                }
+            };
+
+            let parent = (1..path.len()).rev().find_map(|len| by_path.get(&path[..len]).copied());
+            let depth = parent.map_or(0, |p| nodes[p].depth + 1);
+            if let Some(p) = parent {
+                nodes[p].children.push(i);
+            }
+            let number = path
+                .iter()
+                .map(|(kind, s)| if *kind == SegmentKind::Paren { format!("({})", s) } else { s.clone() })
+                .collect();
+            nodes.push(RequirementNode { number, depth, parent, children: Vec::new() });
+            if !path.is_empty() {
+                by_path.entry(path.clone()).or_insert(i);
+            }
+            last_path = path;
+        }
+        Self { nodes }
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn node(&self, index: usize) -> &RequirementNode {
+        &self.nodes[index]
+    }
+
+    pub fn depth(&self, index: usize) -> usize {
+        self.nodes.get(index).map_or(0, |n| n.depth)
+    }
+
+    /// Whether a requirement is done: marked complete itself, or every one
+    /// of its sub-requirements is.
+    pub fn is_done(&self, index: usize, completed: &dyn Fn(usize) -> bool) -> bool {
+        completed(index) || {
+            let children = &self.nodes[index].children;
+            !children.is_empty() && children.iter().all(|&c| self.is_done(c, completed))
+        }
+    }
+
+    /// (done, total) of a requirement's direct sub-requirements, or None
+    /// if it has none.
+    pub fn rollup(&self, index: usize, completed: &dyn Fn(usize) -> bool) -> Option<(usize, usize)> {
+        let children = &self.nodes[index].children;
+        if children.is_empty() {
+            return None;
+        }
+        let done = children.iter().filter(|&&c| self.is_done(c, completed)).count();
+        Some((done, children.len()))
+    }
+
+    /// Full numbers of the requirements still to do, counting only those
+    /// without sub-requirements so "3" isn't listed alongside "3a".
+    pub fn remaining_numbers(&self, completed: &dyn Fn(usize) -> bool) -> Vec<String> {
+        (0..self.nodes.len())
+            .filter(|&i| self.nodes[i].children.is_empty() && !self.is_done(i, completed))
+            .map(|i| self.nodes[i].number.clone())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    impl HasRequirementNumber for &str {
+        fn requirement_number_str(&self) -> String {
+            self.to_string()
+        }
+    }
+
+    #[test]
+    fn test_requirement_tree() {
+        let reqs = ["1", "2", "a", "b", "b(1)", "(2)", "3", "3a", "3b", "4b"];
+        let tree = RequirementTree::build(&reqs);
+
+        let numbers: Vec<&str> = (0..tree.len()).map(|i| tree.node(i).number.as_str()).collect();
+        assert_eq!(numbers, ["1", "2", "2a", "2b", "2b(1)", "2b(2)", "3", "3a", "3b", "4b"]);
+        let depths: Vec<usize> = (0..tree.len()).map(|i| tree.depth(i)).collect();
+        assert_eq!(depths, [0, 0, 1, 1, 2, 2, 0, 1, 1, 0], "4b has no 4 to sit under");
+        assert_eq!(tree.node(1).children, [2, 3]);
+        assert_eq!(tree.node(5).parent, Some(3));
+
+        // 2a and both parts of 2b are done, so 2b and 2 roll up as done
+        let completed = |i: usize| [2, 4, 5, 7].contains(&i);
+        assert_eq!(tree.rollup(3, &completed), Some((2, 2)));
+        assert_eq!(tree.rollup(1, &completed), Some((2, 2)));
+        assert!(tree.is_done(1, &completed));
+        assert_eq!(tree.rollup(6, &completed), Some((1, 2)));
+        assert!(!tree.is_done(6, &completed));
+        assert_eq!(tree.rollup(0, &completed), None);
+        assert_eq!(tree.remaining_numbers(&completed), ["1", "3b", "4b"]);
+    }
+}
@@ -0,0 +1,295 @@
+//! Locally tracked scout certifications: Totin' Chip, Firem'n Chit, and swim
+//! classification.
+//!
+//! Leaders check these before activities that require them, e.g. only
+//! Totin' Chip holders may carry a knife. Records carry the earned date and
+//! card number, and round-trip through CSV so they can be kept in a
+//! spreadsheet as well.
+
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use chrono::{Months, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use super::advancement::Award;
+use crate::utils::{csv_line, parse_csv_line};
+
+/// Swim classification has to be renewed this often
+const SWIM_TEST_VALID_MONTHS: u32 = 12;
+
+const CSV_HEADER: [&str; 6] = ["user_id", "name", "certification", "earned_on", "card_number", "swim_level"];
+
+/// A certification tracked in the skills matrix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Certification {
+    TotinChip,
+    FiremnChit,
+    SwimTest,
+}
+
+impl Certification {
+    pub const ALL: [Certification; 3] = [Certification::TotinChip, Certification::FiremnChit, Certification::SwimTest];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Certification::TotinChip => "Totin' Chip",
+            Certification::FiremnChit => "Firem'n Chit",
+            Certification::SwimTest => "Swim Classification",
+        }
+    }
+
+    /// Column heading for the matrix
+    pub fn short_name(&self) -> &'static str {
+        match self {
+            Certification::TotinChip => "Totin'",
+            Certification::FiremnChit => "Firem'n",
+            Certification::SwimTest => "Swim",
+        }
+    }
+
+    /// Match a name from CSV or Scoutbook, ignoring case and punctuation.
+    pub fn parse(name: &str) -> Option<Self> {
+        let key: String = name.chars().filter(|c| c.is_alphanumeric()).collect::<String>().to_lowercase();
+        match key.as_str() {
+            "totinchip" | "totin" => Some(Certification::TotinChip),
+            "firemnchit" | "firemn" => Some(Certification::FiremnChit),
+            "swimclassification" | "swimtest" | "swim" => Some(Certification::SwimTest),
+            _ => None,
+        }
+    }
+}
+
+/// BSA swim classification levels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SwimLevel {
+    NonSwimmer,
+    Beginner,
+    Swimmer,
+}
+
+impl SwimLevel {
+    pub fn label(&self) -> &'static str {
+        match self {
+            SwimLevel::NonSwimmer => "Non-swimmer",
+            SwimLevel::Beginner => "Beginner",
+            SwimLevel::Swimmer => "Swimmer",
+        }
+    }
+
+    pub fn parse(label: &str) -> Option<Self> {
+        match label.trim().to_lowercase().replace('-', "").as_str() {
+            "nonswimmer" => Some(SwimLevel::NonSwimmer),
+            "beginner" => Some(SwimLevel::Beginner),
+            "swimmer" => Some(SwimLevel::Swimmer),
+            _ => None,
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            SwimLevel::NonSwimmer => SwimLevel::Beginner,
+            SwimLevel::Beginner => SwimLevel::Swimmer,
+            SwimLevel::Swimmer => SwimLevel::NonSwimmer,
+        }
+    }
+}
+
+/// One scout's certification.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SkillRecord {
+    pub user_id: i64,
+    pub certification: Certification,
+    pub earned_on: Option<NaiveDate>,
+    pub card_number: Option<String>,
+    /// Only for `Certification::SwimTest`
+    pub swim_level: Option<SwimLevel>,
+}
+
+impl SkillRecord {
+    /// Swim tests lapse after a year; the other certifications don't expire.
+    pub fn expires_on(&self) -> Option<NaiveDate> {
+        match self.certification {
+            Certification::SwimTest => self.earned_on?.checked_add_months(Months::new(SWIM_TEST_VALID_MONTHS)),
+            _ => None,
+        }
+    }
+
+    pub fn is_current(&self, today: NaiveDate) -> bool {
+        self.expires_on().is_none_or(|d| d > today)
+    }
+
+    /// Whether this record qualifies the scout: current, and for swim tests,
+    /// classified as a swimmer.
+    pub fn qualifies(&self, today: NaiveDate) -> bool {
+        self.is_current(today)
+            && (self.certification != Certification::SwimTest || self.swim_level == Some(SwimLevel::Swimmer))
+    }
+}
+
+/// Persisted certification records for the whole troop.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SkillMatrix {
+    pub records: Vec<SkillRecord>,
+}
+
+impl SkillMatrix {
+    pub fn get(&self, user_id: i64, certification: Certification) -> Option<&SkillRecord> {
+        self.records.iter().find(|r| r.user_id == user_id && r.certification == certification)
+    }
+
+    /// Add a record, replacing any existing one for the same scout and certification.
+    pub fn record(&mut self, record: SkillRecord) {
+        self.remove(record.user_id, record.certification);
+        self.records.push(record);
+    }
+
+    pub fn remove(&mut self, user_id: i64, certification: Certification) -> Option<SkillRecord> {
+        let pos = self.records.iter().position(|r| r.user_id == user_id && r.certification == certification)?;
+        Some(self.records.remove(pos))
+    }
+
+    /// Scouts who currently qualify for a certification.
+    pub fn holders(&self, certification: Certification, today: NaiveDate) -> Vec<i64> {
+        self.records
+            .iter()
+            .filter(|r| r.certification == certification && r.qualifies(today))
+            .map(|r| r.user_id)
+            .collect()
+    }
+
+    /// Add Totin' Chip and Firem'n Chit records for awards Scoutbook shows as
+    /// awarded but that aren't tracked locally yet. Returns how many were added.
+    pub fn sync_from_awards(&mut self, awards_by_youth: &HashMap<i64, Vec<Award>>) -> usize {
+        let mut added = 0;
+        for (&user_id, awards) in awards_by_youth {
+            for award in awards.iter().filter(|a| a.is_awarded()) {
+                let Some(certification) = Certification::parse(award.name()) else { continue };
+                if certification == Certification::SwimTest || self.get(user_id, certification).is_some() {
+                    continue;
+                }
+                let earned_on = award
+                    .date_awarded
+                    .as_deref()
+                    .and_then(|d| d.get(..10))
+                    .and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok());
+                self.records.push(SkillRecord { user_id, certification, earned_on, card_number: None, swim_level: None });
+                added += 1;
+            }
+        }
+        added
+    }
+
+    /// Export as CSV, with `name` filling in the informational name column.
+    pub fn to_csv(&self, name: impl Fn(i64) -> String) -> String {
+        let mut records: Vec<&SkillRecord> = self.records.iter().collect();
+        records.sort_by_key(|r| (name(r.user_id), r.certification as u8));
+
+        let mut out = csv_line(&CSV_HEADER);
+        out.push('\n');
+        for r in records {
+            out.push_str(&csv_line(&[
+                r.user_id.to_string(),
+                name(r.user_id),
+                r.certification.name().to_string(),
+                r.earned_on.map(|d| d.to_string()).unwrap_or_default(),
+                r.card_number.clone().unwrap_or_default(),
+                r.swim_level.map(|l| l.label().to_string()).unwrap_or_default(),
+            ]));
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Import records from CSV in the `to_csv` layout, replacing matching
+    /// records. Returns how many were imported.
+    pub fn import_csv(&mut self, csv: &str) -> Result<usize> {
+        let mut count = 0;
+        for (i, line) in csv.lines().enumerate().skip(1) {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let row = parse_csv_line(line);
+            let field = |n: usize| row.get(n).map(|s| s.trim()).filter(|s| !s.is_empty());
+            let line_no = i + 1;
+
+            let user_id = field(0)
+                .and_then(|s| s.parse().ok())
+                .ok_or_else(|| anyhow!("Line {}: missing or invalid user_id", line_no))?;
+            let certification = field(2)
+                .and_then(Certification::parse)
+                .ok_or_else(|| anyhow!("Line {}: unknown certification", line_no))?;
+            let earned_on = match field(3) {
+                Some(d) => Some(
+                    NaiveDate::parse_from_str(d, "%Y-%m-%d")
+                        .map_err(|_| anyhow!("Line {}: earned_on must be YYYY-MM-DD", line_no))?,
+                ),
+                None => None,
+            };
+            let swim_level = field(5).and_then(SwimLevel::parse);
+
+            self.record(SkillRecord {
+                user_id,
+                certification,
+                earned_on,
+                card_number: field(4).map(str::to_string),
+                swim_level,
+            });
+            count += 1;
+        }
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn test_holders_and_swim_expiry() {
+        let mut matrix = SkillMatrix::default();
+        matrix.record(SkillRecord {
+            user_id: 1,
+            certification: Certification::SwimTest,
+            earned_on: Some(date("2025-06-01")),
+            card_number: None,
+            swim_level: Some(SwimLevel::Swimmer),
+        });
+        matrix.record(SkillRecord {
+            user_id: 2,
+            certification: Certification::SwimTest,
+            earned_on: Some(date("2026-06-01")),
+            card_number: None,
+            swim_level: Some(SwimLevel::Beginner),
+        });
+
+        assert_eq!(matrix.holders(Certification::SwimTest, date("2026-05-01")), [1]);
+        assert!(matrix.holders(Certification::SwimTest, date("2026-06-02")).is_empty(), "expired after a year");
+        assert!(matrix.get(2, Certification::SwimTest).unwrap().is_current(date("2026-10-01")));
+    }
+
+    #[test]
+    fn test_csv_round_trip() {
+        let mut matrix = SkillMatrix::default();
+        matrix.record(SkillRecord {
+            user_id: 7,
+            certification: Certification::TotinChip,
+            earned_on: Some(date("2026-03-14")),
+            card_number: Some("TC-042".to_string()),
+            swim_level: None,
+        });
+        let csv = matrix.to_csv(|_| "Scout, Sam".to_string());
+        assert!(csv.contains("7,\"Scout, Sam\",Totin' Chip,2026-03-14,TC-042,"));
+
+        let mut imported = SkillMatrix::default();
+        assert_eq!(imported.import_csv(&csv).unwrap(), 1);
+        assert_eq!(imported.records, matrix.records);
+
+        let err = imported.import_csv("user_id,name,certification\n7,Sam,Canoeing").unwrap_err();
+        assert_eq!(err.to_string(), "Line 2: unknown certification");
+    }
+}
@@ -25,26 +25,17 @@ pub fn req_number_sort_key(num: &str) -> (u32, String) {
 pub fn sorted_indices_by_number(numbers: &[String]) -> Vec<usize> {
     let keys: Vec<(u32, String)> = numbers.iter().map(|n| req_number_sort_key(n)).collect();
 
-    // Assign parent numbers to purely-alpha sub-requirements
-    let mut parent_map: Vec<u32> = Vec::with_capacity(keys.len());
-    let mut last_parent: u32 = 0;
-    for key in &keys {
-        if key.0 > 0 {
-            last_parent = key.0;
-            parent_map.push(key.0);
-        } else {
-            parent_map.push(last_parent);
-        }
-    }
-
-    let final_keys: Vec<(u32, String)> = keys
-        .iter()
-        .enumerate()
-        .map(|(i, key)| {
-            if key.0 == 0 {
-                (parent_map[i], key.1.clone())
+    // Purely-alpha sub-requirements sort right after the requirement they
+    // follow ("1b", "a" stays in that order), so sorting again moves nothing
+    let mut last_parent: (u32, String) = (0, String::new());
+    let final_keys: Vec<(u32, String, String)> = keys
+        .into_iter()
+        .map(|(n, suffix)| {
+            if n > 0 {
+                last_parent = (n, suffix.clone());
+                (n, suffix, String::new())
             } else {
-                key.clone()
+                (last_parent.0, last_parent.1.clone(), suffix)
             }
         })
         .collect();
@@ -127,4 +118,33 @@ mod tests {
         let sorted: Vec<&str> = order.iter().map(|&i| nums[i].as_str()).collect();
         assert_eq!(sorted, vec!["1", "1a", "1b", "2", "2a", "3"]);
     }
+
+    #[test]
+    fn test_sorted_indices_properties() {
+        use rand::rngs::StdRng;
+        use rand::seq::SliceRandom;
+        use rand::{Rng, SeedableRng};
+
+        // Numbers as Scoutbook writes them, and malformed ones
+        let pieces = ["", " ", "(", ")", "1", "2", "10", "3a", "3b", "a", "B", "ii", "é", "٣", "4294967296", "007", "1.", "🙂"];
+        let mut rng = StdRng::seed_from_u64(1272);
+        for _ in 0..500 {
+            let nums: Vec<String> = (0..rng.gen_range(0..12))
+                .map(|_| (0..rng.gen_range(1..3)).map(|_| *pieces.choose(&mut rng).unwrap()).collect())
+                .collect();
+            let order = sorted_indices_by_number(&nums);
+
+            let mut seen = order.clone();
+            seen.sort_unstable();
+            assert_eq!(seen, (0..nums.len()).collect::<Vec<_>>(), "not a permutation of {:?}", nums);
+
+            // Numbered requirements come out in numeric order
+            let numbered: Vec<u32> = order.iter().map(|&i| req_number_sort_key(&nums[i]).0).filter(|&n| n > 0).collect();
+            assert!(numbered.windows(2).all(|w| w[0] <= w[1]), "{:?}", nums);
+
+            let sorted: Vec<String> = order.iter().map(|&i| nums[i].clone()).collect();
+            let again: Vec<String> = sorted_indices_by_number(&sorted).iter().map(|&i| sorted[i].clone()).collect();
+            assert_eq!(again, sorted, "sorting sorted numbers moved them");
+        }
+    }
 }
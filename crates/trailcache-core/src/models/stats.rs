@@ -3,10 +3,16 @@
 //! These functions consolidate business logic that was previously
 //! duplicated between the TUI and GUI interfaces.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
+use chrono::{Datelike, Duration, Months, NaiveDate};
+
+use crate::models::attendance::AttendanceHistory;
+use crate::models::event::{Event, EventGuest, RsvpStatus};
+use crate::models::feed::ActivityFeed;
+use crate::models::pace::{progress_dates, ScoutPace, PACE_WINDOW_MONTHS};
 use crate::models::person::{Adult, Youth};
-use crate::models::advancement::ScoutRank;
+use crate::models::advancement::{MeritBadgeProgress, RankProgress, ScoutRank};
 use crate::utils::format::{check_expiration, ExpirationStatus};
 
 // ============================================================================
@@ -180,6 +186,350 @@ pub fn patrol_rank_breakdown(youth: &[Youth]) -> HashMap<String, PatrolBreakdown
     result
 }
 
+// ============================================================================
+// RSVP Statistics
+// ============================================================================
+
+/// RSVP responses for one group of invitees.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RsvpTally {
+    pub going: usize,
+    pub not_going: usize,
+    pub maybe: usize,
+    pub no_response: usize,
+}
+
+impl RsvpTally {
+    fn add(&mut self, status: RsvpStatus) {
+        match status {
+            RsvpStatus::Going => self.going += 1,
+            RsvpStatus::NotGoing => self.not_going += 1,
+            RsvpStatus::Maybe => self.maybe += 1,
+            RsvpStatus::NoResponse => self.no_response += 1,
+        }
+    }
+
+    pub fn total(&self) -> usize {
+        self.going + self.not_going + self.maybe + self.no_response
+    }
+}
+
+/// RSVP totals for one event, split by youth and adults.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RsvpStats {
+    pub youth: RsvpTally,
+    pub adults: RsvpTally,
+    /// Guests not marked as not going (guests are usually added as attendees)
+    pub guests: usize,
+}
+
+impl RsvpStats {
+    /// Compute RSVP totals from an event's invitees and its guest list.
+    pub fn from_event(event: &Event, guests: &[EventGuest]) -> Self {
+        let mut stats = RsvpStats::default();
+        for user in &event.invited_users {
+            if user.is_adult {
+                stats.adults.add(user.status());
+            } else {
+                stats.youth.add(user.status());
+            }
+        }
+        stats.guests = guests
+            .iter()
+            .filter(|g| g.status() != RsvpStatus::NotGoing)
+            .count();
+        stats
+    }
+
+    /// Expected attendance: youth and adults going, plus guests.
+    pub fn headcount(&self) -> usize {
+        self.youth.going + self.adults.going + self.guests
+    }
+}
+
+// ============================================================================
+// Attendance Statistics
+// ============================================================================
+
+/// A scout's attendance at troop meetings and calendar events over a period.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AttendanceStats {
+    pub meetings_attended: usize,
+    /// Recorded meetings the scout was on the roster for
+    pub meetings_held: usize,
+    pub events_attended: usize,
+    /// Past events the scout was invited to
+    pub events_invited: usize,
+}
+
+impl AttendanceStats {
+    /// Attendance for one scout from `since` up to (not including) `today`.
+    pub fn for_scout(
+        user_id: i64,
+        history: &AttendanceHistory,
+        events: &[Event],
+        since: NaiveDate,
+        today: NaiveDate,
+    ) -> Self {
+        let mut stats = AttendanceStats::default();
+
+        for meeting in history.since(since).filter(|m| m.date <= today && m.was_expected(user_id)) {
+            stats.meetings_held += 1;
+            if meeting.was_present(user_id) {
+                stats.meetings_attended += 1;
+            }
+        }
+
+        for event in events {
+            let Some(date) = event.start_date.as_deref().and_then(|d| d.get(..10)) else { continue };
+            let Ok(date) = NaiveDate::parse_from_str(date, "%Y-%m-%d") else { continue };
+            if date < since || date >= today {
+                continue;
+            }
+            if let Some(user) = event.invited_users.iter().find(|u| u.user_id == user_id) {
+                stats.events_invited += 1;
+                if user.attended {
+                    stats.events_attended += 1;
+                }
+            }
+        }
+
+        stats
+    }
+
+    /// Percentage of meetings attended, if any were recorded.
+    pub fn meeting_percent(&self) -> Option<u32> {
+        percent(self.meetings_attended, self.meetings_held)
+    }
+
+    /// Percentage of invited events attended, if any.
+    pub fn event_percent(&self) -> Option<u32> {
+        percent(self.events_attended, self.events_invited)
+    }
+}
+
+fn percent(part: usize, whole: usize) -> Option<u32> {
+    (whole > 0).then(|| ((part as f64 / whole as f64) * 100.0).round() as u32)
+}
+
+// ============================================================================
+// Advancement Velocity
+// ============================================================================
+
+/// How quickly one scout is advancing.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoutVelocity {
+    pub user_id: i64,
+    pub name: String,
+    pub rank: ScoutRank,
+    /// Requirements, ranks, and merit badges completed per month over the
+    /// pace window
+    pub per_month: f64,
+    /// Days since the current rank was earned; None before Scout rank
+    pub days_at_rank: Option<i64>,
+}
+
+impl ScoutVelocity {
+    pub fn new(
+        youth: &Youth,
+        ranks: &[RankProgress],
+        badges: &[MeritBadgeProgress],
+        feed: &ActivityFeed,
+        today: NaiveDate,
+    ) -> Self {
+        let earned = ranks
+            .iter()
+            .filter(|r| r.is_completed())
+            .max_by_key(|r| r.sort_order())
+            .and_then(|r| parse_day(r.date_completed.as_deref()));
+        ScoutVelocity {
+            user_id: youth.get_user_id(),
+            name: youth.full_name(),
+            rank: ScoutRank::parse(youth.current_rank.as_deref()),
+            per_month: ScoutPace::new(youth, ranks, badges, feed, today).per_month(),
+            days_at_rank: earned.map(|d| (today - d).num_days()),
+        }
+    }
+}
+
+/// Velocity for every scout whose ranks are loaded, slowest first: fewest
+/// completions a month, then longest at their rank.
+pub fn advancement_velocity(
+    youth: &[Youth],
+    ranks: &HashMap<i64, Vec<RankProgress>>,
+    badges: &HashMap<i64, Vec<MeritBadgeProgress>>,
+    feed: &ActivityFeed,
+    today: NaiveDate,
+) -> Vec<ScoutVelocity> {
+    let mut velocity: Vec<ScoutVelocity> = youth
+        .iter()
+        .filter_map(|y| {
+            let id = y.user_id?;
+            let badges = badges.get(&id).map(Vec::as_slice).unwrap_or_default();
+            Some(ScoutVelocity::new(y, ranks.get(&id)?, badges, feed, today))
+        })
+        .collect();
+    velocity.sort_by(|a, b| {
+        a.per_month
+            .total_cmp(&b.per_month)
+            .then(b.days_at_rank.cmp(&a.days_at_rank))
+            .then(a.name.cmp(&b.name))
+    });
+    velocity
+}
+
+/// Troop-wide completions in each of the pace window's months, oldest
+/// first and ending with this month.
+pub fn completions_by_month(
+    youth: &[Youth],
+    ranks: &HashMap<i64, Vec<RankProgress>>,
+    badges: &HashMap<i64, Vec<MeritBadgeProgress>>,
+    feed: &ActivityFeed,
+    today: NaiveDate,
+) -> Vec<(NaiveDate, usize)> {
+    let this_month = today.with_day(1).unwrap_or(today);
+    let mut months: Vec<(NaiveDate, usize)> = (0..PACE_WINDOW_MONTHS)
+        .rev()
+        .filter_map(|i| this_month.checked_sub_months(Months::new(i)))
+        .map(|m| (m, 0))
+        .collect();
+    for y in youth {
+        let Some(id) = y.user_id else { continue };
+        let ranks = ranks.get(&id).map(Vec::as_slice).unwrap_or_default();
+        let badges = badges.get(&id).map(Vec::as_slice).unwrap_or_default();
+        for date in progress_dates(id, ranks, badges, feed, today) {
+            let month = date.with_day(1).unwrap_or(date);
+            if let Some(slot) = months.iter_mut().find(|(m, _)| *m == month) {
+                slot.1 += 1;
+            }
+        }
+    }
+    months
+}
+
+// ============================================================================
+// Unit Statistics
+// ============================================================================
+
+/// Advancement and attendance figures cover this many days before today
+pub const STATS_WINDOW_DAYS: i64 = 365;
+
+/// Everything the unit-wide aggregates draw from.
+pub struct UnitStatsInput<'a> {
+    pub youth: &'a [Youth],
+    pub adults: &'a [Adult],
+    pub ranks: &'a HashMap<i64, Vec<RankProgress>>,
+    pub badges: &'a HashMap<i64, Vec<MeritBadgeProgress>>,
+    pub attendance: &'a AttendanceHistory,
+    pub events: &'a [Event],
+    pub feed: &'a ActivityFeed,
+}
+
+/// Unit-wide aggregates, computed once per refresh and shared by the Unit
+/// tab, the committee report, and exports instead of each recomputing them
+/// from the raw rosters.
+#[derive(Debug, Clone, Default)]
+pub struct UnitStats {
+    pub training: TrainingStats,
+    pub renewals: RenewalStats,
+    pub patrols: HashMap<String, PatrolBreakdown>,
+    /// Scouts at each rank in display order; ranks nobody holds are left out.
+    pub rank_counts: Vec<(ScoutRank, usize)>,
+    pub ranks_awarded_past_year: usize,
+    pub badges_awarded_past_year: usize,
+    /// Merit badges awarded per month, keyed "YYYY-MM".
+    pub badges_by_month: BTreeMap<String, usize>,
+    /// Each scout's attendance over the past year, by user ID.
+    pub attendance: HashMap<i64, AttendanceStats>,
+    /// Scouts with loaded ranks, slowest advancing first.
+    pub velocity: Vec<ScoutVelocity>,
+    /// Troop-wide completions per month over the past year.
+    pub completions_by_month: Vec<(NaiveDate, usize)>,
+}
+
+impl UnitStats {
+    pub fn compute(input: &UnitStatsInput, today: NaiveDate) -> Self {
+        let since = today - Duration::days(STATS_WINDOW_DAYS);
+        let in_window = |date: Option<NaiveDate>| date.is_some_and(|d| d >= since && d <= today);
+
+        let rank_counts = ScoutRank::all_display_order()
+            .iter()
+            .map(|rank| {
+                let count = input.youth.iter().filter(|y| ScoutRank::parse(y.current_rank.as_deref()) == *rank).count();
+                (*rank, count)
+            })
+            .filter(|&(_, count)| count > 0)
+            .collect();
+
+        let ranks_awarded_past_year = input
+            .ranks
+            .values()
+            .flatten()
+            .filter(|r| in_window(parse_day(r.date_awarded.as_deref())))
+            .count();
+
+        let mut badges_awarded_past_year = 0;
+        let mut badges_by_month = BTreeMap::new();
+        for badge in input.badges.values().flatten().filter(|b| b.is_awarded()) {
+            let Some(date) = parse_day(badge.awarded_date.as_deref().or(badge.date_completed.as_deref())) else {
+                continue;
+            };
+            *badges_by_month.entry(date.format("%Y-%m").to_string()).or_default() += 1;
+            if in_window(Some(date)) {
+                badges_awarded_past_year += 1;
+            }
+        }
+
+        let attendance = input
+            .youth
+            .iter()
+            .filter_map(|y| y.user_id)
+            .map(|id| (id, AttendanceStats::for_scout(id, input.attendance, input.events, since, today)))
+            .collect();
+
+        UnitStats {
+            training: TrainingStats::from_adults(input.adults),
+            renewals: RenewalStats::compute(input.youth, input.adults),
+            patrols: patrol_rank_breakdown(input.youth),
+            rank_counts,
+            ranks_awarded_past_year,
+            badges_awarded_past_year,
+            badges_by_month,
+            attendance,
+            velocity: advancement_velocity(input.youth, input.ranks, input.badges, input.feed, today),
+            completions_by_month: completions_by_month(input.youth, input.ranks, input.badges, input.feed, today),
+        }
+    }
+
+    /// One scout's attendance, or zeros if they weren't on the roster.
+    pub fn attendance_for(&self, user_id: i64) -> AttendanceStats {
+        self.attendance.get(&user_id).copied().unwrap_or_default()
+    }
+
+    /// Percentage of expected meeting attendance across all scouts.
+    pub fn meeting_attendance_percent(&self) -> Option<u32> {
+        let (attended, held) = self
+            .attendance
+            .values()
+            .fold((0, 0), |(a, h), s| (a + s.meetings_attended, h + s.meetings_held));
+        percent(attended, held)
+    }
+
+    /// Percentage of event invitations attended across all scouts.
+    pub fn event_attendance_percent(&self) -> Option<u32> {
+        let (attended, invited) = self
+            .attendance
+            .values()
+            .fold((0, 0), |(a, i), s| (a + s.events_attended, i + s.events_invited));
+        percent(attended, invited)
+    }
+}
+
+/// The date part of an API timestamp ("2026-03-15T00:00:00" or "2026-03-15").
+fn parse_day(date: Option<&str>) -> Option<NaiveDate> {
+    date.and_then(|d| d.get(..10)).and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -189,7 +539,7 @@ mod tests {
         Adult {
             person_guid: None, member_id: None, person_full_name: None,
             first_name: "Jane".to_string(), middle_name: None, last_name: "Doe".to_string(),
-            nick_name: None, position: Some("Scoutmaster".to_string()), position_id: None,
+            nick_name: None, gender: None, position: Some("Scoutmaster".to_string()), position_id: None,
             key3: None,
             position_trained: position_trained.map(|s| s.to_string()),
             ypt_status: None,
@@ -279,4 +629,152 @@ mod tests {
         let hawk = breakdown.get("Hawk").unwrap();
         assert_eq!(hawk.member_count, 1);
     }
+
+    #[test]
+    fn test_rsvp_stats() {
+        let event: Event = serde_json::from_value(serde_json::json!({
+            "name": "Campout",
+            "rsvp": true,
+            "invitedUsers": [
+                { "userId": 1, "firstName": "A", "lastName": "Scout", "rsvpCode": "Y" },
+                { "userId": 2, "firstName": "B", "lastName": "Scout", "rsvpCode": "N" },
+                { "userId": 3, "firstName": "C", "lastName": "Scout", "rsvp": "Maybe" },
+                { "userId": 4, "firstName": "D", "lastName": "Scout" },
+                { "userId": 5, "firstName": "E", "lastName": "Adult", "rsvpCode": "Y", "isAdult": true },
+            ],
+        }))
+        .unwrap();
+        let guests: Vec<EventGuest> = serde_json::from_value(serde_json::json!([
+            { "userId": 10, "firstName": "G", "lastName": "Guest", "rsvpStatus": "Going" },
+            { "userId": 11, "firstName": "H", "lastName": "Guest", "rsvpStatus": "No" },
+        ]))
+        .unwrap();
+
+        let stats = RsvpStats::from_event(&event, &guests);
+        assert_eq!(
+            stats.youth,
+            RsvpTally { going: 1, not_going: 1, maybe: 1, no_response: 1 }
+        );
+        assert_eq!(stats.adults.going, 1);
+        assert_eq!(stats.adults.total(), 1);
+        assert_eq!(stats.guests, 1);
+        assert_eq!(stats.headcount(), 3);
+    }
+
+    #[test]
+    fn test_attendance_stats_for_scout() {
+        use crate::models::attendance::MeetingRecord;
+
+        let day = |d| NaiveDate::from_ymd_opt(2026, 10, d).unwrap();
+        let mut history = AttendanceHistory::default();
+        history.record(MeetingRecord { date: day(1), present: vec![7], roster: vec![7, 8] });
+        history.record(MeetingRecord { date: day(8), present: vec![8], roster: vec![7, 8] });
+        // Before the scout joined
+        history.record(MeetingRecord { date: day(2), present: vec![8], roster: vec![8] });
+
+        let events: Vec<Event> = serde_json::from_value(serde_json::json!([
+            { "id": 1, "name": "Campout", "startDate": "2026-10-03T18:00:00",
+              "invitedUsers": [{ "userId": 7, "firstName": "A", "lastName": "B", "attended": true }] },
+            { "id": 2, "name": "Future", "startDate": "2026-10-30T18:00:00",
+              "invitedUsers": [{ "userId": 7, "firstName": "A", "lastName": "B" }] }
+        ]))
+        .unwrap();
+
+        let stats = AttendanceStats::for_scout(7, &history, &events, day(1), day(14));
+        assert_eq!(stats.meetings_attended, 1);
+        assert_eq!(stats.meetings_held, 2);
+        assert_eq!(stats.meeting_percent(), Some(50));
+        assert_eq!(stats.events_attended, 1);
+        assert_eq!(stats.events_invited, 1, "future events aren't counted");
+    }
+
+    #[test]
+    fn test_unit_stats() {
+        use crate::models::attendance::MeetingRecord;
+
+        let mut youth = vec![
+            make_youth(Some("Eagle"), Some("Star"), None),
+            make_youth(Some("Hawk"), Some("Star"), None),
+            make_youth(Some("Hawk"), Some("Scout"), None),
+        ];
+        youth[0].user_id = Some(1);
+        let badges: HashMap<i64, Vec<MeritBadgeProgress>> = serde_json::from_value(serde_json::json!({
+            "1": [
+                { "id": 1, "name": "Camping", "status": "Awarded", "awardedDate": "2026-09-12" },
+                { "id": 2, "name": "Cooking", "status": "Awarded", "awardedDate": "2026-09-30" },
+                { "id": 3, "name": "Hiking", "status": "Awarded", "awardedDate": "2024-05-01" },
+                { "id": 4, "name": "Swimming", "status": "Started" },
+            ]
+        }))
+        .unwrap();
+        let mut attendance = AttendanceHistory::default();
+        let day = |d| NaiveDate::from_ymd_opt(2026, 10, d).unwrap();
+        attendance.record(MeetingRecord { date: day(1), present: vec![1], roster: vec![1] });
+        attendance.record(MeetingRecord { date: day(8), present: vec![], roster: vec![1] });
+
+        let input = UnitStatsInput {
+            youth: &youth,
+            adults: &[],
+            ranks: &HashMap::new(),
+            badges: &badges,
+            attendance: &attendance,
+            events: &[],
+            feed: &ActivityFeed::default(),
+        };
+        let stats = UnitStats::compute(&input, day(14));
+
+        assert_eq!(stats.rank_counts, [(ScoutRank::Star, 2), (ScoutRank::Scout, 1)]);
+        assert_eq!(stats.patrols["Hawk"].member_count, 2);
+        assert_eq!(stats.badges_awarded_past_year, 2);
+        assert_eq!(stats.badges_by_month.get("2026-09"), Some(&2));
+        assert_eq!(stats.badges_by_month.get("2024-05"), Some(&1));
+        assert_eq!(stats.attendance_for(1).meetings_held, 2);
+        assert_eq!(stats.meeting_attendance_percent(), Some(50));
+        assert_eq!(stats.event_attendance_percent(), None);
+        assert!(stats.velocity.is_empty(), "no ranks loaded");
+        assert_eq!(stats.completions_by_month.len(), 12);
+    }
+
+    #[test]
+    fn test_advancement_velocity() {
+        use crate::models::feed::{FeedCategory, FeedEntry};
+
+        let today = NaiveDate::from_ymd_opt(2026, 10, 15).unwrap();
+        let youth: Vec<Youth> = serde_json::from_value(serde_json::json!([
+            { "userId": 1, "firstName": "Sam", "lastName": "Ortiz", "currentRankName": "Tenderfoot" },
+            { "userId": 2, "firstName": "Ana", "lastName": "Diaz", "currentRankName": "Scout" },
+            { "userId": 3, "firstName": "Lee", "lastName": "Park" },
+        ]))
+        .unwrap();
+        let ranks: HashMap<i64, Vec<RankProgress>> = serde_json::from_value(serde_json::json!({
+            "1": [
+                { "rank_id": 1, "rank_name": "Scout", "date_completed": "2025-11-01", "level": 1 },
+                { "rank_id": 2, "rank_name": "Tenderfoot", "date_completed": "2026-04-18", "level": 2 },
+            ],
+            "2": [{ "rank_id": 1, "rank_name": "Scout", "date_completed": "2026-09-01", "level": 1 }],
+        }))
+        .unwrap();
+        let feed = ActivityFeed {
+            entries: (1..=4)
+                .map(|d| FeedEntry {
+                    date: NaiveDate::from_ymd_opt(2026, 10, d).unwrap(),
+                    category: FeedCategory::Requirement,
+                    user_id: Some(2),
+                    text: "A. Diaz completed a requirement".to_string(),
+                })
+                .collect(),
+        };
+
+        let velocity = advancement_velocity(&youth, &ranks, &HashMap::new(), &feed, today);
+        let names: Vec<&str> = velocity.iter().map(|v| v.name.as_str()).collect();
+        assert_eq!(names, ["Sam Ortiz", "Ana Diaz"], "slowest first; Lee isn't loaded");
+        assert_eq!(velocity[0].rank, ScoutRank::Tenderfoot);
+        assert_eq!(velocity[0].days_at_rank, Some(180));
+        assert!((velocity[1].per_month - 5.0 / 12.0).abs() < 1e-9);
+
+        let months = completions_by_month(&youth, &ranks, &HashMap::new(), &feed, today);
+        assert_eq!(months.first(), Some(&(NaiveDate::from_ymd_opt(2025, 11, 1).unwrap(), 1)));
+        assert_eq!(months.last(), Some(&(NaiveDate::from_ymd_opt(2026, 10, 1).unwrap(), 4)));
+        assert_eq!(months.iter().map(|(_, n)| n).sum::<usize>(), 7);
+    }
 }
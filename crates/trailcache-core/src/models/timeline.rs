@@ -0,0 +1,126 @@
+//! A scout's advancement history as one dated list.
+//!
+//! Merges rank awards, merit badge completions, leadership terms, and other
+//! awards, oldest first, for the scout detail Timeline view.
+
+use chrono::NaiveDate;
+
+use super::advancement::{Award, LeadershipPosition, MeritBadgeProgress, RankProgress};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TimelineKind {
+    Rank,
+    MeritBadge,
+    Leadership,
+    Award,
+}
+
+impl TimelineKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            TimelineKind::Rank => "Rank",
+            TimelineKind::MeritBadge => "Badge",
+            TimelineKind::Leadership => "Position",
+            TimelineKind::Award => "Award",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimelineEntry {
+    pub date: NaiveDate,
+    pub kind: TimelineKind,
+    pub text: String,
+}
+
+fn parse_day(date: Option<&str>) -> Option<NaiveDate> {
+    let date = date?;
+    NaiveDate::parse_from_str(date.get(..10)?, "%Y-%m-%d").ok()
+}
+
+/// Everything dated in a scout's advancement records, oldest first. Ranks
+/// and awards are placed on the day they were awarded, or completed if
+/// they haven't been presented yet; leadership terms get an entry when they
+/// started and another when they ended.
+pub fn scout_timeline(
+    ranks: &[RankProgress],
+    badges: &[MeritBadgeProgress],
+    leadership: &[LeadershipPosition],
+    awards: &[Award],
+) -> Vec<TimelineEntry> {
+    let mut entries = Vec::new();
+    let mut push = |date: Option<NaiveDate>, kind, text: String| {
+        if let Some(date) = date {
+            entries.push(TimelineEntry { date, kind, text });
+        }
+    };
+
+    for rank in ranks {
+        if rank.is_awarded() {
+            push(parse_day(rank.date_awarded.as_deref()), TimelineKind::Rank, format!("{} awarded", rank.rank_name));
+        } else if rank.is_completed() {
+            push(parse_day(rank.date_completed.as_deref()), TimelineKind::Rank, format!("{} earned", rank.rank_name));
+        }
+    }
+    for badge in badges.iter().filter(|b| b.is_completed()) {
+        let date = parse_day(badge.date_completed.as_deref()).or_else(|| parse_day(badge.awarded_date.as_deref()));
+        push(date, TimelineKind::MeritBadge, format!("{} merit badge", badge.name));
+    }
+    for position in leadership {
+        push(parse_day(position.start_date.as_deref()), TimelineKind::Leadership, format!("Began as {}", position.name()));
+        if !position.is_current() {
+            push(parse_day(position.end_date.as_deref()), TimelineKind::Leadership, format!("Finished as {}", position.name()));
+        }
+    }
+    for award in awards.iter().filter(|a| a.is_completed() || a.is_awarded()) {
+        let date = parse_day(award.date_awarded.as_deref())
+            .or_else(|| parse_day(award.date_completed.as_deref()))
+            .or_else(|| parse_day(award.date_earned.as_deref()));
+        push(date, TimelineKind::Award, award.name().to_string());
+    }
+
+    entries.sort_by(|a, b| (a.date, a.kind, &a.text).cmp(&(b.date, b.kind, &b.text)));
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scout_timeline() {
+        let ranks: Vec<RankProgress> = serde_json::from_value(serde_json::json!([
+            { "rank_id": 1, "rank_name": "Scout", "date_completed": "2024-10-01", "date_awarded": "2024-11-12T00:00:00" },
+            { "rank_id": 2, "rank_name": "Tenderfoot", "date_completed": "2025-03-04" },
+            { "rank_id": 3, "rank_name": "Second Class", "percent_completed": 0.4 },
+        ]))
+        .unwrap();
+        let badges: Vec<MeritBadgeProgress> = serde_json::from_value(serde_json::json!([
+            { "id": 10, "name": "Swimming", "status": "Awarded", "dateCompleted": "2025-07-20" },
+            { "id": 11, "name": "Cooking", "status": "Started" },
+        ]))
+        .unwrap();
+        let leadership: Vec<LeadershipPosition> = serde_json::from_value(serde_json::json!([
+            { "position": "Scribe", "startDate": "2025-01-06", "endDate": "2025-06-30" },
+            { "position": "Patrol Leader", "startDate": "2025-07-01" },
+        ]))
+        .unwrap();
+        let awards = vec![Award { name: Some("50-Miler".into()), awarded: Some(true), date_awarded: Some("2025-07-01".into()), ..Default::default() }];
+
+        let timeline = scout_timeline(&ranks, &badges, &leadership, &awards);
+        let texts: Vec<&str> = timeline.iter().map(|e| e.text.as_str()).collect();
+        assert_eq!(
+            texts,
+            [
+                "Scout awarded",
+                "Began as Scribe",
+                "Tenderfoot earned",
+                "Finished as Scribe",
+                "Began as Patrol Leader",
+                "50-Miler",
+                "Swimming merit badge",
+            ]
+        );
+        assert_eq!(timeline[0].date, NaiveDate::from_ymd_opt(2024, 11, 12).unwrap());
+    }
+}
@@ -0,0 +1,88 @@
+//! General to-do list, attached to a scout, an event, or the unit.
+//!
+//! Items reuse the committee's `ActionItem` (text, owner, due date) so they
+//! are typed the same way: "Collect medical form @Smith 2026-06-01".
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use super::minutes::ActionItem;
+
+/// What a to-do is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TodoSubject {
+    Unit,
+    Scout(i64),
+    Event(i64),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TodoItem {
+    pub subject: TodoSubject,
+    #[serde(flatten)]
+    pub item: ActionItem,
+}
+
+/// Persisted to-do list.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TodoList {
+    pub items: Vec<TodoItem>,
+}
+
+impl TodoList {
+    /// Parse and add an item. Returns false if `input` has no text.
+    pub fn add(&mut self, subject: TodoSubject, input: &str) -> bool {
+        match ActionItem::parse(input) {
+            Some(item) => {
+                self.items.push(TodoItem { subject, item });
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Items in display order: open before done, then soonest due (undated last).
+    pub fn sorted(&self, include_done: bool) -> Vec<&TodoItem> {
+        let mut items: Vec<&TodoItem> = self.items.iter().filter(|t| include_done || t.item.is_open()).collect();
+        items.sort_by_key(|t| (!t.item.is_open(), t.item.due.is_none(), t.item.due));
+        items
+    }
+
+    pub fn overdue_count(&self, today: NaiveDate) -> usize {
+        self.items.iter().filter(|t| t.item.is_overdue(today)).count()
+    }
+
+    /// Drop every item about a scout. Returns how many were removed.
+    pub fn remove_scout(&mut self, user_id: i64) -> usize {
+        let before = self.items.len();
+        self.items.retain(|t| t.subject != TodoSubject::Scout(user_id));
+        before - self.items.len()
+    }
+
+    /// Open items about `subject`.
+    pub fn open_for(&self, subject: TodoSubject) -> impl Iterator<Item = &TodoItem> {
+        self.items.iter().filter(move |t| t.subject == subject && t.item.is_open())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sorted_and_overdue() {
+        let mut todos = TodoList::default();
+        assert!(todos.add(TodoSubject::Unit, "Renew charter"));
+        assert!(todos.add(TodoSubject::Scout(7), "Collect medical form @Smith 2026-06-01"));
+        assert!(todos.add(TodoSubject::Event(3), "Reserve trailer 2026-05-15"));
+        assert!(!todos.add(TodoSubject::Unit, "  "));
+        todos.items[2].item.closed_on = NaiveDate::from_ymd_opt(2026, 5, 10);
+
+        let today = NaiveDate::from_ymd_opt(2026, 6, 2).unwrap();
+        assert_eq!(todos.overdue_count(today), 1);
+        let texts: Vec<&str> = todos.sorted(true).iter().map(|t| t.item.text.as_str()).collect();
+        assert_eq!(texts, ["Collect medical form", "Renew charter", "Reserve trailer"]);
+        assert_eq!(todos.sorted(false).len(), 2);
+        assert_eq!(todos.open_for(TodoSubject::Scout(7)).count(), 1);
+    }
+}
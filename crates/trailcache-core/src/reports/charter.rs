@@ -0,0 +1,199 @@
+//! Annual report to the chartered organization.
+//!
+//! Rendered from a report template so units can reword it: the built-in
+//! Markdown template is used unless `charter-report.md` is in the report
+//! templates folder. Finances aren't in Scoutbook, so the template leaves a
+//! section for the treasurer to fill in.
+
+use anyhow::Result;
+use chrono::NaiveDate;
+use serde_json::{json, Value};
+
+use super::template::render_template;
+use crate::models::{Adult, HistorianLog, UnitStats, Youth};
+
+/// A unit's own charter report template, looked up in the report templates folder
+pub const CHARTER_TEMPLATE_FILE: &str = "charter-report.md";
+
+/// The built-in charter report template.
+pub const CHARTER_TEMPLATE: &str = r#"# {{ unit }} Annual Report
+
+To {{ charter_org | default("our chartered organization") }}, for {{ year }}. Prepared {{ generated }}.
+
+## Membership
+
+- Scouts: {{ membership.scouts }}, in {{ membership.patrols }} patrols
+- Adult leaders: {{ membership.adults }}, {{ membership.trained }} of them trained for their position
+- Youth Protection training current: {{ membership.ypt_current }} of {{ membership.adults }}
+{% for rank in membership.ranks %}
+- {{ rank.name }}: {{ rank.count }}
+{% endfor %}
+
+## Advancement
+
+- Ranks earned in the past year: {{ advancement.ranks }}
+- Merit badges earned in the past year: {{ advancement.badges }}
+
+## Leadership
+
+{% for leader in leaders %}
+- {{ leader.position }}: {{ leader.name }}{% if not leader.trained %} (position training needed){% endif %}
+
+{% endfor %}
+
+## Program Highlights
+
+{% for item in highlights %}
+- {{ item.date }}, {{ item.event }}{% if item.summary %}: {{ item.summary }}{% endif %}
+
+{% endfor %}
+{% if not highlights %}
+No events were recorded in the historian's log for {{ year }}.
+{% endif %}
+
+## Finances
+
+- Opening balance:
+- Income (dues, fundraising, donations):
+- Expenses (camping, advancement, equipment):
+- Closing balance:
+
+_To be completed by the treasurer._
+"#;
+
+/// Positions listed first, in this order; anyone else follows by name.
+const LEADER_ORDER: [&str; 6] = [
+    "chartered organization rep",
+    "committee chair",
+    "scoutmaster",
+    "assistant scoutmaster",
+    "committee member",
+    "merit badge counselor",
+];
+
+/// Everything the charter report draws on.
+pub struct CharterInput<'a> {
+    pub unit_name: &'a str,
+    pub charter_org: Option<&'a str>,
+    pub youth: &'a [Youth],
+    pub adults: &'a [Adult],
+    pub stats: &'a UnitStats,
+    pub historian: &'a HistorianLog,
+    pub year: i32,
+}
+
+fn leader_order(adult: &Adult) -> usize {
+    let position = adult.role().to_lowercase();
+    LEADER_ORDER.iter().position(|p| position.starts_with(p)).unwrap_or(LEADER_ORDER.len())
+}
+
+/// The values the charter template can use: `unit`, `charter_org`, `year`,
+/// `generated`, `membership`, `advancement`, `leaders`, and `highlights`.
+pub fn charter_context(input: &CharterInput, today: NaiveDate) -> Value {
+    let mut leaders: Vec<&Adult> = input.adults.iter().collect();
+    leaders.sort_by_key(|a| (leader_order(a), a.last_name.clone(), a.first_name.clone()));
+    let stats = input.stats;
+
+    json!({
+        "unit": input.unit_name,
+        "charter_org": input.charter_org,
+        "year": input.year,
+        "generated": today.format("%B %-d, %Y").to_string(),
+        "membership": {
+            "scouts": input.youth.len(),
+            "patrols": stats.patrols.len(),
+            "adults": input.adults.len(),
+            "trained": stats.training.position_trained,
+            "ypt_current": stats.training.ypt_current + stats.training.ypt_expiring,
+            "ranks": stats
+                .rank_counts
+                .iter()
+                .map(|(rank, count)| json!({ "name": rank.display_name(), "count": count }))
+                .collect::<Vec<_>>(),
+        },
+        "advancement": {
+            "ranks": stats.ranks_awarded_past_year,
+            "badges": stats.badges_awarded_past_year,
+        },
+        "leaders": leaders
+            .iter()
+            .map(|a| json!({
+                "name": a.full_name(),
+                "position": a.role(),
+                "trained": a.is_position_trained() != Some(false),
+            }))
+            .collect::<Vec<_>>(),
+        "highlights": input
+            .historian
+            .year(input.year)
+            .map(|e| json!({
+                "date": e.date.format("%b %-d").to_string(),
+                "event": e.event_name,
+                "summary": e.summary,
+            }))
+            .collect::<Vec<_>>(),
+    })
+}
+
+/// Render the charter report, from the unit's own template if given.
+pub fn charter_report(input: &CharterInput, today: NaiveDate, template: Option<&str>) -> Result<String> {
+    render_template(template.unwrap_or(CHARTER_TEMPLATE), &charter_context(input, today))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{HistoryEntry, ScoutRank};
+
+    #[test]
+    fn test_charter_report() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 15).unwrap();
+        let youth: Vec<Youth> = serde_json::from_value(serde_json::json!([
+            { "userId": 1, "firstName": "Sam", "lastName": "Ortiz" },
+            { "userId": 2, "firstName": "Ana", "lastName": "Baker" }
+        ]))
+        .unwrap();
+        let adults: Vec<Adult> = serde_json::from_value(serde_json::json!([
+            { "firstName": "Jo", "lastName": "Kim", "position": "Committee Member", "positionTrained": "Trained" },
+            { "firstName": "Pat", "lastName": "Lee", "position": "Scoutmaster", "positionTrained": "Not Trained" }
+        ]))
+        .unwrap();
+        let stats = UnitStats {
+            rank_counts: vec![(ScoutRank::Scout, 1), (ScoutRank::Star, 1)],
+            badges_awarded_past_year: 14,
+            ..Default::default()
+        };
+        let mut historian = HistorianLog::default();
+        historian.entries.push(HistoryEntry {
+            event_id: 7,
+            event_name: "Summer Camp".to_string(),
+            date: NaiveDate::from_ymd_opt(2026, 7, 12).unwrap(),
+            summary: "Eleven scouts at Camp Yawgoog".to_string(),
+            highlights: Vec::new(),
+            photo_folder: None,
+        });
+        let input = CharterInput {
+            unit_name: "Troop 12",
+            charter_org: Some("St. Mark's Church"),
+            youth: &youth,
+            adults: &adults,
+            stats: &stats,
+            historian: &historian,
+            year: 2026,
+        };
+
+        let report = charter_report(&input, today, None).unwrap();
+        assert!(report.starts_with("# Troop 12 Annual Report\n\nTo St. Mark's Church, for 2026."));
+        assert!(report.contains("- Scouts: 2, in 0 patrols\n"));
+        assert!(report.contains("- Star: 1\n"));
+        assert!(report.contains("- Merit badges earned in the past year: 14\n"));
+        let scoutmaster = report.find("- Scoutmaster: Pat Lee (position training needed)\n").unwrap();
+        assert!(scoutmaster < report.find("- Committee Member: Jo Kim\n").unwrap());
+        assert!(report.contains("- Jul 12, Summer Camp: Eleven scouts at Camp Yawgoog\n"));
+        assert!(!report.contains("No events were recorded"));
+        assert!(report.contains("## Finances"));
+
+        let custom = charter_report(&input, today, Some("{{ unit }}: {{ membership.scouts }} scouts")).unwrap();
+        assert_eq!(custom, "Troop 12: 2 scouts");
+    }
+}
@@ -0,0 +1,104 @@
+//! Merit badge clinic class rosters and completion sign-off sheets.
+//!
+//! Rosters go to counselors on the day: one page per class with the scouts
+//! registered. Afterward, each scout who finished a class gets a sign-off
+//! page listing the badges to record, in the same page-per-scout layout as
+//! the rank sign-off sheets.
+
+use super::document::{Section, Table};
+use crate::models::{Clinic, ClinicClass, Youth};
+
+/// Space left for a handwritten date or signature
+const SIGNATURE_BLANK: &str = "______________";
+
+fn scout(youth: &[Youth], user_id: i64) -> Option<&Youth> {
+    youth.iter().find(|y| y.user_id == Some(user_id))
+}
+
+fn counselor(class: &ClinicClass) -> String {
+    if class.counselor.is_empty() { "Not assigned".to_string() } else { class.counselor.clone() }
+}
+
+/// A section per class with its counselor and registered scouts.
+pub fn clinic_roster_sections(clinic: &Clinic, youth: &[Youth]) -> Vec<Section> {
+    clinic
+        .classes
+        .iter()
+        .map(|class| {
+            let mut section = Section::new(class.badge.clone());
+            section.bullet(format!("Counselor: {}", counselor(class)));
+            section.bullet(format!("{} scouts registered", class.scouts.len()));
+            let mut table = Table::new(["Scout", "Patrol", "Rank", "Completed"]);
+            for &user_id in &class.scouts {
+                let Some(y) = scout(youth, user_id) else { continue };
+                let done = if class.is_completed(user_id) { "Yes" } else { "" };
+                table.row([y.full_name(), y.patrol(), y.rank(), done.to_string()]);
+            }
+            if !table.is_empty() {
+                section.table(table);
+            }
+            section
+        })
+        .collect()
+}
+
+/// A section per scout who finished a class, each a page when printed as
+/// HTML, listing the badges for the counselor to sign off.
+pub fn clinic_signoff_sections(clinic: &Clinic, youth: &[Youth]) -> Vec<Section> {
+    let date = clinic.date.map(|d| d.format("%Y-%m-%d").to_string()).unwrap_or_else(|| SIGNATURE_BLANK.to_string());
+    let mut completions: Vec<(&Youth, Vec<&ClinicClass>)> = clinic
+        .completions()
+        .into_iter()
+        .filter_map(|(user_id, classes)| scout(youth, user_id).map(|y| (y, classes)))
+        .collect();
+    completions.sort_by_key(|(y, _)| (y.patrol(), y.last_name.to_lowercase(), y.first_name.to_lowercase()));
+
+    completions
+        .into_iter()
+        .map(|(y, classes)| {
+            let mut section = Section::new(format!("{} - {}", y.full_name(), clinic.name));
+            section.bullet(format!("Patrol: {}", y.patrol()));
+            let mut table = Table::new(["Merit Badge", "Counselor", "Date", "Counselor Signature"]);
+            for class in classes {
+                table.row([class.badge.clone(), counselor(class), date.clone(), SIGNATURE_BLANK.to_string()]);
+            }
+            section.table(table);
+            section
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reports::{to_text, TextLayout};
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_clinic_sheets() {
+        let youth: Vec<Youth> = serde_json::from_value(serde_json::json!([
+            { "userId": 7, "firstName": "Alex", "lastName": "Reed" },
+            { "userId": 8, "firstName": "Sam", "lastName": "Ortiz" }
+        ]))
+        .unwrap();
+        let mut clinic = Clinic { date: NaiveDate::from_ymd_opt(2026, 11, 7), ..Default::default() };
+        clinic.add_class("First Aid");
+        clinic.add_class("Chess");
+        clinic.classes[0].counselor = "Pat Lee".to_string();
+        clinic.toggle_registration(0, 7);
+        clinic.toggle_registration(0, 8);
+        clinic.toggle_registration(1, 8);
+        clinic.toggle_completed(0, 7);
+
+        let rosters = clinic_roster_sections(&clinic, &youth);
+        assert_eq!(rosters.len(), 2);
+        let text = to_text("Rosters", &rosters, TextLayout::default());
+        assert!(text.contains("Counselor: Pat Lee"));
+        assert!(text.contains("Counselor: Not assigned"));
+
+        let sheets = clinic_signoff_sections(&clinic, &youth);
+        assert_eq!(sheets.len(), 1, "only scouts with completions");
+        assert_eq!(sheets[0].title, "Alex Reed - Merit Badge Clinic");
+        assert!(to_text("Sign-off", &sheets, TextLayout::default()).contains("2026-11-07"));
+    }
+}
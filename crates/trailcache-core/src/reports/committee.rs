@@ -0,0 +1,233 @@
+//! Unit dashboard summary for monthly committee meetings.
+//!
+//! One slide each for membership, advancement, training, and renewals, with
+//! progress against the goals set in the config. Rendered as Markdown with
+//! `---` slide breaks (Marp, reveal.js, Deckset), as a printable HTML page, or
+//! as paginated plain text.
+
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use super::document::{to_html, to_markdown, to_text, Section, Table, TextLayout};
+use crate::models::{Adult, UnitStats, Youth};
+
+/// Committee targets shown alongside the current numbers. All optional.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UnitGoals {
+    /// Registered scouts
+    pub membership: Option<usize>,
+    /// Ranks awarded in the past 12 months
+    pub ranks_awarded: Option<usize>,
+    /// Merit badges awarded in the past 12 months
+    pub merit_badges_awarded: Option<usize>,
+    /// Percent of adults position trained
+    pub trained_percent: Option<u32>,
+}
+
+impl UnitGoals {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Output format for the committee summary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlideFormat {
+    Markdown,
+    Html,
+    Text,
+}
+
+impl SlideFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            SlideFormat::Markdown => "md",
+            SlideFormat::Html => "html",
+            SlideFormat::Text => "txt",
+        }
+    }
+}
+
+/// Everything the summary draws from.
+pub struct CommitteeInput<'a> {
+    pub unit_name: &'a str,
+    pub youth: &'a [Youth],
+    pub adults: &'a [Adult],
+    /// Aggregates computed at the last refresh
+    pub stats: &'a UnitStats,
+    pub goals: &'a UnitGoals,
+}
+
+/// Render the committee summary, dated `today`.
+pub fn committee_summary(input: &CommitteeInput, today: NaiveDate, format: SlideFormat) -> String {
+    let title = format!("{} Committee Report - {}", input.unit_name, today.format("%B %Y"));
+    let sections = [
+        membership_slide(input),
+        advancement_slide(input),
+        training_slide(input),
+        renewals_slide(input),
+    ];
+    match format {
+        SlideFormat::Markdown => to_markdown(&title, &sections, Some("---")),
+        SlideFormat::Html => to_html(&title, &sections),
+        SlideFormat::Text => to_text(&title, &sections, TextLayout::default()),
+    }
+}
+
+/// "25 (goal 30, 83%)", or just the number without a goal.
+fn against_goal(actual: usize, goal: Option<usize>) -> String {
+    match goal {
+        Some(goal) if goal > 0 => {
+            let percent = (actual as f64 / goal as f64 * 100.0).round() as u32;
+            format!("{} (goal {}, {}%)", actual, goal, percent)
+        }
+        _ => actual.to_string(),
+    }
+}
+
+fn membership_slide(input: &CommitteeInput) -> Section {
+    let mut slide = Section::new("Membership");
+    slide.bullet(format!("Scouts: {}", against_goal(input.youth.len(), input.goals.membership)));
+    slide.bullet(format!("Adults: {}", input.adults.len()));
+
+    let mut patrols: HashMap<String, usize> = HashMap::new();
+    for youth in input.youth {
+        *patrols.entry(youth.patrol()).or_default() += 1;
+    }
+    let mut patrols: Vec<(String, usize)> = patrols.into_iter().collect();
+    patrols.sort();
+    let mut table = Table::new(["Patrol", "Scouts"]);
+    for (patrol, count) in patrols {
+        table.row([patrol, count.to_string()]);
+    }
+    slide.table(table);
+    slide
+}
+
+fn advancement_slide(input: &CommitteeInput) -> Section {
+    let mut slide = Section::new("Advancement");
+    let stats = input.stats;
+    slide.bullet(format!(
+        "Ranks awarded (12 months): {}",
+        against_goal(stats.ranks_awarded_past_year, input.goals.ranks_awarded)
+    ));
+    slide.bullet(format!(
+        "Merit badges awarded (12 months): {}",
+        against_goal(stats.badges_awarded_past_year, input.goals.merit_badges_awarded)
+    ));
+
+    let mut table = Table::new(["Rank", "Scouts"]);
+    for (rank, count) in &stats.rank_counts {
+        table.row([rank.display_name().to_string(), count.to_string()]);
+    }
+    slide.table(table);
+    slide
+}
+
+fn training_slide(input: &CommitteeInput) -> Section {
+    let stats = &input.stats.training;
+    let mut slide = Section::new("Training");
+
+    let rated = stats.position_trained + stats.position_not_trained;
+    let percent = if rated > 0 { (stats.position_trained as f64 / rated as f64 * 100.0).round() as u32 } else { 0 };
+    let goal = input.goals.trained_percent.map(|g| format!(" (goal {}%)", g)).unwrap_or_default();
+    slide.bullet(format!("Position trained: {} of {} ({}%){}", stats.position_trained, rated, percent, goal));
+    slide.bullet(format!(
+        "Youth Protection: {} current, {} expiring, {} expired",
+        stats.ypt_current, stats.ypt_expiring, stats.ypt_expired
+    ));
+
+    let mut table = Table::new(["Adult", "Needs"]);
+    for name in &stats.position_not_trained_list {
+        table.row([name.clone(), "Position training".to_string()]);
+    }
+    for (name, status) in &stats.ypt_issues {
+        table.row([name.clone(), format!("YPT {}", status.to_lowercase())]);
+    }
+    slide.table(table);
+    slide
+}
+
+fn renewals_slide(input: &CommitteeInput) -> Section {
+    let stats = &input.stats.renewals;
+    let mut slide = Section::new("Renewals");
+    slide.bullet(format!(
+        "Scouts: {} current, {} expiring, {} expired",
+        stats.scouts_current, stats.scouts_expiring, stats.scouts_expired
+    ));
+    slide.bullet(format!(
+        "Adults: {} current, {} expiring, {} expired",
+        stats.adults_current, stats.adults_expiring, stats.adults_expired
+    ));
+
+    let mut table = Table::new(["Member", "Registration"]);
+    for (name, status) in stats.scout_issues.iter().chain(&stats.adult_issues) {
+        table.row([name.clone(), status.clone()]);
+    }
+    slide.table(table);
+    slide
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ActivityFeed, AttendanceHistory, RankProgress, UnitStatsInput};
+
+    fn youth(id: i64, patrol: &str, rank: &str) -> Youth {
+        serde_json::from_value(serde_json::json!({
+            "userId": id, "firstName": "S", "lastName": format!("Scout{}", id),
+            "subUnitName": patrol, "currentRankName": rank
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_committee_summary_slides() {
+        let youth = vec![youth(1, "Eagles", "Star"), youth(2, "Eagles", "Tenderfoot"), youth(3, "Hawks", "Star")];
+        let mut ranks = HashMap::new();
+        ranks.insert(
+            1,
+            vec![RankProgress {
+                rank_id: 5,
+                version_id: None,
+                rank_name: "Star".to_string(),
+                date_completed: Some("2026-03-01".to_string()),
+                date_awarded: Some("2026-03-15".to_string()),
+                requirements_completed: None,
+                requirements_total: None,
+                percent_completed: Some(1.0),
+                level: Some(5),
+            }],
+        );
+        let goals = UnitGoals { membership: Some(4), ranks_awarded: Some(2), ..Default::default() };
+        let today = NaiveDate::from_ymd_opt(2026, 10, 1).unwrap();
+        let stats_input = UnitStatsInput {
+            youth: &youth,
+            adults: &[],
+            ranks: &ranks,
+            badges: &HashMap::new(),
+            attendance: &AttendanceHistory::default(),
+            events: &[],
+            feed: &ActivityFeed::default(),
+        };
+        let stats = UnitStats::compute(&stats_input, today);
+        let input = CommitteeInput { unit_name: "Troop 42", youth: &youth, adults: &[], stats: &stats, goals: &goals };
+
+        let md = committee_summary(&input, today, SlideFormat::Markdown);
+        assert!(md.starts_with("# Troop 42 Committee Report - October 2026"));
+        assert_eq!(md.matches("\n---\n").count(), 4, "one break per slide");
+        assert!(md.contains("Scouts: 3 (goal 4, 75%)"));
+        assert!(md.contains("Ranks awarded (12 months): 1 (goal 2, 50%)"));
+        assert!(md.contains("| Eagles | 2 |"));
+        assert!(md.contains("| Star | 2 |"));
+
+        let html = committee_summary(&input, today, SlideFormat::Html);
+        assert_eq!(html.matches("<section>").count(), 4);
+
+        let text = committee_summary(&input, today, SlideFormat::Text);
+        assert!(text.contains("MEMBERSHIP\n\n  * Scouts: 3 (goal 4, 75%)"));
+    }
+}
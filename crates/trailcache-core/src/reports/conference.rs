@@ -0,0 +1,336 @@
+//! Scoutmaster conference prep sheet.
+//!
+//! Renders a one-page Markdown summary of a scout's current rank progress,
+//! recent merit badges, leadership, and event attendance, with space for
+//! the Scoutmaster's notes.
+
+use std::fmt::Write;
+
+use chrono::NaiveDate;
+
+use crate::models::{sort_requirements, AttendanceHistory, AttendanceStats, Event, LeadershipPosition, MeritBadgeProgress, RankProgress, RankRequirement, RequirementTree, Youth};
+use crate::models::advancement::format_date;
+
+/// Badges completed within this many days are listed as recent
+const RECENT_BADGE_DAYS: i64 = 365;
+
+/// Attendance covers events within this many days before the sheet date
+const ATTENDANCE_WINDOW_DAYS: i64 = 365;
+
+/// Maximum attended events listed by name
+const MAX_LISTED_EVENTS: usize = 5;
+
+/// Blank lines left for handwritten notes
+const NOTE_LINES: usize = 6;
+
+/// Everything the sheet draws from, already loaded for one scout.
+pub struct ConferenceInput<'a> {
+    pub youth: &'a Youth,
+    pub ranks: &'a [RankProgress],
+    /// Requirements for the rank being worked on, if cached
+    pub next_rank_requirements: &'a [RankRequirement],
+    pub badges: &'a [MeritBadgeProgress],
+    pub leadership: &'a [LeadershipPosition],
+    pub events: &'a [Event],
+    /// Locally recorded troop meeting attendance
+    pub meetings: &'a AttendanceHistory,
+    /// Notes to pre-fill; blank lines are always added for handwriting
+    pub notes: Option<&'a str>,
+}
+
+/// Render the conference sheet as Markdown, dated `today`.
+pub fn conference_sheet(input: &ConferenceInput, today: NaiveDate) -> String {
+    let mut out = String::new();
+    let youth = input.youth;
+
+    let _ = writeln!(out, "# Scoutmaster Conference: {}", youth.short_name());
+    let _ = writeln!(out);
+    let _ = writeln!(
+        out,
+        "Patrol: {} | Grade: {} | Age: {} | Date: {}",
+        youth.patrol(),
+        youth.grade_str(),
+        youth.age_str(),
+        today.format("%b %d, %Y")
+    );
+    let _ = writeln!(out);
+
+    write_rank_section(&mut out, input);
+    write_badge_section(&mut out, input.badges, today);
+    write_leadership_section(&mut out, input.leadership);
+    write_attendance_section(&mut out, youth, input.events, input.meetings, today);
+
+    let _ = writeln!(out, "## Notes");
+    let _ = writeln!(out);
+    if let Some(notes) = input.notes.filter(|n| !n.trim().is_empty()) {
+        let _ = writeln!(out, "{}", notes.trim());
+        let _ = writeln!(out);
+    }
+    for _ in 0..NOTE_LINES {
+        let _ = writeln!(out, "_______________________________________________");
+        let _ = writeln!(out);
+    }
+
+    out
+}
+
+/// Highest completed or awarded rank.
+pub fn current_rank(ranks: &[RankProgress]) -> Option<&RankProgress> {
+    ranks
+        .iter()
+        .filter(|r| r.is_completed() || r.is_awarded())
+        .max_by_key(|r| r.sort_order())
+}
+
+/// The rank the scout is working toward: the lowest unfinished rank above the current one.
+pub fn next_rank(ranks: &[RankProgress]) -> Option<&RankProgress> {
+    let current = current_rank(ranks);
+    ranks
+        .iter()
+        .filter(|r| !(r.is_completed() || r.is_awarded()))
+        .filter(|r| current.is_none_or(|c| r.sort_order() > c.sort_order()))
+        .min_by_key(|r| r.sort_order())
+}
+
+fn write_rank_section(out: &mut String, input: &ConferenceInput) {
+    let _ = writeln!(out, "## Rank Progress");
+    let _ = writeln!(out);
+
+    let current = current_rank(input.ranks);
+    let next = next_rank(input.ranks);
+
+    match current {
+        Some(rank) => {
+            let date = rank.date_awarded.as_deref().or(rank.date_completed.as_deref());
+            let _ = writeln!(out, "- Current rank: **{}** ({})", rank.rank_name, format_date(date));
+        }
+        None => {
+            let _ = writeln!(out, "- Current rank: none yet");
+        }
+    }
+
+    let Some(next) = next else {
+        let _ = writeln!(out);
+        return;
+    };
+
+    let percent = next.progress_percent().map(|p| format!(" - {}% complete", p)).unwrap_or_default();
+    let _ = writeln!(out, "- Working on: **{}**{}", next.rank_name, percent);
+
+    let mut requirements = input.next_rank_requirements.to_vec();
+    sort_requirements(&mut requirements);
+    let tree = RequirementTree::build(&requirements);
+    let completed = |i: usize| requirements[i].is_completed();
+    let remaining: Vec<usize> = (0..requirements.len()).filter(|&i| !tree.is_done(i, &completed)).collect();
+    if !remaining.is_empty() {
+        let _ = writeln!(out);
+        let _ = writeln!(out, "Remaining {} requirements:", next.rank_name);
+        let _ = writeln!(out);
+        for i in remaining {
+            let _ = writeln!(
+                out,
+                "{}- [ ] {} {}",
+                "  ".repeat(tree.depth(i)),
+                tree.node(i).number,
+                crate::utils::strip_html(&requirements[i].text()).trim()
+            );
+        }
+    }
+    let _ = writeln!(out);
+}
+
+fn write_badge_section(out: &mut String, badges: &[MeritBadgeProgress], today: NaiveDate) {
+    let _ = writeln!(out, "## Merit Badges");
+    let _ = writeln!(out);
+
+    let eagle_earned = badges.iter().filter(|b| b.is_completed() && b.is_eagle_required.unwrap_or(false)).count();
+    let earned = badges.iter().filter(|b| b.is_completed()).count();
+    let _ = writeln!(out, "- Earned: {} ({} Eagle-required)", earned, eagle_earned);
+
+    let mut recent: Vec<(&MeritBadgeProgress, NaiveDate)> = badges
+        .iter()
+        .filter(|b| b.is_completed())
+        .filter_map(|b| {
+            let date = b.awarded_date.as_deref().or(b.date_completed.as_deref())?;
+            Some((b, parse_date(date)?))
+        })
+        .filter(|(_, date)| (today - *date).num_days() <= RECENT_BADGE_DAYS)
+        .collect();
+    recent.sort_by_key(|b| std::cmp::Reverse(b.1));
+    if !recent.is_empty() {
+        let names: Vec<String> = recent
+            .iter()
+            .map(|(b, date)| format!("{} ({})", b.name, date.format("%b %Y")))
+            .collect();
+        let _ = writeln!(out, "- Recently earned: {}", names.join(", "));
+    }
+
+    let in_progress: Vec<String> = badges
+        .iter()
+        .filter(|b| !b.is_completed())
+        .map(|b| match b.progress_percent() {
+            Some(p) => format!("{} ({}%)", b.name, p),
+            None => b.name.clone(),
+        })
+        .collect();
+    if !in_progress.is_empty() {
+        let _ = writeln!(out, "- In progress: {}", in_progress.join(", "));
+    }
+    let _ = writeln!(out);
+}
+
+fn write_leadership_section(out: &mut String, leadership: &[LeadershipPosition]) {
+    let _ = writeln!(out, "## Leadership");
+    let _ = writeln!(out);
+    if leadership.is_empty() {
+        let _ = writeln!(out, "- No positions recorded");
+    }
+    for position in leadership {
+        let marker = if position.is_current() { " (current)" } else { "" };
+        let _ = writeln!(
+            out,
+            "- {}{}: {}, {}",
+            position.name(),
+            marker,
+            position.date_range(),
+            position.days_display()
+        );
+    }
+    let _ = writeln!(out);
+}
+
+fn write_attendance_section(
+    out: &mut String,
+    youth: &Youth,
+    events: &[Event],
+    meetings: &AttendanceHistory,
+    today: NaiveDate,
+) {
+    let _ = writeln!(out, "## Attendance (past 12 months)");
+    let _ = writeln!(out);
+
+    let Some(user_id) = youth.user_id else {
+        let _ = writeln!(out, "- No attendance data");
+        let _ = writeln!(out);
+        return;
+    };
+
+    let mut invited: Vec<(&Event, NaiveDate, bool)> = events
+        .iter()
+        .filter_map(|e| {
+            let date = parse_date(e.start_date.as_deref()?)?;
+            let days_ago = (today - date).num_days();
+            if !(0..=ATTENDANCE_WINDOW_DAYS).contains(&days_ago) {
+                return None;
+            }
+            let user = e.invited_users.iter().find(|u| u.user_id == user_id)?;
+            Some((e, date, user.attended))
+        })
+        .collect();
+    invited.sort_by_key(|e| std::cmp::Reverse(e.1));
+
+    let since = today - chrono::Duration::days(ATTENDANCE_WINDOW_DAYS);
+    let stats = AttendanceStats::for_scout(user_id, meetings, &[], since, today);
+    if let Some(percent) = stats.meeting_percent() {
+        let _ = writeln!(
+            out,
+            "- Attended {} of {} troop meetings ({}%)",
+            stats.meetings_attended, stats.meetings_held, percent
+        );
+    }
+
+    let attended: Vec<&(&Event, NaiveDate, bool)> = invited.iter().filter(|(_, _, a)| *a).collect();
+    let _ = writeln!(out, "- Attended {} of {} invited events", attended.len(), invited.len());
+    if !attended.is_empty() {
+        let names: Vec<String> = attended
+            .iter()
+            .take(MAX_LISTED_EVENTS)
+            .map(|(e, date, _)| format!("{} ({})", e.name, date.format("%b %d")))
+            .collect();
+        let _ = writeln!(out, "- Recent: {}", names.join(", "));
+    }
+    let _ = writeln!(out);
+}
+
+fn parse_date(s: &str) -> Option<NaiveDate> {
+    s.get(..10).and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::MeetingRecord;
+
+    fn youth() -> Youth {
+        serde_json::from_value(serde_json::json!({
+            "firstName": "Sam",
+            "lastName": "Scout",
+            "userId": 1,
+            "subUnitName": "Eagles",
+        }))
+        .unwrap()
+    }
+
+    fn rank(name: &str, level: i32, awarded: Option<&str>) -> RankProgress {
+        RankProgress {
+            rank_id: level as i64,
+            version_id: None,
+            rank_name: name.to_string(),
+            date_completed: awarded.map(str::to_string),
+            date_awarded: awarded.map(str::to_string),
+            requirements_completed: None,
+            requirements_total: None,
+            percent_completed: if awarded.is_some() { Some(1.0) } else { Some(0.5) },
+            level: Some(level),
+        }
+    }
+
+    #[test]
+    fn test_conference_sheet_sections() {
+        let youth = youth();
+        let ranks = vec![
+            rank("Scout", 1, Some("2025-01-10")),
+            rank("Tenderfoot", 2, Some("2025-06-01")),
+            rank("Second Class", 3, None),
+        ];
+        let events: Vec<Event> = serde_json::from_value(serde_json::json!([
+            {
+                "id": 1, "name": "Spring Campout", "startDate": "2026-04-10T18:00:00",
+                "invitedUsers": [{ "userId": 1, "firstName": "Sam", "lastName": "Scout", "attended": true }]
+            },
+            {
+                "id": 2, "name": "Service Day", "startDate": "2026-05-02T09:00:00",
+                "invitedUsers": [{ "userId": 1, "firstName": "Sam", "lastName": "Scout" }]
+            }
+        ]))
+        .unwrap();
+
+        let mut meetings = AttendanceHistory::default();
+        meetings.record(MeetingRecord {
+            date: NaiveDate::from_ymd_opt(2026, 5, 19).unwrap(),
+            present: vec![1],
+            roster: vec![1],
+        });
+
+        let input = ConferenceInput {
+            youth: &youth,
+            ranks: &ranks,
+            next_rank_requirements: &[],
+            badges: &[],
+            leadership: &[],
+            events: &events,
+            meetings: &meetings,
+            notes: Some("Ask about patrol cooking plan"),
+        };
+        let sheet = conference_sheet(&input, NaiveDate::from_ymd_opt(2026, 6, 1).unwrap());
+
+        assert!(sheet.starts_with("# Scoutmaster Conference: Sam Scout"));
+        assert!(sheet.contains("Current rank: **Tenderfoot**"));
+        assert!(sheet.contains("Working on: **Second Class** - 50% complete"));
+        assert!(sheet.contains("Attended 1 of 2 invited events"));
+        assert!(sheet.contains("Attended 1 of 1 troop meetings (100%)"));
+        assert!(sheet.contains("Spring Campout (Apr 10)"));
+        assert!(sheet.contains("Ask about patrol cooking plan"));
+        assert!(sheet.contains("No positions recorded"));
+    }
+}
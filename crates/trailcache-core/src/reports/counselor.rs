@@ -0,0 +1,167 @@
+//! Merit badge progress grouped by assigned counselor.
+//!
+//! Each counselor gets a list of the scouts working with them and the
+//! requirements still open, so the report can be sent to them directly.
+//! Badges without an assigned counselor are grouped last.
+
+use std::collections::HashMap;
+
+use super::document::{Section, Table};
+use super::my_scout::percent_label;
+use crate::models::{sort_requirements, MeritBadgeProgress, MeritBadgeRequirement, RequirementTree, Youth};
+use crate::utils::PhoneFormat;
+
+/// Group name for badges with no counselor assigned in Scoutbook
+pub const NO_COUNSELOR: &str = "No counselor assigned";
+
+/// Everything the report draws from. Requirements are keyed by
+/// (user_id, badge_id) and only need to cover what's been cached.
+pub struct CounselorInput<'a> {
+    pub youth: &'a [Youth],
+    pub badges: &'a HashMap<i64, Vec<MeritBadgeProgress>>,
+    pub requirements: &'a HashMap<(i64, i64), Vec<MeritBadgeRequirement>>,
+    pub phone_format: PhoneFormat,
+}
+
+/// One scout's in-progress badge with this counselor.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CounselorScout {
+    pub scout: String,
+    pub badge: String,
+    pub percent: Option<i32>,
+    /// Numbers of requirements not yet completed; None when requirements aren't cached
+    pub remaining: Option<Vec<String>>,
+}
+
+/// A counselor and the scouts working with them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CounselorGroup {
+    pub name: String,
+    pub phone: Option<String>,
+    pub email: Option<String>,
+    pub scouts: Vec<CounselorScout>,
+}
+
+impl CounselorGroup {
+    pub fn is_unassigned(&self) -> bool {
+        self.name == NO_COUNSELOR
+    }
+}
+
+/// Group every in-progress badge on the roster by assigned counselor,
+/// counselors alphabetically with the unassigned group last.
+pub fn counselor_groups(input: &CounselorInput) -> Vec<CounselorGroup> {
+    let mut groups: HashMap<String, CounselorGroup> = HashMap::new();
+    for youth in input.youth {
+        let Some(user_id) = youth.user_id else { continue };
+        let Some(badges) = input.badges.get(&user_id) else { continue };
+        for badge in badges.iter().filter(|b| !b.is_completed()) {
+            let counselor = badge.assigned_counselor.as_ref().filter(|c| !c.full_name().is_empty());
+            let name = counselor.map(|c| c.full_name()).unwrap_or_else(|| NO_COUNSELOR.to_string());
+            let group = groups.entry(name.clone()).or_insert_with(|| CounselorGroup {
+                name,
+                phone: counselor.and_then(|c| c.phone(input.phone_format)),
+                email: counselor.and_then(|c| c.email.clone()).filter(|e| !e.is_empty()),
+                scouts: Vec::new(),
+            });
+            let remaining = input.requirements.get(&(user_id, badge.id)).map(|reqs| {
+                let mut reqs = reqs.clone();
+                sort_requirements(&mut reqs);
+                RequirementTree::build(&reqs).remaining_numbers(&|i| reqs[i].is_completed())
+            });
+            group.scouts.push(CounselorScout {
+                scout: youth.display_name(),
+                badge: badge.name.clone(),
+                percent: badge.progress_percent(),
+                remaining,
+            });
+        }
+    }
+
+    let mut groups: Vec<CounselorGroup> = groups.into_values().collect();
+    for group in &mut groups {
+        group.scouts.sort_by(|a, b| a.badge.cmp(&b.badge).then_with(|| a.scout.cmp(&b.scout)));
+    }
+    groups.sort_by(|a, b| a.is_unassigned().cmp(&b.is_unassigned()).then_with(|| a.name.cmp(&b.name)));
+    groups
+}
+
+/// Remaining requirements for display, e.g. "1, 3a, 4" or "not cached".
+pub fn remaining_label(remaining: &Option<Vec<String>>) -> String {
+    match remaining {
+        Some(numbers) if numbers.is_empty() => "None".to_string(),
+        Some(numbers) => numbers.join(", "),
+        None => "not cached".to_string(),
+    }
+}
+
+/// Report sections for one counselor.
+pub fn counselor_sections(group: &CounselorGroup) -> Vec<Section> {
+    let mut contact = Section::new(group.name.clone());
+    if let Some(phone) = &group.phone {
+        contact.bullet(format!("Phone: {}", phone));
+    }
+    if let Some(email) = &group.email {
+        contact.bullet(format!("Email: {}", email));
+    }
+    contact.bullet(format!("{} scouts in progress", group.scouts.len()));
+
+    let mut scouts = Section::new("Scouts and Remaining Requirements");
+    let mut table = Table::new(["Merit Badge", "Scout", "Done", "Remaining"]);
+    for s in &group.scouts {
+        table.row([s.badge.clone(), s.scout.clone(), percent_label(s.percent), remaining_label(&s.remaining)]);
+    }
+    scouts.table(table);
+    vec![contact, scouts]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_groups_by_counselor_with_remaining_requirements() {
+        let youth: Vec<Youth> = serde_json::from_value(serde_json::json!([
+            { "userId": 1, "firstName": "Sam", "lastName": "Scout" },
+            { "userId": 2, "firstName": "Alex", "lastName": "Ranger" }
+        ]))
+        .unwrap();
+        let sam_badges: Vec<MeritBadgeProgress> = serde_json::from_value(serde_json::json!([
+            { "id": 10, "name": "Cooking", "percentCompleted": 0.5,
+              "assignedCounselorUser": { "firstName": "Pat", "lastName": "Chef", "email": "pat@example.com" } },
+            { "id": 11, "name": "Swimming", "status": "Awarded",
+              "assignedCounselorUser": { "firstName": "Pat", "lastName": "Chef" } },
+            { "id": 12, "name": "Camping" }
+        ]))
+        .unwrap();
+        let alex_badges: Vec<MeritBadgeProgress> = serde_json::from_value(serde_json::json!([
+            { "id": 10, "name": "Cooking",
+              "assignedCounselorUser": { "firstName": "Pat", "lastName": "Chef" } }
+        ]))
+        .unwrap();
+        let badges = HashMap::from([(1, sam_badges), (2, alex_badges)]);
+        let reqs: Vec<MeritBadgeRequirement> = serde_json::from_value(serde_json::json!([
+            { "listNumber": "1", "completed": true },
+            { "listNumber": "2" },
+            { "listNumber": "3" },
+            { "listNumber": "a" }
+        ]))
+        .unwrap();
+        let requirements = HashMap::from([((1, 10), reqs)]);
+
+        let groups = counselor_groups(&CounselorInput {
+            youth: &youth,
+            badges: &badges,
+            requirements: &requirements,
+            phone_format: PhoneFormat::Parens,
+        });
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].name, "Pat Chef");
+        assert_eq!(groups[0].email.as_deref(), Some("pat@example.com"));
+        assert_eq!(groups[0].scouts.len(), 2, "awarded badges are left out");
+        let sam = groups[0].scouts.iter().find(|s| s.scout.contains("Sam")).unwrap();
+        assert_eq!(remaining_label(&sam.remaining), "2, 3a");
+        assert!(groups[1].is_unassigned());
+        assert_eq!(groups[1].scouts[0].badge, "Camping");
+    }
+}
@@ -0,0 +1,91 @@
+//! Court of Honor handout: everything ready to award, grouped by scout.
+//!
+//! Built from Scoutbook's ready-to-award list so the advancement chair can
+//! print it for the ceremony (or save the HTML as a PDF) instead of
+//! re-typing it. Scouts are in patrol order, each with their ranks first,
+//! then merit badges, then other awards.
+
+use std::collections::BTreeMap;
+
+use super::document::{Section, Table};
+use crate::models::{format_date, ReadyToAward, ScoutRank, Youth};
+
+/// Ranks, then merit badges, then anything else; ranks in rank order.
+fn award_order(award: &ReadyToAward) -> (u8, usize, String) {
+    let kind = award.advancement_type.to_lowercase();
+    if kind.contains("rank") {
+        let rank = ScoutRank::parse(Some(&award.advancement_name));
+        (0, rank as usize, String::new())
+    } else if kind.contains("merit") {
+        (1, 0, award.advancement_name.to_lowercase())
+    } else {
+        (2, 0, award.advancement_name.to_lowercase())
+    }
+}
+
+/// A summary section, then a section per scout with their awards.
+pub fn court_of_honor_sections(awards: &[ReadyToAward], youth: &[Youth]) -> Vec<Section> {
+    let mut by_scout: BTreeMap<i64, Vec<&ReadyToAward>> = BTreeMap::new();
+    for award in awards {
+        by_scout.entry(award.user_id).or_default().push(award);
+    }
+    let patrol = |user_id: i64| {
+        youth.iter().find(|y| y.user_id == Some(user_id)).and_then(|y| y.patrol_name.clone()).unwrap_or_default()
+    };
+    let mut scouts: Vec<(String, Vec<&ReadyToAward>)> =
+        by_scout.into_iter().map(|(user_id, awards)| (patrol(user_id), awards)).collect();
+    scouts.sort_by_key(|(patrol, awards)| (patrol.clone(), awards[0].last_name.to_lowercase(), awards[0].first_name.to_lowercase()));
+
+    let count = |kind: u8| awards.iter().filter(|a| award_order(a).0 == kind).count();
+    let mut summary = Section::new("Summary");
+    summary.bullet(format!("{} scouts recognized", scouts.len()));
+    summary.bullet(format!("{} ranks, {} merit badges, {} other awards", count(0), count(1), count(2)));
+
+    let mut sections = vec![summary];
+    for (patrol, mut awards) in scouts {
+        awards.sort_by_key(|a| award_order(a));
+        let mut section = Section::new(awards[0].full_name());
+        if !patrol.is_empty() {
+            section.bullet(format!("Patrol: {}", patrol));
+        }
+        let mut table = Table::new(["Award", "Type", "Completed"]);
+        for award in awards {
+            table.row([award.advancement_name.clone(), award.advancement_type.clone(), format_date(award.date_completed.as_deref())]);
+        }
+        section.table(table);
+        sections.push(section);
+    }
+    sections
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reports::to_markdown;
+
+    #[test]
+    fn test_court_of_honor_sections() {
+        let youth: Vec<Youth> = serde_json::from_value(serde_json::json!([
+            { "userId": 1, "firstName": "Sam", "lastName": "Ortiz", "subUnitName": "Hawks" },
+            { "userId": 2, "firstName": "Ana", "lastName": "Baker", "subUnitName": "Wolves" }
+        ]))
+        .unwrap();
+        let awards: Vec<ReadyToAward> = serde_json::from_value(serde_json::json!([
+            { "userId": 2, "firstName": "Ana", "lastName": "Baker", "advancementType": "Merit Badge", "advancementName": "Cooking", "dateCompleted": "2026-09-20" },
+            { "userId": 1, "firstName": "Sam", "lastName": "Ortiz", "advancementType": "Merit Badge", "advancementName": "Swimming", "dateCompleted": null },
+            { "userId": 1, "firstName": "Sam", "lastName": "Ortiz", "advancementType": "Rank", "advancementName": "First Class", "dateCompleted": "2026-10-01" },
+            { "userId": 1, "firstName": "Sam", "lastName": "Ortiz", "advancementType": "Rank", "advancementName": "Second Class", "dateCompleted": "2026-06-01" }
+        ]))
+        .unwrap();
+
+        let sections = court_of_honor_sections(&awards, &youth);
+        assert_eq!(sections.len(), 3);
+        assert_eq!(sections[0].bullets[1], "2 ranks, 2 merit badges, 0 other awards");
+        assert_eq!(sections[1].title, "Sam Ortiz", "Hawks before Wolves");
+        let names: Vec<&str> = sections[1].tables[0].rows.iter().map(|r| r[0].as_str()).collect();
+        assert_eq!(names, ["Second Class", "First Class", "Swimming"]);
+
+        let markdown = to_markdown("Court of Honor", &sections, None);
+        assert!(markdown.contains("## Ana Baker\n\n- Patrol: Wolves\n"));
+    }
+}
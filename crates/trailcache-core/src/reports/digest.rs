@@ -0,0 +1,128 @@
+//! Weekly digest for the unit's leaders: the coming week's events, awards
+//! waiting to be presented, scouts whose advancement has stalled, and
+//! registrations and YPT about to lapse.
+//!
+//! Laid out as plain text so it reads the same in any mail client.
+
+use chrono::{Duration, NaiveDate};
+
+use super::document::{to_text, Section, Table, TextLayout};
+use super::tabular::expiring_table;
+use crate::models::{format_date, Adult, Event, ReadyToAward, ScoutPace, Youth};
+
+/// Events starting within this many days are listed.
+pub const DIGEST_EVENT_DAYS: i64 = 7;
+/// Registrations and YPT expiring within this many days are listed.
+pub const DIGEST_RENEWAL_DAYS: i64 = 30;
+
+pub struct DigestInput<'a> {
+    pub unit_name: &'a str,
+    pub youth: &'a [Youth],
+    pub adults: &'a [Adult],
+    pub events: &'a [Event],
+    pub ready_to_award: &'a [ReadyToAward],
+    pub stalled: &'a [ScoutPace],
+}
+
+pub fn digest_subject(input: &DigestInput, today: NaiveDate) -> String {
+    format!("{} weekly digest - {}", input.unit_name, today.format("%b %-d, %Y"))
+}
+
+pub fn digest_sections(input: &DigestInput, today: NaiveDate) -> Vec<Section> {
+    let mut events: Vec<(NaiveDate, &Event)> = input
+        .events
+        .iter()
+        .filter_map(|e| e.start_day().map(|d| (d, e)))
+        .filter(|(d, _)| *d >= today && *d <= today + Duration::days(DIGEST_EVENT_DAYS))
+        .collect();
+    events.sort_by_key(|(d, e)| (*d, e.name.clone()));
+    let mut upcoming = Section::new("This week's events");
+    if events.is_empty() {
+        upcoming.bullet("No events in the next week");
+    } else {
+        let mut table = Table::new(["Date", "Event", "Going"]);
+        for (_, event) in events {
+            table.row([event.formatted_datetime_short(), event.name.clone(), event.going_count().to_string()]);
+        }
+        upcoming.table(table);
+    }
+
+    let mut awards = Section::new("Ready to award");
+    if input.ready_to_award.is_empty() {
+        awards.bullet("Nothing waiting to be presented");
+    } else {
+        let mut list: Vec<&ReadyToAward> = input.ready_to_award.iter().collect();
+        list.sort_by_key(|a| (a.last_name.to_lowercase(), a.first_name.to_lowercase(), a.advancement_name.clone()));
+        let mut table = Table::new(["Scout", "Award", "Completed"]);
+        for award in list {
+            table.row([award.full_name(), award.advancement_name.clone(), format_date(award.date_completed.as_deref())]);
+        }
+        awards.table(table);
+    }
+
+    let mut stalled = Section::new("Stalled advancement");
+    if input.stalled.is_empty() {
+        stalled.bullet("Every scout has recent progress");
+    } else {
+        let mut table = Table::new(["Scout", "Last progress", "Per month"]);
+        for pace in input.stalled {
+            let last = pace.last_progress.map(|d| d.format("%b %-d, %Y").to_string()).unwrap_or_default();
+            table.row([pace.name.clone(), last, format!("{:.1}", pace.per_month())]);
+        }
+        stalled.table(table);
+    }
+
+    let mut renewals = Section::new("Expiring renewals");
+    let expiring = expiring_table(input.youth, input.adults, today, DIGEST_RENEWAL_DAYS);
+    if expiring.is_empty() {
+        renewals.bullet(format!("No registrations or YPT expiring in the next {} days", DIGEST_RENEWAL_DAYS));
+    } else {
+        renewals.table(expiring);
+    }
+
+    vec![upcoming, awards, stalled, renewals]
+}
+
+/// The digest as the body of an email.
+pub fn digest_text(input: &DigestInput, today: NaiveDate) -> String {
+    let layout = TextLayout { width: 78, page_lines: None };
+    to_text(&digest_subject(input, today), &digest_sections(input, today), layout)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_digest_sections() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 15).unwrap();
+        let events: Vec<Event> = serde_json::from_value(serde_json::json!([
+            { "id": 1, "name": "Fall Campout", "startDate": "2026-10-17T17:00:00" },
+            { "id": 2, "name": "Court of Honor", "startDate": "2026-11-20T19:00:00" },
+            { "id": 3, "name": "Last Week's Hike", "startDate": "2026-10-10T09:00:00" },
+        ]))
+        .unwrap();
+        let adults: Vec<Adult> = serde_json::from_value(serde_json::json!([
+            { "firstName": "Pat", "lastName": "Lee", "yptExpiredDate": "2026-11-01" },
+        ]))
+        .unwrap();
+        let stalled = vec![ScoutPace {
+            user_id: 1,
+            name: "Sam Ortiz".to_string(),
+            recent: 6,
+            last_progress: NaiveDate::from_ymd_opt(2026, 6, 1),
+        }];
+        let input = DigestInput { unit_name: "Troop 12", youth: &[], adults: &adults, events: &events, ready_to_award: &[], stalled: &stalled };
+
+        let sections = digest_sections(&input, today);
+        assert_eq!(sections[0].tables[0].rows.len(), 1, "only this week's events");
+        assert_eq!(sections[0].tables[0].rows[0][1], "Fall Campout");
+        assert_eq!(sections[1].bullets, ["Nothing waiting to be presented"]);
+        assert_eq!(sections[2].tables[0].rows[0], ["Sam Ortiz", "Jun 1, 2026", "0.5"]);
+        assert_eq!(sections[3].tables[0].rows[0][0], "Pat Lee");
+
+        let text = digest_text(&input, today);
+        assert!(text.contains("Troop 12 weekly digest - Oct 15, 2026"));
+        assert!(!text.contains('\u{c}'), "one continuous page");
+    }
+}
@@ -0,0 +1,404 @@
+//! Format-neutral building blocks for generated reports.
+//!
+//! Reports that come in more than one output format build a list of
+//! `Section`s and render them with `to_markdown`, `to_html`, or `to_text`.
+
+use std::fmt::Write;
+
+use crate::utils::{csv_line, tel_uri};
+
+/// A simple table with a header row.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Table {
+    pub header: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new<S: Into<String>>(header: impl IntoIterator<Item = S>) -> Self {
+        Self { header: header.into_iter().map(Into::into).collect(), rows: Vec::new() }
+    }
+
+    pub fn row<S: Into<String>>(&mut self, cells: impl IntoIterator<Item = S>) {
+        self.rows.push(cells.into_iter().map(Into::into).collect());
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// The table as CSV text, header line first, with a trailing newline.
+    pub fn to_csv(&self) -> String {
+        let mut out = csv_line(&self.header);
+        for row in &self.rows {
+            out.push('\n');
+            out.push_str(&csv_line(row));
+        }
+        out.push('\n');
+        out
+    }
+
+    /// The table as a JSON array of objects keyed by column header.
+    pub fn to_json(&self) -> serde_json::Value {
+        self.rows
+            .iter()
+            .map(|row| {
+                let object: serde_json::Map<String, serde_json::Value> =
+                    self.header.iter().cloned().zip(row.iter().map(|cell| cell.as_str().into())).collect();
+                serde_json::Value::Object(object)
+            })
+            .collect()
+    }
+}
+
+/// A titled block of bullet points and tables (a slide, or a page section).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Section {
+    pub title: String,
+    pub bullets: Vec<String>,
+    pub tables: Vec<Table>,
+}
+
+impl Section {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self { title: title.into(), ..Default::default() }
+    }
+
+    pub fn bullet(&mut self, text: impl Into<String>) {
+        self.bullets.push(text.into());
+    }
+
+    /// Add a table, skipping empty ones.
+    pub fn table(&mut self, table: Table) {
+        if !table.is_empty() {
+            self.tables.push(table);
+        }
+    }
+}
+
+/// Render sections as Markdown, separated by `separator` (e.g. `---` for slides).
+pub fn to_markdown(title: &str, sections: &[Section], separator: Option<&str>) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "# {}", title);
+    for section in sections {
+        let _ = writeln!(out);
+        if let Some(sep) = separator {
+            let _ = writeln!(out, "{}", sep);
+            let _ = writeln!(out);
+        }
+        let _ = writeln!(out, "## {}", section.title);
+        let _ = writeln!(out);
+        for bullet in &section.bullets {
+            let _ = writeln!(out, "- {}", bullet);
+        }
+        for (i, table) in section.tables.iter().enumerate() {
+            if i > 0 || !section.bullets.is_empty() {
+                let _ = writeln!(out);
+            }
+            write_markdown_table(&mut out, table);
+        }
+    }
+    out
+}
+
+fn write_markdown_table(out: &mut String, table: &Table) {
+    let cell = |s: &str| s.replace('|', "\\|");
+    let header: Vec<String> = table.header.iter().map(|h| cell(h)).collect();
+    let _ = writeln!(out, "| {} |", header.join(" | "));
+    let _ = writeln!(out, "|{}|", vec!["---"; header.len()].join("|"));
+    for row in &table.rows {
+        let cells: Vec<String> = row.iter().map(|c| cell(c)).collect();
+        let _ = writeln!(out, "| {} |", cells.join(" | "));
+    }
+}
+
+/// Render sections as a standalone HTML page, one `<section>` per section.
+pub fn to_html(title: &str, sections: &[Section]) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "<!DOCTYPE html>");
+    let _ = writeln!(out, "<html lang=\"en\">");
+    let _ = writeln!(out, "<head>");
+    let _ = writeln!(out, "<meta charset=\"utf-8\">");
+    let _ = writeln!(out, "<title>{}</title>", escape_html(title));
+    let _ = writeln!(out, "<style>{}</style>", HTML_STYLE);
+    let _ = writeln!(out, "</head>");
+    let _ = writeln!(out, "<body>");
+    let _ = writeln!(out, "<h1>{}</h1>", escape_html(title));
+    out.push_str(&sections_html(sections));
+    let _ = writeln!(out, "</body>");
+    let _ = writeln!(out, "</html>");
+    out
+}
+
+/// Render sections as an HTML fragment, for embedding in a page template.
+pub fn sections_html(sections: &[Section]) -> String {
+    let mut out = String::new();
+    for section in sections {
+        let _ = writeln!(out, "<section>");
+        let _ = writeln!(out, "<h2>{}</h2>", escape_html(&section.title));
+        if !section.bullets.is_empty() {
+            let _ = writeln!(out, "<ul>");
+            for bullet in &section.bullets {
+                let _ = writeln!(out, "<li>{}</li>", escape_html(bullet));
+            }
+            let _ = writeln!(out, "</ul>");
+        }
+        for table in &section.tables {
+            write_html_table(&mut out, table);
+        }
+        let _ = writeln!(out, "</section>");
+    }
+    out
+}
+
+/// Cells in a "Phone" column link to the number, so it can be tapped to call.
+fn write_html_table(out: &mut String, table: &Table) {
+    let _ = writeln!(out, "<table>");
+    let header: String = table.header.iter().map(|h| format!("<th>{}</th>", escape_html(h))).collect();
+    let _ = writeln!(out, "<tr>{}</tr>", header);
+    let phone_column = |i: usize| table.header.get(i).is_some_and(|h| h == "Phone");
+    for row in &table.rows {
+        let cells: String = row
+            .iter()
+            .enumerate()
+            .map(|(i, c)| match tel_uri(c).filter(|_| phone_column(i)) {
+                Some(uri) => format!("<td><a href=\"{}\">{}</a></td>", escape_html(&uri), escape_html(c)),
+                None => format!("<td>{}</td>", escape_html(c)),
+            })
+            .collect();
+        let _ = writeln!(out, "<tr>{}</tr>", cells);
+    }
+    let _ = writeln!(out, "</table>");
+}
+
+/// Page size for plain-text output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextLayout {
+    /// Characters per line
+    pub width: usize,
+    /// Lines per page including the page header, or None for one continuous page
+    pub page_lines: Option<usize>,
+}
+
+impl Default for TextLayout {
+    /// US Letter at 10 characters per inch, 6 lines per inch
+    fn default() -> Self {
+        Self { width: 80, page_lines: Some(60) }
+    }
+}
+
+/// Lines reserved at the top of each page for the title, page number, and rule
+const TEXT_PAGE_HEADER_LINES: usize = 3;
+
+/// A body line, remembering the table it belongs to so its header can be
+/// repeated at the top of a new page.
+struct TextLine {
+    text: String,
+    table_header: Option<(String, String)>,
+}
+
+/// Render sections as fixed-width plain text for printing.
+///
+/// Tables are laid out in aligned columns, shrinking the widest columns to fit
+/// the page width. Pages are separated by form feeds, each headed by the title
+/// and page number; a table that runs onto a new page repeats its header row.
+pub fn to_text(title: &str, sections: &[Section], layout: TextLayout) -> String {
+    let width = layout.width.max(20);
+    let mut body: Vec<TextLine> = Vec::new();
+    let plain = |body: &mut Vec<TextLine>, text: String| body.push(TextLine { text, table_header: None });
+
+    for (i, section) in sections.iter().enumerate() {
+        if i > 0 {
+            plain(&mut body, String::new());
+        }
+        plain(&mut body, fit(&section.title.to_uppercase(), width));
+        plain(&mut body, String::new());
+        for bullet in &section.bullets {
+            for (j, line) in crate::utils::wrap_text(bullet, width - 4).into_iter().enumerate() {
+                let marker = if j == 0 { "  * " } else { "    " };
+                plain(&mut body, format!("{}{}", marker, line));
+            }
+        }
+        for (j, table) in section.tables.iter().enumerate() {
+            if j > 0 || !section.bullets.is_empty() {
+                plain(&mut body, String::new());
+            }
+            let widths = column_widths(table, width);
+            let header = text_row(&table.header, &widths);
+            let rule = widths.iter().map(|w| "-".repeat(*w)).collect::<Vec<_>>().join("  ");
+            plain(&mut body, header.clone());
+            plain(&mut body, rule.clone());
+            for row in &table.rows {
+                body.push(TextLine { text: text_row(row, &widths), table_header: Some((header.clone(), rule.clone())) });
+            }
+        }
+    }
+
+    let per_page = layout
+        .page_lines
+        .map(|n| n.saturating_sub(TEXT_PAGE_HEADER_LINES).max(1))
+        .unwrap_or(usize::MAX);
+
+    let mut pages: Vec<Vec<String>> = Vec::new();
+    let mut current: Vec<String> = Vec::new();
+    for line in body {
+        if current.len() >= per_page {
+            pages.push(std::mem::take(&mut current));
+            if let Some((header, rule)) = &line.table_header {
+                current.push(header.clone());
+                current.push(rule.clone());
+            }
+        }
+        current.push(line.text);
+    }
+    pages.push(current);
+
+    let total = pages.len();
+    let mut out = String::new();
+    for (n, page) in pages.into_iter().enumerate() {
+        if n > 0 {
+            out.push('\x0c');
+        }
+        let number = if layout.page_lines.is_some() { format!("Page {} of {}", n + 1, total) } else { String::new() };
+        let title = fit(title, width.saturating_sub(number.len() + 1));
+        let gap = width.saturating_sub(title.chars().count() + number.len());
+        let _ = writeln!(out, "{}", format!("{}{}{}", title, " ".repeat(gap), number).trim_end());
+        let _ = writeln!(out, "{}", "=".repeat(width));
+        let _ = writeln!(out);
+        for line in page {
+            let _ = writeln!(out, "{}", line.trim_end());
+        }
+    }
+    out
+}
+
+/// Natural column widths, narrowing the widest column until the row fits.
+fn column_widths(table: &Table, width: usize) -> Vec<usize> {
+    let columns = table.header.len();
+    let mut widths: Vec<usize> = (0..columns)
+        .map(|c| {
+            std::iter::once(&table.header)
+                .chain(&table.rows)
+                .filter_map(|row| row.get(c))
+                .map(|cell| cell.chars().count())
+                .max()
+                .unwrap_or(0)
+                .max(1)
+        })
+        .collect();
+
+    let available = width.saturating_sub(2 * columns.saturating_sub(1));
+    while widths.iter().sum::<usize>() > available {
+        let Some(widest) = widths.iter_mut().max() else { break };
+        if *widest <= 4 {
+            break;
+        }
+        *widest -= 1;
+    }
+    widths
+}
+
+fn text_row(cells: &[String], widths: &[usize]) -> String {
+    widths
+        .iter()
+        .enumerate()
+        .map(|(i, &w)| {
+            let cell = fit(cells.get(i).map(String::as_str).unwrap_or(""), w);
+            format!("{}{}", cell, " ".repeat(w - cell.chars().count()))
+        })
+        .collect::<Vec<_>>()
+        .join("  ")
+}
+
+/// Cut text to `max` characters, marking the cut with an ellipsis.
+fn fit(text: &str, max: usize) -> String {
+    if text.chars().count() <= max {
+        return text.to_string();
+    }
+    let mut out: String = text.chars().take(max.saturating_sub(1)).collect();
+    out.push('…');
+    out
+}
+
+/// Styling that makes each section read as a slide when printed or presented.
+const HTML_STYLE: &str = "body{font-family:sans-serif;max-width:960px;margin:auto;padding:1em}\
+section{min-height:90vh;page-break-after:always;border-bottom:1px solid #ccc;padding:1em 0}\
+table{border-collapse:collapse;margin:1em 0}th,td{border:1px solid #999;padding:4px 10px;text-align:left}\
+th{background:#eee}";
+
+/// Escape text for inclusion in HTML element content or attributes.
+pub fn escape_html(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_markdown_and_html_tables() {
+        let mut section = Section::new("Membership");
+        section.bullet("25 scouts");
+        let mut table = Table::new(["Patrol", "Scouts"]);
+        table.row(["Eagles", "8"]);
+        table.row(["R&D | Lab", "3"]);
+        section.table(table);
+        let mut contacts = Table::new(["Name", "Phone"]);
+        contacts.row(["Pat Lee", "(555) 123-4567"]);
+        contacts.row(["Sam Ortiz", "ask the front desk"]);
+        section.table(contacts);
+        section.table(Table::new(["Empty"]));
+
+        let md = to_markdown("Report", std::slice::from_ref(&section), Some("---"));
+        assert!(md.contains("---\n\n## Membership\n\n- 25 scouts\n\n| Patrol | Scouts |\n|---|---|\n| Eagles | 8 |"));
+        assert!(md.contains("R&D \\| Lab"));
+        assert!(!md.contains("Empty"), "empty tables are skipped");
+
+        let html = to_html("Report", &[section]);
+        assert!(html.contains("<td>R&amp;D | Lab</td>"));
+        assert!(html.contains("<li>25 scouts</li>"));
+        assert!(html.contains("<td><a href=\"tel:+15551234567\">(555) 123-4567</a></td>"));
+        assert!(html.contains("<td>ask the front desk</td>"));
+    }
+
+    #[test]
+    fn test_text_pages_repeat_table_header() {
+        let mut section = Section::new("Roster");
+        let mut table = Table::new(["Name", "Patrol"]);
+        for i in 0..10 {
+            table.row([format!("Scout {}", i), "Eagles".to_string()]);
+        }
+        section.table(table);
+
+        let text = to_text("Troop 42", &[section], TextLayout { width: 40, page_lines: Some(10) });
+        let pages: Vec<&str> = text.split('\x0c').collect();
+        assert_eq!(pages.len(), 3);
+        assert!(pages[0].starts_with("Troop 42                     Page 1 of 3\n"));
+        assert!(pages[0].contains("ROSTER\n\nName     Patrol\n-------  ------\nScout 0  Eagles\n"));
+        assert!(pages[1].contains("=\n\nName     Patrol\n-------  ------\nScout"), "header repeats on new page");
+        assert!(pages.iter().flat_map(|p| p.lines()).all(|l| l.chars().count() <= 40));
+    }
+
+    #[test]
+    fn test_text_columns_shrink_to_width() {
+        let mut table = Table::new(["Event", "Location"]);
+        table.row(["A very long event name that will not fit".to_string(), "Camp".to_string()]);
+        let mut section = Section::new("Events");
+        section.table(table);
+
+        let text = to_text("T", &[section], TextLayout { width: 30, page_lines: None });
+        assert!(text.contains("A very long event n…  Camp\n"));
+        assert!(!text.contains("Page"));
+    }
+}
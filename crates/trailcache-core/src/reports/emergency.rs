@@ -0,0 +1,122 @@
+//! Event emergency sheet.
+//!
+//! Lists the scouts going on an event with their patrol and a parent to call,
+//! and flags anyone whose family hasn't signed this year's consent forms.
+//! Meant to be printed and carried by the adult in charge.
+
+use chrono::NaiveDate;
+
+use super::document::{Section, Table};
+use crate::models::{ConsentForm, Consents, Event, Parent, RsvpStatus, Youth};
+use crate::utils::PhoneFormat;
+
+/// Everything the sheet draws from, already loaded.
+pub struct EmergencyInput<'a> {
+    pub event: &'a Event,
+    pub youth: &'a [Youth],
+    pub parents: &'a [Parent],
+    pub consents: &'a Consents,
+    pub phone_format: PhoneFormat,
+}
+
+/// Scouts on the event: those invited who haven't declined, or the whole
+/// roster when the event has no invite list.
+pub fn event_participants<'a>(event: &Event, youth: &'a [Youth]) -> Vec<&'a Youth> {
+    let mut participants: Vec<&Youth> = youth
+        .iter()
+        .filter(|y| {
+            event.invited_users.is_empty()
+                || event
+                    .invited_users
+                    .iter()
+                    .any(|u| Some(u.user_id) == y.user_id && u.status() != RsvpStatus::NotGoing)
+        })
+        .collect();
+    participants.sort_by_key(|y| (y.last_name.clone(), y.first_name.clone()));
+    participants
+}
+
+/// Consent forms a scout is missing as of `today`.
+pub fn missing_forms(consents: &Consents, user_id: Option<i64>, today: NaiveDate) -> Vec<ConsentForm> {
+    ConsentForm::ALL
+        .into_iter()
+        .filter(|&form| user_id.is_none_or(|id| !consents.is_current(id, form, today)))
+        .collect()
+}
+
+/// Build the emergency sheet for one event.
+pub fn emergency_sheet(input: &EmergencyInput, today: NaiveDate) -> Vec<Section> {
+    let event = input.event;
+    let participants = event_participants(event, input.youth);
+
+    let mut details = Section::new(event.name.clone());
+    details.bullet(format!("When: {}", event.formatted_start_datetime()));
+    if let Some(location) = event.location.as_deref().filter(|l| !l.is_empty()) {
+        details.bullet(format!("Where: {}", location));
+    }
+    details.bullet(format!("Scouts: {}", participants.len()));
+
+    let mut roster = Section::new("Participants");
+    let mut table = Table::new(["Scout", "Patrol", "Parent/Guardian", "Phone", "Forms"]);
+    let mut missing = Section::new("Missing Consent Forms");
+    for youth in &participants {
+        let parent = input.parents.iter().find(|p| p.youth_user_id.is_some() && p.youth_user_id == youth.user_id);
+        let forms = missing_forms(input.consents, youth.user_id, today);
+        table.row([
+            youth.display_name(),
+            youth.patrol(),
+            parent.map(|p| p.full_name()).unwrap_or_default(),
+            parent.and_then(|p| p.phone(input.phone_format)).unwrap_or_default(),
+            if forms.is_empty() { "OK".to_string() } else { "MISSING".to_string() },
+        ]);
+        if !forms.is_empty() {
+            let names: Vec<&str> = forms.iter().map(|f| f.name()).collect();
+            missing.bullet(format!("{}: {}", youth.display_name(), names.join(", ")));
+        }
+    }
+    roster.table(table);
+    if participants.iter().all(|y| missing_forms(input.consents, y.user_id, today).is_empty()) {
+        missing.bullet("All participants have current consent forms");
+    }
+
+    vec![details, missing, roster]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reports::document::to_markdown;
+
+    #[test]
+    fn test_sheet_lists_participants_and_missing_forms() {
+        let youth: Vec<Youth> = serde_json::from_value(serde_json::json!([
+            { "userId": 1, "firstName": "Sam", "lastName": "Scout", "subUnitName": "Eagles" },
+            { "userId": 2, "firstName": "Alex", "lastName": "Ranger" },
+            { "userId": 3, "firstName": "Pat", "lastName": "Stay" }
+        ]))
+        .unwrap();
+        let event: Event = serde_json::from_value(serde_json::json!({
+            "id": 9, "name": "Fall Campout", "startDate": "2026-10-16T18:00:00", "rsvp": true,
+            "invitedUsers": [
+                { "userId": 1, "firstName": "Sam", "lastName": "Scout", "rsvpCode": "Y" },
+                { "userId": 2, "firstName": "Alex", "lastName": "Ranger" },
+                { "userId": 3, "firstName": "Pat", "lastName": "Stay", "rsvpCode": "N" }
+            ]
+        }))
+        .unwrap();
+        let today = NaiveDate::from_ymd_opt(2026, 10, 14).unwrap();
+        let mut consents = Consents::default();
+        consents.sign(1, ConsentForm::ActivityConsent, today);
+        consents.sign(1, ConsentForm::MediaRelease, today);
+        consents.sign(2, ConsentForm::ActivityConsent, today);
+
+        let names: Vec<String> = event_participants(&event, &youth).iter().map(|y| y.display_name()).collect();
+        assert_eq!(names.len(), 2, "declined scouts are left off");
+
+        let input = EmergencyInput { event: &event, youth: &youth, parents: &[], consents: &consents, phone_format: PhoneFormat::Parens };
+        let sheet = to_markdown("Emergency Sheet", &emergency_sheet(&input, today), None);
+        assert!(sheet.contains("Media release"));
+        assert!(!sheet.contains("Stay"));
+        assert_eq!(sheet.matches("MISSING").count(), 1);
+    }
+}
@@ -0,0 +1,59 @@
+//! Eligible-participant list and gap report for a high-adventure trek.
+//!
+//! Built from `HighAdventure::screen`: who can go, what everyone else still
+//! needs, and whether the crew has enough adult advisors.
+
+use super::document::{Section, Table};
+use crate::models::{crew_advisors, Screening, TrekPlan, CREW_ADVISORS_REQUIRED, CREW_ADVISOR_AGE};
+
+fn role(s: &Screening) -> &'static str {
+    if s.is_adult { "Adult" } else { "Scout" }
+}
+
+fn age(s: &Screening) -> String {
+    s.age.map(|a| a.to_string()).unwrap_or_else(|| "-".to_string())
+}
+
+/// Summary, eligible list, and gap report for `plan`.
+pub fn high_adventure_sections(plan: &TrekPlan, results: &[Screening]) -> Vec<Section> {
+    let eligible: Vec<&Screening> = results.iter().filter(|s| s.is_eligible()).collect();
+    let advisors = crew_advisors(results);
+
+    let mut summary = Section::new("Trek");
+    summary.bullet(format!("{}, {}", plan.base.name(), plan.date.format("%B %-d, %Y")));
+    summary.bullet(format!("Minimum rank: {}", plan.min_rank.display_name()));
+    summary.bullet(format!(
+        "{} scouts and {} adults eligible",
+        eligible.iter().filter(|s| !s.is_adult).count(),
+        eligible.iter().filter(|s| s.is_adult).count()
+    ));
+    if advisors < CREW_ADVISORS_REQUIRED {
+        summary.bullet(format!(
+            "Crew needs {} eligible adults age {}+, has {}",
+            CREW_ADVISORS_REQUIRED, CREW_ADVISOR_AGE, advisors
+        ));
+    }
+
+    let mut eligible_section = Section::new("Eligible Participants");
+    let mut table = Table::new(["Name", "Role", "Age at Trek"]);
+    for s in &eligible {
+        table.row([s.name.clone(), role(s).to_string(), age(s)]);
+    }
+    if table.is_empty() {
+        eligible_section.bullet("No one is eligible yet");
+    }
+    eligible_section.table(table);
+
+    let mut gaps_section = Section::new("Gap Report");
+    let mut table = Table::new(["Name", "Role", "Age at Trek", "Still Needed"]);
+    for s in results.iter().filter(|s| !s.is_eligible()) {
+        let gaps: Vec<String> = s.gaps.iter().map(|g| g.describe()).collect();
+        table.row([s.name.clone(), role(s).to_string(), age(s), gaps.join("; ")]);
+    }
+    if table.is_empty() {
+        gaps_section.bullet("None");
+    }
+    gaps_section.table(table);
+
+    vec![summary, eligible_section, gaps_section]
+}
@@ -0,0 +1,130 @@
+//! iCalendar (.ics) export of troop events.
+//!
+//! Families can subscribe to the file from a shared folder or web host and
+//! see the troop calendar in their own calendar app without a Scoutbook
+//! login. Times with an offset are written in UTC; times without one are
+//! left floating so they show at the same clock time wherever the file is
+//! opened.
+
+use std::fmt::Write;
+
+use chrono::{DateTime, Utc};
+
+use crate::models::{parse_event_datetime, Event};
+use crate::utils::strip_html;
+
+/// Longest line allowed before folding, in bytes (RFC 5545)
+const MAX_LINE_BYTES: usize = 75;
+
+/// An .ics calendar named `calendar_name` with a VEVENT per dated event.
+/// `stamp` is when the file was generated.
+pub fn to_ical(calendar_name: &str, events: &[Event], stamp: DateTime<Utc>) -> String {
+    let mut out = String::new();
+    let mut line = |text: String| {
+        let _ = write!(out, "{}\r\n", fold(&text));
+    };
+    line("BEGIN:VCALENDAR".to_string());
+    line("VERSION:2.0".to_string());
+    line("PRODID:-//trailcache//Troop Calendar//EN".to_string());
+    line("CALSCALE:GREGORIAN".to_string());
+    line("METHOD:PUBLISH".to_string());
+    line(format!("X-WR-CALNAME:{}", escape(calendar_name)));
+
+    let dtstamp = stamp.format("%Y%m%dT%H%M%SZ").to_string();
+    for event in events {
+        let Some(start) = event.start_date.as_deref().and_then(ics_datetime) else {
+            continue;
+        };
+        line("BEGIN:VEVENT".to_string());
+        line(format!("UID:event-{}@trailcache", event.id));
+        line(format!("DTSTAMP:{}", dtstamp));
+        line(format!("DTSTART:{}", start));
+        if let Some(end) = event.end_date.as_deref().and_then(ics_datetime).filter(|end| *end > start) {
+            line(format!("DTEND:{}", end));
+        }
+        line(format!("SUMMARY:{}", escape(&event.name)));
+        if let Some(location) = event.location.as_deref().map(str::trim).filter(|l| !l.is_empty()) {
+            line(format!("LOCATION:{}", escape(location)));
+        }
+        if let Some(description) = event.description.as_deref().map(strip_html).filter(|d| !d.trim().is_empty()) {
+            line(format!("DESCRIPTION:{}", escape(description.trim())));
+        }
+        line(format!("CATEGORIES:{}", escape(event.derived_type())));
+        line("END:VEVENT".to_string());
+    }
+    line("END:VCALENDAR".to_string());
+    out
+}
+
+/// A Scoutbook date or date-time as an iCalendar DATE-TIME.
+fn ics_datetime(value: &str) -> Option<String> {
+    if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+        return Some(dt.with_timezone(&Utc).format("%Y%m%dT%H%M%SZ").to_string());
+    }
+    parse_event_datetime(value).map(|dt| dt.format("%Y%m%dT%H%M%S").to_string())
+}
+
+/// Escape a TEXT value.
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            ';' => out.push_str("\\;"),
+            ',' => out.push_str("\\,"),
+            '\n' => out.push_str("\\n"),
+            '\r' => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Fold a content line so no line is longer than 75 bytes, continuation
+/// lines starting with a space. Never splits a character.
+fn fold(line: &str) -> String {
+    let mut out = String::with_capacity(line.len() + line.len() / MAX_LINE_BYTES * 3);
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > MAX_LINE_BYTES {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(c);
+        width += c.len_utf8();
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_ical() {
+        let events: Vec<Event> = serde_json::from_value(serde_json::json!([
+            { "id": 7, "name": "Spring Campout", "startDate": "2026-04-17T18:00:00-05:00",
+              "endDate": "2026-04-19T11:00:00-05:00", "location": "Camp Lake, Site 4",
+              "description": "<p>Bring a tent; rain likely</p>", "eventType": "Campout" },
+            { "id": 8, "name": "Troop Meeting", "startDate": "2026-04-21T19:00:00" },
+            { "id": 9, "name": "Date TBD" }
+        ]))
+        .unwrap();
+        let stamp = DateTime::parse_from_rfc3339("2026-04-01T12:00:00Z").unwrap().with_timezone(&Utc);
+        let ics = to_ical("Troop 12", &events, stamp);
+
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 2, "undated events are left out");
+        assert!(ics.contains("UID:event-7@trailcache\r\n"));
+        assert!(ics.contains("DTSTART:20260417T230000Z\r\n"));
+        assert!(ics.contains("DTEND:20260419T160000Z\r\n"));
+        assert!(ics.contains("LOCATION:Camp Lake\\, Site 4\r\n"));
+        assert!(ics.contains("DESCRIPTION:Bring a tent\\; rain likely\r\n"));
+        assert!(ics.contains("DTSTART:20260421T190000\r\n"), "no offset stays floating");
+
+        let long = fold(&format!("DESCRIPTION:{}", "x".repeat(100)));
+        assert!(long.split("\r\n").all(|l| l.len() <= MAX_LINE_BYTES));
+        assert_eq!(long.replace("\r\n ", ""), format!("DESCRIPTION:{}", "x".repeat(100)));
+    }
+}
@@ -0,0 +1,119 @@
+//! Incident report draft.
+//!
+//! Pre-fills what the troop already knows about an event, who was there,
+//! and any incident notes from the debrief, leaving blanks for the rest.
+//! Leaders copy it into the official BSA incident report.
+
+use super::document::{Section, Table};
+use crate::models::{DebriefKind, Event, EventDebrief, InvitedUser, RsvpStatus, Youth};
+
+/// Written where leaders still need to fill something in
+const BLANK: &str = "____________________";
+
+/// Everything the draft draws from.
+pub struct IncidentInput<'a> {
+    pub unit_name: &'a str,
+    pub event: &'a Event,
+    pub youth: &'a [Youth],
+    pub debrief: Option<&'a EventDebrief>,
+}
+
+/// People at the event: those marked attended, or those who RSVP'd yes when
+/// attendance hasn't been taken. Adults first, then by name.
+pub fn incident_people(event: &Event) -> Vec<&InvitedUser> {
+    let attended: Vec<&InvitedUser> = event.invited_users.iter().filter(|u| u.attended).collect();
+    let mut people = if attended.is_empty() {
+        event.invited_users.iter().filter(|u| u.status() == RsvpStatus::Going).collect()
+    } else {
+        attended
+    };
+    people.sort_by_key(|u| (!u.is_adult, u.last_name.clone(), u.first_name.clone()));
+    people
+}
+
+/// Build the incident report draft for one event.
+pub fn incident_report(input: &IncidentInput) -> Vec<Section> {
+    let event = input.event;
+
+    let mut details = Section::new("Incident Details");
+    details.bullet(format!("Unit: {}", input.unit_name));
+    details.bullet(format!("Event: {}", event.name));
+    details.bullet(format!("Event dates: {} - {}", event.formatted_start_datetime(), event.formatted_end_datetime()));
+    let location = event.location.as_deref().filter(|l| !l.is_empty()).unwrap_or(BLANK);
+    details.bullet(format!("Location: {}", location));
+    details.bullet(format!("Date and time of incident: {}", BLANK));
+    details.bullet("Type: [ ] Injury  [ ] Illness  [ ] Property damage  [ ] Behavioral  [ ] Near miss");
+
+    let mut people = Section::new("People Involved");
+    let present = incident_people(event);
+    people.bullet(format!("{} people at the event. Mark those involved and note any witnesses.", present.len()));
+    let mut table = Table::new(["Name", "Youth/Adult", "Patrol", "Involved/Witness"]);
+    for user in present {
+        let patrol = input
+            .youth
+            .iter()
+            .find(|y| y.user_id == Some(user.user_id))
+            .and_then(|y| y.patrol_name.clone())
+            .unwrap_or_default();
+        let role = if user.is_adult { "Adult" } else { "Youth" };
+        table.row([user.display_name(), role.to_string(), patrol, String::new()]);
+    }
+    people.table(table);
+
+    let mut narrative = Section::new("Narrative");
+    let notes: Vec<&str> = input
+        .debrief
+        .into_iter()
+        .flat_map(|d| d.notes_of(DebriefKind::Incident))
+        .map(|n| n.text.as_str())
+        .collect();
+    if notes.is_empty() {
+        narrative.bullet(format!("What happened, in order: {}", BLANK));
+    }
+    for note in notes {
+        narrative.bullet(format!("From the debrief: {}", note));
+    }
+    narrative.bullet(format!("First aid or treatment given: {}", BLANK));
+    narrative.bullet(format!("Parents or guardians notified (who, when): {}", BLANK));
+    narrative.bullet(format!("Reported by: {}", BLANK));
+
+    vec![details, people, narrative]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Debriefs;
+    use crate::reports::document::to_markdown;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_draft_uses_attendance_and_incident_notes() {
+        let event: Event = serde_json::from_value(serde_json::json!({
+            "id": 4, "name": "Canoe Trip", "startDate": "2026-09-19T08:00:00", "location": "Lake Sammamish",
+            "invitedUsers": [
+                { "userId": 1, "firstName": "Sam", "lastName": "Scout", "rsvpCode": "Y", "attended": true },
+                { "userId": 2, "firstName": "Alex", "lastName": "Ranger", "rsvpCode": "Y" },
+                { "userId": 9, "firstName": "Jo", "lastName": "Leader", "isAdult": true, "attended": true }
+            ]
+        }))
+        .unwrap();
+        let youth: Vec<Youth> = serde_json::from_value(serde_json::json!([
+            { "userId": 1, "firstName": "Sam", "lastName": "Scout", "subUnitName": "Eagles" }
+        ]))
+        .unwrap();
+        let mut debriefs = Debriefs::default();
+        let today = NaiveDate::from_ymd_opt(2026, 9, 20).unwrap();
+        debriefs.add(&event, DebriefKind::Incident, "Capsized near the dock".to_string(), today);
+
+        let people: Vec<i64> = incident_people(&event).iter().map(|u| u.user_id).collect();
+        assert_eq!(people, [9, 1], "attended only, adults first");
+
+        let input = IncidentInput { unit_name: "Troop 42", event: &event, youth: &youth, debrief: debriefs.for_event(4) };
+        let draft = to_markdown("Incident Report Draft", &incident_report(&input), None);
+        assert!(draft.contains("Location: Lake Sammamish"));
+        assert!(draft.contains("| Scout, Sam | Youth | Eagles |"));
+        assert!(draft.contains("From the debrief: Capsized near the dock"));
+        assert!(!draft.contains("Ranger"));
+    }
+}
@@ -0,0 +1,68 @@
+//! Printable reports generated from cached troop data.
+//!
+//! - `charter`: Annual report to the chartered organization, from a template units can replace
+//! - `conference`: Scoutmaster conference prep sheet for one scout
+//! - `clinic`: Class rosters for a merit badge clinic, and sign-off sheets for its completions
+//! - `committee`: Unit dashboard slides for committee meetings
+//! - `court_of_honor`: Ready-to-award handout for a Court of Honor, grouped by scout
+//! - `counselor`: In-progress merit badges grouped by assigned counselor
+//! - `digest`: Weekly email digest of upcoming events, awards, and expiring renewals
+//! - `emergency`: Event emergency sheet with parent contacts and missing consent forms
+//! - `high_adventure`: Eligible participants and gap report for a high-adventure trek
+//! - `incident`: Pre-filled incident report draft for an event
+//! - `ical`: Troop events as an iCalendar file families can subscribe to
+//! - `redaction`: Export profiles (full, leader, public) and the fields each allows
+//! - `position_plan`: Next term's planned youth positions for entry in Scoutbook
+//! - `my_scout`: Compact summary of a parent's own scouts
+//! - `roster_csv`: Scout, adult, and parent rosters as CSV with chosen columns
+//! - `tabular`: Advancement and expiring registration/YPT tables for the headless report commands
+//! - `signoff`: Handbook-style rank sign-off sheets for paper records
+//! - `site`: Static HTML mini-site for families
+//! - `template`: User-written report templates rendered from cached data
+//! - `troop_history`: Annual troop history from the historian's log and event debriefs
+//! - `document`: Sections and tables shared by multi-format reports, with
+//!   Markdown, HTML, and paginated plain-text renderers
+
+pub mod charter;
+pub mod clinic;
+pub mod committee;
+pub mod conference;
+pub mod counselor;
+pub mod court_of_honor;
+pub mod digest;
+pub mod document;
+pub mod emergency;
+pub mod high_adventure;
+pub mod ical;
+pub mod incident;
+pub mod my_scout;
+pub mod position_plan;
+pub mod redaction;
+pub mod roster_csv;
+pub mod signoff;
+pub mod site;
+pub mod tabular;
+pub mod template;
+pub mod troop_history;
+
+pub use charter::{charter_context, charter_report, CharterInput, CHARTER_TEMPLATE, CHARTER_TEMPLATE_FILE};
+pub use clinic::{clinic_roster_sections, clinic_signoff_sections};
+pub use committee::{committee_summary, CommitteeInput, SlideFormat, UnitGoals};
+pub use conference::{conference_sheet, next_rank, ConferenceInput};
+pub use court_of_honor::court_of_honor_sections;
+pub use counselor::{counselor_groups, counselor_sections, CounselorGroup, CounselorInput, CounselorScout, NO_COUNSELOR};
+pub use digest::{digest_subject, digest_text, DigestInput};
+pub use document::{to_html, to_markdown, to_text, Section, Table, TextLayout};
+pub use emergency::{emergency_sheet, event_participants, missing_forms, EmergencyInput};
+pub use high_adventure::high_adventure_sections;
+pub use ical::to_ical;
+pub use incident::{incident_people, incident_report, IncidentInput};
+pub use my_scout::{my_scout_sections, my_scout_summary, MyScoutInput, MyScoutSummary, UpcomingEvent};
+pub use position_plan::position_plan_sections;
+pub use redaction::{ExportField, ExportProfile};
+pub use roster_csv::{RosterColumn, RosterCsv, RosterKind};
+pub use signoff::{signoff_sections, SignoffSheet};
+pub use tabular::{advancement_table, expiring_table, grade_table};
+pub use site::{build_site, SiteInput, SitePage, SITE_TEMPLATE_FILE};
+pub use template::{render_template, template_context, TemplateInput, REPORT_TEMPLATES_DIR};
+pub use troop_history::troop_history_sections;
@@ -0,0 +1,205 @@
+//! Compact summary of one scout for parents.
+//!
+//! Covers upcoming events the scout is invited to, den meetings for den
+//! chiefs, rank and merit badge progress, and anything that needs a parent's
+//! attention. Shown in the TUI's My Scouts view and printed by
+//! `trailcache --my-scout`.
+
+use chrono::NaiveDate;
+
+use super::conference::{current_rank, next_rank};
+use super::document::{Section, Table};
+use crate::models::{Event, MeritBadgeProgress, RankProgress, RsvpStatus, Youth};
+use crate::utils::{check_expiration, ExpirationStatus};
+
+/// Events are listed this many days ahead
+const UPCOMING_DAYS: i64 = 30;
+
+/// A merit badge started this long ago without being finished gets an alert
+const STALLED_BADGE_DAYS: i64 = 180;
+
+/// Everything the summary draws from, already loaded for one scout.
+pub struct MyScoutInput<'a> {
+    pub youth: &'a Youth,
+    pub ranks: &'a [RankProgress],
+    pub badges: &'a [MeritBadgeProgress],
+    pub events: &'a [Event],
+    /// Den meetings for scouts serving as den chiefs
+    pub den_meetings: &'a [(NaiveDate, String)],
+}
+
+/// An upcoming event the scout is invited to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UpcomingEvent {
+    pub date: NaiveDate,
+    pub name: String,
+    /// RSVP status, for events that take RSVPs
+    pub rsvp: Option<RsvpStatus>,
+}
+
+/// At-a-glance status for one scout.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MyScoutSummary {
+    pub name: String,
+    pub patrol: String,
+    pub current_rank: Option<String>,
+    /// Rank being worked on and its percent complete
+    pub next_rank: Option<(String, Option<i32>)>,
+    pub badges_earned: usize,
+    /// Unfinished merit badges and their percent complete, furthest along first
+    pub badges_in_progress: Vec<(String, Option<i32>)>,
+    pub upcoming: Vec<UpcomingEvent>,
+    pub alerts: Vec<String>,
+}
+
+fn parse_date(date: Option<&str>) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(date?.get(..10)?, "%Y-%m-%d").ok()
+}
+
+/// Build the summary for one scout as of `today`.
+pub fn my_scout_summary(input: &MyScoutInput, today: NaiveDate) -> MyScoutSummary {
+    let youth = input.youth;
+    let user_id = youth.user_id;
+    let mut alerts = Vec::new();
+
+    if let Some((status, date)) = youth.registration_expires().as_deref().and_then(check_expiration) {
+        if status != ExpirationStatus::Active {
+            alerts.push(format!("Registration: {}", status.format_expiry(&date)));
+        }
+    }
+
+    let mut upcoming: Vec<UpcomingEvent> = input
+        .events
+        .iter()
+        .filter_map(|e| {
+            let date = parse_date(e.start_date.as_deref())?;
+            if !(0..=UPCOMING_DAYS).contains(&(date - today).num_days()) {
+                return None;
+            }
+            let invite = e.invited_users.iter().find(|u| Some(u.user_id) == user_id);
+            if invite.is_none() && !e.invited_users.is_empty() {
+                return None;
+            }
+            let rsvp = e.rsvp.then(|| invite.map(|u| u.status()).unwrap_or(RsvpStatus::NoResponse));
+            Some(UpcomingEvent { date, name: e.name.clone(), rsvp })
+        })
+        .collect();
+    upcoming.extend(
+        input
+            .den_meetings
+            .iter()
+            .filter(|(date, _)| (0..=UPCOMING_DAYS).contains(&(*date - today).num_days()))
+            .map(|(date, name)| UpcomingEvent { date: *date, name: name.clone(), rsvp: None }),
+    );
+    upcoming.sort_by_key(|e| e.date);
+    for event in &upcoming {
+        if event.rsvp == Some(RsvpStatus::NoResponse) {
+            alerts.push(format!("RSVP needed: {} ({})", event.name, event.date.format("%b %-d")));
+        }
+    }
+
+    for rank in input.ranks.iter().filter(|r| r.is_completed() && !r.is_awarded()) {
+        alerts.push(format!("{} complete, waiting to be awarded", rank.rank_name));
+    }
+
+    let mut in_progress: Vec<&MeritBadgeProgress> = input.badges.iter().filter(|b| !b.is_completed()).collect();
+    in_progress.sort_by(|a, b| MeritBadgeProgress::cmp_by_progress(a, b));
+    for badge in &in_progress {
+        let started = parse_date(badge.date_started.as_deref());
+        if started.is_some_and(|d| (today - d).num_days() > STALLED_BADGE_DAYS) {
+            alerts.push(format!("{} merit badge started over 6 months ago", badge.name));
+        }
+    }
+
+    MyScoutSummary {
+        name: youth.short_name(),
+        patrol: youth.patrol(),
+        current_rank: current_rank(input.ranks).map(|r| r.rank_name.clone()),
+        next_rank: next_rank(input.ranks).map(|r| (r.rank_name.clone(), r.progress_percent())),
+        badges_earned: input.badges.iter().filter(|b| b.is_completed()).count(),
+        badges_in_progress: in_progress.iter().map(|b| (b.name.clone(), b.progress_percent())).collect(),
+        upcoming,
+        alerts,
+    }
+}
+
+/// Percent as "40%", or "-" when unknown.
+pub fn percent_label(percent: Option<i32>) -> String {
+    percent.map(|p| format!("{}%", p)).unwrap_or_else(|| "-".to_string())
+}
+
+/// One report section per scout, for the printable `--my-scout` report.
+pub fn my_scout_sections(summaries: &[MyScoutSummary]) -> Vec<Section> {
+    summaries
+        .iter()
+        .map(|s| {
+            let mut section = Section::new(format!("{} ({})", s.name, s.patrol));
+            section.bullet(format!("Current rank: {}", s.current_rank.as_deref().unwrap_or("None yet")));
+            if let Some((rank, percent)) = &s.next_rank {
+                section.bullet(format!("Working on: {} ({})", rank, percent_label(*percent)));
+            }
+            section.bullet(format!(
+                "Merit badges: {} earned, {} in progress",
+                s.badges_earned,
+                s.badges_in_progress.len()
+            ));
+            for alert in &s.alerts {
+                section.bullet(format!("ALERT: {}", alert));
+            }
+
+            let mut events = Table::new(["Date", "Upcoming Event", "RSVP"]);
+            for e in &s.upcoming {
+                let rsvp = e.rsvp.map(|r| r.to_string()).unwrap_or_default();
+                events.row([e.date.format("%a %b %-d").to_string(), e.name.clone(), rsvp]);
+            }
+            section.table(events);
+
+            let mut badges = Table::new(["Merit Badge", "Done"]);
+            for (name, percent) in &s.badges_in_progress {
+                badges.row([name.clone(), percent_label(*percent)]);
+            }
+            section.table(badges);
+            section
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summary_upcoming_events_and_alerts() {
+        let youth: Youth = serde_json::from_value(serde_json::json!({
+            "userId": 7, "firstName": "Sam", "lastName": "Scout", "subUnitName": "Eagles"
+        }))
+        .unwrap();
+        let events: Vec<Event> = serde_json::from_value(serde_json::json!([
+            { "id": 1, "name": "Campout", "startDate": "2026-10-10T18:00:00", "rsvp": true,
+              "invitedUsers": [{ "userId": 7, "firstName": "Sam", "lastName": "Scout" }] },
+            { "id": 2, "name": "Other Patrol Hike", "startDate": "2026-10-12",
+              "invitedUsers": [{ "userId": 8, "firstName": "Alex", "lastName": "Scout" }] },
+            { "id": 3, "name": "Troop Meeting", "startDate": "2026-10-06" },
+            { "id": 4, "name": "Too Far Out", "startDate": "2026-12-25" }
+        ]))
+        .unwrap();
+        let badges: Vec<MeritBadgeProgress> = serde_json::from_value(serde_json::json!([
+            { "id": 1, "name": "Cooking", "dateStarted": "2026-01-05", "percentCompleted": 0.5 },
+            { "id": 2, "name": "Swimming", "status": "Awarded" }
+        ]))
+        .unwrap();
+        let den_meetings = [(NaiveDate::from_ymd_opt(2026, 10, 8).unwrap(), "Pack 12 Den 4 meeting".to_string())];
+        let input = MyScoutInput { youth: &youth, ranks: &[], badges: &badges, events: &events, den_meetings: &den_meetings };
+
+        let summary = my_scout_summary(&input, NaiveDate::from_ymd_opt(2026, 10, 1).unwrap());
+        let names: Vec<&str> = summary.upcoming.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, ["Troop Meeting", "Pack 12 Den 4 meeting", "Campout"]);
+        assert_eq!(summary.upcoming[2].rsvp, Some(RsvpStatus::NoResponse));
+        assert_eq!(summary.badges_earned, 1);
+        assert_eq!(summary.badges_in_progress, [("Cooking".to_string(), Some(50))]);
+        assert_eq!(
+            summary.alerts,
+            ["RSVP needed: Campout (Oct 10)", "Cooking merit badge started over 6 months ago"]
+        );
+    }
+}
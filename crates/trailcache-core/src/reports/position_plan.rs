@@ -0,0 +1,40 @@
+//! Printable position plan for the term change.
+//!
+//! Lists next term's planned assignments beside each scout's current
+//! position, plus any eligibility problems, so the changes can be entered in
+//! Scoutbook by hand.
+
+use super::document::{Section, Table};
+use crate::models::{PositionPlan, Youth, YOUTH_POSITION_PRIORITY};
+
+fn position_order(position: &str) -> usize {
+    YOUTH_POSITION_PRIORITY.iter().position(|&p| p == position).unwrap_or(usize::MAX)
+}
+
+/// Assignments table and issues list for `plan`. `issues` comes from `plan_issues`.
+pub fn position_plan_sections(plan: &PositionPlan, youth: &[Youth], issues: &[(i64, String)]) -> Vec<Section> {
+    let scout = |id: i64| youth.iter().find(|y| y.user_id == Some(id));
+    let name = |id: i64| scout(id).map(|y| y.display_name()).unwrap_or_else(|| format!("User {}", id));
+
+    let mut assignments: Vec<_> = plan.assignments.iter().collect();
+    assignments.sort_by_key(|a| (position_order(&a.position), name(a.user_id)));
+
+    let mut section = Section::new("Planned Positions");
+    section.bullet(format!("{} positions planned", assignments.len()));
+    let mut table = Table::new(["Position", "Scout", "Patrol", "Current Position"]);
+    for a in assignments {
+        let current = scout(a.user_id).and_then(|y| y.position_display()).unwrap_or_else(|| "-".to_string());
+        let patrol = scout(a.user_id).map(|y| y.patrol()).unwrap_or_else(|| "-".to_string());
+        table.row([a.position.clone(), name(a.user_id), patrol, current]);
+    }
+    section.table(table);
+
+    let mut problems = Section::new("Eligibility Issues");
+    if issues.is_empty() {
+        problems.bullet("None");
+    }
+    for (id, issue) in issues {
+        problems.bullet(format!("{}: {}", name(*id), issue));
+    }
+    vec![section, problems]
+}
@@ -0,0 +1,269 @@
+//! Export profiles that decide which personal details an export may contain.
+//!
+//! - `Full`: everything cached, for the Scoutmaster's own records
+//! - `Leader`: contact details, ages, member IDs, grades, registration, and
+//!   training, but no birth dates, home addresses, or ethnicity
+//! - `Public`: names, patrols, ranks, and positions only, safe to hand to
+//!   families or post
+//!
+//! The limited profiles build each record from the fields they allow, so a
+//! field added to `Youth` or `Adult` stays out of them until it's listed.
+//!
+//! Exports pick a profile when they're written; the default comes from
+//! `Config::export_profile`.
+
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{bail, Error};
+use serde::{Deserialize, Serialize};
+
+use crate::models::person::RegistrarInfo;
+use crate::models::{Adult, Event, Youth};
+
+/// A personal detail an export profile can allow or leave out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportField {
+    /// Email addresses and phone numbers
+    Contact,
+    Age,
+    BirthDate,
+    Address,
+    MemberId,
+    /// Middle names, nicknames, gender, grade, registration, training, and
+    /// who has RSVPed to events
+    Personal,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportProfile {
+    Full,
+    #[default]
+    Leader,
+    Public,
+}
+
+impl ExportProfile {
+    pub const ALL: [ExportProfile; 3] = [ExportProfile::Full, ExportProfile::Leader, ExportProfile::Public];
+
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ExportProfile::Full => "full",
+            ExportProfile::Leader => "leader",
+            ExportProfile::Public => "public",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            ExportProfile::Full => ExportProfile::Leader,
+            ExportProfile::Leader => ExportProfile::Public,
+            ExportProfile::Public => ExportProfile::Full,
+        }
+    }
+
+    pub fn includes(&self, field: ExportField) -> bool {
+        match self {
+            ExportProfile::Full => true,
+            ExportProfile::Leader => matches!(
+                field,
+                ExportField::Contact | ExportField::Age | ExportField::MemberId | ExportField::Personal
+            ),
+            ExportProfile::Public => false,
+        }
+    }
+
+    /// Copy of `youth` with only the details this profile allows. Ages
+    /// come from birth dates, so callers that show an age under a profile
+    /// without `BirthDate` should compute it from the original.
+    pub fn youth(&self, youth: &Youth) -> Youth {
+        if *self == ExportProfile::Full {
+            return youth.clone();
+        }
+        let personal = self.includes(ExportField::Personal);
+        let contact = self.includes(ExportField::Contact);
+        let keep = |allowed: bool, value: &Option<String>| value.clone().filter(|_| allowed);
+        Youth {
+            person_guid: None,
+            member_id: keep(self.includes(ExportField::MemberId), &youth.member_id),
+            person_full_name: None,
+            first_name: youth.first_name.clone(),
+            middle_name: keep(personal, &youth.middle_name),
+            last_name: youth.last_name.clone(),
+            nick_name: keep(personal, &youth.nick_name),
+            gender: keep(personal, &youth.gender),
+            name_suffix: youth.name_suffix.clone(),
+            ethnicity: None,
+            grade: youth.grade.filter(|_| personal),
+            grade_id: youth.grade_id.filter(|_| personal),
+            position: youth.position.clone(),
+            position_id: youth.position_id,
+            program_id: youth.program_id,
+            program: youth.program.clone(),
+            registrar_info: self.registrar_info(youth.registrar_info.as_ref()),
+            primary_email_info: youth.primary_email_info.clone().filter(|_| contact),
+            primary_phone_info: youth.primary_phone_info.clone().filter(|_| contact),
+            primary_address_info: youth.primary_address_info.clone().filter(|_| self.includes(ExportField::Address)),
+            user_id: youth.user_id,
+            email: keep(contact, &youth.email),
+            phone_number: keep(contact, &youth.phone_number),
+            patrol_name: youth.patrol_name.clone(),
+            patrol_guid: youth.patrol_guid.clone(),
+            is_patrol_leader: youth.is_patrol_leader,
+            current_rank: youth.current_rank.clone(),
+        }
+    }
+
+    /// Copy of `adult` with only the details this profile allows.
+    pub fn adult(&self, adult: &Adult) -> Adult {
+        if *self == ExportProfile::Full {
+            return adult.clone();
+        }
+        let personal = self.includes(ExportField::Personal);
+        let contact = self.includes(ExportField::Contact);
+        let keep = |allowed: bool, value: &Option<String>| value.clone().filter(|_| allowed);
+        Adult {
+            person_guid: None,
+            member_id: keep(self.includes(ExportField::MemberId), &adult.member_id),
+            person_full_name: None,
+            first_name: adult.first_name.clone(),
+            middle_name: keep(personal, &adult.middle_name),
+            last_name: adult.last_name.clone(),
+            nick_name: keep(personal, &adult.nick_name),
+            gender: keep(personal, &adult.gender),
+            position: adult.position.clone(),
+            position_id: adult.position_id,
+            key3: adult.key3.clone(),
+            position_trained: keep(personal, &adult.position_trained),
+            ypt_status: keep(personal, &adult.ypt_status),
+            ypt_completed_date: keep(personal, &adult.ypt_completed_date),
+            ypt_expired_date: keep(personal, &adult.ypt_expired_date),
+            registrar_info: self.registrar_info(adult.registrar_info.as_ref()),
+            primary_email_info: adult.primary_email_info.clone().filter(|_| contact),
+            primary_phone_info: adult.primary_phone_info.clone().filter(|_| contact),
+            primary_address_info: adult.primary_address_info.clone().filter(|_| self.includes(ExportField::Address)),
+            user_id: adult.user_id,
+            email: keep(contact, &adult.email),
+            phone_number: keep(contact, &adult.phone_number),
+        }
+    }
+
+    /// Copy of `event` without its invitees and their RSVPs unless the
+    /// profile allows `Personal`.
+    pub fn event(&self, event: &Event) -> Event {
+        let mut event = event.clone();
+        if !self.includes(ExportField::Personal) {
+            event.invited_users.clear();
+        }
+        event
+    }
+
+    /// Registration details under `Personal`, with the birth date only when
+    /// the profile allows it.
+    fn registrar_info(&self, info: Option<&RegistrarInfo>) -> Option<RegistrarInfo> {
+        let mut info = info.filter(|_| self.includes(ExportField::Personal))?.clone();
+        if !self.includes(ExportField::BirthDate) {
+            info.date_of_birth = None;
+        }
+        Some(info)
+    }
+}
+
+impl fmt::Display for ExportProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+impl FromStr for ExportProfile {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match ExportProfile::ALL.into_iter().find(|p| p.label().eq_ignore_ascii_case(s)) {
+            Some(profile) => Ok(profile),
+            None => bail!("Unknown export profile \"{}\" (expected full, leader, or public)", s),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profiles_strip_fields() {
+        let youth: Youth = serde_json::from_value(serde_json::json!({
+            "userId": 1, "firstName": "Sam", "lastName": "Scout", "memberId": "123456789",
+            "registrarInfo": { "dateOfBirth": "2012-05-01" },
+            "primaryEmailInfo": { "emailAddress": "sam@example.com" },
+            "primaryAddressInfo": { "address1": "1 Main St", "city": "Town", "state": "MA" }
+        }))
+        .unwrap();
+
+        let full = ExportProfile::Full.youth(&youth);
+        assert!(full.date_of_birth().is_some() && full.address().is_some());
+
+        let leader = ExportProfile::Leader.youth(&youth);
+        assert_eq!(leader.email().as_deref(), Some("sam@example.com"));
+        assert_eq!(leader.member_id.as_deref(), Some("123456789"));
+        assert!(leader.date_of_birth().is_none() && leader.address().is_none());
+
+        let public = ExportProfile::Public.youth(&youth);
+        assert!(public.email().is_none() && public.member_id.is_none());
+        assert_eq!(public.short_name(), "Sam Scout");
+
+        assert_eq!("Public".parse::<ExportProfile>().unwrap(), ExportProfile::Public);
+        assert!("everyone".parse::<ExportProfile>().is_err());
+    }
+
+    /// Keys of a serialized record that hold a value.
+    fn present_keys(value: serde_json::Value) -> Vec<String> {
+        let mut keys: Vec<String> =
+            value.as_object().unwrap().iter().filter(|(_, v)| !v.is_null()).map(|(k, _)| k.clone()).collect();
+        keys.sort();
+        keys
+    }
+
+    #[test]
+    fn test_public_profile_keeps_only_allowed_keys() {
+        let youth: Youth = serde_json::from_value(serde_json::json!({
+            "personGuid": "P1", "memberId": "123456789", "personFullName": "Samuel Q Scout",
+            "userId": 1, "firstName": "Samuel", "middleName": "Quincy", "lastName": "Scout", "nickName": "Sam",
+            "gender": "M", "ethnicity": "Prefer not to say", "grade": 8, "gradeId": 8,
+            "position": "Scribe", "positionId": 7, "programId": 2, "program": "Scouts BSA",
+            "registrarInfo": { "dateOfBirth": "2012-05-01", "registrationStatus": "Current" },
+            "email": "sam@example.com", "phoneNumber": "5555550100",
+            "subUnitName": "Eagles", "subUnitGuid": "PAT1", "isPatrolLeader": false, "currentRankName": "First Class"
+        }))
+        .unwrap();
+        let public = serde_json::to_value(ExportProfile::Public.youth(&youth)).unwrap();
+        assert_eq!(
+            present_keys(public),
+            [
+                "currentRankName", "firstName", "isPatrolLeader", "lastName", "position", "positionId", "program",
+                "programId", "subUnitGuid", "subUnitName", "userId"
+            ]
+        );
+
+        let adult: Adult = serde_json::from_value(serde_json::json!({
+            "personGuid": "P2", "memberId": "987654321", "userId": 10, "firstName": "Dana", "middleName": "Lee",
+            "lastName": "Ortiz", "nickName": "D", "gender": "F", "position": "Scoutmaster", "positionId": 1,
+            "key3": "Scoutmaster", "positionTrained": "Trained", "yptStatus": "Current",
+            "yptCompletedDate": "2025-02-10", "yptExpiredDate": "2027-02-10",
+            "registrarInfo": { "dateOfBirth": "1980-03-01" }, "email": "dana@example.com"
+        }))
+        .unwrap();
+        let public = serde_json::to_value(ExportProfile::Public.adult(&adult)).unwrap();
+        assert_eq!(present_keys(public), ["firstName", "key3", "lastName", "position", "positionId", "userId"]);
+
+        let leader = ExportProfile::Leader.adult(&adult);
+        assert_eq!(leader.ypt_expired_date.as_deref(), Some("2027-02-10"));
+        assert!(leader.registrar_info.is_some_and(|r| r.date_of_birth.is_none()));
+        assert!(ExportProfile::Leader.youth(&youth).ethnicity.is_none());
+    }
+}
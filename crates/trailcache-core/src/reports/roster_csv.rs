@@ -0,0 +1,336 @@
+//! Roster spreadsheets: scouts, adults, or parents as CSV.
+//!
+//! Columns are picked by name (`first,last,email`) or by set (`basic`,
+//! `contact`, `all`). Columns that don't apply to a kind of person, like
+//! Rank for a parent, are left out of that roster. The export profile
+//! blanks anything it doesn't allow.
+
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{bail, Error, Result};
+
+use super::redaction::{ExportField, ExportProfile};
+use crate::models::{Adult, Parent, Youth};
+use crate::utils::PhoneFormat;
+use super::document::Table;
+
+/// Which people a roster lists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RosterKind {
+    Youth,
+    Adults,
+    Parents,
+}
+
+impl RosterKind {
+    pub const ALL: [RosterKind; 3] = [RosterKind::Youth, RosterKind::Adults, RosterKind::Parents];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            RosterKind::Youth => "scouts",
+            RosterKind::Adults => "adults",
+            RosterKind::Parents => "parents",
+        }
+    }
+}
+
+impl fmt::Display for RosterKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+impl FromStr for RosterKind {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "scouts" | "youth" => Ok(RosterKind::Youth),
+            "adults" | "leaders" => Ok(RosterKind::Adults),
+            "parents" => Ok(RosterKind::Parents),
+            _ => bail!("Unknown roster \"{}\" (expected scouts, adults, or parents)", s),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RosterColumn {
+    FirstName,
+    LastName,
+    MemberId,
+    Patrol,
+    Rank,
+    Grade,
+    Age,
+    BirthDate,
+    Position,
+    Trained,
+    YptExpires,
+    Email,
+    Phone,
+    Address,
+    /// For parents, the scout they're listed under
+    Scout,
+    Relationship,
+}
+
+impl RosterColumn {
+    pub const ALL: [RosterColumn; 16] = [
+        RosterColumn::FirstName,
+        RosterColumn::LastName,
+        RosterColumn::MemberId,
+        RosterColumn::Patrol,
+        RosterColumn::Rank,
+        RosterColumn::Grade,
+        RosterColumn::Age,
+        RosterColumn::BirthDate,
+        RosterColumn::Position,
+        RosterColumn::Trained,
+        RosterColumn::YptExpires,
+        RosterColumn::Email,
+        RosterColumn::Phone,
+        RosterColumn::Address,
+        RosterColumn::Scout,
+        RosterColumn::Relationship,
+    ];
+
+    /// Name used on the command line
+    pub fn key(&self) -> &'static str {
+        match self {
+            RosterColumn::FirstName => "first",
+            RosterColumn::LastName => "last",
+            RosterColumn::MemberId => "id",
+            RosterColumn::Patrol => "patrol",
+            RosterColumn::Rank => "rank",
+            RosterColumn::Grade => "grade",
+            RosterColumn::Age => "age",
+            RosterColumn::BirthDate => "birthdate",
+            RosterColumn::Position => "position",
+            RosterColumn::Trained => "trained",
+            RosterColumn::YptExpires => "ypt",
+            RosterColumn::Email => "email",
+            RosterColumn::Phone => "phone",
+            RosterColumn::Address => "address",
+            RosterColumn::Scout => "scout",
+            RosterColumn::Relationship => "relationship",
+        }
+    }
+
+    /// Heading in the CSV file
+    pub fn header(&self) -> &'static str {
+        match self {
+            RosterColumn::FirstName => "First Name",
+            RosterColumn::LastName => "Last Name",
+            RosterColumn::MemberId => "BSA ID",
+            RosterColumn::Patrol => "Patrol",
+            RosterColumn::Rank => "Rank",
+            RosterColumn::Grade => "Grade",
+            RosterColumn::Age => "Age",
+            RosterColumn::BirthDate => "Birth Date",
+            RosterColumn::Position => "Position",
+            RosterColumn::Trained => "Position Trained",
+            RosterColumn::YptExpires => "YPT Expires",
+            RosterColumn::Email => "Email",
+            RosterColumn::Phone => "Phone",
+            RosterColumn::Address => "Address",
+            RosterColumn::Scout => "Scout",
+            RosterColumn::Relationship => "Relationship",
+        }
+    }
+
+    pub fn applies_to(&self, kind: RosterKind) -> bool {
+        use RosterColumn::*;
+        match kind {
+            RosterKind::Youth => !matches!(self, Trained | YptExpires | Scout | Relationship),
+            RosterKind::Adults => !matches!(self, Patrol | Rank | Grade | Age | BirthDate | Scout | Relationship),
+            RosterKind::Parents => matches!(self, FirstName | LastName | MemberId | Email | Phone | Address | Scout | Relationship),
+        }
+    }
+
+    /// The profile field that must be allowed for this column to be filled
+    fn export_field(&self) -> Option<ExportField> {
+        match self {
+            RosterColumn::MemberId => Some(ExportField::MemberId),
+            RosterColumn::Age => Some(ExportField::Age),
+            RosterColumn::BirthDate => Some(ExportField::BirthDate),
+            RosterColumn::Email | RosterColumn::Phone => Some(ExportField::Contact),
+            RosterColumn::Address => Some(ExportField::Address),
+            RosterColumn::Grade | RosterColumn::Trained | RosterColumn::YptExpires => Some(ExportField::Personal),
+            _ => None,
+        }
+    }
+
+    /// Columns from a set name (`basic`, `contact`, `all`) or a
+    /// comma-separated list of column keys.
+    pub fn parse_list(spec: &str) -> Result<Vec<RosterColumn>> {
+        use RosterColumn::*;
+        match spec.to_lowercase().as_str() {
+            "basic" => return Ok(vec![FirstName, LastName, Patrol, Rank, Position, Scout, Relationship]),
+            "contact" => return Ok(vec![FirstName, LastName, Scout, Relationship, Email, Phone, Address]),
+            "all" => return Ok(Self::ALL.to_vec()),
+            _ => {}
+        }
+        spec.split(',')
+            .map(str::trim)
+            .filter(|key| !key.is_empty())
+            .map(|key| match Self::ALL.into_iter().find(|c| c.key().eq_ignore_ascii_case(key)) {
+                Some(column) => Ok(column),
+                None => {
+                    let keys: Vec<_> = Self::ALL.iter().map(|c| c.key()).collect();
+                    bail!("Unknown roster column \"{}\" (expected basic, contact, all, or some of {})", key, keys.join(","))
+                }
+            })
+            .collect()
+    }
+}
+
+/// One roster as CSV text, header line first.
+pub struct RosterCsv {
+    columns: Vec<RosterColumn>,
+    profile: ExportProfile,
+    phone_format: PhoneFormat,
+    table: Table,
+}
+
+impl RosterCsv {
+    /// Start a roster of `kind` with the `columns` that apply to it, phone
+    /// numbers written in `phone_format`.
+    pub fn new(kind: RosterKind, columns: &[RosterColumn], profile: ExportProfile, phone_format: PhoneFormat) -> Self {
+        let columns: Vec<_> = columns.iter().copied().filter(|c| c.applies_to(kind)).collect();
+        let table = Table::new(columns.iter().map(|c| c.header()));
+        Self { table, columns, profile, phone_format }
+    }
+
+    pub fn youth(mut self, youth: &[Youth]) -> Self {
+        let phone_format = self.phone_format;
+        for original in youth {
+            let y = &self.profile.youth(original);
+            self.push(|column| match column {
+                RosterColumn::FirstName => Some(y.first_name.clone()),
+                RosterColumn::LastName => Some(y.last_name.clone()),
+                RosterColumn::MemberId => y.member_id.clone(),
+                RosterColumn::Patrol => y.patrol_name.clone(),
+                RosterColumn::Rank => y.current_rank.clone(),
+                RosterColumn::Grade => y.grade.map(|g| g.to_string()),
+                // The profile may drop the birth date but keep the age
+                RosterColumn::Age => original.age().map(|a| a.to_string()),
+                RosterColumn::BirthDate => y.date_of_birth().map(|d| d.to_string()),
+                RosterColumn::Position => y.position.clone(),
+                RosterColumn::Email => y.email(),
+                RosterColumn::Phone => y.phone(phone_format),
+                RosterColumn::Address => y.address(),
+                _ => None,
+            });
+        }
+        self
+    }
+
+    pub fn adults(mut self, adults: &[Adult]) -> Self {
+        let phone_format = self.phone_format;
+        for a in adults {
+            let a = &self.profile.adult(a);
+            self.push(|column| match column {
+                RosterColumn::FirstName => Some(a.first_name.clone()),
+                RosterColumn::LastName => Some(a.last_name.clone()),
+                RosterColumn::MemberId => a.member_id.clone(),
+                RosterColumn::Position => Some(a.role()),
+                RosterColumn::Trained => Some(a.position_trained_display().to_string()),
+                RosterColumn::YptExpires => a.ypt_expired_date.as_deref().map(|d| d.get(..10).unwrap_or(d).to_string()),
+                RosterColumn::Email => a.email(),
+                RosterColumn::Phone => a.phone(phone_format),
+                RosterColumn::Address => a.primary_address_info.as_ref().and_then(|addr| addr.formatted()),
+                _ => None,
+            });
+        }
+        self
+    }
+
+    pub fn parents(mut self, parents: &[Parent]) -> Self {
+        let phone_format = self.phone_format;
+        for p in parents {
+            self.push(|column| match column {
+                RosterColumn::FirstName => Some(p.first_name.clone()),
+                RosterColumn::LastName => Some(p.last_name.clone()),
+                RosterColumn::MemberId => p.member_id.clone(),
+                RosterColumn::Email => p.email.clone(),
+                RosterColumn::Phone => p.phone(phone_format),
+                RosterColumn::Address => p.address_line(),
+                RosterColumn::Scout => p.youth_name(),
+                RosterColumn::Relationship => p.relationship.clone(),
+                _ => None,
+            });
+        }
+        self
+    }
+
+    /// Number of people written so far
+    pub fn len(&self) -> usize {
+        self.table.rows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The CSV text, one line per person, with a trailing newline.
+    pub fn finish(self) -> String {
+        self.table.to_csv()
+    }
+
+    /// The roster as a table, for output other than CSV.
+    pub fn into_table(self) -> Table {
+        self.table
+    }
+
+    fn push(&mut self, value: impl Fn(RosterColumn) -> Option<String>) {
+        let profile = self.profile;
+        let fields: Vec<String> = self
+            .columns
+            .iter()
+            .map(|&column| {
+                let allowed = column.export_field().is_none_or(|field| profile.includes(field));
+                if allowed { value(column).unwrap_or_default() } else { String::new() }
+            })
+            .collect();
+        self.table.row(fields);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roster_csv_columns_and_profiles() {
+        let youth: Youth = serde_json::from_value(serde_json::json!({
+            "userId": 1, "firstName": "Sam", "lastName": "Scout", "memberId": "123456789",
+            "subUnitName": "Eagles", "currentRankName": "First Class",
+            "primaryEmailInfo": { "emailAddress": "sam@example.com" }
+        }))
+        .unwrap();
+        let parent: Parent = serde_json::from_value(serde_json::json!({
+            "userId": 9, "firstName": "Pat", "lastName": "Scout", "email": "pat@example.com",
+            "youthFirstName": "Sam", "youthLastName": "Scout", "relationship": "Mother, Step"
+        }))
+        .unwrap();
+
+        let columns = RosterColumn::parse_list("first,last,patrol,email,scout").unwrap();
+        let csv = RosterCsv::new(RosterKind::Youth, &columns, ExportProfile::Leader, PhoneFormat::Parens).youth(std::slice::from_ref(&youth)).finish();
+        assert_eq!(csv, "First Name,Last Name,Patrol,Email\nSam,Scout,Eagles,sam@example.com\n");
+
+        let csv = RosterCsv::new(RosterKind::Youth, &columns, ExportProfile::Public, PhoneFormat::Parens).youth(&[youth]).finish();
+        assert_eq!(csv.lines().nth(1), Some("Sam,Scout,Eagles,"));
+
+        let contact = RosterColumn::parse_list("contact").unwrap();
+        let roster = RosterCsv::new(RosterKind::Parents, &contact, ExportProfile::Leader, PhoneFormat::Parens).parents(&[parent]);
+        assert_eq!(roster.len(), 1);
+        assert_eq!(
+            roster.finish(),
+            "First Name,Last Name,Scout,Relationship,Email,Phone,Address\nPat,Scout,Sam Scout,\"Mother, Step\",pat@example.com,,\n"
+        );
+
+        assert!(RosterColumn::parse_list("first,shoe_size").is_err());
+        assert_eq!("leaders".parse::<RosterKind>().unwrap(), RosterKind::Adults);
+    }
+}
@@ -0,0 +1,129 @@
+//! Handbook-style rank sign-off sheets.
+//!
+//! One page per scout and rank, listing each requirement's number and short
+//! text with space for the date and a leader's initials, like the
+//! requirement pages in the Scouts BSA handbook. For troops that sign off on
+//! paper and transcribe into Scoutbook later. Requirements already recorded
+//! in Scoutbook are filled in so nobody signs them twice. Sub-requirements
+//! are indented under their parent, and a parent whose parts are all signed
+//! counts as done.
+
+use chrono::NaiveDate;
+
+use super::document::{Section, Table};
+use crate::models::{sort_requirements, RankRequirement, RequirementTree, Youth};
+use crate::utils::strip_html;
+
+/// Space left for a handwritten date
+const DATE_BLANK: &str = "__________";
+
+/// Space left for handwritten initials
+const INITIALS_BLANK: &str = "______";
+
+/// Indent per level of sub-requirement; non-breaking so HTML keeps it
+const NEST_INDENT: &str = "\u{a0}\u{a0}\u{a0}\u{a0}";
+
+/// One scout's sheet for one rank.
+pub struct SignoffSheet<'a> {
+    pub youth: &'a Youth,
+    pub rank: &'a str,
+    /// The rank's requirements from the cache; empty if not cached
+    pub requirements: &'a [RankRequirement],
+}
+
+/// A section per sheet, each a page when printed as HTML.
+pub fn signoff_sections(sheets: &[SignoffSheet]) -> Vec<Section> {
+    sheets.iter().map(signoff_section).collect()
+}
+
+fn signoff_section(sheet: &SignoffSheet) -> Section {
+    let mut section = Section::new(format!("{} - {}", sheet.youth.full_name(), sheet.rank));
+    section.bullet(format!("Patrol: {}", sheet.youth.patrol()));
+
+    if sheet.requirements.is_empty() {
+        section.bullet("Requirements not cached. Open this rank in trailcache while online, then export again.");
+        return section;
+    }
+
+    let mut requirements = sheet.requirements.to_vec();
+    sort_requirements(&mut requirements);
+    let (completed, total) = RankRequirement::completion_count(&requirements);
+    section.bullet(format!("{} of {} already recorded in Scoutbook", completed, total));
+
+    let tree = RequirementTree::build(&requirements);
+    let is_completed = |i: usize| requirements[i].is_completed();
+    let mut table = Table::new(["Req", "Requirement", "Date", "Initials"]);
+    for (i, req) in requirements.iter().enumerate() {
+        let (date, initials) = if req.is_completed() {
+            (completed_date(req), leader_initials(req))
+        } else if let Some((done, parts)) = tree.rollup(i, &is_completed) {
+            // Signed off through its parts, not on its own line
+            (format!("{} of {} parts", done, parts), String::new())
+        } else {
+            (DATE_BLANK.to_string(), INITIALS_BLANK.to_string())
+        };
+        let text = NEST_INDENT.repeat(tree.depth(i)) + &strip_html(&req.text());
+        table.row([tree.node(i).number.clone(), text, date, initials]);
+    }
+    section.table(table);
+    section
+}
+
+/// Date the requirement was completed, as written in the handbook.
+fn completed_date(req: &RankRequirement) -> String {
+    req.date_completed
+        .as_deref()
+        .or(req.leader_approved_date.as_deref())
+        .and_then(|d| NaiveDate::parse_from_str(d.get(..10)?, "%Y-%m-%d").ok())
+        .map(|d| d.format("%m/%d/%y").to_string())
+        .unwrap_or_else(|| "done".to_string())
+}
+
+/// Initials of the leader who approved it in Scoutbook, if known.
+fn leader_initials(req: &RankRequirement) -> String {
+    [&req.leader_approved_first_name, &req.leader_approved_last_name]
+        .into_iter()
+        .filter_map(|name| name.as_deref()?.chars().next())
+        .flat_map(char::to_uppercase)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reports::{to_text, TextLayout};
+
+    #[test]
+    fn test_signoff_sheet() {
+        let youth: Youth = serde_json::from_value(serde_json::json!({
+            "userId": 1, "firstName": "Sam", "lastName": "Scout", "subUnitName": "Eagles"
+        }))
+        .unwrap();
+        let requirements: Vec<RankRequirement> = serde_json::from_value(serde_json::json!([
+            {"id": 2, "listNumber": "10", "short": "Tie a <b>square knot</b>"},
+            {"id": 1, "listNumber": "2", "short": "Pack for a campout", "completed": true,
+             "dateCompleted": "2026-04-18T00:00:00", "leaderApprovedFirstName": "alex", "leaderApprovedLastName": "Leader"},
+            {"id": 3, "listNumber": "3", "short": "Do the following:"},
+            {"id": 4, "listNumber": "a", "short": "Whip a rope", "completed": true},
+            {"id": 5, "listNumber": "b", "short": "Fuse a rope"}
+        ]))
+        .unwrap();
+
+        let sheets = [
+            SignoffSheet { youth: &youth, rank: "Scout", requirements: &requirements },
+            SignoffSheet { youth: &youth, rank: "Tenderfoot", requirements: &[] },
+        ];
+        let sections = signoff_sections(&sheets);
+        assert_eq!(sections[0].title, "Sam Scout - Scout");
+        let rows = &sections[0].tables[0].rows;
+        assert_eq!(rows[0], ["2", "Pack for a campout", "04/18/26", "AL"]);
+        assert_eq!(rows[1], ["3", "Do the following:", "1 of 2 parts", ""]);
+        assert_eq!(rows[3], ["3b", "\u{a0}\u{a0}\u{a0}\u{a0}Fuse a rope", DATE_BLANK, INITIALS_BLANK]);
+        assert_eq!(rows[4], ["10", "Tie a square knot", DATE_BLANK, INITIALS_BLANK]);
+        assert!(sections[1].tables.is_empty());
+
+        let layout = TextLayout { page_lines: None, ..TextLayout::default() };
+        let text = to_text("Sign-off Sheets", &sections, layout);
+        assert!(text.contains("2 of 5 already recorded in Scoutbook"));
+    }
+}
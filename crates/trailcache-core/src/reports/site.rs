@@ -0,0 +1,282 @@
+//! Static HTML mini-site generated from cached troop data.
+//!
+//! Produces a handful of linked pages (overview, roster, calendar,
+//! advancement) meant for a family-only site. Under the public export
+//! profile the roster leaves out contact details, birth dates, addresses,
+//! and member IDs; the leader and full profiles add email and phone columns.
+//!
+//! Pages are filled into a template with `{{title}}`, `{{unit}}`, `{{nav}}`,
+//! `{{content}}`, and `{{generated}}` placeholders. `DEFAULT_SITE_TEMPLATE`
+//! is used unless a custom template is supplied.
+
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+
+use super::document::{escape_html, sections_html, Section, Table};
+use super::redaction::{ExportField, ExportProfile};
+use crate::models::{Adult, Event, RankProgress, ScoutRank, Youth};
+use crate::utils::PhoneFormat;
+
+/// Upcoming events are listed this many days ahead
+const CALENDAR_DAYS_AHEAD: i64 = 120;
+
+/// Ranks awarded within this many days appear under recent advancement
+const RECENT_ADVANCEMENT_DAYS: i64 = 90;
+
+/// File name of a user-supplied template, looked up next to the config file
+pub const SITE_TEMPLATE_FILE: &str = "site-template.html";
+
+pub const DEFAULT_SITE_TEMPLATE: &str = r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<meta name="robots" content="noindex">
+<title>{{title}} - {{unit}}</title>
+<style>
+body{font-family:sans-serif;max-width:960px;margin:auto;padding:1em;color:#222}
+nav a{margin-right:1em}
+table{border-collapse:collapse;margin:1em 0}
+th,td{border:1px solid #bbb;padding:4px 10px;text-align:left}
+th{background:#eee}
+footer{margin-top:2em;color:#777;font-size:small}
+</style>
+</head>
+<body>
+<header><h1>{{unit}}</h1><nav>{{nav}}</nav></header>
+<main>
+{{content}}
+</main>
+<footer>Generated {{generated}}</footer>
+</body>
+</html>
+"#;
+
+/// Cached data the site is built from.
+pub struct SiteInput<'a> {
+    pub unit_name: &'a str,
+    pub youth: &'a [Youth],
+    pub adults: &'a [Adult],
+    pub events: &'a [Event],
+    pub ranks: &'a HashMap<i64, Vec<RankProgress>>,
+    pub profile: ExportProfile,
+    pub phone_format: PhoneFormat,
+}
+
+/// A generated page: file name and HTML.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SitePage {
+    pub file_name: &'static str,
+    pub html: String,
+}
+
+/// Page file names and nav titles, in nav order
+const PAGES: [(&str, &str); 4] = [
+    ("index.html", "Overview"),
+    ("roster.html", "Roster"),
+    ("calendar.html", "Calendar"),
+    ("advancement.html", "Advancement"),
+];
+
+/// Build every page of the site. `template` defaults to `DEFAULT_SITE_TEMPLATE`.
+pub fn build_site(input: &SiteInput, today: NaiveDate, template: Option<&str>) -> Vec<SitePage> {
+    let template = template.unwrap_or(DEFAULT_SITE_TEMPLATE);
+    let nav: String = PAGES
+        .iter()
+        .map(|(file, title)| format!("<a href=\"{}\">{}</a>", file, title))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let contents = [
+        overview(input, today),
+        roster(input),
+        calendar(input, today),
+        advancement(input, today),
+    ];
+
+    PAGES
+        .iter()
+        .zip(contents)
+        .map(|(&(file_name, title), sections)| SitePage {
+            file_name,
+            html: template
+                .replace("{{title}}", &escape_html(title))
+                .replace("{{unit}}", &escape_html(input.unit_name))
+                .replace("{{nav}}", &nav)
+                .replace("{{generated}}", &today.format("%B %-d, %Y").to_string())
+                .replace("{{content}}", &sections_html(&sections)),
+        })
+        .collect()
+}
+
+fn overview(input: &SiteInput, today: NaiveDate) -> Vec<Section> {
+    let mut section = Section::new("Troop at a Glance");
+    section.bullet(format!("{} scouts, {} adult leaders", input.youth.len(), input.adults.len()));
+    let upcoming = upcoming_events(input.events, today);
+    if let Some((date, event)) = upcoming.first() {
+        section.bullet(format!("Next event: {} on {}", event.name, date.format("%a %b %-d")));
+    }
+    section.bullet(format!("{} events in the next {} days", upcoming.len(), CALENDAR_DAYS_AHEAD));
+    vec![section]
+}
+
+fn roster(input: &SiteInput) -> Vec<Section> {
+    let mut youth: Vec<&Youth> = input.youth.iter().collect();
+    youth.sort_by(|a, b| a.patrol().cmp(&b.patrol()).then_with(|| a.last_name.cmp(&b.last_name)));
+
+    let contact = input.profile.includes(ExportField::Contact);
+    let with_contact = |mut columns: Vec<&'static str>| {
+        if contact {
+            columns.extend(["Email", "Phone"]);
+        }
+        Table::new(columns)
+    };
+
+    let mut scouts = Section::new("Scouts");
+    let mut table = with_contact(vec!["Name", "Patrol", "Rank", "Position"]);
+    for y in youth {
+        let mut row = vec![y.short_name(), y.patrol(), y.rank(), y.position_display().unwrap_or_default()];
+        if contact {
+            row.extend([y.email().unwrap_or_default(), y.phone(input.phone_format).unwrap_or_default()]);
+        }
+        table.row(row);
+    }
+    scouts.table(table);
+
+    let mut leaders = Section::new("Leaders");
+    let mut table = with_contact(vec!["Name", "Position"]);
+    let mut adults: Vec<&Adult> = input.adults.iter().collect();
+    adults.sort_by(|a, b| a.last_name.cmp(&b.last_name));
+    for a in adults {
+        let mut row = vec![a.full_name(), a.role()];
+        if contact {
+            row.extend([a.email().unwrap_or_default(), a.phone(input.phone_format).unwrap_or_default()]);
+        }
+        table.row(row);
+    }
+    leaders.table(table);
+
+    vec![scouts, leaders]
+}
+
+fn upcoming_events(events: &[Event], today: NaiveDate) -> Vec<(NaiveDate, &Event)> {
+    let mut upcoming: Vec<(NaiveDate, &Event)> = events
+        .iter()
+        .filter_map(|e| {
+            let date = e.start_date.as_deref()?.get(..10)?;
+            let date = NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()?;
+            (0..=CALENDAR_DAYS_AHEAD).contains(&(date - today).num_days()).then_some((date, e))
+        })
+        .collect();
+    upcoming.sort_by_key(|(date, _)| *date);
+    upcoming
+}
+
+fn calendar(input: &SiteInput, today: NaiveDate) -> Vec<Section> {
+    let mut section = Section::new("Upcoming Events");
+    let upcoming = upcoming_events(input.events, today);
+    if upcoming.is_empty() {
+        section.bullet("No upcoming events");
+    }
+    let mut table = Table::new(["Date", "Event", "Location"]);
+    for (date, event) in upcoming {
+        table.row([
+            date.format("%a %b %-d").to_string(),
+            event.name.clone(),
+            event.location.clone().unwrap_or_default(),
+        ]);
+    }
+    section.table(table);
+    vec![section]
+}
+
+fn advancement(input: &SiteInput, today: NaiveDate) -> Vec<Section> {
+    let mut ranks = Section::new("Scouts by Rank");
+    let mut table = Table::new(["Rank", "Scouts"]);
+    for rank in ScoutRank::all_display_order() {
+        let count = input
+            .youth
+            .iter()
+            .filter(|y| ScoutRank::parse(y.current_rank.as_deref()) == *rank)
+            .count();
+        if count > 0 {
+            table.row([rank.display_name().to_string(), count.to_string()]);
+        }
+    }
+    ranks.table(table);
+
+    let mut recent = Section::new("Recent Rank Advancement");
+    let mut awarded: Vec<(NaiveDate, String, &str)> = Vec::new();
+    for youth in input.youth {
+        let Some(progress) = youth.user_id.and_then(|id| input.ranks.get(&id)) else { continue };
+        for rank in progress {
+            let Some(date) = rank.date_awarded.as_deref().and_then(|d| d.get(..10)) else { continue };
+            let Ok(date) = NaiveDate::parse_from_str(date, "%Y-%m-%d") else { continue };
+            if (0..=RECENT_ADVANCEMENT_DAYS).contains(&(today - date).num_days()) {
+                awarded.push((date, youth.short_name(), &rank.rank_name));
+            }
+        }
+    }
+    awarded.sort_by_key(|a| std::cmp::Reverse(a.0));
+    if awarded.is_empty() {
+        recent.bullet(format!("No ranks awarded in the last {} days", RECENT_ADVANCEMENT_DAYS));
+    }
+    let mut table = Table::new(["Date", "Scout", "Rank"]);
+    for (date, name, rank) in awarded {
+        table.row([date.format("%b %-d").to_string(), name, rank.to_string()]);
+    }
+    recent.table(table);
+
+    vec![ranks, recent]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_site_omits_contact_details() {
+        let youth: Vec<Youth> = serde_json::from_value(serde_json::json!([{
+            "userId": 1, "firstName": "Sam", "lastName": "Scout", "subUnitName": "Eagles",
+            "currentRankName": "Star", "email": "sam@example.com", "dateOfBirth": "2012-01-01",
+            "memberId": "123456789"
+        }]))
+        .unwrap();
+        let events: Vec<Event> = serde_json::from_value(serde_json::json!([
+            { "id": 1, "name": "Fall <Campout>", "startDate": "2026-10-20T18:00:00", "location": "Camp" },
+            { "id": 2, "name": "Last Year", "startDate": "2025-10-20T18:00:00" }
+        ]))
+        .unwrap();
+        let mut input = SiteInput {
+            unit_name: "Troop 42",
+            youth: &youth,
+            adults: &[],
+            events: &events,
+            ranks: &HashMap::new(),
+            profile: ExportProfile::Public,
+            phone_format: PhoneFormat::Parens,
+        };
+
+        let pages = build_site(&input, NaiveDate::from_ymd_opt(2026, 10, 1).unwrap(), None);
+        assert_eq!(pages.len(), 4);
+        assert_eq!(pages[0].file_name, "index.html");
+
+        let all: String = pages.iter().map(|p| p.html.as_str()).collect();
+        assert!(all.contains("<td>Sam Scout</td><td>Eagles</td><td>Star</td>"));
+        assert!(!all.contains("sam@example.com"));
+        assert!(!all.contains("123456789"));
+        assert!(!all.contains("2012"));
+
+        let calendar = &pages[2].html;
+        assert!(calendar.contains("Fall &lt;Campout&gt;"));
+        assert!(!calendar.contains("Last Year"));
+
+        let custom = build_site(&input, NaiveDate::from_ymd_opt(2026, 10, 1).unwrap(), Some("<h1>{{title}}</h1>"));
+        assert_eq!(custom[1].html, "<h1>Roster</h1>");
+
+        input.profile = ExportProfile::Leader;
+        let roster = &build_site(&input, NaiveDate::from_ymd_opt(2026, 10, 1).unwrap(), None)[1].html;
+        assert!(roster.contains("<td>sam@example.com</td>"));
+    }
+}
@@ -0,0 +1,153 @@
+//! Advancement, expiration, and stale grade reports as plain tables.
+//!
+//! These back the headless `trailcache report` commands, which print them
+//! as CSV or JSON for scheduled jobs and spreadsheets.
+
+use std::collections::HashMap;
+
+use chrono::{Duration, NaiveDate};
+
+use super::conference::{current_rank, next_rank};
+use super::document::Table;
+use crate::models::{Adult, GradeRollover, MeritBadgeProgress, RankProgress, Youth};
+
+fn date_only(date: &str) -> String {
+    date.get(..10).unwrap_or(date).to_string()
+}
+
+/// One row per scout: current rank and when, the next rank and how far
+/// along, and merit badge counts.
+pub fn advancement_table(
+    youth: &[Youth],
+    ranks: &HashMap<i64, Vec<RankProgress>>,
+    badges: &HashMap<i64, Vec<MeritBadgeProgress>>,
+) -> Table {
+    let mut table = Table::new([
+        "First Name",
+        "Last Name",
+        "Patrol",
+        "Rank",
+        "Rank Date",
+        "Next Rank",
+        "Next Rank Progress",
+        "Merit Badges",
+        "Eagle Required",
+        "Badges In Progress",
+    ]);
+    for y in youth {
+        let ranks = y.user_id.and_then(|id| ranks.get(&id)).map(Vec::as_slice).unwrap_or_default();
+        let current = current_rank(ranks);
+        let next = next_rank(ranks);
+        let summary = MeritBadgeProgress::summarize(y.user_id.and_then(|id| badges.get(&id)).map(Vec::as_slice).unwrap_or_default());
+        table.row([
+            y.first_name.clone(),
+            y.last_name.clone(),
+            y.patrol_name.clone().unwrap_or_default(),
+            current.map(|r| r.rank_name.clone()).or_else(|| y.current_rank.clone()).unwrap_or_default(),
+            current
+                .and_then(|r| r.date_awarded.as_deref().or(r.date_completed.as_deref()))
+                .map(date_only)
+                .unwrap_or_default(),
+            next.map(|r| r.rank_name.clone()).unwrap_or_default(),
+            next.and_then(|r| r.progress_percent()).map(|p| format!("{}%", p)).unwrap_or_default(),
+            summary.completed.to_string(),
+            summary.eagle_completed.to_string(),
+            summary.in_progress.to_string(),
+        ]);
+    }
+    table
+}
+
+/// Registrations and Youth Protection training that have expired or expire
+/// within `days`, soonest first.
+pub fn expiring_table(youth: &[Youth], adults: &[Adult], today: NaiveDate, days: i64) -> Table {
+    let parse = |d: &str| NaiveDate::parse_from_str(&date_only(d), "%Y-%m-%d").ok();
+    let mut items: Vec<(NaiveDate, String, &str, &str)> = Vec::new();
+    for y in youth {
+        if let Some(date) = y.registration_expires().as_deref().and_then(parse) {
+            items.push((date, y.full_name(), "Scout", "Registration"));
+        }
+    }
+    for a in adults {
+        if let Some(date) = a.registration_expires_on() {
+            items.push((date, a.full_name(), "Adult", "Registration"));
+        }
+        if let Some(date) = a.ypt_expires() {
+            items.push((date, a.full_name(), "Adult", "Youth Protection"));
+        }
+    }
+    let cutoff = today + Duration::days(days);
+    items.retain(|(date, ..)| *date <= cutoff);
+    items.sort();
+
+    let mut table = Table::new(["Name", "Member Type", "Item", "Expires", "Days Left"]);
+    for (date, name, kind, item) in items {
+        table.row([name, kind.to_string(), item.to_string(), date.to_string(), (date - today).num_days().to_string()]);
+    }
+    table
+}
+
+/// Scouts whose Scoutbook grade looks a school year or more behind, to
+/// fix in Scoutbook.
+pub fn grade_table(youth: &[Youth], rollover: GradeRollover, today: NaiveDate) -> Table {
+    let mut stale: Vec<(&Youth, i32, i32)> = youth
+        .iter()
+        .filter_map(|y| y.stale_grade(rollover, today).map(|s| (y, s.recorded, s.expected)))
+        .collect();
+    stale.sort_by_key(|(y, ..)| (y.last_name.to_lowercase(), y.first_name.to_lowercase()));
+
+    let mut table = Table::new(["First Name", "Last Name", "Patrol", "Scoutbook Grade", "Expected Grade"]);
+    for (y, recorded, expected) in stale {
+        table.row([
+            y.first_name.clone(),
+            y.last_name.clone(),
+            y.patrol_name.clone().unwrap_or_default(),
+            recorded.to_string(),
+            expected.to_string(),
+        ]);
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expiring_and_advancement_tables() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 1).unwrap();
+        let youth: Vec<Youth> = serde_json::from_value(serde_json::json!([
+            { "userId": 1, "firstName": "Sam", "lastName": "Ortiz", "currentRankName": "Tenderfoot",
+              "registrarInfo": { "registrationExpireDt": "2026-12-01T00:00:00" } }
+        ]))
+        .unwrap();
+        let adults: Vec<Adult> = serde_json::from_value(serde_json::json!([
+            { "firstName": "Pat", "lastName": "Lee", "yptExpiredDate": "2026-09-15",
+              "registrarInfo": { "registrationExpireDt": "2027-06-30" } }
+        ]))
+        .unwrap();
+
+        let expiring = expiring_table(&youth, &adults, today, 90);
+        assert_eq!(expiring.rows.len(), 2, "the adult's registration is months out");
+        assert_eq!(expiring.rows[0], ["Pat Lee", "Adult", "Youth Protection", "2026-09-15", "-16"]);
+        assert_eq!(expiring.rows[1], ["Sam Ortiz", "Scout", "Registration", "2026-12-01", "61"]);
+        assert!(expiring.to_csv().starts_with("Name,Member Type,Item,Expires,Days Left\n"));
+
+        let advancement = advancement_table(&youth, &HashMap::new(), &HashMap::new());
+        let json = advancement.to_json();
+        assert_eq!(json[0]["Rank"], "Tenderfoot", "falls back to the roster rank");
+        assert_eq!(json[0]["Merit Badges"], "0");
+    }
+
+    #[test]
+    fn test_grade_table() {
+        let youth: Vec<Youth> = serde_json::from_value(serde_json::json!([
+            { "firstName": "Sam", "lastName": "Ortiz", "grade": 6, "registrarInfo": { "dateOfBirth": "2014-03-02" } },
+            { "firstName": "Ana", "lastName": "Diaz", "grade": 7, "registrarInfo": { "dateOfBirth": "2014-05-20" } },
+            { "firstName": "Lee", "lastName": "Park", "grade": 8 }
+        ]))
+        .unwrap();
+        let table = grade_table(&youth, GradeRollover::default(), NaiveDate::from_ymd_opt(2026, 10, 1).unwrap());
+        assert_eq!(table.rows, [["Sam", "Ortiz", "", "6", "7"]]);
+    }
+}
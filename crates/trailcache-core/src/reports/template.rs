@@ -0,0 +1,455 @@
+//! Custom report templates.
+//!
+//! Units write their own reports as templates in the `report-templates`
+//! folder next to the config file and render them with
+//! `trailcache report custom <name>`. Templates use a small subset of Jinja:
+//!
+//! - `{{ scout.name }}`, `{{ scouts | length }}`: values, with `length`,
+//!   `upper`, `lower`, `escape`, and `default("text")` filters
+//! - `{% for scout in scouts %}...{% endfor %}`, with `loop.index` and
+//!   `loop.last` inside the loop
+//! - `{% if scout.rank %}...{% else %}...{% endif %}`, and `if not ...`
+//! - `{# comments #}`
+//!
+//! Tags alone on a line don't leave a blank line behind. Missing values
+//! render as empty. `template_context` lays out the data templates can use.
+
+use std::collections::BTreeMap;
+
+use anyhow::{anyhow, bail, Result};
+use chrono::NaiveDate;
+use serde_json::{json, Value};
+
+use super::document::escape_html;
+use super::redaction::{ExportField, ExportProfile};
+use crate::models::{Adult, Event, RsvpStatus, Youth};
+use crate::utils::PhoneFormat;
+
+/// Folder of user templates, looked up next to the config file
+pub const REPORT_TEMPLATES_DIR: &str = "report-templates";
+
+#[derive(Debug, Clone, PartialEq)]
+enum Filter {
+    Length,
+    Upper,
+    Lower,
+    Escape,
+    Default(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Expr {
+    path: Vec<String>,
+    filters: Vec<Filter>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    Text(String),
+    Value(Expr),
+    For { var: String, list: Expr, body: Vec<Node> },
+    If { cond: Expr, negate: bool, then: Vec<Node>, otherwise: Vec<Node> },
+}
+
+enum Token {
+    Text(String),
+    Value(String, usize),
+    Tag(String, usize),
+}
+
+fn tokenize(source: &str) -> Result<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut rest = source;
+    let mut line = 1;
+    // Whether `rest` begins at the start of a line
+    let mut at_line_start = true;
+    while let Some(start) = rest.find('{') {
+        let kind = rest[start..].chars().nth(1);
+        let close = match kind {
+            Some('{') => "}}",
+            Some('%') => "%}",
+            Some('#') => "#}",
+            _ => {
+                tokens.push(Token::Text(rest[..=start].to_string()));
+                line += rest[..=start].matches('\n').count();
+                rest = &rest[start + 1..];
+                at_line_start = false;
+                continue;
+            }
+        };
+        let text = &rest[..start];
+        let line_start = text.rfind('\n').map_or(0, |i| i + 1);
+        let starts_line = (line_start > 0 || at_line_start) && text[line_start..].chars().all(|c| c == ' ' || c == '\t');
+        line += rest[..start].matches('\n').count();
+
+        let body_start = start + 2;
+        let end = rest[body_start..]
+            .find(close)
+            .ok_or_else(|| anyhow!("Line {}: unclosed {}", line, &rest[start..body_start]))?
+            + body_start;
+        let body = rest[body_start..end].trim().to_string();
+        let tag_line = line;
+        line += rest[start..end].matches('\n').count();
+        rest = &rest[end + 2..];
+
+        // A tag alone on its line is dropped along with the line
+        let alone = kind != Some('{') && starts_line && (rest.is_empty() || rest.starts_with(['\n', '\r']));
+        tokens.push(Token::Text(if alone { &text[..line_start] } else { text }.to_string()));
+        at_line_start = false;
+        if alone {
+            if let Some(stripped) = rest.strip_prefix("\r\n").or_else(|| rest.strip_prefix('\n')) {
+                rest = stripped;
+                line += 1;
+                at_line_start = true;
+            }
+        }
+        match kind {
+            Some('{') => tokens.push(Token::Value(body, tag_line)),
+            Some('%') => tokens.push(Token::Tag(body, tag_line)),
+            _ => {}
+        }
+    }
+    tokens.push(Token::Text(rest.to_string()));
+    Ok(tokens)
+}
+
+fn parse_expr(text: &str, line: usize) -> Result<Expr> {
+    let mut parts = text.split('|').map(str::trim);
+    let path_text = parts.next().unwrap_or_default();
+    if path_text.is_empty() || !path_text.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '.') {
+        bail!("Line {}: can't read \"{}\"", line, text);
+    }
+    let path = path_text.split('.').map(str::to_string).collect();
+    let filters = parts
+        .map(|f| {
+            Ok(match f {
+                "length" => Filter::Length,
+                "upper" => Filter::Upper,
+                "lower" => Filter::Lower,
+                "escape" | "e" => Filter::Escape,
+                _ => match f.strip_prefix("default(").and_then(|a| a.strip_suffix(')')) {
+                    Some(arg) => Filter::Default(arg.trim().trim_matches(['"', '\'']).to_string()),
+                    None => bail!("Line {}: unknown filter \"{}\"", line, f),
+                },
+            })
+        })
+        .collect::<Result<_>>()?;
+    Ok(Expr { path, filters })
+}
+
+/// Parse nodes until one of `ends` (or the end of input when `ends` is
+/// empty). Returns the nodes and the tag that ended them.
+fn parse_nodes(tokens: &[Token], pos: &mut usize, ends: &[&str]) -> Result<(Vec<Node>, Option<String>)> {
+    let mut nodes = Vec::new();
+    while let Some(token) = tokens.get(*pos) {
+        *pos += 1;
+        match token {
+            Token::Text(text) if !text.is_empty() => nodes.push(Node::Text(text.clone())),
+            Token::Text(_) => {}
+            Token::Value(text, line) => nodes.push(Node::Value(parse_expr(text, *line)?)),
+            Token::Tag(text, line) => {
+                let words: Vec<&str> = text.split_whitespace().collect();
+                match words.as_slice() {
+                    [end] if ends.contains(end) => return Ok((nodes, Some(end.to_string()))),
+                    ["for", var, "in", list @ ..] if !list.is_empty() => {
+                        let list = parse_expr(&list.join(" "), *line)?;
+                        let (body, end) = parse_nodes(tokens, pos, &["endfor"])?;
+                        if end.is_none() {
+                            bail!("Line {}: for without endfor", line);
+                        }
+                        nodes.push(Node::For { var: var.to_string(), list, body });
+                    }
+                    ["if", cond @ ..] if !cond.is_empty() => {
+                        let (negate, cond) = match cond {
+                            ["not", rest @ ..] => (true, rest.join(" ")),
+                            _ => (false, cond.join(" ")),
+                        };
+                        let cond = parse_expr(&cond, *line)?;
+                        let (then, end) = parse_nodes(tokens, pos, &["else", "endif"])?;
+                        let otherwise = match end.as_deref() {
+                            Some("else") => match parse_nodes(tokens, pos, &["endif"])? {
+                                (nodes, Some(_)) => nodes,
+                                _ => bail!("Line {}: if without endif", line),
+                            },
+                            Some(_) => Vec::new(),
+                            None => bail!("Line {}: if without endif", line),
+                        };
+                        nodes.push(Node::If { cond, negate, then, otherwise });
+                    }
+                    _ => bail!("Line {}: unexpected {{% {} %}}", line, text),
+                }
+            }
+        }
+    }
+    Ok((nodes, None))
+}
+
+fn lookup(expr: &Expr, scopes: &[(String, Value)], context: &Value) -> Value {
+    let (first, rest) = expr.path.split_first().expect("paths have at least one part");
+    let root = scopes
+        .iter()
+        .rev()
+        .find(|(name, _)| name == first)
+        .map(|(_, v)| v)
+        .or_else(|| context.get(first));
+    let value = rest.iter().try_fold(root, |value, part| {
+        let value = value?;
+        Some(match part.parse::<usize>() {
+            Ok(i) if value.is_array() => value.get(i),
+            _ => value.get(part),
+        })
+    });
+    let mut value = value.flatten().cloned().unwrap_or(Value::Null);
+    for filter in &expr.filters {
+        value = match filter {
+            Filter::Length => json!(match &value {
+                Value::Array(a) => a.len(),
+                Value::Object(o) => o.len(),
+                Value::String(s) => s.chars().count(),
+                _ => 0,
+            }),
+            Filter::Upper => Value::String(to_text(&value).to_uppercase()),
+            Filter::Lower => Value::String(to_text(&value).to_lowercase()),
+            Filter::Escape => Value::String(escape_html(&to_text(&value))),
+            Filter::Default(text) if !truthy(&value) => Value::String(text.clone()),
+            Filter::Default(_) => value,
+        };
+    }
+    value
+}
+
+fn truthy(value: &Value) -> bool {
+    match value {
+        Value::Null => false,
+        Value::Bool(b) => *b,
+        Value::Number(n) => n.as_f64() != Some(0.0),
+        Value::String(s) => !s.is_empty(),
+        Value::Array(a) => !a.is_empty(),
+        Value::Object(o) => !o.is_empty(),
+    }
+}
+
+fn to_text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn render_nodes(nodes: &[Node], scopes: &mut Vec<(String, Value)>, context: &Value, out: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Text(text) => out.push_str(text),
+            Node::Value(expr) => out.push_str(&to_text(&lookup(expr, scopes, context))),
+            Node::For { var, list, body } => {
+                let items = match lookup(list, scopes, context) {
+                    Value::Array(items) => items,
+                    Value::Object(map) => map.into_iter().map(|(key, value)| json!({ "key": key, "value": value })).collect(),
+                    _ => Vec::new(),
+                };
+                let count = items.len();
+                for (i, item) in items.into_iter().enumerate() {
+                    scopes.push(("loop".to_string(), json!({ "index": i + 1, "first": i == 0, "last": i + 1 == count })));
+                    scopes.push((var.clone(), item));
+                    render_nodes(body, scopes, context, out);
+                    scopes.truncate(scopes.len() - 2);
+                }
+            }
+            Node::If { cond, negate, then, otherwise } => {
+                let branch = if truthy(&lookup(cond, scopes, context)) != *negate { then } else { otherwise };
+                render_nodes(branch, scopes, context, out);
+            }
+        }
+    }
+}
+
+/// Render `source` with the values in `context`. Syntax errors name the line.
+pub fn render_template(source: &str, context: &Value) -> Result<String> {
+    let tokens = tokenize(source)?;
+    let mut pos = 0;
+    let (nodes, _) = parse_nodes(&tokens, &mut pos, &[])?;
+    let mut out = String::new();
+    render_nodes(&nodes, &mut Vec::new(), context, &mut out);
+    Ok(out)
+}
+
+/// Everything a custom report can draw from.
+pub struct TemplateInput<'a> {
+    pub unit_name: &'a str,
+    pub youth: &'a [Youth],
+    pub adults: &'a [Adult],
+    pub events: &'a [Event],
+    /// Which personal details the report may show
+    pub profile: ExportProfile,
+    pub phone_format: PhoneFormat,
+}
+
+/// The values available to templates: `unit`, `generated`, `today`,
+/// `scouts`, `adults`, `events`, `upcoming_events`, `patrols`, and the
+/// cached records as Scoutbook returned them under `data`. Details the
+/// export profile leaves out are null in the curated lists and removed from
+/// the raw records.
+pub fn template_context(input: &TemplateInput, today: NaiveDate) -> Value {
+    let profile = input.profile;
+    let contact = profile.includes(ExportField::Contact);
+    let mut youth: Vec<&Youth> = input.youth.iter().collect();
+    youth.sort_by_key(|y| (y.last_name.clone(), y.first_name.clone()));
+    let scouts: Vec<Value> = youth
+        .iter()
+        .map(|y| {
+            json!({
+                "id": y.user_id,
+                "name": y.short_name(),
+                "first_name": y.first_name,
+                "last_name": y.last_name,
+                "patrol": y.patrol_name,
+                "rank": y.rank(),
+                "age": y.age().filter(|_| profile.includes(ExportField::Age)),
+                "grade": y.grade.filter(|_| profile.includes(ExportField::Personal)),
+                "email": y.email().filter(|_| contact),
+                "phone": y.phone(input.phone_format).filter(|_| contact),
+            })
+        })
+        .collect();
+
+    let mut patrols: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for y in &youth {
+        patrols.entry(y.patrol_name.clone().unwrap_or_else(|| "No Patrol".to_string())).or_default().push(y.short_name());
+    }
+
+    let adults: Vec<Value> = input
+        .adults
+        .iter()
+        .map(|a| {
+            json!({
+                "id": a.user_id,
+                "name": a.full_name(),
+                "first_name": a.first_name,
+                "last_name": a.last_name,
+                "position": a.role(),
+                "email": a.email().filter(|_| contact),
+                "phone": a.phone(input.phone_format).filter(|_| contact),
+            })
+        })
+        .collect();
+
+    let mut events: Vec<&Event> = input.events.iter().collect();
+    events.sort_by_key(|e| e.start_date.clone());
+    let event_value = |e: &Event| {
+        json!({
+            "id": e.id,
+            "name": e.name,
+            "date": e.start_day().map(|d| d.format("%Y-%m-%d").to_string()),
+            "start": e.formatted_start_datetime(),
+            "end": e.formatted_end_datetime(),
+            "location": e.location,
+            "type": e.derived_type(),
+            "going": e.invited_users.iter().filter(|u| u.status() == RsvpStatus::Going).count(),
+        })
+    };
+
+    json!({
+        "unit": input.unit_name,
+        "generated": today.format("%B %-d, %Y").to_string(),
+        "today": today.format("%Y-%m-%d").to_string(),
+        "scouts": scouts,
+        "adults": adults,
+        "events": events.iter().map(|e| event_value(e)).collect::<Vec<_>>(),
+        "upcoming_events": events.iter().filter(|e| !e.is_past(today)).map(|e| event_value(e)).collect::<Vec<_>>(),
+        "patrols": patrols.into_iter().map(|(name, scouts)| json!({ "name": name, "scouts": scouts })).collect::<Vec<_>>(),
+        "data": {
+            "youth": input.youth.iter().map(|y| profile.youth(y)).collect::<Vec<_>>(),
+            "adults": input.adults.iter().map(|a| profile.adult(a)).collect::<Vec<_>>(),
+            "events": input.events.iter().map(|e| profile.event(e)).collect::<Vec<_>>(),
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_loops_conditions_and_filters() {
+        let youth: Vec<Youth> = serde_json::from_value(json!([
+            { "userId": 1, "firstName": "Sam", "lastName": "Scout", "subUnitName": "Eagles" },
+            { "userId": 2, "firstName": "Alex", "lastName": "Ranger" }
+        ]))
+        .unwrap();
+        let input = TemplateInput {
+            unit_name: "Troop 42",
+            youth: &youth,
+            adults: &[],
+            events: &[],
+            profile: ExportProfile::Public,
+            phone_format: PhoneFormat::Parens,
+        };
+        let context = template_context(&input, NaiveDate::from_ymd_opt(2026, 10, 1).unwrap());
+
+        let template = "\
+{{ unit | upper }}: {{ scouts | length }} scouts {# roster #}
+{% for scout in scouts %}
+  {{ loop.index }}. {{ scout.name }} ({{ scout.patrol | default(\"none\") }}){% if not loop.last %},{% endif %}
+{% endfor %}
+{% if events %}has events{% else %}no events{% endif %}
+";
+        assert_eq!(
+            render_template(template, &context).unwrap(),
+            "TROOP 42: 2 scouts \n  1. Alex Ranger (none),\n  2. Sam Scout (Eagles)\nno events\n"
+        );
+        assert_eq!(render_template("{{ data.youth.0.firstName }}", &context).unwrap(), "Sam");
+        let nested = "{% for p in patrols %}\n{% for s in p.scouts %}\n{{ p.name }}: {{ s }}\n{% endfor %}\n{% endfor %}\n";
+        assert_eq!(render_template(nested, &context).unwrap(), "Eagles: Sam Scout\nNo Patrol: Alex Ranger\n");
+
+        let err = render_template("line one\n{% for s in scouts %}{{ s.name }}", &context).unwrap_err();
+        assert_eq!(err.to_string(), "Line 2: for without endfor");
+        assert!(render_template("{{ unit | shout }}", &context).is_err());
+    }
+
+    #[test]
+    fn test_public_render_hides_invitees() {
+        let events: Vec<Event> = serde_json::from_value(json!([{
+            "id": 7, "name": "Campout", "startDate": "2026-10-23T17:00:00",
+            "invitedUsers": [{ "userId": 1, "firstName": "Sam", "lastName": "Scout", "rsvpCode": "Y" }]
+        }]))
+        .unwrap();
+        let template = "{% for e in data.events %}{% for u in e.invitedUsers %}{{ u.firstName }}{% endfor %}{% endfor %}\
+                        {{ events.0.going }}";
+        let today = NaiveDate::from_ymd_opt(2026, 10, 1).unwrap();
+        let render = |profile| {
+            let input = TemplateInput {
+                unit_name: "Troop 42",
+                youth: &[],
+                adults: &[],
+                events: &events,
+                profile,
+                phone_format: PhoneFormat::Parens,
+            };
+            render_template(template, &template_context(&input, today)).unwrap()
+        };
+        assert_eq!(render(ExportProfile::Public), "1");
+        assert_eq!(render(ExportProfile::Leader), "Sam1");
+    }
+
+    #[test]
+    fn test_public_render_hides_grade() {
+        let youth: Vec<Youth> =
+            serde_json::from_value(json!([{ "userId": 1, "firstName": "Sam", "lastName": "Scout", "grade": 8 }])).unwrap();
+        let today = NaiveDate::from_ymd_opt(2026, 10, 1).unwrap();
+        let render = |profile| {
+            let input = TemplateInput {
+                unit_name: "Troop 42",
+                youth: &youth,
+                adults: &[],
+                events: &[],
+                profile,
+                phone_format: PhoneFormat::Parens,
+            };
+            render_template("{% for scout in scouts %}[{{ scout.grade }}]{% endfor %}", &template_context(&input, today))
+                .unwrap()
+        };
+        assert_eq!(render(ExportProfile::Public), "[]");
+        assert_eq!(render(ExportProfile::Leader), "[8]");
+    }
+}
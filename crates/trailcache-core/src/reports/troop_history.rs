@@ -0,0 +1,44 @@
+//! Annual troop history built from the historian's log and event debriefs.
+
+use chrono::NaiveDate;
+
+use super::document::Section;
+use crate::models::{DebriefKind, Debriefs, EventDebrief, HistorianLog, HistoryEntry};
+
+/// One section per event in `year` with a history entry or a debrief, in
+/// date order.
+pub fn troop_history_sections(log: &HistorianLog, debriefs: &Debriefs, year: i32) -> Vec<Section> {
+    let mut rows: Vec<(NaiveDate, &str, Option<&HistoryEntry>, Option<&EventDebrief>)> = log
+        .year(year)
+        .map(|e| (e.date, e.event_name.as_str(), Some(e), debriefs.for_event(e.event_id)))
+        .collect();
+    for debrief in debriefs.year(year) {
+        if !rows.iter().any(|row| row.2.is_some_and(|e| e.event_id == debrief.event_id)) {
+            rows.push((debrief.date, debrief.event_name.as_str(), None, Some(debrief)));
+        }
+    }
+    rows.sort_by_key(|row| (row.0, row.1));
+
+    rows.into_iter()
+        .map(|(date, name, entry, debrief)| {
+            let mut section = Section::new(format!("{} - {}", date.format("%b %-d"), name));
+            if let Some(entry) = entry {
+                if !entry.summary.is_empty() {
+                    section.bullet(entry.summary.clone());
+                }
+                for highlight in &entry.highlights {
+                    section.bullet(format!("Highlight: {}", highlight));
+                }
+                if let Some(folder) = &entry.photo_folder {
+                    section.bullet(format!("Photos: {}", folder));
+                }
+            }
+            for kind in DebriefKind::ALL {
+                for note in debrief.into_iter().flat_map(|d| d.notes_of(kind)) {
+                    section.bullet(format!("{}: {}", kind.label(), note.text));
+                }
+            }
+            section
+        })
+        .collect()
+}
@@ -0,0 +1,100 @@
+//! Rank requirement cross-references from a local file.
+//!
+//! Leaders keep a JSON file mapping rank requirements to related resources,
+//! such as a merit badge requirement that covers the same skill:
+//!
+//! ```json
+//! {
+//!   "First Class": {
+//!     "2a": ["See Cooking MB req 4", "Patrol menu planner in the troop library"]
+//!   }
+//! }
+//! ```
+//!
+//! Rank names match case-insensitively and requirement numbers match
+//! ignoring punctuation, so "2a", "2a." and "2(a)" are the same entry.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+/// Cross-reference file name, stored next to `config.json`
+pub const CROSSREFS_FILE: &str = "crossrefs.json";
+
+/// Rank → requirement → related resource notes.
+#[derive(Debug, Clone, Default)]
+pub struct CrossReferences {
+    by_rank: HashMap<String, HashMap<String, Vec<String>>>,
+}
+
+impl CrossReferences {
+    /// Load cross-references from `path`. A missing file yields an empty set.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        Self::from_json(&contents).with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    pub fn from_json(json: &str) -> Result<Self> {
+        let raw: HashMap<String, HashMap<String, Vec<String>>> = serde_json::from_str(json)?;
+        let by_rank = raw
+            .into_iter()
+            .map(|(rank, reqs)| {
+                let reqs = reqs
+                    .into_iter()
+                    .map(|(number, notes)| (normalize_number(&number), notes))
+                    .collect();
+                (rank.trim().to_lowercase(), reqs)
+            })
+            .collect();
+        Ok(Self { by_rank })
+    }
+
+    /// Notes for a requirement, or an empty slice when none are recorded.
+    pub fn lookup(&self, rank_name: &str, requirement_number: &str) -> &[String] {
+        self.by_rank
+            .get(&rank_name.trim().to_lowercase())
+            .and_then(|reqs| reqs.get(&normalize_number(requirement_number)))
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.by_rank.is_empty()
+    }
+}
+
+/// "2(a)." → "2a"
+fn normalize_number(number: &str) -> String {
+    number
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .collect::<String>()
+        .to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup_normalizes_keys() {
+        let refs = CrossReferences::from_json(
+            r#"{ "First Class": { "2a.": ["See Cooking MB req 4"] } }"#,
+        )
+        .unwrap();
+        assert_eq!(refs.lookup("first class", "2(a)"), ["See Cooking MB req 4"]);
+        assert!(refs.lookup("First Class", "2b").is_empty());
+        assert!(refs.lookup("Star", "2a").is_empty());
+    }
+
+    #[test]
+    fn test_missing_file_is_empty() {
+        let refs = CrossReferences::load(Path::new("/nonexistent/crossrefs.json")).unwrap();
+        assert!(refs.is_empty());
+    }
+}
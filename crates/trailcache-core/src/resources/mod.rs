@@ -0,0 +1,10 @@
+//! Reference material linked from advancement views.
+//!
+//! - `workbooks`: Merit badge workbook and pamphlet links
+//! - `crossrefs`: Rank requirement cross-references from a local file
+
+pub mod crossrefs;
+pub mod workbooks;
+
+pub use crossrefs::{CrossReferences, CROSSREFS_FILE};
+pub use workbooks::{download_workbook, workbook_pdf_path, WorkbookLink, WorkbookRegistry};
@@ -0,0 +1,165 @@
+//! Merit badge workbook and pamphlet links.
+//!
+//! Every badge gets default links derived from its name. Entries in
+//! `Config::workbook_links` (keyed by badge name) override individual links,
+//! so a unit can point at its own copies or fix a badge whose URL differs.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+/// Fillable workbook PDFs, named after the badge (e.g. `Camping.pdf`)
+const WORKBOOK_BASE_URL: &str = "https://usscouts.org/mb/worksheets";
+
+/// Official badge pages with requirements and pamphlet information
+const BADGE_PAGE_BASE_URL: &str = "https://www.scouting.org/merit-badges";
+
+/// Subdirectory of the cache directory holding downloaded workbook PDFs
+const WORKBOOK_DIR: &str = "workbooks";
+
+/// Links for one merit badge. Either link may be absent in a config override.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkbookLink {
+    /// Official badge page with pamphlet information
+    #[serde(default)]
+    pub info_url: Option<String>,
+    /// Fillable workbook PDF
+    #[serde(default)]
+    pub workbook_url: Option<String>,
+}
+
+/// Badge name → links, combining built-in defaults with config overrides.
+#[derive(Debug, Clone, Default)]
+pub struct WorkbookRegistry {
+    overrides: HashMap<String, WorkbookLink>,
+}
+
+impl WorkbookRegistry {
+    /// Build a registry from config overrides. Keys match case-insensitively.
+    pub fn new(overrides: &HashMap<String, WorkbookLink>) -> Self {
+        Self {
+            overrides: overrides
+                .iter()
+                .map(|(name, link)| (base_name(name).to_lowercase(), link.clone()))
+                .collect(),
+        }
+    }
+
+    /// Links for a badge, with any override fields taking precedence over defaults.
+    pub fn lookup(&self, badge_name: &str) -> WorkbookLink {
+        let defaults = Self::default_link(badge_name);
+        match self.overrides.get(&base_name(badge_name).to_lowercase()) {
+            Some(link) => WorkbookLink {
+                info_url: link.info_url.clone().or(defaults.info_url),
+                workbook_url: link.workbook_url.clone().or(defaults.workbook_url),
+            },
+            None => defaults,
+        }
+    }
+
+    /// Links derived from the badge name alone.
+    pub fn default_link(badge_name: &str) -> WorkbookLink {
+        let words = name_words(badge_name);
+        if words.is_empty() {
+            return WorkbookLink::default();
+        }
+        WorkbookLink {
+            info_url: Some(format!("{}/{}/", BADGE_PAGE_BASE_URL, words.join("-").to_lowercase())),
+            workbook_url: Some(format!("{}/{}.pdf", WORKBOOK_BASE_URL, words.join("-"))),
+        }
+    }
+}
+
+/// Where a downloaded workbook PDF for `badge_name` is stored.
+pub fn workbook_pdf_path(cache_dir: &Path, badge_name: &str) -> PathBuf {
+    let file = name_words(badge_name).join("-").to_lowercase();
+    cache_dir.join(WORKBOOK_DIR).join(format!("{}.pdf", file))
+}
+
+/// Download a workbook PDF to `dest`, creating parent directories as needed.
+pub async fn download_workbook(url: &str, dest: &Path) -> Result<()> {
+    let response = reqwest::get(url)
+        .await
+        .with_context(|| format!("Failed to download {}", url))?
+        .error_for_status()
+        .with_context(|| format!("Failed to download {}", url))?;
+    let bytes = response.bytes().await?;
+
+    // Error pages come back as HTML with a 200 status on some mirrors
+    if !bytes.starts_with(b"%PDF") {
+        anyhow::bail!("Not a PDF: {}", url);
+    }
+
+    if let Some(parent) = dest.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(dest, &bytes)
+        .with_context(|| format!("Failed to save workbook to {}", dest.display()))?;
+    Ok(())
+}
+
+/// Strip decorations the API adds to badge names, e.g. "Camping *" or "Camping (2024)".
+fn base_name(name: &str) -> &str {
+    let name = name.trim().trim_end_matches('*').trim_end();
+    match name.rfind(" (") {
+        Some(idx) if name.ends_with(')') => &name[..idx],
+        _ => name,
+    }
+}
+
+/// Words of the badge name with punctuation dropped and "&" spelled out.
+fn name_words(name: &str) -> Vec<String> {
+    base_name(name)
+        .split_whitespace()
+        .map(|w| if w == "&" { "and".to_string() } else { w.chars().filter(|c| c.is_alphanumeric()).collect() })
+        .filter(|w: &String| !w.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_link() {
+        let link = WorkbookRegistry::default_link("Citizenship in the Community *");
+        assert_eq!(
+            link.info_url.as_deref(),
+            Some("https://www.scouting.org/merit-badges/citizenship-in-the-community/")
+        );
+        assert_eq!(
+            link.workbook_url.as_deref(),
+            Some("https://usscouts.org/mb/worksheets/Citizenship-in-the-Community.pdf")
+        );
+        assert_eq!(
+            WorkbookRegistry::default_link("Fish & Wildlife Management (2023)").workbook_url.as_deref(),
+            Some("https://usscouts.org/mb/worksheets/Fish-and-Wildlife-Management.pdf")
+        );
+    }
+
+    #[test]
+    fn test_override_falls_back_to_defaults() {
+        let mut overrides = HashMap::new();
+        overrides.insert(
+            "camping".to_string(),
+            WorkbookLink {
+                info_url: None,
+                workbook_url: Some("https://example.org/camping.pdf".to_string()),
+            },
+        );
+        let registry = WorkbookRegistry::new(&overrides);
+
+        let link = registry.lookup("Camping");
+        assert_eq!(link.workbook_url.as_deref(), Some("https://example.org/camping.pdf"));
+        assert_eq!(link.info_url, WorkbookRegistry::default_link("Camping").info_url);
+        assert_eq!(registry.lookup("Cooking"), WorkbookRegistry::default_link("Cooking"));
+    }
+
+    #[test]
+    fn test_workbook_pdf_path() {
+        let path = workbook_pdf_path(Path::new("/tmp/cache"), "First Aid");
+        assert_eq!(path, PathBuf::from("/tmp/cache/workbooks/first-aid.pdf"));
+    }
+}
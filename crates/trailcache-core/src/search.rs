@@ -0,0 +1,208 @@
+//! Search matching shared by every list that can be filtered.
+//!
+//! A `SearchQuery` is compiled once from the user's input and a `SearchMode`,
+//! then used both to filter/rank items (`score`) and to find the substrings to
+//! highlight (`match_ranges`).
+
+use std::ops::Range;
+
+use regex::{Regex, RegexBuilder};
+
+use crate::utils::match_ranges_ignore_case;
+
+/// How the search text is interpreted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SearchMode {
+    /// Case-insensitive substring
+    #[default]
+    Substring,
+    /// Characters in order, not necessarily adjacent; results ranked by match quality
+    Fuzzy,
+    /// Case-insensitive regular expression
+    Regex,
+}
+
+impl SearchMode {
+    pub fn label(&self) -> &'static str {
+        match self {
+            SearchMode::Substring => "text",
+            SearchMode::Fuzzy => "fuzzy",
+            SearchMode::Regex => "regex",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            SearchMode::Substring => SearchMode::Fuzzy,
+            SearchMode::Fuzzy => SearchMode::Regex,
+            SearchMode::Regex => SearchMode::Substring,
+        }
+    }
+}
+
+/// A compiled search.
+#[derive(Debug, Clone, Default)]
+pub struct SearchQuery {
+    mode: SearchMode,
+    text: String,
+    lowercase: String,
+    regex: Option<Regex>,
+}
+
+impl SearchQuery {
+    pub fn new(text: &str, mode: SearchMode) -> Self {
+        let regex = match mode {
+            SearchMode::Regex if !text.is_empty() => {
+                RegexBuilder::new(text).case_insensitive(true).build().ok()
+            }
+            _ => None,
+        };
+        Self {
+            mode,
+            text: text.to_string(),
+            lowercase: text.to_lowercase(),
+            regex,
+        }
+    }
+
+    pub fn mode(&self) -> SearchMode {
+        self.mode
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// True when there is nothing to filter by.
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// False for a regex that doesn't compile. Invalid queries filter nothing
+    /// so the list doesn't blank out while a pattern is half-typed.
+    pub fn is_valid(&self) -> bool {
+        self.mode != SearchMode::Regex || self.is_empty() || self.regex.is_some()
+    }
+
+    /// Whether results should be ordered by match score instead of the column sort.
+    pub fn ranks_results(&self) -> bool {
+        self.mode == SearchMode::Fuzzy && !self.is_empty()
+    }
+
+    /// Match score for one field, higher is better. None when it doesn't match.
+    pub fn score(&self, field: &str) -> Option<u32> {
+        if self.is_empty() || !self.is_valid() {
+            return Some(0);
+        }
+        match self.mode {
+            SearchMode::Substring => {
+                (!match_ranges_ignore_case(field, &self.lowercase).is_empty()).then_some(1)
+            }
+            SearchMode::Regex => self.regex.as_ref().and_then(|re| re.is_match(field).then_some(1)),
+            SearchMode::Fuzzy => fuzzy_match(field, &self.lowercase).map(|(score, _)| score),
+        }
+    }
+
+    /// Best score across an item's searchable fields.
+    pub fn best_score<S: AsRef<str>>(&self, fields: &[S]) -> Option<u32> {
+        fields.iter().filter_map(|f| self.score(f.as_ref())).max()
+    }
+
+    /// Byte ranges in `field` to highlight.
+    pub fn match_ranges(&self, field: &str) -> Vec<Range<usize>> {
+        if self.is_empty() {
+            return Vec::new();
+        }
+        match self.mode {
+            SearchMode::Substring => match_ranges_ignore_case(field, &self.lowercase),
+            SearchMode::Regex => match &self.regex {
+                Some(re) => re.find_iter(field).filter(|m| !m.is_empty()).map(|m| m.range()).collect(),
+                None => Vec::new(),
+            },
+            SearchMode::Fuzzy => fuzzy_match(field, &self.lowercase)
+                .map(|(_, ranges)| ranges)
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Match `needle_lowercase` as an in-order subsequence of `haystack`.
+///
+/// Scores reward consecutive characters and matches at word starts, so "tf"
+/// ranks "Tender Foot" above "ThereaFter". Returns the score and the matched
+/// byte ranges (adjacent characters merged).
+pub fn fuzzy_match(haystack: &str, needle_lowercase: &str) -> Option<(u32, Vec<Range<usize>>)> {
+    let mut needle = needle_lowercase.chars().peekable();
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    let mut score = 0u32;
+    let mut prev_char: Option<char> = None;
+    let mut prev_matched = false;
+
+    for (i, c) in haystack.char_indices() {
+        let Some(&want) = needle.peek() else { break };
+        let matched = c.to_lowercase().eq(std::iter::once(want));
+        if matched {
+            needle.next();
+            score += 1;
+            let word_start = prev_char.is_none_or(|p| !p.is_alphanumeric());
+            if word_start {
+                score += 3;
+            }
+            if prev_matched {
+                score += 2;
+                if let Some(last) = ranges.last_mut() {
+                    last.end = i + c.len_utf8();
+                }
+            } else {
+                ranges.push(i..i + c.len_utf8());
+            }
+        }
+        prev_matched = matched;
+        prev_char = Some(c);
+    }
+
+    needle.peek().is_none().then_some((score, ranges))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_substring_mode() {
+        let q = SearchQuery::new("eag", SearchMode::Substring);
+        assert_eq!(q.score("Eagles"), Some(1));
+        assert_eq!(q.score("Hawks"), None);
+        assert_eq!(q.match_ranges("The Eagles"), vec![4..7]);
+    }
+
+    #[test]
+    fn test_fuzzy_mode_ranks_word_starts() {
+        let q = SearchQuery::new("js", SearchMode::Fuzzy);
+        let word_starts = q.score("John Smith").unwrap();
+        let inner = q.score("Jones").unwrap();
+        assert!(word_starts > inner);
+        assert_eq!(q.score("Smith"), None, "order matters");
+        assert_eq!(q.match_ranges("John Smith"), vec![0..1, 5..6]);
+        assert!(q.ranks_results());
+    }
+
+    #[test]
+    fn test_regex_mode() {
+        let q = SearchQuery::new("^s(mith|nyder)", SearchMode::Regex);
+        assert_eq!(q.score("Snyder"), Some(1));
+        assert_eq!(q.score("Jones"), None);
+        assert_eq!(q.match_ranges("smithers"), vec![0..5]);
+
+        let invalid = SearchQuery::new("(ab", SearchMode::Regex);
+        assert!(!invalid.is_valid());
+        assert_eq!(invalid.score("anything"), Some(0), "invalid patterns filter nothing");
+    }
+
+    #[test]
+    fn test_best_score_across_fields() {
+        let q = SearchQuery::new("eagle", SearchMode::Substring);
+        assert_eq!(q.best_score(&["Sam", "Eagle Patrol"]), Some(1));
+        assert_eq!(q.best_score(&["Sam", "Hawk Patrol"]), None);
+    }
+}
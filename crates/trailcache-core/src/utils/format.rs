@@ -1,4 +1,5 @@
 use std::cmp::Ordering;
+use std::ops::Range;
 
 use chrono::{NaiveDate, Utc};
 
@@ -97,6 +98,32 @@ pub fn contains_ignore_case(haystack: &str, needle_lowercase: &str) -> bool {
         })
 }
 
+/// Byte ranges of non-overlapping case-insensitive matches, for highlighting.
+/// Assumes `needle` is already lowercase.
+pub fn match_ranges_ignore_case(haystack: &str, needle_lowercase: &str) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    if needle_lowercase.is_empty() {
+        return ranges;
+    }
+    let needle_len = needle_lowercase.chars().count();
+    let mut next_start = 0;
+    for (i, _) in haystack.char_indices() {
+        if i < next_start {
+            continue;
+        }
+        let mut matched = haystack[i..]
+            .char_indices()
+            .zip(needle_lowercase.chars())
+            .take_while(|((_, h), n)| h.to_ascii_lowercase() == *n);
+        if let Some(((offset, last), _)) = matched.by_ref().nth(needle_len - 1) {
+            let end = i + offset + last.len_utf8();
+            ranges.push(i..end);
+            next_start = end;
+        }
+    }
+    ranges
+}
+
 /// Case-insensitive string comparison for sorting (no allocation).
 pub fn cmp_ignore_case(a: &str, b: &str) -> Ordering {
     a.chars()
@@ -104,33 +131,6 @@ pub fn cmp_ignore_case(a: &str, b: &str) -> Ordering {
         .cmp(b.chars().map(|c| c.to_ascii_lowercase()))
 }
 
-// ============================================================================
-// Phone Number Formatting
-// ============================================================================
-
-/// Format a phone number for display
-/// Handles various input formats and normalizes to (XXX) XXX-XXXX
-pub fn format_phone(phone: &str) -> String {
-    // Extract just the digits
-    let digits: String = phone.chars().filter(|c| c.is_ascii_digit()).collect();
-
-    match digits.len() {
-        10 => format!(
-            "({}) {}-{}",
-            &digits[0..3],
-            &digits[3..6],
-            &digits[6..10]
-        ),
-        11 if digits.starts_with('1') => format!(
-            "({}) {}-{}",
-            &digits[1..4],
-            &digits[4..7],
-            &digits[7..11]
-        ),
-        _ => phone.to_string(), // Return original if can't format
-    }
-}
-
 /// Strip HTML tags from a string.
 /// Useful for cleaning up requirement text from the API.
 pub fn strip_html(s: &str) -> String {
@@ -235,19 +235,51 @@ pub fn format_date(date: &str) -> String {
     }
 }
 
+// ============================================================================
+// CSV
+// ============================================================================
+
+/// Join fields into one CSV line, quoting fields that contain commas, quotes, or newlines.
+pub fn csv_line<S: AsRef<str>>(fields: &[S]) -> String {
+    fields
+        .iter()
+        .map(|f| {
+            let f = f.as_ref();
+            if f.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", f.replace('"', "\"\""))
+            } else {
+                f.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Split one CSV line into fields, handling quoted fields and doubled quotes.
+pub fn parse_csv_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.trim_end_matches(['\r', '\n']).chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_format_phone() {
-        assert_eq!(format_phone("5551234567"), "(555) 123-4567");
-        assert_eq!(format_phone("15551234567"), "(555) 123-4567");
-        assert_eq!(format_phone("555-123-4567"), "(555) 123-4567");
-        assert_eq!(format_phone("(555) 123-4567"), "(555) 123-4567");
-        assert_eq!(format_phone("123"), "123"); // Too short, return as-is
-    }
-
     #[test]
     fn test_check_expiration_expired() {
         let past = "2020-01-01";
@@ -276,6 +308,15 @@ mod tests {
         assert_eq!(status, ExpirationStatus::Expired);
     }
 
+    #[test]
+    fn test_match_ranges_ignore_case() {
+        assert_eq!(match_ranges_ignore_case("Anna Banana", "an"), vec![0..2, 6..8, 8..10]);
+        assert_eq!(match_ranges_ignore_case("Eagles", "eagles"), vec![0..6]);
+        assert!(match_ranges_ignore_case("Eagles", "hawk").is_empty());
+        assert!(match_ranges_ignore_case("Eagles", "").is_empty());
+        assert_eq!(match_ranges_ignore_case("Zoë Smith", "smith"), vec![5..10]);
+    }
+
     #[test]
     fn test_truncate() {
         assert_eq!(truncate("Hello", 10), "Hello");
@@ -286,4 +327,12 @@ mod tests {
         // Test trimming
         assert_eq!(truncate("  Hello  ", 10), "Hello");
     }
+
+    #[test]
+    fn test_csv_round_trip() {
+        let line = csv_line(&["Sam", "Totin' Chip", "Card \"A\", 12"]);
+        assert_eq!(line, "Sam,Totin' Chip,\"Card \"\"A\"\", 12\"");
+        assert_eq!(parse_csv_line(&line), ["Sam", "Totin' Chip", "Card \"A\", 12"]);
+        assert_eq!(parse_csv_line("a,,b\r\n"), ["a", "", "b"]);
+    }
 }
@@ -1,9 +1,12 @@
 //! Utility functions for string formatting and manipulation.
 
 pub mod format;
+pub mod phone;
 
 // Re-export commonly used functions at module level
 pub use format::{
-    check_expiration, cmp_ignore_case, contains_ignore_case, format_phone, strip_html,
+    check_expiration, cmp_ignore_case, csv_line, parse_csv_line, contains_ignore_case, match_ranges_ignore_case,
+    strip_html,
     strip_url_scheme, truncate, wrap_text, ExpirationStatus,
 };
+pub use phone::{format_phone, tel_uri, PhoneFormat};
@@ -0,0 +1,147 @@
+//! Phone number normalization and display.
+//!
+//! Scoutbook returns numbers as "5551234567", "555-123-4567", "+1 (555)
+//! 123-4567 x12", or split into area code, prefix, and line number. North
+//! American numbers are parsed into their parts and shown in the display
+//! format the caller passes, normally the config's `phone_format`; anything
+//! else is shown as entered.
+
+use serde::{Deserialize, Serialize};
+
+/// How North American numbers are displayed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PhoneFormat {
+    /// (555) 123-4567
+    #[default]
+    Parens,
+    /// 555-123-4567
+    Dashes,
+    /// 555.123.4567
+    Dots,
+    /// +1 555 123 4567
+    International,
+}
+
+impl PhoneFormat {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// A North American number split into its parts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PhoneNumber {
+    pub area_code: String,
+    pub exchange: String,
+    pub line: String,
+    pub extension: Option<String>,
+}
+
+impl PhoneNumber {
+    /// Parse ten digits, with an optional leading 1 and an extension after
+    /// "x", "ext", or "#". Returns None for anything else, including other
+    /// countries' numbers.
+    pub fn parse(raw: &str) -> Option<Self> {
+        let raw = raw.trim();
+        let lower = raw.to_lowercase();
+        let (main, extension) = match lower.find(['x', '#']) {
+            Some(at) => (&lower[..at], Some(&lower[at..])),
+            None => (lower.as_str(), None),
+        };
+        let digits: String = main.chars().filter(|c| c.is_ascii_digit()).collect();
+        if main.chars().any(|c| c.is_alphabetic() && c != 'e') {
+            return None;
+        }
+        let national = match digits.len() {
+            10 if !raw.starts_with('+') => digits.as_str(),
+            11 if digits.starts_with('1') => &digits[1..],
+            _ => return None,
+        };
+        let extension = extension
+            .map(|e| e.chars().filter(|c| c.is_ascii_digit()).collect::<String>())
+            .filter(|e| !e.is_empty());
+        Some(Self {
+            area_code: national[..3].to_string(),
+            exchange: national[3..6].to_string(),
+            line: national[6..].to_string(),
+            extension,
+        })
+    }
+
+    pub fn format(&self, style: PhoneFormat) -> String {
+        let (a, e, l) = (&self.area_code, &self.exchange, &self.line);
+        let number = match style {
+            PhoneFormat::Parens => format!("({}) {}-{}", a, e, l),
+            PhoneFormat::Dashes => format!("{}-{}-{}", a, e, l),
+            PhoneFormat::Dots => format!("{}.{}.{}", a, e, l),
+            PhoneFormat::International => format!("+1 {} {} {}", a, e, l),
+        };
+        match &self.extension {
+            Some(ext) => format!("{} x{}", number, ext),
+            None => number,
+        }
+    }
+
+    /// RFC 3966 `tel:` URI, e.g. "tel:+15551234567;ext=12".
+    pub fn tel_uri(&self) -> String {
+        let uri = format!("tel:+1{}{}{}", self.area_code, self.exchange, self.line);
+        match &self.extension {
+            Some(ext) => format!("{};ext={}", uri, ext),
+            None => uri,
+        }
+    }
+}
+
+/// Format a phone number in `format`, or return it as entered (trimmed) if
+/// it isn't a North American number.
+pub fn format_phone(phone: &str, format: PhoneFormat) -> String {
+    match PhoneNumber::parse(phone) {
+        Some(number) => number.format(format),
+        None => phone.trim().to_string(),
+    }
+}
+
+/// A `tel:` link for a phone number: North American numbers, and other
+/// countries' written with a leading "+".
+pub fn tel_uri(phone: &str) -> Option<String> {
+    if let Some(number) = PhoneNumber::parse(phone) {
+        return Some(number.tel_uri());
+    }
+    let phone = phone.trim();
+    let digits: String = phone.chars().filter(|c| c.is_ascii_digit()).collect();
+    let plain = phone.chars().all(|c| c.is_ascii_digit() || " +-().".contains(c));
+    (phone.starts_with('+') && plain && (8..=15).contains(&digits.len())).then(|| format!("tel:+{}", digits))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_phone() {
+        assert_eq!(format_phone("5551234567", PhoneFormat::Parens), "(555) 123-4567");
+        assert_eq!(format_phone("15551234567", PhoneFormat::Parens), "(555) 123-4567");
+        assert_eq!(format_phone("555-123-4567", PhoneFormat::Parens), "(555) 123-4567");
+        assert_eq!(format_phone("(555) 123-4567", PhoneFormat::Parens), "(555) 123-4567");
+        assert_eq!(format_phone("+1 555.123.4567 ext. 12", PhoneFormat::Parens), "(555) 123-4567 x12");
+        assert_eq!(format_phone("123", PhoneFormat::Parens), "123"); // Too short, return as-is
+        assert_eq!(format_phone("5551234567", PhoneFormat::Dots), "555.123.4567");
+        assert_eq!(format_phone(" +44 20 7946 0958 ", PhoneFormat::Parens), "+44 20 7946 0958");
+        assert_eq!(format_phone("call 555-123-4567", PhoneFormat::Parens), "call 555-123-4567");
+    }
+
+    #[test]
+    fn test_phone_styles_and_tel_uri() {
+        let number = PhoneNumber::parse("555 123 4567 #89").unwrap();
+        assert_eq!(number.format(PhoneFormat::Dashes), "555-123-4567 x89");
+        assert_eq!(number.format(PhoneFormat::Dots), "555.123.4567 x89");
+        assert_eq!(number.format(PhoneFormat::International), "+1 555 123 4567 x89");
+        assert_eq!(number.tel_uri(), "tel:+15551234567;ext=89");
+
+        assert_eq!(tel_uri("(555) 123-4567").as_deref(), Some("tel:+15551234567"));
+        assert_eq!(tel_uri("+44 20 7946 0958").as_deref(), Some("tel:+442079460958"));
+        assert_eq!(tel_uri("ask at the meeting"), None);
+        assert_eq!(tel_uri(""), None);
+    }
+}
@@ -5,12 +5,14 @@ use std::sync::Arc;
 use serde::Serialize;
 use tauri::{Emitter, State};
 
+use trailcache_core::api::{ApiError, ApiErrorKind};
 use trailcache_core::auth::CredentialStore;
 use trailcache_core::cache::{fetch_with_cache, CacheAges};
 use trailcache_core::models::{
     sort_requirements, Adult, AdvancementDashboard, Commissioner, Key3Leaders, LeadershipPosition,
     MeritBadgeProgress, OrgProfile, Patrol, RankProgress, UnitInfo, Youth,
 };
+use trailcache_core::utils::PhoneFormat;
 
 use crate::dto::{
     AdultDisplay, AwardDisplay, BadgePivotEntry, BadgeRequirementsResponseDisplay,
@@ -61,8 +63,8 @@ fn format_login_error(err: &anyhow::Error) -> String {
         }
     }
 
-    // Known error patterns without JSON
-    if raw.contains("Unauthorized") {
+    // A 401 from the auth endpoint means the credentials were rejected
+    if ApiError::classify(err) == ApiErrorKind::AuthExpired {
         return "Login failed: Invalid username or password".to_string();
     }
 
@@ -606,7 +608,7 @@ pub async fn get_badge_requirements(
                 requirements: reqs,
                 version,
                 counselor_name: counselor.as_ref().map(|c| c.full_name()).unwrap_or_default(),
-                counselor_phone: counselor.as_ref().and_then(|c| c.phone()).unwrap_or("").to_string(),
+                counselor_phone: counselor.as_ref().and_then(|c| c.phone(PhoneFormat::default())).unwrap_or_default(),
                 counselor_email: counselor.as_ref().and_then(|c| c.email.clone()),
             })
         }
@@ -681,7 +683,7 @@ pub async fn cache_for_offline(
 
     let app_handle = app.clone();
     let result = trailcache_core::cache::cache_all_for_offline(
-        &api,
+        &*api,
         &cache,
         &org_guid,
         user_id,
@@ -726,7 +728,7 @@ pub async fn refresh_data(
 
     let app_handle = app.clone();
     let result = trailcache_core::cache::refresh_base_data(
-        &api,
+        &*api,
         &cache,
         &org_guid,
         user_id,
@@ -16,6 +16,7 @@ use trailcache_core::models::{
 use trailcache_core::models::event::InvitedUser;
 use trailcache_core::models::advancement::format_date;
 use trailcache_core::utils::format::{check_expiration, strip_html, ExpirationStatus};
+use trailcache_core::utils::PhoneFormat;
 
 // ============================================================================
 // Youth & Adult DTOs
@@ -92,7 +93,7 @@ impl From<&Youth> for YouthDisplay {
             age: y.age_str(),
             grade: y.grade_str(),
             email: y.email(),
-            phone: y.phone(),
+            phone: y.phone(PhoneFormat::default()),
             position: y.position_display(),
             position_sort_key: y.position_sort_key(),
             position_display: y.position_display_with_patrol(),
@@ -130,7 +131,7 @@ pub struct ParentDisplay {
 
 impl From<&Parent> for ParentDisplay {
     fn from(p: &Parent) -> Self {
-        let phone = p.phone();
+        let phone = p.phone(PhoneFormat::default());
         let addr1 = p.address1.clone().filter(|a| !a.trim().is_empty());
         let addr2 = p.city_state_zip();
 
@@ -227,7 +228,7 @@ impl From<&Adult> for AdultDisplay {
             display_name: a.display_name(),
             role: a.role(),
             email: a.email(),
-            phone: a.phone(),
+            phone: a.phone(PhoneFormat::default()),
             ypt_status,
             ypt_style,
             ypt_sort_date,
@@ -448,9 +449,8 @@ impl From<&MeritBadgeProgress> for MeritBadgeDisplay {
             status_category: cat.as_str().to_string(),
             counselor_name: counselor.map(|c| c.full_name()).unwrap_or_default(),
             counselor_phone: counselor
-                .and_then(|c| c.phone())
-                .unwrap_or("")
-                .to_string(),
+                .and_then(|c| c.phone(PhoneFormat::default()))
+                .unwrap_or_default(),
             sort_date,
             date_completed: b.date_completed.clone(),
             percent_completed: b.percent_completed,
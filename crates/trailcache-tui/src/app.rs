@@ -1,35 +1,76 @@
 //! Application state management for Trailcache.
 //!
-//! This module contains the core `App` struct that manages all application state,
-//! including UI state, cached data, session management, and background task coordination.
+//! This module contains the core `App` struct that ties the application together:
+//! the session, the data `Store`, navigation in `UiState`, each view's state, and
+//! the results coming back from the background tasks in `services`.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::io::{self, Write};
 use std::path::PathBuf;
+use std::process::{Command, Stdio};
 use std::sync::Arc;
 use std::time::Instant;
 
 use anyhow::Result;
+use chrono::{Datelike, Local, NaiveDate, NaiveDateTime, Utc};
 use futures::stream::{self, StreamExt};
 use tokio::sync::mpsc;
 use tracing::{debug, error, info, warn};
 
-use trailcache_core::api::ApiClient;
+use trailcache_core::api::{ApiError, ApiErrorKind, Backend, ScoutingApi};
 use trailcache_core::auth::{CredentialStore, Session};
-use trailcache_core::cache::CacheManager;
-use trailcache_core::config::Config;
+use trailcache_core::cache::{
+    apply_key_source, audit_offline_cache, estimate_offline_requests, CacheManager, Freshness, OfflineAudit, OfflineScope,
+};
+use trailcache_core::config::{Config, NotifyMode};
+use trailcache_core::search::{SearchMode, SearchQuery};
+use trailcache_core::utils::format::csv_line;
+
+use crate::export::{court_of_honor_title, report_document, signoff_document};
+use crate::refresh_queue::RefreshFocus;
+use crate::selection::ListSelection;
+use crate::services::date_correction::{self, DateCorrectionState};
+use crate::services::patrol_move::{self, PatrolMove};
+use crate::services::requirements::{self, PendingCompletion};
+use crate::services::retry::DatasetRetries;
+use crate::services::{self, send_result, RefreshResult, MAX_CONCURRENT_REQUESTS};
+use crate::store::{RefreshDataset, Store, Update};
+use crate::timer::{AgendaTimer, Countdown, TIMER_PRESETS};
+use crate::ui::keymap::KeyMap;
+use crate::ui::state::{AdvancementView, AppState, EventDetailView, EventListView, Focus, Tab, UiState};
+use crate::ui::styles::ThemeChoices;
+use crate::ui::tabs::plugin::{PluginRegistry, TabPlugin};
+use trailcache_core::reports::{
+    charter_report, clinic_roster_sections, court_of_honor_sections, clinic_signoff_sections, committee_summary, conference_sheet, emergency_sheet, event_participants, high_adventure_sections, missing_forms, my_scout_summary, next_rank,
+    counselor_groups, counselor_sections, incident_report, to_html, to_ical, to_text, CommitteeInput, ConferenceInput, CounselorGroup, CounselorInput,
+    CharterInput, EmergencyInput, ExportField, ExportProfile, IncidentInput, MyScoutInput, MyScoutSummary, position_plan_sections, Section, troop_history_sections, SlideFormat, Table, TextLayout, CHARTER_TEMPLATE_FILE, REPORT_TEMPLATES_DIR,
+};
+use trailcache_core::resources::{workbook_pdf_path, CrossReferences, WorkbookRegistry, CROSSREFS_FILE};
 
 use trailcache_core::models::{
-    sort_requirements, Adult, AdvancementDashboard, Commissioner, Event, EventGuest,
-    EventSortColumn, Key3Leaders, LeadershipPosition, MeritBadgeProgress,
-    MeritBadgeRequirement, OrgProfile, Award, Parent, Patrol, RankProgress, RankRequirement,
-    ReadyToAward, ScoutSortColumn, UnitInfo, Youth,
+    sort_requirements, Adult, EagleProgress, EagleSortColumn, Event,
+    EventSortColumn, LeadershipPosition, MeritBadgeProgress,
+    MeritBadgeRequirement, Award, Parent, RankProgress, RankRequirement,
+    Patrol, RequirementTree, RsvpStats, ScoutPace, ScoutSortColumn, stalled_scouts, Youth,
+};
+use trailcache_core::models::{
+    award_catalog, unclassified_swimmers, ActivityPlanItem, AdultSkill, duty_roster, oa_standings, OaStanding, Automations, BuddyGroups, ScoutTags, AUTOMATIONS_FILE, AdultAward, CommitteeRole, OnboardingItem, AttendanceHistory, AttendanceStats, AttendanceTally, AwardCatalogEntry,
+    default_agenda, Accommodation, Accommodations, AdvancementRef, Certification, ConsentForm, Consents, LeaderCoverage, DebriefKind, Debriefs, EventTemplate,
+    contact_problems, find_by_member_id, CorrectionRecord, is_committee_event, ContactIssue, member_id_problems, ActionItem, Paperwork, Screening, TrekPlan, LOAN_DAYS, MedicationDose, TodoItem, TodoSubject, FeedEntry, MeetingRecord, MeetingPattern, plan_issues, SkillRecord,
+    MemberIdIssue, MemberKind, MemberMatch, linked_adults, linked_events, linked_youth, LinkedData, Troops, sort_by_concerns, SwimLevel, UnitData, UnitHealth, EAGLE_POR_DAYS, EAGLE_TOTAL_BADGES, DEFAULT_DUTIES, YOUTH_POSITION_PRIORITY,
 };
 use trailcache_core::models::advancement::CounselorInfo;
 
+pub mod alerts;
+pub mod features;
 
-use ratatui::layout::Rect;
-use ratatui::widgets::TableState;
+use alerts::Alert;
+use features::{
+    ActivityPlansState, AdultAwardsState, AdultSkillsState, AlertsState, BuddiesState, CachingProgress, ClinicState,
+    ClosetState, CommitteeState, CounselorsState, DenChiefsState, DistrictState, EagleProjectsState, FeedState,
+    HistoryState, LibraryState, LinkedState, LoginForm, MedicationsState, MeetingSheet, MinutesState, NominationsState,
+    OnboardingState, PlannerState, SkillsState, TemplatesState, TodosState, TrekState,
+};
 
 // ============================================================================
 // Constants
@@ -47,28 +88,58 @@ const MAX_USERNAME_LENGTH: usize = 50;
 /// 128 chars accommodates password managers and passphrases.
 const MAX_PASSWORD_LENGTH: usize = 128;
 
-/// Number of items to scroll on page up/down.
-/// 10 rows provides a good balance of speed without losing context.
-pub const PAGE_SCROLL_SIZE: usize = 10;
+/// Heading for scouts without a patrol when the roster is grouped
+const NO_PATROL: &str = "No Patrol";
+
+/// Refreshes that take at least this long send a notification when done.
+const LONG_REFRESH_SECS: u64 = 20;
+
+/// Status shown while fetching what a refresh paused on an expired session missed
+const RESUMING_REFRESH: &str = "Resuming refresh";
+
+/// Status shown when Scoutbook answers with a maintenance or outage page
+const SCOUTBOOK_DOWN: &str = "Scoutbook is down for maintenance - using cached data, will retry";
 
-/// Maximum concurrent API requests for event details.
-/// Limits parallel requests to avoid overwhelming the server or hitting rate limits.
-const MAX_CONCURRENT_REQUESTS: usize = 10;
+/// Where a demo run keeps its config and cache, under the temp directory,
+/// apart from the real ones.
+const DEMO_DIR: &str = "trailcache-demo";
 
-/// Maximum number of event guest lists to cache.
-/// Limits memory usage while keeping recently viewed events accessible.
-const MAX_EVENT_GUESTS_CACHE_SIZE: usize = 50;
+/// Demo sign-in, so `--demo` goes straight to the roster.
+const DEMO_CREDENTIAL: &str = "demo";
+
+/// Status shown once a demo run has signed in
+const DEMO_STATUS: &str = "Demo mode - a made-up troop; nothing is sent to Scoutbook";
+
+/// Skills matrix CSV, in the export directory, for spreadsheet round-trips.
+const SKILLS_CSV_FILE: &str = "skills.csv";
+
+/// Events calendar, in the export directory, for families to subscribe to.
+const ICAL_FILE: &str = "events.ics";
 
 // ============================================================================
 // Helper Functions
 // ============================================================================
 
-/// Create an authenticated API client with the given token.
-/// This is a free function to allow use inside spawned async tasks.
-fn create_authenticated_api(token: String) -> Result<ApiClient> {
-    let mut api = ApiClient::new()?;
-    api.set_token(token);
-    Ok(api)
+/// Open a URL or file with the platform's default handler (browser, PDF viewer).
+fn open_external(target: &str) -> io::Result<()> {
+    #[cfg(target_os = "macos")]
+    let mut cmd = Command::new("open");
+    #[cfg(target_os = "windows")]
+    let mut cmd = {
+        let mut c = Command::new("cmd");
+        c.args(["/C", "start", ""]);
+        c
+    };
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    let mut cmd = Command::new("xdg-open");
+
+    // Detach from the terminal so the viewer's output can't corrupt the TUI
+    cmd.arg(target)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map(|_| ())
 }
 
 // Re-export ScoutRank from core for use in TUI modules
@@ -78,97 +149,159 @@ pub use trailcache_core::models::ScoutRank;
 // UI State Types
 // ============================================================================
 
-/// Main navigation tabs
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum Tab {
-    Scouts,
-    Ranks,
-    Badges,
-    Events,
-    Adults,
-    Unit,
+/// A row of the Scouts table. Patrol headers only appear when grouped by patrol.
+#[derive(Debug, Clone, Copy)]
+pub enum RosterRow<'a> {
+    Patrol { name: &'a str, members: usize, collapsed: bool },
+    Scout(&'a Youth),
+}
+
+/// Where a global search result leads.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PaletteTarget {
+    Scout(i64),
+    Adult(i64),
+    Event { id: i64, view: EventListView },
+    Rank(String),
+    Badge(String),
 }
 
-impl Tab {
-    /// Get the display title for this tab.
-    pub fn title(&self) -> &'static str {
+impl PaletteTarget {
+    pub fn label(&self) -> &'static str {
         match self {
-            Tab::Scouts => "Scouts",
-            Tab::Ranks => "Ranks",
-            Tab::Badges => "Badges",
-            Tab::Events => "Events",
-            Tab::Adults => "Adults",
-            Tab::Unit => "Unit",
+            PaletteTarget::Scout(_) => "Scout",
+            PaletteTarget::Adult(_) => "Adult",
+            PaletteTarget::Event { .. } => "Event",
+            PaletteTarget::Rank(_) => "Rank",
+            PaletteTarget::Badge(_) => "Badge",
         }
     }
+}
+
+/// One match in the global search palette.
+#[derive(Debug, Clone)]
+pub struct PaletteHit {
+    pub target: PaletteTarget,
+    pub title: String,
+    /// Patrol, position, or date shown after the title
+    pub detail: String,
+    pub score: u32,
+}
+
+/// Which list the linked troop view shows
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkedList {
+    Scouts,
+    Adults,
+    Events,
+}
 
-    /// Get the next tab (wrapping around)
+impl LinkedList {
     pub fn next(&self) -> Self {
         match self {
-            Tab::Scouts => Tab::Ranks,
-            Tab::Ranks => Tab::Badges,
-            Tab::Badges => Tab::Events,
-            Tab::Events => Tab::Adults,
-            Tab::Adults => Tab::Unit,
-            Tab::Unit => Tab::Scouts,
+            LinkedList::Scouts => LinkedList::Adults,
+            LinkedList::Adults => LinkedList::Events,
+            LinkedList::Events => LinkedList::Scouts,
         }
     }
 
-    /// Get the previous tab (wrapping around)
-    pub fn prev(&self) -> Self {
+    pub fn label(&self) -> &'static str {
         match self {
-            Tab::Scouts => Tab::Unit,
-            Tab::Ranks => Tab::Scouts,
-            Tab::Badges => Tab::Ranks,
-            Tab::Events => Tab::Badges,
-            Tab::Adults => Tab::Events,
-            Tab::Unit => Tab::Adults,
+            LinkedList::Scouts => "Scouts",
+            LinkedList::Adults => "Adults",
+            LinkedList::Events => "Upcoming Events",
         }
     }
 }
 
-/// Sub-view for scout detail panel
+/// One line of the linked troop view.
+#[derive(Debug, Clone)]
+pub struct LinkedLine {
+    pub name: String,
+    pub detail: String,
+    pub troops: Troops,
+}
+
+/// Text being typed in the event templates view
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TemplateInput {
+    /// Start date and time of a new event from the selected template
+    StartDate,
+    /// Item to add to the selected template's packing list
+    PackingItem,
+}
+
+/// Text being typed in the minutes editor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MinutesInput {
+    Note,
+    /// "text @owner YYYY-MM-DD", owner and due date optional
+    ActionItem,
+}
+
+/// Text being typed in the historian log
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HistoryField {
+    Summary,
+    Highlight,
+    PhotoFolder,
+}
+
+/// Date being typed in the high-adventure screener
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum ScoutDetailView {
-    Details,
-    Ranks,
-    MeritBadges,
-    Leadership,
-    Awards,
+pub enum TrekField {
+    TrekDate,
+    /// Medical exam date for the selected person
+    ExamDate,
 }
 
-/// Sub-view for event detail panel
+/// Text being typed in the clinic planner
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum EventDetailView {
-    Details,
-    Rsvp,
+pub enum ClinicField {
+    Name,
+    Date,
+    /// Merit badge for a new class
+    Badge,
+    /// Counselor for the selected class
+    Counselor,
 }
 
-/// Advancement tab sub-view
+/// Text being typed in an Eagle project
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum AdvancementView {
-    Ranks,
-    MeritBadges,
+pub enum ProjectField {
+    Title,
+    Beneficiary,
+    /// "3.5" or "3.5 patrol helpers"
+    Hours,
+    /// Date of a troop calendar event that's a workday
+    Workday,
 }
 
-/// Current UI focus area (list panel or detail panel)
+/// Field being typed when linking a den chief to a pack
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum Focus {
-    List,
-    Detail,
+pub enum DenChiefField {
+    Pack,
+    Den,
+    /// Meeting day, e.g. "Tue" or "3rd Thu"
+    Meets,
 }
 
-/// Overall application state
+/// Field being typed when recording a medication dose
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum AppState {
-    Normal,
-    Searching,
-    ShowingHelp,
-    LoggingIn,
-    ConfirmingQuit,
-    ConfirmingOffline,
-    ConfirmingOnline,
-    Quitting,
+pub enum MedicationField {
+    Medication,
+    Dose,
+    GivenBy,
+}
+
+/// An accommodation note being typed for one requirement
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccommodationDraft {
+    pub user_id: i64,
+    pub advancement: AdvancementRef,
+    /// Requirement number including its parents
+    pub requirement: String,
+    pub text: String,
 }
 
 /// Login form focus state
@@ -183,119 +316,62 @@ pub enum LoginFocus {
 // Background Task Results
 // ============================================================================
 
-/// Result types from background refresh tasks.
-///
-/// These variants are sent through an MPSC channel from the background refresh
-/// task back to the main application. Each variant represents a different type
-/// of data that was fetched from the API.
-#[allow(dead_code)]
-enum RefreshResult {
-    /// Youth roster data fetched successfully
-    Youth(Vec<Youth>),
-    /// Adult roster data fetched successfully
-    Adults(Vec<Adult>),
-    /// Parent contact data fetched successfully
-    Parents(Vec<Parent>),
-    /// Patrol structure data fetched successfully
-    Patrols(Vec<Patrol>),
-    /// Calendar events fetched successfully
-    Events(Vec<Event>),
-    /// Detailed event info (RSVP list) for a single event
-    EventDetail(Event),
-    /// Advancement statistics dashboard
-    AdvancementDashboard(AdvancementDashboard),
-    /// Awards ready to be presented
-    ReadyToAward(Vec<ReadyToAward>),
-    /// Guest list for a specific event (event_id, guests)
-    EventGuests(i64, Vec<EventGuest>),
-    /// Rank progress for a specific youth (user_id, ranks)
-    YouthRanks(i64, Vec<RankProgress>),
-    /// Merit badge progress for a specific youth (user_id, badges)
-    YouthMeritBadges(i64, Vec<MeritBadgeProgress>),
-    /// Leadership position history for a specific youth (user_id, positions)
-    YouthLeadership(i64, Vec<LeadershipPosition>),
-    /// Awards for a specific youth (user_id, awards)
-    YouthAwards(i64, Vec<Award>),
-    /// Requirements for a specific rank (user_id, rank_id, requirements)
-    RankRequirements(i64, i64, Vec<RankRequirement>),
-    /// Requirements for a specific merit badge (user_id, badge_id, requirements, version, counselor)
-    BadgeRequirements(i64, i64, Vec<MeritBadgeRequirement>, Option<String>, Option<CounselorInfo>),
-    /// Key 3 leadership positions (SM, CC, COR)
-    Key3(Key3Leaders),
-    /// Unit PIN information (charter, contact info)
-    UnitPinInfo(UnitInfo),
-    /// Organization profile information
-    OrgProfile(OrgProfile),
-    /// Assigned commissioners for the unit
-    Commissioners(Vec<Commissioner>),
-    /// Signal that all refresh tasks have completed
-    RefreshComplete,
-    /// Progress update for offline caching (current, total, description)
-    CachingProgress(usize, usize, String),
-    /// Offline caching is complete
-    CachingComplete,
-    /// An error occurred during refresh
-    Error(String),
+/// Load progress of a dataset, used to choose a tab's loading or empty state.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum LoadState {
+    /// Nothing cached and no fetch has run yet
+    #[default]
+    NotLoaded,
+    /// A fetch is in progress
+    Loading,
+    /// Data came from the cache or a successful fetch
+    Loaded,
+    /// The most recent fetch failed (error message)
+    Failed(String),
+}
+
+/// Datasets a refresh hadn't delivered yet: still loading or failed.
+fn unfinished_datasets(load_states: &HashMap<RefreshDataset, LoadState>) -> Vec<RefreshDataset> {
+    RefreshDataset::ALL
+        .into_iter()
+        .filter(|d| matches!(load_states.get(d), Some(LoadState::Loading | LoadState::Failed(_))))
+        .collect()
 }
 
 // ============================================================================
 // Main Application Struct
 // ============================================================================
 
-#[derive(Default)]
-pub struct LayoutAreas {
-    pub title_bar: Rect,
-    pub tabs_bar: Rect,
-    pub left_panel: Rect,
-    pub right_panel: Rect,
-    pub detail_tabs_area: Rect,
-}
-
 /// Main application state container
 #[allow(dead_code)]
 pub struct App {
     // Core services
     pub config: Config,
     pub session: Session,
-    pub api: ApiClient,
+    pub api: Backend,
     pub cache: CacheManager,
-
-    // UI State
-    pub state: AppState,
-    pub current_tab: Tab,
-    pub focus: Focus,
-    pub search_query: String,
-    pub advancement_view: AdvancementView,
-    pub scout_sort_column: ScoutSortColumn,
-    pub scout_sort_ascending: bool,
-    pub scout_detail_view: ScoutDetailView,
-    pub event_detail_view: EventDetailView,
-    pub event_sort_column: EventSortColumn,
-    pub event_sort_ascending: bool,
-    pub viewing_rsvp_list: bool,
-    pub layout_areas: LayoutAreas,
-    pub left_table_state: TableState,
-    pub right_table_state: TableState,
-    pub last_click: Option<(u16, u16, Instant)>,
-
-    // Login form state
-    pub login_username: String,
-    pub login_password: String,
-    pub login_focus: LoginFocus,
-    pub login_error: Option<String>,
-
-    // Selection indices
-    pub roster_selection: usize,
-    pub adults_selection: usize,
+    /// User key bindings for the main screen
+    pub keymap: KeyMap,
+    /// Color themes the theme key cycles through
+    pub themes: ThemeChoices,
+
+    // Where the user is on screen
+    pub ui: UiState,
+    pub login: LoginForm,
+
+    // Selection indices. Top-level lists are keyed so they follow the
+    // selected item across re-sorts and refreshes.
+    pub roster_selection: ListSelection<String>,
+    pub adults_selection: ListSelection<String>,
     pub patrol_member_selection: usize,
     pub advancement_selection: usize,
     pub advancement_rank_selection: usize,
     pub advancement_badge_selection: usize,
-    pub event_selection: usize,
+    pub event_selection: ListSelection<i64>,
     pub event_guest_selection: usize,
 
     // Ranks tab state
-    pub ranks_selection: usize,
+    pub ranks_selection: ListSelection<String>,
     pub ranks_scout_selection: usize,
     pub ranks_viewing_requirements: bool,
     pub ranks_requirement_selection: usize,
@@ -303,36 +379,25 @@ pub struct App {
     pub ranks_sort_ascending: bool,
 
     // Badges tab state
-    pub badges_selection: usize,
+    pub badges_selection: ListSelection<String>,
     pub badges_scout_selection: usize,
     pub badges_viewing_requirements: bool,
     pub badges_requirement_selection: usize,
     pub badges_sort_by_count: bool,
     pub badges_sort_ascending: bool,
 
-    // Cached data
-    pub youth: Vec<Youth>,
-    pub adults: Vec<Adult>,
-    pub parents: Vec<Parent>,
-    pub patrols: Vec<Patrol>,
-    pub events: Vec<Event>,
-    pub advancement_dashboard: AdvancementDashboard,
-    pub ready_to_award: Vec<ReadyToAward>,
-    pub event_guests: HashMap<i64, Vec<EventGuest>>,
-    /// Tracks event_guests keys in access order (oldest first) for LRU eviction
-    event_guests_order: Vec<i64>,
-
-    /// Merit badge progress for all youth, keyed by user_id
-    pub all_youth_badges: HashMap<i64, Vec<MeritBadgeProgress>>,
-
-    /// Rank progress for all youth, keyed by user_id
-    pub all_youth_ranks: HashMap<i64, Vec<RankProgress>>,
-
-    // Unit info (domain types)
-    pub key3: Key3Leaders,
-    pub unit_info: Option<UnitInfo>,
-    pub org_profile: OrgProfile,
-    pub commissioners: Vec<Commissioner>,
+    // Eagle tab state
+    pub eagle_selection: ListSelection<i64>,
+    pub eagle_sort_column: EagleSortColumn,
+    pub eagle_sort_ascending: bool,
+
+    // Attendance tab state
+    pub attendance_selection: ListSelection<i64>,
+    /// Lowest attendance first instead of by name
+    pub attendance_sort_by_percent: bool,
+
+    /// Data fetched from Scoutbook
+    pub store: Store,
 
     // Individual youth data
     pub selected_youth_ranks: Vec<RankProgress>,
@@ -348,44 +413,178 @@ pub struct App {
     pub requirement_selection: usize,
     pub leadership_selection: usize,
     pub awards_selection: usize,
+    pub timeline_scroll: usize,
 
     // Track which requirements are currently being viewed (to prevent overwrites from background fetches)
     viewing_rank_user_id: Option<i64>,
     viewing_rank_id: Option<i64>,
     viewing_badge_user_id: Option<i64>,
     viewing_badge_id: Option<i64>,
+    /// Requirement waiting for the leader to confirm it's complete
+    pub pending_completion: Option<PendingCompletion>,
+    /// Scout waiting for the leader to pick a new patrol
+    pub patrol_move: Option<PatrolMove>,
+    /// Bulk correction of requirement completion dates
+    pub date_correction: DateCorrectionState,
 
     // Background task channel
     refresh_rx: Option<mpsc::Receiver<RefreshResult>>,
     refresh_tx: mpsc::Sender<RefreshResult>,
 
+    /// Datasets that failed during refresh and are scheduled for automatic retry
+    pub dataset_retries: DatasetRetries,
+    /// Per-dataset load progress for loading and empty states
+    pub load_states: HashMap<RefreshDataset, LoadState>,
+    /// When the app started, used to animate loading spinners
+    pub started_at: Instant,
+    /// When the running full refresh or offline caching began
+    pub refresh_started_at: Option<Instant>,
+    /// The running full refresh, so it can be stopped if the session expires
+    refresh_task: Option<tokio::task::JoinHandle<()>>,
+    /// Datasets to fetch again once the user logs back in, while a refresh
+    /// is paused on an expired session
+    pub reauth_pending: Option<HashSet<RefreshDataset>>,
+    /// Scoutbook answered with a maintenance or outage page, so views are
+    /// showing cached data. Cleared when a fetch succeeds.
+    pub scoutbook_down: bool,
+    /// Whether the terminal has focus, per focus-change events
+    pub terminal_focused: bool,
+
     // Status message
     pub status_message: Option<String>,
 
-    // Cache ages for status bar
-    pub cache_ages: trailcache_core::cache::CacheAges,
-
     // Offline mode - when true, only use cached data
     pub offline_mode: bool,
 
+    // Export profile for files written this session, starting from the config default
+    pub export_profile: ExportProfile,
+
+    // Merit badge workbook and pamphlet links
+    pub workbooks: WorkbookRegistry,
+
+    // Rank requirement cross-references, and whether the selected requirement's are expanded
+    pub crossrefs: CrossReferences,
+    pub crossref_expanded: bool,
+
+    // Activity feed of changes seen across refreshes, with the Feed panel's filters
+    pub feed: FeedState,
+    // This refresh's feed entries, sent to the webhook when it completes
+    refresh_changes: Vec<FeedEntry>,
+
+    // Scroll offset of the My Scouts view (scouts are pinned in config.my_scouts)
+    pub my_scouts_scroll: usize,
+
+    // Meeting timers, which keep running while the Timers overlay is closed
+    pub countdown: Countdown,
+    pub agenda: AgendaTimer,
+
+    // Overlay tools, each with its saved data and selection
+    pub nominations: NominationsState,
+    pub skills: SkillsState,
+    pub templates: TemplatesState,
+    pub todos: TodosState,
+    pub alerts: AlertsState,
+    pub library: LibraryState,
+    pub closet: ClosetState,
+    pub adult_skills: AdultSkillsState,
+    pub trek: TrekState,
+    pub eagle_projects: EagleProjectsState,
+    pub clinic: ClinicState,
+
+    // Global search palette: the text being typed and the selected match
+    pub palette_query: String,
+    pub palette_selection: usize,
+
+    // BSA ID lookup: the ID being typed and the selected match
+    pub member_id_query: String,
+    pub member_id_selection: usize,
+
+    // Contact check overlay
+    pub contact_selection: usize,
+
+    // Order of the Arrow eligibility overlay
+    pub oa_selection: usize,
+
+    // District mode, the linked troop, and the committee and planning tools
+    pub district: DistrictState,
+    pub linked: LinkedState,
+    pub minutes: MinutesState,
+    pub history: HistoryState,
+    pub counselors: CounselorsState,
+    pub planner: PlannerState,
+
+    // Post-event debriefs, and the note being typed with its kind
+    pub debriefs: Debriefs,
+    pub debrief_input: Option<(DebriefKind, String)>,
+
+    // Den chief links to partner packs
+    pub den_chiefs: DenChiefsState,
+
+    // Annual consent and media release forms signed by each scout's family
+    pub consents: Consents,
+
+    // Approved alternate requirements and accommodations
+    pub accommodations: Accommodations,
+    pub accommodation_input: Option<AccommodationDraft>,
+
+    // Tabs added by plugins, after the built-in ones
+    pub plugins: PluginRegistry,
+
+    // Automation rules from the config folder, the tags they've given, and
+    // each untagged rule's matches on its last run
+    pub automations: Automations,
+    pub scout_tags: ScoutTags,
+    automation_matches: HashMap<String, Vec<i64>>,
+
+    // Event and leader tools
+    pub buddies: BuddiesState,
+    pub activity_plans: ActivityPlansState,
+    pub medications: MedicationsState,
+    pub adult_awards: AdultAwardsState,
+    pub onboarding: OnboardingState,
+    pub committee: CommitteeState,
+
+    // Meeting attendance history, and the in-progress meeting-night sheet
+    pub attendance: AttendanceHistory,
+    pub meeting: MeetingSheet,
+
+    // Completeness audit, scope, and request estimate shown on the go-offline confirmation
+    pub offline_audit: Option<OfflineAudit>,
+    pub offline_scope: OfflineScope,
+    pub offline_estimate: u32,
+
     // Offline caching progress tracking
-    pub caching_in_progress: bool,
-    pub caching_current: usize,
-    pub caching_total: usize,
-    pub caching_description: String,
+    pub caching: CachingProgress,
 }
 
 impl App {
     /// Create a new application instance
     pub async fn new() -> Result<Self> {
         debug!("App::new() starting");
-        let config = match Config::load() {
+        let loaded = if services::is_demo() {
+            let dir = std::env::temp_dir().join(DEMO_DIR);
+            Config::load_from(dir.clone()).map(|mut c| {
+                c.set_cache_dir(dir);
+                c
+            })
+        } else {
+            Config::load()
+        };
+        let config = match loaded {
             Ok(c) => c,
             Err(e) => {
                 warn!(error = %e, "Failed to load config, using defaults");
                 Config::default()
             }
         };
+        Self::with_backend(config, services::create_api()?)
+    }
+
+    /// Create the app around a loaded config and an API backend. `new` picks
+    /// both at startup; tests pass a scratch config and a `DemoApi` holding
+    /// their own data.
+    pub fn with_backend(config: Config, mut api: Backend) -> Result<Self> {
+        let demo = api.is_demo();
         info!(
             org_guid = ?config.organization_guid,
             offline_mode = config.offline_mode,
@@ -400,8 +599,6 @@ impl App {
         let load_result = session.load();
         debug!(?load_result, has_data = session.data.is_some(), "Session loaded");
 
-        let mut api = ApiClient::new()?;
-
         // If we have a valid session, set the token on the API client
         if let Some(ref data) = session.data {
             debug!(expired = data.is_expired(), "Session found");
@@ -419,82 +616,85 @@ impl App {
         let (tx, rx) = mpsc::channel(CHANNEL_BUFFER_SIZE);
 
         // Get credentials from env vars or config
-        let login_username = std::env::var("SCOUTBOOK_USERNAME")
-            .ok()
-            .or_else(|| config.last_username.clone())
-            .unwrap_or_default();
-
-        let login_password = std::env::var("SCOUTBOOK_PASSWORD").unwrap_or_default();
+        let (login_username, login_password) = if demo {
+            (DEMO_CREDENTIAL.to_string(), DEMO_CREDENTIAL.to_string())
+        } else {
+            let username = std::env::var("SCOUTBOOK_USERNAME")
+                .ok()
+                .or_else(|| config.last_username.clone())
+                .unwrap_or_default();
+            (username, std::env::var("SCOUTBOOK_PASSWORD").unwrap_or_default())
+        };
 
         let offline_mode = config.offline_mode;
+        let config_export_profile = config.export_profile;
+        let workbooks = WorkbookRegistry::new(&config.workbook_links);
+        let crossrefs = config
+            .data_file_path(CROSSREFS_FILE)
+            .and_then(|path| CrossReferences::load(&path))
+            .unwrap_or_else(|e| {
+                warn!(error = %e, "Failed to load requirement cross-references");
+                CrossReferences::default()
+            });
+        let (keymap, key_problems) = KeyMap::new(&config.keybindings);
+        for problem in &key_problems {
+            warn!(problem = %problem, "Skipped key binding");
+        }
+        let (themes, theme_problem) = ThemeChoices::new(&config);
+        if let Some(problem) = &theme_problem {
+            warn!(problem = %problem, "Failed to load theme");
+        }
+        let automations = config
+            .data_file_path(AUTOMATIONS_FILE)
+            .and_then(|path| Automations::load(&path))
+            .unwrap_or_else(|e| {
+                warn!(error = %e, "Failed to load automation rules");
+                Automations::default()
+            });
 
         Ok(Self {
             config,
             session,
             api,
             cache,
+            keymap,
+            themes,
+
+            ui: UiState::default(),
+
+            login: LoginForm::new(login_username, login_password),
 
-            state: AppState::Normal,
-            current_tab: Tab::Scouts,
-            focus: Focus::List,
-            search_query: String::new(),
-            advancement_view: AdvancementView::Ranks,
-            scout_sort_column: ScoutSortColumn::Name,
-            scout_sort_ascending: true,
-            scout_detail_view: ScoutDetailView::Details,
-            event_detail_view: EventDetailView::Details,
-            event_sort_column: EventSortColumn::Date,
-            event_sort_ascending: true,
-            viewing_rsvp_list: false,
-            layout_areas: LayoutAreas::default(),
-            left_table_state: TableState::default(),
-            right_table_state: TableState::default(),
-            last_click: None,
-
-            login_username,
-            login_password,
-            login_focus: LoginFocus::Username,
-            login_error: None,
-
-            roster_selection: 0,
-            adults_selection: 0,
+            roster_selection: ListSelection::default(),
+            adults_selection: ListSelection::default(),
             patrol_member_selection: 0,
             advancement_selection: 0,
             advancement_rank_selection: 0,
             advancement_badge_selection: 0,
-            event_selection: 0,
+            event_selection: ListSelection::default(),
             event_guest_selection: 0,
 
-            ranks_selection: 0,
+            ranks_selection: ListSelection::default(),
             ranks_scout_selection: 0,
             ranks_viewing_requirements: false,
             ranks_requirement_selection: 0,
             ranks_sort_by_count: false,
             ranks_sort_ascending: false,
 
-            badges_selection: 0,
+            badges_selection: ListSelection::default(),
             badges_scout_selection: 0,
             badges_viewing_requirements: false,
             badges_requirement_selection: 0,
             badges_sort_by_count: false,
             badges_sort_ascending: true,
 
-            youth: Vec::new(),
-            adults: Vec::new(),
-            parents: Vec::new(),
-            patrols: Vec::new(),
-            events: Vec::new(),
-            advancement_dashboard: AdvancementDashboard::default(),
-            ready_to_award: Vec::new(),
-            event_guests: HashMap::new(),
-            event_guests_order: Vec::new(),
-            all_youth_badges: HashMap::new(),
-            all_youth_ranks: HashMap::new(),
-
-            key3: Default::default(),
-            unit_info: None,
-            org_profile: Default::default(),
-            commissioners: Vec::new(),
+            eagle_selection: ListSelection::default(),
+            eagle_sort_column: EagleSortColumn::Closest,
+            eagle_sort_ascending: true,
+
+            attendance_selection: ListSelection::default(),
+            attendance_sort_by_percent: false,
+
+            store: Store::default(),
 
             selected_youth_ranks: Vec::new(),
             selected_youth_badges: Vec::new(),
@@ -509,23 +709,99 @@ impl App {
             requirement_selection: 0,
             leadership_selection: 0,
             awards_selection: 0,
+            timeline_scroll: 0,
 
             viewing_rank_user_id: None,
             viewing_rank_id: None,
             viewing_badge_user_id: None,
             viewing_badge_id: None,
+            pending_completion: None,
+            patrol_move: None,
+            date_correction: DateCorrectionState::default(),
 
             refresh_rx: Some(rx),
             refresh_tx: tx,
 
-            status_message: None,
-            cache_ages: Default::default(),
+            dataset_retries: DatasetRetries::default(),
+            load_states: HashMap::new(),
+            started_at: Instant::now(),
+            refresh_started_at: None,
+            refresh_task: None,
+            reauth_pending: None,
+            scoutbook_down: false,
+            terminal_focused: true,
+
+            status_message: theme_problem.or_else(|| key_problems.first().cloned()),
             offline_mode,
 
-            caching_in_progress: false,
-            caching_current: 0,
-            caching_total: 0,
-            caching_description: String::new(),
+            export_profile: config_export_profile,
+
+            workbooks,
+
+            crossrefs,
+            crossref_expanded: false,
+
+            feed: FeedState::default(),
+            refresh_changes: Vec::new(),
+            my_scouts_scroll: 0,
+            countdown: Countdown::new(TIMER_PRESETS[0].0, TIMER_PRESETS[0].1),
+            agenda: AgendaTimer::default(),
+
+            nominations: NominationsState::default(),
+            skills: SkillsState::default(),
+            templates: TemplatesState::default(),
+            todos: TodosState::default(),
+            alerts: AlertsState::default(),
+            library: LibraryState::default(),
+            closet: ClosetState::default(),
+            adult_skills: AdultSkillsState::default(),
+            trek: TrekState::default(),
+            eagle_projects: EagleProjectsState::default(),
+            clinic: ClinicState::default(),
+
+            palette_query: String::new(),
+            palette_selection: 0,
+            member_id_query: String::new(),
+            member_id_selection: 0,
+            contact_selection: 0,
+            oa_selection: 0,
+
+            district: DistrictState::default(),
+            linked: LinkedState::default(),
+            minutes: MinutesState::default(),
+            history: HistoryState::default(),
+            counselors: CounselorsState::default(),
+            planner: PlannerState::default(),
+
+            den_chiefs: DenChiefsState::default(),
+            debriefs: Debriefs::default(),
+            debrief_input: None,
+
+            consents: Consents::default(),
+            accommodations: Accommodations::default(),
+            accommodation_input: None,
+
+            plugins: PluginRegistry::builtin(),
+
+            automations,
+            scout_tags: ScoutTags::default(),
+            automation_matches: HashMap::new(),
+
+            buddies: BuddiesState::default(),
+            activity_plans: ActivityPlansState::default(),
+            medications: MedicationsState::default(),
+            adult_awards: AdultAwardsState::default(),
+            onboarding: OnboardingState::default(),
+            committee: CommitteeState::default(),
+
+            attendance: AttendanceHistory::default(),
+            meeting: MeetingSheet::default(),
+
+            offline_audit: None,
+            offline_scope: OfflineScope::default(),
+            offline_estimate: 0,
+
+            caching: CachingProgress::default(),
         })
     }
 
@@ -620,43 +896,48 @@ impl App {
 
     /// Attempt login with the credentials from the login form
     pub async fn attempt_login(&mut self) -> Result<()> {
-        let username = self.login_username.clone();
-        let password = self.login_password.clone();
+        let username = self.login.username.clone();
+        let password = self.login.password.clone();
 
         if username.is_empty() || password.is_empty() {
-            self.login_error = Some("Username and password required".to_string());
+            self.login.error = Some("Username and password required".to_string());
             return Err(anyhow::anyhow!("Username and password required"));
         }
 
-        self.login_error = None;
+        self.login.error = None;
 
         // If offline mode, just derive key and load cache (no API call)
         if self.offline_mode {
             if let Some(ref org_guid) = self.config.organization_guid {
                 // Derive encryption key from password
                 self.cache.set_password(&password, org_guid);
+                let key_error = self.apply_cache_key_source(&org_guid.clone());
+                self.open_district_caches(&password);
+                self.open_linked_cache(&password);
 
                 // Try to load cache to verify password is correct
                 if let Err(e) = self.load_from_cache() {
-                    self.login_error = Some("Failed to decrypt cache. Wrong password?".to_string());
+                    self.login.error = Some(key_error.unwrap_or_else(|| "Failed to decrypt cache. Wrong password?".to_string()));
                     return Err(e);
                 }
 
                 // Check if we actually loaded any data
-                if self.youth.is_empty() {
-                    self.login_error = Some("No cached data found. Go online to download.".to_string());
+                if self.store.youth.is_empty() {
+                    self.login.error =
+                        Some(key_error.unwrap_or_else(|| "No cached data found. Go online to download.".to_string()));
                     return Err(anyhow::anyhow!("No cached data"));
                 }
 
                 self.config.last_username = Some(username);
                 let _ = self.config.save();
-                self.login_password.clear();
-                self.state = AppState::Normal;
-                self.status_message = Some("Offline mode - loaded from cache".to_string());
+                self.login.password.clear();
+                self.ui.state = AppState::Normal;
+                self.open_alerts_on_startup();
+                self.status_message = Some(key_error.unwrap_or_else(|| "Offline mode - loaded from cache".to_string()));
                 info!("Offline login successful - loaded from cache");
                 return Ok(());
             } else {
-                self.login_error = Some("No organization configured. Go online first.".to_string());
+                self.login.error = Some("No organization configured. Go online first.".to_string());
                 return Err(anyhow::anyhow!("No organization GUID"));
             }
         }
@@ -664,7 +945,9 @@ impl App {
         // Online mode - authenticate with API
         match self.api.authenticate(&username, &password).await {
             Ok(session_data) => {
-                if let Err(e) = CredentialStore::store(&username, &password) {
+                if self.api.is_demo() {
+                    self.status_message = Some(DEMO_STATUS.to_string());
+                } else if let Err(e) = CredentialStore::store(&username, &password) {
                     warn!(error = %e, "Failed to store credentials");
                 }
 
@@ -673,6 +956,9 @@ impl App {
 
                 // Enable cache encryption with password-derived key
                 self.cache.set_password(&password, &session_data.organization_guid);
+                let key_error = self.apply_cache_key_source(&session_data.organization_guid);
+                self.open_district_caches(&password);
+                self.open_linked_cache(&password);
 
                 if let Err(e) = self.config.save() {
                     warn!(error = %e, "Failed to save config");
@@ -688,37 +974,61 @@ impl App {
                     self.api.set_token(data.token.clone());
                 }
 
-                // Load any existing cache and refresh
+                // Load any existing cache, then finish a refresh interrupted
+                // by the expired session or refresh if the cache is stale
                 let _ = self.load_from_cache();
-                if self.is_cache_stale() {
+                if let Some(pending) = self.reauth_pending.take() {
+                    self.resume_refresh(pending);
+                } else if self.is_cache_stale() {
                     self.refresh_all_background().await;
                 }
 
-                self.login_password.clear();
-                self.state = AppState::Normal;
+                self.login.password.clear();
+                self.ui.state = AppState::Normal;
+                self.open_alerts_on_startup();
+                if key_error.is_some() {
+                    self.status_message = key_error;
+                }
                 info!("Login successful");
                 Ok(())
             }
             Err(e) => {
                 // Don't log to stderr - it corrupts the TUI display
                 // Show error, clear password, and focus password field for retry
-                self.login_error = Some("LOGIN FAILED".to_string());
-                self.login_password.clear();
-                self.login_focus = LoginFocus::Password;
+                let message = match ApiError::classify(&e) {
+                    // A 401 from the auth endpoint means the credentials were rejected
+                    ApiErrorKind::AuthExpired => "INVALID USERNAME OR PASSWORD",
+                    ApiErrorKind::Forbidden => "ACCOUNT NOT AUTHORIZED",
+                    ApiErrorKind::RateLimited => "SERVER BUSY - TRY AGAIN SOON",
+                    ApiErrorKind::Maintenance => "SCOUTBOOK IS DOWN FOR MAINTENANCE",
+                    ApiErrorKind::Network => "NETWORK ERROR - CHECK CONNECTION",
+                    _ => "LOGIN FAILED",
+                };
+                self.login.error = Some(message.to_string());
+                self.login.password.clear();
+                self.login.focus = LoginFocus::Password;
                 Err(e)
             }
         }
     }
 
+    /// Apply the `keyring_cache_key` setting once the password key is set.
+    /// Returns what to tell the user if it couldn't be applied.
+    fn apply_cache_key_source(&mut self, org_guid: &str) -> Option<String> {
+        let e = apply_key_source(&mut self.cache, org_guid, self.config.keyring_cache_key).err()?;
+        error!(error = %e, "Failed to apply the cache key setting");
+        Some(format!("Could not switch the cache key: {:#}", e))
+    }
+
     /// Start the login process (show login overlay)
     pub fn start_login(&mut self) {
-        self.state = AppState::LoggingIn;
-        self.login_focus = if self.login_username.is_empty() {
+        self.ui.state = AppState::LoggingIn;
+        self.login.focus = if self.login.username.is_empty() {
             LoginFocus::Username
         } else {
             LoginFocus::Password
         };
-        self.login_error = None;
+        self.login.error = None;
     }
 
     // =========================================================================
@@ -727,76 +1037,196 @@ impl App {
 
     /// Load all data from cache
     pub fn load_from_cache(&mut self) -> Result<()> {
-        info!(cache_dir = ?self.cache.cache_dir(), "Loading from cache");
-
-        match self.cache.load_youth() {
-            Ok(Some(cached)) => {
-                info!(count = cached.data.len(), age = %cached.age_display(), "Loaded youth from cache");
-                self.youth = cached.data;
-            }
-            Ok(None) => {
-                info!("No youth cache found");
-            }
-            Err(e) => {
-                warn!(error = %e, "Failed to load youth cache");
-            }
+        let today = Local::now().date_naive();
+        for dataset in self.store.load(&self.cache, today) {
+            self.load_states.insert(dataset, LoadState::Loaded);
         }
 
-        if let Ok(Some(cached)) = self.cache.load_adults() {
-            info!(count = cached.data.len(), "Loaded adults from cache");
-            self.adults = cached.data;
+        if let Ok(Some(cached)) = self.cache.load_activity_feed() {
+            self.feed.entries = cached.data;
         }
-
-        if let Ok(Some(cached)) = self.cache.load_parents() {
-            self.parents = cached.data;
+        if let Ok(Some(cached)) = self.cache.load_attendance() {
+            self.attendance = cached.data;
         }
-
-        if let Ok(Some(cached)) = self.cache.load_patrols() {
-            self.patrols = cached.data;
+        if let Ok(Some(cached)) = self.cache.load_adult_awards() {
+            self.adult_awards.checklists = cached.data;
         }
-
-        if let Ok(Some(cached)) = self.cache.load_events() {
-            self.events = cached.data;
+        if let Ok(Some(cached)) = self.cache.load_committee_roles() {
+            self.committee.roles = cached.data;
         }
-
-        if let Ok(Some(cached)) = self.cache.load_advancement_dashboard() {
-            self.advancement_dashboard = cached.data;
+        if let Ok(Some(cached)) = self.cache.load_onboarding() {
+            self.onboarding.checklists = cached.data;
         }
-
-        if let Ok(Some(cached)) = self.cache.load_ready_to_award() {
-            self.ready_to_award = cached.data;
+        if let Ok(Some(cached)) = self.cache.load_activity_plans() {
+            self.activity_plans.plans = cached.data;
         }
-
-        if let Ok(Some(cached)) = self.cache.load_unit_info() {
-            self.unit_info = Some(cached.data.with_computed_fields());
+        if let Ok(Some(cached)) = self.cache.load_event_templates() {
+            self.templates.templates = cached.data;
         }
-
-        if let Ok(Some(cached)) = self.cache.load_key3() {
-            self.key3 = cached.data;
+        if let Ok(Some(cached)) = self.cache.load_todos() {
+            self.todos.list = cached.data;
+        }
+        if let Ok(Some(cached)) = self.cache.load_library() {
+            self.library.titles = cached.data;
+        }
+        if let Ok(Some(cached)) = self.cache.load_closet() {
+            self.closet.items = cached.data;
+        }
+        if let Ok(Some(cached)) = self.cache.load_adult_skills() {
+            self.adult_skills.directory = cached.data;
+        }
+        if let Ok(Some(cached)) = self.cache.load_high_adventure() {
+            self.trek.plan = cached.data;
+        }
+        if let Ok(Some(cached)) = self.cache.load_eagle_projects() {
+            self.eagle_projects.projects = cached.data;
+        }
+        if let Ok(Some(cached)) = self.cache.load_clinic() {
+            self.clinic.plan = cached.data;
+        }
+        if let Ok(Some(cached)) = self.cache.load_minutes() {
+            self.minutes.meetings = cached.data;
+        }
+        if let Ok(Some(cached)) = self.cache.load_historian_log() {
+            self.history.log = cached.data;
+        }
+        if let Ok(Some(cached)) = self.cache.load_debriefs() {
+            self.debriefs = cached.data;
+        }
+        if let Ok(Some(cached)) = self.cache.load_consents() {
+            self.consents = cached.data;
+        }
+        if let Ok(Some(cached)) = self.cache.load_accommodations() {
+            self.accommodations = cached.data;
+        }
+        if let Ok(Some(cached)) = self.cache.load_den_chiefs() {
+            self.den_chiefs.links = cached.data;
+        }
+        if let Ok(Some(cached)) = self.cache.load_position_plan() {
+            self.planner.plan = cached.data;
         }
+        if let Ok(Some(cached)) = self.cache.load_skills() {
+            self.skills.matrix = cached.data;
+        }
+        if let Ok(Some(cached)) = self.cache.load_nominations() {
+            self.nominations.list = cached.data;
+        }
+        if let Ok(Some(cached)) = self.cache.load_scout_tags() {
+            self.scout_tags = cached.data;
+        }
+        if self.status_message.is_none() {
+            self.status_message = self.nomination_reminder();
+        }
+
+        self.refresh_unit_stats();
+        self.scan_alerts();
+        Ok(())
+    }
+
+    /// Tab bar labels in order: the built-in tabs on 1–8, then plugin tabs.
+    pub fn tab_labels(&self) -> Vec<(Tab, String)> {
+        let builtin = [Tab::Scouts, Tab::Ranks, Tab::Badges, Tab::Events, Tab::Adults, Tab::Unit, Tab::Eagle, Tab::Attendance];
+        let plugins = (0..self.plugins.len()).map(Tab::Plugin);
+        builtin
+            .into_iter()
+            .chain(plugins)
+            .enumerate()
+            .map(|(i, tab)| {
+                let title = match tab {
+                    Tab::Plugin(p) => self.plugins.title(p).unwrap_or_default(),
+                    tab => tab.title(),
+                };
+                (tab, format!("[{}] {}", i + 1, title))
+            })
+            .collect()
+    }
 
-        if let Ok(Some(cached)) = self.cache.load_org_profile() {
-            self.org_profile = cached.data;
+    /// Title of the current tab, including plugin tabs.
+    pub fn tab_title(&self) -> &str {
+        match self.ui.current_tab {
+            Tab::Plugin(i) => self.plugins.title(i).unwrap_or_default(),
+            tab => tab.title(),
         }
+    }
 
-        if let Ok(Some(cached)) = self.cache.load_commissioners() {
-            self.commissioners = cached.data;
+    /// Call `f` with plugin tab `index`, lent out of the registry so it can
+    /// use the rest of the app.
+    pub fn with_plugin<R>(&mut self, index: usize, f: impl FnOnce(&mut dyn TabPlugin, &mut App) -> R) -> Option<R> {
+        let mut plugins = std::mem::take(&mut self.plugins);
+        let result = plugins.get_mut(index).map(|plugin| f(plugin, self));
+        self.plugins = plugins;
+        result
+    }
+
+    /// Run the automation rules against the current roster and events:
+    /// sync each rule's tag, notify about newly matching scouts, and write
+    /// the match list where a rule asks for it.
+    pub fn run_automations(&mut self) {
+        if self.automations.rules.is_empty() {
+            return;
         }
+        let today = Local::now().date_naive();
+        let mut tags_changed = false;
+        for rule in self.automations.rules.clone() {
+            let matched = rule.matching(&self.store.youth, &self.store.events, today);
+            let ids: Vec<i64> = matched.iter().filter_map(|y| y.user_id).collect();
+            let new = match &rule.tag {
+                Some(tag) => {
+                    let before = self.scout_tags.tags.clone();
+                    let added = self.scout_tags.sync(tag, &ids);
+                    tags_changed |= self.scout_tags.tags != before;
+                    added
+                }
+                None => {
+                    let previous = self.automation_matches.insert(rule.name.clone(), ids.clone()).unwrap_or_default();
+                    ids.iter().copied().filter(|id| !previous.contains(id)).collect()
+                }
+            };
+            debug!(rule = %rule.name, matched = ids.len(), new = new.len(), "Ran automation rule");
 
-        // Load per-youth ranks and badges for Ranks/Badges tabs
-        for youth in &self.youth {
-            if let Some(user_id) = youth.user_id {
-                if let Ok(Some(cached)) = self.cache.load_youth_ranks(user_id) {
-                    self.all_youth_ranks.insert(user_id, cached.data);
+            if rule.notify && !new.is_empty() {
+                let names: Vec<String> = new.iter().map(|&id| self.youth_name(id)).collect();
+                self.notify(&rule.name, &format!("{} newly matching: {}", names.len(), names.join(", ")));
+            }
+            if rule.export {
+                let with_age = self.export_profile.includes(ExportField::Age);
+                let header: &[&str] = if with_age { &["Name", "Patrol", "Rank", "Age"] } else { &["Name", "Patrol", "Rank"] };
+                let mut csv = csv_line(header);
+                csv.push('\n');
+                for youth in &matched {
+                    let mut row = vec![youth.short_name(), youth.patrol(), youth.rank()];
+                    if with_age {
+                        row.push(youth.age_str());
+                    }
+                    csv.push_str(&csv_line(&row));
+                    csv.push('\n');
                 }
-                if let Ok(Some(cached)) = self.cache.load_youth_merit_badges(user_id) {
-                    self.all_youth_badges.insert(user_id, cached.data);
+                let slug: String = rule
+                    .name
+                    .to_lowercase()
+                    .chars()
+                    .map(|c| if c.is_alphanumeric() { c } else { '-' })
+                    .collect();
+                let result = self.config.export_dir().and_then(|dir| {
+                    std::fs::create_dir_all(&dir)?;
+                    std::fs::write(dir.join(format!("automation-{}.csv", slug)), csv)?;
+                    Ok(())
+                });
+                if let Err(e) = result {
+                    warn!(error = %e, rule = %rule.name, "Failed to write automation export");
                 }
             }
         }
+        if tags_changed {
+            if let Err(e) = self.cache.save_scout_tags(&self.scout_tags) {
+                warn!(error = %e, "Failed to save scout tags");
+            }
+        }
+    }
 
-        self.cache_ages = self.cache.get_cache_ages();
-        Ok(())
+    /// Recompute the unit-wide aggregates from the loaded data.
+    pub fn refresh_unit_stats(&mut self) {
+        self.store.refresh_unit_stats(&self.attendance, &self.feed.entries, Local::now().date_naive());
     }
 
     /// Check if any cache data is stale
@@ -825,13 +1255,10 @@ impl App {
             }
         };
 
-        let token = match self.session.token() {
-            Some(t) => Arc::new(t.to_string()),
-            None => {
-                warn!("No token available for refresh");
-                return;
-            }
-        };
+        if self.session.token().is_none() {
+            warn!("No token available for refresh");
+            return;
+        }
 
         let user_id = match self.session.user_id() {
             Some(id) => id,
@@ -843,16 +1270,92 @@ impl App {
 
         let tx = self.refresh_tx.clone();
 
-        tokio::spawn(async move {
-            Self::execute_background_refresh(tx, org_guid, token, user_id).await;
-        });
+        // A full refresh supersedes any pending per-dataset retries
+        self.dataset_retries.clear();
+        for dataset in RefreshDataset::ALL {
+            self.load_states.insert(dataset, LoadState::Loading);
+        }
+
+        let focus = self.refresh_focus();
+        let api = self.refresh_api();
+        self.refresh_started_at = Some(Instant::now());
+        self.refresh_task = Some(tokio::spawn(async move {
+            services::refresh_all(tx, api, org_guid, user_id, focus).await;
+        }));
 
         self.status_message = Some("Refreshing data...".to_string());
     }
 
+    /// The API client for background refreshes, whose GET requests are
+    /// conditional so unchanged payloads come from the cache.
+    fn refresh_api(&self) -> Backend {
+        self.api.clone().with_validators(Arc::new(self.cache.clone()))
+    }
+
+    /// The visible tab and the scout selected on it, fetched first.
+    fn refresh_focus(&self) -> RefreshFocus {
+        let user_id = match self.ui.current_tab {
+            Tab::Scouts => self.selected_youth().and_then(|y| y.user_id),
+            Tab::Ranks => self.viewing_rank_user_id,
+            Tab::Badges => self.viewing_badge_user_id,
+            _ => None,
+        };
+        RefreshFocus::new(self.ui.current_tab, user_id)
+    }
+
+    /// Audit the offline bundle and ask the user to confirm going offline.
+    pub fn confirm_offline(&mut self) {
+        self.refresh_offline_audit();
+        self.ui.state = AppState::ConfirmingOffline;
+    }
+
+    /// Re-run the completeness audit and request estimate for the current scope.
+    fn refresh_offline_audit(&mut self) {
+        self.offline_audit = Some(audit_offline_cache(&self.cache, &self.offline_scope));
+        self.offline_estimate = estimate_offline_requests(&self.cache, &self.offline_scope);
+    }
+
+    /// Cycle the offline scope through all scouts, then each patrol in turn.
+    pub fn cycle_offline_patrol(&mut self) {
+        let mut patrols: Vec<String> = self.store.youth.iter().filter_map(|y| y.patrol_name.clone()).collect();
+        patrols.sort_by_key(|p| p.to_lowercase());
+        patrols.dedup();
+
+        let next = match &self.offline_scope.patrols {
+            None => patrols.first().cloned(),
+            Some(current) => current
+                .first()
+                .and_then(|c| patrols.iter().position(|p| p == c))
+                .and_then(|i| patrols.get(i + 1).cloned()),
+        };
+        self.offline_scope.patrols = next.map(|p| vec![p]);
+        self.refresh_offline_audit();
+    }
+
+    /// Toggle whether offline caching skips completed ranks and badges.
+    pub fn toggle_offline_active_only(&mut self) {
+        self.offline_scope.active_only = !self.offline_scope.active_only;
+        self.refresh_offline_audit();
+    }
+
     /// Enter offline mode - cache all data and work from cache only.
     pub async fn go_offline(&mut self) {
         info!("Entering offline mode - caching all data");
+        self.offline_audit = None;
+        self.start_offline_caching(None);
+    }
+
+    /// Enter offline mode after fetching only what the completeness audit found missing.
+    pub async fn fill_offline_gaps(&mut self) {
+        let audit = self
+            .offline_audit
+            .take()
+            .unwrap_or_else(|| audit_offline_cache(&self.cache, &self.offline_scope));
+        info!(gaps = audit.gaps().len(), "Entering offline mode - fetching missing data");
+        self.start_offline_caching(Some(audit));
+    }
+
+    fn start_offline_caching(&mut self, audit: Option<OfflineAudit>) {
 
         // First, do a full refresh to ensure cache is current
         let org_guid = match &self.config.organization_guid {
@@ -864,14 +1367,11 @@ impl App {
             }
         };
 
-        let token = match self.session.token() {
-            Some(t) => Arc::new(t.to_string()),
-            None => {
-                warn!("No token available");
-                self.status_message = Some("Error: Not authenticated".to_string());
-                return;
-            }
-        };
+        if self.session.token().is_none() {
+            warn!("No token available");
+            self.status_message = Some("Error: Not authenticated".to_string());
+            return;
+        }
 
         let user_id = match self.session.user_id() {
             Some(id) => id,
@@ -883,17 +1383,20 @@ impl App {
         };
 
         let tx = self.refresh_tx.clone();
+        let api = self.api.clone();
         let cache = self.cache.clone();
+        let scope = self.offline_scope.clone();
 
         // Set caching in progress state
-        self.caching_in_progress = true;
-        self.caching_current = 0;
-        self.caching_total = 0;
-        self.caching_description = "Starting...".to_string();
+        self.caching.in_progress = true;
+        self.caching.current = 0;
+        self.caching.total = 0;
+        self.caching.description = "Starting...".to_string();
         self.status_message = Some("Caching data for offline mode: Starting...".to_string());
+        self.refresh_started_at = Some(Instant::now());
 
         tokio::spawn(async move {
-            Self::execute_offline_caching(tx, org_guid, token, user_id, cache).await;
+            services::cache_for_offline(tx, api, org_guid, user_id, cache, scope, audit).await;
         });
     }
 
@@ -908,632 +1411,291 @@ impl App {
         self.start_login();
     }
 
+    /// Switch to the next color theme and remember it in the config.
+    pub fn cycle_theme(&mut self) {
+        let name = self.themes.next().to_string();
+        self.config.theme = Some(name.clone());
+        self.status_message = Some(match self.config.save() {
+            Ok(()) => format!("Theme: {}", name),
+            Err(e) => {
+                warn!(error = %e, "Failed to save theme");
+                format!("Theme: {} (not saved: {})", name, e)
+            }
+        });
+    }
+
     /// Check if app should show offline mode prompt on startup
     #[allow(dead_code)]
     pub fn should_prompt_offline_on_startup(&self) -> bool {
         self.offline_mode
     }
 
-    /// Helper to send refresh results, logging any channel errors
-    async fn send_result(tx: &mpsc::Sender<RefreshResult>, result: RefreshResult) {
-        if let Err(e) = tx.send(result).await {
-            error!(error = %e, "Failed to send refresh result - channel closed");
-        }
-    }
-
-    /// Execute the background refresh task.
-    ///
-    /// This function runs in a spawned Tokio task and fetches all data from the
-    /// Scouting API in parallel. Results are sent back through the MPSC channel
-    /// as `RefreshResult` variants.
-    ///
-    /// # Arguments
-    /// * `tx` - Channel sender to communicate results back to main app
-    /// * `org_guid` - Organization GUID for API requests
-    /// * `token` - Authentication token for API requests
-    /// * `user_id` - User ID for user-specific data (events)
-    ///
-    /// # Behavior
-    /// - Creates multiple API clients for parallel requests
-    /// - Fetches youth, adults, parents, patrols, events concurrently
-    /// - Fetches event details with limited concurrency (MAX_CONCURRENT_REQUESTS)
-    /// - Sends RefreshComplete when all fetches are done
-    async fn execute_background_refresh(
-        tx: mpsc::Sender<RefreshResult>,
-        org_guid: Arc<String>,
-        token: Arc<String>,
-        user_id: i64,
-    ) {
-        info!("Background refresh task started");
-
-        let base_api = match ApiClient::new() {
-            Ok(api) => api,
-            Err(e) => {
-                error!(error = %e, "Failed to create API client");
-                Self::send_result(&tx, RefreshResult::Error("Failed to create API client".to_string())).await;
-                return;
+    /// Check for completed background tasks and process results
+    pub async fn check_background_tasks(&mut self) {
+        // Collect all pending results first to avoid borrow conflicts
+        let results: Vec<RefreshResult> = {
+            if let Some(ref mut rx) = self.refresh_rx {
+                let mut results = Vec::new();
+                while let Ok(result) = rx.try_recv() {
+                    results.push(result);
+                }
+                results
+            } else {
+                Vec::new()
             }
         };
 
-        // Create API clients for parallel fetching.
-        // The TUI's process_refresh_result handles caching, so we just fetch here.
-        let api = base_api.with_token(Arc::clone(&token));
-        let api2 = api.clone();
-        let api3 = api.clone();
-        let api4 = api.clone();
-        let api5 = api.clone();
-        let api6 = api.clone();
-        let api7 = api.clone();
-        let api8 = api.clone();
-        let api9 = api.clone();
-        let api10 = api.clone();
-
-        let org2 = Arc::clone(&org_guid);
-        let org3 = Arc::clone(&org_guid);
-        let org4 = Arc::clone(&org_guid);
-        let org5 = Arc::clone(&org_guid);
-        let org6 = Arc::clone(&org_guid);
-        let org7 = Arc::clone(&org_guid);
-        let org8 = Arc::clone(&org_guid);
-
-        // Fetch all main data in parallel
-        let (youth_res, adults_res, parents_res, patrols_res, events_res, dashboard_res, ready_res, key3_res, pin_res, profile_res) = tokio::join!(
-            api.fetch_youth(&org_guid),
-            api2.fetch_adults(&org2),
-            api3.fetch_parents(&org3),
-            api4.fetch_patrols(&org4),
-            api5.fetch_events(user_id),
-            api6.fetch_advancement_dashboard(&org5),
-            api7.fetch_ready_to_award(&org6),
-            api8.fetch_key3(&org7),
-            api9.fetch_unit_pin(&org8),
-            api10.fetch_org_profile(&org_guid),
-        );
-
-        // Extract youth user IDs before moving youth_res
-        let youth_user_ids: Vec<i64> = youth_res
-            .as_ref()
-            .map(|list| list.iter().filter_map(|y| y.user_id).collect())
-            .unwrap_or_default();
-
-        // Deduplicate adults before sending
-        let adults_res = adults_res.map(Adult::deduplicate);
-
-        // Process and send results
-        Self::send_fetch_result(&tx, "Youth", youth_res, RefreshResult::Youth).await;
-        Self::send_fetch_result(&tx, "Adults", adults_res, RefreshResult::Adults).await;
-        Self::send_fetch_result_or_empty(&tx, "Parents", parents_res, RefreshResult::Parents, vec![]).await;
-        Self::send_fetch_result_or_empty(&tx, "Patrols", patrols_res, RefreshResult::Patrols, vec![]).await;
-        Self::send_fetch_result_or_default(&tx, "Dashboard", dashboard_res, RefreshResult::AdvancementDashboard).await;
-        Self::send_fetch_result_or_empty(&tx, "ReadyToAward", ready_res, RefreshResult::ReadyToAward, vec![]).await;
-        Self::send_key3_result(&tx, key3_res).await;
-        Self::send_pin_result(&tx, pin_res).await;
-        Self::send_profile_result(&tx, profile_res).await;
-
-        // Handle events with detail fetches
-        Self::handle_events_refresh(&tx, events_res, &token).await;
-
-        // Fetch commissioners separately
-        let api_commissioners = base_api.with_token(Arc::clone(&token));
-        match api_commissioners.fetch_commissioners(&org_guid).await {
-            Ok(commissioners) => {
-                debug!(count = commissioners.len(), "Commissioners fetched");
-                Self::send_result(&tx, RefreshResult::Commissioners(commissioners)).await;
-            }
-            Err(e) => {
-                debug!(error = %e, "Failed to fetch commissioners");
+        // Now process all results
+        let received = !results.is_empty();
+        let roster_or_events = results.iter().any(|r| matches!(r, RefreshResult::Data(update) if matches!(**update, Update::Youth(_) | Update::Events(_))));
+        for result in results {
+            self.process_refresh_result(result);
+        }
+        if received {
+            self.refresh_unit_stats();
+            self.scan_alerts();
+        }
+        if roster_or_events {
+            self.run_automations();
+        }
+        if received {
+            let mut plugins = std::mem::take(&mut self.plugins);
+            for plugin in plugins.iter_mut() {
+                plugin.on_refresh(self);
             }
+            self.plugins = plugins;
         }
 
-        // Fetch rank and merit badge progress for all youth (TUI-specific)
-        Self::handle_all_youth_advancement_refresh(&tx, &youth_user_ids, &token).await;
-
-        info!("Background refresh complete");
-        Self::send_result(&tx, RefreshResult::RefreshComplete).await;
+        self.spawn_due_retries();
+        self.tick_timers();
     }
 
-    /// Execute offline caching using the shared core function.
-    /// Delegates all fetching + caching to `trailcache_core::cache::cache_all_for_offline`,
-    /// forwarding progress via the refresh channel.
-    async fn execute_offline_caching(
-        tx: mpsc::Sender<RefreshResult>,
-        org_guid: Arc<String>,
-        token: Arc<String>,
-        user_id: i64,
-        cache: CacheManager,
-    ) {
-        info!("Offline caching task started");
-
-        let base_api = match ApiClient::new() {
-            Ok(api) => api,
-            Err(e) => {
-                error!(error = %e, "Failed to create API client for offline caching");
-                Self::send_result(&tx, RefreshResult::Error("Failed to create API client".to_string())).await;
-                return;
-            }
-        };
-
-        let api = base_api.with_token(token);
-
-        let tx_progress = tx.clone();
-        let result = trailcache_core::cache::cache_all_for_offline(
-            &api,
-            &cache,
-            &org_guid,
-            user_id,
-            |progress| {
-                // Forward progress to the TUI's refresh channel (best-effort)
-                let _ = tx_progress.try_send(RefreshResult::CachingProgress(
-                    progress.current as usize,
-                    progress.total as usize,
-                    progress.description,
-                ));
-            },
-        )
-        .await;
+    /// Record a failed dataset for an automatic retry, and say so once
+    /// retries give up on one the user would miss.
+    fn schedule_dataset_retry(&mut self, dataset: RefreshDataset, kind: ApiErrorKind, error: String) {
+        if self.dataset_retries.record_failure(dataset, kind, error) && !dataset.is_optional() {
+            self.notify("Refresh failed", &format!("{} could not be loaded", dataset.label()));
+        }
+    }
 
-        match result {
-            Ok(msg) => info!("{}", msg),
-            Err(e) => error!(error = %e, "Offline caching failed"),
+    /// Spawn background fetches for any datasets whose retry time has arrived.
+    fn spawn_due_retries(&mut self) {
+        if self.offline_mode || self.reauth_pending.is_some() || self.dataset_retries.is_empty() {
+            return;
         }
 
-        Self::send_result(&tx, RefreshResult::CachingComplete).await;
+        let due = self.dataset_retries.take_due(Instant::now());
+        self.spawn_dataset_fetches(due);
     }
 
-    /// Fetch rank, merit badge, and leadership progress for all youth members.
-    /// This populates all_youth_ranks and all_youth_badges HashMaps for the Ranks/Badges tabs,
-    /// and caches leadership data for the Leadership tab.
-    async fn handle_all_youth_advancement_refresh(
-        tx: &mpsc::Sender<RefreshResult>,
-        user_ids: &[i64],
-        token: &Arc<String>,
-    ) {
-        if user_ids.is_empty() {
+    /// Fetch each of `datasets` on its own in the background.
+    fn spawn_dataset_fetches(&mut self, datasets: Vec<RefreshDataset>) {
+        if datasets.is_empty() {
             return;
         }
 
-        debug!(count = user_ids.len(), "Fetching ranks, badges, and leadership for all youth");
-
-        // Create API client
-        let api = match create_authenticated_api(token.to_string()) {
-            Ok(api) => api,
-            Err(e) => {
-                error!(error = %e, "Failed to create API client for youth advancement");
-                return;
-            }
+        let (org_guid, user_id) = match (
+            &self.config.organization_guid,
+            self.session.token(),
+            self.session.user_id(),
+        ) {
+            (Some(guid), Some(_), Some(user_id)) => (Arc::new(guid.clone()), user_id),
+            _ => return,
         };
 
-        // Fetch ranks, badges, and leadership for all youth with limited concurrency
-        const MAX_CONCURRENT: usize = 5;
-        for chunk in user_ids.chunks(MAX_CONCURRENT) {
-            let futures: Vec<_> = chunk
-                .iter()
-                .map(|&user_id| {
-                    let api = api.clone();
-                    async move {
-                        let ranks = api.fetch_youth_ranks(user_id).await.ok();
-                        let badges = api.fetch_youth_merit_badges(user_id).await.ok();
-                        let leadership = api.fetch_youth_leadership(user_id).await.ok();
-                        (user_id, ranks, badges, leadership)
-                    }
-                })
-                .collect();
-
-            let results = futures::future::join_all(futures).await;
-            for (user_id, ranks, badges, leadership) in results {
-                if let Some(ranks) = ranks {
-                    Self::send_result(tx, RefreshResult::YouthRanks(user_id, ranks)).await;
-                }
-                if let Some(badges) = badges {
-                    Self::send_result(tx, RefreshResult::YouthMeritBadges(user_id, badges)).await;
-                }
-                if let Some(leadership) = leadership {
-                    Self::send_result(tx, RefreshResult::YouthLeadership(user_id, leadership)).await;
-                }
-            }
+        for dataset in datasets {
+            self.load_states.insert(dataset, LoadState::Loading);
+            let tx = self.refresh_tx.clone();
+            let org_guid = Arc::clone(&org_guid);
+            let api = self.refresh_api();
+            tokio::spawn(async move {
+                services::retry_dataset(tx, api, dataset, org_guid, user_id).await;
+            });
         }
-
-        debug!("All youth advancement fetching complete");
     }
 
-    /// Helper to send a successful fetch result or an error
-    async fn send_fetch_result<T, F>(
-        tx: &mpsc::Sender<RefreshResult>,
-        name: &str,
-        result: Result<T>,
-        wrapper: F,
-    ) where
-        F: FnOnce(T) -> RefreshResult,
-    {
-        match result {
-            Ok(data) => {
-                info!("{} fetched successfully, sending to channel", name);
-                Self::send_result(tx, wrapper(data)).await;
-            }
-            Err(e) => {
-                error!(error = %e, "{} fetch failed", name);
-                Self::send_result(tx, RefreshResult::Error(format!("{}: {}", name, e))).await;
-            }
+    /// Stop refreshing after a dataset came back 401, remember what still
+    /// needs fetching, and ask the user to log in again. The first failure
+    /// stops the full refresh; later ones from fetches already under way are
+    /// added to what will be fetched after login.
+    fn pause_for_reauth(&mut self, dataset: RefreshDataset, msg: String) {
+        let first = self.reauth_pending.is_none();
+        let mut pending = self.reauth_pending.take().unwrap_or_default();
+        pending.insert(dataset);
+        if let Some(task) = self.refresh_task.take() {
+            task.abort();
+            pending.extend(unfinished_datasets(&self.load_states));
+            // Per-scout advancement is fetched last and comes with the roster
+            pending.insert(RefreshDataset::Youth);
         }
-    }
+        for dataset in &pending {
+            self.dataset_retries.remove(*dataset);
+            self.load_states.insert(*dataset, LoadState::Failed("Session expired".to_string()));
+        }
+        info!(datasets = pending.len(), "Refresh paused until the user logs in again");
+        self.reauth_pending = Some(pending);
 
-    /// Helper to send a fetch result or a default empty value
-    async fn send_fetch_result_or_empty<T, F>(
-        tx: &mpsc::Sender<RefreshResult>,
-        name: &str,
-        result: Result<T>,
-        wrapper: F,
-        default: T,
-    ) where
-        F: FnOnce(T) -> RefreshResult,
-    {
-        match result {
-            Ok(data) => {
-                debug!("{} fetched successfully", name);
-                Self::send_result(tx, wrapper(data)).await;
-            }
-            Err(e) => {
-                debug!(error = %e, "{} fetch failed, using default", name);
-                Self::send_result(tx, wrapper(default)).await;
+        if first {
+            if self.login.username.is_empty() {
+                self.login.username = self.config.last_username.clone().unwrap_or_default();
             }
+            self.report_refresh_error(ApiErrorKind::AuthExpired, msg);
+            self.login.error = Some("Session expired. Log in to finish refreshing.".to_string());
+            self.status_message = Some("Session expired - refresh paused until you log in".to_string());
         }
     }
 
-    /// Helper to send a fetch result or a Default value
-    async fn send_fetch_result_or_default<T: Default, F>(
-        tx: &mpsc::Sender<RefreshResult>,
-        name: &str,
-        result: Result<T>,
-        wrapper: F,
-    ) where
-        F: FnOnce(T) -> RefreshResult,
-    {
-        match result {
-            Ok(data) => {
-                debug!("{} fetched successfully", name);
-                Self::send_result(tx, wrapper(data)).await;
-            }
-            Err(e) => {
-                debug!(error = %e, "{} fetch failed, using default", name);
-                Self::send_result(tx, wrapper(T::default())).await;
-            }
-        }
+    /// Fetch the datasets a refresh paused on an expired session didn't get to.
+    fn resume_refresh(&mut self, pending: HashSet<RefreshDataset>) {
+        let mut datasets: Vec<RefreshDataset> = pending.into_iter().collect();
+        datasets.sort_by_key(|d| RefreshDataset::ALL.iter().position(|a| a == d));
+        info!(datasets = datasets.len(), "Resuming refresh after login");
+        self.status_message = Some(format!("{} ({} datasets)...", RESUMING_REFRESH, datasets.len()));
+        self.spawn_dataset_fetches(datasets);
     }
 
-    async fn send_key3_result(
-        tx: &mpsc::Sender<RefreshResult>,
-        result: Result<Key3Leaders>,
-    ) {
-        match result {
-            Ok(data) => {
-                debug!("Key3 fetched successfully");
-                Self::send_result(tx, RefreshResult::Key3(data)).await;
-            }
-            Err(e) => {
-                debug!(error = %e, "Key3 fetch failed");
-                Self::send_result(tx, RefreshResult::Key3(Default::default())).await;
-            }
-        }
+    /// Load progress of a dataset.
+    pub fn load_state(&self, dataset: RefreshDataset) -> LoadState {
+        self.load_states.get(&dataset).cloned().unwrap_or_default()
     }
 
-    async fn send_pin_result(
-        tx: &mpsc::Sender<RefreshResult>,
-        result: Result<UnitInfo>,
-    ) {
-        match result {
-            Ok(data) => {
-                debug!(website = ?data.website, "Unit info fetched");
-                Self::send_result(tx, RefreshResult::UnitPinInfo(data)).await;
-            }
-            Err(e) => {
-                debug!(error = %e, "Unit info fetch failed");
-            }
-        }
-    }
-
-    async fn send_profile_result(
-        tx: &mpsc::Sender<RefreshResult>,
-        result: Result<OrgProfile>,
-    ) {
-        match result {
-            Ok(data) => {
-                debug!("Org profile fetched successfully");
-                Self::send_result(tx, RefreshResult::OrgProfile(data)).await;
-            }
-            Err(e) => {
-                debug!(error = %e, "Profile fetch failed");
-                Self::send_result(tx, RefreshResult::OrgProfile(Default::default())).await;
-            }
-        }
-    }
-
-    async fn handle_events_refresh(
-        tx: &mpsc::Sender<RefreshResult>,
-        events_res: Result<Vec<Event>>,
-        token: &Arc<String>,
-    ) {
-        match events_res {
-            Ok(data) => {
-                info!(count = data.len(), "Events fetched");
-
-                // Extract IDs before sending to avoid cloning the entire events list
-                let event_ids: Vec<i64> = data.iter().map(|e| e.id).collect();
-
-                Self::send_result(tx, RefreshResult::Events(data)).await;
-
-                // Fetch detailed info for each event with limited concurrency
-                debug!("Fetching event details with max {} concurrent requests...", MAX_CONCURRENT_REQUESTS);
-
-                let tx_clone = tx.clone();
-                let token = Arc::clone(token);
-
-                stream::iter(event_ids)
-                    .map(|id| {
-                        let token = Arc::clone(&token);
-                        async move {
-                            match ApiClient::new() {
-                                Ok(mut api) => {
-                                    api.set_token(token);
-                                    api.fetch_event_detail(id).await
-                                }
-                                Err(e) => Err(e),
-                            }
-                        }
-                    })
-                    .buffer_unordered(MAX_CONCURRENT_REQUESTS)
-                    .for_each(|result| {
-                        let tx = tx_clone.clone();
-                        async move {
-                            if let Ok(detail) = result {
-                                debug!(event = %detail.name, users = detail.invited_users.len(), "Event detail fetched");
-                                Self::send_result(&tx, RefreshResult::EventDetail(detail)).await;
-                            }
-                        }
-                    })
-                    .await;
-
-                debug!("Event details complete");
-            }
-            Err(e) => {
-                error!(error = %e, "Events fetch failed");
-                Self::send_result(tx, RefreshResult::Events(vec![])).await;
-            }
-        }
-    }
-
-    /// Check for completed background tasks and process results
-    pub async fn check_background_tasks(&mut self) {
-        // Collect all pending results first to avoid borrow conflicts
-        let results: Vec<RefreshResult> = {
-            if let Some(ref mut rx) = self.refresh_rx {
-                let mut results = Vec::new();
-                while let Ok(result) = rx.try_recv() {
-                    results.push(result);
-                }
-                results
-            } else {
-                Vec::new()
-            }
-        };
-
-        // Now process all results
-        for result in results {
-            self.process_refresh_result(result);
-        }
+    /// Current frame of the loading spinner.
+    pub fn spinner_frame(&self) -> &'static str {
+        const FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+        let tick = self.started_at.elapsed().as_millis() / 100;
+        FRAMES[tick as usize % FRAMES.len()]
     }
 
     /// Process a single refresh result from the background task.
     ///
     /// Updates the corresponding app state and caches the data. This is called
     /// by `check_background_tasks` for each result received from the channel.
-    fn process_refresh_result(&mut self, result: RefreshResult) {
-        match result {
-            RefreshResult::Youth(data) => {
-                info!(count = data.len(), "Processing Youth result - saving to cache");
-                match self.cache.save_youth(&data) {
-                    Ok(()) => info!("Youth cache saved successfully"),
-                    Err(e) => error!(error = %e, "Failed to cache youth data"),
-                }
-                self.youth = data;
-                if self.roster_selection >= self.youth.len() {
-                    self.roster_selection = self.youth.len().saturating_sub(1);
-                }
-                self.cache_ages = self.cache.get_cache_ages();
-            }
-            RefreshResult::Adults(data) => {
-                // Data is already deduplicated by execute_background_refresh
-                info!(count = data.len(), "Processing Adults result - saving to cache");
-                match self.cache.save_adults(&data) {
-                    Ok(()) => info!("Adults cache saved successfully"),
-                    Err(e) => error!(error = %e, "Failed to cache adults data"),
-                }
-                self.adults = data;
-            }
-            RefreshResult::Parents(data) => {
-                if let Err(e) = self.cache.save_parents(&data) {
-                    warn!(error = %e, "Failed to cache parents data");
-                }
-                self.parents = data;
-            }
-            RefreshResult::Patrols(data) => {
-                if let Err(e) = self.cache.save_patrols(&data) {
-                    warn!(error = %e, "Failed to cache patrols data");
-                }
-                self.patrols = data;
-            }
-            RefreshResult::Events(data) => {
-                if let Err(e) = self.cache.save_events(&data) {
-                    warn!(error = %e, "Failed to cache events data");
-                }
-                self.events = data;
-                self.cache_ages = self.cache.get_cache_ages();
-            }
-            RefreshResult::EventDetail(event) => {
-                if let Some(existing) = self.events.iter_mut().find(|e| e.id == event.id) {
-                    *existing = event;
-                }
-                if let Err(e) = self.cache.save_events(&self.events) {
-                    warn!(error = %e, "Failed to cache event detail");
-                }
-            }
-            RefreshResult::AdvancementDashboard(data) => {
-                if let Err(e) = self.cache.save_advancement_dashboard(&data) {
-                    warn!(error = %e, "Failed to cache advancement dashboard");
-                }
-                self.advancement_dashboard = data;
-            }
-            RefreshResult::ReadyToAward(data) => {
-                if let Err(e) = self.cache.save_ready_to_award(&data) {
-                    warn!(error = %e, "Failed to cache ready to award");
-                }
-                self.ready_to_award = data;
+    /// Apply fetched data to the store, keeping list selections on the same
+    /// items and the open detail views in step with it.
+    fn apply_update(&mut self, update: Update) {
+        let today = Local::now().date_naive();
+        let selected_user_id = self.selected_youth().and_then(|y| y.user_id);
+        match update {
+            Update::Youth(data) => {
+                self.enforce_retention(&data);
+                // Keep the same scout selected even if the roster reordered or shrank
+                self.roster_selection.anchor(self.youth_keys());
+                self.apply_to_store(Update::Youth(data), today);
+                self.roster_selection.restore(self.youth_keys());
             }
-            RefreshResult::Key3(data) => {
-                if let Err(e) = self.cache.save_key3(&data) {
-                    warn!(error = %e, "Failed to cache key3");
-                }
-                self.key3 = data;
-            }
-            RefreshResult::UnitPinInfo(data) => {
-                if let Err(e) = self.cache.save_unit_info(&data) {
-                    warn!(error = %e, "Failed to cache unit pin info");
-                }
-                self.unit_info = Some(data.with_computed_fields());
-            }
-            RefreshResult::OrgProfile(data) => {
-                if let Err(e) = self.cache.save_org_profile(&data) {
-                    warn!(error = %e, "Failed to cache org profile");
-                }
-                self.org_profile = data;
+            Update::Adults(data) => {
+                self.adults_selection.anchor(self.adult_keys());
+                self.apply_to_store(Update::Adults(data), today);
+                self.adults_selection.restore(self.adult_keys());
             }
-            RefreshResult::Commissioners(data) => {
-                if let Err(e) = self.cache.save_commissioners(&data) {
-                    warn!(error = %e, "Failed to cache commissioners");
-                }
-                self.commissioners = data;
-            }
-            RefreshResult::EventGuests(event_id, data) => {
-                // LRU eviction: remove oldest entries if cache is at capacity
-                if self.event_guests.len() >= MAX_EVENT_GUESTS_CACHE_SIZE
-                    && !self.event_guests.contains_key(&event_id)
-                {
-                    // Remove oldest half based on access order
-                    let evict_count = MAX_EVENT_GUESTS_CACHE_SIZE / 2;
-                    let to_remove: Vec<_> = self.event_guests_order.drain(..evict_count).collect();
-                    for key in &to_remove {
-                        self.event_guests.remove(key);
-                    }
-                    debug!(
-                        evicted = to_remove.len(),
-                        "Evicted oldest event guests cache entries"
-                    );
-                }
-
-                // Update access order: remove existing entry if present, then add to end
-                if let Some(pos) = self.event_guests_order.iter().position(|&id| id == event_id) {
-                    self.event_guests_order.remove(pos);
-                }
-                self.event_guests_order.push(event_id);
-                self.event_guests.insert(event_id, data);
+            Update::Events(data) => {
+                self.event_selection.anchor(self.event_keys());
+                self.apply_to_store(Update::Events(data), today);
+                self.event_selection.restore(self.event_keys());
             }
-            RefreshResult::YouthRanks(user_id, data) => {
-                if let Err(e) = self.cache.save_youth_ranks(user_id, &data) {
-                    warn!(error = %e, "Failed to cache youth ranks");
-                }
-                // Store in all_youth_ranks for the Ranks tab aggregate view
-                self.all_youth_ranks.insert(user_id, data.clone());
-                // Only update selected view if this is the currently selected scout
-                let selected_user_id = self.get_sorted_youth()
-                    .get(self.roster_selection)
-                    .and_then(|y| y.user_id);
+            Update::YouthRanks(user_id, data) => {
+                // The Ranks tab aggregates every scout's ranks
+                self.ranks_selection.anchor(self.rank_list_keys());
+                self.apply_to_store(Update::YouthRanks(user_id, data), today);
+                self.ranks_selection.restore(self.rank_list_keys());
                 if selected_user_id == Some(user_id) {
-                    self.selected_youth_ranks = data;
+                    self.selected_youth_ranks = self.store.all_youth_ranks[&user_id].clone();
                 }
             }
-            RefreshResult::YouthMeritBadges(user_id, data) => {
-                if let Err(e) = self.cache.save_youth_merit_badges(user_id, &data) {
-                    warn!(error = %e, "Failed to cache youth merit badges");
-                }
-                // Store in all_youth_badges for the Badges tab aggregate view
-                self.all_youth_badges.insert(user_id, data.clone());
-                // Only update selected view if this is the currently selected scout
-                let selected_user_id = self.get_sorted_youth()
-                    .get(self.roster_selection)
-                    .and_then(|y| y.user_id);
+            Update::YouthMeritBadges(user_id, data) => {
+                self.badges_selection.anchor(self.badge_list_keys());
+                self.apply_to_store(Update::YouthMeritBadges(user_id, data), today);
+                self.badges_selection.restore(self.badge_list_keys());
                 if selected_user_id == Some(user_id) {
-                    self.selected_youth_badges = data;
+                    self.selected_youth_badges = self.store.all_youth_badges[&user_id].clone();
                 }
             }
-            RefreshResult::YouthLeadership(user_id, mut data) => {
-                LeadershipPosition::sort_for_display(&mut data);
-                if let Err(e) = self.cache.save_youth_leadership(user_id, &data) {
-                    warn!(error = %e, "Failed to cache youth leadership");
-                }
-                // Only update selected view if this is the currently selected scout
-                let selected_user_id = self.get_sorted_youth()
-                    .get(self.roster_selection)
-                    .and_then(|y| y.user_id);
+            Update::YouthLeadership(user_id, data) => {
+                self.apply_to_store(Update::YouthLeadership(user_id, data), today);
                 if selected_user_id == Some(user_id) {
-                    self.selected_youth_leadership = data;
+                    self.selected_youth_leadership = self.store.all_youth_leadership[&user_id].clone();
                 }
             }
-            RefreshResult::YouthAwards(user_id, mut data) => {
-                Award::sort_for_display(&mut data);
-                if let Err(e) = self.cache.save_youth_awards(user_id, &data) {
-                    warn!(error = %e, "Failed to cache youth awards");
-                }
-                // Only update selected view if this is the currently selected scout
-                let selected_user_id = self.get_sorted_youth()
-                    .get(self.roster_selection)
-                    .and_then(|y| y.user_id);
+            Update::YouthAwards(user_id, data) => {
+                self.apply_to_store(Update::YouthAwards(user_id, data), today);
                 if selected_user_id == Some(user_id) {
-                    self.selected_youth_awards = data;
+                    self.selected_youth_awards = self.store.all_youth_awards[&user_id].clone();
                     self.awards_loaded = true;
                 }
             }
-            RefreshResult::RankRequirements(user_id, rank_id, data) => {
-                // Cache the requirements
-                if let Err(e) = self.cache.save_rank_requirements(user_id, rank_id, &data) {
-                    warn!(error = %e, "Failed to cache rank requirements");
-                }
-                // Only update selected view if this is the currently viewed rank
+            Update::RankRequirements(user_id, rank_id, data) => {
+                // Only update the view if this is the rank being viewed
                 if self.viewing_rank_user_id == Some(user_id) && self.viewing_rank_id == Some(rank_id) {
-                    let mut sorted = data;
+                    let mut sorted = data.clone();
                     sort_requirements(&mut sorted);
                     self.selected_rank_requirements = sorted;
                     self.viewing_requirements = true;
                     self.requirement_selection = 0;
                 }
+                self.apply_to_store(Update::RankRequirements(user_id, rank_id, data), today);
             }
-            RefreshResult::BadgeRequirements(user_id, badge_id, data, version, counselor) => {
-                // Cache the requirements
-                if let Err(e) = self.cache.save_badge_requirements(user_id, badge_id, &data, &version) {
-                    warn!(error = %e, "Failed to cache badge requirements");
-                }
-                // Only update selected view if this is the currently viewed badge
+            Update::BadgeRequirements(user_id, badge_id, data, version, counselor) => {
                 if self.viewing_badge_user_id == Some(user_id) && self.viewing_badge_id == Some(badge_id) {
-                    let mut sorted = data;
+                    let mut sorted = data.clone();
                     sort_requirements(&mut sorted);
                     self.selected_badge_requirements = sorted;
-                    self.selected_badge_version = version;
-                    self.selected_badge_counselor = counselor;
+                    self.selected_badge_version = version.clone();
+                    self.selected_badge_counselor = counselor.clone();
                     self.viewing_requirements = true;
                     self.requirement_selection = 0;
                 }
+                self.apply_to_store(Update::BadgeRequirements(user_id, badge_id, data, version, counselor), today);
+            }
+            update => self.apply_to_store(update, today),
+        }
+    }
+
+    fn apply_to_store(&mut self, update: Update, today: NaiveDate) {
+        let activity = self.store.apply(update, &self.cache, today);
+        self.record_activity(activity);
+    }
+
+    fn process_refresh_result(&mut self, result: RefreshResult) {
+        // A successful fetch clears any pending retry for that dataset
+        if let Some(dataset) = result.dataset() {
+            if self.scoutbook_down {
+                info!("Scoutbook is back up");
+                self.scoutbook_down = false;
             }
+            self.dataset_retries.remove(dataset);
+            self.load_states.insert(dataset, LoadState::Loaded);
+            let resuming = self.status_message.as_deref().is_some_and(|m| m.starts_with(RESUMING_REFRESH));
+            if resuming && !self.load_states.values().any(|s| *s == LoadState::Loading) {
+                self.status_message = None;
+            }
+        }
+
+        match result {
+            RefreshResult::Data(update) => self.apply_update(*update),
             RefreshResult::RefreshComplete => {
+                self.refresh_task = None;
                 // Only clear status if it's a progress message, preserve errors
                 if let Some(ref msg) = self.status_message {
                     if !msg.starts_with("Error:") {
                         self.status_message = None;
                     }
                 }
+                if self.refresh_started_at.take().is_some_and(|t| t.elapsed().as_secs() >= LONG_REFRESH_SECS) {
+                    self.notify("Refresh complete", "Troop data is up to date");
+                }
+                if let Some(reminder) = self.nomination_reminder() {
+                    self.notify("Award nominations", &reminder);
+                }
+                let changes = std::mem::take(&mut self.refresh_changes);
+                if let Some(url) = self.config.webhook_url.as_deref().filter(|_| !changes.is_empty()) {
+                    crate::notify::webhook(url, &self.unit_name(), &changes);
+                }
             }
             RefreshResult::CachingProgress(current, total, description) => {
-                self.caching_current = current;
-                self.caching_total = total;
-                self.caching_description = description.clone();
-                let pct = if total > 0 { (current * 100) / total } else { 0 };
+                self.caching.current = current;
+                self.caching.total = total;
+                self.caching.description = description.clone();
+                let pct = (current * 100).checked_div(total).unwrap_or(0);
                 self.status_message = Some(format!("Caching: {} ({}%)", description, pct));
             }
             RefreshResult::CachingComplete => {
@@ -1543,7 +1705,7 @@ impl App {
                 }
 
                 // Enable offline mode
-                self.caching_in_progress = false;
+                self.caching.in_progress = false;
                 self.offline_mode = true;
                 self.config.offline_mode = true;
                 if let Err(e) = self.config.save() {
@@ -1552,39 +1714,97 @@ impl App {
                     return;
                 }
 
-                info!(cache_dir = ?self.cache.cache_dir(), youth_count = self.youth.len(), "Offline caching complete");
-                self.status_message = Some(format!(
-                    "Offline mode ready - {} scouts cached",
-                    self.youth.len()
-                ));
+                self.refresh_started_at = None;
+                self.notify("Offline caching complete", "Troop data is ready for offline use");
+
+                let gaps = audit_offline_cache(&self.cache, &self.offline_scope).gaps();
+                info!(cache_dir = ?self.cache.cache_dir(), youth_count = self.store.youth.len(), gaps = gaps.len(), "Offline caching complete");
+                self.status_message = Some(if gaps.is_empty() {
+                    format!("Offline mode ready - {} scouts cached", self.store.youth.len())
+                } else {
+                    format!(
+                        "Offline mode ready - {} scouts cached ({} gaps: {})",
+                        self.store.youth.len(),
+                        gaps.len(),
+                        gaps.join("; ")
+                    )
+                });
             }
-            RefreshResult::Error(msg) => {
-                // Show error and log it
-                error!(error = %msg, "Background task error");
-                // Simplify common error messages for the user
-                let user_message = if msg.to_lowercase().contains("rate limit") {
-                    "Server is busy. Please wait a moment and try again.".to_string()
-                } else if msg.to_lowercase().contains("unauthorized")
-                    || msg.to_lowercase().contains("401")
-                {
-                    // Session expired - prompt for re-login if not offline
-                    if !self.offline_mode {
-                        self.start_login();
-                        self.login_error = Some("Session expired. Please log in again.".to_string());
+            RefreshResult::WorkbookDownloaded(badge_name, result) => {
+                self.status_message = Some(match result {
+                    Ok(path) => {
+                        info!(badge = %badge_name, path = ?path, "Workbook downloaded");
+                        format!("{} workbook saved for offline use", badge_name)
+                    }
+                    Err(e) => {
+                        warn!(badge = %badge_name, error = %e, "Workbook download failed");
+                        format!("{} workbook download failed: {}", badge_name, e)
                     }
-                    "Session expired. Please log in again.".to_string()
-                } else if msg.to_lowercase().contains("network")
-                    || msg.to_lowercase().contains("connect")
-                {
-                    "Network error. Check your connection.".to_string()
+                });
+            }
+            RefreshResult::DistrictUnit(org_guid, result) => self.finish_district_fetch(&org_guid, result),
+            RefreshResult::LinkedUnit(result) => self.finish_linked_fetch(result),
+            RefreshResult::RequirementCompleted(user_id, rank_id, previous, result) => {
+                self.finish_requirement_completion(user_id, rank_id, *previous, result)
+            }
+            RefreshResult::PatrolMoved(user_id, previous, result) => self.finish_patrol_move(user_id, *previous, result),
+            RefreshResult::DatesCorrected(records) => self.finish_date_correction(records),
+            RefreshResult::DatasetFailed(dataset, kind, msg) => {
+                if dataset.is_optional() {
+                    debug!(dataset = dataset.label(), ?kind, error = %msg, "Optional dataset refresh failed");
                 } else {
-                    format!("Error: {}", msg)
-                };
-                self.status_message = Some(user_message);
+                    error!(dataset = dataset.label(), ?kind, error = %msg, "Dataset refresh failed");
+                }
+                self.load_states.insert(dataset, LoadState::Failed(msg.clone()));
+                // Retrying cannot fix an expired session - pause and prompt for login instead
+                if kind == ApiErrorKind::AuthExpired && !self.offline_mode {
+                    self.pause_for_reauth(dataset, msg);
+                    return;
+                }
+                if kind == ApiErrorKind::Maintenance && !self.scoutbook_down {
+                    self.scoutbook_down = true;
+                    self.status_message = Some(SCOUTBOOK_DOWN.to_string());
+                }
+                self.schedule_dataset_retry(dataset, kind, msg);
             }
         }
     }
 
+    /// Show a background task error to the user and log it.
+    fn report_refresh_error(&mut self, kind: ApiErrorKind, msg: String) {
+        error!(?kind, error = %msg, "Background task error");
+        self.refresh_started_at = None;
+        self.notify("Refresh failed", &msg);
+        let user_message = match kind {
+            ApiErrorKind::AuthExpired => {
+                // Session expired - prompt for re-login if not offline
+                if !self.offline_mode {
+                    self.start_login();
+                    self.login.error = Some("Session expired. Please log in again.".to_string());
+                }
+                "Session expired. Please log in again.".to_string()
+            }
+            ApiErrorKind::Forbidden => {
+                "Insufficient role - your account cannot view this data.".to_string()
+            }
+            ApiErrorKind::RateLimited => {
+                "Server is busy. Please wait a moment and try again.".to_string()
+            }
+            ApiErrorKind::Maintenance => {
+                self.scoutbook_down = true;
+                SCOUTBOOK_DOWN.to_string()
+            }
+            ApiErrorKind::Network => "Network error. Check your connection.".to_string(),
+            ApiErrorKind::SchemaMismatch => {
+                "Unexpected data from server. Try updating Trailcache.".to_string()
+            }
+            ApiErrorKind::NotFound | ApiErrorKind::Server | ApiErrorKind::Other => {
+                format!("Error: {}", msg)
+            }
+        };
+        self.status_message = Some(user_message);
+    }
+
     /// Refresh only data for the current tab
     #[allow(dead_code)]
     pub async fn refresh_current_tab(&mut self) {
@@ -1592,16 +1812,21 @@ impl App {
         if self.offline_mode {
             return;
         }
+        // Plugin tabs can draw on any dataset
+        if matches!(self.ui.current_tab, Tab::Plugin(_)) {
+            self.refresh_all_background().await;
+            return;
+        }
 
         let org_guid = match &self.config.organization_guid {
             Some(guid) => Arc::new(guid.clone()),
             None => return,
         };
 
-        let token = match self.session.token() {
-            Some(t) => Arc::new(t.to_string()),
-            None => return,
-        };
+        if self.session.token().is_none() {
+            return;
+        }
+        let api = self.api.clone();
 
         let user_id = match self.session.user_id() {
             Some(id) => id,
@@ -1609,105 +1834,3748 @@ impl App {
         };
 
         let tx = self.refresh_tx.clone();
-        let tab = self.current_tab;
+        let tab = self.ui.current_tab;
 
         tokio::spawn(async move {
-            let api = match create_authenticated_api(token.to_string()) {
-                Ok(api) => api,
-                Err(e) => {
-                    error!(error = %e, "Failed to create API client for tab refresh");
-                    return;
-                }
-            };
-
             match tab {
                 Tab::Scouts => {
                     if let Ok(data) = api.fetch_youth(&org_guid).await {
-                        Self::send_result(&tx, RefreshResult::Youth(data)).await;
+                        send_result(&tx, Update::Youth(data).into()).await;
                     }
                     if let Ok(data) = api.fetch_parents(&org_guid).await {
-                        Self::send_result(&tx, RefreshResult::Parents(data)).await;
+                        send_result(&tx, Update::Parents(data).into()).await;
                     }
                     if let Ok(data) = api.fetch_advancement_dashboard(&org_guid).await {
-                        Self::send_result(&tx, RefreshResult::AdvancementDashboard(data)).await;
+                        send_result(&tx, Update::AdvancementDashboard(data).into()).await;
                     }
                 }
                 Tab::Adults => {
                     if let Ok(data) = api.fetch_adults(&org_guid).await {
-                        Self::send_result(&tx, RefreshResult::Adults(data)).await;
+                        send_result(&tx, Update::Adults(data).into()).await;
                     }
                 }
-                Tab::Events => {
+                Tab::Events | Tab::Attendance => {
                     if let Ok(data) = api.fetch_events(user_id).await {
                         // Extract IDs before sending to avoid cloning entire events list
                         let event_ids: Vec<i64> = data.iter().map(|e| e.id).collect();
 
-                        Self::send_result(&tx, RefreshResult::Events(data)).await;
+                        send_result(&tx, Update::Events(data).into()).await;
                         let detail_futures: Vec<_> = event_ids.iter().map(|&id| {
                             api.fetch_event_detail(id)
                         }).collect();
 
                         let results = futures::future::join_all(detail_futures).await;
                         for detail in results.into_iter().flatten() {
-                            Self::send_result(&tx, RefreshResult::EventDetail(detail)).await;
+                            send_result(&tx, Update::EventDetail(detail).into()).await;
                         }
                     }
                 }
                 Tab::Unit => {
                     if let Ok(data) = api.fetch_youth(&org_guid).await {
-                        Self::send_result(&tx, RefreshResult::Youth(data)).await;
+                        send_result(&tx, Update::Youth(data).into()).await;
                     }
                     if let Ok(data) = api.fetch_adults(&org_guid).await {
-                        Self::send_result(&tx, RefreshResult::Adults(data)).await;
+                        send_result(&tx, Update::Adults(data).into()).await;
                     }
                     if let Ok(data) = api.fetch_advancement_dashboard(&org_guid).await {
-                        Self::send_result(&tx, RefreshResult::AdvancementDashboard(data)).await;
+                        send_result(&tx, Update::AdvancementDashboard(data).into()).await;
                     }
                 }
-                Tab::Ranks => {
-                    // Ranks tab uses youth data
+                Tab::Ranks | Tab::Eagle => {
+                    // Ranks and Eagle tabs use youth data
                     if let Ok(data) = api.fetch_youth(&org_guid).await {
-                        Self::send_result(&tx, RefreshResult::Youth(data)).await;
+                        send_result(&tx, Update::Youth(data).into()).await;
                     }
                 }
                 Tab::Badges => {
                     // Badges tab uses ready-to-award data
                     if let Ok(data) = api.fetch_ready_to_award(&org_guid).await {
-                        Self::send_result(&tx, RefreshResult::ReadyToAward(data)).await;
+                        send_result(&tx, Update::ReadyToAward(data).into()).await;
+                    }
+                }
+                Tab::Plugin(_) => {}
+            }
+        });
+
+        self.status_message = Some(format!("Refreshing {}...", tab.title()));
+    }
+
+    /// Local path for a badge's downloaded workbook PDF.
+    pub fn workbook_pdf(&self, badge_name: &str) -> PathBuf {
+        workbook_pdf_path(self.cache.cache_dir(), badge_name)
+    }
+
+    /// Open a badge's workbook, preferring a PDF downloaded for offline use.
+    pub fn open_workbook(&mut self, badge_name: &str) {
+        let local = self.workbook_pdf(badge_name);
+        let target = if local.exists() {
+            local.to_string_lossy().to_string()
+        } else if let Some(url) = self.workbooks.lookup(badge_name).workbook_url {
+            url
+        } else {
+            self.status_message = Some(format!("No workbook link for {}", badge_name));
+            return;
+        };
+        self.open_target(&target, "workbook");
+    }
+
+    /// Open a badge's official info page (requirements and pamphlet details).
+    pub fn open_badge_info(&mut self, badge_name: &str) {
+        match self.workbooks.lookup(badge_name).info_url {
+            Some(url) => self.open_target(&url, "badge page"),
+            None => self.status_message = Some(format!("No info link for {}", badge_name)),
+        }
+    }
+
+    fn open_target(&mut self, target: &str, what: &str) {
+        match open_external(target) {
+            Ok(()) => {
+                debug!(target, "Opened external {}", what);
+                self.status_message = Some(format!("Opened {}", what));
+            }
+            Err(e) => {
+                warn!(target, error = %e, "Failed to open {}", what);
+                self.status_message = Some(format!("Could not open {}: {}", what, target));
+            }
+        }
+    }
+
+    /// Download a badge's workbook PDF into the cache for offline use.
+    pub fn download_workbook(&mut self, badge_name: &str) {
+        if self.offline_mode {
+            self.status_message = Some("Workbook downloads unavailable offline".to_string());
+            return;
+        }
+        let Some(url) = self.workbooks.lookup(badge_name).workbook_url else {
+            self.status_message = Some(format!("No workbook link for {}", badge_name));
+            return;
+        };
+
+        let dest = self.workbook_pdf(badge_name);
+        let badge_name = badge_name.to_string();
+        let tx = self.refresh_tx.clone();
+        self.status_message = Some(format!("Downloading {} workbook...", badge_name));
+
+        tokio::spawn(async move {
+            let result = trailcache_core::resources::download_workbook(&url, &dest)
+                .await
+                .map(|_| dest)
+                .map_err(|e| e.to_string());
+            send_result(&tx, RefreshResult::WorkbookDownloaded(badge_name, result)).await;
+        });
+    }
+
+    /// Write the committee report slides (Markdown and HTML) to the export directory.
+    pub fn export_committee_report(&mut self) {
+        if self.blocked_in_youth_leader_mode("The committee report") {
+            return;
+        }
+        let today = Local::now().date_naive();
+        let unit_name = self.unit_name();
+        let input = CommitteeInput {
+            unit_name: &unit_name,
+            youth: &self.store.youth,
+            adults: &self.store.adults,
+            stats: &self.store.unit_stats,
+            goals: &self.config.unit_goals,
+        };
+
+        let result = self.config.export_dir().and_then(|dir| {
+            std::fs::create_dir_all(&dir)?;
+            for format in [SlideFormat::Markdown, SlideFormat::Html, SlideFormat::Text] {
+                let path = dir.join(format!("committee-{}.{}", today.format("%Y-%m"), format.extension()));
+                std::fs::write(&path, committee_summary(&input, today, format))?;
+            }
+            Ok(dir)
+        });
+        match result {
+            Ok(dir) => {
+                debug!(dir = %dir.display(), "Wrote committee report");
+                self.status_message = Some(format!("Committee report saved to {}", dir.display()));
+            }
+            Err(e) => {
+                warn!(error = %e, "Failed to write committee report");
+                self.status_message = Some(format!("Failed to save committee report: {}", e));
+            }
+        }
+    }
+
+    /// Write the Court of Honor handout, everything ready to award grouped
+    /// by scout, to the export directory as HTML, Markdown, and text.
+    pub fn export_court_of_honor(&mut self) {
+        if self.store.ready_to_award.is_empty() {
+            self.status_message = Some("Nothing is ready to award".to_string());
+            return;
+        }
+        let today = Local::now().date_naive();
+        let title = court_of_honor_title(&self.config, today);
+        let sections = court_of_honor_sections(&self.store.ready_to_award, &self.store.youth);
+        let result = self.config.export_dir().and_then(|dir| {
+            std::fs::create_dir_all(&dir)?;
+            for extension in ["html", "md", "txt"] {
+                let path = dir.join(format!("court-of-honor-{}.{}", today.format("%Y-%m-%d"), extension));
+                std::fs::write(&path, report_document(&path, &title, &sections))?;
+            }
+            Ok(dir)
+        });
+        self.status_message = Some(match result {
+            Ok(dir) => format!("Court of Honor handout ({} scouts) saved to {}", sections.len() - 1, dir.display()),
+            Err(e) => {
+                warn!(error = %e, "Failed to write Court of Honor handout");
+                format!("Failed to save Court of Honor handout: {}", e)
+            }
+        });
+    }
+
+    /// Write this year's report to the chartered organization to the export
+    /// directory, from the unit's own template if there is one.
+    pub fn export_charter_report(&mut self) {
+        if self.blocked_in_youth_leader_mode("The charter report") {
+            return;
+        }
+        let today = Local::now().date_naive();
+        let unit_name = self.unit_name();
+        let charter_org = self.store
+            .unit_info
+            .as_ref()
+            .and_then(|i| i.charter_org_name.as_deref())
+            .or(self.store.org_profile.charter_org_name.as_deref());
+        let input = CharterInput {
+            unit_name: &unit_name,
+            charter_org,
+            youth: &self.store.youth,
+            adults: &self.store.adults,
+            stats: &self.store.unit_stats,
+            historian: &self.history.log,
+            year: today.year(),
+        };
+
+        let result = self.config.data_file_path(REPORT_TEMPLATES_DIR).and_then(|dir| {
+            let template_path = dir.join(CHARTER_TEMPLATE_FILE);
+            let template = if template_path.is_file() { Some(std::fs::read_to_string(&template_path)?) } else { None };
+            let report = charter_report(&input, today, template.as_deref())?;
+            let dir = self.config.export_dir()?;
+            std::fs::create_dir_all(&dir)?;
+            let path = dir.join(format!("charter-report-{}.md", today.year()));
+            std::fs::write(&path, report)?;
+            Ok(path)
+        });
+        self.status_message = Some(match result {
+            Ok(path) => format!("Charter report saved to {}", path.display()),
+            Err(e) => {
+                warn!(error = %e, "Failed to write charter report");
+                format!("Failed to save charter report: {}", e)
+            }
+        });
+    }
+
+    /// Write a Scoutmaster conference prep sheet for the selected scout to the export directory.
+    pub fn export_conference_sheet(&mut self) {
+        if self.blocked_in_youth_leader_mode("Conference sheets") {
+            return;
+        }
+        let Some(youth) = self.selected_youth().cloned() else {
+            return;
+        };
+        let Some(user_id) = youth.user_id else {
+            self.status_message = Some("No advancement data for this scout".to_string());
+            return;
+        };
+
+        let ranks = self.store.all_youth_ranks.get(&user_id).cloned().unwrap_or_default();
+        let badges = self.store.all_youth_badges.get(&user_id).cloned().unwrap_or_default();
+        let leadership = self
+            .cache
+            .load_youth_leadership(user_id)
+            .ok()
+            .flatten()
+            .map(|c| c.data)
+            .unwrap_or_default();
+        let next_rank_requirements = next_rank(&ranks)
+            .and_then(|rank| self.cache.load_rank_requirements(user_id, rank.rank_id).ok().flatten())
+            .map(|c| c.data)
+            .unwrap_or_default();
+
+        let input = ConferenceInput {
+            youth: &youth,
+            ranks: &ranks,
+            next_rank_requirements: &next_rank_requirements,
+            badges: &badges,
+            leadership: &leadership,
+            events: &self.store.events,
+            meetings: &self.attendance,
+            notes: None,
+        };
+        let today = Local::now().date_naive();
+        let sheet = conference_sheet(&input, today);
+
+        let file_name = format!(
+            "conference-{}-{}.md",
+            youth.short_name().to_lowercase().replace(' ', "-"),
+            today.format("%Y-%m-%d")
+        );
+        let result = self.config.export_dir().and_then(|dir| {
+            std::fs::create_dir_all(&dir)?;
+            let path = dir.join(file_name);
+            std::fs::write(&path, sheet)?;
+            Ok(path)
+        });
+        match result {
+            Ok(path) => {
+                debug!(path = %path.display(), "Wrote conference sheet");
+                self.status_message = Some(format!("Conference sheet saved to {}", path.display()));
+            }
+            Err(e) => {
+                warn!(error = %e, "Failed to write conference sheet");
+                self.status_message = Some(format!("Could not save conference sheet: {}", e));
+            }
+        }
+    }
+
+    /// Save the cached events as events.ics in the export folder, replacing
+    /// the last one so a calendar subscribed to it picks up changes.
+    pub fn export_ical(&mut self) {
+        if self.store.events.is_empty() {
+            self.status_message = Some("No events to export".to_string());
+            return;
+        }
+        let ics = to_ical(&self.unit_name(), &self.store.events, Utc::now());
+        let result = self.config.export_dir().and_then(|dir| {
+            std::fs::create_dir_all(&dir)?;
+            let path = dir.join(ICAL_FILE);
+            std::fs::write(&path, ics)?;
+            Ok(path)
+        });
+        match result {
+            Ok(path) => {
+                debug!(path = %path.display(), "Wrote calendar");
+                self.status_message = Some(format!("{} events saved to {}", self.store.events.len(), path.display()));
+            }
+            Err(e) => {
+                warn!(error = %e, "Failed to write calendar");
+                self.status_message = Some(format!("Could not save calendar: {}", e));
+            }
+        }
+    }
+
+    /// Render the current tab's list as paginated plain text for printing.
+    pub fn text_view(&self) -> String {
+        let today = Local::now().date_naive();
+        let unit_name = self.unit_name();
+        let title = format!("{} {} - {}", unit_name, self.ui.current_tab.title(), today.format("%b %-d, %Y"));
+
+        let table = match self.ui.current_tab {
+            Tab::Scouts => {
+                let with_age = self.export_profile.includes(ExportField::Age);
+                let mut header = vec!["Name", "Patrol", "Rank", "Grade"];
+                if with_age {
+                    header.push("Age");
+                }
+                let mut table = Table::new(header);
+                for y in self.get_sorted_youth() {
+                    let mut row = vec![y.display_name(), y.patrol(), y.rank(), y.grade_str()];
+                    if with_age {
+                        row.push(y.age_str());
+                    }
+                    table.row(row);
+                }
+                table
+            }
+            Tab::Ranks => {
+                let mut table = Table::new(["Rank", "Scouts"]);
+                let ranks = crate::ui::tabs::ranks::get_rank_list(
+                    &self.store.youth,
+                    &self.store.all_youth_ranks,
+                    self.ranks_sort_by_count,
+                    self.ranks_sort_ascending,
+                );
+                for (name, count) in ranks {
+                    table.row([name, count.to_string()]);
+                }
+                table
+            }
+            Tab::Badges => {
+                let mut table = Table::new(["Merit Badge", "Eagle", "Scouts"]);
+                let badges = crate::ui::tabs::badges::get_badge_list(
+                    &self.store.youth,
+                    &self.store.all_youth_badges,
+                    self.badges_sort_by_count,
+                    self.badges_sort_ascending,
+                );
+                for (name, eagle, count) in badges {
+                    table.row([name, if eagle { "Yes" } else { "" }.to_string(), count.to_string()]);
+                }
+                table
+            }
+            Tab::Events => {
+                let mut table = Table::new(["Name", "Date", "Location", "Type", "Going"]);
+                for e in self.get_sorted_events() {
+                    let going = if e.rsvp { self.event_rsvp_stats(e).headcount().to_string() } else { "-".to_string() };
+                    table.row([
+                        e.name.clone(),
+                        e.formatted_date(),
+                        e.location.clone().unwrap_or_else(|| "-".to_string()),
+                        e.derived_type().to_string(),
+                        going,
+                    ]);
+                }
+                table
+            }
+            Tab::Adults => {
+                let contact = self.export_profile.includes(ExportField::Contact);
+                let mut table = Table::new(if contact { vec!["Name", "Position", "Email", "Phone"] } else { vec!["Name", "Position"] });
+                for a in &self.store.adults {
+                    let mut row = vec![a.display_name(), a.role()];
+                    if contact {
+                        row.extend([a.email().unwrap_or_default(), a.phone(self.config.phone_format).unwrap_or_default()]);
                     }
+                    table.row(row);
+                }
+                table
+            }
+            Tab::Unit => {
+                let input = CommitteeInput {
+                    unit_name: &unit_name,
+                    youth: &self.store.youth,
+                    adults: &self.store.adults,
+                    stats: &self.store.unit_stats,
+                    goals: &self.config.unit_goals,
+                };
+                return committee_summary(&input, today, SlideFormat::Text);
+            }
+            Tab::Eagle => {
+                let mut table = Table::new(["Name", "Rank", "Ranks to Go", "Badges", "Required Missing", "POR Days", "Turns 18"]);
+                for p in self.eagle_rows() {
+                    table.row([
+                        p.name.clone(),
+                        p.rank.display_name().to_string(),
+                        p.ranks_remaining.len().to_string(),
+                        format!("{}/{}", p.badges_earned.min(EAGLE_TOTAL_BADGES), EAGLE_TOTAL_BADGES),
+                        p.required_missing.join(", "),
+                        format!("{}/{}", p.por_days.min(EAGLE_POR_DAYS), EAGLE_POR_DAYS),
+                        p.deadline.map(|d| d.format("%Y-%m-%d").to_string()).unwrap_or_default(),
+                    ]);
+                }
+                table
+            }
+            Tab::Attendance => {
+                let mut table = Table::new(["Name", "Attended", "Invited", "Percent"]);
+                for (y, tally) in self.attendance_rows() {
+                    table.row([
+                        y.display_name(),
+                        tally.attended.to_string(),
+                        tally.invited.to_string(),
+                        tally.percent().map(|p| format!("{}%", p)).unwrap_or_else(|| "-".to_string()),
+                    ]);
                 }
+                table
             }
+            Tab::Plugin(_) => Table::new([""]),
+        };
+
+        let mut section = Section::new(format!("{} ({})", self.ui.current_tab.title(), table.rows.len()));
+        section.table(table);
+        to_text(&title, &[section], TextLayout::default())
+    }
+
+    /// Write the current tab's list as plain text to the export directory.
+    pub fn export_text_view(&mut self) {
+        if self.ui.current_tab == Tab::Unit && self.blocked_in_youth_leader_mode("The committee report") {
+            return;
+        }
+        if matches!(self.ui.current_tab, Tab::Plugin(_)) {
+            self.status_message = Some("Plugin tabs don't have a printable list".to_string());
+            return;
+        }
+        let file_name = format!(
+            "{}-{}.txt",
+            self.ui.current_tab.title().to_lowercase(),
+            Local::now().date_naive().format("%Y-%m-%d")
+        );
+        let text = self.text_view();
+        let result = self.config.export_dir().and_then(|dir| {
+            std::fs::create_dir_all(&dir)?;
+            let path = dir.join(file_name);
+            std::fs::write(&path, text)?;
+            Ok(path)
         });
+        match result {
+            Ok(path) => {
+                debug!(path = %path.display(), "Wrote printable list");
+                let unreleased = match self.ui.current_tab {
+                    Tab::Scouts => self.scouts_without_media_release(),
+                    _ => 0,
+                };
+                self.status_message = Some(if unreleased > 0 {
+                    format!(
+                        "Printable list saved to {} ({} profile) - includes {} scouts without a media release",
+                        path.display(),
+                        self.export_profile,
+                        unreleased
+                    )
+                } else {
+                    format!("Printable list saved to {} ({} profile)", path.display(), self.export_profile)
+                });
+            }
+            Err(e) => {
+                warn!(error = %e, "Failed to write printable list");
+                self.status_message = Some(format!("Could not save printable list: {}", e));
+            }
+        }
+    }
+
+    /// Switch the export profile used for files written from here on.
+    pub fn cycle_export_profile(&mut self) {
+        self.export_profile = self.export_profile.next();
+        let detail = match self.export_profile {
+            ExportProfile::Full => "all cached details",
+            ExportProfile::Leader => "contact details and ages, no birth dates or addresses",
+            ExportProfile::Public => "names, patrols, and ranks only",
+        };
+        self.status_message = Some(format!("Export profile: {} ({})", self.export_profile, detail));
+    }
+
+    /// Add entries to the activity feed and persist it.
+    fn record_activity(&mut self, entries: Vec<FeedEntry>) {
+        if entries.is_empty() {
+            return;
+        }
+        debug!(count = entries.len(), "Recording activity feed entries");
+        self.refresh_changes.extend(entries.iter().cloned());
+        self.feed.entries.record(entries, Local::now().date_naive());
+        if let Err(e) = self.cache.save_activity_feed(&self.feed.entries) {
+            warn!(error = %e, "Failed to cache activity feed");
+        }
+    }
+
+    /// Apply the retention policy after a roster refresh, dropping purged
+    /// scouts' data from memory as well as the cache.
+    fn enforce_retention(&mut self, roster: &[Youth]) {
+        let today = Local::now().date_naive();
+        let purged = match self.cache.enforce_retention(&self.config.retention, &self.store.youth, roster, today) {
+            Ok(purged) => purged,
+            Err(e) => {
+                warn!(error = %e, "Failed to apply retention policy");
+                return;
+            }
+        };
+        if purged.is_empty() {
+            return;
+        }
+        for record in &purged {
+            self.store.forget_scout(record.user_id);
+        }
+        if let Ok(Some(cached)) = self.cache.load_consents() {
+            self.consents = cached.data;
+        }
+        if let Ok(Some(cached)) = self.cache.load_accommodations() {
+            self.accommodations = cached.data;
+        }
+        if let Ok(Some(cached)) = self.cache.load_event_attendance() {
+            self.store.event_attendance = cached.data;
+        }
+        if let Ok(Some(cached)) = self.cache.load_skills() {
+            self.skills.matrix = cached.data;
+        }
+        if let Ok(Some(cached)) = self.cache.load_scout_tags() {
+            self.scout_tags = cached.data;
+        }
+        if let Ok(Some(cached)) = self.cache.load_attendance() {
+            self.attendance = cached.data;
+        }
+        if let Ok(Some(cached)) = self.cache.load_closet() {
+            self.closet.items = cached.data;
+        }
+        if let Ok(Some(cached)) = self.cache.load_library() {
+            self.library.titles = cached.data;
+        }
+        if let Ok(Some(cached)) = self.cache.load_high_adventure() {
+            self.trek.plan = cached.data;
+        }
+        if let Ok(Some(cached)) = self.cache.load_eagle_projects() {
+            self.eagle_projects.projects = cached.data;
+        }
+        if let Ok(Some(cached)) = self.cache.load_clinic() {
+            self.clinic.plan = cached.data;
+        }
+        if let Ok(Some(cached)) = self.cache.load_nominations() {
+            self.nominations.list = cached.data;
+        }
+        if let Ok(Some(cached)) = self.cache.load_todos() {
+            self.todos.list = cached.data;
+        }
+        if let Ok(Some(cached)) = self.cache.load_position_plan() {
+            self.planner.plan = cached.data;
+        }
+        if let Ok(Some(cached)) = self.cache.load_den_chiefs() {
+            self.den_chiefs.links = cached.data;
+        }
+        if let Ok(Some(cached)) = self.cache.load_activity_feed() {
+            self.feed.entries = cached.data;
+        }
+        if let Ok(Some(cached)) = self.cache.load_date_corrections() {
+            self.date_correction.log = cached.data;
+        }
+        if self.medications.unlocked {
+            for record in &purged {
+                self.medications.log.remove_scout(record.user_id);
+            }
+        }
+        self.status_message = Some(format!(
+            "Purged data for {} departed scout{} (see trailcache retention)",
+            purged.len(),
+            if purged.len() == 1 { "" } else { "s" }
+        ));
+    }
+
+    /// Open the Feed panel.
+    pub fn show_feed(&mut self) {
+        self.feed.scroll = 0;
+        self.ui.state = AppState::ShowingFeed;
+    }
+
+    /// Feed entries after applying the category and selected-scout filters.
+    pub fn filtered_feed(&self) -> Vec<&FeedEntry> {
+        let user_id = if self.feed.scout_only { self.feed_scout().and_then(|y| y.user_id) } else { None };
+        self.feed.entries.filtered(self.feed.category, user_id)
+    }
+
+    /// Scout the "selected scout" feed filter applies to.
+    pub fn feed_scout(&self) -> Option<&Youth> {
+        self.selected_youth()
+    }
+
+    /// Open the Timers overlay, loading the agenda from the config the first time.
+    pub fn show_timers(&mut self) {
+        if self.agenda.items.is_empty() {
+            let items = if self.config.meeting_agenda.is_empty() {
+                default_agenda()
+            } else {
+                self.config.meeting_agenda.clone()
+            };
+            self.agenda = AgendaTimer::new(items);
+        }
+        self.ui.state = AppState::ShowingTimers;
+    }
+
+    /// Load a countdown preset, stopped at full length.
+    pub fn set_countdown_preset(&mut self, index: usize) {
+        if let Some(&(label, minutes)) = TIMER_PRESETS.get(index) {
+            self.countdown = Countdown::new(label, minutes);
+        }
+    }
+
+    /// Ring once when the countdown ends or the current agenda item runs over.
+    fn tick_timers(&mut self) {
+        let now = Instant::now();
+        if self.countdown.clock.is_running() && self.countdown.is_finished(now) && !self.countdown.announced {
+            self.countdown.announced = true;
+            let message = format!("Time's up: {}", self.countdown.label);
+            self.status_message = Some(message.clone());
+            self.ring(&message);
+        }
+        if self.agenda.is_running()
+            && self.agenda.item_remaining_secs(now) <= 0
+            && self.agenda.announced != Some(self.agenda.current)
+        {
+            self.agenda.announced = Some(self.agenda.current);
+            let title = self.agenda.current_item().map(|i| i.title.clone()).unwrap_or_default();
+            let message = format!("Agenda: time for {} is up", title);
+            self.status_message = Some(message.clone());
+            self.ring(&message);
+        }
+    }
+
+    /// Timers always get attention: the bell, or a desktop notification if configured.
+    fn ring(&self, message: &str) {
+        let mode = match self.config.notifications {
+            NotifyMode::Desktop => NotifyMode::Desktop,
+            _ => NotifyMode::Bell,
+        };
+        crate::notify::send(mode, "Trailcache timer", message);
+    }
+
+    /// Notify the user per the config, but only while the terminal is in the background.
+    pub fn notify(&self, summary: &str, body: &str) {
+        if !self.terminal_focused {
+            debug!(summary, "Sending notification");
+            crate::notify::send(self.config.notifications, summary, body);
+        }
+    }
+
+    /// In youth leader mode, explain that `feature` is for adult leaders and return true.
+    /// How recent the data behind `tab` is. Tabs without roster or event
+    /// data are always current.
+    pub fn view_freshness(&self, tab: Tab) -> Freshness {
+        let cached_at = match tab {
+            Tab::Scouts | Tab::Ranks | Tab::Badges | Tab::Eagle => self.store.cache_times.youth,
+            Tab::Adults => self.store.cache_times.adults,
+            Tab::Events | Tab::Attendance => self.store.cache_times.events,
+            Tab::Unit | Tab::Plugin(_) => None,
+        };
+        match cached_at {
+            Some(at) => self.config.freshness.rule_for(tab.title()).check(at, chrono::Utc::now()),
+            None => Freshness::Current,
+        }
+    }
+
+    /// Why contact details, birth dates, and member IDs are hidden on the
+    /// current tab, if they are.
+    pub fn personal_info_hidden(&self) -> Option<String> {
+        if self.config.youth_leader_mode {
+            return Some("Contact details are hidden in youth leader mode".to_string());
+        }
+        match self.view_freshness(self.ui.current_tab) {
+            Freshness::Expired(days) => Some(format!("Contact details are hidden until this {}-day-old data is refreshed", days)),
+            _ => None,
+        }
+    }
+
+    pub fn blocked_in_youth_leader_mode(&mut self, feature: &str) -> bool {
+        if self.config.youth_leader_mode {
+            self.status_message = Some(format!("{} - not available in youth leader mode", feature));
+        }
+        self.config.youth_leader_mode
+    }
+
+    /// Patrol duties for an event, rotating one patrol per event in date order.
+    pub fn event_duty_roster(&self, event: &Event) -> Vec<(String, String)> {
+        let duties: Vec<String> = if self.config.patrol_duties.is_empty() {
+            DEFAULT_DUTIES.iter().map(|d| d.to_string()).collect()
+        } else {
+            self.config.patrol_duties.clone()
+        };
+        let mut patrols: Vec<String> = if self.store.patrols.is_empty() {
+            self.store.youth.iter().filter_map(|y| y.patrol_name.clone()).collect()
+        } else {
+            self.store.patrols.iter().map(|p| p.name.clone()).collect()
+        };
+        patrols.sort();
+        patrols.dedup();
+
+        let mut by_date: Vec<&Event> = self.store.events.iter().collect();
+        by_date.sort_by(|a, b| a.start_date.cmp(&b.start_date).then(a.id.cmp(&b.id)));
+        let rotation = by_date.iter().position(|e| e.id == event.id).unwrap_or_default();
+
+        duty_roster(&duties, &patrols, rotation)
+            .into_iter()
+            .map(|(duty, patrol)| (duty.to_string(), patrol.to_string()))
+            .collect()
+    }
+
+    /// Pin or unpin the selected scout as one of "my scouts".
+    pub fn toggle_my_scout(&mut self) {
+        let Some(youth) = self.feed_scout() else {
+            return;
+        };
+        let Some(user_id) = youth.user_id else {
+            return;
+        };
+        let name = youth.short_name();
+        let message = if let Some(pos) = self.config.my_scouts.iter().position(|&id| id == user_id) {
+            self.config.my_scouts.remove(pos);
+            format!("Unpinned {}", name)
+        } else {
+            self.config.my_scouts.push(user_id);
+            format!("Pinned {} to My Scouts (Y)", name)
+        };
+        self.status_message = Some(match self.config.save() {
+            Ok(()) => message,
+            Err(e) => {
+                warn!(error = %e, "Failed to save config");
+                format!("{} (not saved: {})", message, e)
+            }
+        });
+    }
+
+    pub fn is_my_scout(&self, youth: &Youth) -> bool {
+        youth.user_id.is_some_and(|id| self.config.my_scouts.contains(&id))
+    }
+
+    /// Open the My Scouts view, or explain how to pin scouts if none are.
+    pub fn show_my_scouts(&mut self) {
+        if self.config.my_scouts.is_empty() {
+            self.status_message = Some("No scouts pinned. Press y on the Scouts tab to pin yours.".to_string());
+            return;
+        }
+        self.my_scouts_scroll = 0;
+        self.ui.state = AppState::ShowingMyScouts;
+    }
+
+    /// Summaries for the pinned scouts still on the roster, in pinned order.
+    pub fn my_scout_summaries(&self) -> Vec<MyScoutSummary> {
+        let today = Local::now().date_naive();
+        self.config
+            .my_scouts
+            .iter()
+            .filter_map(|&id| self.store.youth.iter().find(|y| y.user_id == Some(id)))
+            .map(|youth| {
+                let id = youth.user_id.unwrap_or_default();
+                let input = MyScoutInput {
+                    youth,
+                    ranks: self.store.all_youth_ranks.get(&id).map(Vec::as_slice).unwrap_or_default(),
+                    badges: self.store.all_youth_badges.get(&id).map(Vec::as_slice).unwrap_or_default(),
+                    events: &self.store.events,
+                    den_meetings: &self.den_chiefs.links.meetings(id, today, today + chrono::Duration::days(30)),
+                };
+                my_scout_summary(&input, today)
+            })
+            .collect()
+    }
+
+    /// Open the awards catalog, dropping nominations for awards since received.
+    pub fn show_awards(&mut self) {
+        if self.blocked_in_youth_leader_mode("Award nominations") {
+            return;
+        }
+        let catalog = self.award_catalog();
+        let before = self.nominations.list.entries.len();
+        self.nominations.list.prune_awarded(&catalog);
+        if self.nominations.list.entries.len() != before {
+            self.save_nominations();
+        }
+        self.nominations.catalog_selection = self.nominations.catalog_selection.min(catalog.len().saturating_sub(1));
+        self.ui.state = AppState::ShowingAwards;
+    }
+
+    pub fn award_catalog(&self) -> Vec<AwardCatalogEntry> {
+        award_catalog(&self.store.all_youth_awards)
+    }
+
+    pub fn youth_name(&self, user_id: i64) -> String {
+        self.store.youth
+            .iter()
+            .find(|y| y.user_id == Some(user_id))
+            .map(|y| y.short_name())
+            .unwrap_or_else(|| format!("#{}", user_id))
+    }
+
+    /// Nominate the scout selected on the Scouts tab for the selected award,
+    /// or withdraw the nomination if there already is one.
+    pub fn toggle_nomination(&mut self) {
+        let Some(entry) = self.award_catalog().into_iter().nth(self.nominations.catalog_selection) else {
+            return;
+        };
+        let Some(youth) = self.feed_scout() else {
+            self.status_message = Some("Select a scout on the Scouts tab to nominate".to_string());
+            return;
+        };
+        let (Some(user_id), name) = (youth.user_id, youth.short_name()) else {
+            return;
+        };
+
+        if self.nominations.list.withdraw(user_id, &entry.name) {
+            self.status_message = Some(format!("Withdrew {} for {}", name, entry.name));
+        } else if entry.holders.contains(&user_id) {
+            self.status_message = Some(format!("{} already holds {}", name, entry.name));
+            return;
+        } else {
+            self.nominations.list.nominate(user_id, &entry.name, Local::now().date_naive());
+            self.status_message = Some(format!("Nominated {} for {}", name, entry.name));
+        }
+        self.save_nominations();
+    }
+
+    fn save_nominations(&mut self) {
+        if let Err(e) = self.cache.save_nominations(&self.nominations.list) {
+            warn!(error = %e, "Failed to save nominations");
+            self.status_message = Some(format!("Failed to save nominations: {}", e));
+            self.notify("Save failed", &format!("Could not save nominations: {}", e));
+        }
+    }
+
+    /// Status line reminder for nominations due for follow-up.
+    pub fn nomination_reminder(&self) -> Option<String> {
+        let due = self.nominations.list.due(Local::now().date_naive());
+        match due.len() {
+            0 => None,
+            1 => Some(format!(
+                "Reminder: follow up on {}'s {} nomination [N]",
+                self.youth_name(due[0].user_id),
+                due[0].award
+            )),
+            n => Some(format!("Reminder: {} award nominations need follow-up [N]", n)),
+        }
+    }
+
+    /// Save the selected event as a template, replacing one with the same name.
+    pub fn save_event_template(&mut self) {
+        let Some(event) = self.get_sorted_events().get(self.event_selection.index).copied() else {
+            return;
+        };
+        let template = EventTemplate::from_event(event);
+        let name = template.name.clone();
+        self.templates.templates.save(template);
+        self.templates.selection = self.templates.templates.templates.iter().position(|t| t.name == name).unwrap_or_default();
+        self.status_message = Some(format!("Saved template \"{}\" (L to view)", name));
+        self.save_event_templates();
+    }
+
+    pub fn show_event_templates(&mut self) {
+        if self.templates.templates.templates.is_empty() {
+            self.status_message = Some("No templates yet. Press S on an event to save one.".to_string());
+            return;
+        }
+        self.templates.selection = self.templates.selection.min(self.templates.templates.templates.len() - 1);
+        self.templates.input = None;
+        self.ui.state = AppState::ShowingEventTemplates;
+    }
+
+    pub fn delete_event_template(&mut self) {
+        if let Some(template) = self.templates.templates.remove(self.templates.selection) {
+            self.status_message = Some(format!("Deleted template \"{}\"", template.name));
+            self.templates.selection = self.templates.selection.saturating_sub(1);
+            self.save_event_templates();
+        }
+        if self.templates.templates.templates.is_empty() {
+            self.ui.state = AppState::Normal;
+        }
+    }
+
+    /// Apply the text typed in the templates view.
+    pub fn finish_template_input(&mut self) {
+        let Some((kind, text)) = self.templates.input.take() else {
+            return;
+        };
+        let text = text.trim().to_string();
+        match kind {
+            TemplateInput::PackingItem if !text.is_empty() => {
+                if let Some(template) = self.templates.templates.templates.get_mut(self.templates.selection) {
+                    template.packing_list.push(text);
+                    self.save_event_templates();
+                }
+            }
+            TemplateInput::StartDate => self.export_event_plan(&text),
+            _ => {}
+        }
+    }
+
+    /// Drop the last item from the selected template's packing list.
+    pub fn remove_packing_item(&mut self) {
+        if let Some(template) = self.templates.templates.templates.get_mut(self.templates.selection) {
+            if template.packing_list.pop().is_some() {
+                self.save_event_templates();
+            }
+        }
+    }
+
+    /// Lay out a new event from the selected template starting at `start`
+    /// ("YYYY-MM-DD HH:MM") and save it as a plan to enter in Scoutbook.
+    fn export_event_plan(&mut self, start: &str) {
+        let Some(template) = self.templates.templates.templates.get(self.templates.selection) else {
+            return;
+        };
+        let Ok(start) = NaiveDateTime::parse_from_str(start, "%Y-%m-%d %H:%M") else {
+            self.status_message = Some("Enter the start as YYYY-MM-DD HH:MM".to_string());
+            return;
+        };
+        let plan = template.plan(start);
+
+        let mut details = Section::new(plan.name.clone());
+        details.bullet(format!("Starts: {}", plan.start.format("%a %b %-d, %Y %-I:%M %p")));
+        details.bullet(format!("Ends: {}", plan.end.format("%a %b %-d, %Y %-I:%M %p")));
+        if let Some(location) = &plan.location {
+            details.bullet(format!("Location: {}", location));
+        }
+        let mut invitees = Section::new(format!("Invite ({})", plan.invitees.len()));
+        for invitee in &plan.invitees {
+            invitees.bullet(invitee.name.clone());
+        }
+        let mut packing = Section::new("Packing List");
+        for item in &plan.packing_list {
+            packing.bullet(format!("[ ] {}", item));
+        }
+        let text = to_text(&format!("Event Plan - {}", plan.name), &[details, invitees, packing], TextLayout::default());
+
+        let slug: String = plan
+            .name
+            .to_lowercase()
+            .chars()
+            .map(|c| if c.is_alphanumeric() { c } else { '-' })
+            .collect();
+        let file_name = format!("event-plan-{}-{}.txt", slug, plan.start.format("%Y-%m-%d"));
+        let result = self.config.export_dir().and_then(|dir| {
+            std::fs::create_dir_all(&dir)?;
+            let path = dir.join(file_name);
+            std::fs::write(&path, text)?;
+            Ok(path)
+        });
+        self.status_message = Some(match result {
+            Ok(path) => format!("Event plan saved to {} - enter it in Scoutbook", path.display()),
+            Err(e) => {
+                warn!(error = %e, "Failed to write event plan");
+                format!("Could not save event plan: {}", e)
+            }
+        });
+    }
+
+    /// Open the to-do list. New items attach to the scout or event selected
+    /// on the current tab, or to the unit.
+    pub fn show_todos(&mut self) {
+        self.todos.subject = match self.ui.current_tab {
+            Tab::Scouts => self.feed_scout().and_then(|y| y.user_id).map(TodoSubject::Scout),
+            Tab::Events => self.get_sorted_events().get(self.event_selection.index).map(|e| TodoSubject::Event(e.id)),
+            _ => None,
+        }
+        .unwrap_or(TodoSubject::Unit);
+        self.todos.selection = self.todos.selection.min(self.todo_rows().len().saturating_sub(1));
+        self.todos.input = None;
+        self.ui.state = AppState::ShowingTodos;
+    }
+
+    /// Work out the alerts for the loaded roster, leaving out dismissed ones.
+    /// None in youth leader mode, which hides birth dates.
+    pub fn scan_alerts(&mut self) {
+        if self.config.youth_leader_mode {
+            self.alerts.list.clear();
+            return;
+        }
+        let today = Local::now().date_naive();
+        let stalled = self.stalled_scouts(today);
+        let dismissed = &self.config.dismissed_alerts;
+        self.alerts.list = alerts::scan(&self.store.youth, &self.store.adults, &stalled, today)
+            .into_iter()
+            .filter(|a| !dismissed.contains(&a.id))
+            .collect();
+        self.alerts.selection = self.alerts.selection.min(self.alerts.list.len().saturating_sub(1));
+    }
+
+    /// Scouts with no recorded advancement in the configured number of
+    /// months, among those whose ranks are loaded.
+    fn stalled_scouts(&self, today: NaiveDate) -> Vec<ScoutPace> {
+        stalled_scouts(
+            &self.store.youth,
+            &self.store.all_youth_ranks,
+            &self.store.all_youth_badges,
+            &self.feed.entries,
+            self.config.pace,
+            today,
+        )
+    }
+
+    /// Open the alerts panel the first time the app is ready, if there's
+    /// anything in it.
+    fn open_alerts_on_startup(&mut self) {
+        if !self.alerts.opened && !self.alerts.list.is_empty() {
+            self.alerts.opened = true;
+            self.alerts.selection = 0;
+            self.ui.state = AppState::ShowingAlerts;
+        }
+    }
+
+    pub fn show_alerts(&mut self) {
+        if self.alerts.list.is_empty() {
+            self.status_message = Some("No alerts".to_string());
+            return;
+        }
+        self.alerts.opened = true;
+        self.ui.state = AppState::ShowingAlerts;
+    }
+
+    /// Dismiss the selected alert, or all of them, for good. Dismissals for
+    /// alerts that no longer apply are dropped as the list is saved.
+    pub fn dismiss_alerts(&mut self, all: bool) {
+        let dismissed: Vec<Alert> = if all {
+            std::mem::take(&mut self.alerts.list)
+        } else if self.alerts.selection < self.alerts.list.len() {
+            vec![self.alerts.list.remove(self.alerts.selection)]
+        } else {
+            return;
+        };
+
+        let today = Local::now().date_naive();
+        let current = alerts::scan(&self.store.youth, &self.store.adults, &self.stalled_scouts(today), today);
+        self.config.dismissed_alerts.retain(|id| current.iter().any(|a| &a.id == id));
+        self.config.dismissed_alerts.extend(dismissed.into_iter().map(|a| a.id));
+        if let Err(e) = self.config.save() {
+            self.status_message = Some(format!("Failed to save dismissed alerts: {}", e));
+        }
+
+        self.alerts.selection = self.alerts.selection.min(self.alerts.list.len().saturating_sub(1));
+        if self.alerts.list.is_empty() {
+            self.ui.state = AppState::Normal;
+        }
+    }
+
+    pub fn todo_rows(&self) -> Vec<&TodoItem> {
+        self.todos.list.sorted(self.todos.show_done)
+    }
+
+    /// Name of the scout or event a to-do is about.
+    pub fn todo_subject_label(&self, subject: TodoSubject) -> String {
+        match subject {
+            TodoSubject::Unit => "Unit".to_string(),
+            TodoSubject::Scout(id) => self.store
+                .youth
+                .iter()
+                .find(|y| y.user_id == Some(id))
+                .map(|y| y.short_name())
+                .unwrap_or_else(|| "Former scout".to_string()),
+            TodoSubject::Event(id) => self.store
+                .events
+                .iter()
+                .find(|e| e.id == id)
+                .map(|e| e.name.clone())
+                .unwrap_or_else(|| "Past event".to_string()),
+        }
+    }
+
+    /// Open the global search palette, which matches scouts, adults, events,
+    /// ranks, and badges at once and jumps to the chosen one.
+    pub fn show_palette(&mut self) {
+        self.palette_query.clear();
+        self.palette_selection = 0;
+        self.ui.state = AppState::GlobalSearch;
+    }
+
+    pub fn palette_input(&mut self, c: char) {
+        self.palette_query.push(c);
+        self.palette_selection = 0;
+    }
+
+    pub fn palette_backspace(&mut self) {
+        self.palette_query.pop();
+        self.palette_selection = 0;
+    }
+
+    /// Everything matching the palette query, best fuzzy match first.
+    pub fn palette_hits(&self) -> Vec<PaletteHit> {
+        let query = SearchQuery::new(self.palette_query.trim(), SearchMode::Fuzzy);
+        if query.is_empty() {
+            return Vec::new();
+        }
+        let mut hits = Vec::new();
+        let mut add = |target: PaletteTarget, title: String, detail: String, fields: Vec<String>| {
+            if let Some(score) = query.best_score(&fields) {
+                hits.push(PaletteHit { target, title, detail, score });
+            }
+        };
+        for y in &self.store.youth {
+            if let Some(id) = y.user_id {
+                add(PaletteTarget::Scout(id), y.display_name(), y.patrol_name.clone().unwrap_or_default(), y.search_fields());
+            }
+        }
+        for a in &self.store.adults {
+            if let Some(id) = a.user_id {
+                let fields = vec![a.first_name.clone(), a.last_name.clone(), a.full_name(), a.role()];
+                add(PaletteTarget::Adult(id), a.display_name(), a.role(), fields);
+            }
+        }
+        let today = Local::now().date_naive();
+        let events = self.store.events.iter().map(|e| (e, if e.is_past(today) { EventListView::Past } else { EventListView::Upcoming }));
+        let archived = self.store.event_archive.events.iter().map(|e| (e, EventListView::Archive));
+        for (e, view) in events.chain(archived) {
+            add(PaletteTarget::Event { id: e.id, view }, e.name.clone(), e.formatted_date(), e.search_fields());
+        }
+        for name in self.rank_list_keys() {
+            add(PaletteTarget::Rank(name.clone()), name.clone(), String::new(), vec![name]);
+        }
+        for name in self.badge_list_keys() {
+            add(PaletteTarget::Badge(name.clone()), name.clone(), String::new(), vec![name]);
+        }
+        hits.sort_by(|a, b| b.score.cmp(&a.score).then_with(|| a.title.cmp(&b.title)));
+        hits
+    }
+
+    /// Close the palette and go to the chosen match on its tab. The list
+    /// search is cleared so the match isn't filtered out.
+    pub fn open_palette_hit(&mut self) {
+        let Some(hit) = self.palette_hits().into_iter().nth(self.palette_selection) else {
+            return;
+        };
+        self.ui.state = AppState::Normal;
+        self.clear_search();
+        self.ui.focus = Focus::List;
+        match hit.target {
+            PaletteTarget::Scout(id) | PaletteTarget::Adult(id) => {
+                let kind = if matches!(hit.target, PaletteTarget::Scout(_)) { MemberKind::Youth } else { MemberKind::Adult };
+                self.go_to_member(&MemberMatch { kind, name: hit.title, user_id: Some(id), member_id: None, youth_user_id: None });
+            }
+            PaletteTarget::Event { id, view } => {
+                self.ui.current_tab = Tab::Events;
+                self.ui.event_list_view = view;
+                self.ui.event_detail_view = EventDetailView::Details;
+                self.event_selection.index = self.event_keys().iter().position(|&k| k == id).unwrap_or_default();
+            }
+            PaletteTarget::Rank(name) => {
+                self.ui.current_tab = Tab::Ranks;
+                self.ranks_viewing_requirements = false;
+                self.ranks_selection.index = self.rank_list_keys().iter().position(|k| *k == name).unwrap_or_default();
+                self.ranks_scout_selection = 0;
+            }
+            PaletteTarget::Badge(name) => {
+                self.ui.current_tab = Tab::Badges;
+                self.badges_viewing_requirements = false;
+                self.badges_selection.index = self.badge_list_keys().iter().position(|k| *k == name).unwrap_or_default();
+                self.badges_scout_selection = 0;
+            }
+        }
+    }
+
+    /// Open the BSA ID lookup with an empty query, which lists the scouts
+    /// and adults whose IDs are missing or invalid.
+    pub fn show_member_id_lookup(&mut self) {
+        self.member_id_query.clear();
+        self.member_id_selection = 0;
+        self.ui.state = AppState::LookingUpMemberId;
+    }
+
+    /// People whose BSA ID starts with the query, or with no query, everyone
+    /// whose ID would block advancement submission and what's wrong with it.
+    pub fn member_id_rows(&self) -> Vec<(MemberMatch, Option<MemberIdIssue>)> {
+        if self.member_id_query.trim().is_empty() {
+            member_id_problems(&self.store.youth, &self.store.adults).into_iter().map(|(m, issue)| (m, Some(issue))).collect()
+        } else {
+            find_by_member_id(&self.member_id_query, &self.store.youth, &self.store.adults, &self.store.parents)
+                .into_iter()
+                .map(|m| (m, None))
+                .collect()
+        }
+    }
+
+    pub fn member_id_input(&mut self, c: char) {
+        self.member_id_query.push(c);
+        self.member_id_selection = 0;
+    }
+
+    pub fn member_id_backspace(&mut self) {
+        self.member_id_query.pop();
+        self.member_id_selection = 0;
+    }
+
+    /// Close the lookup and select the chosen person.
+    pub fn open_member_id_match(&mut self) {
+        let Some((found, _)) = self.member_id_rows().into_iter().nth(self.member_id_selection) else {
+            return;
+        };
+        self.ui.state = AppState::Normal;
+        self.go_to_member(&found);
+    }
+
+    /// Open the contact check, listing everyone whose contact details would
+    /// keep Scoutbook messages from reaching their family.
+    pub fn show_contact_check(&mut self) {
+        if let Some(reason) = self.personal_info_hidden() {
+            self.status_message = Some(reason);
+            return;
+        }
+        self.contact_selection = 0;
+        self.ui.state = AppState::CheckingContacts;
+    }
+
+    pub fn contact_rows(&self) -> Vec<(MemberMatch, ContactIssue)> {
+        contact_problems(&self.store.youth, &self.store.adults, &self.store.parents)
+    }
+
+    /// Close the contact check and select the person to fix.
+    pub fn open_contact_problem(&mut self) {
+        let Some((found, _)) = self.contact_rows().into_iter().nth(self.contact_selection) else {
+            return;
+        };
+        self.ui.state = AppState::Normal;
+        self.go_to_member(&found);
+    }
+
+    /// Select a person: scouts on the Scouts tab, adults on the Adults tab,
+    /// and parents' scouts on the Scouts tab.
+    /// Open the Order of the Arrow eligibility list, built from each scout's
+    /// camping log and rank.
+    pub fn show_oa_eligibility(&mut self) {
+        self.oa_selection = 0;
+        self.ui.state = AppState::ShowingOaEligibility;
+    }
+
+    pub fn oa_rows(&self) -> Vec<OaStanding<'_>> {
+        oa_standings(&self.store.youth, &self.store.all_youth_activity)
+    }
+
+    /// Close the OA list and select the scout on the roster.
+    pub fn open_oa_scout(&mut self) {
+        let Some(found) = self.oa_rows().get(self.oa_selection).map(|s| MemberMatch {
+            kind: MemberKind::Youth,
+            name: s.youth.display_name(),
+            user_id: s.youth.user_id,
+            member_id: None,
+            youth_user_id: None,
+        }) else {
+            return;
+        };
+        self.ui.state = AppState::Normal;
+        self.go_to_member(&found);
+    }
+
+    fn go_to_member(&mut self, found: &MemberMatch) {
+        match found.kind {
+            MemberKind::Adult => {
+                if let Some(i) = self.store.adults.iter().position(|a| a.user_id == found.user_id) {
+                    self.ui.current_tab = Tab::Adults;
+                    self.ui.focus = Focus::List;
+                    self.adults_selection.index = i;
+                }
+            }
+            MemberKind::Youth | MemberKind::Parent => {
+                let scout_id = if found.kind == MemberKind::Youth { found.user_id } else { found.youth_user_id };
+                let find = |app: &Self| {
+                    app.roster_rows()
+                        .iter()
+                        .position(|row| matches!(row, RosterRow::Scout(y) if y.user_id == scout_id))
+                };
+                if find(self).is_none() {
+                    self.clear_search();
+                    self.ui.collapsed_patrols.clear();
+                }
+                let Some(i) = find(self) else {
+                    self.status_message = Some(format!("{} isn't on the roster", found.name));
+                    return;
+                };
+                self.ui.current_tab = Tab::Scouts;
+                self.ui.focus = Focus::List;
+                self.roster_selection.index = i;
+                self.selected_youth_ranks.clear();
+                self.selected_youth_badges.clear();
+                self.selected_youth_leadership.clear();
+                self.viewing_requirements = false;
+            }
+        }
+    }
+
+    /// Open the cache of each district unit, each with its own key.
+    fn open_district_caches(&mut self, password: &str) {
+        self.district.caches.clear();
+        for unit in &self.config.district_units {
+            let guid = &unit.organization_guid;
+            match self.config.cache_dir_for(guid).and_then(CacheManager::new_without_encryption) {
+                Ok(mut cache) => {
+                    cache.set_password(password, guid);
+                    self.district.caches.insert(guid.clone(), cache);
+                }
+                Err(e) => warn!(unit = %guid, error = %e, "Failed to open district unit cache"),
+            }
+        }
+    }
+
+    /// Open the district dashboard for the units in the config.
+    pub fn show_district(&mut self) {
+        if self.blocked_in_youth_leader_mode("The district dashboard") {
+            return;
+        }
+        if self.config.district_units.is_empty() {
+            self.status_message = Some("No district units - add one with: trailcache district add <org-guid>".to_string());
+            return;
+        }
+        self.reload_district();
+        self.district.selection = 0;
+        self.district.detail = false;
+        self.ui.state = AppState::ShowingDistrict;
+    }
+
+    /// Rebuild the unit summaries from their caches, keeping the selected unit.
+    fn reload_district(&mut self) {
+        let today = Local::now().date_naive();
+        let selected = self.selected_district_unit().map(|u| u.organization_guid.clone());
+        self.district.units = self
+            .config
+            .district_units
+            .iter()
+            .map(|unit| {
+                let data = self.district.caches.get(&unit.organization_guid).map(|c| c.load_unit_data()).unwrap_or_default();
+                UnitHealth::new(unit, &data, self.config.phone_format)
+            })
+            .collect();
+        sort_by_concerns(&mut self.district.units, today);
+        self.district.selection = selected
+            .and_then(|guid| self.district.units.iter().position(|u| u.organization_guid == guid))
+            .unwrap_or(0);
+    }
+
+    pub fn selected_district_unit(&self) -> Option<&UnitHealth> {
+        self.district.units.get(self.district.selection)
+    }
+
+    /// Fetch every district unit's profile, Key 3, charter, and adults in
+    /// the background.
+    pub fn refresh_district(&mut self) {
+        if self.offline_mode {
+            self.status_message = Some("District refresh unavailable offline".to_string());
+            return;
+        }
+        if self.session.token().is_none() {
+            return;
+        }
+        let api = self.api.clone();
+        let guids: Vec<String> = self.config.district_units.iter().map(|u| u.organization_guid.clone()).collect();
+        self.status_message = Some(format!("Refreshing {} district units...", guids.len()));
+        let tx = self.refresh_tx.clone();
+
+        tokio::spawn(async move {
+            let fetches = guids.into_iter().map(|guid| {
+                let api = api.clone();
+                async move {
+                    let (profile, info, key3, adults) = futures::join!(
+                        api.fetch_org_profile(&guid),
+                        api.fetch_unit_pin(&guid),
+                        api.fetch_key3(&guid),
+                        api.fetch_adults(&guid),
+                    );
+                    // The adult roster is what the dashboard can't do without
+                    let result = adults
+                        .map(|adults| Box::new(UnitData {
+                            profile: profile.ok(),
+                            info: info.ok(),
+                            key3: key3.ok(),
+                            adults: Adult::deduplicate(adults),
+                            fetched_at: Some(chrono::Utc::now()),
+                        }))
+                        .map_err(|e| e.to_string());
+                    (guid, result)
+                }
+            });
+            let mut results = stream::iter(fetches).buffer_unordered(MAX_CONCURRENT_REQUESTS);
+            while let Some((guid, result)) = results.next().await {
+                send_result(&tx, RefreshResult::DistrictUnit(guid, result)).await;
+            }
+        });
+    }
+
+    fn finish_district_fetch(&mut self, org_guid: &str, result: std::result::Result<Box<UnitData>, String>) {
+        match result {
+            Ok(data) => {
+                if let Some(cache) = self.district.caches.get(org_guid) {
+                    if let Err(e) = cache.save_unit_data(&data) {
+                        warn!(unit = %org_guid, error = %e, "Failed to cache district unit");
+                    }
+                }
+                self.status_message = Some("District units refreshed".to_string());
+            }
+            Err(e) => {
+                warn!(unit = %org_guid, error = %e, "District unit fetch failed");
+                self.status_message = Some(format!("Couldn't fetch unit {}: {}", org_guid, e));
+            }
+        }
+        self.reload_district();
+    }
+
+    /// Record that the selected unit came to roundtable today.
+    pub fn mark_district_roundtable(&mut self) {
+        let Some(guid) = self.selected_district_unit().map(|u| u.organization_guid.clone()) else {
+            return;
+        };
+        let today = Local::now().date_naive();
+        if let Some(unit) = self.config.district_units.iter_mut().find(|u| u.organization_guid == guid) {
+            unit.last_roundtable = Some(today);
+        }
+        self.status_message = Some(match self.config.save() {
+            Ok(()) => "Roundtable attendance recorded".to_string(),
+            Err(e) => format!("Failed to save config: {}", e),
+        });
+        self.reload_district();
+    }
+
+    /// Open the linked troop's cache, with its own key.
+    fn open_linked_cache(&mut self, password: &str) {
+        self.linked.cache = None;
+        let Some(guid) = self.config.linked_unit.as_ref().map(|u| u.organization_guid.clone()) else {
+            return;
+        };
+        match self.config.cache_dir_for(&guid).and_then(CacheManager::new_without_encryption) {
+            Ok(mut cache) => {
+                cache.set_password(password, &guid);
+                self.linked.data = cache.load_linked_data();
+                self.linked.cache = Some(cache);
+            }
+            Err(e) => warn!(unit = %guid, error = %e, "Failed to open linked troop cache"),
+        }
+    }
+
+    /// Open the combined view of this troop and its linked troop.
+    pub fn show_linked(&mut self) {
+        if self.config.linked_unit.is_none() {
+            self.status_message = Some("No linked troop - add \"linked_unit\" to the config".to_string());
+            return;
+        }
+        self.linked.scroll = 0;
+        self.ui.state = AppState::ShowingLinked;
+    }
+
+    /// This troop's name in the linked view.
+    pub fn home_troop_name(&self) -> String {
+        self.store.unit_info
+            .as_ref()
+            .and_then(|i| i.name.clone())
+            .or_else(|| self.config.unit_name.clone())
+            .unwrap_or_else(|| "This troop".to_string())
+    }
+
+    /// The linked troop's name: from its profile once fetched, else the config.
+    pub fn linked_troop_name(&self) -> String {
+        self.linked.data
+            .profile
+            .as_ref()
+            .and_then(|p| p.name.clone())
+            .or_else(|| self.config.linked_unit.as_ref().and_then(|u| u.name.clone()))
+            .unwrap_or_else(|| "Linked troop".to_string())
+    }
+
+    /// Lines of the linked view's current list, both troops together.
+    pub fn linked_lines(&self) -> Vec<LinkedLine> {
+        let linked = &self.linked.data;
+        match self.linked.list {
+            LinkedList::Scouts => linked_youth(&self.store.youth, &linked.youth)
+                .into_iter()
+                .map(|row| LinkedLine {
+                    name: row.item.display_name(),
+                    detail: format!("{} {}", row.item.rank_short(), row.item.patrol_name.as_deref().unwrap_or_default()),
+                    troops: row.troops,
+                })
+                .collect(),
+            LinkedList::Adults => linked_adults(&self.store.adults, &linked.adults)
+                .into_iter()
+                .map(|row| LinkedLine {
+                    name: row.item.display_name(),
+                    detail: row.item.position.clone().unwrap_or_default(),
+                    troops: row.troops,
+                })
+                .collect(),
+            LinkedList::Events => {
+                let today = Local::now().date_naive();
+                linked_events(&self.store.events, &self.store.youth, &linked.youth)
+                    .into_iter()
+                    .filter(|row| !row.item.is_past(today))
+                    .map(|row| LinkedLine {
+                        name: row.item.name.clone(),
+                        detail: row.item.start_day().map(|d| d.format("%b %-d").to_string()).unwrap_or_default(),
+                        troops: row.troops,
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    /// Switch the linked view to its next list.
+    pub fn cycle_linked_list(&mut self) {
+        self.linked.list = self.linked.list.next();
+        self.linked.scroll = 0;
+    }
+
+    /// Show both troops, only this one, or only the linked one.
+    pub fn cycle_linked_filter(&mut self) {
+        self.linked.filter = self.linked.filter.next();
+        self.linked.scroll = 0;
+    }
+
+    /// Fetch the linked troop's profile and rosters in the background.
+    pub fn refresh_linked(&mut self) {
+        if self.offline_mode {
+            self.status_message = Some("Linked troop refresh unavailable offline".to_string());
+            return;
+        }
+        let Some(guid) = self.config.linked_unit.as_ref().map(|u| u.organization_guid.clone()) else {
+            return;
+        };
+        if self.session.token().is_none() {
+            return;
+        }
+        let api = self.api.clone();
+        self.status_message = Some("Refreshing linked troop...".to_string());
+        let tx = self.refresh_tx.clone();
+
+        tokio::spawn(async move {
+            let (profile, youth, adults) =
+                futures::join!(api.fetch_org_profile(&guid), api.fetch_youth(&guid), api.fetch_adults(&guid));
+            let result = match (youth, adults) {
+                (Ok(youth), Ok(adults)) => {
+                    Ok(Box::new(LinkedData { profile: profile.ok(), youth, adults: Adult::deduplicate(adults) }))
+                }
+                (Err(e), _) | (_, Err(e)) => Err(e.to_string()),
+            };
+            send_result(&tx, RefreshResult::LinkedUnit(result)).await;
+        });
+    }
+
+    fn finish_linked_fetch(&mut self, result: std::result::Result<Box<LinkedData>, String>) {
+        match result {
+            Ok(data) => {
+                if let Some(cache) = &self.linked.cache {
+                    if let Err(e) = cache.save_linked_data(&data) {
+                        warn!(error = %e, "Failed to cache linked troop");
+                    }
+                }
+                self.linked.data = *data;
+                self.status_message = Some(format!("{} refreshed", self.linked_troop_name()));
+            }
+            Err(e) => {
+                warn!(error = %e, "Linked troop fetch failed");
+                self.status_message = Some(format!("Couldn't fetch the linked troop: {}", e));
+            }
+        }
+    }
+
+    /// To-dos past their due date, for the status bar.
+    pub fn overdue_todos(&self) -> usize {
+        self.todos.list.overdue_count(Local::now().date_naive())
+    }
+
+    /// Index into `todos.items` of the selected row.
+    fn selected_todo(&self) -> Option<usize> {
+        let row = *self.todo_rows().get(self.todos.selection)?;
+        self.todos.list.items.iter().position(|t| std::ptr::eq(t, row))
+    }
+
+    pub fn finish_todo_input(&mut self) {
+        let Some(text) = self.todos.input.take() else {
+            return;
+        };
+        if self.todos.list.add(self.todos.subject, &text) {
+            self.save_todos();
+        }
+    }
+
+    /// Mark the selected to-do done today, or reopen it.
+    pub fn toggle_todo(&mut self) {
+        let Some(i) = self.selected_todo() else {
+            return;
+        };
+        let item = &mut self.todos.list.items[i].item;
+        item.closed_on = if item.is_open() { Some(Local::now().date_naive()) } else { None };
+        self.todos.selection = self.todos.selection.min(self.todo_rows().len().saturating_sub(1));
+        self.save_todos();
+    }
+
+    pub fn delete_todo(&mut self) {
+        let Some(i) = self.selected_todo() else {
+            return;
+        };
+        self.todos.list.items.remove(i);
+        self.todos.selection = self.todos.selection.min(self.todo_rows().len().saturating_sub(1));
+        self.save_todos();
+    }
+
+    /// Open the troop library. Lending acts on the scout selected on the
+    /// Scouts tab, if any.
+    pub fn show_library(&mut self) {
+        self.library.borrower = match self.ui.current_tab {
+            Tab::Scouts => self.feed_scout().and_then(|y| y.user_id),
+            _ => None,
+        };
+        self.library.selection = self.library.selection.min(self.library.titles.items.len().saturating_sub(1));
+        self.library.input = None;
+        self.ui.state = AppState::ShowingLibrary;
+    }
+
+    /// Short name of a scout who borrowed something.
+    pub fn borrower_name(&self, user_id: i64) -> String {
+        self.store.youth
+            .iter()
+            .find(|y| y.user_id == Some(user_id))
+            .map(|y| y.short_name())
+            .unwrap_or_else(|| "Former scout".to_string())
+    }
+
+    pub fn finish_library_input(&mut self) {
+        let Some(text) = self.library.input.take() else {
+            return;
+        };
+        if let Some(index) = self.library.titles.add(&text) {
+            self.library.selection = index;
+            self.save_library();
+        }
+    }
+
+    /// Take a copy of the selected title out of the library.
+    pub fn remove_library_copy(&mut self) {
+        if self.library.titles.remove_copy(self.library.selection) {
+            self.library.selection = self.library.selection.min(self.library.titles.items.len().saturating_sub(1));
+            self.save_library();
+        } else if !self.library.titles.items.is_empty() {
+            self.status_message = Some("Every copy is checked out".to_string());
+        }
+    }
+
+    /// Lend the selected title to the scout the library was opened for.
+    pub fn check_out_library_item(&mut self) {
+        let Some(user_id) = self.library.borrower else {
+            self.status_message = Some("Open the library from a scout on the Scouts tab to lend to them".to_string());
+            return;
+        };
+        let Some(title) = self.library.titles.items.get(self.library.selection).map(|i| i.title.clone()) else {
+            return;
+        };
+        let today = Local::now().date_naive();
+        if self.library.titles.check_out(self.library.selection, user_id, today) {
+            self.status_message = Some(format!(
+                "Lent {} to {}, due {}",
+                title,
+                self.borrower_name(user_id),
+                (today + chrono::Duration::days(LOAN_DAYS)).format("%b %-d")
+            ));
+            self.save_library();
+        } else {
+            self.status_message = Some(format!("No copy of {} to lend to {}", title, self.borrower_name(user_id)));
+        }
+    }
+
+    /// Check the selected title back in from the scout the library was
+    /// opened for, or the copy due soonest.
+    pub fn check_in_library_item(&mut self) {
+        let Some(loan) = self.library.titles.check_in(self.library.selection, self.library.borrower) else {
+            return;
+        };
+        let title = self.library.titles.items[self.library.selection].title.clone();
+        self.status_message = Some(format!("{} returned by {}", title, self.borrower_name(loan.user_id)));
+        self.save_library();
+    }
+
+    fn save_library(&mut self) {
+        if let Err(e) = self.cache.save_library(&self.library.titles) {
+            warn!(error = %e, "Failed to save library");
+            self.status_message = Some(format!("Failed to save library: {}", e));
+            self.notify("Save failed", &format!("Could not save library: {}", e));
+        }
+    }
+
+    /// Open the uniform closet. Checkouts go to the scout selected on the
+    /// Scouts tab, if any.
+    pub fn show_closet(&mut self) {
+        self.closet.borrower = match self.ui.current_tab {
+            Tab::Scouts => self.feed_scout().and_then(|y| y.user_id),
+            _ => None,
+        };
+        self.closet.input = None;
+        self.closet.editing_filter = false;
+        self.closet.selection = self.closet.selection.min(self.closet_rows().len().saturating_sub(1));
+        self.ui.state = AppState::ShowingCloset;
+    }
+
+    /// IDs of closet items matching the filter, newest donation first. Each
+    /// word of the filter has to match the type, size, or note.
+    pub fn closet_rows(&self) -> Vec<u32> {
+        let terms: Vec<SearchQuery> =
+            self.closet.filter.split_whitespace().map(|t| SearchQuery::new(t, SearchMode::Substring)).collect();
+        self.closet.items
+            .items
+            .iter()
+            .rev()
+            .filter(|i| self.closet.show_out || i.is_available())
+            .filter(|i| {
+                let fields = i.search_fields();
+                terms.iter().all(|t| t.best_score(&fields).is_some())
+            })
+            .map(|i| i.id)
+            .collect()
+    }
+
+    fn selected_closet_item(&self) -> Option<u32> {
+        self.closet_rows().get(self.closet.selection).copied()
+    }
+
+    pub fn toggle_closet_show_out(&mut self) {
+        let selected = self.selected_closet_item();
+        self.closet.show_out = !self.closet.show_out;
+        self.closet.selection = selected.and_then(|id| self.closet_rows().iter().position(|&r| r == id)).unwrap_or(0);
+    }
+
+    /// The filter changed; go back to the first match.
+    pub fn closet_filter_changed(&mut self) {
+        self.closet.selection = 0;
+    }
+
+    pub fn finish_closet_input(&mut self) {
+        let Some(text) = self.closet.input.take() else {
+            return;
+        };
+        match self.closet.items.add(&text, Local::now().date_naive()) {
+            Some(id) => {
+                self.closet.selection = self.closet_rows().iter().position(|&r| r == id).unwrap_or(0);
+                self.save_closet();
+            }
+            None => self.status_message = Some("Type the item as: type, size, note".to_string()),
+        }
+    }
+
+    /// Give the selected item to the scout the closet was opened for.
+    pub fn check_out_closet_item(&mut self) {
+        let Some(user_id) = self.closet.borrower else {
+            self.status_message = Some("Open the closet from a scout on the Scouts tab to give them an item".to_string());
+            return;
+        };
+        let Some(id) = self.selected_closet_item() else {
+            return;
+        };
+        if self.closet.items.check_out(id, user_id, Local::now().date_naive()) {
+            let item = self.closet.items.get(id).map(|i| format!("{} ({})", i.kind, i.size)).unwrap_or_default();
+            self.status_message = Some(format!("{} checked out to {}", item, self.borrower_name(user_id)));
+            self.closet.selection = self.closet.selection.min(self.closet_rows().len().saturating_sub(1));
+            self.save_closet();
+        } else {
+            self.status_message = Some("That item is already checked out".to_string());
+        }
+    }
+
+    /// Put the selected item back in the closet.
+    pub fn check_in_closet_item(&mut self) {
+        let Some(id) = self.selected_closet_item() else {
+            return;
+        };
+        if let Some(user_id) = self.closet.items.check_in(id, Local::now().date_naive()) {
+            let item = self.closet.items.get(id).map(|i| format!("{} ({})", i.kind, i.size)).unwrap_or_default();
+            self.status_message = Some(format!("{} returned by {}", item, self.borrower_name(user_id)));
+            self.save_closet();
+        }
+    }
+
+    /// Drop the selected item from the closet, e.g. when it's worn out.
+    pub fn remove_closet_item(&mut self) {
+        let Some(id) = self.selected_closet_item() else {
+            return;
+        };
+        if self.closet.items.remove(id) {
+            self.closet.selection = self.closet.selection.min(self.closet_rows().len().saturating_sub(1));
+            self.save_closet();
+        }
+    }
+
+    fn save_closet(&mut self) {
+        if let Err(e) = self.cache.save_closet(&self.closet.items) {
+            warn!(error = %e, "Failed to save closet");
+            self.status_message = Some(format!("Failed to save closet: {}", e));
+            self.notify("Save failed", &format!("Could not save closet: {}", e));
+        }
+    }
+
+    /// Open the adult skills directory. New skills go to the adult selected
+    /// on the Adults tab, if any.
+    pub fn show_adult_skills(&mut self) {
+        self.adult_skills.adult = match self.ui.current_tab {
+            Tab::Adults => self.store.adults.get(self.adults_selection.index).map(|a| a.get_user_id()),
+            _ => None,
+        };
+        self.adult_skills.input = None;
+        self.adult_skills.editing_filter = false;
+        self.adult_skills.selection = self.adult_skills.selection.min(self.adult_skill_rows().len().saturating_sub(1));
+        self.ui.state = AppState::ShowingAdultSkills;
+    }
+
+    pub fn adult_name(&self, user_id: i64) -> String {
+        self.store.adults
+            .iter()
+            .find(|a| a.get_user_id() == user_id)
+            .map(|a| a.full_name())
+            .unwrap_or_else(|| "Former leader".to_string())
+    }
+
+    /// Skills matching the filter, by skill and then adult. Each word of the
+    /// filter has to match the skill, the note, or the adult's name.
+    pub fn adult_skill_rows(&self) -> Vec<&AdultSkill> {
+        let terms: Vec<SearchQuery> =
+            self.adult_skills.filter.split_whitespace().map(|t| SearchQuery::new(t, SearchMode::Substring)).collect();
+        let mut rows: Vec<(&AdultSkill, String)> = self
+            .adult_skills
+            .directory
+            .skills
+            .iter()
+            .map(|s| (s, self.adult_name(s.user_id)))
+            .filter(|(s, name)| {
+                let fields = [s.skill.clone(), s.note.clone(), name.clone()];
+                terms.iter().all(|t| t.best_score(&fields).is_some())
+            })
+            .collect();
+        rows.sort_by_key(|(s, name)| (s.skill.to_lowercase(), name.clone()));
+        rows.into_iter().map(|(s, _)| s).collect()
+    }
+
+    /// The filter changed; go back to the first match.
+    pub fn adult_skills_filter_changed(&mut self) {
+        self.adult_skills.selection = 0;
+    }
+
+    pub fn start_adult_skill_input(&mut self) {
+        if self.adult_skills.adult.is_none() {
+            self.status_message = Some("Open the directory from an adult on the Adults tab to add their skills".to_string());
+            return;
+        }
+        self.adult_skills.input = Some(String::new());
+    }
+
+    pub fn finish_adult_skill_input(&mut self) {
+        let (Some(text), Some(user_id)) = (self.adult_skills.input.take(), self.adult_skills.adult) else {
+            return;
+        };
+        let Some(added) = self.adult_skills.directory.add(user_id, &text).cloned() else {
+            self.status_message = Some("Type the skill as: skill, expires (YYYY-MM-DD), note".to_string());
+            return;
+        };
+        self.adult_skills.selection = self.adult_skill_rows().iter().position(|s| **s == added).unwrap_or(0);
+        self.save_adult_skills();
+    }
+
+    /// Drop the selected skill, e.g. when a certification won't be renewed.
+    pub fn remove_adult_skill(&mut self) {
+        let Some((user_id, skill)) =
+            self.adult_skill_rows().get(self.adult_skills.selection).map(|s| (s.user_id, s.skill.clone()))
+        else {
+            return;
+        };
+        if self.adult_skills.directory.remove(user_id, &skill) {
+            self.adult_skills.selection = self.adult_skills.selection.min(self.adult_skill_rows().len().saturating_sub(1));
+            self.save_adult_skills();
+        }
+    }
+
+    fn save_adult_skills(&mut self) {
+        if let Err(e) = self.cache.save_adult_skills(&self.adult_skills.directory) {
+            warn!(error = %e, "Failed to save adult skills");
+            self.status_message = Some(format!("Failed to save adult skills: {}", e));
+            self.notify("Save failed", &format!("Could not save adult skills: {}", e));
+        }
+    }
+
+    /// Open the high-adventure screener, starting a plan for next July at
+    /// Philmont with First Class required if there isn't one yet.
+    pub fn show_trek_screener(&mut self) {
+        if self.trek.plan.plan.is_none() {
+            let today = Local::now().date_naive();
+            let year = if today.month() < 7 { today.year() } else { today.year() + 1 };
+            self.trek.plan.plan = Some(TrekPlan {
+                base: Default::default(),
+                date: NaiveDate::from_ymd_opt(year, 7, 1).unwrap_or(today),
+                min_rank: ScoutRank::FirstClass,
+            });
+            self.save_high_adventure();
+        }
+        self.trek.input = None;
+        self.trek.selection = self.trek.selection.min(self.trek_results().len().saturating_sub(1));
+        self.ui.state = AppState::ScreeningTrek;
+    }
+
+    /// Every scout and adult screened against the trek plan.
+    pub fn trek_results(&self) -> Vec<Screening> {
+        let Some(plan) = &self.trek.plan.plan else {
+            return Vec::new();
+        };
+        self.trek.plan.screen(plan, &self.store.youth, &self.store.adults, &self.skills.matrix, Local::now().date_naive())
+    }
+
+    pub fn cycle_trek_base(&mut self) {
+        if let Some(plan) = self.trek.plan.plan.as_mut() {
+            plan.base = plan.base.next();
+            self.save_high_adventure();
+        }
+    }
+
+    /// Raise the minimum rank one step, wrapping back to none after Eagle.
+    pub fn cycle_trek_rank(&mut self) {
+        if let Some(plan) = self.trek.plan.plan.as_mut() {
+            let ranks = ScoutRank::all_display_order();
+            plan.min_rank = ranks.iter().rev().copied().find(|r| r.order() > plan.min_rank.order()).unwrap_or(ScoutRank::Unknown);
+            self.save_high_adventure();
+        }
+    }
+
+    /// Record the height/weight check for the selected person: unchecked,
+    /// meets the chart, or over it.
+    pub fn cycle_trek_weight(&mut self) {
+        let Some(user_id) = self.trek_results().get(self.trek.selection).map(|s| s.user_id) else {
+            return;
+        };
+        self.trek.plan.cycle_weight(user_id);
+        self.save_high_adventure();
+    }
+
+    pub fn start_trek_input(&mut self, field: TrekField) {
+        let date = match field {
+            TrekField::TrekDate => self.trek.plan.plan.as_ref().map(|p| p.date),
+            TrekField::ExamDate => {
+                let Some(user_id) = self.trek_results().get(self.trek.selection).map(|s| s.user_id) else {
+                    return;
+                };
+                self.trek.plan.physical(user_id).exam_on
+            }
+        };
+        self.trek.input = Some((field, date.map(|d| d.format("%Y-%m-%d").to_string()).unwrap_or_default()));
+    }
+
+    /// Save a typed date. An empty exam date clears it.
+    pub fn finish_trek_input(&mut self) {
+        let Some((field, text)) = self.trek.input.take() else {
+            return;
+        };
+        let text = text.trim();
+        let date = match NaiveDate::parse_from_str(text, "%Y-%m-%d") {
+            Ok(date) => Some(date),
+            Err(_) if text.is_empty() && field == TrekField::ExamDate => None,
+            Err(_) => {
+                self.status_message = Some(format!("\"{}\" isn't a date - use YYYY-MM-DD", text));
+                return;
+            }
+        };
+        match field {
+            TrekField::TrekDate => {
+                if let (Some(plan), Some(date)) = (self.trek.plan.plan.as_mut(), date) {
+                    plan.date = date;
+                }
+            }
+            TrekField::ExamDate => {
+                let Some(user_id) = self.trek_results().get(self.trek.selection).map(|s| s.user_id) else {
+                    return;
+                };
+                self.trek.plan.set_exam(user_id, date);
+            }
+        }
+        self.save_high_adventure();
+    }
+
+    /// Write the eligible-participant list and gap report to the export
+    /// folder.
+    pub fn export_trek_report(&mut self) {
+        let Some(plan) = self.trek.plan.plan.clone() else {
+            return;
+        };
+        let results = self.trek_results();
+        let title = format!("{} Eligibility - {}", plan.base.name(), plan.date.format("%b %-d, %Y"));
+        let text = to_text(&title, &high_adventure_sections(&plan, &results), TextLayout::default());
+        let file_name = format!(
+            "{}-eligibility-{}.txt",
+            plan.base.name().to_lowercase().replace(' ', "-"),
+            plan.date.format("%Y-%m-%d")
+        );
+        let result = self.config.export_dir().and_then(|dir| {
+            std::fs::create_dir_all(&dir)?;
+            let path = dir.join(file_name);
+            std::fs::write(&path, text)?;
+            Ok(path)
+        });
+        let eligible = results.iter().filter(|s| s.is_eligible()).count();
+        self.status_message = Some(match result {
+            Ok(path) => format!("{} of {} eligible - report saved to {}", eligible, results.len(), path.display()),
+            Err(e) => {
+                warn!(error = %e, "Failed to write trek eligibility report");
+                format!("Could not save trek report: {}", e)
+            }
+        });
+    }
+
+    fn save_high_adventure(&mut self) {
+        if let Err(e) = self.cache.save_high_adventure(&self.trek.plan) {
+            warn!(error = %e, "Failed to save high adventure plan");
+            self.status_message = Some(format!("Failed to save high adventure plan: {}", e));
+            self.notify("Save failed", &format!("Could not save high adventure plan: {}", e));
+        }
+    }
+
+    /// Open the Eagle project of the scout selected on the Eagle tab.
+    pub fn show_eagle_project(&mut self) {
+        let Some(user_id) = self.eagle_rows().get(self.eagle_selection.index).map(|p| p.user_id) else {
+            return;
+        };
+        self.eagle_projects.user = Some(user_id);
+        self.eagle_projects.input = None;
+        self.ui.state = AppState::ShowingEagleProject;
+    }
+
+    /// Name of the candidate whose project is open.
+    pub fn eagle_project_name(&self) -> String {
+        self.eagle_projects.user
+            .and_then(|id| self.store.youth.iter().find(|y| y.user_id == Some(id)))
+            .map(|y| y.display_name())
+            .unwrap_or_default()
+    }
+
+    pub fn start_project_input(&mut self, field: ProjectField) {
+        let project = self.eagle_projects.user.and_then(|id| self.eagle_projects.projects.get(id));
+        let text = match field {
+            ProjectField::Title => project.map(|p| p.title.clone()).unwrap_or_default(),
+            ProjectField::Beneficiary => project.map(|p| p.beneficiary.clone()).unwrap_or_default(),
+            ProjectField::Hours | ProjectField::Workday => String::new(),
+        };
+        self.eagle_projects.input = Some((field, text));
+    }
+
+    pub fn finish_project_input(&mut self) {
+        let (Some((field, text)), Some(user_id)) = (self.eagle_projects.input.take(), self.eagle_projects.user) else {
+            return;
+        };
+        let today = Local::now().date_naive();
+        let text = text.trim().to_string();
+        match field {
+            ProjectField::Title => self.eagle_projects.projects.get_or_start(user_id, today).title = text,
+            ProjectField::Beneficiary => self.eagle_projects.projects.get_or_start(user_id, today).beneficiary = text,
+            ProjectField::Hours => {
+                if self.eagle_projects.projects.log_hours(user_id, &text, today).is_none() {
+                    self.status_message = Some("Type hours as a number, then who worked: 3.5 patrol helpers".to_string());
+                    return;
+                }
+            }
+            ProjectField::Workday => {
+                let Ok(date) = NaiveDate::parse_from_str(&text, "%Y-%m-%d") else {
+                    self.status_message = Some(format!("\"{}\" isn't a date - use YYYY-MM-DD", text));
+                    return;
+                };
+                let ids: Vec<i64> = self.store
+                    .events
+                    .iter()
+                    .chain(&self.store.event_archive.events)
+                    .filter(|e| e.start_day() == Some(date))
+                    .map(|e| e.id)
+                    .collect();
+                if ids.is_empty() {
+                    self.status_message = Some(format!("No troop event on {}", date.format("%b %-d, %Y")));
+                    return;
+                }
+                let added = self.eagle_projects.projects.link_workdays(user_id, ids, today);
+                self.status_message = Some(format!("Linked {} workday event(s)", added));
+            }
+        }
+        self.save_eagle_projects();
+    }
+
+    /// Move the open project's stage forward or back.
+    pub fn set_project_stage(&mut self, forward: bool) {
+        let Some(user_id) = self.eagle_projects.user else {
+            return;
+        };
+        if self.eagle_projects.projects.set_stage(user_id, forward, Local::now().date_naive()).is_some() {
+            self.save_eagle_projects();
+        }
+    }
+
+    pub fn cycle_project_fundraising(&mut self) {
+        let Some(user_id) = self.eagle_projects.user else {
+            return;
+        };
+        self.eagle_projects.projects.cycle_fundraising(user_id, Local::now().date_naive());
+        self.save_eagle_projects();
+    }
+
+    /// Check off the selected paperwork item, or uncheck it.
+    pub fn toggle_project_paperwork(&mut self) {
+        let (Some(user_id), Some(&item)) = (self.eagle_projects.user, Paperwork::ALL.get(self.eagle_projects.selection)) else {
+            return;
+        };
+        self.eagle_projects.projects.toggle_paperwork(user_id, item, Local::now().date_naive());
+        self.save_eagle_projects();
+    }
+
+    fn save_eagle_projects(&mut self) {
+        if let Err(e) = self.cache.save_eagle_projects(&self.eagle_projects.projects) {
+            warn!(error = %e, "Failed to save Eagle projects");
+            self.status_message = Some(format!("Failed to save Eagle projects: {}", e));
+            self.notify("Save failed", &format!("Could not save Eagle projects: {}", e));
+        }
+    }
+
+    /// Open the clinic planner. Registrations go to the scout selected on
+    /// the Scouts tab, if any.
+    pub fn show_clinic(&mut self) {
+        self.clinic.scout = match self.ui.current_tab {
+            Tab::Scouts => self.feed_scout().and_then(|y| y.user_id),
+            _ => None,
+        };
+        self.clinic.input = None;
+        self.clinic.selection = self.clinic.selection.min(self.clinic_rows().len().saturating_sub(1));
+        self.ui.state = AppState::PlanningClinic;
+    }
+
+    /// The planner's rows: each class, followed by its registered scouts.
+    pub fn clinic_rows(&self) -> Vec<(usize, Option<i64>)> {
+        let mut rows = Vec::new();
+        for (i, class) in self.clinic.plan.classes.iter().enumerate() {
+            rows.push((i, None));
+            rows.extend(class.scouts.iter().map(|&id| (i, Some(id))));
+        }
+        rows
+    }
+
+    fn selected_clinic_row(&self) -> Option<(usize, Option<i64>)> {
+        self.clinic_rows().get(self.clinic.selection).copied()
+    }
+
+    pub fn start_clinic_input(&mut self, field: ClinicField) {
+        let class = self.selected_clinic_row().and_then(|(i, _)| self.clinic.plan.classes.get(i));
+        let text = match field {
+            ClinicField::Name => self.clinic.plan.name.clone(),
+            ClinicField::Date => self.clinic.plan.date.map(|d| d.format("%Y-%m-%d").to_string()).unwrap_or_default(),
+            ClinicField::Badge => String::new(),
+            ClinicField::Counselor => match class {
+                Some(class) => class.counselor.clone(),
+                None => {
+                    self.status_message = Some("Add a class first".to_string());
+                    return;
+                }
+            },
+        };
+        self.clinic.input = Some((field, text));
+    }
+
+    pub fn finish_clinic_input(&mut self) {
+        let Some((field, text)) = self.clinic.input.take() else {
+            return;
+        };
+        let text = text.trim().to_string();
+        match field {
+            ClinicField::Name if text.is_empty() => return,
+            ClinicField::Name => self.clinic.plan.name = text,
+            ClinicField::Date => match NaiveDate::parse_from_str(&text, "%Y-%m-%d") {
+                Ok(date) => self.clinic.plan.date = Some(date),
+                Err(_) if text.is_empty() => self.clinic.plan.date = None,
+                Err(_) => {
+                    self.status_message = Some(format!("\"{}\" isn't a date - use YYYY-MM-DD", text));
+                    return;
+                }
+            },
+            ClinicField::Badge => {
+                // Use the badge's Scoutbook spelling when a scout has it
+                let badge = self.store
+                    .all_youth_badges
+                    .values()
+                    .flatten()
+                    .find(|b| b.name.eq_ignore_ascii_case(&text))
+                    .map(|b| b.name.clone())
+                    .unwrap_or(text);
+                match self.clinic.plan.add_class(&badge) {
+                    Some(index) => {
+                        self.clinic.selection = self.clinic_rows().iter().position(|&r| r == (index, None)).unwrap_or(0);
+                    }
+                    None => {
+                        self.status_message = Some(format!("{} is already offered", badge));
+                        return;
+                    }
+                }
+            }
+            ClinicField::Counselor => {
+                let Some((index, _)) = self.selected_clinic_row() else {
+                    return;
+                };
+                // A unique match among the troop's adults gets their full name
+                let needle = text.to_lowercase();
+                let matches: Vec<String> = self.store
+                    .adults
+                    .iter()
+                    .map(|a| a.full_name())
+                    .filter(|name| !needle.is_empty() && name.to_lowercase().contains(&needle))
+                    .collect();
+                self.clinic.plan.classes[index].counselor = match matches.as_slice() {
+                    [name] => name.clone(),
+                    _ => text,
+                };
+            }
+        }
+        self.save_clinic();
+    }
+
+    /// Register the scout the planner was opened for in the selected class,
+    /// or drop them if they're registered.
+    pub fn toggle_clinic_registration(&mut self) {
+        let Some(user_id) = self.clinic.scout else {
+            self.status_message = Some("Open the clinic planner from a scout on the Scouts tab to register them".to_string());
+            return;
+        };
+        let Some((index, _)) = self.selected_clinic_row() else {
+            return;
+        };
+        let name = self.youth_name(user_id);
+        let badge = self.clinic.plan.classes[index].badge.clone();
+        let earned = self.store
+            .all_youth_badges
+            .get(&user_id)
+            .is_some_and(|badges| badges.iter().any(|b| b.name.eq_ignore_ascii_case(&badge) && b.is_completed()));
+        self.status_message = Some(if self.clinic.plan.toggle_registration(index, user_id) {
+            if earned {
+                format!("Registered {} for {} - they've already earned it", name, badge)
+            } else {
+                format!("Registered {} for {}", name, badge)
+            }
+        } else {
+            format!("Dropped {} from {}", name, badge)
+        });
+        self.clinic.selection = self.clinic.selection.min(self.clinic_rows().len().saturating_sub(1));
+        self.save_clinic();
+    }
+
+    /// Mark the selected scout as having finished their class, or not.
+    pub fn toggle_clinic_completion(&mut self) {
+        let Some((index, Some(user_id))) = self.selected_clinic_row() else {
+            return;
+        };
+        if self.clinic.plan.toggle_completed(index, user_id).is_some() {
+            self.save_clinic();
+        }
+    }
+
+    /// Remove the selected class, or drop the selected scout from theirs.
+    pub fn remove_clinic_row(&mut self) {
+        match self.selected_clinic_row() {
+            Some((index, Some(user_id))) => {
+                self.clinic.plan.toggle_registration(index, user_id);
+            }
+            Some((index, None)) => {
+                self.clinic.plan.remove_class(index);
+            }
+            None => return,
+        }
+        self.clinic.selection = self.clinic.selection.min(self.clinic_rows().len().saturating_sub(1));
+        self.save_clinic();
+    }
+
+    /// Save class rosters, or sign-off sheets for the clinic's completions,
+    /// to the export folder.
+    pub fn export_clinic(&mut self, signoff: bool) {
+        let stem = self.clinic.plan.name.to_lowercase().replace(|c: char| !c.is_alphanumeric(), "-");
+        let (sections, file_name) = if signoff {
+            (clinic_signoff_sections(&self.clinic.plan, &self.store.youth), format!("{}-signoff.txt", stem))
+        } else {
+            (clinic_roster_sections(&self.clinic.plan, &self.store.youth), format!("{}-rosters.txt", stem))
+        };
+        if sections.is_empty() {
+            self.status_message =
+                Some(if signoff { "No scouts marked complete yet" } else { "No classes planned yet" }.to_string());
+            return;
+        }
+        let result = self.config.export_dir().and_then(|dir| {
+            std::fs::create_dir_all(&dir)?;
+            let path = dir.join(file_name);
+            let text = if signoff {
+                signoff_document(&path, &self.clinic.plan.name, &sections)
+            } else {
+                to_text(&self.clinic.plan.name, &sections, TextLayout::default())
+            };
+            std::fs::write(&path, text)?;
+            Ok(path)
+        });
+        self.status_message = Some(match result {
+            Ok(path) => format!("Saved {} pages to {}", sections.len(), path.display()),
+            Err(e) => {
+                warn!(error = %e, "Failed to write clinic export");
+                format!("Could not save clinic export: {}", e)
+            }
+        });
+    }
+
+    fn save_clinic(&mut self) {
+        if let Err(e) = self.cache.save_clinic(&self.clinic.plan) {
+            warn!(error = %e, "Failed to save clinic");
+            self.status_message = Some(format!("Failed to save clinic: {}", e));
+            self.notify("Save failed", &format!("Could not save clinic: {}", e));
+        }
+    }
+
+    /// The scout and rank or badge whose requirements are loaded for `view`.
+    fn viewed_advancement(&self, view: AdvancementView) -> Option<(i64, AdvancementRef)> {
+        match view {
+            AdvancementView::Ranks => Some((self.viewing_rank_user_id?, AdvancementRef::Rank(self.viewing_rank_id?))),
+            AdvancementView::MeritBadges => Some((self.viewing_badge_user_id?, AdvancementRef::Badge(self.viewing_badge_id?))),
+        }
+    }
+
+    /// The accommodation recorded for a requirement (by full number) of the
+    /// rank or badge loaded for `view`.
+    pub fn requirement_accommodation(&self, view: AdvancementView, requirement: &str) -> Option<&Accommodation> {
+        let (user_id, advancement) = self.viewed_advancement(view)?;
+        self.accommodations.get(user_id, advancement, requirement)
+    }
+
+    /// Start typing an accommodation for the requirement selected on the
+    /// Scouts tab, starting from the one already recorded.
+    pub fn start_accommodation_input(&mut self) {
+        if self.blocked_in_youth_leader_mode("Accommodations") {
+            return;
+        }
+        let Some((user_id, advancement)) = self.viewed_advancement(self.ui.advancement_view) else {
+            return;
+        };
+        let tree = match self.ui.advancement_view {
+            AdvancementView::Ranks => RequirementTree::build(&self.selected_rank_requirements),
+            AdvancementView::MeritBadges => RequirementTree::build(&self.selected_badge_requirements),
+        };
+        if self.requirement_selection >= tree.len() {
+            return;
+        }
+        let requirement = tree.node(self.requirement_selection).number.clone();
+        let text = self
+            .accommodations
+            .get(user_id, advancement, &requirement)
+            .map(|a| a.note.clone())
+            .unwrap_or_default();
+        self.accommodation_input = Some(AccommodationDraft { user_id, advancement, requirement, text });
+    }
+
+    pub fn finish_accommodation_input(&mut self) {
+        let Some(draft) = self.accommodation_input.take() else {
+            return;
+        };
+        let today = Local::now().date_naive();
+        if !self.accommodations.set(draft.user_id, draft.advancement, &draft.requirement, &draft.text, today) {
+            return;
+        }
+        let name = self.youth_name(draft.user_id);
+        self.status_message = Some(if draft.text.trim().is_empty() {
+            format!("Cleared accommodation for {} requirement {}", name, draft.requirement)
+        } else {
+            format!("Recorded accommodation for {} requirement {}", name, draft.requirement)
+        });
+        if let Err(e) = self.cache.save_accommodations(&self.accommodations) {
+            warn!(error = %e, "Failed to save accommodations");
+            self.status_message = Some(format!("Failed to save accommodations: {}", e));
+        }
+    }
+
+    fn save_todos(&mut self) {
+        if let Err(e) = self.cache.save_todos(&self.todos.list) {
+            warn!(error = %e, "Failed to save to-do list");
+            self.status_message = Some(format!("Failed to save to-do list: {}", e));
+            self.notify("Save failed", &format!("Could not save to-do list: {}", e));
+        }
+    }
+
+    /// Open the minutes for the selected committee meeting, starting them
+    /// with attendees from the RSVPs.
+    pub fn show_minutes(&mut self) {
+        if self.blocked_in_youth_leader_mode("Committee minutes") {
+            return;
+        }
+        let Some(event) = self.get_sorted_events().get(self.event_selection.index).map(|e| (*e).clone()) else {
+            return;
+        };
+        if !is_committee_event(&event) {
+            self.status_message = Some("Minutes are for committee meetings".to_string());
+            return;
+        }
+        let Some(i) = self.minutes.meetings.minutes_for_event(&event) else {
+            self.status_message = Some("This meeting has no date".to_string());
+            return;
+        };
+        self.minutes.selection = i;
+        self.minutes.item_selection = 0;
+        self.minutes.input = None;
+        self.ui.state = AppState::EditingMinutes;
+    }
+
+    /// Close the editor, discarding minutes that were opened but never written.
+    pub fn close_minutes(&mut self) {
+        let before = self.minutes.meetings.meetings.len();
+        self.minutes.meetings.meetings.retain(|m| !m.notes.is_empty() || !m.action_items.is_empty());
+        if self.minutes.meetings.meetings.len() != before {
+            self.save_minutes();
+        }
+        self.ui.state = AppState::Normal;
+    }
+
+    /// Refill attendees from the meeting's current RSVPs.
+    pub fn refresh_minutes_attendees(&mut self) {
+        let Some(minutes) = self.minutes.meetings.meetings.get_mut(self.minutes.selection) else {
+            return;
+        };
+        let Some(event) = self.store.events.iter().find(|e| e.id == minutes.event_id) else {
+            return;
+        };
+        minutes.fill_attendees(event);
+        self.status_message = Some(format!("{} attendees from RSVPs", minutes.attendees.len()));
+        self.save_minutes();
+    }
+
+    pub fn finish_minutes_input(&mut self) {
+        let Some((kind, text)) = self.minutes.input.take() else {
+            return;
+        };
+        let Some(minutes) = self.minutes.meetings.meetings.get_mut(self.minutes.selection) else {
+            return;
+        };
+        let text = text.trim();
+        match kind {
+            MinutesInput::Note if !text.is_empty() => minutes.notes.push(text.to_string()),
+            MinutesInput::ActionItem => match ActionItem::parse(text) {
+                Some(item) => {
+                    minutes.action_items.push(item);
+                    self.minutes.item_selection = minutes.action_items.len() - 1;
+                }
+                None => return,
+            },
+            _ => return,
+        }
+        self.save_minutes();
+    }
+
+    /// Drop the last note from the minutes.
+    pub fn remove_minutes_note(&mut self) {
+        if let Some(minutes) = self.minutes.meetings.meetings.get_mut(self.minutes.selection) {
+            if minutes.notes.pop().is_some() {
+                self.save_minutes();
+            }
+        }
+    }
+
+    /// Close the selected action item, or reopen it if already closed.
+    pub fn toggle_action_item(&mut self) {
+        let today = Local::now().date_naive();
+        let Some(item) = self
+            .minutes
+            .meetings
+            .meetings
+            .get_mut(self.minutes.selection)
+            .and_then(|m| m.action_items.get_mut(self.minutes.item_selection))
+        else {
+            return;
+        };
+        item.closed_on = if item.is_open() { Some(today) } else { None };
+        self.save_minutes();
+    }
+
+    pub fn delete_action_item(&mut self) {
+        let Some(minutes) = self.minutes.meetings.meetings.get_mut(self.minutes.selection) else {
+            return;
+        };
+        if self.minutes.item_selection < minutes.action_items.len() {
+            minutes.action_items.remove(self.minutes.item_selection);
+            self.minutes.item_selection = self.minutes.item_selection.min(minutes.action_items.len().saturating_sub(1));
+            self.save_minutes();
+        }
+    }
+
+    fn save_minutes(&mut self) {
+        if let Err(e) = self.cache.save_minutes(&self.minutes.meetings) {
+            warn!(error = %e, "Failed to save minutes");
+            self.status_message = Some(format!("Failed to save minutes: {}", e));
+            self.notify("Save failed", &format!("Could not save minutes: {}", e));
+        }
+    }
+
+    /// Start typing a debrief note for the selected event.
+    pub fn start_debrief_note(&mut self) {
+        if self.get_sorted_events().get(self.event_selection.index).is_some() {
+            self.debrief_input = Some((DebriefKind::WentWell, String::new()));
+        }
+    }
+
+    /// Add the typed note to the selected event's debrief.
+    pub fn finish_debrief_note(&mut self) {
+        let Some((kind, text)) = self.debrief_input.take() else {
+            return;
+        };
+        let text = text.trim().to_string();
+        let Some(event) = self.get_sorted_events().get(self.event_selection.index).map(|e| (*e).clone()) else {
+            return;
+        };
+        if text.is_empty() {
+            return;
+        }
+        if !self.debriefs.add(&event, kind, text, Local::now().date_naive()) {
+            self.status_message = Some("Debriefs need an event with a start date".to_string());
+            return;
+        }
+        self.save_debriefs();
+    }
+
+    /// Remove the most recent debrief note from the selected event.
+    pub fn remove_debrief_note(&mut self) {
+        let Some(event_id) = self.get_sorted_events().get(self.event_selection.index).map(|e| e.id) else {
+            return;
+        };
+        if let Some(note) = self.debriefs.remove_last(event_id) {
+            self.status_message = Some(format!("Removed debrief note: {}", note.text));
+            self.save_debriefs();
+        }
+    }
+
+    fn save_debriefs(&mut self) {
+        if let Err(e) = self.cache.save_debriefs(&self.debriefs) {
+            warn!(error = %e, "Failed to save debriefs");
+            self.status_message = Some(format!("Failed to save debriefs: {}", e));
+            self.notify("Save failed", &format!("Could not save debriefs: {}", e));
+        }
+    }
+
+    /// Open the historian log at the selected event's entry, starting one if
+    /// the event hasn't been written up yet.
+    pub fn show_historian_log(&mut self) {
+        let event = self.get_sorted_events().get(self.event_selection.index).map(|e| (*e).clone());
+        if let Some(i) = event.and_then(|e| self.history.log.entry_for_event(&e)) {
+            self.history.selection = i;
+        }
+        if self.history.log.entries.is_empty() {
+            self.status_message = Some("Select an event with a date to start the historian log".to_string());
+            return;
+        }
+        self.history.input = None;
+        self.ui.state = AppState::ShowingHistory;
+    }
+
+    /// Close the log, dropping entries that were started but left blank.
+    pub fn close_historian_log(&mut self) {
+        self.history.log.prune_empty();
+        self.history.selection = self.history.selection.min(self.history.log.entries.len().saturating_sub(1));
+        self.ui.state = AppState::Normal;
+    }
+
+    pub fn start_history_input(&mut self, field: HistoryField) {
+        let Some(entry) = self.history.log.entries.get(self.history.selection) else {
+            return;
+        };
+        let text = match field {
+            HistoryField::Summary => entry.summary.clone(),
+            HistoryField::Highlight => String::new(),
+            HistoryField::PhotoFolder => entry.photo_folder.clone().unwrap_or_default(),
+        };
+        self.history.input = Some((field, text));
+    }
+
+    pub fn finish_history_input(&mut self) {
+        let Some((field, text)) = self.history.input.take() else {
+            return;
+        };
+        let Some(entry) = self.history.log.entries.get_mut(self.history.selection) else {
+            return;
+        };
+        let text = text.trim().to_string();
+        match field {
+            HistoryField::Summary => entry.summary = text,
+            HistoryField::Highlight if !text.is_empty() => entry.highlights.push(text),
+            HistoryField::Highlight => return,
+            HistoryField::PhotoFolder => entry.photo_folder = Some(text).filter(|t| !t.is_empty()),
+        }
+        self.save_historian_log();
+    }
+
+    /// Drop the last highlight from the selected entry.
+    pub fn remove_history_highlight(&mut self) {
+        if let Some(entry) = self.history.log.entries.get_mut(self.history.selection) {
+            if entry.highlights.pop().is_some() {
+                self.save_historian_log();
+            }
+        }
+    }
+
+    pub fn delete_history_entry(&mut self) {
+        if self.history.selection >= self.history.log.entries.len() {
+            return;
+        }
+        let entry = self.history.log.entries.remove(self.history.selection);
+        self.history.selection = self.history.selection.min(self.history.log.entries.len().saturating_sub(1));
+        self.status_message = Some(format!("Deleted log entry for {}", entry.event_name));
+        self.save_historian_log();
+        if self.history.log.entries.is_empty() {
+            self.ui.state = AppState::Normal;
+        }
+    }
+
+    /// Save the troop history for the selected entry's year.
+    pub fn export_troop_history(&mut self) {
+        let year = self
+            .history
+            .log
+            .entries
+            .get(self.history.selection)
+            .map(|e| e.date.year())
+            .unwrap_or_else(|| Local::now().year());
+        let sections = troop_history_sections(&self.history.log, &self.debriefs, year);
+        let unit = self.config.unit_name.clone().unwrap_or_else(|| "Troop".to_string());
+        let text = to_text(&format!("{} History - {}", unit, year), &sections, TextLayout::default());
+        let result = self.config.export_dir().and_then(|dir| {
+            std::fs::create_dir_all(&dir)?;
+            let path = dir.join(format!("troop-history-{}.txt", year));
+            std::fs::write(&path, text)?;
+            Ok(path)
+        });
+        self.status_message = Some(match result {
+            Ok(path) => format!("{} history ({} events) saved to {}", year, sections.len(), path.display()),
+            Err(e) => {
+                warn!(error = %e, "Failed to write troop history");
+                format!("Could not save troop history: {}", e)
+            }
+        });
+    }
+
+    fn save_historian_log(&mut self) {
+        if let Err(e) = self.cache.save_historian_log(&self.history.log) {
+            warn!(error = %e, "Failed to save historian log");
+            self.status_message = Some(format!("Failed to save historian log: {}", e));
+            self.notify("Save failed", &format!("Could not save historian log: {}", e));
+        }
+    }
+
+    fn save_event_templates(&mut self) {
+        if let Err(e) = self.cache.save_event_templates(&self.templates.templates) {
+            warn!(error = %e, "Failed to save event templates");
+            self.status_message = Some(format!("Failed to save event templates: {}", e));
+            self.notify("Save failed", &format!("Could not save event templates: {}", e));
+        }
+    }
+
+    /// Open the counselor view, grouping in-progress badges by counselor with
+    /// whatever requirements are cached.
+    pub fn show_counselors(&mut self) {
+        if self.blocked_in_youth_leader_mode("Counselor reports") {
+            return;
+        }
+        let mut requirements = HashMap::new();
+        for (&user_id, badges) in &self.store.all_youth_badges {
+            for badge in badges.iter().filter(|b| !b.is_completed()) {
+                if let Ok(Some(cached)) = self.cache.load_badge_requirements(user_id, badge.id) {
+                    requirements.insert((user_id, badge.id), cached.data.0);
+                }
+            }
+        }
+        let input = CounselorInput {
+            youth: &self.store.youth,
+            badges: &self.store.all_youth_badges,
+            requirements: &requirements,
+            phone_format: self.config.phone_format,
+        };
+        self.counselors.groups = counselor_groups(&input);
+        if self.counselors.groups.is_empty() {
+            self.status_message = Some("No merit badges in progress".to_string());
+            return;
+        }
+        self.counselors.selection = self.counselors.selection.min(self.counselors.groups.len() - 1);
+        self.counselors.scroll = 0;
+        self.ui.state = AppState::ShowingCounselors;
+    }
+
+    /// Save the report for the selected counselor, or for every counselor.
+    pub fn export_counselor_reports(&mut self, all: bool) {
+        let today = Local::now().date_naive();
+        let groups: Vec<&CounselorGroup> = if all {
+            self.counselors.groups.iter().collect()
+        } else {
+            self.counselors.groups.get(self.counselors.selection).into_iter().collect()
+        };
+        let result = self.config.export_dir().and_then(|dir| {
+            std::fs::create_dir_all(&dir)?;
+            for group in &groups {
+                let title = format!("Merit Badge Counselor Report - {}", today.format("%b %-d, %Y"));
+                let text = to_text(&title, &counselor_sections(group), TextLayout::default());
+                let slug: String = group
+                    .name
+                    .to_lowercase()
+                    .chars()
+                    .map(|c| if c.is_alphanumeric() { c } else { '-' })
+                    .collect();
+                std::fs::write(dir.join(format!("counselor-{}-{}.txt", slug, today.format("%Y-%m-%d"))), text)?;
+            }
+            Ok(dir)
+        });
+        self.status_message = Some(match result {
+            Ok(dir) => format!("Saved {} counselor reports to {}", groups.len(), dir.display()),
+            Err(e) => {
+                warn!(error = %e, "Failed to write counselor reports");
+                format!("Could not save counselor reports: {}", e)
+            }
+        });
+    }
+
+    /// Open the position planner, loading cached leadership history (plus den
+    /// chief service) for the tenure checks.
+    pub fn show_position_planner(&mut self) {
+        let today = Local::now().date_naive();
+        self.planner.leadership = self.store
+            .youth
+            .iter()
+            .filter_map(|y| y.user_id)
+            .map(|id| {
+                let history = self.cache.load_youth_leadership(id).ok().flatten().map(|c| c.data).unwrap_or_default();
+                (id, self.den_chiefs.links.with_leadership(id, &history, today))
+            })
+            .collect();
+        self.planner.selection = self.planner.selection.min(self.meeting_rows().len().saturating_sub(1));
+        self.ui.state = AppState::PlanningPositions;
+    }
+
+    pub fn planner_position_name(&self) -> &'static str {
+        YOUTH_POSITION_PRIORITY[self.planner.position.min(YOUTH_POSITION_PRIORITY.len() - 1)]
+    }
+
+    pub fn position_plan_issues(&self) -> Vec<(i64, String)> {
+        plan_issues(&self.planner.plan, &self.store.youth, &self.planner.leadership, Local::now().date_naive())
+    }
+
+    /// Plan the selected position for the selected scout, or clear it with `None`.
+    pub fn plan_position(&mut self, position: Option<&str>) {
+        let Some(youth) = self.meeting_rows().get(self.planner.selection).copied() else {
+            return;
+        };
+        let (Some(user_id), name) = (youth.user_id, youth.short_name()) else {
+            return;
+        };
+        match position {
+            Some(position) => {
+                self.planner.plan.assign(user_id, position);
+                let issues: Vec<String> = self
+                    .position_plan_issues()
+                    .into_iter()
+                    .filter(|(id, _)| *id == user_id)
+                    .map(|(_, issue)| issue)
+                    .collect();
+                self.status_message = Some(match issues.first() {
+                    Some(issue) => format!("{} planned as {} - {}", name, position, issue),
+                    None => format!("{} planned as {}", name, position),
+                });
+            }
+            None if self.planner.plan.unassign(user_id) => {
+                self.status_message = Some(format!("Cleared planned position for {}", name));
+            }
+            None => return,
+        }
+        if let Err(e) = self.cache.save_position_plan(&self.planner.plan) {
+            warn!(error = %e, "Failed to save position plan");
+            self.status_message = Some(format!("Failed to save position plan: {}", e));
+            self.notify("Save failed", &format!("Could not save position plan: {}", e));
+        }
+    }
+
+    /// Save the plan as text for entering the changes in Scoutbook.
+    pub fn export_position_plan(&mut self) {
+        if self.planner.plan.assignments.is_empty() {
+            self.status_message = Some("No positions planned yet".to_string());
+            return;
+        }
+        let today = Local::now().date_naive();
+        let issues = self.position_plan_issues();
+        let title = format!("Position Plan - {}", today.format("%b %-d, %Y"));
+        let text = to_text(&title, &position_plan_sections(&self.planner.plan, &self.store.youth, &issues), TextLayout::default());
+        let file_name = format!("position-plan-{}.txt", today.format("%Y-%m-%d"));
+        let result = self.config.export_dir().and_then(|dir| {
+            std::fs::create_dir_all(&dir)?;
+            let path = dir.join(file_name);
+            std::fs::write(&path, text)?;
+            Ok(path)
+        });
+        self.status_message = Some(match result {
+            Ok(path) if !issues.is_empty() => {
+                format!("Position plan saved to {} - {} eligibility issues", path.display(), issues.len())
+            }
+            Ok(path) => format!("Position plan saved to {} - enter it in Scoutbook", path.display()),
+            Err(e) => {
+                warn!(error = %e, "Failed to write position plan");
+                format!("Could not save position plan: {}", e)
+            }
+        });
+    }
+
+    /// Start linking the selected scout to a pack as den chief, prefilled
+    /// from their current link.
+    pub fn start_den_chief_link(&mut self) {
+        let Some(user_id) = self.feed_scout().and_then(|y| y.user_id) else {
+            return;
+        };
+        let pack = self.den_chiefs.links.active(user_id).map(|l| l.pack.clone()).unwrap_or_default();
+        self.den_chiefs.input = Some((DenChiefField::Pack, pack));
+    }
+
+    /// Move to the next den chief field, saving the link after the meeting
+    /// day. Leaving the pack blank ends the scout's current link.
+    pub fn finish_den_chief_input(&mut self) {
+        let Some((field, text)) = self.den_chiefs.input.take() else {
+            return;
+        };
+        let Some(youth) = self.feed_scout() else {
+            return;
+        };
+        let (Some(user_id), name) = (youth.user_id, youth.short_name()) else {
+            return;
+        };
+        let today = Local::now().date_naive();
+        let active = self.den_chiefs.links.active(user_id);
+        let text = text.trim().to_string();
+        match field {
+            DenChiefField::Pack if text.is_empty() => {
+                if !self.den_chiefs.links.end(user_id, today) {
+                    return;
+                }
+                self.status_message = Some(format!("Ended den chief service for {}", name));
+            }
+            DenChiefField::Pack => {
+                let den = active.filter(|l| l.pack == text).map(|l| l.den.clone()).unwrap_or_default();
+                self.den_chiefs.draft.0 = text;
+                self.den_chiefs.input = Some((DenChiefField::Den, den));
+                return;
+            }
+            DenChiefField::Den => {
+                let meets = active.and_then(|l| l.meets).map(|m| m.label()).unwrap_or_default();
+                self.den_chiefs.draft.1 = text;
+                self.den_chiefs.input = Some((DenChiefField::Meets, meets));
+                return;
+            }
+            DenChiefField::Meets => {
+                let meets = match MeetingPattern::parse(&text) {
+                    Some(pattern) => Some(pattern),
+                    None if text.is_empty() => None,
+                    None => {
+                        self.status_message = Some("Meeting day should look like \"Tue\" or \"3rd Thu\"".to_string());
+                        self.den_chiefs.input = Some((DenChiefField::Meets, text));
+                        return;
+                    }
+                };
+                let (pack, den) = std::mem::take(&mut self.den_chiefs.draft);
+                self.den_chiefs.links.link(user_id, &pack, &den, meets, today);
+                self.status_message = Some(format!("{} is den chief for {} {}", name, pack, den));
+            }
+        }
+        if let Err(e) = self.cache.save_den_chiefs(&self.den_chiefs.links) {
+            warn!(error = %e, "Failed to save den chief links");
+            self.status_message = Some(format!("Failed to save den chief links: {}", e));
+            self.notify("Save failed", &format!("Could not save den chief links: {}", e));
+        }
+    }
+
+    /// Record the selected scout's family as having signed `form` today, or
+    /// clear a signature that's still current.
+    pub fn toggle_consent(&mut self, form: ConsentForm) {
+        if self.blocked_in_youth_leader_mode("Consent forms") {
+            return;
+        }
+        let Some(youth) = self.feed_scout() else {
+            return;
+        };
+        let (Some(user_id), name) = (youth.user_id, youth.short_name()) else {
+            return;
+        };
+        let today = Local::now().date_naive();
+        if self.consents.is_current(user_id, form, today) {
+            self.consents.revoke(user_id, form);
+            self.status_message = Some(format!("Cleared {} for {}", form.name(), name));
+        } else {
+            self.consents.sign(user_id, form, today);
+            self.status_message = Some(format!("{} signed for {}", form.name(), name));
+        }
+        if let Err(e) = self.cache.save_consents(&self.consents) {
+            warn!(error = %e, "Failed to save consent forms");
+            self.status_message = Some(format!("Failed to save consent forms: {}", e));
+            self.notify("Save failed", &format!("Could not save consent forms: {}", e));
+        }
+    }
+
+    /// Scouts going on `event` whose families haven't signed this year's forms.
+    pub fn event_missing_consents(&self, event: &Event) -> usize {
+        let today = Local::now().date_naive();
+        event_participants(event, &self.store.youth)
+            .iter()
+            .filter(|y| !missing_forms(&self.consents, y.user_id, today).is_empty())
+            .count()
+    }
+
+    /// Adult leadership coverage for an upcoming event, from its RSVPs.
+    /// Past events aren't checked.
+    pub fn event_leader_coverage(&self, event: &Event) -> Option<LeaderCoverage> {
+        let today = Local::now().date_naive();
+        if event.is_past(today) {
+            return None;
+        }
+        Some(LeaderCoverage::check(event, &self.store.youth, &self.store.adults, &self.adult_skills.directory, today))
+    }
+
+    /// Pre-activity checklist items done and total for an upcoming event
+    /// that needs any.
+    pub fn event_activity_plan_progress(&self, event: &Event) -> Option<(usize, usize)> {
+        let today = Local::now().date_naive();
+        if event.is_past(today) {
+            return None;
+        }
+        self.activity_plans.plans.progress(event, &self.skills.matrix, today)
+    }
+
+    /// Scouts on the roster without a current media release.
+    fn scouts_without_media_release(&self) -> usize {
+        let today = Local::now().date_naive();
+        self.store.youth
+            .iter()
+            .filter(|y| y.user_id.is_none_or(|id| !self.consents.is_current(id, ConsentForm::MediaRelease, today)))
+            .count()
+    }
+
+    /// Save a printable emergency sheet for the selected event.
+    pub fn export_emergency_sheet(&mut self) {
+        if self.blocked_in_youth_leader_mode("Emergency sheets") {
+            return;
+        }
+        let Some(event) = self.get_sorted_events().get(self.event_selection.index).map(|e| (*e).clone()) else {
+            return;
+        };
+        let today = Local::now().date_naive();
+        let input = EmergencyInput {
+            event: &event,
+            youth: &self.store.youth,
+            parents: &self.store.parents,
+            consents: &self.consents,
+            phone_format: self.config.phone_format,
+        };
+        let text = to_text(
+            &format!("Emergency Sheet - {}", event.name),
+            &emergency_sheet(&input, today),
+            TextLayout::default(),
+        );
+        let missing = self.event_missing_consents(&event);
+
+        let slug: String = event
+            .name
+            .to_lowercase()
+            .chars()
+            .map(|c| if c.is_alphanumeric() { c } else { '-' })
+            .collect();
+        let file_name = format!("emergency-{}-{}.txt", slug, today.format("%Y-%m-%d"));
+        let result = self.config.export_dir().and_then(|dir| {
+            std::fs::create_dir_all(&dir)?;
+            let path = dir.join(file_name);
+            std::fs::write(&path, text)?;
+            Ok(path)
+        });
+        self.status_message = Some(match result {
+            Ok(path) if missing > 0 => {
+                format!("Emergency sheet saved to {} - {} scouts missing consent forms", path.display(), missing)
+            }
+            Ok(path) => format!("Emergency sheet saved to {}", path.display()),
+            Err(e) => {
+                warn!(error = %e, "Failed to write emergency sheet");
+                format!("Could not save emergency sheet: {}", e)
+            }
+        });
+    }
+
+    /// Write an incident report draft for the selected event, as text and as
+    /// a printable HTML page.
+    pub fn export_incident_report(&mut self) {
+        if self.blocked_in_youth_leader_mode("Incident reports") {
+            return;
+        }
+        let Some(event) = self.get_sorted_events().get(self.event_selection.index).map(|e| (*e).clone()) else {
+            return;
+        };
+        let today = Local::now().date_naive();
+        let unit_name = self.unit_name();
+        let input = IncidentInput {
+            unit_name: &unit_name,
+            event: &event,
+            youth: &self.store.youth,
+            debrief: self.debriefs.for_event(event.id),
+        };
+        let sections = incident_report(&input);
+        let title = format!("Incident Report Draft - {}", event.name);
+
+        let slug: String = event
+            .name
+            .to_lowercase()
+            .chars()
+            .map(|c| if c.is_alphanumeric() { c } else { '-' })
+            .collect();
+        let stem = format!("incident-{}-{}", slug, today.format("%Y-%m-%d"));
+        let result = self.config.export_dir().and_then(|dir| {
+            std::fs::create_dir_all(&dir)?;
+            std::fs::write(dir.join(format!("{}.html", stem)), to_html(&title, &sections))?;
+            let path = dir.join(format!("{}.txt", stem));
+            std::fs::write(&path, to_text(&title, &sections, TextLayout::default()))?;
+            Ok(path)
+        });
+        self.status_message = Some(match result {
+            Ok(path) => format!("Incident report draft saved to {} (and .html to print)", path.display()),
+            Err(e) => {
+                warn!(error = %e, "Failed to write incident report");
+                format!("Could not save incident report: {}", e)
+            }
+        });
+    }
+
+    /// Draw up buddy groups from the scouts going to the selected event.
+    pub fn show_buddy_pairs(&mut self) {
+        let Some(event) = self.get_sorted_events().get(self.event_selection.index).map(|e| (*e).clone()) else {
+            return;
+        };
+        let scouts = event_participants(&event, &self.store.youth);
+        if scouts.len() < 2 {
+            self.status_message = Some("Buddy pairs need at least two scouts going".to_string());
+            return;
+        }
+        self.buddies.groups = BuddyGroups::pair(&scouts);
+        self.buddies.event = Some((event.id, event.name.clone()));
+        self.buddies.selection = 0;
+        self.buddies.marked = None;
+        self.ui.state = AppState::ShowingBuddies;
+    }
+
+    /// Open the pre-activity checklist for the selected event.
+    pub fn show_activity_plan(&mut self) {
+        let Some(event) = self.get_sorted_events().get(self.event_selection.index).map(|e| (*e).clone()) else {
+            return;
+        };
+        if ActivityPlanItem::for_event(&event).is_empty() {
+            self.status_message = Some(format!("{} doesn't need a pre-activity checklist", event.name));
+            return;
+        }
+        self.activity_plans.event = Some(event);
+        self.activity_plans.selection = 0;
+        self.ui.state = AppState::ShowingActivityPlan;
+    }
+
+    /// Items on the open checklist.
+    pub fn activity_plan_items(&self) -> Vec<ActivityPlanItem> {
+        self.activity_plans.event.as_ref().map(ActivityPlanItem::for_event).unwrap_or_default()
+    }
+
+    /// Names of scouts going to an event without a current swimmer
+    /// classification.
+    pub fn event_unclassified_swimmers(&self, event: &Event) -> Vec<String> {
+        unclassified_swimmers(event, &self.skills.matrix, Local::now().date_naive())
+            .into_iter()
+            .map(|id| match self.store.youth.iter().find(|y| y.user_id == Some(id)) {
+                Some(y) => y.short_name(),
+                None => id.to_string(),
+            })
+            .collect()
+    }
+
+    /// Check or uncheck the selected item on the open checklist.
+    pub fn toggle_activity_plan_item(&mut self) {
+        let Some(event) = &self.activity_plans.event else {
+            return;
+        };
+        let Some(&item) = self.activity_plan_items().get(self.activity_plans.selection) else {
+            return;
+        };
+        if !self.activity_plans.plans.toggle(event, item, &self.skills.matrix, Local::now().date_naive()) {
+            self.status_message = Some("Every scout going is classified a swimmer".to_string());
+            return;
+        }
+        if let Err(e) = self.cache.save_activity_plans(&self.activity_plans.plans) {
+            warn!(error = %e, "Failed to save activity plans");
+            self.status_message = Some(format!("Failed to save checklist: {}", e));
+            self.notify("Save failed", &format!("Could not save pre-activity checklist: {}", e));
+        }
+    }
+
+    /// Mark the selected scout, or swap them with the one already marked.
+    pub fn mark_or_swap_buddy(&mut self) {
+        let Some(&(_, user_id)) = self.buddies.groups.rows().get(self.buddies.selection) else {
+            return;
+        };
+        match self.buddies.marked.take() {
+            Some(marked) if marked != user_id => {
+                self.buddies.groups.swap(marked, user_id);
+                self.status_message = Some(format!("Swapped {} and {}", self.youth_name(marked), self.youth_name(user_id)));
+            }
+            Some(_) => {}
+            None => self.buddies.marked = Some(user_id),
+        }
+    }
+
+    /// Save the buddy groups as a check-in sheet with out/in columns for
+    /// each buddy check.
+    pub fn export_buddy_sheet(&mut self) {
+        let Some((_, event_name)) = self.buddies.event.clone() else {
+            return;
+        };
+        let today = Local::now().date_naive();
+        let mut section = Section::new("Buddy Pairs");
+        section.bullet("Check each pair out and back in. Both buddies answer together.");
+        let mut table = Table::new(["Pair", "Scout", "Patrol", "Age", "Out", "In", "Out", "In"]);
+        for (group, user_id) in self.buddies.groups.rows() {
+            let youth = self.store.youth.iter().find(|y| y.user_id == Some(user_id));
+            table.row([
+                group.to_string(),
+                youth.map(|y| y.short_name()).unwrap_or_default(),
+                youth.and_then(|y| y.patrol_name.clone()).unwrap_or_default(),
+                youth.map(|y| y.age_str()).unwrap_or_default(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+            ]);
+        }
+        section.table(table);
+        let sections = vec![section];
+        let title = format!("Buddy Check-In - {}", event_name);
+
+        let slug: String = event_name
+            .to_lowercase()
+            .chars()
+            .map(|c| if c.is_alphanumeric() { c } else { '-' })
+            .collect();
+        let stem = format!("buddies-{}-{}", slug, today.format("%Y-%m-%d"));
+        let result = self.config.export_dir().and_then(|dir| {
+            std::fs::create_dir_all(&dir)?;
+            std::fs::write(dir.join(format!("{}.html", stem)), to_html(&title, &sections))?;
+            let path = dir.join(format!("{}.txt", stem));
+            std::fs::write(&path, to_text(&title, &sections, TextLayout::default()))?;
+            Ok(path)
+        });
+        self.status_message = Some(match result {
+            Ok(path) => format!("Buddy check-in sheet saved to {} (and .html to print)", path.display()),
+            Err(e) => {
+                warn!(error = %e, "Failed to write buddy sheet");
+                format!("Could not save buddy sheet: {}", e)
+            }
+        });
+    }
+
+    /// Open the medication log for the selected scout, asking first if
+    /// access hasn't been confirmed this session.
+    pub fn show_medication_log(&mut self) {
+        if self.blocked_in_youth_leader_mode("Medication log") {
+            return;
+        }
+        self.medications.scout = self.selected_youth().and_then(|y| Some((y.user_id?, y.short_name())));
+        self.medications.input = None;
+        self.ui.state = if self.medications.unlocked {
+            AppState::ShowingMedications
+        } else {
+            AppState::ConfirmingMedicationAccess
+        };
+    }
+
+    pub fn unlock_medication_log(&mut self) {
+        match self.cache.load_medication_log() {
+            Ok(cached) => self.medications.log = cached.map(|c| c.data).unwrap_or_default(),
+            Err(e) => {
+                warn!(error = %e, "Failed to load medication log");
+                self.status_message = Some(format!("Could not open medication log: {}", e));
+                self.ui.state = AppState::Normal;
+                return;
+            }
+        }
+        self.medications.unlocked = true;
+        self.medications.selection = self.medications.log.doses.len().saturating_sub(1);
+        self.ui.state = AppState::ShowingMedications;
+    }
+
+    /// Start entering a dose for the scout the log was opened for.
+    pub fn start_medication_dose(&mut self) {
+        match &self.medications.scout {
+            Some((user_id, _)) => {
+                let last = self.medications.log.last_for(*user_id);
+                let medication = last.map(|d| d.medication.clone()).unwrap_or_default();
+                let dose = last.map(|d| d.dose.clone()).unwrap_or_default();
+                self.medications.draft = (String::new(), dose);
+                self.medications.input = Some((MedicationField::Medication, medication));
+            }
+            None => self.status_message = Some("Select a scout on the Scouts tab first".to_string()),
+        }
+    }
+
+    /// Move to the next field, recording the dose as given now after the last.
+    pub fn finish_medication_field(&mut self) {
+        let Some((field, text)) = self.medications.input.take() else {
+            return;
+        };
+        let text = text.trim().to_string();
+        if text.is_empty() {
+            self.medications.input = Some((field, text));
+            return;
+        }
+        match field {
+            MedicationField::Medication => {
+                self.medications.draft.0 = text;
+                let dose = std::mem::take(&mut self.medications.draft.1);
+                self.medications.input = Some((MedicationField::Dose, dose));
+            }
+            MedicationField::Dose => {
+                self.medications.draft.1 = text;
+                let given_by = self.medications.log.last_given_by().unwrap_or_default().to_string();
+                self.medications.input = Some((MedicationField::GivenBy, given_by));
+            }
+            MedicationField::GivenBy => {
+                let Some((user_id, scout_name)) = self.medications.scout.clone() else {
+                    return;
+                };
+                let (medication, dose) = std::mem::take(&mut self.medications.draft);
+                let given_at = Local::now().naive_local();
+                self.status_message = Some(format!(
+                    "Recorded {} {} for {} at {}",
+                    medication,
+                    dose,
+                    scout_name,
+                    given_at.format("%-I:%M %p")
+                ));
+                self.medications.log.record(MedicationDose { user_id, scout_name, medication, dose, given_at, given_by: text });
+                self.medications.selection = self.medications.log.doses.iter().rposition(|d| d.given_at == given_at).unwrap_or_default();
+                self.save_medication_log();
+            }
+        }
+    }
+
+    /// Delete the selected dose, for one recorded by mistake.
+    pub fn delete_medication_dose(&mut self) {
+        if let Some(dose) = self.medications.log.remove(self.medications.selection) {
+            self.status_message = Some(format!("Deleted {} for {}", dose.medication, dose.scout_name));
+            self.medications.selection = self.medications.selection.min(self.medications.log.doses.len().saturating_sub(1));
+            self.save_medication_log();
+        }
+    }
+
+    fn save_medication_log(&mut self) {
+        if let Err(e) = self.cache.save_medication_log(&self.medications.log) {
+            warn!(error = %e, "Failed to save medication log");
+            self.status_message = Some(format!("Failed to save medication log: {}", e));
+            self.notify("Save failed", &format!("Could not save medication log: {}", e));
+        }
+    }
+
+    /// Write the whole log as CSV, for the camp health officer or the
+    /// scout's family. Only done when asked from the log itself.
+    pub fn export_medication_log(&mut self) {
+        if self.medications.log.doses.is_empty() {
+            self.status_message = Some("No doses recorded".to_string());
+            return;
+        }
+        let csv = self.medications.log.to_csv();
+        let file_name = format!("medication-log-{}.csv", Local::now().format("%Y-%m-%d"));
+        let result = self.config.export_dir().and_then(|dir| {
+            std::fs::create_dir_all(&dir)?;
+            let path = dir.join(file_name);
+            std::fs::write(&path, csv)?;
+            Ok(path)
+        });
+        self.status_message = Some(match result {
+            Ok(path) => format!("Medication log saved to {} - it contains health information", path.display()),
+            Err(e) => {
+                warn!(error = %e, "Failed to write medication log");
+                format!("Could not save medication log: {}", e)
+            }
+        });
+    }
+
+    /// Open the skills matrix, first picking up Totin' Chip and Firem'n Chit
+    /// awards recorded in Scoutbook.
+    pub fn show_skills(&mut self) {
+        if self.skills.matrix.sync_from_awards(&self.store.all_youth_awards) > 0 {
+            self.save_skills();
+        }
+        self.skills.selection = self.skills.selection.min(self.skill_rows().len().saturating_sub(1));
+        self.skills.card_input = None;
+        self.ui.state = AppState::ShowingSkills;
+    }
+
+    /// Scouts shown in the matrix: everyone, or only those who currently
+    /// qualify for the filter certification.
+    pub fn skill_rows(&self) -> Vec<&Youth> {
+        let rows = self.meeting_rows();
+        match self.skills.filter {
+            Some(certification) => {
+                let holders = self.skills.matrix.holders(certification, Local::now().date_naive());
+                rows.into_iter().filter(|y| y.user_id.is_some_and(|id| holders.contains(&id))).collect()
+            }
+            None => rows,
+        }
+    }
+
+    pub fn skills_certification(&self) -> Certification {
+        Certification::ALL[self.skills.column.min(Certification::ALL.len() - 1)]
+    }
+
+    fn selected_skill_scout(&self) -> Option<i64> {
+        self.skill_rows().get(self.skills.selection).and_then(|y| y.user_id)
+    }
+
+    /// Cycle the filter through all scouts and holders of each certification.
+    pub fn cycle_skills_filter(&mut self) {
+        let all = Certification::ALL;
+        self.skills.filter = match self.skills.filter {
+            None => Some(all[0]),
+            Some(c) => all.iter().position(|&a| a == c).and_then(|i| all.get(i + 1)).copied(),
+        };
+        self.skills.selection = 0;
+    }
+
+    /// Mark the selected certification earned today, or clear it.
+    pub fn toggle_skill(&mut self) {
+        let Some(user_id) = self.selected_skill_scout() else {
+            return;
+        };
+        let certification = self.skills_certification();
+        let name = self.youth_name(user_id);
+        if self.skills.matrix.remove(user_id, certification).is_some() {
+            self.status_message = Some(format!("Cleared {} for {}", certification.name(), name));
+        } else {
+            let swim_level = (certification == Certification::SwimTest).then_some(SwimLevel::Swimmer);
+            self.skills.matrix.record(SkillRecord {
+                user_id,
+                certification,
+                earned_on: Some(Local::now().date_naive()),
+                card_number: None,
+                swim_level,
+            });
+            self.status_message = Some(format!("{} earned {}", name, certification.name()));
+        }
+        self.skills.selection = self.skills.selection.min(self.skill_rows().len().saturating_sub(1));
+        self.save_skills();
+    }
+
+    /// Step the selected scout's swim classification, recording a test today if there wasn't one.
+    pub fn cycle_swim_level(&mut self) {
+        let Some(user_id) = self.selected_skill_scout() else {
+            return;
+        };
+        let mut record = self.skills.matrix.get(user_id, Certification::SwimTest).cloned().unwrap_or(SkillRecord {
+            user_id,
+            certification: Certification::SwimTest,
+            earned_on: Some(Local::now().date_naive()),
+            card_number: None,
+            swim_level: None,
+        });
+        record.swim_level = Some(record.swim_level.map(|l| l.next()).unwrap_or(SwimLevel::NonSwimmer));
+        self.skills.matrix.record(record);
+        self.save_skills();
+    }
+
+    /// Start typing a card number for the selected certification, if the scout has it.
+    pub fn start_card_edit(&mut self) {
+        let Some(user_id) = self.selected_skill_scout() else {
+            return;
+        };
+        match self.skills.matrix.get(user_id, self.skills_certification()) {
+            Some(record) => self.skills.card_input = Some(record.card_number.clone().unwrap_or_default()),
+            None => self.status_message = Some("Mark the certification earned first [Space]".to_string()),
+        }
+    }
+
+    pub fn finish_card_edit(&mut self) {
+        let Some(input) = self.skills.card_input.take() else {
+            return;
+        };
+        let Some(user_id) = self.selected_skill_scout() else {
+            return;
+        };
+        if let Some(record) = self.skills.matrix.get(user_id, self.skills_certification()).cloned() {
+            let card_number = Some(input.trim().to_string()).filter(|s| !s.is_empty());
+            self.skills.matrix.record(SkillRecord { card_number, ..record });
+            self.save_skills();
+        }
+    }
+
+    fn save_skills(&mut self) {
+        if let Err(e) = self.cache.save_skills(&self.skills.matrix) {
+            warn!(error = %e, "Failed to save skills");
+            self.status_message = Some(format!("Failed to save skills: {}", e));
+            self.notify("Save failed", &format!("Could not save skills: {}", e));
+        }
+    }
+
+    /// Write the skills matrix to skills.csv in the export directory.
+    pub fn export_skills_csv(&mut self) {
+        let csv = self.skills.matrix.to_csv(|id| self.youth_name(id));
+        let result = self.config.export_dir().and_then(|dir| {
+            std::fs::create_dir_all(&dir)?;
+            let path = dir.join(SKILLS_CSV_FILE);
+            std::fs::write(&path, csv)?;
+            Ok(path)
+        });
+        self.status_message = Some(match result {
+            Ok(path) => format!("Skills saved to {}", path.display()),
+            Err(e) => {
+                warn!(error = %e, "Failed to export skills");
+                format!("Could not export skills: {}", e)
+            }
+        });
+    }
+
+    /// Read skills.csv from the export directory, replacing matching records.
+    pub fn import_skills_csv(&mut self) {
+        let result = self.config.export_dir().and_then(|dir| {
+            let path = dir.join(SKILLS_CSV_FILE);
+            let csv = std::fs::read_to_string(&path)
+                .map_err(|e| anyhow::anyhow!("Reading {}: {}", path.display(), e))?;
+            let mut skills = self.skills.matrix.clone();
+            let count = skills.import_csv(&csv)?;
+            Ok((skills, count))
+        });
+        match result {
+            Ok((skills, count)) => {
+                self.skills.matrix = skills;
+                self.save_skills();
+                self.status_message = Some(format!("Imported {} skill records", count));
+            }
+            Err(e) => {
+                warn!(error = %e, "Failed to import skills");
+                self.status_message = Some(format!("Could not import skills: {}", e));
+            }
+        }
+    }
+
+    /// Open the award checklists for the selected adult.
+    pub fn show_adult_awards(&mut self) {
+        if self.blocked_in_youth_leader_mode("Adult award checklists") {
+            return;
+        }
+        if self.store.adults.get(self.adults_selection.index).is_some() {
+            self.adult_awards.selection = 0;
+            self.ui.state = AppState::EditingAdultAwards;
+        }
+    }
+
+    /// Every checklist row as (award, requirement index), in display order.
+    pub fn adult_award_items(&self) -> Vec<(AdultAward, usize)> {
+        AdultAward::ALL
+            .into_iter()
+            .flat_map(|award| (0..award.requirements().len()).map(move |i| (award, i)))
+            .collect()
+    }
+
+    /// Check or uncheck the selected requirement for the selected adult.
+    pub fn toggle_adult_award(&mut self) {
+        let Some(&(award, index)) = self.adult_award_items().get(self.adult_awards.selection) else {
+            return;
+        };
+        let Some(adult) = self.store.adults.get(self.adults_selection.index) else {
+            return;
+        };
+        if !self.adult_awards.checklists.toggle(adult, award, index) {
+            self.status_message = Some("Position training comes from Scoutbook".to_string());
+            return;
+        }
+        if let Err(e) = self.cache.save_adult_awards(&self.adult_awards.checklists) {
+            warn!(error = %e, "Failed to save adult award checklists");
+            self.status_message = Some(format!("Failed to save award checklist: {}", e));
+            self.notify("Save failed", &format!("Could not save award checklist: {}", e));
+        }
+    }
+
+    /// Open the onboarding checklist for the selected adult, starting one if
+    /// they don't have it yet.
+    pub fn show_onboarding(&mut self) {
+        if self.blocked_in_youth_leader_mode("Leader onboarding") {
+            return;
+        }
+        let Some(adult) = self.store.adults.get(self.adults_selection.index) else {
+            return;
+        };
+        if self.onboarding.checklists.start(adult, Local::now().date_naive()) {
+            self.status_message = Some(format!("Started onboarding for {}", adult.full_name()));
+            self.save_onboarding();
+        }
+        self.onboarding.selection = 0;
+        self.ui.state = AppState::ShowingOnboarding;
+    }
+
+    /// Check or uncheck the selected onboarding item for the selected adult.
+    pub fn toggle_onboarding_item(&mut self) {
+        let Some(&item) = OnboardingItem::ALL.get(self.onboarding.selection) else {
+            return;
+        };
+        let Some(adult) = self.store.adults.get(self.adults_selection.index) else {
+            return;
+        };
+        if !self.onboarding.checklists.toggle(adult, item, Local::now().date_naive()) {
+            self.status_message = Some(format!("{} is done in Scoutbook", item.name()));
+            return;
+        }
+        self.save_onboarding();
+    }
+
+    /// Stop tracking the selected adult's onboarding, e.g. once they're on board.
+    pub fn finish_onboarding(&mut self) {
+        let Some(adult) = self.store.adults.get(self.adults_selection.index) else {
+            return;
+        };
+        if self.onboarding.checklists.remove(adult) {
+            self.status_message = Some(format!("Finished onboarding for {}", adult.full_name()));
+            self.save_onboarding();
+        }
+        self.ui.state = AppState::Normal;
+    }
+
+    fn save_onboarding(&mut self) {
+        if let Err(e) = self.cache.save_onboarding(&self.onboarding.checklists) {
+            warn!(error = %e, "Failed to save onboarding checklists");
+            self.status_message = Some(format!("Failed to save onboarding checklist: {}", e));
+            self.notify("Save failed", &format!("Could not save onboarding checklist: {}", e));
+        }
+    }
+
+    /// Open the committee role matrix.
+    pub fn show_committee_roles(&mut self) {
+        if self.blocked_in_youth_leader_mode("Committee roles") {
+            return;
+        }
+        self.committee.row = 0;
+        self.committee.column = 0;
+        self.ui.state = AppState::ShowingCommitteeRoles;
+    }
+
+    /// Adults listed in the matrix.
+    pub fn committee_rows(&self) -> Vec<&Adult> {
+        if self.committee.all_adults {
+            self.store.adults.iter().collect()
+        } else {
+            self.committee.roles.members(&self.store.adults)
+        }
+    }
+
+    /// List every adult, to assign a role to someone off the committee.
+    pub fn toggle_committee_all_adults(&mut self) {
+        let selected = self.committee_rows().get(self.committee.row).and_then(|a| a.person_guid.clone());
+        self.committee.all_adults = !self.committee.all_adults;
+        self.committee.row = self
+            .committee_rows()
+            .iter()
+            .position(|a| a.person_guid.is_some() && a.person_guid == selected)
+            .unwrap_or(0);
+    }
+
+    /// Assign or unassign the selected adult to the selected role.
+    pub fn toggle_committee_role(&mut self) {
+        let Some(&role) = CommitteeRole::ALL.get(self.committee.column) else {
+            return;
+        };
+        let Some(adult) = self.committee_rows().get(self.committee.row).map(|a| (*a).clone()) else {
+            return;
+        };
+        let Some(assigned) = self.committee.roles.toggle(role, &adult) else {
+            self.status_message = Some(format!("{} has no Scoutbook record to assign", adult.full_name()));
+            return;
+        };
+        self.status_message = Some(if assigned {
+            format!("{} covers {}", adult.full_name(), role.name())
+        } else {
+            format!("{} off {}", adult.full_name(), role.name())
+        });
+        // Unassigning can drop them off the committee list
+        self.committee.row = self.committee.row.min(self.committee_rows().len().saturating_sub(1));
+        if let Err(e) = self.cache.save_committee_roles(&self.committee.roles) {
+            warn!(error = %e, "Failed to save committee roles");
+            self.status_message = Some(format!("Failed to save committee roles: {}", e));
+            self.notify("Save failed", &format!("Could not save committee roles: {}", e));
+        }
+    }
+
+    /// Open the meeting-night attendance sheet for today, starting from any
+    /// attendance already saved for today's meeting.
+    pub fn start_meeting(&mut self) {
+        self.meeting.date = Local::now().date_naive();
+        self.meeting.present = self
+            .attendance
+            .meeting(self.meeting.date)
+            .map(|m| m.present.iter().copied().collect())
+            .unwrap_or_default();
+        self.meeting.selection = 0;
+        self.ui.state = AppState::TakingAttendance;
+    }
+
+    /// Scouts on the attendance sheet, grouped by patrol then sorted by name.
+    pub fn meeting_rows(&self) -> Vec<&Youth> {
+        let mut rows: Vec<&Youth> = self.store.youth.iter().filter(|y| y.user_id.is_some()).collect();
+        rows.sort_by(|a, b| {
+            let patrol = |y: &Youth| y.patrol_name.clone().unwrap_or_default();
+            patrol(a)
+                .cmp(&patrol(b))
+                .then_with(|| a.last_name.cmp(&b.last_name))
+                .then_with(|| a.first_name.cmp(&b.first_name))
+        });
+        rows
+    }
+
+    /// Set the selected scout's attendance and optionally move to the next row.
+    pub fn mark_meeting(&mut self, present: Option<bool>, advance: bool) {
+        let rows = self.meeting_rows();
+        let len = rows.len();
+        let Some(user_id) = rows.get(self.meeting.selection).and_then(|y| y.user_id) else {
+            return;
+        };
+        let present = present.unwrap_or(!self.meeting.present.contains(&user_id));
+        if present {
+            self.meeting.present.insert(user_id);
+        } else {
+            self.meeting.present.remove(&user_id);
+        }
+        if advance && self.meeting.selection + 1 < len {
+            self.meeting.selection += 1;
+        }
+    }
+
+    /// Save the attendance sheet to local history.
+    pub fn save_meeting(&mut self) {
+        let roster: Vec<i64> = self.meeting_rows().iter().filter_map(|y| y.user_id).collect();
+        let mut present: Vec<i64> = roster.iter().copied().filter(|id| self.meeting.present.contains(id)).collect();
+        present.sort_unstable();
+        let count = present.len();
+        let total = roster.len();
+
+        self.attendance.record(MeetingRecord { date: self.meeting.date, present, roster });
+        self.refresh_unit_stats();
+        match self.cache.save_attendance(&self.attendance) {
+            Ok(()) => {
+                self.status_message = Some(format!(
+                    "Saved attendance for {}: {} of {} present",
+                    self.meeting.date.format("%b %-d"),
+                    count,
+                    total
+                ));
+            }
+            Err(e) => {
+                warn!(error = %e, "Failed to save attendance");
+                self.status_message = Some(format!("Failed to save attendance: {}", e));
+                self.notify("Save failed", &format!("Could not save attendance: {}", e));
+            }
+        }
+        self.ui.state = AppState::Normal;
+    }
+
+    /// Scouts on the Attendance tab with their attendance at recorded events,
+    /// filtered by search query, by name or lowest attendance first.
+    pub fn attendance_rows(&self) -> Vec<(&Youth, AttendanceTally)> {
+        let mut rows: Vec<(&Youth, AttendanceTally)> = self
+            .apply_search(self.store.youth.iter(), Youth::search_fields, |a, b| Youth::cmp_by_column(a, b, ScoutSortColumn::Name))
+            .into_iter()
+            .map(|y| (y, self.store.event_attendance.for_scout(y.user_id.unwrap_or_default())))
+            .collect();
+        if self.attendance_sort_by_percent {
+            // Stable sort keeps name order within the same percentage; scouts
+            // never invited go last
+            rows.sort_by_key(|(_, t)| t.percent().unwrap_or(u32::MAX));
+        }
+        rows
+    }
+
+    /// User IDs of the Attendance list in display order.
+    pub fn attendance_keys(&self) -> Vec<i64> {
+        self.attendance_rows().iter().map(|(y, _)| y.user_id.unwrap_or_default()).collect()
+    }
+
+    /// Switch the Attendance tab between name order and lowest attendance
+    /// first. Keeps the selected scout.
+    pub fn toggle_attendance_sort(&mut self) {
+        self.attendance_selection.anchor(self.attendance_keys());
+        self.attendance_sort_by_percent = !self.attendance_sort_by_percent;
+        self.attendance_selection.restore(self.attendance_keys());
+    }
+
+    /// A scout's meeting and event attendance over the past year.
+    pub fn attendance_stats(&self, user_id: i64) -> AttendanceStats {
+        self.store.unit_stats.attendance_for(user_id)
+    }
+
+    /// Switch the Events detail pane to the RSVP view, loading guests for the headcount.
+    pub async fn show_event_rsvp(&mut self) {
+        self.ui.event_detail_view = EventDetailView::Rsvp;
+        let event_id = self.get_sorted_events().get(self.event_selection.index).map(|e| e.id);
+        if let Some(event_id) = event_id {
+            if !self.store.event_guests.contains_key(&event_id) {
+                self.fetch_event_guests(event_id).await;
+            }
+        }
+    }
 
-        self.status_message = Some(format!("Refreshing {}...", tab.title()));
+    /// RSVP totals for an event, including any guests loaded for it.
+    pub fn event_rsvp_stats(&self, event: &Event) -> RsvpStats {
+        let guests = self.store.event_guests.get(&event.id).map(Vec::as_slice).unwrap_or(&[]);
+        RsvpStats::from_event(event, guests)
     }
 
     /// Fetch event guests for a specific event
-    #[allow(dead_code)]
     pub async fn fetch_event_guests(&mut self, event_id: i64) {
-        // In offline mode, don't fetch (event guests aren't cached)
+        // In offline mode, use the guest list persisted on disk
         if self.offline_mode {
+            if !self.store.event_guests.contains_key(&event_id) {
+                if let Ok(Some(cached)) = self.cache.load_event_guests(event_id) {
+                    self.store.remember_event_guests(event_id, cached.data);
+                }
+            }
             return;
         }
 
-        let token = match self.session.token() {
-            Some(t) => t.to_string(),
-            None => return,
-        };
+        if self.session.token().is_none() {
+            return;
+        }
+        let api = self.api.clone();
 
         let tx = self.refresh_tx.clone();
 
         tokio::spawn(async move {
-            let api = match create_authenticated_api(token) {
-                Ok(api) => api,
-                Err(e) => {
-                    error!(error = %e, "Failed to create API client for event guests");
-                    return;
-                }
-            };
-
             if let Ok(data) = api.fetch_event_guests(event_id).await {
-                Self::send_result(&tx, RefreshResult::EventGuests(event_id, data)).await;
+                send_result(&tx, Update::EventGuests(event_id, data).into()).await;
             }
         });
     }
@@ -1730,10 +5598,10 @@ impl App {
             return;
         }
 
-        let token = match self.session.token() {
-            Some(t) => t.to_string(),
-            None => return,
-        };
+        if self.session.token().is_none() {
+            return;
+        }
+        let api = self.api.clone();
 
         let tx = self.refresh_tx.clone();
 
@@ -1751,20 +5619,12 @@ impl App {
 
         // Fetch fresh data in background
         tokio::spawn(async move {
-            let api = match create_authenticated_api(token) {
-                Ok(api) => api,
-                Err(e) => {
-                    error!(error = %e, "Failed to create API client for youth progress");
-                    return;
-                }
-            };
-
             if let Ok(data) = api.fetch_youth_ranks(user_id).await {
-                Self::send_result(&tx, RefreshResult::YouthRanks(user_id, data)).await;
+                send_result(&tx, Update::YouthRanks(user_id, data).into()).await;
             }
 
             if let Ok(data) = api.fetch_youth_merit_badges(user_id).await {
-                Self::send_result(&tx, RefreshResult::YouthMeritBadges(user_id, data)).await;
+                send_result(&tx, Update::YouthMeritBadges(user_id, data).into()).await;
             }
         });
     }
@@ -1784,10 +5644,10 @@ impl App {
             return;
         }
 
-        let token = match self.session.token() {
-            Some(t) => t.to_string(),
-            None => return,
-        };
+        if self.session.token().is_none() {
+            return;
+        }
+        let api = self.api.clone();
 
         let tx = self.refresh_tx.clone();
 
@@ -1801,20 +5661,20 @@ impl App {
 
         // Fetch fresh data in background
         tokio::spawn(async move {
-            let api = match create_authenticated_api(token) {
-                Ok(api) => api,
-                Err(e) => {
-                    error!(error = %e, "Failed to create API client for youth leadership");
-                    return;
-                }
-            };
-
             if let Ok(data) = api.fetch_youth_leadership(user_id).await {
-                Self::send_result(&tx, RefreshResult::YouthLeadership(user_id, data)).await;
+                send_result(&tx, Update::YouthLeadership(user_id, data).into()).await;
             }
         });
     }
 
+    /// Load everything the Timeline view merges: ranks and badges,
+    /// leadership, and awards.
+    pub async fn fetch_youth_timeline(&mut self, user_id: i64) {
+        self.fetch_youth_progress(user_id).await;
+        self.fetch_youth_leadership(user_id).await;
+        self.fetch_youth_awards(user_id).await;
+    }
+
     /// Fetch awards for a specific youth
     pub async fn fetch_youth_awards(&mut self, user_id: i64) {
         if user_id <= 0 {
@@ -1831,10 +5691,10 @@ impl App {
             return;
         }
 
-        let token = match self.session.token() {
-            Some(t) => t.to_string(),
-            None => return,
-        };
+        if self.session.token().is_none() {
+            return;
+        }
+        let api = self.api.clone();
 
         let tx = self.refresh_tx.clone();
 
@@ -1849,19 +5709,9 @@ impl App {
 
         // Fetch fresh data in background
         tokio::spawn(async move {
-            let api = match create_authenticated_api(token) {
-                Ok(api) => api,
-                Err(e) => {
-                    error!(error = %e, "Failed to create API client for youth awards");
-                    // Send empty result so UI shows "No awards" instead of loading forever
-                    Self::send_result(&tx, RefreshResult::YouthAwards(user_id, vec![])).await;
-                    return;
-                }
-            };
-
             // Send result even if empty or on error
             let data = api.fetch_youth_awards(user_id).await.unwrap_or_default();
-            Self::send_result(&tx, RefreshResult::YouthAwards(user_id, data)).await;
+            send_result(&tx, Update::YouthAwards(user_id, data).into()).await;
         });
     }
 
@@ -1888,30 +5738,198 @@ impl App {
             return;
         }
 
-        let token = match self.session.token() {
-            Some(t) => t.to_string(),
-            None => return,
-        };
+        if self.session.token().is_none() {
+            return;
+        }
+        let api = self.api.clone();
 
         let tx = self.refresh_tx.clone();
         let uid = user_id;
         let rid = rank_id;
 
         tokio::spawn(async move {
-            let api = match create_authenticated_api(token) {
-                Ok(api) => api,
-                Err(e) => {
-                    error!(error = %e, "Failed to create API client for rank requirements");
-                    return;
-                }
-            };
-
             if let Ok(data) = api.fetch_rank_requirements(uid, rid).await {
-                Self::send_result(&tx, RefreshResult::RankRequirements(uid, rid, data)).await;
+                send_result(&tx, Update::RankRequirements(uid, rid, data).into()).await;
             }
         });
     }
 
+    /// Ask to mark the requirement selected on the Ranks tab complete.
+    pub fn start_requirement_completion(&mut self) {
+        if self.blocked_in_youth_leader_mode("Recording requirements") {
+            return;
+        }
+        if self.offline_mode {
+            self.status_message = Some("Offline - go online to record requirements".to_string());
+            return;
+        }
+        let (Some(user_id), Some(rank_id)) = (self.viewing_rank_user_id, self.viewing_rank_id) else {
+            return;
+        };
+        let Some(req) = self.selected_rank_requirements.get(self.ranks_requirement_selection) else {
+            return;
+        };
+        match PendingCompletion::new(user_id, rank_id, self.youth_name(user_id), req) {
+            Ok(pending) => {
+                self.pending_completion = Some(pending);
+                self.ui.state = AppState::ConfirmingRequirementCompletion;
+            }
+            Err(why) => self.status_message = Some(why),
+        }
+    }
+
+    /// Mark the confirmed requirement complete as of today. The requirement
+    /// shows as done straight away; it's rolled back if Scoutbook refuses.
+    pub fn complete_pending_requirement(&mut self) {
+        self.ui.state = AppState::Normal;
+        let Some(pending) = self.pending_completion.take() else {
+            return;
+        };
+        if self.session.token().is_none() {
+            self.status_message = Some("Not logged in - can't record requirements".to_string());
+            return;
+        }
+        let api = self.api.clone();
+        let Some(req) = self
+            .selected_rank_requirements
+            .iter_mut()
+            .find(|r| r.id == Some(pending.requirement_id))
+        else {
+            return;
+        };
+
+        let today = Local::now().date_naive();
+        let previous = Box::new(req.clone());
+        req.mark_completed(today);
+        self.status_message = Some(format!("Recording {} for {}...", req.number(), pending.scout));
+
+        let tx = self.refresh_tx.clone();
+        tokio::spawn(requirements::complete_requirement(tx, api, pending, today, previous));
+    }
+
+    fn finish_requirement_completion(
+        &mut self,
+        user_id: i64,
+        rank_id: i64,
+        previous: RankRequirement,
+        result: std::result::Result<(), String>,
+    ) {
+        let name = self.youth_name(user_id);
+        let viewing = self.viewing_rank_user_id == Some(user_id) && self.viewing_rank_id == Some(rank_id);
+        let shown = viewing.then_some(self.selected_rank_requirements.as_mut_slice());
+        self.status_message =
+            Some(requirements::finish_completion(&self.cache, shown, user_id, rank_id, &name, previous, result));
+    }
+
+    /// Patrols a scout can be moved into, in the order the picker lists them.
+    pub fn patrol_choices(&self) -> Vec<&Patrol> {
+        patrol_move::patrol_choices(&self.store.patrols)
+    }
+
+    /// Open the patrol picker for the scout selected on the Scouts tab.
+    pub fn start_patrol_move(&mut self) {
+        if self.blocked_in_youth_leader_mode("Moving scouts between patrols") {
+            return;
+        }
+        if self.offline_mode {
+            self.status_message = Some("Offline - go online to move scouts between patrols".to_string());
+            return;
+        }
+        let Some(youth) = self.selected_youth() else {
+            return;
+        };
+        let Some(user_id) = youth.user_id else {
+            return;
+        };
+        match PatrolMove::new(user_id, youth, &self.store.patrols) {
+            Ok(pending) => {
+                self.patrol_move = Some(pending);
+                self.ui.state = AppState::MovingToPatrol;
+            }
+            Err(why) => self.status_message = Some(why),
+        }
+    }
+
+    /// Move the scout to the patrol picked. The roster shows the new patrol
+    /// straight away; it's put back if Scoutbook refuses.
+    pub fn confirm_patrol_move(&mut self) {
+        self.ui.state = AppState::Normal;
+        let Some(pending) = self.patrol_move.take() else {
+            return;
+        };
+        if self.session.token().is_none() {
+            self.status_message = Some("Not logged in - can't move scouts".to_string());
+            return;
+        }
+        match pending.apply(&mut self.store.youth, &self.store.patrols) {
+            Ok((local, status)) => {
+                self.status_message = Some(status);
+                let org_guid = self.config.organization_guid.clone();
+                tokio::spawn(patrol_move::move_to_patrol(self.refresh_tx.clone(), self.api.clone(), org_guid, local));
+            }
+            Err(why) => self.status_message = Some(why),
+        }
+    }
+
+    fn finish_patrol_move(
+        &mut self,
+        user_id: i64,
+        previous: (Option<String>, Option<String>),
+        result: std::result::Result<(), String>,
+    ) {
+        let name = self.youth_name(user_id);
+        if let Some(status) = patrol_move::finish_move(&mut self.store.youth, user_id, &name, previous, result) {
+            self.status_message = Some(status);
+        }
+    }
+
+    /// Open the bulk date correction, starting with the wrong date.
+    pub fn show_date_correction(&mut self) {
+        if self.blocked_in_youth_leader_mode("Correcting completion dates") {
+            return;
+        }
+        self.date_correction.log = self.cache.load_date_corrections().ok().flatten().map(|c| c.data).unwrap_or_default();
+        self.date_correction.restart();
+        self.ui.state = AppState::CorrectingDates;
+    }
+
+    /// Take the typed wrong or corrected date.
+    pub fn finish_date_correction_input(&mut self) {
+        let (store, cache) = (&self.store, &self.cache);
+        if let Some(message) = self.date_correction.take_input(|date| date_correction::find_completions_on(store, cache, date)) {
+            self.status_message = Some(message);
+        }
+    }
+
+    /// Record the selected completions again with the corrected date.
+    pub fn apply_date_correction(&mut self) {
+        if self.offline_mode {
+            self.status_message = Some("Offline - go online to correct dates".to_string());
+            return;
+        }
+        if self.session.token().is_none() {
+            self.status_message = Some("Not logged in - can't correct dates".to_string());
+            return;
+        }
+        match self.date_correction.start_sending() {
+            Some(Ok(batch)) => {
+                self.status_message = Some(format!("Correcting {} completions...", batch.items.len()));
+                tokio::spawn(date_correction::correct_dates(self.refresh_tx.clone(), self.api.clone(), batch));
+            }
+            Some(Err(why)) => self.status_message = Some(why),
+            None => {}
+        }
+    }
+
+    /// Log the corrections and update the cached requirements that were saved.
+    fn finish_date_correction(&mut self, records: Vec<CorrectionRecord>) {
+        let shown = match (self.viewing_rank_user_id, self.viewing_rank_id) {
+            (Some(user_id), Some(rank_id)) => Some((user_id, rank_id, self.selected_rank_requirements.as_mut_slice())),
+            _ => None,
+        };
+        self.status_message = Some(self.date_correction.finish(&self.cache, shown, records));
+    }
+
     /// Fetch badge requirements for a specific youth and badge
     pub async fn fetch_badge_requirements(&mut self, user_id: i64, badge_id: i64) {
         if user_id <= 0 || badge_id <= 0 {
@@ -1936,26 +5954,18 @@ impl App {
             return;
         }
 
-        let token = match self.session.token() {
-            Some(t) => t.to_string(),
-            None => return,
-        };
+        if self.session.token().is_none() {
+            return;
+        }
+        let api = self.api.clone();
 
         let tx = self.refresh_tx.clone();
         let uid = user_id;
         let bid = badge_id;
 
         tokio::spawn(async move {
-            let api = match create_authenticated_api(token) {
-                Ok(api) => api,
-                Err(e) => {
-                    error!(error = %e, "Failed to create API client for badge requirements");
-                    return;
-                }
-            };
-
             if let Ok((reqs, version, counselor)) = api.fetch_badge_requirements(uid, bid).await {
-                Self::send_result(&tx, RefreshResult::BadgeRequirements(uid, bid, reqs, version, counselor)).await;
+                send_result(&tx, Update::BadgeRequirements(uid, bid, reqs, version, counselor).into()).await;
             }
         });
     }
@@ -1967,7 +5977,7 @@ impl App {
     /// Get patrol members for a specific patrol
     #[allow(dead_code)]
     pub fn get_patrol_members(&self, patrol_guid: &str) -> Vec<&Youth> {
-        self.youth
+        self.store.youth
             .iter()
             .filter(|y| y.patrol_guid.as_deref() == Some(patrol_guid))
             .collect()
@@ -1975,7 +5985,7 @@ impl App {
 
     /// Get parents for a specific youth
     pub fn get_parents_for_youth(&self, youth_user_id: i64) -> Vec<&Parent> {
-        self.parents
+        self.store.parents
             .iter()
             .filter(|p| p.youth_user_id == Some(youth_user_id))
             .collect()
@@ -1983,59 +5993,170 @@ impl App {
 
     /// Check if a youth matches the search query.
     /// Query should already be lowercased.
-    fn youth_matches_search(youth: &Youth, query: &str) -> bool {
-        youth.matches_search(query)
+    /// Append to the search text.
+    pub fn search_push(&mut self, c: char) {
+        self.ui.search_query.push(c);
+        self.rebuild_search();
     }
 
-    /// Get youth sorted by current sort settings, filtered by search query
-    pub fn get_sorted_youth(&self) -> Vec<&Youth> {
-        let mut sorted: Vec<&Youth> = self.youth.iter().collect();
+    /// Remove the last character of the search text.
+    pub fn search_pop(&mut self) {
+        self.ui.search_query.pop();
+        self.rebuild_search();
+    }
 
-        // Apply search filter (searches name, patrol, rank, email)
-        if !self.search_query.is_empty() {
-            let query = self.search_query.to_lowercase();
-            sorted.retain(|y| Self::youth_matches_search(y, &query));
-        }
+    /// Clear the search filter.
+    pub fn clear_search(&mut self) {
+        self.ui.search_query.clear();
+        self.rebuild_search();
+    }
 
-        sorted.sort_by(|a, b| {
-            let cmp = Youth::cmp_by_column(a, b, self.scout_sort_column);
-            if self.scout_sort_ascending { cmp } else { cmp.reverse() }
-        });
+    /// Switch between substring, fuzzy, and regex matching.
+    pub fn cycle_search_mode(&mut self) {
+        self.ui.search_mode = self.ui.search_mode.next();
+        self.rebuild_search();
+    }
 
-        sorted
+    fn rebuild_search(&mut self) {
+        self.ui.search = SearchQuery::new(&self.ui.search_query, self.ui.search_mode);
     }
 
-    /// Get youth sorted by rank (highest to lowest), then alphabetically
-    pub fn get_youth_by_rank(&self) -> Vec<&Youth> {
-        let mut sorted: Vec<&Youth> = self.youth.iter().collect();
+    /// Title suffix describing the active search, e.g. `3 of 45 match "sm" [fuzzy]`.
+    pub fn search_summary(&self, matches: usize, total: usize) -> String {
+        let validity = if self.ui.search.is_valid() { "" } else { " (invalid)" };
+        format!(
+            "{} of {} match \"{}\" [{}{}]",
+            matches,
+            total,
+            self.ui.search.text(),
+            self.ui.search.mode().label(),
+            validity
+        )
+    }
+
+    /// Filter items by the search, ordering by match score when the mode ranks results.
+    fn apply_search<'a, T>(
+        &self,
+        items: impl Iterator<Item = &'a T>,
+        fields: impl Fn(&T) -> Vec<String>,
+        cmp: impl Fn(&T, &T) -> std::cmp::Ordering,
+    ) -> Vec<&'a T> {
+        let mut scored: Vec<(&T, u32)> = items
+            .filter_map(|item| self.ui.search.best_score(&fields(item)).map(|score| (item, score)))
+            .collect();
+        if self.ui.search.ranks_results() {
+            scored.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| cmp(a.0, b.0)));
+        } else {
+            scored.sort_by(|a, b| cmp(a.0, b.0));
+        }
+        scored.into_iter().map(|(item, _)| item).collect()
+    }
+
+    /// Get youth sorted by current sort settings, filtered by search query
+    pub fn get_sorted_youth(&self) -> Vec<&Youth> {
+        self.apply_search(self.store.youth.iter(), Youth::search_fields, |a, b| {
+            let cmp = Youth::cmp_by_column(a, b, self.ui.scout_sort_column);
+            if self.ui.scout_sort_ascending { cmp } else { cmp.reverse() }
+        })
+    }
 
-        // Apply search filter
-        if !self.search_query.is_empty() {
-            let query = self.search_query.to_lowercase();
-            sorted.retain(|y| Self::youth_matches_search(y, &query));
+    /// Rows of the Scouts table: the sorted roster, or with `roster_grouped`,
+    /// a heading per patrol followed by its members in sort order.
+    pub fn roster_rows(&self) -> Vec<RosterRow<'_>> {
+        let sorted = self.get_sorted_youth();
+        if !self.ui.roster_grouped {
+            return sorted.into_iter().map(RosterRow::Scout).collect();
         }
 
-        sorted.sort_by(|a, b| Youth::cmp_by_column(a, b, ScoutSortColumn::Rank));
+        let mut patrols: BTreeMap<&str, Vec<&Youth>> = BTreeMap::new();
+        for youth in sorted {
+            patrols.entry(youth.patrol_name.as_deref().filter(|p| !p.is_empty()).unwrap_or(NO_PATROL)).or_default().push(youth);
+        }
+        let mut rows = Vec::new();
+        for (name, members) in patrols {
+            let collapsed = self.ui.collapsed_patrols.contains(name);
+            rows.push(RosterRow::Patrol { name, members: members.len(), collapsed });
+            if !collapsed {
+                rows.extend(members.into_iter().map(RosterRow::Scout));
+            }
+        }
+        rows
+    }
 
-        sorted
+    /// The scout on the selected Scouts row, if it isn't a patrol heading.
+    pub fn selected_youth(&self) -> Option<&Youth> {
+        match self.roster_rows().get(self.roster_selection.index) {
+            Some(RosterRow::Scout(youth)) => Some(*youth),
+            _ => None,
+        }
     }
 
-    /// Get events sorted by current sort settings, filtered by search query
-    pub fn get_sorted_events(&self) -> Vec<&Event> {
-        let mut sorted: Vec<&Event> = self.events.iter().collect();
+    /// Switch the roster between a flat list and patrol groups. Keeps the selected scout.
+    pub fn toggle_roster_grouping(&mut self) {
+        self.roster_selection.anchor(self.youth_keys());
+        self.ui.roster_grouped = !self.ui.roster_grouped;
+        self.roster_selection.restore(self.youth_keys());
+    }
 
-        // Apply search filter (searches name, location, type)
-        if !self.search_query.is_empty() {
-            let query = self.search_query.to_lowercase();
-            sorted.retain(|e| e.matches_search(&query));
+    /// Collapse or expand the patrol whose heading is selected. Returns false
+    /// if a scout is selected instead.
+    pub fn toggle_selected_patrol(&mut self) -> bool {
+        let name = match self.roster_rows().get(self.roster_selection.index) {
+            Some(RosterRow::Patrol { name, .. }) => name.to_string(),
+            _ => return false,
+        };
+        if !self.ui.collapsed_patrols.remove(&name) {
+            self.ui.collapsed_patrols.insert(name);
         }
+        true
+    }
 
-        sorted.sort_by(|a, b| {
-            let cmp = Event::cmp_by_column(a, b, self.event_sort_column);
-            if self.event_sort_ascending { cmp } else { cmp.reverse() }
+    /// Each scout's Eagle progress, filtered by search query and sorted by
+    /// the Eagle tab's sort settings.
+    pub fn eagle_rows(&self) -> Vec<EagleProgress> {
+        let today = Local::now().date_naive();
+        let mut rows: Vec<EagleProgress> = self
+            .apply_search(self.store.youth.iter(), Youth::search_fields, |a, b| Youth::cmp_by_column(a, b, ScoutSortColumn::Name))
+            .into_iter()
+            .map(|y| {
+                let id = y.user_id.unwrap_or_default();
+                let leadership = self.store.all_youth_leadership.get(&id).map(Vec::as_slice).unwrap_or_default();
+                EagleProgress::new(
+                    y,
+                    self.store.all_youth_ranks.get(&id).map(Vec::as_slice).unwrap_or_default(),
+                    self.store.all_youth_badges.get(&id).map(Vec::as_slice).unwrap_or_default(),
+                    &self.den_chiefs.links.with_leadership(id, leadership, today),
+                    today,
+                )
+            })
+            .collect();
+        rows.sort_by(|a, b| {
+            let cmp = EagleProgress::cmp_by_column(a, b, self.eagle_sort_column);
+            if self.eagle_sort_ascending { cmp } else { cmp.reverse() }
         });
+        rows
+    }
+
+    /// Get youth sorted by rank (highest to lowest), then alphabetically
+    pub fn get_youth_by_rank(&self) -> Vec<&Youth> {
+        self.apply_search(self.store.youth.iter(), Youth::search_fields, |a, b| {
+            Youth::cmp_by_column(a, b, ScoutSortColumn::Rank)
+        })
+    }
 
-        sorted
+    /// Get events in the current list view, sorted by current sort settings and
+    /// filtered by search query
+    pub fn get_sorted_events(&self) -> Vec<&Event> {
+        let today = Local::now().date_naive();
+        let events: Vec<&Event> = match self.ui.event_list_view {
+            EventListView::Upcoming => self.store.events.iter().filter(|e| !e.is_past(today)).collect(),
+            EventListView::Past => self.store.events.iter().filter(|e| e.is_past(today)).collect(),
+            EventListView::Archive => self.store.event_archive.events.iter().collect(),
+        };
+        self.apply_search(events.into_iter(), Event::search_fields, |a, b| {
+            let cmp = Event::cmp_by_column(a, b, self.ui.event_sort_column);
+            if self.ui.event_sort_ascending { cmp } else { cmp.reverse() }
+        })
     }
 
     /// Get the unit name for display
@@ -2051,77 +6172,165 @@ impl App {
     // Sort Toggle Helpers
     // =========================================================================
 
+    /// Cycle the Events tab between upcoming, past, and archived events.
+    pub fn cycle_event_list_view(&mut self) {
+        self.ui.event_list_view = self.ui.event_list_view.next();
+        self.event_selection.reset();
+        self.ui.event_detail_view = EventDetailView::Details;
+        self.event_guest_selection = 0;
+    }
+
     /// Toggle scout sort column - if already sorting by this column, flip direction;
-    /// otherwise switch to this column with ascending=true. Resets selection to 0.
+    /// otherwise switch to this column with ascending=true. Keeps the selected scout.
     pub fn toggle_scout_sort(&mut self, column: ScoutSortColumn) {
-        if self.scout_sort_column == column {
-            self.scout_sort_ascending = !self.scout_sort_ascending;
+        self.roster_selection.anchor(self.youth_keys());
+        if self.ui.scout_sort_column == column {
+            self.ui.scout_sort_ascending = !self.ui.scout_sort_ascending;
         } else {
-            self.scout_sort_column = column;
-            self.scout_sort_ascending = true;
+            self.ui.scout_sort_column = column;
+            self.ui.scout_sort_ascending = true;
         }
-        self.roster_selection = 0;
+        self.roster_selection.restore(self.youth_keys());
+    }
+
+    /// Advance to the next scout sort column (ascending). Keeps the selected scout.
+    pub fn cycle_scout_sort(&mut self) {
+        self.roster_selection.anchor(self.youth_keys());
+        self.ui.scout_sort_column = self.ui.scout_sort_column.next();
+        self.ui.scout_sort_ascending = true;
+        self.roster_selection.restore(self.youth_keys());
+    }
+
+    /// Flip the scout sort direction. Keeps the selected scout.
+    pub fn reverse_scout_sort(&mut self) {
+        self.roster_selection.anchor(self.youth_keys());
+        self.ui.scout_sort_ascending = !self.ui.scout_sort_ascending;
+        self.roster_selection.restore(self.youth_keys());
     }
 
     /// Toggle event sort column - if already sorting by this column, flip direction;
-    /// otherwise switch to this column with ascending=true. Resets selection to 0.
+    /// otherwise switch to this column with ascending=true. Keeps the selected event.
     pub fn toggle_event_sort(&mut self, column: EventSortColumn) {
-        if self.event_sort_column == column {
-            self.event_sort_ascending = !self.event_sort_ascending;
+        self.event_selection.anchor(self.event_keys());
+        if self.ui.event_sort_column == column {
+            self.ui.event_sort_ascending = !self.ui.event_sort_ascending;
+        } else {
+            self.ui.event_sort_column = column;
+            self.ui.event_sort_ascending = true;
+        }
+        self.event_selection.restore(self.event_keys());
+    }
+
+    /// Sort the Eagle tab by `column`, or reverse it if already sorted by it.
+    /// Keeps the selected scout.
+    pub fn toggle_eagle_sort(&mut self, column: EagleSortColumn) {
+        self.eagle_selection.anchor(self.eagle_keys());
+        if self.eagle_sort_column == column {
+            self.eagle_sort_ascending = !self.eagle_sort_ascending;
         } else {
-            self.event_sort_column = column;
-            self.event_sort_ascending = true;
+            self.eagle_sort_column = column;
+            self.eagle_sort_ascending = true;
         }
-        self.event_selection = 0;
+        self.eagle_selection.restore(self.eagle_keys());
     }
 
-    /// Toggle ranks tab sort to sort by name. Resets selections.
+    /// Toggle ranks tab sort to sort by name. Keeps the selected rank.
     pub fn toggle_ranks_sort_by_name(&mut self) {
+        self.ranks_selection.anchor(self.rank_list_keys());
         if !self.ranks_sort_by_count {
             self.ranks_sort_ascending = !self.ranks_sort_ascending;
         } else {
             self.ranks_sort_by_count = false;
             self.ranks_sort_ascending = true;
         }
-        self.ranks_selection = 0;
+        self.ranks_selection.restore(self.rank_list_keys());
         self.ranks_scout_selection = 0;
     }
 
-    /// Toggle ranks tab sort to sort by count. Resets selections.
+    /// Toggle ranks tab sort to sort by count. Keeps the selected rank.
     pub fn toggle_ranks_sort_by_count(&mut self) {
+        self.ranks_selection.anchor(self.rank_list_keys());
         if self.ranks_sort_by_count {
             self.ranks_sort_ascending = !self.ranks_sort_ascending;
         } else {
             self.ranks_sort_by_count = true;
             self.ranks_sort_ascending = false; // Default descending for count
         }
-        self.ranks_selection = 0;
+        self.ranks_selection.restore(self.rank_list_keys());
         self.ranks_scout_selection = 0;
     }
 
-    /// Toggle badges tab sort to sort by name. Resets selections.
+    /// Toggle badges tab sort to sort by name. Keeps the selected badge.
     pub fn toggle_badges_sort_by_name(&mut self) {
+        self.badges_selection.anchor(self.badge_list_keys());
         if !self.badges_sort_by_count {
             self.badges_sort_ascending = !self.badges_sort_ascending;
         } else {
             self.badges_sort_by_count = false;
             self.badges_sort_ascending = true;
         }
-        self.badges_selection = 0;
+        self.badges_selection.restore(self.badge_list_keys());
         self.badges_scout_selection = 0;
     }
 
-    /// Toggle badges tab sort to sort by count. Resets selections.
+    /// Toggle badges tab sort to sort by count. Keeps the selected badge.
     pub fn toggle_badges_sort_by_count(&mut self) {
+        self.badges_selection.anchor(self.badge_list_keys());
         if self.badges_sort_by_count {
             self.badges_sort_ascending = !self.badges_sort_ascending;
         } else {
             self.badges_sort_by_count = true;
             self.badges_sort_ascending = false; // Default descending for count
         }
-        self.badges_selection = 0;
+        self.badges_selection.restore(self.badge_list_keys());
         self.badges_scout_selection = 0;
     }
+
+    // =========================================================================
+    // Selection Keys
+    // =========================================================================
+
+    /// Person GUIDs of the Scouts list in display order, falling back to user ID.
+    pub fn youth_keys(&self) -> Vec<Option<String>> {
+        self.roster_rows()
+            .iter()
+            .map(|row| match row {
+                RosterRow::Patrol { name, .. } => Some(format!("patrol:{}", name)),
+                RosterRow::Scout(y) => y.person_guid.clone().or_else(|| y.user_id.map(|id| id.to_string())),
+            })
+            .collect()
+    }
+
+    /// Person GUIDs of the Adults list in display order, falling back to user ID.
+    pub fn adult_keys(&self) -> Vec<Option<String>> {
+        self.store.adults.iter().map(|a| a.person_guid.clone().or_else(|| a.user_id.map(|id| id.to_string()))).collect()
+    }
+
+    /// User IDs of the Eagle tab list in display order.
+    pub fn eagle_keys(&self) -> Vec<i64> {
+        self.eagle_rows().iter().map(|p| p.user_id).collect()
+    }
+
+    /// Event IDs of the Events list in display order.
+    pub fn event_keys(&self) -> Vec<i64> {
+        self.get_sorted_events().iter().map(|e| e.id).collect()
+    }
+
+    /// Rank names of the Ranks tab list in display order.
+    pub fn rank_list_keys(&self) -> Vec<String> {
+        crate::ui::tabs::ranks::get_rank_list(&self.store.youth, &self.store.all_youth_ranks, self.ranks_sort_by_count, self.ranks_sort_ascending)
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect()
+    }
+
+    /// Badge names of the Badges tab list in display order.
+    pub fn badge_list_keys(&self) -> Vec<String> {
+        crate::ui::tabs::badges::get_badge_list(&self.store.youth, &self.store.all_youth_badges, self.badges_sort_by_count, self.badges_sort_ascending)
+            .into_iter()
+            .map(|(name, _, _)| name)
+            .collect()
+    }
 }
 
 // ============================================================================
@@ -2152,6 +6361,7 @@ pub fn can_add_password_char(current_len: usize, c: char) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use trailcache_core::api::{DemoApi, DemoData};
 
     // -------------------------------------------------------------------------
     // ScoutRank Tests
@@ -2209,22 +6419,338 @@ mod tests {
 
     #[test]
     fn test_tab_next() {
-        assert_eq!(Tab::Scouts.next(), Tab::Ranks);
-        assert_eq!(Tab::Ranks.next(), Tab::Badges);
-        assert_eq!(Tab::Badges.next(), Tab::Events);
-        assert_eq!(Tab::Events.next(), Tab::Adults);
-        assert_eq!(Tab::Adults.next(), Tab::Unit);
-        assert_eq!(Tab::Unit.next(), Tab::Scouts); // Wraps around
+        assert_eq!(Tab::Scouts.next(0), Tab::Ranks);
+        assert_eq!(Tab::Ranks.next(0), Tab::Badges);
+        assert_eq!(Tab::Badges.next(0), Tab::Events);
+        assert_eq!(Tab::Events.next(0), Tab::Adults);
+        assert_eq!(Tab::Adults.next(0), Tab::Unit);
+        assert_eq!(Tab::Unit.next(0), Tab::Eagle);
+        assert_eq!(Tab::Eagle.next(0), Tab::Attendance);
+        assert_eq!(Tab::Attendance.next(0), Tab::Scouts); // Wraps around
     }
 
     #[test]
     fn test_tab_prev() {
-        assert_eq!(Tab::Scouts.prev(), Tab::Unit); // Wraps around
-        assert_eq!(Tab::Unit.prev(), Tab::Adults);
-        assert_eq!(Tab::Adults.prev(), Tab::Events);
-        assert_eq!(Tab::Events.prev(), Tab::Badges);
-        assert_eq!(Tab::Badges.prev(), Tab::Ranks);
-        assert_eq!(Tab::Ranks.prev(), Tab::Scouts);
+        assert_eq!(Tab::Scouts.prev(0), Tab::Attendance); // Wraps around
+        assert_eq!(Tab::Attendance.prev(0), Tab::Eagle);
+        assert_eq!(Tab::Eagle.prev(0), Tab::Unit);
+        assert_eq!(Tab::Unit.prev(0), Tab::Adults);
+        assert_eq!(Tab::Adults.prev(0), Tab::Events);
+        assert_eq!(Tab::Events.prev(0), Tab::Badges);
+        assert_eq!(Tab::Badges.prev(0), Tab::Ranks);
+        assert_eq!(Tab::Ranks.prev(0), Tab::Scouts);
+    }
+
+    // -------------------------------------------------------------------------
+    // Dataset Retry Tests
+    // -------------------------------------------------------------------------
+
+    #[test]
+    fn test_refresh_result_dataset() {
+        assert_eq!(RefreshResult::from(Update::Youth(vec![])).dataset(), Some(RefreshDataset::Youth));
+        assert_eq!(RefreshResult::from(Update::Events(vec![])).dataset(), Some(RefreshDataset::Events));
+        assert_eq!(
+            RefreshResult::from(Update::AdvancementDashboard(Default::default())).dataset(),
+            Some(RefreshDataset::Dashboard)
+        );
+        assert_eq!(RefreshResult::from(Update::YouthRanks(1, vec![])).dataset(), None);
+        assert_eq!(RefreshResult::RefreshComplete.dataset(), None);
+    }
+
+    #[test]
+    fn test_unfinished_datasets() {
+        let states = HashMap::from([
+            (RefreshDataset::Youth, LoadState::Loaded),
+            (RefreshDataset::Adults, LoadState::Loading),
+            (RefreshDataset::Events, LoadState::Failed("401".to_string())),
+            (RefreshDataset::Key3, LoadState::NotLoaded),
+        ]);
+        assert_eq!(unfinished_datasets(&states), [RefreshDataset::Adults, RefreshDataset::Events]);
+    }
+
+    // -------------------------------------------------------------------------
+    // Background Refresh Tests
+    // -------------------------------------------------------------------------
+
+    /// An app with a scratch config and cache, backed by `data` instead of Scoutbook.
+    fn mock_app(name: &str, data: DemoData) -> App {
+        let dir = std::env::temp_dir().join(format!("trailcache-app-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let mut config = Config::load_from(dir.clone()).unwrap();
+        config.set_cache_dir(dir);
+        App::with_backend(config, Backend::Demo(DemoApi::from_data(data))).unwrap()
+    }
+
+    /// Apply results as the UI loop would until the refresh finishes.
+    async fn finish_refresh(app: &mut App) {
+        for _ in 0..1000 {
+            if app.refresh_task.is_none() {
+                return;
+            }
+            app.check_background_tasks().await;
+            tokio::task::yield_now().await;
+        }
+        panic!("refresh never finished");
+    }
+
+    #[test]
+    fn test_refresh_all_background_with_mock_api() {
+        let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+        runtime.block_on(async {
+            let mut data = DemoData::bundled().unwrap();
+            data.youth.truncate(2);
+            let scout = data.youth[0].user_id.unwrap();
+            let mut app = mock_app("refresh", data);
+
+            // Nothing to refresh with before signing in
+            app.refresh_all_background().await;
+            assert!(app.refresh_task.is_none());
+
+            // Signing in to an empty cache starts a full refresh
+            app.attempt_login().await.unwrap();
+            assert!(app.refresh_task.is_some());
+            finish_refresh(&mut app).await;
+
+            assert_eq!(app.store.youth.len(), 2);
+            assert!(!app.store.events.is_empty());
+            assert!(app.store.events.iter().any(|e| !e.invited_users.is_empty()), "event details merged");
+            for dataset in RefreshDataset::ALL {
+                assert_eq!(app.load_states.get(&dataset), Some(&LoadState::Loaded), "{:?}", dataset);
+            }
+            assert!(app.store.all_youth_ranks.contains_key(&scout));
+            assert_eq!(app.cache.load_youth().unwrap().map(|c| c.data.len()), Some(2));
+        });
+    }
+
+    #[test]
+    fn test_load_states_follow_refresh() {
+        let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+        runtime.block_on(async {
+            let mut app = mock_app("load-states", DemoData::bundled().unwrap());
+            assert_eq!(app.load_state(RefreshDataset::Youth), LoadState::NotLoaded);
+
+            // Every dataset shows as loading until its data arrives
+            app.attempt_login().await.unwrap();
+            assert_eq!(app.load_state(RefreshDataset::Youth), LoadState::Loading);
+            finish_refresh(&mut app).await;
+            assert_eq!(app.load_state(RefreshDataset::Events), LoadState::Loaded);
+
+            // A failed fetch is shown until the data comes through again
+            app.process_refresh_result(RefreshResult::DatasetFailed(
+                RefreshDataset::Events,
+                ApiErrorKind::Server,
+                "500".to_string(),
+            ));
+            assert_eq!(app.load_state(RefreshDataset::Events), LoadState::Failed("500".to_string()));
+            let events = app.store.events.clone();
+            app.process_refresh_result(RefreshResult::from(Update::Events(events)));
+            assert_eq!(app.load_state(RefreshDataset::Events), LoadState::Loaded);
+        });
+    }
+
+    #[test]
+    fn test_selection_kept_across_refresh() {
+        let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+        runtime.block_on(async {
+            let mut app = mock_app("keep-selection", DemoData::bundled().unwrap());
+            app.attempt_login().await.unwrap();
+            finish_refresh(&mut app).await;
+
+            app.roster_selection.index = 2;
+            let scout = app.selected_youth().unwrap().user_id;
+            let first = app.get_sorted_youth()[0].user_id;
+            app.event_selection.index = 1;
+            let event = app.event_keys()[1];
+
+            // The refreshed lists come back in another order, one scout short
+            let mut youth = app.store.youth.clone();
+            youth.reverse();
+            youth.retain(|y| y.user_id != first);
+            let mut events = app.store.events.clone();
+            events.reverse();
+            app.process_refresh_result(RefreshResult::from(Update::Youth(youth)));
+            app.process_refresh_result(RefreshResult::from(Update::Events(events)));
+
+            assert_eq!(app.selected_youth().unwrap().user_id, scout);
+            assert_eq!(app.event_keys()[app.event_selection.index], event);
+        });
+    }
+
+    #[test]
+    fn test_patrol_picker() {
+        let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+        runtime.block_on(async {
+            let mut app = mock_app("patrol-picker", DemoData::bundled().unwrap());
+            app.attempt_login().await.unwrap();
+            finish_refresh(&mut app).await;
+            app.roster_selection.index = app.get_sorted_youth().iter().position(|y| y.patrol_name.is_some()).unwrap();
+            let youth = app.selected_youth().unwrap().clone();
+
+            // The picker opens on the scout's own patrol, and picking it changes nothing
+            app.start_patrol_move();
+            assert_eq!(app.ui.state, AppState::MovingToPatrol);
+            let pending = app.patrol_move.clone().unwrap();
+            assert_eq!(pending.user_id, youth.user_id.unwrap());
+            assert_eq!(Some(&app.patrol_choices()[pending.selection].name), youth.patrol_name.as_ref());
+            app.confirm_patrol_move();
+            assert_eq!(app.ui.state, AppState::Normal);
+            assert_eq!(app.status_message, Some(format!("{} is already in {}", youth.short_name(), youth.patrol_name.clone().unwrap())));
+
+            // Moves need Scoutbook
+            app.offline_mode = true;
+            app.start_patrol_move();
+            assert!(app.patrol_move.is_none());
+            assert_eq!(app.ui.state, AppState::Normal);
+        });
+    }
+
+    #[test]
+    fn test_patrol_move_saved_or_rolled_back() {
+        let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+        runtime.block_on(async {
+            let mut app = mock_app("patrol-move", DemoData::bundled().unwrap());
+            app.attempt_login().await.unwrap();
+            finish_refresh(&mut app).await;
+
+            let youth = app.store.youth[0].clone();
+            let user_id = youth.user_id.unwrap();
+            let patrol_of =
+                |app: &App| app.store.youth.iter().find(|y| y.user_id == Some(user_id)).unwrap().patrol_name.clone();
+            let move_to = |app: &mut App, patrol: &str| {
+                let selection = app.patrol_choices().iter().position(|p| p.name == patrol).unwrap();
+                app.patrol_move = Some(PatrolMove {
+                    user_id,
+                    scout: youth.short_name(),
+                    current: patrol_of(app),
+                    selection,
+                });
+                app.confirm_patrol_move();
+            };
+            async fn settle(app: &mut App) {
+                for _ in 0..1000 {
+                    if app.status_message.as_deref().is_some_and(|m| !m.starts_with("Moving")) {
+                        return;
+                    }
+                    app.check_background_tasks().await;
+                    tokio::task::yield_now().await;
+                }
+                panic!("patrol move never finished");
+            }
+
+            // Scoutbook takes the move, and the roster shows it straight away
+            let choices = app.patrol_choices();
+            let other = choices.iter().find(|p| Some(&p.name) != youth.patrol_name.as_ref()).unwrap().name.clone();
+            move_to(&mut app, &other);
+            assert_eq!(patrol_of(&app).as_ref(), Some(&other));
+            settle(&mut app).await;
+            assert_eq!(app.status_message, Some(format!("Moved {} to {}", app.youth_name(user_id), other)));
+            assert_eq!(patrol_of(&app).as_ref(), Some(&other));
+
+            // A patrol Scoutbook doesn't know refuses the move, which is put back
+            let ghost: Patrol = serde_json::from_value(serde_json::json!({
+                "subUnitGuid": "NOWHERE", "subUnitName": "Ghost Patrol"
+            }))
+            .unwrap();
+            app.store.patrols.push(ghost);
+            move_to(&mut app, "Ghost Patrol");
+            assert_eq!(patrol_of(&app).as_deref(), Some("Ghost Patrol"));
+            settle(&mut app).await;
+            assert!(app.status_message.as_deref().unwrap().starts_with("Could not move"));
+            assert_eq!(patrol_of(&app).as_ref(), Some(&other));
+        });
+    }
+
+    #[test]
+    fn test_grouped_roster_rows() {
+        let mut app = mock_app("grouped-roster", DemoData::default());
+        app.store.youth = serde_json::from_value(serde_json::json!([
+            { "userId": 1, "firstName": "Zoe", "lastName": "Young", "subUnitName": "Wolves" },
+            { "userId": 2, "firstName": "Alex", "lastName": "Adams" },
+            { "userId": 3, "firstName": "Ben", "lastName": "Brown", "subUnitName": "Eagles" },
+            { "userId": 4, "firstName": "Cal", "lastName": "Cole", "subUnitName": "" },
+            { "userId": 5, "firstName": "Dana", "lastName": "Diaz", "subUnitName": "Wolves" }
+        ]))
+        .unwrap();
+        let rows = |app: &App| -> Vec<String> {
+            app.roster_rows()
+                .iter()
+                .map(|row| match row {
+                    RosterRow::Patrol { name, members, collapsed } => format!("{} ({}){}", name, members, if *collapsed { "+" } else { "" }),
+                    RosterRow::Scout(y) => y.first_name.clone(),
+                })
+                .collect()
+        };
+        assert_eq!(rows(&app), ["Alex", "Ben", "Cal", "Dana", "Zoe"]);
+
+        // Headings in name order, with scouts missing a patrol (or with a
+        // blank one) together under No Patrol, each group sorted by name
+        app.roster_selection.index = 4;
+        app.toggle_roster_grouping();
+        assert_eq!(rows(&app), ["Eagles (1)", "Ben", "No Patrol (2)", "Alex", "Cal", "Wolves (2)", "Dana", "Zoe"]);
+        assert_eq!(app.selected_youth().map(|y| y.first_name.as_str()), Some("Zoe"));
+
+        app.roster_selection.index = 2;
+        assert!(app.toggle_selected_patrol());
+        assert_eq!(rows(&app), ["Eagles (1)", "Ben", "No Patrol (2)+", "Wolves (2)", "Dana", "Zoe"]);
+        app.roster_selection.index = 4;
+        assert!(!app.toggle_selected_patrol());
+    }
+
+    #[test]
+    fn test_palette_ranks_and_jumps_to_hits() {
+        let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+        runtime.block_on(async {
+            let mut app = mock_app("palette", DemoData::bundled().unwrap());
+            app.attempt_login().await.unwrap();
+            finish_refresh(&mut app).await;
+            let search = |app: &mut App, query: &str| {
+                app.show_palette();
+                query.chars().for_each(|c| app.palette_input(c));
+                app.palette_hits()
+            };
+
+            // Nothing is listed until something is typed
+            assert!(search(&mut app, "").is_empty());
+            assert!(search(&mut app, "   ").is_empty());
+
+            // A full name puts that person first, and every kind is searched
+            let scout = app.store.youth[3].clone();
+            let hits = search(&mut app, &scout.short_name());
+            assert_eq!(hits[0].target, PaletteTarget::Scout(scout.user_id.unwrap()));
+            assert!(hits.windows(2).all(|w| w[0].score >= w[1].score), "best match first");
+            let adult = app.store.adults[0].clone();
+            assert_eq!(search(&mut app, &adult.full_name())[0].target, PaletteTarget::Adult(adult.user_id.unwrap()));
+            let badge = app.badge_list_keys()[0].clone();
+            assert_eq!(search(&mut app, &badge)[0].target, PaletteTarget::Badge(badge.clone()));
+            let rank = app.rank_list_keys()[0].clone();
+            assert!(search(&mut app, &rank).iter().any(|h| h.target == PaletteTarget::Rank(rank.clone())));
+            let event = app.store.events[0].clone();
+            let hits = search(&mut app, &event.name);
+            assert!(matches!(hits[0].target, PaletteTarget::Event { id, .. } if id == event.id));
+
+            // Choosing a match opens it on its tab
+            search(&mut app, &scout.short_name());
+            app.open_palette_hit();
+            assert_eq!(app.ui.state, AppState::Normal);
+            assert_eq!(app.ui.current_tab, Tab::Scouts);
+            assert_eq!(app.selected_youth().and_then(|y| y.user_id), scout.user_id);
+
+            search(&mut app, &event.name);
+            app.open_palette_hit();
+            assert_eq!(app.ui.current_tab, Tab::Events);
+            assert_eq!(app.event_keys()[app.event_selection.index], event.id);
+
+            let hits = search(&mut app, &badge);
+            app.palette_selection = hits.iter().position(|h| h.target == PaletteTarget::Badge(badge.clone())).unwrap();
+            app.open_palette_hit();
+            assert_eq!(app.ui.current_tab, Tab::Badges);
+            assert_eq!(app.badge_list_keys()[app.badges_selection.index], badge);
+
+            // With nothing matching, Enter leaves the palette open
+            search(&mut app, "zzzzqqqq");
+            app.open_palette_hit();
+            assert_eq!(app.ui.state, AppState::GlobalSearch);
+        });
     }
 
     // -------------------------------------------------------------------------
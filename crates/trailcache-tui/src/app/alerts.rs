@@ -0,0 +1,196 @@
+//! Things a leader should hear about when the app opens: birthdays this
+//! month, memberships and Youth Protection training about to lapse, and
+//! scouts whose advancement has stalled.
+//!
+//! Alerts are worked out from the cached roster each time. Dismissing one
+//! records its ID, which names the person, the kind, and the date, so next
+//! year's birthday, a renewed membership's new expiry, or a stalled scout's
+//! next stall alerts again.
+
+use chrono::{Datelike, NaiveDate};
+
+use trailcache_core::models::{Adult, ScoutPace, Youth, PACE_WINDOW_MONTHS};
+
+/// Memberships expiring within this many days are alerted.
+pub const MEMBERSHIP_ALERT_DAYS: i64 = 30;
+/// Youth Protection training expiring within this many days is alerted.
+pub const YPT_ALERT_DAYS: i64 = 60;
+
+/// Listed in this order, most pressing first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AlertKind {
+    YouthProtection,
+    Membership,
+    Pace,
+    Birthday,
+}
+
+impl AlertKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            AlertKind::YouthProtection => "YPT",
+            AlertKind::Membership => "Membership",
+            AlertKind::Pace => "Pace",
+            AlertKind::Birthday => "Birthday",
+        }
+    }
+
+    fn key(&self) -> &'static str {
+        match self {
+            AlertKind::YouthProtection => "ypt",
+            AlertKind::Membership => "membership",
+            AlertKind::Pace => "pace",
+            AlertKind::Birthday => "birthday",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Alert {
+    pub kind: AlertKind,
+    pub name: String,
+    /// The birthday this year, the expiry date, or a stalled scout's last
+    /// progress
+    pub date: NaiveDate,
+    /// Age on the birthday, for scouts
+    pub turning: Option<i32>,
+    /// Completions over the pace window, for stalled scouts
+    pub recent: Option<usize>,
+    /// Stable across sessions, for remembering dismissals
+    pub id: String,
+}
+
+impl Alert {
+    fn new(kind: AlertKind, person: String, name: String, date: NaiveDate, turning: Option<i32>) -> Self {
+        let id = format!("{}:{}:{}", kind.key(), person, date);
+        Self { kind, name, date, turning, recent: None, id }
+    }
+
+    /// One-line description, e.g. "Sam Ortiz turns 14 on Oct 22".
+    pub fn describe(&self, today: NaiveDate) -> String {
+        let when = self.date.format("%b %-d");
+        match self.kind {
+            AlertKind::Birthday => match self.turning {
+                Some(age) => format!("{} turns {} on {}", self.name, age, when),
+                None => format!("{}'s birthday is {}", self.name, when),
+            },
+            AlertKind::Pace => {
+                let per_month = self.recent.unwrap_or(0) as f64 / PACE_WINDOW_MONTHS as f64;
+                format!("{}: no recorded advancement since {} ({:.1} a month this year)", self.name, when, per_month)
+            }
+            AlertKind::Membership | AlertKind::YouthProtection => {
+                let what = if self.kind == AlertKind::Membership { "membership" } else { "YPT" };
+                let days = (self.date - today).num_days();
+                match days {
+                    d if d < 0 => format!("{}'s {} expired {}", self.name, what, when),
+                    0 => format!("{}'s {} expires today", self.name, what),
+                    1 => format!("{}'s {} expires tomorrow", self.name, what),
+                    d => format!("{}'s {} expires in {} days ({})", self.name, what, d, when),
+                }
+            }
+        }
+    }
+}
+
+/// This year's birthday, with Feb 29 falling on Feb 28 in other years.
+fn birthday_in(dob: NaiveDate, year: i32) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(year, dob.month(), dob.day())
+        .or_else(|| NaiveDate::from_ymd_opt(year, dob.month(), dob.day() - 1))
+}
+
+fn parse_date(date: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(date.get(..10).unwrap_or(date), "%Y-%m-%d").ok()
+}
+
+/// Every alert due for the roster as of `today`, most pressing first.
+/// Lapsed memberships and training stay listed until renewed; `stalled` is
+/// from `stalled_scouts`.
+pub fn scan(youth: &[Youth], adults: &[Adult], stalled: &[ScoutPace], today: NaiveDate) -> Vec<Alert> {
+    let mut alerts = Vec::new();
+    let birthday = |dob: NaiveDate| birthday_in(dob, today.year()).filter(|d| d.month() == today.month());
+    let within = |date: NaiveDate, days: i64| (date - today).num_days() <= days;
+
+    for y in youth {
+        let person = format!("youth-{}", y.get_user_id());
+        if let Some((dob, day)) = y.date_of_birth().and_then(|dob| birthday(dob).map(|d| (dob, d))) {
+            alerts.push(Alert::new(AlertKind::Birthday, person.clone(), y.full_name(), day, Some(day.year() - dob.year())));
+        }
+        if let Some(date) = y.registration_expires().as_deref().and_then(parse_date) {
+            if within(date, MEMBERSHIP_ALERT_DAYS) {
+                alerts.push(Alert::new(AlertKind::Membership, person, y.full_name(), date, None));
+            }
+        }
+    }
+    for pace in stalled {
+        if let Some(last) = pace.last_progress {
+            let alert = Alert::new(AlertKind::Pace, format!("youth-{}", pace.user_id), pace.name.clone(), last, None);
+            alerts.push(Alert { recent: Some(pace.recent), ..alert });
+        }
+    }
+    for a in adults {
+        let person = format!("adult-{}", a.get_user_id());
+        if let Some(day) = a.date_of_birth().and_then(birthday) {
+            alerts.push(Alert::new(AlertKind::Birthday, person.clone(), a.full_name(), day, None));
+        }
+        if let Some(date) = a.registration_expires_on().filter(|&d| within(d, MEMBERSHIP_ALERT_DAYS)) {
+            alerts.push(Alert::new(AlertKind::Membership, person.clone(), a.full_name(), date, None));
+        }
+        if let Some(date) = a.ypt_expires().filter(|&d| within(d, YPT_ALERT_DAYS)) {
+            alerts.push(Alert::new(AlertKind::YouthProtection, person, a.full_name(), date, None));
+        }
+    }
+
+    alerts.sort_by(|a, b| (a.kind, a.date, &a.name).cmp(&(b.kind, b.date, &b.name)));
+    alerts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn person<T: serde::de::DeserializeOwned>(user_id: i64, first: &str, extra: serde_json::Value) -> T {
+        let mut value = serde_json::json!({ "userId": user_id, "firstName": first, "lastName": "Ortiz" });
+        value.as_object_mut().unwrap().extend(extra.as_object().unwrap().clone());
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_scan_birthdays_and_expiries() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 15).unwrap();
+        let youth: Vec<Youth> = vec![
+            person(1, "Sam", serde_json::json!({ "registrarInfo": {
+                "dateOfBirth": "2012-10-22", "registrationExpireDt": "2026-11-01T00:00:00" } })),
+            person(2, "Ana", serde_json::json!({ "registrarInfo": {
+                "dateOfBirth": "2012-11-02", "registrationExpireDt": "2027-06-30" } })),
+        ];
+        let adults: Vec<Adult> = vec![
+            person(3, "Pat", serde_json::json!({ "yptExpiredDate": "2026-12-01",
+                "registrarInfo": { "dateOfBirth": "1980-10-01" } })),
+            person(4, "Lee", serde_json::json!({ "yptExpiredDate": "2027-12-01" })),
+        ];
+
+        let stalled = vec![ScoutPace { user_id: 2, name: "Ana Ortiz".into(), recent: 6, last_progress: NaiveDate::from_ymd_opt(2026, 6, 3) }];
+        let alerts = scan(&youth, &adults, &stalled, today);
+        let kinds: Vec<(AlertKind, &str)> = alerts.iter().map(|a| (a.kind, a.name.as_str())).collect();
+        assert_eq!(kinds, [
+            (AlertKind::YouthProtection, "Pat Ortiz"),
+            (AlertKind::Membership, "Sam Ortiz"),
+            (AlertKind::Pace, "Ana Ortiz"),
+            (AlertKind::Birthday, "Pat Ortiz"),
+            (AlertKind::Birthday, "Sam Ortiz"),
+        ]);
+        assert_eq!(alerts[4].describe(today), "Sam Ortiz turns 14 on Oct 22");
+        assert_eq!(alerts[2].describe(today), "Ana Ortiz: no recorded advancement since Jun 3 (0.5 a month this year)");
+        assert_eq!(alerts[2].id, "pace:youth-2:2026-06-03");
+        assert_eq!(alerts[1].describe(today), "Sam Ortiz's membership expires in 17 days (Nov 1)");
+        assert_eq!(alerts[1].id, "membership:youth-1:2026-11-01");
+
+        // A renewal moves the date, so the old dismissal no longer matches
+        let renewed: Vec<Youth> = vec![person(1, "Sam", serde_json::json!({ "registrarInfo": {
+            "registrationExpireDt": "2026-11-10" } }))];
+        assert_ne!(scan(&renewed, &[], &[], today)[0].id, alerts[1].id);
+
+        let leap = NaiveDate::from_ymd_opt(2012, 2, 29).unwrap();
+        assert_eq!(birthday_in(leap, 2027), NaiveDate::from_ymd_opt(2027, 2, 28));
+    }
+}
@@ -0,0 +1,320 @@
+//! State for the tools opened from overlays: each one's saved data, kept
+//! with its selection and whatever is being typed into it, so `App` holds
+//! one field per tool.
+
+use std::collections::{HashMap, HashSet};
+
+use chrono::{Local, NaiveDate};
+
+use trailcache_core::cache::CacheManager;
+use trailcache_core::models::{
+    ActivityFeed, ActivityPlans, AdultAwardChecklists, AdultSkills, BuddyGroups, Certification, Clinic, CommitteeMinutes,
+    CommitteeRoles, DenChiefLinks, EagleProjects, Event, EventTemplates, FeedCategory, HighAdventure,
+    HistorianLog, LeadershipPosition, Library, LinkedData, MedicationLog, Nominations, Onboarding, PositionPlan,
+    SkillMatrix, TodoList, TodoSubject, TroopFilter, UniformCloset, UnitHealth,
+};
+use trailcache_core::reports::CounselorGroup;
+
+use super::alerts::Alert;
+use super::{
+    ClinicField, DenChiefField, HistoryField, LinkedList, LoginFocus, MedicationField, MinutesInput, ProjectField,
+    TemplateInput, TrekField,
+};
+
+/// The login form.
+pub struct LoginForm {
+    pub username: String,
+    pub password: String,
+    pub focus: LoginFocus,
+    pub error: Option<String>,
+}
+
+impl LoginForm {
+    pub fn new(username: String, password: String) -> Self {
+        Self { username, password, focus: LoginFocus::Username, error: None }
+    }
+}
+
+/// Activity feed of changes seen across refreshes, with the Feed panel's
+/// filters.
+#[derive(Default)]
+pub struct FeedState {
+    pub entries: ActivityFeed,
+    pub category: Option<FeedCategory>,
+    pub scout_only: bool,
+    pub scroll: usize,
+}
+
+/// Startup alerts not yet dismissed, the selected one, and whether the
+/// panel has opened on its own this session.
+#[derive(Default)]
+pub struct AlertsState {
+    pub list: Vec<Alert>,
+    pub selection: usize,
+    pub opened: bool,
+}
+
+/// Locally tracked award nominations, and the awards catalog selection.
+#[derive(Default)]
+pub struct NominationsState {
+    pub list: Nominations,
+    pub catalog_selection: usize,
+}
+
+/// Skill certification matrix: selected scout row and certification column,
+/// holder filter, and the card number being typed.
+#[derive(Default)]
+pub struct SkillsState {
+    pub matrix: SkillMatrix,
+    pub selection: usize,
+    pub column: usize,
+    pub filter: Option<Certification>,
+    pub card_input: Option<String>,
+}
+
+/// Saved event templates, the selected one, and any text being typed.
+#[derive(Default)]
+pub struct TemplatesState {
+    pub templates: EventTemplates,
+    pub selection: usize,
+    pub input: Option<(TemplateInput, String)>,
+}
+
+/// To-do list: the selected row, whether done items are shown, what new
+/// items attach to, and the item being typed.
+pub struct TodosState {
+    pub list: TodoList,
+    pub selection: usize,
+    pub show_done: bool,
+    pub subject: TodoSubject,
+    pub input: Option<String>,
+}
+
+impl Default for TodosState {
+    fn default() -> Self {
+        Self { list: TodoList::default(), selection: 0, show_done: false, subject: TodoSubject::Unit, input: None }
+    }
+}
+
+/// Troop library: the selected title, the scout lending acts on, and a
+/// title being typed.
+#[derive(Default)]
+pub struct LibraryState {
+    pub titles: Library,
+    pub selection: usize,
+    pub borrower: Option<i64>,
+    pub input: Option<String>,
+}
+
+/// Uniform closet: the selected item, the scout checkouts go to, an item or
+/// filter being typed, and whether items that are out are listed.
+#[derive(Default)]
+pub struct ClosetState {
+    pub items: UniformCloset,
+    pub selection: usize,
+    pub borrower: Option<i64>,
+    pub input: Option<String>,
+    pub filter: String,
+    pub editing_filter: bool,
+    pub show_out: bool,
+}
+
+/// Adult skills directory: the selected skill, the adult new skills go to,
+/// and a skill or filter being typed.
+#[derive(Default)]
+pub struct AdultSkillsState {
+    pub directory: AdultSkills,
+    pub selection: usize,
+    pub adult: Option<i64>,
+    pub input: Option<String>,
+    pub filter: String,
+    pub editing_filter: bool,
+}
+
+/// High-adventure screener: the trek plan and physical checks, the selected
+/// person, and a date being typed.
+#[derive(Default)]
+pub struct TrekState {
+    pub plan: HighAdventure,
+    pub selection: usize,
+    pub input: Option<(TrekField, String)>,
+}
+
+/// Eagle projects: the candidate whose project is open, the selected
+/// paperwork item, and any text being typed.
+#[derive(Default)]
+pub struct EagleProjectsState {
+    pub projects: EagleProjects,
+    pub user: Option<i64>,
+    pub selection: usize,
+    pub input: Option<(ProjectField, String)>,
+}
+
+/// Merit badge clinic: the selected class or registered scout, the scout
+/// registrations go to, and any text being typed.
+#[derive(Default)]
+pub struct ClinicState {
+    pub plan: Clinic,
+    pub selection: usize,
+    pub scout: Option<i64>,
+    pub input: Option<(ClinicField, String)>,
+}
+
+/// District mode: a cache for each assigned unit (opened at login), their
+/// summaries in dashboard order, the selected unit, and whether its details
+/// are open.
+#[derive(Default)]
+pub struct DistrictState {
+    pub caches: HashMap<String, CacheManager>,
+    pub units: Vec<UnitHealth>,
+    pub selection: usize,
+    pub detail: bool,
+}
+
+/// Linked troop: its cache (opened at login) and rosters, and the linked
+/// view's list, troop filter, layout, and scroll position.
+pub struct LinkedState {
+    pub cache: Option<CacheManager>,
+    pub data: LinkedData,
+    pub list: LinkedList,
+    pub filter: TroopFilter,
+    pub side_by_side: bool,
+    pub scroll: usize,
+}
+
+impl Default for LinkedState {
+    fn default() -> Self {
+        Self {
+            cache: None,
+            data: LinkedData::default(),
+            list: LinkedList::Scouts,
+            filter: TroopFilter::Both,
+            side_by_side: false,
+            scroll: 0,
+        }
+    }
+}
+
+/// Committee meeting minutes: the meeting being edited, its selected action
+/// item, and any text being typed.
+#[derive(Default)]
+pub struct MinutesState {
+    pub meetings: CommitteeMinutes,
+    pub selection: usize,
+    pub item_selection: usize,
+    pub input: Option<(MinutesInput, String)>,
+}
+
+/// Historian's event log, the selected entry, and any text being typed.
+#[derive(Default)]
+pub struct HistoryState {
+    pub log: HistorianLog,
+    pub selection: usize,
+    pub input: Option<(HistoryField, String)>,
+}
+
+/// Counselor report groups, built when the view is opened, with the
+/// selected counselor and scroll offset.
+#[derive(Default)]
+pub struct CounselorsState {
+    pub groups: Vec<CounselorGroup>,
+    pub selection: usize,
+    pub scroll: usize,
+}
+
+/// Next term's planned youth positions, cached leadership history for the
+/// eligibility checks, and the selected scout and position.
+#[derive(Default)]
+pub struct PlannerState {
+    pub plan: PositionPlan,
+    pub leadership: HashMap<i64, Vec<LeadershipPosition>>,
+    pub selection: usize,
+    pub position: usize,
+}
+
+/// Den chief links to partner packs, and the link being entered: the field
+/// being typed and the pack and den entered so far.
+#[derive(Default)]
+pub struct DenChiefsState {
+    pub links: DenChiefLinks,
+    pub input: Option<(DenChiefField, String)>,
+    pub draft: (String, String),
+}
+
+/// Buddy groups for the event they were drawn up for, the selected row, and
+/// a scout marked to swap with the next one picked.
+#[derive(Default)]
+pub struct BuddiesState {
+    pub groups: BuddyGroups,
+    pub event: Option<(i64, String)>,
+    pub selection: usize,
+    pub marked: Option<i64>,
+}
+
+/// Pre-activity checklists, the event whose checklist is open, and the
+/// selected item.
+#[derive(Default)]
+pub struct ActivityPlansState {
+    pub plans: ActivityPlans,
+    pub event: Option<Event>,
+    pub selection: usize,
+}
+
+/// Medication log, loaded only once access is confirmed this session; the
+/// scout doses are recorded for, the selected row, and the dose being
+/// entered: the field being typed and the medication and dose so far.
+#[derive(Default)]
+pub struct MedicationsState {
+    pub log: MedicationLog,
+    pub unlocked: bool,
+    pub scout: Option<(i64, String)>,
+    pub selection: usize,
+    pub input: Option<(MedicationField, String)>,
+    pub draft: (String, String),
+}
+
+/// Adult leader award checklists, and the selected checklist row.
+#[derive(Default)]
+pub struct AdultAwardsState {
+    pub checklists: AdultAwardChecklists,
+    pub selection: usize,
+}
+
+/// New leader onboarding checklists and the selected item.
+#[derive(Default)]
+pub struct OnboardingState {
+    pub checklists: Onboarding,
+    pub selection: usize,
+}
+
+/// Committee role overrides, the selected matrix cell (adult row, role
+/// column), and whether every adult is listed or just the committee.
+#[derive(Default)]
+pub struct CommitteeState {
+    pub roles: CommitteeRoles,
+    pub row: usize,
+    pub column: usize,
+    pub all_adults: bool,
+}
+
+/// The in-progress meeting-night attendance sheet.
+pub struct MeetingSheet {
+    pub date: NaiveDate,
+    pub present: HashSet<i64>,
+    pub selection: usize,
+}
+
+impl Default for MeetingSheet {
+    fn default() -> Self {
+        Self { date: Local::now().date_naive(), present: HashSet::new(), selection: 0 }
+    }
+}
+
+/// Offline caching progress.
+#[derive(Default)]
+pub struct CachingProgress {
+    pub in_progress: bool,
+    pub current: usize,
+    pub total: usize,
+    pub description: String,
+}
@@ -0,0 +1,58 @@
+//! `trailcache backup <file>` and `trailcache restore <file>`: save and
+//! restore locally authored data (attendance, nominations, consent forms,
+//! minutes, and so on). Data fetched from Scoutbook isn't included; it comes
+//! back with the next refresh. The medication log is only included when
+//! `--include-medical` is given.
+
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use chrono::Local;
+
+use trailcache_core::cache::BackupArchive;
+
+use crate::export::open_cache;
+
+pub fn run_backup(args: &[String]) -> Result<()> {
+    let (file, include_medical) = match args {
+        [file] => (file, false),
+        [file, flag] | [flag, file] if flag == "--include-medical" => (file, true),
+        _ => bail!("Usage: trailcache backup <file> [--include-medical]"),
+    };
+    let (_, cache) = open_cache()?;
+    let archive = cache.create_backup(env!("CARGO_PKG_VERSION"), include_medical)?;
+    if archive.sections.is_empty() {
+        bail!("No local data to back up (or the password doesn't match the cache)");
+    }
+    let path = Path::new(file);
+    std::fs::write(path, serde_json::to_string_pretty(&archive)?)
+        .with_context(|| format!("Writing {}", path.display()))?;
+    eprintln!("Backed up {} to {}", archive.sections.join(", "), path.display());
+    Ok(())
+}
+
+pub fn run_restore(args: &[String]) -> Result<()> {
+    let [file] = args else {
+        bail!("Usage: trailcache restore <file>");
+    };
+    let path = Path::new(file);
+    let json = std::fs::read_to_string(path).with_context(|| format!("Reading {}", path.display()))?;
+    let archive = BackupArchive::parse(&json)?;
+
+    let created = archive.created_at.with_timezone(&Local).format("%b %-d, %Y %H:%M");
+    eprintln!("Backup from {} (trailcache {}): {}", created, archive.app_version, archive.sections.join(", "));
+    eprint!("Replace local data with these sections? [y/N] ");
+    io::stderr().flush()?;
+    let mut answer = String::new();
+    io::stdin().lock().read_line(&mut answer)?;
+    if !answer.trim().eq_ignore_ascii_case("y") {
+        eprintln!("Restore cancelled");
+        return Ok(());
+    }
+
+    let (_, cache) = open_cache()?;
+    let restored = cache.restore_backup(&archive)?;
+    eprintln!("Restored {} sections", restored.len());
+    Ok(())
+}
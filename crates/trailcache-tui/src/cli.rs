@@ -0,0 +1,95 @@
+//! Command-line subcommands. With no arguments trailcache starts the TUI;
+//! otherwise the first argument names a command that runs without it. The
+//! older `--flag` spellings are still accepted.
+
+use anyhow::{bail, Result};
+
+pub const USAGE: &str = "Usage: trailcache [command]
+
+Commands:
+  report roster|advancement|expiring|grades
+                                       Print a report as CSV or JSON, from the cache or --live
+  report custom <template>             Render a report template
+  export site|roster|rosters|signoff|ical|court-of-honor ...
+                                       Write exports from the cache
+  backup <file> / restore <file>       Save or restore locally kept records
+  district ...                         Manage the units a commissioner serves
+  retention                            Show the departed-scout retention policy
+  digest [--send]                      Print the weekly digest, or email it to the configured recipients
+  dump-requirements                    Print every merit badge's requirements as JSON
+  demo                                 Start the terminal UI with a made-up troop, no account needed
+  help                                 Show this message
+
+Run a command without arguments for its options. With no command,
+trailcache starts the terminal UI.";
+
+/// What to run, borrowing the rest of the arguments.
+#[derive(Debug, PartialEq)]
+pub enum Command<'a> {
+    Tui,
+    /// The TUI against the bundled demo troop
+    Demo,
+    Help,
+    DumpRequirements,
+    TestVersions,
+    Report(&'a [String]),
+    Export(&'a [String]),
+    Backup(&'a [String]),
+    Restore(&'a [String]),
+    District(&'a [String]),
+    Retention(&'a [String]),
+    Digest(&'a [String]),
+    /// `--export-roster <file.csv>`
+    ExportRoster(&'a [String]),
+    /// `--export-ical <file.ics>`
+    ExportIcal(&'a [String]),
+    /// `--my-scout [name]`
+    MyScout(Option<&'a str>),
+}
+
+impl<'a> Command<'a> {
+    /// Parse the arguments after the program name.
+    pub fn parse(args: &'a [String]) -> Result<Self> {
+        let Some((first, rest)) = args.split_first() else {
+            return Ok(Command::Tui);
+        };
+        Ok(match first.as_str() {
+            "help" | "--help" | "-h" => Command::Help,
+            "demo" | "--demo" => Command::Demo,
+            "dump-requirements" | "--dump-requirements" => Command::DumpRequirements,
+            "test-versions" | "--test-versions" => Command::TestVersions,
+            "report" => Command::Report(rest),
+            "export" => Command::Export(rest),
+            "backup" => Command::Backup(rest),
+            "restore" => Command::Restore(rest),
+            "district" => Command::District(rest),
+            "retention" => Command::Retention(rest),
+            "digest" => Command::Digest(rest),
+            "--export-roster" => Command::ExportRoster(rest),
+            "--export-ical" => Command::ExportIcal(rest),
+            "--my-scout" => Command::MyScout(rest.first().map(String::as_str)),
+            other => bail!("Unknown command \"{}\"\n\n{}", other, USAGE),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_commands_and_legacy_flags() {
+        assert_eq!(Command::parse(&[]).unwrap(), Command::Tui);
+        let report = args(&["report", "roster", "--format", "json"]);
+        assert_eq!(Command::parse(&report).unwrap(), Command::Report(&report[1..]));
+        assert_eq!(Command::parse(&args(&["--dump-requirements"])).unwrap(), Command::DumpRequirements);
+        assert_eq!(Command::parse(&args(&["dump-requirements"])).unwrap(), Command::DumpRequirements);
+        assert_eq!(Command::parse(&args(&["--demo"])).unwrap(), Command::Demo);
+        assert_eq!(Command::parse(&args(&["--my-scout", "Sam"])).unwrap(), Command::MyScout(Some("Sam")));
+        assert!(Command::parse(&args(&["reprot"])).is_err());
+    }
+}
@@ -0,0 +1,69 @@
+//! `trailcache digest`: the weekly summary of upcoming events, awards ready
+//! to present, stalled advancement, and expiring renewals, printed or emailed to the recipients
+//! in the config's `email` section. Meant to run from a weekly scheduled job.
+
+use anyhow::{anyhow, bail, Result};
+use chrono::Local;
+
+use trailcache_core::integrations::{email, Message, SMTP_PASSWORD_ENV};
+use trailcache_core::models::stalled_scouts;
+use trailcache_core::reports::{digest_subject, digest_text, DigestInput};
+
+use crate::export::{cached_youth, open_store};
+
+const USAGE: &str = "Usage: trailcache digest [--send]
+
+Prints this week's digest from the cache. With --send it's emailed to the
+recipients in the config's \"email\" section; the SMTP password is read
+from TRAILCACHE_SMTP_PASSWORD, or asked for.";
+
+pub async fn run(args: &[String]) -> Result<()> {
+    let send = match args {
+        [] => false,
+        [flag] if flag == "--send" => true,
+        _ => bail!(USAGE),
+    };
+    let (config, cache, store) = open_store()?;
+    let youth = cached_youth(&store)?;
+    let feed = cache.load_activity_feed()?.map(|c| c.data).unwrap_or_default();
+
+    let today = Local::now().date_naive();
+    let stalled = stalled_scouts(youth, &store.all_youth_ranks, &store.all_youth_badges, &feed, config.pace, today);
+    let input = DigestInput {
+        unit_name: config.unit_name.as_deref().unwrap_or("Unit"),
+        youth,
+        adults: &store.adults,
+        events: &store.events,
+        ready_to_award: &store.ready_to_award,
+        stalled: &stalled,
+    };
+    let body = digest_text(&input, today);
+    if !send {
+        print!("{}", body);
+        return Ok(());
+    }
+
+    let settings = config
+        .email
+        .as_ref()
+        .ok_or_else(|| anyhow!("No \"email\" section in the config. Add smtp_host, from, and recipients to send digests."))?;
+    if settings.recipients.is_empty() {
+        bail!("No recipients in the config's \"email\" section.");
+    }
+    let password = match &settings.username {
+        Some(_) => Some(match std::env::var(SMTP_PASSWORD_ENV) {
+            Ok(password) => password,
+            Err(_) => rpassword::prompt_password("SMTP password: ")?,
+        }),
+        None => None,
+    };
+    let message = Message {
+        from: settings.from.clone(),
+        to: settings.recipients.clone(),
+        subject: digest_subject(&input, today),
+        body,
+    };
+    email::send(settings, password.as_deref(), &message).await?;
+    println!("Digest sent to {}", settings.recipients.join(", "));
+    Ok(())
+}
@@ -0,0 +1,106 @@
+//! `trailcache district`: manage a commissioner's list of units and print
+//! the district dashboard from their caches. Units are fetched from the
+//! dashboard in the TUI (J, then u).
+
+use anyhow::{anyhow, bail, Result};
+use chrono::{Local, NaiveDate};
+
+use trailcache_core::cache::CacheManager;
+use trailcache_core::config::Config;
+use trailcache_core::models::{sort_by_concerns, DistrictUnit, UnitHealth};
+
+use crate::export::cache_password;
+
+const USAGE: &str = "Usage: trailcache district [list]
+       trailcache district add <org-guid> [name]
+       trailcache district remove <org-guid>
+       trailcache district roundtable <org-guid> [YYYY-MM-DD]";
+
+pub fn run(args: &[String]) -> Result<()> {
+    let mut config = Config::load()?;
+    match args {
+        [] => list(&config),
+        [cmd] if cmd == "list" => list(&config),
+        [cmd, guid, name @ ..] if cmd == "add" => {
+            if config.district_units.iter().any(|u| u.organization_guid.eq_ignore_ascii_case(guid)) {
+                bail!("{} is already in the district list", guid);
+            }
+            let name = (!name.is_empty()).then(|| name.join(" "));
+            config.district_units.push(DistrictUnit::new(guid.clone(), name));
+            config.save()?;
+            println!("Added {}. Log in to trailcache and press J, then u, to fetch it.", guid);
+            Ok(())
+        }
+        [cmd, guid] if cmd == "remove" => {
+            let before = config.district_units.len();
+            config.district_units.retain(|u| !u.organization_guid.eq_ignore_ascii_case(guid));
+            if config.district_units.len() == before {
+                bail!("{} isn't in the district list", guid);
+            }
+            config.save()?;
+            println!("Removed {}", guid);
+            Ok(())
+        }
+        [cmd, guid, date @ ..] if cmd == "roundtable" && date.len() <= 1 => {
+            let date = match date.first() {
+                Some(d) => NaiveDate::parse_from_str(d, "%Y-%m-%d").map_err(|_| anyhow!("Dates look like 2026-03-05"))?,
+                None => Local::now().date_naive(),
+            };
+            let unit = config
+                .district_units
+                .iter_mut()
+                .find(|u| u.organization_guid.eq_ignore_ascii_case(guid))
+                .ok_or_else(|| anyhow!("{} isn't in the district list", guid))?;
+            unit.last_roundtable = Some(date);
+            config.save()?;
+            println!("Recorded roundtable attendance on {}", date.format("%b %-d, %Y"));
+            Ok(())
+        }
+        _ => bail!(USAGE),
+    }
+}
+
+fn list(config: &Config) -> Result<()> {
+    if config.district_units.is_empty() {
+        println!("No district units. Add one with: trailcache district add <org-guid> [name]");
+        return Ok(());
+    }
+    let password = cache_password(config)?;
+    let today = Local::now().date_naive();
+
+    let mut units: Vec<UnitHealth> = config
+        .district_units
+        .iter()
+        .map(|unit| {
+            let data = config
+                .cache_dir_for(&unit.organization_guid)
+                .and_then(CacheManager::new_without_encryption)
+                .map(|mut cache| {
+                    cache.set_password(&password, &unit.organization_guid);
+                    cache.load_unit_data()
+                })
+                .unwrap_or_default();
+            UnitHealth::new(unit, &data, config.phone_format)
+        })
+        .collect();
+    sort_by_concerns(&mut units, today);
+
+    for unit in &units {
+        println!("{} ({})", unit.name, unit.organization_guid);
+        for k in &unit.key3 {
+            let contact: Vec<&str> = [k.email.as_deref(), k.phone.as_deref()].into_iter().flatten().collect();
+            match k.name {
+                Some(ref name) => println!("  {:<18} {}  {}", k.role, name, contact.join("  ")),
+                None => println!("  {:<18} vacant", k.role),
+            }
+        }
+        if let Some(percent) = unit.trained_percent() {
+            println!("  {}% of {} adults position trained, {} with expired YPT", percent, unit.adults, unit.training.ypt_expired);
+        }
+        for concern in unit.concerns(today) {
+            println!("  ! {}", concern);
+        }
+        println!();
+    }
+    Ok(())
+}
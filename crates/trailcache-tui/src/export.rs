@@ -0,0 +1,429 @@
+//! `trailcache export ...`, `--export-roster`, and `--my-scout` commands
+//! that read the local cache into a `Store` without starting the TUI.
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use anyhow::{anyhow, bail, Context, Result};
+use chrono::{Duration, Local, Utc};
+
+use trailcache_core::auth::CredentialStore;
+use trailcache_core::cache::{apply_key_source, CacheManager};
+use trailcache_core::config::Config;
+use trailcache_core::models::{ConsentForm, Consents, Parent, Youth};
+use trailcache_core::reports::{
+    build_site, clinic_signoff_sections, court_of_honor_sections, my_scout_sections, my_scout_summary, next_rank, signoff_sections, to_html, to_ical, to_markdown, to_text,
+    ExportProfile, MyScoutInput, RosterColumn, RosterCsv, RosterKind, Section, SignoffSheet, SiteInput, TextLayout,
+    SITE_TEMPLATE_FILE,
+};
+use trailcache_core::utils::PhoneFormat;
+
+use crate::store::Store;
+
+const USAGE: &str = "Usage: trailcache export site <dir> [--profile full|leader|public]
+       trailcache export roster <file.csv> [--people scouts|adults|parents] [--columns <set or list>] [--profile full|leader|public]
+       trailcache export rosters <dir> [--columns <set or list>] [--profile full|leader|public]
+       trailcache export signoff <file.html|file.txt> [--rank <name>] [--scout <name>]
+       trailcache export signoff <file.html|file.txt> --clinic
+       trailcache export ical <file.ics>
+       trailcache export court-of-honor <file.html|file.md|file.txt>
+
+Column sets are basic, contact, and all; or list columns, e.g. --columns first,last,patrol,email
+Sign-off sheets cover each scout's next rank unless --rank names one, or
+the merit badges finished at the planned clinic with --clinic";
+
+pub(crate) const NO_ROSTER: &str = "No cached roster. Refresh data in trailcache first (or check the password).";
+
+/// Run an export subcommand. `args` are the arguments after `export`.
+pub fn run(args: &[String]) -> Result<()> {
+    let (args, profile) = take_profile(args)?;
+    match args.as_slice() {
+        // The site is for families, so it's public unless asked otherwise
+        [kind, dir] if kind == "site" => export_site(Path::new(dir), profile.unwrap_or(ExportProfile::Public)),
+        [kind, rest @ ..] if kind == "roster" => export_roster(rest, profile),
+        [kind, rest @ ..] if kind == "rosters" => export_rosters(rest, profile),
+        [kind, rest @ ..] if kind == "signoff" => export_signoff(rest),
+        [kind, path] if kind == "ical" => export_ical(Path::new(path)),
+        [kind, path] if kind == "court-of-honor" => export_court_of_honor(Path::new(path)),
+        _ => bail!(USAGE),
+    }
+}
+
+/// Split a `--profile <name>` option out of `args`.
+pub(crate) fn take_profile(args: &[String]) -> Result<(Vec<String>, Option<ExportProfile>)> {
+    let mut rest = Vec::new();
+    let mut profile = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if arg == "--profile" {
+            let name = args.next().ok_or_else(|| anyhow!("--profile needs full, leader, or public"))?;
+            profile = Some(name.parse()?);
+        } else {
+            rest.push(arg.clone());
+        }
+    }
+    Ok((rest, profile))
+}
+
+/// Open the encrypted cache, using the stored password or prompting for one.
+pub(crate) fn open_cache() -> Result<(Config, CacheManager)> {
+    let config = Config::load()?;
+    let org_guid = config
+        .organization_guid
+        .clone()
+        .ok_or_else(|| anyhow!("No organization configured. Log in with trailcache first."))?;
+
+    let mut cache = CacheManager::new_without_encryption(config.cache_dir()?)?;
+    cache.set_password(&cache_password(&config)?, &org_guid);
+    apply_key_source(&mut cache, &org_guid, config.keyring_cache_key)?;
+    Ok((config, cache))
+}
+
+/// Open the cache and read it into a `Store`, as the TUI does at startup.
+pub(crate) fn open_store() -> Result<(Config, CacheManager, Store)> {
+    let (config, cache) = open_cache()?;
+    let mut store = Store::default();
+    store.load(&cache, Local::now().date_naive());
+    Ok((config, cache, store))
+}
+
+/// The store's roster, or an error if nothing was cached.
+pub(crate) fn cached_youth(store: &Store) -> Result<&[Youth]> {
+    if store.youth.is_empty() {
+        bail!(NO_ROSTER);
+    }
+    Ok(&store.youth)
+}
+
+/// The troop's consent signatures from the cache.
+pub(crate) fn load_consents(cache: &CacheManager) -> Result<Consents> {
+    Ok(cache.load_consents()?.map(|c| c.data).unwrap_or_default())
+}
+
+/// Warn on stderr about scouts in `youth` without a current media release,
+/// since `what` is about to name them.
+pub(crate) fn warn_unreleased<'a>(consents: &Consents, youth: impl IntoIterator<Item = &'a Youth>, what: &str) {
+    let today = Local::now().date_naive();
+    let unreleased: Vec<String> = youth
+        .into_iter()
+        .filter(|y| y.user_id.is_none_or(|id| !consents.is_current(id, ConsentForm::MediaRelease, today)))
+        .map(|y| y.short_name())
+        .collect();
+    if !unreleased.is_empty() {
+        eprintln!(
+            "Warning: {} includes {} scouts without a current media release: {}",
+            what,
+            unreleased.len(),
+            unreleased.join(", ")
+        );
+    }
+}
+
+/// The scouts a roster of `kind` names: all of them for scouts, and for
+/// parents only the ones whose names the `Scout` column brings in.
+pub(crate) fn roster_youth<'a>(
+    kind: RosterKind,
+    columns: &[RosterColumn],
+    youth: &'a [Youth],
+    parents: &[Parent],
+) -> Vec<&'a Youth> {
+    match kind {
+        RosterKind::Youth => youth.iter().collect(),
+        RosterKind::Parents if columns.contains(&RosterColumn::Scout) => {
+            let ids: HashSet<i64> = parents.iter().filter_map(|p| p.youth_user_id).collect();
+            youth.iter().filter(|y| y.user_id.is_some_and(|id| ids.contains(&id))).collect()
+        }
+        RosterKind::Adults | RosterKind::Parents => Vec::new(),
+    }
+}
+
+/// The cache password: the stored one, or prompt for it.
+pub(crate) fn cache_password(config: &Config) -> Result<String> {
+    Ok(match config.last_username.as_deref().filter(|u| CredentialStore::has_credentials(u)) {
+        Some(username) => CredentialStore::get_password(username)?,
+        None => rpassword::prompt_password("Password: ")?,
+    })
+}
+
+fn export_site(dir: &Path, profile: ExportProfile) -> Result<()> {
+    let (config, cache, store) = open_store()?;
+    let youth = cached_youth(&store)?;
+
+    let template_path = config.data_file_path(SITE_TEMPLATE_FILE)?;
+    let template = if template_path.exists() {
+        Some(std::fs::read_to_string(&template_path).with_context(|| format!("Reading {}", template_path.display()))?)
+    } else {
+        None
+    };
+
+    let today = Local::now().date_naive();
+    warn_unreleased(&load_consents(&cache)?, youth, "the roster page");
+
+    let unit_name = config.unit_name.clone().unwrap_or_else(|| "Troop".to_string());
+    let input = SiteInput {
+        unit_name: &unit_name,
+        youth,
+        adults: &store.adults,
+        events: &store.events,
+        ranks: &store.all_youth_ranks,
+        profile,
+        phone_format: config.phone_format,
+    };
+    let pages = build_site(&input, today, template.as_deref());
+
+    std::fs::create_dir_all(dir).with_context(|| format!("Creating {}", dir.display()))?;
+    for page in &pages {
+        std::fs::write(dir.join(page.file_name), &page.html)?;
+    }
+    eprintln!("Wrote {} pages to {} ({} profile)", pages.len(), dir.display(), profile);
+    Ok(())
+}
+
+/// Split a `--name <value>` option out of `args`.
+pub(crate) fn take_option(args: &[String], name: &str) -> Result<(Vec<String>, Option<String>)> {
+    let mut rest = Vec::new();
+    let mut value = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if arg == name {
+            value = Some(args.next().ok_or_else(|| anyhow!("{} needs a value", name))?.clone());
+        } else {
+            rest.push(arg.clone());
+        }
+    }
+    Ok((rest, value))
+}
+
+/// The `--columns` choice, `basic` if none was given.
+pub(crate) fn take_columns(args: &[String]) -> Result<(Vec<String>, Vec<RosterColumn>)> {
+    let (rest, spec) = take_option(args, "--columns")?;
+    Ok((rest, RosterColumn::parse_list(spec.as_deref().unwrap_or("basic"))?))
+}
+
+/// Write the roster of `kind` from the store as CSV.
+fn roster_csv(
+    store: &Store,
+    kind: RosterKind,
+    columns: &[RosterColumn],
+    profile: ExportProfile,
+    phone_format: PhoneFormat,
+) -> Result<RosterCsv> {
+    let roster = RosterCsv::new(kind, columns, profile, phone_format);
+    Ok(match kind {
+        RosterKind::Youth => roster.youth(cached_youth(store)?),
+        RosterKind::Adults => roster.adults(&store.adults),
+        RosterKind::Parents => roster.parents(&store.parents),
+    })
+}
+
+/// `export roster <file.csv>` and `--export-roster <file.csv>`: one roster,
+/// scouts unless `--people` says otherwise.
+pub fn export_roster(args: &[String], profile: Option<ExportProfile>) -> Result<()> {
+    let (args, columns) = take_columns(args)?;
+    let (args, people) = take_option(&args, "--people")?;
+    let [path] = args.as_slice() else {
+        bail!(USAGE);
+    };
+    let kind = people.as_deref().map(str::parse).transpose()?.unwrap_or(RosterKind::Youth);
+
+    let (config, cache, store) = open_store()?;
+    let profile = profile.unwrap_or(config.export_profile);
+    let roster = roster_csv(&store, kind, &columns, profile, config.phone_format)?;
+    warn_unreleased(&load_consents(&cache)?, roster_youth(kind, &columns, &store.youth, &store.parents), path);
+    let count = roster.len();
+    std::fs::write(path, roster.finish()).with_context(|| format!("Writing {}", path))?;
+    eprintln!("Wrote {} {} to {} ({} profile)", count, kind, path, profile);
+    Ok(())
+}
+
+/// `export rosters <dir>`: scouts.csv, adults.csv, and parents.csv.
+fn export_rosters(args: &[String], profile: Option<ExportProfile>) -> Result<()> {
+    let (args, columns) = take_columns(args)?;
+    let [dir] = args.as_slice() else {
+        bail!(USAGE);
+    };
+    let dir = Path::new(dir);
+
+    let (config, cache, store) = open_store()?;
+    let profile = profile.unwrap_or(config.export_profile);
+    let consents = load_consents(&cache)?;
+    std::fs::create_dir_all(dir).with_context(|| format!("Creating {}", dir.display()))?;
+    for kind in RosterKind::ALL {
+        let roster = roster_csv(&store, kind, &columns, profile, config.phone_format)?;
+        let path = dir.join(format!("{}.csv", kind));
+        warn_unreleased(&consents, roster_youth(kind, &columns, &store.youth, &store.parents), &path.display().to_string());
+        let count = roster.len();
+        std::fs::write(&path, roster.finish()).with_context(|| format!("Writing {}", path.display()))?;
+        eprintln!("Wrote {} {} to {}", count, kind, path.display());
+    }
+    eprintln!("({} profile)", profile);
+    Ok(())
+}
+
+/// `export ical <file.ics>` and `--export-ical <file.ics>`: cached events
+/// as a calendar families can subscribe to.
+pub fn export_ical(path: &Path) -> Result<()> {
+    let (config, _, store) = open_store()?;
+    if store.events.is_empty() {
+        bail!("No cached events. Refresh data in trailcache first (or check the password).");
+    }
+    let unit_name = config.unit_name.clone().unwrap_or_else(|| "Troop".to_string());
+    let ics = to_ical(&unit_name, &store.events, Utc::now());
+    std::fs::write(path, ics).with_context(|| format!("Writing {}", path.display()))?;
+    eprintln!("Wrote {} events to {}", store.events.len(), path.display());
+    Ok(())
+}
+
+/// `export signoff <file>`: a handbook-style sign-off page per scout, for
+/// their next rank or the `--rank` given, or with `--clinic` for the merit
+/// badges they finished at the clinic. HTML prints a page per scout; any
+/// other extension gets plain text.
+fn export_signoff(args: &[String]) -> Result<()> {
+    let (args, rank) = take_option(args, "--rank")?;
+    let (args, scout) = take_option(&args, "--scout")?;
+    let clinic = args.iter().any(|a| a == "--clinic");
+    let args: Vec<&String> = args.iter().filter(|a| *a != "--clinic").collect();
+    let [path] = args.as_slice() else {
+        bail!(USAGE);
+    };
+
+    let (_, cache, store) = open_store()?;
+    let youth = cached_youth(&store)?;
+
+    if clinic {
+        let clinic = cache.load_clinic()?.map(|c| c.data).unwrap_or_default();
+        let sections = clinic_signoff_sections(&clinic, youth);
+        if sections.is_empty() {
+            bail!("No clinic completions recorded. Mark scouts complete in the clinic planner first.");
+        }
+        std::fs::write(path, signoff_document(Path::new(path), &clinic.name, &sections)).with_context(|| format!("Writing {}", path))?;
+        eprintln!("Wrote {} clinic sign-off sheets to {}", sections.len(), path);
+        return Ok(());
+    }
+    let scout = scout.map(|s| s.to_lowercase());
+    let mut youth: Vec<_> = youth
+        .iter()
+        .filter(|y| scout.as_ref().is_none_or(|s| y.full_name().to_lowercase().contains(s)))
+        .collect();
+    youth.sort_by_key(|y| (y.patrol(), y.last_name.to_lowercase(), y.first_name.to_lowercase()));
+
+    // (scout, rank name, cached requirements) for each scout with a rank to work on
+    let mut pages = Vec::new();
+    for y in youth {
+        let Some(id) = y.user_id else { continue };
+        let ranks = store.all_youth_ranks.get(&id).map(Vec::as_slice).unwrap_or_default();
+        let progress = match &rank {
+            Some(name) => ranks.iter().find(|r| r.rank_name.eq_ignore_ascii_case(name)),
+            None => next_rank(ranks),
+        };
+        let Some(progress) = progress else { continue };
+        let requirements = cache.load_rank_requirements(id, progress.rank_id)?.map(|c| c.data).unwrap_or_default();
+        pages.push((y, progress.rank_name.clone(), requirements));
+    }
+    if pages.is_empty() {
+        match &rank {
+            Some(name) => bail!("No cached {} progress for the scouts chosen", name),
+            None => bail!("No scouts with a rank in progress"),
+        }
+    }
+
+    let sheets: Vec<_> = pages
+        .iter()
+        .map(|(youth, rank, requirements)| SignoffSheet { youth, rank, requirements })
+        .collect();
+    let missing = sheets.iter().filter(|s| s.requirements.is_empty()).count();
+    let sections = signoff_sections(&sheets);
+    std::fs::write(path, signoff_document(Path::new(path), "Rank Sign-off Sheets", &sections)).with_context(|| format!("Writing {}", path))?;
+
+    eprintln!("Wrote {} sign-off sheets to {}", sheets.len(), path);
+    if missing > 0 {
+        eprintln!("{} sheets have no cached requirements; cache them for offline use in trailcache first", missing);
+    }
+    Ok(())
+}
+
+/// Sign-off sections as HTML for an .html `path`, or plain text with each
+/// scout on a new page.
+pub(crate) fn signoff_document(path: &Path, title: &str, sections: &[Section]) -> String {
+    let is_html = path.extension().is_some_and(|e| e.eq_ignore_ascii_case("html") || e.eq_ignore_ascii_case("htm"));
+    if is_html {
+        return to_html(title, sections);
+    }
+    // Each scout starts a new page, as HTML sections do
+    sections
+        .iter()
+        .map(|section| to_text(title, std::slice::from_ref(section), TextLayout::default()))
+        .collect::<Vec<_>>()
+        .join("\x0c")
+}
+
+/// Sections as HTML, Markdown, or plain text, by `path`'s extension.
+pub(crate) fn report_document(path: &Path, title: &str, sections: &[Section]) -> String {
+    let extension = path.extension().map(|e| e.to_string_lossy().to_lowercase()).unwrap_or_default();
+    match extension.as_str() {
+        "html" | "htm" => to_html(title, sections),
+        "md" | "markdown" => to_markdown(title, sections, None),
+        _ => to_text(title, sections, TextLayout::default()),
+    }
+}
+
+/// The Court of Honor handout's title.
+pub(crate) fn court_of_honor_title(config: &Config, today: chrono::NaiveDate) -> String {
+    let unit_name = config.unit_name.as_deref().unwrap_or("Troop");
+    format!("{} Court of Honor - {}", unit_name, today.format("%B %-d, %Y"))
+}
+
+/// `export court-of-honor <file>`: everything ready to award, by scout.
+fn export_court_of_honor(path: &Path) -> Result<()> {
+    let (config, _, store) = open_store()?;
+    let awards = &store.ready_to_award;
+    if awards.is_empty() {
+        bail!("Nothing cached is ready to award. Refresh data in trailcache first (or check the password).");
+    }
+    let sections = court_of_honor_sections(awards, &store.youth);
+    let title = court_of_honor_title(&config, Local::now().date_naive());
+    std::fs::write(path, report_document(path, &title, &sections)).with_context(|| format!("Writing {}", path.display()))?;
+    eprintln!("Wrote {} awards for {} scouts to {}", awards.len(), sections.len() - 1, path.display());
+    Ok(())
+}
+
+/// Print the My Scouts summary for pinned scouts, or for scouts whose name
+/// contains `name`.
+pub fn my_scout_report(name: Option<&str>) -> Result<()> {
+    let (config, cache, store) = open_store()?;
+    let youth = cached_youth(&store)?;
+    let den_chiefs = cache.load_den_chiefs()?.map(|c| c.data).unwrap_or_default();
+
+    let scouts: Vec<_> = match name.map(str::to_lowercase) {
+        Some(name) => youth.iter().filter(|y| y.full_name().to_lowercase().contains(&name)).collect(),
+        None => config
+            .my_scouts
+            .iter()
+            .filter_map(|&id| youth.iter().find(|y| y.user_id == Some(id)))
+            .collect(),
+    };
+    if scouts.is_empty() {
+        match name {
+            Some(name) => bail!("No scout matching \"{}\"", name),
+            None => bail!("No scouts pinned. Press y on the Scouts tab to pin yours, or pass a name."),
+        }
+    }
+
+    let today = Local::now().date_naive();
+    let summaries: Vec<_> = scouts
+        .into_iter()
+        .map(|youth| {
+            let id = youth.user_id.unwrap_or_default();
+            let ranks = store.all_youth_ranks.get(&id).map(Vec::as_slice).unwrap_or_default();
+            let badges = store.all_youth_badges.get(&id).map(Vec::as_slice).unwrap_or_default();
+            let den_meetings = den_chiefs.meetings(id, today, today + Duration::days(30));
+            let input = MyScoutInput { youth, ranks, badges, events: &store.events, den_meetings: &den_meetings };
+            my_scout_summary(&input, today)
+        })
+        .collect();
+
+    let title = format!("My Scouts - {}", today.format("%b %-d, %Y"));
+    let layout = TextLayout { page_lines: None, ..TextLayout::default() };
+    print!("{}", to_text(&title, &my_scout_sections(&summaries), layout));
+    Ok(())
+}
@@ -4,6 +4,19 @@
 //! and managing Boy Scouts of America troop data. Works offline with cached data.
 
 mod app;
+mod backup;
+mod cli;
+mod digest;
+mod district;
+mod export;
+mod notify;
+mod refresh_queue;
+mod report;
+mod retention;
+mod selection;
+mod services;
+mod store;
+mod timer;
 mod ui;
 
 use trailcache_core as core;
@@ -13,7 +26,10 @@ use std::time::Duration;
 
 use anyhow::Result;
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind, KeyModifiers},
+    event::{
+        self, DisableFocusChange, DisableMouseCapture, EnableFocusChange, EnableMouseCapture, Event, KeyCode, KeyEventKind,
+        KeyModifiers,
+    },
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -21,7 +37,9 @@ use ratatui::{backend::CrosstermBackend, Terminal};
 use tracing::info;
 use tracing_subscriber::{fmt, prelude::*, EnvFilter};
 
-use app::{App, AppState};
+use app::App;
+use ui::state::AppState;
+use cli::Command;
 use ui::input::handle_input;
 use ui::render::render;
 
@@ -53,13 +71,33 @@ async fn main() -> Result<()> {
     // Load requirement summaries
     core::summaries::init();
 
-    // Check for CLI commands
-    let args: Vec<String> = std::env::args().collect();
-    if args.len() > 1 && args[1] == "--dump-requirements" {
-        return dump_requirements().await;
-    }
-    if args.len() > 1 && args[1] == "--test-versions" {
-        return test_version_endpoints().await;
+    // Run a command instead of the TUI if one was given
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match Command::parse(&args)? {
+        Command::Tui => {}
+        Command::Demo => services::use_demo_api()?,
+        Command::Help => {
+            println!("{}", cli::USAGE);
+            return Ok(());
+        }
+        Command::DumpRequirements => return dump_requirements().await,
+        Command::TestVersions => return test_version_endpoints().await,
+        Command::Report(args) => return report::run(args).await,
+        Command::Export(args) => return export::run(args),
+        Command::Backup(args) => return backup::run_backup(args),
+        Command::Restore(args) => return backup::run_restore(args),
+        Command::District(args) => return district::run(args),
+        Command::Retention(args) => return retention::run(args),
+        Command::Digest(args) => return digest::run(args).await,
+        Command::ExportRoster(args) => {
+            let (args, profile) = export::take_profile(args)?;
+            return export::export_roster(&args, profile);
+        }
+        Command::ExportIcal(args) => {
+            let path = args.first().ok_or_else(|| anyhow::anyhow!("Usage: trailcache --export-ical <file.ics>"))?;
+            return export::export_ical(std::path::Path::new(path));
+        }
+        Command::MyScout(name) => return export::my_scout_report(name),
     }
 
     // Initialize logging
@@ -69,7 +107,7 @@ async fn main() -> Result<()> {
     // Setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture, EnableFocusChange)?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
@@ -80,8 +118,10 @@ async fn main() -> Result<()> {
     let _ = app.load_from_cache();
 
     // Always require login to derive encryption key for cache
-    // (password is needed to decrypt cached data)
-    app.start_login();
+    // (password is needed to decrypt cached data). The demo signs itself in.
+    if !services::is_demo() || app.attempt_login().await.is_err() {
+        app.start_login();
+    }
 
     // Main loop
     let result = run_app(&mut terminal, &mut app).await;
@@ -91,7 +131,8 @@ async fn main() -> Result<()> {
     execute!(
         terminal.backend_mut(),
         LeaveAlternateScreen,
-        DisableMouseCapture
+        DisableMouseCapture,
+        DisableFocusChange
     )?;
     terminal.show_cursor()?;
 
@@ -380,11 +421,12 @@ async fn run_app(
                         return Ok(());
                     }
                 }
-                Event::Mouse(mouse) => {
-                    if ui::input::handle_mouse(app, mouse).await? {
-                        return Ok(());
-                    }
+                Event::Mouse(mouse) if ui::input::handle_mouse(app, mouse).await? => {
+                    return Ok(());
                 }
+                // Notifications only fire while the terminal is in the background
+                Event::FocusGained => app.terminal_focused = true,
+                Event::FocusLost => app.terminal_focused = false,
                 _ => {}
             }
         }
@@ -393,7 +435,7 @@ async fn run_app(
         app.check_background_tasks().await;
 
         // Check if we should quit
-        if matches!(app.state, AppState::Quitting) {
+        if matches!(app.ui.state, AppState::Quitting) {
             return Ok(());
         }
     }
@@ -0,0 +1,62 @@
+//! Terminal bell and desktop notifications, and the webhook pushed after a
+//! refresh.
+//!
+//! Desktop notifications need the `desktop-notifications` feature; without
+//! it, or if the notification can't be shown, the terminal bell rings instead.
+
+use std::io::{self, Write};
+
+use chrono::Local;
+use tracing::{debug, info, warn};
+use trailcache_core::config::NotifyMode;
+use trailcache_core::integrations::{webhook, WebhookPayload};
+use trailcache_core::models::FeedEntry;
+
+/// Get the user's attention according to `mode`.
+pub fn send(mode: NotifyMode, summary: &str, body: &str) {
+    match mode {
+        NotifyMode::Off => {}
+        NotifyMode::Bell => bell(),
+        NotifyMode::Desktop => {
+            if !desktop(summary, body) {
+                bell();
+            }
+        }
+    }
+}
+
+/// POST what changed in a refresh to the configured webhook, in the
+/// background. Failures are logged; the refresh itself already succeeded.
+pub fn webhook(url: &str, unit: &str, changes: &[FeedEntry]) {
+    let payload = WebhookPayload::new(unit, changes, Local::now());
+    let url = url.to_string();
+    tokio::spawn(async move {
+        match webhook::post(&url, &payload).await {
+            Ok(()) => info!(changes = payload.changes.len(), "Webhook sent"),
+            Err(e) => warn!(error = %e, "Webhook failed"),
+        }
+    });
+}
+
+fn bell() {
+    let mut stdout = io::stdout();
+    let _ = stdout.write_all(b"\x07");
+    let _ = stdout.flush();
+}
+
+#[cfg(feature = "desktop-notifications")]
+fn desktop(summary: &str, body: &str) -> bool {
+    match notify_rust::Notification::new().appname("trailcache").summary(summary).body(body).show() {
+        Ok(_) => true,
+        Err(e) => {
+            debug!(error = %e, "Desktop notification failed");
+            false
+        }
+    }
+}
+
+#[cfg(not(feature = "desktop-notifications"))]
+fn desktop(summary: &str, _body: &str) -> bool {
+    debug!(summary, "Built without desktop-notifications; using the bell");
+    false
+}
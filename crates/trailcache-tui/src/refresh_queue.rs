@@ -0,0 +1,135 @@
+//! The order a background refresh fetches in.
+//!
+//! Once every scout's advancement is included a full refresh takes minutes,
+//! so jobs are queued by priority and each result is shown as soon as it
+//! arrives: first the datasets the visible tab draws on and the scout being
+//! looked at, then the other datasets, then everyone else's advancement.
+//! Jobs of equal priority run in the order they were queued.
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashSet};
+
+use crate::ui::state::Tab;
+use crate::store::RefreshDataset;
+
+/// One unit of refresh work.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RefreshJob {
+    Dataset(RefreshDataset),
+    /// A scout's ranks, badges, leadership, awards, and activity
+    Scout(i64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Priority {
+    Background,
+    Normal,
+    Visible,
+}
+
+/// What's on screen when the refresh starts.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RefreshFocus {
+    pub datasets: Vec<RefreshDataset>,
+    pub user_id: Option<i64>,
+}
+
+impl RefreshFocus {
+    /// The datasets `tab` shows, and the scout selected on it.
+    pub fn new(tab: Tab, user_id: Option<i64>) -> Self {
+        use RefreshDataset::*;
+        let datasets = match tab {
+            Tab::Scouts => vec![Youth, Patrols, Parents],
+            Tab::Ranks | Tab::Badges => vec![Youth, Dashboard],
+            Tab::Events => vec![Events],
+            Tab::Adults => vec![Adults],
+            Tab::Unit => vec![Key3, UnitInfo, OrgProfile, Commissioners, Dashboard],
+            Tab::Eagle => vec![Youth],
+            Tab::Attendance => vec![Youth, Events],
+            Tab::Plugin(_) => Vec::new(),
+        };
+        Self { datasets, user_id }
+    }
+
+    pub fn priority(&self, job: RefreshJob) -> Priority {
+        match job {
+            RefreshJob::Dataset(d) if self.datasets.contains(&d) => Priority::Visible,
+            RefreshJob::Scout(id) if self.user_id == Some(id) => Priority::Visible,
+            RefreshJob::Dataset(_) => Priority::Normal,
+            RefreshJob::Scout(_) => Priority::Background,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Entry {
+    priority: Priority,
+    order: usize,
+    job: RefreshJob,
+}
+
+impl Ord for Entry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.priority.cmp(&other.priority).then_with(|| other.order.cmp(&self.order))
+    }
+}
+
+impl PartialOrd for Entry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Jobs waiting to run, highest priority first. Each job is queued once.
+#[derive(Debug, Default)]
+pub struct RefreshQueue {
+    focus: RefreshFocus,
+    heap: BinaryHeap<Entry>,
+    queued: HashSet<RefreshJob>,
+}
+
+impl RefreshQueue {
+    /// Every dataset, and the focused scout, whose ID is known before the
+    /// roster arrives.
+    pub fn new(focus: RefreshFocus) -> Self {
+        let scout = focus.user_id;
+        let mut queue = Self { focus, ..Default::default() };
+        for dataset in RefreshDataset::ALL {
+            queue.push(RefreshJob::Dataset(dataset));
+        }
+        if let Some(id) = scout {
+            queue.push(RefreshJob::Scout(id));
+        }
+        queue
+    }
+
+    pub fn push(&mut self, job: RefreshJob) {
+        if self.queued.insert(job) {
+            let order = self.queued.len();
+            self.heap.push(Entry { priority: self.focus.priority(job), order, job });
+        }
+    }
+
+    pub fn pop(&mut self) -> Option<RefreshJob> {
+        self.heap.pop().map(|e| e.job)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_visible_tab_and_scout_first() {
+        let mut queue = RefreshQueue::new(RefreshFocus::new(Tab::Events, Some(7)));
+        queue.push(RefreshJob::Scout(3));
+        queue.push(RefreshJob::Scout(7));
+
+        let order: Vec<RefreshJob> = std::iter::from_fn(|| queue.pop()).collect();
+        assert_eq!(order[0], RefreshJob::Dataset(RefreshDataset::Events));
+        assert_eq!(order[1], RefreshJob::Scout(7));
+        assert_eq!(order[2], RefreshJob::Dataset(RefreshDataset::Youth), "then the rest in order");
+        assert_eq!(order.last(), Some(&RefreshJob::Scout(3)));
+        assert_eq!(order.len(), RefreshDataset::ALL.len() + 2, "scout 7 queued once");
+    }
+}
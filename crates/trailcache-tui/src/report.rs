@@ -0,0 +1,293 @@
+//! `trailcache report ...`: print reports without starting the TUI.
+//!
+//! `roster`, `advancement`, `expiring`, and `grades` print a table as CSV or JSON,
+//! read from the local cache or, with `--live`, fetched with the saved
+//! session. `custom` renders a user-written report template from the
+//! cache. Personal details are limited by the export profile.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::{anyhow, bail, Context, Result};
+use chrono::Local;
+
+use trailcache_core::api::ApiClient;
+use trailcache_core::auth::Session;
+use trailcache_core::cache::CacheManager;
+use trailcache_core::config::Config;
+use trailcache_core::models::{Adult, Consents, MeritBadgeProgress, Parent, RankProgress, Youth};
+use trailcache_core::reports::{
+    advancement_table, expiring_table, grade_table, render_template, template_context, ExportProfile, RosterColumn,
+    RosterCsv, RosterKind, Table, TemplateInput, REPORT_TEMPLATES_DIR,
+};
+
+use crate::export::{
+    cached_youth, load_consents, open_cache, open_store, roster_youth, take_columns, take_option, take_profile, warn_unreleased,
+};
+use crate::store::Store;
+
+const USAGE: &str = "Usage: trailcache report roster [--people scouts|adults|parents] [--columns <set or list>] [--profile full|leader|public] [--format csv|json] [--live]
+       trailcache report advancement [--format csv|json] [--live]
+       trailcache report expiring [--days <n>] [--format csv|json] [--live]
+       trailcache report grades [--format csv|json] [--live]
+       trailcache report custom <template> [--profile full|leader|public]
+
+Tables print as CSV unless --format json. They're read from the cache
+unless --live fetches them with the saved login. Expiring covers
+registrations and Youth Protection training due within 90 days unless
+--days says otherwise, along with anything already expired. Grades lists
+scouts whose Scoutbook grade looks behind their age since the last
+grade_rollover.";
+
+/// Run a report subcommand. `args` are the arguments after `report`.
+pub async fn run(args: &[String]) -> Result<()> {
+    let (args, profile) = take_profile(args)?;
+    match args.as_slice() {
+        [kind, name] if kind == "custom" => custom_report(name, profile),
+        [kind, rest @ ..] if kind == "roster" => roster_report(rest, profile).await,
+        [kind, rest @ ..] if kind == "advancement" => advancement_report(rest).await,
+        [kind, rest @ ..] if kind == "expiring" => expiring_report(rest).await,
+        [kind, rest @ ..] if kind == "grades" => grades_report(rest).await,
+        _ => {
+            let config = Config::load()?;
+            let dir = config.data_file_path(REPORT_TEMPLATES_DIR)?;
+            let names = template_names(&dir);
+            if names.is_empty() {
+                bail!("{}\nNo templates yet. Add them to {}", USAGE, dir.display());
+            }
+            bail!("{}\nTemplates in {}: {}", USAGE, dir.display(), names.join(", "));
+        }
+    }
+}
+
+/// How a table is printed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Format {
+    Csv,
+    Json,
+}
+
+/// Where the report's data comes from.
+enum Source {
+    Cache { cache: Box<CacheManager>, store: Box<Store> },
+    Live { api: ApiClient, org_guid: String },
+}
+
+impl Source {
+    fn open(live: bool) -> Result<(Config, Source)> {
+        if !live {
+            let (config, cache, store) = open_store()?;
+            return Ok((config, Source::Cache { cache: Box::new(cache), store: Box::new(store) }));
+        }
+        let config = Config::load()?;
+        let org_guid = config
+            .organization_guid
+            .clone()
+            .ok_or_else(|| anyhow!("No organization configured. Log in with trailcache first."))?;
+        let mut session = Session::new(config.cache_dir()?);
+        session.load()?;
+        let token = session
+            .data
+            .map(|d| d.token)
+            .ok_or_else(|| anyhow!("No saved session, or it has expired. Log in with trailcache first."))?;
+        let api = ApiClient::new()?.with_token(Arc::new(token));
+        Ok((config, Source::Live { api, org_guid }))
+    }
+
+    async fn youth(&self) -> Result<Vec<Youth>> {
+        match self {
+            Source::Cache { store, .. } => Ok(cached_youth(store)?.to_vec()),
+            Source::Live { api, org_guid } => api.fetch_youth(org_guid).await,
+        }
+    }
+
+    async fn adults(&self) -> Result<Vec<Adult>> {
+        match self {
+            Source::Cache { store, .. } => Ok(store.adults.clone()),
+            Source::Live { api, org_guid } => Ok(Adult::deduplicate(api.fetch_adults(org_guid).await?)),
+        }
+    }
+
+    async fn parents(&self) -> Result<Vec<Parent>> {
+        match self {
+            Source::Cache { store, .. } => Ok(store.parents.clone()),
+            Source::Live { api, org_guid } => api.fetch_parents(org_guid).await,
+        }
+    }
+
+    /// Media releases are only recorded locally, so a live report still
+    /// reads them from the cache.
+    fn consents(&self) -> Result<Consents> {
+        match self {
+            Source::Cache { cache, .. } => load_consents(cache),
+            Source::Live { .. } => load_consents(&open_cache()?.1),
+        }
+    }
+
+    async fn ranks(&self, user_id: i64) -> Result<Vec<RankProgress>> {
+        match self {
+            Source::Cache { store, .. } => Ok(store.all_youth_ranks.get(&user_id).cloned().unwrap_or_default()),
+            Source::Live { api, .. } => api.fetch_youth_ranks(user_id).await,
+        }
+    }
+
+    async fn merit_badges(&self, user_id: i64) -> Result<Vec<MeritBadgeProgress>> {
+        match self {
+            Source::Cache { store, .. } => Ok(store.all_youth_badges.get(&user_id).cloned().unwrap_or_default()),
+            Source::Live { api, .. } => api.fetch_youth_merit_badges(user_id).await,
+        }
+    }
+}
+
+/// Split out `--format` and `--live`, which every table report takes.
+fn take_output(args: &[String]) -> Result<(Vec<String>, Format, bool)> {
+    let (args, format) = take_option(args, "--format")?;
+    let format = match format.as_deref() {
+        None | Some("csv") => Format::Csv,
+        Some("json") => Format::Json,
+        Some(other) => bail!("Unknown format \"{}\" (use csv or json)", other),
+    };
+    let live = args.iter().any(|a| a == "--live");
+    let rest = args.into_iter().filter(|a| a != "--live").collect();
+    Ok((rest, format, live))
+}
+
+fn print_table(table: &Table, format: Format) -> Result<()> {
+    match format {
+        Format::Csv => print!("{}", table.to_csv()),
+        Format::Json => println!("{}", serde_json::to_string_pretty(&table.to_json())?),
+    }
+    Ok(())
+}
+
+async fn roster_report(args: &[String], profile: Option<ExportProfile>) -> Result<()> {
+    let (args, format, live) = take_output(args)?;
+    let (args, columns) = take_columns(&args)?;
+    let (args, people) = take_option(&args, "--people")?;
+    if !args.is_empty() {
+        bail!(USAGE);
+    }
+    let kind = people.as_deref().map(str::parse).transpose()?.unwrap_or(RosterKind::Youth);
+
+    let (config, source) = Source::open(live)?;
+    let roster = RosterCsv::new(kind, &columns, profile.unwrap_or(config.export_profile), config.phone_format);
+    let (roster, youth, parents) = match kind {
+        RosterKind::Youth => {
+            let youth = source.youth().await?;
+            (roster.youth(&youth), youth, Vec::new())
+        }
+        RosterKind::Adults => (roster.adults(&source.adults().await?), Vec::new(), Vec::new()),
+        RosterKind::Parents => {
+            let parents = source.parents().await?;
+            let youth = if columns.contains(&RosterColumn::Scout) { source.youth().await? } else { Vec::new() };
+            (roster.parents(&parents), youth, parents)
+        }
+    };
+    let named = roster_youth(kind, &columns, &youth, &parents);
+    if !named.is_empty() {
+        warn_unreleased(&source.consents()?, named, "the report");
+    }
+    print_table(&roster.into_table(), format)
+}
+
+async fn advancement_report(args: &[String]) -> Result<()> {
+    let (args, format, live) = take_output(args)?;
+    if !args.is_empty() {
+        bail!(USAGE);
+    }
+    let (_, source) = Source::open(live)?;
+    let youth = source.youth().await?;
+    if live {
+        eprintln!("Fetching advancement for {} scouts...", youth.len());
+    }
+    let mut ranks = HashMap::new();
+    let mut badges = HashMap::new();
+    for id in youth.iter().filter_map(|y| y.user_id) {
+        ranks.insert(id, source.ranks(id).await?);
+        badges.insert(id, source.merit_badges(id).await?);
+    }
+    print_table(&advancement_table(&youth, &ranks, &badges), format)
+}
+
+async fn expiring_report(args: &[String]) -> Result<()> {
+    let (args, format, live) = take_output(args)?;
+    let (args, days) = take_option(&args, "--days")?;
+    if !args.is_empty() {
+        bail!(USAGE);
+    }
+    let days = match days {
+        Some(days) => days.parse().with_context(|| format!("--days needs a number, not \"{}\"", days))?,
+        None => 90,
+    };
+    let (_, source) = Source::open(live)?;
+    let youth = source.youth().await?;
+    let adults = source.adults().await?;
+    print_table(&expiring_table(&youth, &adults, Local::now().date_naive(), days), format)
+}
+
+async fn grades_report(args: &[String]) -> Result<()> {
+    let (args, format, live) = take_output(args)?;
+    if !args.is_empty() {
+        bail!(USAGE);
+    }
+    let (config, source) = Source::open(live)?;
+    let youth = source.youth().await?;
+    print_table(&grade_table(&youth, config.grade_rollover, Local::now().date_naive()), format)
+}
+
+fn template_names(dir: &Path) -> Vec<String> {
+    let mut names: Vec<String> = std::fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|e| e.path().is_file())
+        .map(|e| e.file_name().to_string_lossy().into_owned())
+        .collect();
+    names.sort();
+    names
+}
+
+/// A path to a template file, or the name of one in the templates folder
+/// with or without its extension.
+fn find_template(config: &Config, name: &str) -> Result<PathBuf> {
+    let path = Path::new(name);
+    if path.is_file() {
+        return Ok(path.to_path_buf());
+    }
+    let dir = config.data_file_path(REPORT_TEMPLATES_DIR)?;
+    let exact = dir.join(name);
+    if exact.is_file() {
+        return Ok(exact);
+    }
+    template_names(&dir)
+        .into_iter()
+        .find(|file| Path::new(file).file_stem().is_some_and(|stem| stem == name))
+        .map(|file| dir.join(file))
+        .ok_or_else(|| anyhow!("No template \"{}\" in {}", name, dir.display()))
+}
+
+fn custom_report(name: &str, profile: Option<ExportProfile>) -> Result<()> {
+    let config = Config::load()?;
+    let path = find_template(&config, name)?;
+    let source = std::fs::read_to_string(&path).with_context(|| format!("Reading {}", path.display()))?;
+
+    let (config, cache, store) = open_store()?;
+    let youth = cached_youth(&store)?;
+    warn_unreleased(&load_consents(&cache)?, youth, "the report");
+
+    let unit_name = config.unit_name.clone().unwrap_or_else(|| "Troop".to_string());
+    let profile = profile.unwrap_or(config.export_profile);
+    let input = TemplateInput {
+        unit_name: &unit_name,
+        youth,
+        adults: &store.adults,
+        events: &store.events,
+        profile,
+        phone_format: config.phone_format,
+    };
+    let context = template_context(&input, Local::now().date_naive());
+    let report = render_template(&source, &context).with_context(|| format!("Rendering {}", path.display()))?;
+    print!("{}", report);
+    Ok(())
+}
@@ -0,0 +1,64 @@
+//! `trailcache retention`: show the retention policy, departed scouts
+//! waiting to be purged, and the audit log of what has been purged.
+
+use anyhow::{bail, Result};
+use chrono::{Local, Months};
+
+use trailcache_core::cache::SUGGESTED_DEPARTED_RETENTION_MONTHS;
+
+use crate::export::open_cache;
+
+pub fn run(args: &[String]) -> Result<()> {
+    if !args.is_empty() {
+        bail!("Usage: trailcache retention");
+    }
+    let (config, cache) = open_cache()?;
+    let policy = &config.retention;
+
+    match policy.departed_scout_months {
+        Some(months) => println!("Departed scouts' data is purged {} months after they leave the roster.", months),
+        None => println!(
+            "Departed scouts' data is kept indefinitely. To purge it, set \"retention\": {{ \"departed_scout_months\": {} }} in the config.",
+            SUGGESTED_DEPARTED_RETENTION_MONTHS
+        ),
+    }
+    println!("Exports use the {} profile unless another is chosen.", config.export_profile);
+
+    let departures = cache.load_departures()?.map(|c| c.data).unwrap_or_default();
+    if !departures.scouts.is_empty() {
+        println!("\nWaiting to be purged:");
+        for departure in departures.scouts.values() {
+            let due = policy
+                .departed_scout_months
+                .and_then(|m| departure.left_on.checked_add_months(Months::new(m)))
+                .map(|d| d.format("%Y-%m-%d").to_string())
+                .unwrap_or_else(|| "never".to_string());
+            println!("  {} (left {}, purge on {})", departure.name, departure.left_on.format("%Y-%m-%d"), due);
+        }
+    }
+
+    let audit = cache.load_retention_audit()?.map(|c| c.data).unwrap_or_default();
+    if audit.purges.is_empty() {
+        println!("\nNothing has been purged.");
+    } else {
+        println!("\nPurged:");
+        for record in &audit.purges {
+            println!(
+                "  {} {} (left {}): {}",
+                record.purged_on.format("%Y-%m-%d"),
+                record.name,
+                record.left_on.format("%Y-%m-%d"),
+                record.removed.join(", ")
+            );
+        }
+    }
+
+    let today = Local::now().date_naive();
+    if let Some(cutoff) = policy.purge_cutoff(today) {
+        let overdue = departures.due(cutoff).len();
+        if overdue > 0 {
+            println!("\n{} departed scouts will be purged at the next roster refresh.", overdue);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,87 @@
+//! List selection that follows the selected item rather than its row.
+//!
+//! A `ListSelection` holds the row index used for rendering and navigation.
+//! Before a list is re-sorted or its data replaced, `anchor` records the stable
+//! key of the selected item; `restore` then moves the index to wherever that
+//! item ended up, or clamps it if the item is gone. Keys may be optional: an
+//! item whose key is `None` is never anchored or matched.
+
+#[derive(Debug, Clone)]
+pub struct ListSelection<K> {
+    /// Row of the selected item in the list as currently sorted and filtered
+    pub index: usize,
+    anchor: Option<K>,
+}
+
+impl<K> Default for ListSelection<K> {
+    fn default() -> Self {
+        Self { index: 0, anchor: None }
+    }
+}
+
+impl<K: PartialEq> ListSelection<K> {
+    /// Remember the key of the selected item before the list changes.
+    pub fn anchor<I: Into<Option<K>>>(&mut self, keys: impl IntoIterator<Item = I>) {
+        self.anchor = keys.into_iter().nth(self.index).and_then(Into::into);
+    }
+
+    /// Reselect the anchored item in the changed list.
+    ///
+    /// Falls back to clamping the current index when nothing was anchored or
+    /// the item no longer exists.
+    pub fn restore<I: Into<Option<K>>>(&mut self, keys: impl IntoIterator<Item = I>) {
+        let anchor = self.anchor.take();
+        let mut len = 0;
+        for (i, key) in keys.into_iter().enumerate() {
+            if anchor.is_some() && anchor == key.into() {
+                self.index = i;
+                return;
+            }
+            len = i + 1;
+        }
+        self.clamp(len);
+    }
+
+    /// Keep the index within a list of `len` items.
+    pub fn clamp(&mut self, len: usize) {
+        self.index = self.index.min(len.saturating_sub(1));
+    }
+
+    /// Select the first row and forget any anchor.
+    pub fn reset(&mut self) {
+        self.index = 0;
+        self.anchor = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_restore_follows_item_after_resort() {
+        let mut sel = ListSelection { index: 1, ..Default::default() };
+        sel.anchor(["a", "b", "c"]);
+        sel.restore(["c", "a", "b"]);
+        assert_eq!(sel.index, 2);
+    }
+
+    #[test]
+    fn test_restore_clamps_when_item_removed() {
+        let mut sel = ListSelection { index: 2, ..Default::default() };
+        sel.anchor([1, 2, 3]);
+        sel.restore([1, 2]);
+        assert_eq!(sel.index, 1);
+
+        sel.restore(Vec::<i32>::new());
+        assert_eq!(sel.index, 0);
+    }
+
+    #[test]
+    fn test_missing_key_is_not_anchored() {
+        let mut sel: ListSelection<&str> = ListSelection { index: 1, ..Default::default() };
+        sel.anchor([Some("a"), None, Some("c")]);
+        sel.restore([None, Some("a"), Some("c")]);
+        assert_eq!(sel.index, 1, "clamped, not moved to the other keyless row");
+    }
+}
@@ -0,0 +1,240 @@
+//! Bulk correction of requirement completion dates.
+//!
+//! The leader types the wrong date, which finds every cached rank
+//! requirement completed on it, then the right one. `correct_dates` records
+//! the selected completions again in the background, and `finish` updates
+//! the cached requirements that were saved and keeps every attempt in the
+//! correction log.
+
+use chrono::NaiveDate;
+use tokio::sync::mpsc;
+use tracing::warn;
+
+use trailcache_core::api::ScoutingApi;
+use trailcache_core::cache::CacheManager;
+use trailcache_core::models::{CorrectionItem, CorrectionRecord, DateCorrection, DateCorrectionLog, RankProgress, RankRequirement};
+
+use super::{send_result, RefreshResult};
+use crate::store::Store;
+
+/// The date correction overlay, and the corrections already sent.
+#[derive(Default)]
+pub struct DateCorrectionState {
+    /// Completions found, once the wrong date is typed
+    pub correction: Option<DateCorrection>,
+    /// Wrong or corrected date being typed
+    pub input: Option<String>,
+    pub selection: usize,
+    /// Corrections already sent, for the audit log
+    pub log: DateCorrectionLog,
+    /// Corrections waiting on Scoutbook
+    pub pending: usize,
+}
+
+/// Corrections to send: each item completed `from` is recorded again `to`.
+pub struct CorrectionBatch {
+    pub items: Vec<CorrectionItem>,
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+}
+
+impl DateCorrectionState {
+    /// Start over, asking for the wrong date.
+    pub fn restart(&mut self) {
+        self.correction = None;
+        self.input = Some(String::new());
+        self.selection = 0;
+    }
+
+    /// Take the typed date: the wrong date finds the completions recorded
+    /// on it with `find`, then the corrected date is asked for. Returns what
+    /// to tell the user if the date can't be used.
+    pub fn take_input(&mut self, find: impl FnOnce(NaiveDate) -> DateCorrection) -> Option<String> {
+        let text = self.input.take()?;
+        let Ok(date) = NaiveDate::parse_from_str(text.trim(), "%Y-%m-%d") else {
+            let message = format!("\"{}\" isn't a date - use YYYY-MM-DD", text.trim());
+            self.input = Some(text);
+            return Some(message);
+        };
+        match self.correction.as_mut() {
+            None => {
+                let correction = find(date);
+                if correction.items.is_empty() {
+                    self.input = Some(text);
+                    return Some(format!("No cached requirements were completed on {}", date.format("%b %-d, %Y")));
+                }
+                self.correction = Some(correction);
+                self.input = Some(String::new());
+            }
+            Some(correction) if correction.from == date => {
+                self.input = Some(text);
+                return Some("The corrected date is the same as the wrong one".to_string());
+            }
+            Some(correction) => correction.to = Some(date),
+        }
+        None
+    }
+
+    /// The selected completions, once the corrected date is known, marked
+    /// as waiting on Scoutbook. Returns what's missing otherwise; None if
+    /// nothing has been looked up.
+    pub fn start_sending(&mut self) -> Option<Result<CorrectionBatch, String>> {
+        let correction = self.correction.as_ref()?;
+        let Some(to) = correction.to else {
+            return Some(Err("Type the corrected date first".to_string()));
+        };
+        let items: Vec<CorrectionItem> = correction.selected().cloned().collect();
+        if items.is_empty() {
+            return Some(Err("Nothing selected".to_string()));
+        }
+        let from = correction.from;
+        self.correction = None;
+        self.pending = items.len();
+        Some(Ok(CorrectionBatch { items, from, to }))
+    }
+
+    /// Log the corrections and update the cached requirements that were
+    /// saved, and `shown` if it's the list on screen. Returns the status message.
+    pub fn finish(
+        &mut self,
+        cache: &CacheManager,
+        mut shown: Option<(i64, i64, &mut [RankRequirement])>,
+        records: Vec<CorrectionRecord>,
+    ) -> String {
+        self.pending = 0;
+        for record in records.iter().filter(|r| r.saved()) {
+            if let Ok(Some(cached)) = cache.load_rank_requirements(record.user_id, record.rank_id) {
+                let mut requirements = cached.data;
+                if let Some(req) = requirements.iter_mut().find(|r| r.id == Some(record.requirement_id)) {
+                    req.mark_completed(record.to);
+                }
+                if let Err(e) = cache.save_rank_requirements(record.user_id, record.rank_id, &requirements) {
+                    warn!(error = %e, "Failed to update cached rank requirements");
+                }
+            }
+            if let Some((_, _, requirements)) =
+                shown.as_mut().filter(|(user_id, rank_id, _)| *user_id == record.user_id && *rank_id == record.rank_id)
+            {
+                if let Some(req) = requirements.iter_mut().find(|r| r.id == Some(record.requirement_id)) {
+                    req.mark_completed(record.to);
+                }
+            }
+        }
+        let saved = records.iter().filter(|r| r.saved()).count();
+        let failed = records.len() - saved;
+        if let Some(record) = records.iter().find(|r| !r.saved()) {
+            warn!(error = ?record.error, failed, "Some date corrections were refused");
+        }
+        self.log.records.extend(records);
+        if let Err(e) = cache.save_date_corrections(&self.log) {
+            warn!(error = %e, "Failed to save date correction log");
+        }
+        if failed == 0 {
+            format!("Corrected {} completion dates", saved)
+        } else {
+            format!("Corrected {} completion dates, {} refused - see the log", saved, failed)
+        }
+    }
+}
+
+/// Every cached rank requirement completed on `date`, by scout and rank.
+/// Requirements not yet cached aren't found; caching for offline use
+/// fetches them all.
+pub fn find_completions_on(store: &Store, cache: &CacheManager, date: NaiveDate) -> DateCorrection {
+    let mut correction = DateCorrection::new(date);
+    for youth in &store.youth {
+        let Some(user_id) = youth.user_id else { continue };
+        let mut ranks: Vec<&RankProgress> = store.all_youth_ranks.get(&user_id).into_iter().flatten().collect();
+        ranks.sort_by_key(|r| r.sort_order());
+        for rank in ranks {
+            if let Ok(Some(cached)) = cache.load_rank_requirements(user_id, rank.rank_id) {
+                correction.add_rank(user_id, &youth.full_name(), rank, &cached.data);
+            }
+        }
+    }
+    correction
+}
+
+/// Record each completion again with the corrected date, one record per
+/// requirement whether Scoutbook took it or not.
+pub async fn correct_dates(tx: mpsc::Sender<RefreshResult>, api: impl ScoutingApi, batch: CorrectionBatch) {
+    let CorrectionBatch { items, from, to } = batch;
+    let mut records = Vec::new();
+    for item in &items {
+        let result = api
+            .complete_rank_requirement(item.user_id, item.rank_id, item.requirement_id, to)
+            .await
+            .map_err(|e| e.to_string());
+        records.push(CorrectionRecord::new(item, from, to, result.err()));
+    }
+    send_result(&tx, RefreshResult::DatesCorrected(records)).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_correct_dates_flow() {
+        let day = |d| NaiveDate::from_ymd_opt(2026, 10, d).unwrap();
+        let dir = std::env::temp_dir().join(format!("trailcache-date-correction-test-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let cache = CacheManager::new_without_encryption(dir.clone()).unwrap();
+        let requirements: Vec<RankRequirement> = serde_json::from_value(serde_json::json!([
+            { "id": 11, "listNumber": "1a", "short": "Pack for a campout", "dateCompleted": "2026-10-01" },
+            { "id": 12, "listNumber": "1b", "short": "Spend a night", "dateCompleted": "2026-10-01" }
+        ]))
+        .unwrap();
+        cache.save_rank_requirements(1, 3, &requirements).unwrap();
+        let item = |requirement_id, requirement: &str| CorrectionItem {
+            user_id: 1,
+            scout: "Sam Ortiz".to_string(),
+            rank_id: 3,
+            rank: "Tenderfoot".to_string(),
+            requirement_id,
+            requirement: requirement.to_string(),
+            selected: true,
+        };
+        let found = |date| DateCorrection { from: date, to: None, items: vec![item(11, "1a"), item(12, "1b")] };
+
+        let mut state = DateCorrectionState::default();
+        state.restart();
+        state.input = Some("Oct 1".to_string());
+        assert_eq!(state.take_input(found).as_deref(), Some("\"Oct 1\" isn't a date - use YYYY-MM-DD"));
+        state.input = Some("2026-10-02".to_string());
+        assert_eq!(
+            state.take_input(DateCorrection::new).as_deref(),
+            Some("No cached requirements were completed on Oct 2, 2026")
+        );
+        assert_eq!(state.input.as_deref(), Some("2026-10-02"), "kept for editing");
+
+        state.input = Some("2026-10-01".to_string());
+        assert_eq!(state.take_input(found), None);
+        assert!(matches!(state.start_sending(), Some(Err(m)) if m == "Type the corrected date first"));
+        state.input = Some("2026-10-01".to_string());
+        assert_eq!(state.take_input(found).as_deref(), Some("The corrected date is the same as the wrong one"));
+        state.input = Some("2026-10-08".to_string());
+        assert_eq!(state.take_input(found), None);
+
+        let batch = state.start_sending().unwrap().unwrap();
+        assert_eq!((batch.items.len(), batch.from, batch.to), (2, day(1), day(8)));
+        assert_eq!(state.pending, 2);
+        assert!(state.correction.is_none());
+
+        // Scoutbook takes one and refuses the other
+        let records = vec![
+            CorrectionRecord::new(&batch.items[0], day(1), day(8), None),
+            CorrectionRecord::new(&batch.items[1], day(1), day(8), Some("403".to_string())),
+        ];
+        let mut shown = requirements.clone();
+        let status = state.finish(&cache, Some((1, 3, shown.as_mut_slice())), records);
+        assert_eq!(status, "Corrected 1 completion dates, 1 refused - see the log");
+        assert_eq!(state.pending, 0);
+        let dates = |reqs: &[RankRequirement]| -> Vec<Option<String>> { reqs.iter().map(|r| r.date_completed.clone()).collect() };
+        let expected = [Some("2026-10-08".to_string()), Some("2026-10-01".to_string())];
+        assert_eq!(dates(&shown), expected);
+        assert_eq!(dates(&cache.load_rank_requirements(1, 3).unwrap().unwrap().data), expected);
+        assert_eq!(cache.load_date_corrections().unwrap().unwrap().data.records.len(), 2);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
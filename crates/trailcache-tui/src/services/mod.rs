@@ -0,0 +1,402 @@
+//! Background work that talks to Scoutbook for the TUI.
+//!
+//! Each task is handed a clone of the app's API client, so tests can run
+//! them against a mock, and reports back through the refresh channel:
+//! fetched data as `RefreshResult::Data` for the store to apply, failures
+//! per dataset so they can be retried, and progress while caching for
+//! offline use. None of it touches `App`, which stays on the UI thread and
+//! applies results as they arrive.
+//!
+//! The changes a leader sends to Scoutbook each have a module holding the
+//! state they need and the handlers for it: `requirements` marks a rank
+//! requirement complete, `patrol_move` moves a scout between patrols, and
+//! `date_correction` fixes completion dates in bulk. `retry` schedules
+//! datasets that failed to refresh for another try.
+
+use std::path::PathBuf;
+use std::sync::{Arc, OnceLock};
+
+use anyhow::Result;
+use futures::stream::{self, FuturesUnordered, StreamExt};
+use tokio::sync::mpsc;
+use tracing::{debug, error, info};
+
+use trailcache_core::api::{ApiClient, ApiError, ApiErrorKind, Backend, DemoApi, ScoutingApi};
+use trailcache_core::cache::{CacheManager, OfflineAudit, OfflineScope};
+use trailcache_core::models::{Adult, CorrectionRecord, Event, LinkedData, RankRequirement, UnitData};
+
+use crate::refresh_queue::{RefreshFocus, RefreshJob, RefreshQueue};
+use crate::store::{RefreshDataset, Update};
+
+pub mod date_correction;
+pub mod patrol_move;
+pub mod requirements;
+pub mod retry;
+
+/// Maximum concurrent API requests for event details.
+/// Limits parallel requests to avoid overwhelming the server or hitting rate limits.
+pub const MAX_CONCURRENT_REQUESTS: usize = 10;
+
+/// Refresh jobs run at once: datasets, or one scout's advancement each.
+const REFRESH_CONCURRENCY: usize = 5;
+
+/// Result types from background refresh tasks.
+///
+/// These variants are sent through an MPSC channel from the background tasks
+/// here back to the main application. Fetched data is applied to the
+/// store; the rest report progress and the outcome of the user's actions.
+pub enum RefreshResult {
+    /// Data fetched from Scoutbook, for the store
+    Data(Box<Update>),
+    /// Signal that all refresh tasks have completed
+    RefreshComplete,
+    /// Progress update for offline caching (current, total, description)
+    CachingProgress(usize, usize, String),
+    /// Offline caching is complete
+    CachingComplete,
+    /// Workbook PDF download finished (badge name, saved path or error message)
+    WorkbookDownloaded(String, std::result::Result<PathBuf, String>),
+    /// A district unit's data was fetched (organization GUID, data or error message)
+    DistrictUnit(String, std::result::Result<Box<UnitData>, String>),
+    /// The linked troop's data was fetched (data or error message)
+    LinkedUnit(std::result::Result<Box<LinkedData>, String>),
+    /// A rank requirement completion was sent (user_id, rank_id, requirement as it was before, error message)
+    RequirementCompleted(i64, i64, Box<RankRequirement>, std::result::Result<(), String>),
+    /// A patrol move was sent (user_id, patrol name and GUID before the move, error message)
+    PatrolMoved(i64, Box<(Option<String>, Option<String>)>, std::result::Result<(), String>),
+    /// A bulk date correction was sent, one record per requirement
+    DatesCorrected(Vec<CorrectionRecord>),
+    /// A single dataset failed to fetch (dataset, error kind, message)
+    DatasetFailed(RefreshDataset, ApiErrorKind, String),
+}
+
+impl RefreshResult {
+    /// The dataset this result delivers, if it is a unit-wide refresh dataset.
+    pub fn dataset(&self) -> Option<RefreshDataset> {
+        match self {
+            RefreshResult::Data(update) => update.dataset(),
+            _ => None,
+        }
+    }
+}
+
+impl From<Update> for RefreshResult {
+    fn from(update: Update) -> Self {
+        RefreshResult::Data(Box::new(update))
+    }
+}
+
+/// The demo backend, once `--demo` has switched to it
+static DEMO_API: OnceLock<DemoApi> = OnceLock::new();
+
+/// Serve the bundled demo troop instead of Scoutbook for the rest of the
+/// run. Call this once at startup, before the app is created.
+pub fn use_demo_api() -> Result<()> {
+    let demo = DemoApi::new()?;
+    let _ = DEMO_API.set(demo);
+    Ok(())
+}
+
+/// Whether this run is a demo.
+pub fn is_demo() -> bool {
+    DEMO_API.get().is_some()
+}
+
+/// Create an API client without a token: the demo, or Scoutbook.
+pub fn create_api() -> Result<Backend> {
+    match DEMO_API.get() {
+        Some(demo) => Ok(Backend::Demo(demo.clone())),
+        None => Ok(Backend::Live(ApiClient::new()?)),
+    }
+}
+
+/// Helper to send refresh results, logging any channel errors
+pub async fn send_result(tx: &mpsc::Sender<RefreshResult>, result: RefreshResult) {
+    if let Err(e) = tx.send(result).await {
+        error!(error = %e, "Failed to send refresh result - channel closed");
+    }
+}
+
+/// Execute the background refresh task.
+///
+/// Runs in a spawned Tokio task. Jobs are taken from a `RefreshQueue` in
+/// priority order, up to `REFRESH_CONCURRENCY` at a time, and each result
+/// is sent back through the MPSC channel as soon as it arrives, so the
+/// visible tab fills in first. Every scout's advancement is queued once
+/// the roster arrives. Sends RefreshComplete when the queue is empty.
+pub async fn refresh_all(
+    tx: mpsc::Sender<RefreshResult>,
+    api: impl ScoutingApi,
+    org_guid: Arc<String>,
+    user_id: i64,
+    focus: RefreshFocus,
+) {
+    info!("Background refresh task started");
+
+    let mut queue = RefreshQueue::new(focus);
+    let mut running = FuturesUnordered::new();
+    loop {
+        while running.len() < REFRESH_CONCURRENCY {
+            let Some(job) = queue.pop() else { break };
+            running.push(run_refresh_job(&tx, &api, job, &org_guid, user_id));
+        }
+        let Some(youth_user_ids) = running.next().await else { break };
+        for id in youth_user_ids {
+            queue.push(RefreshJob::Scout(id));
+        }
+    }
+
+    info!("Background refresh complete");
+    send_result(&tx, RefreshResult::RefreshComplete).await;
+}
+
+/// Run one refresh job. Returns the roster's user IDs when the job
+/// fetched it, so their advancement can be queued.
+async fn run_refresh_job(
+    tx: &mpsc::Sender<RefreshResult>,
+    api: &impl ScoutingApi,
+    job: RefreshJob,
+    org_guid: &str,
+    user_id: i64,
+) -> Vec<i64> {
+    match job {
+        RefreshJob::Dataset(dataset) => fetch_dataset(tx, api, dataset, org_guid, user_id).await,
+        RefreshJob::Scout(id) => {
+            fetch_youth_advancement(tx, api, id).await;
+            Vec::new()
+        }
+    }
+}
+
+/// Execute offline caching using the shared core function.
+/// Delegates all fetching + caching to `trailcache_core::cache::cache_scoped_for_offline`
+/// (or `cache_missing_for_offline` when an audit is given), forwarding progress
+/// via the refresh channel.
+pub async fn cache_for_offline(
+    tx: mpsc::Sender<RefreshResult>,
+    api: impl ScoutingApi,
+    org_guid: Arc<String>,
+    user_id: i64,
+    cache: CacheManager,
+    scope: OfflineScope,
+    audit: Option<OfflineAudit>,
+) {
+    info!("Offline caching task started");
+
+    let tx_progress = tx.clone();
+    let on_progress = |progress: trailcache_core::cache::CacheProgress| {
+        // Forward progress to the TUI's refresh channel (best-effort)
+        let _ = tx_progress.try_send(RefreshResult::CachingProgress(
+            progress.current as usize,
+            progress.total as usize,
+            progress.description,
+        ));
+    };
+    let result = match audit {
+        Some(audit) => {
+            trailcache_core::cache::cache_missing_for_offline(
+                &api, &cache, &org_guid, user_id, &scope, &audit, on_progress,
+            )
+            .await
+        }
+        None => {
+            trailcache_core::cache::cache_scoped_for_offline(&api, &cache, &org_guid, user_id, &scope, on_progress)
+                .await
+        }
+    };
+
+    match result {
+        Ok(msg) => info!("{}", msg),
+        Err(e) => error!(error = %e, "Offline caching failed"),
+    }
+
+    send_result(&tx, RefreshResult::CachingComplete).await;
+}
+
+/// Fetch rank, merit badge, leadership, and award progress, and activity log totals, for all youth members.
+/// This populates all_youth_ranks and all_youth_badges HashMaps for the Ranks/Badges tabs,
+/// all_youth_awards for the awards catalog, all_youth_activity for OA eligibility, and caches
+/// leadership data for the Leadership tab.
+async fn handle_all_youth_advancement_refresh(
+    tx: &mpsc::Sender<RefreshResult>,
+    api: &impl ScoutingApi,
+    user_ids: &[i64],
+) {
+    if user_ids.is_empty() {
+        return;
+    }
+
+    debug!(count = user_ids.len(), "Fetching ranks, badges, and leadership for all youth");
+
+    // Fetch ranks, badges, and leadership for all youth with limited concurrency
+    stream::iter(user_ids)
+        .for_each_concurrent(REFRESH_CONCURRENCY, |&user_id| fetch_youth_advancement(tx, api, user_id))
+        .await;
+
+    debug!("All youth advancement fetching complete");
+}
+
+/// Fetch one scout's rank, merit badge, leadership, and award progress
+/// and activity log, sending each as it arrives.
+async fn fetch_youth_advancement(tx: &mpsc::Sender<RefreshResult>, api: &impl ScoutingApi, user_id: i64) {
+    if let Ok(ranks) = api.fetch_youth_ranks(user_id).await {
+        send_result(tx, Update::YouthRanks(user_id, ranks).into()).await;
+    }
+    if let Ok(badges) = api.fetch_youth_merit_badges(user_id).await {
+        send_result(tx, Update::YouthMeritBadges(user_id, badges).into()).await;
+    }
+    if let Ok(leadership) = api.fetch_youth_leadership(user_id).await {
+        send_result(tx, Update::YouthLeadership(user_id, leadership).into()).await;
+    }
+    if let Ok(awards) = api.fetch_youth_awards(user_id).await {
+        send_result(tx, Update::YouthAwards(user_id, awards).into()).await;
+    }
+    if let Ok(activity) = api.fetch_youth_activity(user_id).await {
+        send_result(tx, Update::YouthActivity(user_id, activity).into()).await;
+    }
+}
+
+/// Helper to send a successful fetch result, or report the dataset as failed
+/// so that it can be retried on its own.
+async fn send_fetch_result<T, F>(
+    tx: &mpsc::Sender<RefreshResult>,
+    dataset: RefreshDataset,
+    result: Result<T>,
+    wrapper: F,
+) where
+    F: FnOnce(T) -> Update,
+{
+    match result {
+        Ok(data) => {
+            debug!("{} fetched successfully", dataset.label());
+            send_result(tx, wrapper(data).into()).await;
+        }
+        Err(e) => {
+            error!(error = %e, "{} fetch failed", dataset.label());
+            send_result(tx, RefreshResult::DatasetFailed(dataset, ApiError::classify(&e), e.to_string())).await;
+        }
+    }
+}
+
+/// Retry a single dataset that failed during a previous refresh.
+///
+/// Runs in a spawned Tokio task. The outcome is reported through the channel
+/// like any other refresh result: the data variant on success, or
+/// `DatasetFailed` so the next retry can be scheduled.
+pub async fn retry_dataset(
+    tx: mpsc::Sender<RefreshResult>,
+    api: impl ScoutingApi,
+    dataset: RefreshDataset,
+    org_guid: Arc<String>,
+    user_id: i64,
+) {
+    info!(dataset = dataset.label(), "Retrying failed dataset");
+
+    let youth_user_ids = fetch_dataset(&tx, &api, dataset, &org_guid, user_id).await;
+    handle_all_youth_advancement_refresh(&tx, &api, &youth_user_ids).await;
+}
+
+/// Fetch one dataset and send the result, or report it as failed.
+/// Returns the roster's user IDs when the dataset is the youth roster.
+async fn fetch_dataset(
+    tx: &mpsc::Sender<RefreshResult>,
+    api: &impl ScoutingApi,
+    dataset: RefreshDataset,
+    org_guid: &str,
+    user_id: i64,
+) -> Vec<i64> {
+    match dataset {
+        RefreshDataset::Youth => {
+            let res = api.fetch_youth(org_guid).await;
+            let youth_user_ids: Vec<i64> = res
+                .as_ref()
+                .map(|list| list.iter().filter_map(|y| y.user_id).collect())
+                .unwrap_or_default();
+            send_fetch_result(tx, dataset, res, Update::Youth).await;
+            return youth_user_ids;
+        }
+        RefreshDataset::Adults => {
+            let res = api.fetch_adults(org_guid).await.map(Adult::deduplicate);
+            send_fetch_result(tx, dataset, res, Update::Adults).await;
+        }
+        RefreshDataset::Parents => {
+            let res = api.fetch_parents(org_guid).await;
+            send_fetch_result(tx, dataset, res, Update::Parents).await;
+        }
+        RefreshDataset::Patrols => {
+            let res = api.fetch_patrols(org_guid).await;
+            send_fetch_result(tx, dataset, res, Update::Patrols).await;
+        }
+        RefreshDataset::Events => {
+            let res = api.fetch_events(user_id).await;
+            handle_events_refresh(tx, api, res).await;
+        }
+        RefreshDataset::Dashboard => {
+            let res = api.fetch_advancement_dashboard(org_guid).await;
+            send_fetch_result(tx, dataset, res, Update::AdvancementDashboard).await;
+        }
+        RefreshDataset::ReadyToAward => {
+            let res = api.fetch_ready_to_award(org_guid).await;
+            send_fetch_result(tx, dataset, res, Update::ReadyToAward).await;
+        }
+        RefreshDataset::Key3 => {
+            let res = api.fetch_key3(org_guid).await;
+            send_fetch_result(tx, dataset, res, Update::Key3).await;
+        }
+        RefreshDataset::UnitInfo => {
+            let res = api.fetch_unit_pin(org_guid).await;
+            send_fetch_result(tx, dataset, res, Update::UnitPinInfo).await;
+        }
+        RefreshDataset::OrgProfile => {
+            let res = api.fetch_org_profile(org_guid).await;
+            send_fetch_result(tx, dataset, res, Update::OrgProfile).await;
+        }
+        RefreshDataset::Commissioners => {
+            let res = api.fetch_commissioners(org_guid).await;
+            send_fetch_result(tx, dataset, res, Update::Commissioners).await;
+        }
+    }
+    Vec::new()
+}
+
+async fn handle_events_refresh(
+    tx: &mpsc::Sender<RefreshResult>,
+    api: &impl ScoutingApi,
+    events_res: Result<Vec<Event>>,
+) {
+    match events_res {
+        Ok(data) => {
+            info!(count = data.len(), "Events fetched");
+
+            // Extract IDs before sending to avoid cloning the entire events list
+            let event_ids: Vec<i64> = data.iter().map(|e| e.id).collect();
+
+            send_result(tx, Update::Events(data).into()).await;
+
+            // Fetch detailed info for each event with limited concurrency
+            debug!("Fetching event details with max {} concurrent requests...", MAX_CONCURRENT_REQUESTS);
+
+            let tx_clone = tx.clone();
+
+            stream::iter(event_ids)
+                .map(|id| api.fetch_event_detail(id))
+                .buffer_unordered(MAX_CONCURRENT_REQUESTS)
+                .for_each(|result| {
+                    let tx = tx_clone.clone();
+                    async move {
+                        if let Ok(detail) = result {
+                            debug!(event = %detail.name, users = detail.invited_users.len(), "Event detail fetched");
+                            send_result(&tx, Update::EventDetail(detail).into()).await;
+                        }
+                    }
+                })
+                .await;
+
+            debug!("Event details complete");
+        }
+        Err(e) => {
+            error!(error = %e, "Events fetch failed");
+            send_result(tx, RefreshResult::DatasetFailed(RefreshDataset::Events, ApiError::classify(&e), e.to_string())).await;
+        }
+    }
+}
@@ -0,0 +1,121 @@
+//! Moving a scout to another patrol from the scout details.
+//!
+//! The leader picks a patrol in a `PatrolMove`; applying it changes the
+//! roster straight away and `move_to_patrol` sends it to Scoutbook in the
+//! background. `finish_move` puts the scout back if Scoutbook refuses.
+
+use tokio::sync::mpsc;
+use tracing::{info, warn};
+
+use trailcache_core::api::ScoutingApi;
+use trailcache_core::models::{Patrol, Youth};
+
+use super::{send_result, RefreshResult};
+use crate::store::Update;
+
+/// A scout being moved to another patrol, while the leader picks which
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PatrolMove {
+    pub user_id: i64,
+    pub scout: String,
+    /// Patrol the scout is in now, if any
+    pub current: Option<String>,
+    /// Index into the patrols with a GUID
+    pub selection: usize,
+}
+
+/// A move made on the roster, to send to Scoutbook
+pub struct LocalMove {
+    pub user_id: i64,
+    pub patrol_guid: String,
+    /// Patrol name and GUID before the move
+    pub previous: Box<(Option<String>, Option<String>)>,
+}
+
+/// Patrols a scout can be moved into, in the order the picker lists them.
+pub fn patrol_choices(patrols: &[Patrol]) -> Vec<&Patrol> {
+    let mut patrols: Vec<&Patrol> = patrols.iter().filter(|p| p.guid.is_some()).collect();
+    patrols.sort_by_key(|p| p.name.to_lowercase());
+    patrols
+}
+
+impl PatrolMove {
+    /// Open the picker for the scout with `user_id`, on the patrol they're in now.
+    pub fn new(user_id: i64, youth: &Youth, patrols: &[Patrol]) -> Result<Self, String> {
+        let choices = patrol_choices(patrols);
+        if choices.is_empty() {
+            return Err("No patrols loaded - refresh to pick one".to_string());
+        }
+        let current = youth.patrol_name.clone();
+        let selection = choices.iter().position(|p| Some(&p.name) == current.as_ref()).unwrap_or(0);
+        Ok(Self { user_id, scout: youth.short_name(), current, selection })
+    }
+
+    /// Put the scout in the picked patrol on `roster`. Returns the move to
+    /// send with the status to show, or why there's nothing to send.
+    pub fn apply(&self, roster: &mut [Youth], patrols: &[Patrol]) -> Result<(LocalMove, String), String> {
+        let choices = patrol_choices(patrols);
+        let Some((name, guid)) = choices.get(self.selection).and_then(|p| Some((p.name.clone(), p.guid.clone()?))) else {
+            return Err("Pick a patrol first".to_string());
+        };
+        if self.current.as_ref() == Some(&name) {
+            return Err(format!("{} is already in {}", self.scout, name));
+        }
+        let Some(youth) = roster.iter_mut().find(|y| y.user_id == Some(self.user_id)) else {
+            return Err(format!("{} isn't on the roster", self.scout));
+        };
+
+        let previous = Box::new((youth.patrol_name.replace(name.clone()), youth.patrol_guid.replace(guid.clone())));
+        let status = format!("Moving {} to {}...", self.scout, name);
+        Ok((LocalMove { user_id: self.user_id, patrol_guid: guid, previous }, status))
+    }
+}
+
+/// Send the move to Scoutbook, then pick up its roster and patrol member
+/// counts.
+pub async fn move_to_patrol(
+    tx: mpsc::Sender<RefreshResult>,
+    api: impl ScoutingApi,
+    org_guid: Option<String>,
+    local: LocalMove,
+) {
+    let LocalMove { user_id, patrol_guid, previous } = local;
+    let result = api.move_youth_to_patrol(user_id, &patrol_guid).await.map_err(|e| e.to_string());
+    let saved = result.is_ok();
+    send_result(&tx, RefreshResult::PatrolMoved(user_id, previous, result)).await;
+    let (true, Some(org_guid)) = (saved, org_guid) else {
+        return;
+    };
+
+    if let Ok(data) = api.fetch_youth(&org_guid).await {
+        send_result(&tx, Update::Youth(data).into()).await;
+    }
+    if let Ok(data) = api.fetch_patrols(&org_guid).await {
+        send_result(&tx, Update::Patrols(data).into()).await;
+    }
+}
+
+/// Apply Scoutbook's answer for the move of `scout`, putting them back in
+/// their old patrol if it was refused. Returns the status message, or None
+/// if the scout has left the roster since.
+pub fn finish_move(
+    roster: &mut [Youth],
+    user_id: i64,
+    scout: &str,
+    previous: (Option<String>, Option<String>),
+    result: Result<(), String>,
+) -> Option<String> {
+    let youth = roster.iter_mut().find(|y| y.user_id == Some(user_id))?;
+    Some(match result {
+        Ok(()) => {
+            let patrol = youth.patrol_name.clone().unwrap_or_default();
+            info!(user_id, patrol = %patrol, "Scout moved to patrol");
+            format!("Moved {} to {}", scout, patrol)
+        }
+        Err(e) => {
+            warn!(error = %e, user_id, "Failed to move scout to patrol");
+            (youth.patrol_name, youth.patrol_guid) = previous;
+            format!("Could not move {}: {}", scout, e)
+        }
+    })
+}
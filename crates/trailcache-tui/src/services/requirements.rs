@@ -0,0 +1,98 @@
+//! Marking a rank requirement complete from the Ranks tab.
+//!
+//! The leader confirms a `PendingCompletion`, the requirement shows as done
+//! straight away, and `complete_requirement` sends it to Scoutbook in the
+//! background. `finish_completion` then either drops the cached progress so
+//! the refetch replaces it, or puts the requirement back as it was.
+
+use chrono::NaiveDate;
+use tokio::sync::mpsc;
+use tracing::warn;
+
+use trailcache_core::api::ScoutingApi;
+use trailcache_core::cache::CacheManager;
+use trailcache_core::models::RankRequirement;
+use trailcache_core::utils::format::strip_html;
+
+use super::{send_result, RefreshResult};
+use crate::store::Update;
+
+/// A rank requirement a leader is about to mark complete
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingCompletion {
+    pub user_id: i64,
+    pub rank_id: i64,
+    pub requirement_id: i64,
+    pub scout: String,
+    /// Requirement number and text, for the confirmation prompt
+    pub requirement: String,
+}
+
+impl PendingCompletion {
+    /// Ask to complete `req`, or say why it can't be.
+    pub fn new(user_id: i64, rank_id: i64, scout: String, req: &RankRequirement) -> Result<Self, String> {
+        if req.is_completed() {
+            return Err(format!("Requirement {} is already complete", req.number()));
+        }
+        let Some(requirement_id) = req.id else {
+            return Err(format!("Requirement {} can't be recorded from here", req.number()));
+        };
+        Ok(Self { user_id, rank_id, requirement_id, scout, requirement: format!("{} {}", req.number(), strip_html(&req.text())) })
+    }
+}
+
+/// Record the requirement in Scoutbook as completed on `date`, then fetch
+/// what Scoutbook recorded, including a rank it may now show as earned.
+/// `previous` is the requirement as it was, to put back if it's refused.
+pub async fn complete_requirement(
+    tx: mpsc::Sender<RefreshResult>,
+    api: impl ScoutingApi,
+    pending: PendingCompletion,
+    date: NaiveDate,
+    previous: Box<RankRequirement>,
+) {
+    let PendingCompletion { user_id, rank_id, requirement_id, .. } = pending;
+    let result = api.complete_rank_requirement(user_id, rank_id, requirement_id, date).await.map_err(|e| e.to_string());
+    let saved = result.is_ok();
+    send_result(&tx, RefreshResult::RequirementCompleted(user_id, rank_id, previous, result)).await;
+    if !saved {
+        return;
+    }
+
+    if let Ok(data) = api.fetch_rank_requirements(user_id, rank_id).await {
+        send_result(&tx, Update::RankRequirements(user_id, rank_id, data).into()).await;
+    }
+    if let Ok(data) = api.fetch_youth_ranks(user_id).await {
+        send_result(&tx, Update::YouthRanks(user_id, data).into()).await;
+    }
+}
+
+/// Apply Scoutbook's answer for `scout`'s requirement. `shown` is the
+/// requirement list on screen if it's still this rank's, to roll back in.
+/// Returns the status message.
+pub fn finish_completion(
+    cache: &CacheManager,
+    shown: Option<&mut [RankRequirement]>,
+    user_id: i64,
+    rank_id: i64,
+    scout: &str,
+    previous: RankRequirement,
+    result: Result<(), String>,
+) -> String {
+    match result {
+        Ok(()) => {
+            // The cached copies are out of date until the refetch lands
+            if let Err(e) = cache.invalidate_rank_progress(user_id, rank_id) {
+                warn!(error = %e, "Failed to invalidate cached rank progress");
+            }
+            format!("Recorded {} for {}", previous.number(), scout)
+        }
+        Err(e) => {
+            warn!(error = %e, user_id, rank_id, "Failed to record rank requirement");
+            if let Some(req) = shown.into_iter().flatten().find(|r| r.id == previous.id) {
+                *req = previous.clone();
+            }
+            format!("Could not record {} for {}: {}", previous.number(), scout, e)
+        }
+    }
+}
@@ -0,0 +1,179 @@
+//! Automatic retries of datasets that failed to refresh.
+//!
+//! Each failure is recorded here and the next attempt scheduled with
+//! exponential backoff. `App` asks which retries are due on each tick and
+//! runs them with `retry_dataset`; a successful fetch or a full refresh
+//! clears them.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use tracing::{info, warn};
+
+use trailcache_core::api::ApiErrorKind;
+
+use crate::store::RefreshDataset;
+
+/// Delay before the first automatic retry of a dataset that failed to refresh.
+/// Each subsequent retry doubles the delay (5s, 10s, 20s, 40s).
+const RETRY_BASE_DELAY_SECS: u64 = 5;
+
+/// Maximum number of automatic retries for a single failed dataset.
+/// After this many failures the dataset is shown as failed until the next manual refresh.
+pub const MAX_DATASET_RETRIES: u32 = 4;
+
+/// Delay before retry number `attempt` (1-based) using exponential backoff.
+fn retry_delay(attempt: u32) -> Duration {
+    let exponent = attempt.saturating_sub(1).min(16);
+    Duration::from_secs(RETRY_BASE_DELAY_SECS.saturating_mul(1 << exponent))
+}
+
+/// Automatic retry state for a dataset that failed to refresh.
+#[derive(Debug, Clone)]
+pub struct DatasetRetry {
+    /// Number of failures so far (1 after the initial failure)
+    pub failures: u32,
+    /// When the next retry should run, or None once retries are exhausted
+    pub next_attempt_at: Option<Instant>,
+    /// Whether a retry fetch is currently running
+    pub in_flight: bool,
+    /// Classification of the most recent failure
+    pub kind: ApiErrorKind,
+    /// Most recent error message
+    pub last_error: String,
+}
+
+/// Datasets that failed during refresh, each with its retry schedule.
+#[derive(Debug, Default)]
+pub struct DatasetRetries(HashMap<RefreshDataset, DatasetRetry>);
+
+impl DatasetRetries {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Forget `dataset`'s failures, after it fetched or when a retry can't help.
+    pub fn remove(&mut self, dataset: RefreshDataset) {
+        self.0.remove(&dataset);
+    }
+
+    /// Forget every failure, when a full refresh supersedes them.
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    /// Record a failed dataset and schedule its next retry with exponential
+    /// backoff. Failures that a retry cannot fix (forbidden, not found, bad
+    /// format) are recorded without scheduling another attempt. Returns true
+    /// when this failure used up the last retry.
+    pub fn record_failure(&mut self, dataset: RefreshDataset, kind: ApiErrorKind, error: String) -> bool {
+        let retry = self.0.entry(dataset).or_insert(DatasetRetry {
+            failures: 0,
+            next_attempt_at: None,
+            in_flight: false,
+            kind,
+            last_error: String::new(),
+        });
+        retry.failures += 1;
+        retry.in_flight = false;
+        retry.kind = kind;
+        retry.last_error = error;
+
+        if !kind.is_retryable() {
+            retry.next_attempt_at = None;
+            info!(dataset = dataset.label(), ?kind, "Dataset failure is not retryable");
+            false
+        } else if retry.failures > MAX_DATASET_RETRIES {
+            retry.next_attempt_at = None;
+            warn!(dataset = dataset.label(), "Giving up on dataset after repeated failures");
+            true
+        } else {
+            let delay = retry_delay(retry.failures);
+            retry.next_attempt_at = Some(Instant::now() + delay);
+            info!(dataset = dataset.label(), attempt = retry.failures, delay_secs = delay.as_secs(), "Scheduled dataset retry");
+            false
+        }
+    }
+
+    /// Datasets whose retry time has arrived by `now`, marked as running.
+    pub fn take_due(&mut self, now: Instant) -> Vec<RefreshDataset> {
+        let mut due = Vec::new();
+        for (dataset, retry) in &mut self.0 {
+            if !retry.in_flight && retry.next_attempt_at.is_some_and(|at| at <= now) {
+                retry.in_flight = true;
+                due.push(*dataset);
+            }
+        }
+        due
+    }
+
+    /// Summary of datasets in a retry or failed state, for the status bar.
+    /// Returns None when every dataset refreshed successfully; optional
+    /// datasets are retried without being listed.
+    pub fn summary(&self) -> Option<String> {
+        let mut entries: Vec<(&RefreshDataset, &DatasetRetry)> =
+            self.0.iter().filter(|(dataset, _)| !dataset.is_optional()).collect();
+        if entries.is_empty() {
+            return None;
+        }
+        entries.sort_by_key(|(dataset, _)| dataset.label());
+
+        let mut retrying = Vec::new();
+        let mut failed = Vec::new();
+        let mut no_access = Vec::new();
+        for (dataset, retry) in entries {
+            if retry.kind == ApiErrorKind::Forbidden {
+                no_access.push(dataset.label());
+            } else if retry.next_attempt_at.is_some() {
+                retrying.push(format!("{} ({}/{})", dataset.label(), retry.failures, MAX_DATASET_RETRIES));
+            } else {
+                failed.push(dataset.label());
+            }
+        }
+
+        let mut parts = Vec::new();
+        if !retrying.is_empty() {
+            parts.push(format!("Retrying: {}", retrying.join(", ")));
+        }
+        if !failed.is_empty() {
+            parts.push(format!("Failed: {}", failed.join(", ")));
+        }
+        if !no_access.is_empty() {
+            parts.push(format!("Insufficient role: {}", no_access.join(", ")));
+        }
+        Some(parts.join(" | "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_delay_doubles() {
+        assert_eq!(retry_delay(1), Duration::from_secs(5));
+        assert_eq!(retry_delay(2), Duration::from_secs(10));
+        assert_eq!(retry_delay(3), Duration::from_secs(20));
+        assert_eq!(retry_delay(4), Duration::from_secs(40));
+        // Attempt 0 is treated like the first attempt
+        assert_eq!(retry_delay(0), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_optional_dataset_failures_not_reported() {
+        let mut retries = DatasetRetries::default();
+        assert!(!retries.record_failure(RefreshDataset::Commissioners, ApiErrorKind::Server, "500".to_string()));
+        assert!(retries.0[&RefreshDataset::Commissioners].next_attempt_at.is_some(), "still retried");
+        assert_eq!(retries.summary(), None);
+
+        let gave_up: Vec<bool> = (0..=MAX_DATASET_RETRIES)
+            .map(|_| retries.record_failure(RefreshDataset::Youth, ApiErrorKind::Server, "500".to_string()))
+            .collect();
+        assert_eq!(gave_up.iter().filter(|g| **g).count(), 1, "gives up once");
+        assert_eq!(retries.summary().as_deref(), Some("Failed: Youth"));
+
+        let later = Instant::now() + Duration::from_secs(60);
+        assert_eq!(retries.take_due(later), [RefreshDataset::Commissioners]);
+        assert!(retries.take_due(later).is_empty(), "already running");
+    }
+}
@@ -0,0 +1,653 @@
+//! The unit's data, and the updates that change it.
+//!
+//! `Store` holds what's fetched from Scoutbook: rosters, events, the unit
+//! profile, and each scout's advancement. Fetched data arrives as an
+//! `Update`, which `Store::apply` saves to the cache, merges in, and compares
+//! with what was there for the activity feed. Nothing here knows about the
+//! screen, so the CLI and tests can load and update a store without a
+//! running TUI.
+
+use std::collections::HashMap;
+
+use chrono::{NaiveDate, Utc};
+use tracing::{debug, error, info, warn};
+
+use trailcache_core::api::EVENT_LOOKBACK_DAYS;
+use trailcache_core::cache::{CacheAges, CacheManager, CacheTimes};
+use trailcache_core::models::advancement::CounselorInfo;
+use trailcache_core::models::feed::{
+    diff_event_rsvps, diff_merit_badges, diff_new_events, diff_rank_requirements, diff_ranks, diff_roster, feed_name,
+};
+use trailcache_core::models::{
+    ActivityFeed, ActivityLog, Adult, AdvancementDashboard, AttendanceHistory, Award, Commissioner, Event, EventArchive, EventAttendance, EventGuest,
+    FeedEntry, Key3Leaders, LeadershipPosition, MembershipHistory, MeritBadgeProgress, MeritBadgeRequirement, OrgProfile, Parent, Patrol,
+    RankProgress, RankRequirement, ReadyToAward, UnitInfo, UnitStats, UnitStatsInput, Youth,
+};
+
+/// Maximum number of event guest lists to keep in memory.
+/// Limits memory usage while keeping recently viewed events accessible.
+const MAX_EVENT_GUESTS_CACHE_SIZE: usize = 50;
+
+/// A unit-wide dataset fetched by the background refresh.
+///
+/// Used to track which datasets failed and need an automatic retry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RefreshDataset {
+    Youth,
+    Adults,
+    Parents,
+    Patrols,
+    Events,
+    Dashboard,
+    ReadyToAward,
+    Key3,
+    UnitInfo,
+    OrgProfile,
+    Commissioners,
+}
+
+impl RefreshDataset {
+    /// Get the display label for this dataset.
+    pub fn label(&self) -> &'static str {
+        match self {
+            RefreshDataset::Youth => "Youth",
+            RefreshDataset::Adults => "Adults",
+            RefreshDataset::Parents => "Parents",
+            RefreshDataset::Patrols => "Patrols",
+            RefreshDataset::Events => "Events",
+            RefreshDataset::Dashboard => "Dashboard",
+            RefreshDataset::ReadyToAward => "ReadyToAward",
+            RefreshDataset::Key3 => "Key3",
+            RefreshDataset::UnitInfo => "UnitInfo",
+            RefreshDataset::OrgProfile => "OrgProfile",
+            RefreshDataset::Commissioners => "Commissioners",
+        }
+    }
+
+    /// Extras for the Unit tab that a refresh is complete without. They're
+    /// retried like the rest, but their failures aren't reported.
+    pub fn is_optional(&self) -> bool {
+        matches!(
+            self,
+            RefreshDataset::Key3 | RefreshDataset::UnitInfo | RefreshDataset::OrgProfile | RefreshDataset::Commissioners
+        )
+    }
+}
+
+impl RefreshDataset {
+    pub const ALL: [RefreshDataset; 11] = [
+        RefreshDataset::Youth,
+        RefreshDataset::Adults,
+        RefreshDataset::Parents,
+        RefreshDataset::Patrols,
+        RefreshDataset::Events,
+        RefreshDataset::Dashboard,
+        RefreshDataset::ReadyToAward,
+        RefreshDataset::Key3,
+        RefreshDataset::UnitInfo,
+        RefreshDataset::OrgProfile,
+        RefreshDataset::Commissioners,
+    ];
+}
+
+/// Data fetched from Scoutbook, to be applied to the store.
+#[derive(Debug)]
+pub enum Update {
+    /// Youth roster
+    Youth(Vec<Youth>),
+    /// Adult roster
+    Adults(Vec<Adult>),
+    /// Parent contacts
+    Parents(Vec<Parent>),
+    /// Patrol structure
+    Patrols(Vec<Patrol>),
+    /// Calendar events
+    Events(Vec<Event>),
+    /// Detailed event info (RSVP list) for a single event
+    EventDetail(Event),
+    /// Advancement statistics dashboard
+    AdvancementDashboard(AdvancementDashboard),
+    /// Awards ready to be presented
+    ReadyToAward(Vec<ReadyToAward>),
+    /// Guest list for a specific event (event_id, guests)
+    EventGuests(i64, Vec<EventGuest>),
+    /// Rank progress for a specific youth (user_id, ranks)
+    YouthRanks(i64, Vec<RankProgress>),
+    /// Merit badge progress for a specific youth (user_id, badges)
+    YouthMeritBadges(i64, Vec<MeritBadgeProgress>),
+    /// Leadership position history for a specific youth (user_id, positions)
+    YouthLeadership(i64, Vec<LeadershipPosition>),
+    /// Awards for a specific youth (user_id, awards)
+    YouthAwards(i64, Vec<Award>),
+    /// Camping, hiking, and service log totals for a specific youth (user_id, totals)
+    YouthActivity(i64, ActivityLog),
+    /// Requirements for a specific rank (user_id, rank_id, requirements)
+    RankRequirements(i64, i64, Vec<RankRequirement>),
+    /// Requirements for a specific merit badge (user_id, badge_id, requirements, version, counselor)
+    BadgeRequirements(i64, i64, Vec<MeritBadgeRequirement>, Option<String>, Option<CounselorInfo>),
+    /// Key 3 leadership positions (SM, CC, COR)
+    Key3(Key3Leaders),
+    /// Unit PIN information (charter, contact info)
+    UnitPinInfo(UnitInfo),
+    /// Organization profile information
+    OrgProfile(OrgProfile),
+    /// Assigned commissioners for the unit
+    Commissioners(Vec<Commissioner>),
+}
+
+impl Update {
+    /// The dataset this update delivers, if it is a unit-wide refresh dataset.
+    pub fn dataset(&self) -> Option<RefreshDataset> {
+        match self {
+            Update::Youth(_) => Some(RefreshDataset::Youth),
+            Update::Adults(_) => Some(RefreshDataset::Adults),
+            Update::Parents(_) => Some(RefreshDataset::Parents),
+            Update::Patrols(_) => Some(RefreshDataset::Patrols),
+            Update::Events(_) => Some(RefreshDataset::Events),
+            Update::AdvancementDashboard(_) => Some(RefreshDataset::Dashboard),
+            Update::ReadyToAward(_) => Some(RefreshDataset::ReadyToAward),
+            Update::Key3(_) => Some(RefreshDataset::Key3),
+            Update::UnitPinInfo(_) => Some(RefreshDataset::UnitInfo),
+            Update::OrgProfile(_) => Some(RefreshDataset::OrgProfile),
+            Update::Commissioners(_) => Some(RefreshDataset::Commissioners),
+            _ => None,
+        }
+    }
+}
+
+/// Domain data fetched from Scoutbook, as last cached or refreshed.
+#[derive(Default)]
+pub struct Store {
+    pub youth: Vec<Youth>,
+    pub adults: Vec<Adult>,
+    pub parents: Vec<Parent>,
+    pub patrols: Vec<Patrol>,
+    pub events: Vec<Event>,
+    /// Events older than the refresh window, kept for attendance history
+    pub event_archive: EventArchive,
+    /// Scout attendance at past events, kept across refreshes
+    pub event_attendance: EventAttendance,
+    /// Joins and departures by month, for the membership forecast
+    pub membership: MembershipHistory,
+    pub advancement_dashboard: AdvancementDashboard,
+    pub ready_to_award: Vec<ReadyToAward>,
+    pub event_guests: HashMap<i64, Vec<EventGuest>>,
+    /// Tracks event_guests keys in access order (oldest first) for LRU eviction
+    event_guests_order: Vec<i64>,
+
+    /// Merit badge progress for all youth, keyed by user_id
+    pub all_youth_badges: HashMap<i64, Vec<MeritBadgeProgress>>,
+
+    /// Special awards for all youth, keyed by user_id
+    pub all_youth_awards: HashMap<i64, Vec<Award>>,
+
+    /// Camping, hiking, and service log totals for all youth, keyed by user_id
+    pub all_youth_activity: HashMap<i64, ActivityLog>,
+
+    /// Rank progress for all youth, keyed by user_id
+    pub all_youth_ranks: HashMap<i64, Vec<RankProgress>>,
+
+    /// Leadership history for all youth, keyed by user_id
+    pub all_youth_leadership: HashMap<i64, Vec<LeadershipPosition>>,
+
+    /// Unit-wide aggregates, recomputed after each refresh
+    pub unit_stats: UnitStats,
+
+    // Unit info (domain types)
+    pub key3: Key3Leaders,
+    pub unit_info: Option<UnitInfo>,
+    pub org_profile: OrgProfile,
+    pub commissioners: Vec<Commissioner>,
+
+    // Cache ages for status bar
+    pub cache_ages: CacheAges,
+    /// When each unit-wide dataset was cached, for the freshness warnings
+    pub cache_times: CacheTimes,
+}
+
+impl Store {
+    /// Read everything cached into the store. Returns the refresh datasets
+    /// that were found, so they can be shown as loaded.
+    pub fn load(&mut self, cache: &CacheManager, today: NaiveDate) -> Vec<RefreshDataset> {
+        info!(cache_dir = ?cache.cache_dir(), "Loading from cache");
+        let mut loaded = Vec::new();
+
+        match cache.load_youth() {
+            Ok(Some(cached)) => {
+                info!(count = cached.data.len(), age = %cached.age_display(), "Loaded youth from cache");
+                self.youth = cached.data;
+                loaded.push(RefreshDataset::Youth);
+            }
+            Ok(None) => {
+                info!("No youth cache found");
+            }
+            Err(e) => {
+                warn!(error = %e, "Failed to load youth cache");
+            }
+        }
+
+        if let Ok(Some(cached)) = cache.load_adults() {
+            info!(count = cached.data.len(), "Loaded adults from cache");
+            self.adults = cached.data;
+            loaded.push(RefreshDataset::Adults);
+        }
+
+        if let Ok(Some(cached)) = cache.load_parents() {
+            self.parents = cached.data;
+        }
+
+        if let Ok(Some(cached)) = cache.load_patrols() {
+            self.patrols = cached.data;
+        }
+
+        if let Ok(Some(cached)) = cache.load_events() {
+            self.events = cached.data;
+            loaded.push(RefreshDataset::Events);
+        }
+
+        // Load persisted guest lists for known events
+        let event_ids: Vec<i64> = self.events.iter().map(|e| e.id).collect();
+        for event_id in event_ids {
+            if let Ok(Some(cached)) = cache.load_event_guests(event_id) {
+                self.remember_event_guests(event_id, cached.data);
+            }
+        }
+
+        if let Ok(Some(cached)) = cache.load_advancement_dashboard() {
+            self.advancement_dashboard = cached.data;
+        }
+
+        if let Ok(Some(cached)) = cache.load_ready_to_award() {
+            self.ready_to_award = cached.data;
+        }
+
+        if let Ok(Some(cached)) = cache.load_unit_info() {
+            self.unit_info = Some(cached.data.with_computed_fields());
+            loaded.push(RefreshDataset::UnitInfo);
+        }
+
+        if let Ok(Some(cached)) = cache.load_key3() {
+            self.key3 = cached.data;
+            loaded.push(RefreshDataset::Key3);
+        }
+
+        if let Ok(Some(cached)) = cache.load_org_profile() {
+            self.org_profile = cached.data;
+        }
+
+        if let Ok(Some(cached)) = cache.load_commissioners() {
+            self.commissioners = cached.data;
+        }
+
+        // Load per-youth ranks and badges for Ranks/Badges tabs
+        for youth in &self.youth {
+            if let Some(user_id) = youth.user_id {
+                if let Ok(Some(cached)) = cache.load_youth_ranks(user_id) {
+                    self.all_youth_ranks.insert(user_id, cached.data);
+                }
+                if let Ok(Some(cached)) = cache.load_youth_merit_badges(user_id) {
+                    self.all_youth_badges.insert(user_id, cached.data);
+                }
+                if let Ok(Some(cached)) = cache.load_youth_awards(user_id) {
+                    self.all_youth_awards.insert(user_id, cached.data);
+                }
+                if let Ok(Some(cached)) = cache.load_youth_activity(user_id) {
+                    self.all_youth_activity.insert(user_id, cached.data);
+                }
+                if let Ok(Some(cached)) = cache.load_youth_leadership(user_id) {
+                    self.all_youth_leadership.insert(user_id, cached.data);
+                }
+            }
+        }
+
+        if let Ok(Some(cached)) = cache.load_event_archive() {
+            self.event_archive = cached.data;
+        }
+        if let Ok(Some(cached)) = cache.load_event_attendance() {
+            self.event_attendance = cached.data;
+        }
+        self.record_event_attendance(cache, today);
+        if let Ok(Some(cached)) = cache.load_membership() {
+            self.membership = cached.data;
+        }
+
+        self.cache_ages = cache.get_cache_ages();
+        self.cache_times = cache.cache_times();
+        loaded
+    }
+
+    /// Save `update` to the cache and merge it in. Returns what changed
+    /// since the last refresh, for the activity feed.
+    pub fn apply(&mut self, update: Update, cache: &CacheManager, today: NaiveDate) -> Vec<FeedEntry> {
+        let mut activity = Vec::new();
+        match update {
+            Update::Youth(data) => {
+                info!(count = data.len(), "Processing Youth result - saving to cache");
+                match cache.save_youth(&data) {
+                    Ok(()) => info!("Youth cache saved successfully"),
+                    Err(e) => error!(error = %e, "Failed to cache youth data"),
+                }
+                if !self.youth.is_empty() {
+                    activity = diff_roster(&self.youth, &data, today);
+                }
+                self.membership.record(&self.youth, &data, today);
+                if let Err(e) = cache.save_membership(&self.membership) {
+                    warn!(error = %e, "Failed to save membership history");
+                }
+                self.youth = data;
+                self.cache_ages = cache.get_cache_ages();
+                self.cache_times.youth = Some(Utc::now());
+            }
+            Update::Adults(data) => {
+                // Data is already deduplicated by the refresh
+                info!(count = data.len(), "Processing Adults result - saving to cache");
+                match cache.save_adults(&data) {
+                    Ok(()) => info!("Adults cache saved successfully"),
+                    Err(e) => error!(error = %e, "Failed to cache adults data"),
+                }
+                self.adults = data;
+                self.cache_times.adults = Some(Utc::now());
+            }
+            Update::Parents(data) => {
+                if let Err(e) = cache.save_parents(&data) {
+                    warn!(error = %e, "Failed to cache parents data");
+                }
+                self.parents = data;
+                self.cache_times.parents = Some(Utc::now());
+            }
+            Update::Patrols(data) => {
+                if let Err(e) = cache.save_patrols(&data) {
+                    warn!(error = %e, "Failed to cache patrols data");
+                }
+                self.patrols = data;
+            }
+            Update::Events(data) => {
+                if let Err(e) = cache.save_events(&data) {
+                    warn!(error = %e, "Failed to cache events data");
+                }
+                activity = diff_event_rsvps(&self.events, &data, today);
+                activity.extend(diff_new_events(&self.events, &data, today));
+                let cutoff = today - chrono::Duration::days(EVENT_LOOKBACK_DAYS);
+                if self.event_archive.absorb(&self.events, &data, cutoff) > 0 {
+                    if let Err(e) = cache.save_event_archive(&self.event_archive) {
+                        warn!(error = %e, "Failed to save event archive");
+                    }
+                }
+                self.events = data;
+                self.record_event_attendance(cache, today);
+                self.cache_ages = cache.get_cache_ages();
+                self.cache_times.events = Some(Utc::now());
+            }
+            Update::EventDetail(event) => {
+                activity = diff_event_rsvps(&self.events, std::slice::from_ref(&event), today);
+                if let Some(existing) = self.events.iter_mut().find(|e| e.id == event.id) {
+                    *existing = event;
+                }
+                if let Err(e) = cache.save_events(&self.events) {
+                    warn!(error = %e, "Failed to cache event detail");
+                }
+                self.record_event_attendance(cache, today);
+            }
+            Update::AdvancementDashboard(data) => {
+                if let Err(e) = cache.save_advancement_dashboard(&data) {
+                    warn!(error = %e, "Failed to cache advancement dashboard");
+                }
+                self.advancement_dashboard = data;
+            }
+            Update::ReadyToAward(data) => {
+                if let Err(e) = cache.save_ready_to_award(&data) {
+                    warn!(error = %e, "Failed to cache ready to award");
+                }
+                self.ready_to_award = data;
+            }
+            Update::Key3(data) => {
+                if let Err(e) = cache.save_key3(&data) {
+                    warn!(error = %e, "Failed to cache key3");
+                }
+                self.key3 = data;
+            }
+            Update::UnitPinInfo(data) => {
+                if let Err(e) = cache.save_unit_info(&data) {
+                    warn!(error = %e, "Failed to cache unit pin info");
+                }
+                self.unit_info = Some(data.with_computed_fields());
+            }
+            Update::OrgProfile(data) => {
+                if let Err(e) = cache.save_org_profile(&data) {
+                    warn!(error = %e, "Failed to cache org profile");
+                }
+                self.org_profile = data;
+            }
+            Update::Commissioners(data) => {
+                if let Err(e) = cache.save_commissioners(&data) {
+                    warn!(error = %e, "Failed to cache commissioners");
+                }
+                self.commissioners = data;
+            }
+            Update::EventGuests(event_id, data) => {
+                if let Err(e) = cache.save_event_guests(event_id, &data) {
+                    warn!(error = %e, event_id, "Failed to cache event guests");
+                }
+                self.remember_event_guests(event_id, data);
+            }
+            Update::YouthRanks(user_id, data) => {
+                if let Err(e) = cache.save_youth_ranks(user_id, &data) {
+                    warn!(error = %e, "Failed to cache youth ranks");
+                }
+                if let (Some(old), Some(name)) = (self.all_youth_ranks.get(&user_id), self.feed_name(user_id)) {
+                    activity = diff_ranks(user_id, &name, old, &data, today);
+                }
+                self.all_youth_ranks.insert(user_id, data);
+            }
+            Update::YouthMeritBadges(user_id, data) => {
+                if let Err(e) = cache.save_youth_merit_badges(user_id, &data) {
+                    warn!(error = %e, "Failed to cache youth merit badges");
+                }
+                if let (Some(old), Some(name)) = (self.all_youth_badges.get(&user_id), self.feed_name(user_id)) {
+                    activity = diff_merit_badges(user_id, &name, old, &data, today);
+                }
+                self.all_youth_badges.insert(user_id, data);
+            }
+            Update::YouthLeadership(user_id, mut data) => {
+                LeadershipPosition::sort_for_display(&mut data);
+                if let Err(e) = cache.save_youth_leadership(user_id, &data) {
+                    warn!(error = %e, "Failed to cache youth leadership");
+                }
+                self.all_youth_leadership.insert(user_id, data);
+            }
+            Update::YouthAwards(user_id, mut data) => {
+                Award::sort_for_display(&mut data);
+                if let Err(e) = cache.save_youth_awards(user_id, &data) {
+                    warn!(error = %e, "Failed to cache youth awards");
+                }
+                self.all_youth_awards.insert(user_id, data);
+            }
+            Update::YouthActivity(user_id, data) => {
+                if let Err(e) = cache.save_youth_activity(user_id, &data) {
+                    warn!(error = %e, "Failed to cache youth activity log");
+                }
+                self.all_youth_activity.insert(user_id, data);
+            }
+            Update::RankRequirements(user_id, rank_id, data) => {
+                activity = self.requirement_activity(cache, user_id, rank_id, &data, today);
+                if let Err(e) = cache.save_rank_requirements(user_id, rank_id, &data) {
+                    warn!(error = %e, "Failed to cache rank requirements");
+                }
+            }
+            Update::BadgeRequirements(user_id, badge_id, data, version, counselor) => {
+                if let Err(e) = cache.save_badge_requirements(user_id, badge_id, &data, &version) {
+                    warn!(error = %e, "Failed to cache badge requirements");
+                }
+                // The badge list doesn't always name the counselor; fill it in for the counselor view
+                if let (Some(counselor), Some(badges)) = (counselor, self.all_youth_badges.get_mut(&user_id)) {
+                    if let Some(badge) = badges.iter_mut().find(|b| b.id == badge_id && b.assigned_counselor.is_none()) {
+                        badge.assigned_counselor = Some(counselor);
+                        if let Err(e) = cache.save_youth_merit_badges(user_id, badges) {
+                            warn!(error = %e, "Failed to cache merit badges");
+                        }
+                    }
+                }
+            }
+        }
+        activity
+    }
+
+    /// Recompute the unit-wide aggregates, with the meeting attendance and
+    /// activity feed kept locally.
+    pub fn refresh_unit_stats(&mut self, attendance: &AttendanceHistory, feed: &ActivityFeed, today: NaiveDate) {
+        // Archived events still count toward the past year's attendance
+        let events: Vec<Event> = self.events.iter().chain(&self.event_archive.events).cloned().collect();
+        let input = UnitStatsInput {
+            youth: &self.youth,
+            adults: &self.adults,
+            ranks: &self.all_youth_ranks,
+            badges: &self.all_youth_badges,
+            attendance,
+            events: &events,
+            feed,
+        };
+        self.unit_stats = UnitStats::compute(&input, today);
+    }
+
+    /// Compare freshly fetched rank requirements against the cached copy.
+    fn requirement_activity(
+        &self,
+        cache: &CacheManager,
+        user_id: i64,
+        rank_id: i64,
+        data: &[RankRequirement],
+        today: NaiveDate,
+    ) -> Vec<FeedEntry> {
+        let Ok(Some(old)) = cache.load_rank_requirements(user_id, rank_id) else {
+            return Vec::new();
+        };
+        let rank_name = self
+            .all_youth_ranks
+            .get(&user_id)
+            .and_then(|ranks| ranks.iter().find(|r| r.rank_id == rank_id))
+            .map(|r| r.rank_name.clone());
+        match (rank_name, self.feed_name(user_id)) {
+            (Some(rank_name), Some(name)) => diff_rank_requirements(user_id, &name, &rank_name, &old.data, data, today),
+            _ => Vec::new(),
+        }
+    }
+
+    /// How a scout is named in the activity feed.
+    pub fn feed_name(&self, user_id: i64) -> Option<String> {
+        self.youth.iter().find(|y| y.user_id == Some(user_id)).map(feed_name)
+    }
+
+    /// Add attendance from past cached and archived events to the Attendance
+    /// tab's history, saving it if anything changed.
+    pub fn record_event_attendance(&mut self, cache: &CacheManager, today: NaiveDate) {
+        if self.event_attendance.update(self.events.iter().chain(&self.event_archive.events), today) {
+            if let Err(e) = cache.save_event_attendance(&self.event_attendance) {
+                warn!(error = %e, "Failed to save event attendance");
+            }
+        }
+    }
+
+    /// Drop a departed scout's advancement after the retention policy purged it.
+    pub fn forget_scout(&mut self, user_id: i64) {
+        self.all_youth_ranks.remove(&user_id);
+        self.all_youth_badges.remove(&user_id);
+        self.all_youth_awards.remove(&user_id);
+        self.all_youth_activity.remove(&user_id);
+        self.all_youth_leadership.remove(&user_id);
+    }
+
+    /// Store an event's guest list in the in-memory LRU.
+    pub fn remember_event_guests(&mut self, event_id: i64, data: Vec<EventGuest>) {
+        // LRU eviction: remove oldest entries if cache is at capacity
+        if self.event_guests.len() >= MAX_EVENT_GUESTS_CACHE_SIZE
+            && !self.event_guests.contains_key(&event_id)
+        {
+            // Remove oldest half based on access order
+            let evict_count = MAX_EVENT_GUESTS_CACHE_SIZE / 2;
+            let to_remove: Vec<_> = self.event_guests_order.drain(..evict_count).collect();
+            for key in &to_remove {
+                self.event_guests.remove(key);
+            }
+            debug!(
+                evicted = to_remove.len(),
+                "Evicted oldest event guests cache entries"
+            );
+        }
+
+        // Update access order: remove existing entry if present, then add to end
+        if let Some(pos) = self.event_guests_order.iter().position(|&id| id == event_id) {
+            self.event_guests_order.remove(pos);
+        }
+        self.event_guests_order.push(event_id);
+        self.event_guests.insert(event_id, data);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scout(user_id: i64, first: &str) -> Youth {
+        serde_json::from_value(serde_json::json!({
+            "userId": user_id,
+            "firstName": first,
+            "lastName": "Ortiz",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_apply_updates_without_tui() {
+        let dir = std::env::temp_dir().join(format!("trailcache-store-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let cache = CacheManager::new_without_encryption(dir.clone()).unwrap();
+        let today = NaiveDate::from_ymd_opt(2026, 10, 15).unwrap();
+        let mut store = Store::default();
+
+        let update = Update::Youth(vec![scout(1, "Sam"), scout(2, "Ana")]);
+        assert_eq!(update.dataset(), Some(RefreshDataset::Youth));
+        assert!(store.apply(update, &cache, today).is_empty(), "nothing to compare the first roster with");
+        assert_eq!(store.youth.len(), 2);
+        assert!(store.cache_times.youth.is_some());
+
+        let activity = store.apply(Update::Youth(vec![scout(1, "Sam")]), &cache, today);
+        assert_eq!(activity.len(), 1, "Ana left the roster");
+
+        assert_eq!(Update::YouthActivity(1, ActivityLog::default()).dataset(), None);
+        store.apply(Update::YouthActivity(1, ActivityLog::default()), &cache, today);
+        store.forget_scout(1);
+        assert!(store.all_youth_activity.is_empty());
+
+        // What was saved is there for the next session
+        let mut reloaded = Store::default();
+        assert_eq!(reloaded.load(&cache, today), [RefreshDataset::Youth]);
+        assert_eq!(reloaded.youth.len(), 1);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_event_guests_lru_properties() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(1272);
+        for _ in 0..50 {
+            let mut store = Store::default();
+            let ids = rng.gen_range(1..MAX_EVENT_GUESTS_CACHE_SIZE as i64 * 3);
+            for _ in 0..200 {
+                let id = rng.gen_range(-2..ids);
+                store.remember_event_guests(id, Vec::new());
+
+                assert!(store.event_guests.len() <= MAX_EVENT_GUESTS_CACHE_SIZE);
+                assert_eq!(store.event_guests_order.last(), Some(&id), "latest is most recent");
+                let mut order = store.event_guests_order.clone();
+                order.sort_unstable();
+                order.dedup();
+                assert_eq!(order.len(), store.event_guests_order.len(), "an event is listed once");
+                assert!(order.iter().all(|id| store.event_guests.contains_key(id)));
+                assert_eq!(order.len(), store.event_guests.len(), "every cached event is in the order");
+            }
+        }
+    }
+}
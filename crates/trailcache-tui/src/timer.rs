@@ -0,0 +1,189 @@
+//! Countdown and meeting agenda timers for the Timers overlay.
+//!
+//! Timers keep running while the overlay is closed. Times are computed from
+//! `Instant`s passed in by the caller so they can be tested without sleeping.
+
+use std::time::{Duration, Instant};
+
+use trailcache_core::models::AgendaItem;
+
+/// Quick countdown presets: label and minutes
+pub const TIMER_PRESETS: [(&str, u64); 4] = [("Game", 5), ("Patrol", 15), ("Skills", 20), ("Board of review", 30)];
+
+/// A stopwatch that can be paused and resumed.
+#[derive(Debug, Clone, Default)]
+pub struct Stopwatch {
+    running_since: Option<Instant>,
+    banked: Duration,
+}
+
+impl Stopwatch {
+    pub fn is_running(&self) -> bool {
+        self.running_since.is_some()
+    }
+
+    pub fn elapsed(&self, now: Instant) -> Duration {
+        self.banked + self.running_since.map(|t| now.saturating_duration_since(t)).unwrap_or_default()
+    }
+
+    pub fn toggle(&mut self, now: Instant) {
+        match self.running_since.take() {
+            Some(since) => self.banked += now.saturating_duration_since(since),
+            None => self.running_since = Some(now),
+        }
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// A countdown from a set length.
+#[derive(Debug, Clone)]
+pub struct Countdown {
+    pub label: String,
+    pub length: Duration,
+    pub clock: Stopwatch,
+    /// Set once the finish has been announced, so it rings only once
+    pub announced: bool,
+}
+
+impl Countdown {
+    pub fn new(label: impl Into<String>, minutes: u64) -> Self {
+        Self { label: label.into(), length: Duration::from_secs(minutes * 60), clock: Stopwatch::default(), announced: false }
+    }
+
+    pub fn remaining(&self, now: Instant) -> Duration {
+        self.length.saturating_sub(self.clock.elapsed(now))
+    }
+
+    pub fn is_finished(&self, now: Instant) -> bool {
+        self.remaining(now).is_zero()
+    }
+
+    /// Lengthen or shorten by whole minutes, keeping at least one minute.
+    pub fn adjust_minutes(&mut self, delta: i64) {
+        let minutes = (self.length.as_secs() / 60) as i64 + delta;
+        self.length = Duration::from_secs(minutes.max(1) as u64 * 60);
+        self.announced = false;
+    }
+
+    pub fn reset(&mut self) {
+        self.clock.reset();
+        self.announced = false;
+    }
+}
+
+/// Runs through the agenda, timing each item separately.
+#[derive(Debug, Clone, Default)]
+pub struct AgendaTimer {
+    pub items: Vec<AgendaItem>,
+    pub current: usize,
+    pub item_clock: Stopwatch,
+    pub meeting_clock: Stopwatch,
+    /// Item whose time-up has been announced
+    pub announced: Option<usize>,
+}
+
+impl AgendaTimer {
+    pub fn new(items: Vec<AgendaItem>) -> Self {
+        Self { items, ..Self::default() }
+    }
+
+    pub fn current_item(&self) -> Option<&AgendaItem> {
+        self.items.get(self.current)
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.meeting_clock.is_running()
+    }
+
+    pub fn toggle(&mut self, now: Instant) {
+        self.meeting_clock.toggle(now);
+        self.item_clock.toggle(now);
+    }
+
+    /// Move to the next item, carrying the running state over.
+    pub fn next(&mut self, now: Instant) {
+        if self.current + 1 < self.items.len() {
+            self.current += 1;
+            self.restart_item(now);
+        }
+    }
+
+    pub fn previous(&mut self, now: Instant) {
+        if self.current > 0 {
+            self.current -= 1;
+            self.restart_item(now);
+        }
+    }
+
+    fn restart_item(&mut self, now: Instant) {
+        let running = self.item_clock.is_running();
+        self.item_clock.reset();
+        if running {
+            self.item_clock.toggle(now);
+        }
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::new(std::mem::take(&mut self.items));
+    }
+
+    /// Time left on the current item; negative seconds when over time.
+    pub fn item_remaining_secs(&self, now: Instant) -> i64 {
+        let planned = self.current_item().map(|i| i.minutes * 60).unwrap_or_default() as i64;
+        planned - self.item_clock.elapsed(now).as_secs() as i64
+    }
+
+    pub fn planned_total(&self) -> Duration {
+        Duration::from_secs(self.items.iter().map(|i| i.minutes * 60).sum())
+    }
+}
+
+/// Format seconds as "M:SS", with a leading "-" when negative.
+pub fn format_clock(secs: i64) -> String {
+    let sign = if secs < 0 { "-" } else { "" };
+    let secs = secs.unsigned_abs();
+    format!("{}{}:{:02}", sign, secs / 60, secs % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use trailcache_core::models::default_agenda;
+
+    #[test]
+    fn test_countdown_pause_and_finish() {
+        let start = Instant::now();
+        let mut countdown = Countdown::new("Game", 1);
+        countdown.clock.toggle(start);
+        assert_eq!(countdown.remaining(start + Duration::from_secs(20)), Duration::from_secs(40));
+
+        countdown.clock.toggle(start + Duration::from_secs(20));
+        assert_eq!(countdown.remaining(start + Duration::from_secs(500)), Duration::from_secs(40), "paused");
+
+        countdown.clock.toggle(start + Duration::from_secs(500));
+        assert!(countdown.is_finished(start + Duration::from_secs(540)));
+
+        countdown.adjust_minutes(-5);
+        assert_eq!(countdown.length, Duration::from_secs(60));
+    }
+
+    #[test]
+    fn test_agenda_item_overrun_and_advance() {
+        let start = Instant::now();
+        let mut agenda = AgendaTimer::new(default_agenda());
+        agenda.toggle(start);
+
+        let later = start + Duration::from_secs(6 * 60);
+        assert_eq!(agenda.item_remaining_secs(later), -60);
+        assert_eq!(format_clock(agenda.item_remaining_secs(later)), "-1:00");
+
+        agenda.next(later);
+        assert_eq!(agenda.current_item().unwrap().title, "Skills instruction");
+        assert_eq!(agenda.item_remaining_secs(later + Duration::from_secs(30)), 20 * 60 - 30);
+        assert_eq!(agenda.meeting_clock.elapsed(later), Duration::from_secs(6 * 60));
+        assert_eq!(agenda.planned_total(), Duration::from_secs(60 * 60));
+    }
+}
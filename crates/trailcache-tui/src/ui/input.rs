@@ -12,11 +12,61 @@ use ratatui::widgets::TableState;
 
 const DOUBLE_CLICK_MS: u128 = 500;
 
+/// Longest card number accepted in the skills matrix
+const MAX_CARD_NUMBER_LENGTH: usize = 20;
+
+/// Longest start date or packing item typed in the event templates view
+const MAX_TEMPLATE_INPUT_LENGTH: usize = 60;
+
+/// Longest to-do typed in the to-do list
+const MAX_TODO_INPUT_LENGTH: usize = 200;
+
+/// Longest title typed in the troop library
+const MAX_LIBRARY_INPUT_LENGTH: usize = 80;
+
+/// Longest item or filter typed in the uniform closet
+const MAX_CLOSET_INPUT_LENGTH: usize = 80;
+
+/// Longest skill or filter typed in the adult skills directory
+const MAX_ADULT_SKILL_INPUT_LENGTH: usize = 80;
+
+/// Longest date typed in the high-adventure screener
+const MAX_TREK_DATE_LENGTH: usize = 10;
+
+/// Longest clinic name, badge, counselor, or date typed in the clinic planner
+const MAX_CLINIC_INPUT_LENGTH: usize = 80;
+
+/// Longest title, beneficiary, or hour entry typed in an Eagle project
+const MAX_PROJECT_INPUT_LENGTH: usize = 100;
+
+/// Longest text typed in the global search palette
+const MAX_PALETTE_INPUT_LENGTH: usize = 60;
+
+/// Longest BSA ID typed in the lookup, with room for spaces and dashes
+const MAX_MEMBER_ID_INPUT_LENGTH: usize = 20;
+
+/// Longest note or action item typed in the minutes editor
+const MAX_MINUTES_INPUT_LENGTH: usize = 200;
+
+/// Longest summary, highlight, or folder path typed in the historian log
+const MAX_HISTORY_INPUT_LENGTH: usize = 200;
+
+/// Longest debrief note typed on the Events tab
+const MAX_DEBRIEF_INPUT_LENGTH: usize = 200;
+const MAX_MEDICATION_INPUT_LENGTH: usize = 60;
+
+/// Longest pack, den, or meeting day typed when linking a den chief
+const MAX_DEN_CHIEF_INPUT_LENGTH: usize = 40;
+
+/// Longest alternate requirement or accommodation note
+const MAX_ACCOMMODATION_INPUT_LENGTH: usize = 120;
+
 use crate::app::{
-    can_add_password_char, can_add_username_char, AdvancementView, App, AppState,
-    EventDetailView, Focus, LoginFocus, ScoutDetailView, Tab, PAGE_SCROLL_SIZE,
+    can_add_password_char, can_add_username_char, App, ClinicField, HistoryField, LoginFocus, MinutesInput, ProjectField,
+    TemplateInput, TrekField,
 };
-use trailcache_core::models::{EventSortColumn, ScoutSortColumn};
+use crate::ui::state::{AdvancementView, AppState, EventDetailView, Focus, ScoutDetailView, Tab};
+use trailcache_core::models::{Certification, CommitteeRole, ConsentForm, EagleSortColumn, EventSortColumn, FeedCategory, OnboardingItem, Paperwork, ScoutSortColumn, TodoSubject, YOUTH_POSITION_PRIORITY};
 
 /// Direction for cycling through views
 enum CycleDirection {
@@ -28,33 +78,35 @@ enum CycleDirection {
 /// Returns the user_id if data fetch was initiated.
 async fn cycle_scout_detail_view(app: &mut App, direction: CycleDirection) {
     // Get user_id before modifying app
-    let user_id = app.get_sorted_youth()
-        .get(app.roster_selection)
-        .and_then(|y| y.user_id);
+    let user_id = app.selected_youth().and_then(|y| y.user_id);
 
     let new_view = match direction {
-        CycleDirection::Forward => match app.scout_detail_view {
+        CycleDirection::Forward => match app.ui.scout_detail_view {
             ScoutDetailView::Details => ScoutDetailView::Ranks,
             ScoutDetailView::Ranks => ScoutDetailView::MeritBadges,
             ScoutDetailView::MeritBadges => ScoutDetailView::Leadership,
             ScoutDetailView::Leadership => ScoutDetailView::Awards,
-            ScoutDetailView::Awards => ScoutDetailView::Details,
+            ScoutDetailView::Awards => ScoutDetailView::Timeline,
+            ScoutDetailView::Timeline => ScoutDetailView::Details,
         },
-        CycleDirection::Backward => match app.scout_detail_view {
-            ScoutDetailView::Details => ScoutDetailView::Awards,
+        CycleDirection::Backward => match app.ui.scout_detail_view {
+            ScoutDetailView::Details => ScoutDetailView::Timeline,
             ScoutDetailView::Ranks => ScoutDetailView::Details,
             ScoutDetailView::MeritBadges => ScoutDetailView::Ranks,
             ScoutDetailView::Leadership => ScoutDetailView::MeritBadges,
             ScoutDetailView::Awards => ScoutDetailView::Leadership,
+            ScoutDetailView::Timeline => ScoutDetailView::Awards,
         },
     };
 
-    app.scout_detail_view = new_view;
+    app.ui.scout_detail_view = new_view;
     // Keep advancement_view in sync for navigation
-    app.advancement_view = match new_view {
+    app.ui.advancement_view = match new_view {
         ScoutDetailView::Ranks => AdvancementView::Ranks,
         ScoutDetailView::MeritBadges => AdvancementView::MeritBadges,
-        ScoutDetailView::Details | ScoutDetailView::Leadership | ScoutDetailView::Awards => app.advancement_view, // unchanged
+        ScoutDetailView::Details | ScoutDetailView::Leadership | ScoutDetailView::Awards | ScoutDetailView::Timeline => {
+            app.ui.advancement_view // unchanged
+        }
     };
     app.viewing_requirements = false;
     // Reset selection when switching views (ranks start at top/Eagle since reversed)
@@ -62,9 +114,10 @@ async fn cycle_scout_detail_view(app: &mut App, direction: CycleDirection) {
     app.advancement_badge_selection = 0;
     app.leadership_selection = 0;
     app.awards_selection = 0;
+    app.timeline_scroll = 0;
 
     // Reset awards state when switching to awards view
-    if new_view == ScoutDetailView::Awards {
+    if matches!(new_view, ScoutDetailView::Awards | ScoutDetailView::Timeline) {
         app.selected_youth_awards.clear();
         app.awards_loaded = false;
     }
@@ -81,175 +134,1273 @@ async fn cycle_scout_detail_view(app: &mut App, direction: CycleDirection) {
             ScoutDetailView::Awards => {
                 app.fetch_youth_awards(uid).await;
             }
-            ScoutDetailView::Details => {}
+            ScoutDetailView::Timeline => {
+                app.fetch_youth_timeline(uid).await;
+            }
+            ScoutDetailView::Details => {}
+        }
+    }
+}
+
+/// Handle keyboard input. Returns true if the app should quit.
+pub async fn handle_input(app: &mut App, key: KeyEvent) -> Result<bool> {
+    // Handle login overlay
+    if matches!(app.ui.state, AppState::LoggingIn) {
+        return handle_login_input(app, key).await;
+    }
+
+    // Handle help overlay
+    if matches!(app.ui.state, AppState::ShowingHelp) {
+        if matches!(key.code, KeyCode::Esc | KeyCode::Char('?') | KeyCode::Char('q')) {
+            app.ui.state = AppState::Normal;
+        }
+        return Ok(false);
+    }
+
+    // Handle quit confirmation
+    if matches!(app.ui.state, AppState::ConfirmingQuit) {
+        match key.code {
+            KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => {
+                if app.caching.in_progress {
+                    app.status_message = Some("Cannot quit while caching in progress. Please wait...".to_string());
+                    app.ui.state = AppState::Normal;
+                    return Ok(false);
+                }
+                app.ui.state = AppState::Quitting;
+                return Ok(true);
+            }
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                app.ui.state = AppState::Normal;
+            }
+            _ => {}
+        }
+        return Ok(false);
+    }
+
+    // Handle offline mode confirmation
+    if matches!(app.ui.state, AppState::ConfirmingOffline) {
+        match key.code {
+            KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => {
+                app.ui.state = AppState::Normal;
+                app.go_offline().await;
+            }
+            KeyCode::Char('f') | KeyCode::Char('F') => {
+                app.ui.state = AppState::Normal;
+                app.fill_offline_gaps().await;
+            }
+            KeyCode::Char('p') | KeyCode::Char('P') => {
+                app.cycle_offline_patrol();
+            }
+            KeyCode::Char('a') | KeyCode::Char('A') => {
+                app.toggle_offline_active_only();
+            }
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                app.ui.state = AppState::Normal;
+                app.offline_audit = None;
+            }
+            _ => {}
+        }
+        return Ok(false);
+    }
+
+    // Handle online mode confirmation (when returning from offline)
+    if matches!(app.ui.state, AppState::ConfirmingOnline) {
+        match key.code {
+            KeyCode::Char('o') | KeyCode::Char('O') => {
+                app.ui.state = AppState::Normal;
+                app.go_online();
+            }
+            _ => {
+                // Any other key stays offline
+                app.ui.state = AppState::Normal;
+            }
+        }
+        return Ok(false);
+    }
+
+    // Handle activity feed panel
+    if matches!(app.ui.state, AppState::ShowingFeed) {
+        let max_scroll = app.filtered_feed().len().saturating_sub(1);
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('A') | KeyCode::Char('q') => {
+                app.ui.state = AppState::Normal;
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                app.feed.scroll = (app.feed.scroll + 1).min(max_scroll);
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                app.feed.scroll = app.feed.scroll.saturating_sub(1);
+            }
+            KeyCode::Char('c') => {
+                app.feed.category = FeedCategory::cycle(app.feed.category);
+                app.feed.scroll = 0;
+            }
+            KeyCode::Char('s') => {
+                app.feed.scout_only = !app.feed.scout_only;
+                app.feed.scroll = 0;
+            }
+            _ => {}
+        }
+        return Ok(false);
+    }
+
+    // Handle adult award checklists
+    if matches!(app.ui.state, AppState::EditingAdultAwards) {
+        let max = app.adult_award_items().len().saturating_sub(1);
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('w') | KeyCode::Char('q') => {
+                app.ui.state = AppState::Normal;
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                app.adult_awards.selection = (app.adult_awards.selection + 1).min(max);
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                app.adult_awards.selection = app.adult_awards.selection.saturating_sub(1);
+            }
+            KeyCode::Char(' ') | KeyCode::Enter => app.toggle_adult_award(),
+            _ => {}
+        }
+        return Ok(false);
+    }
+
+    // Handle new leader onboarding checklist
+    if matches!(app.ui.state, AppState::ShowingOnboarding) {
+        let max = OnboardingItem::ALL.len() - 1;
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('n') | KeyCode::Char('q') => {
+                app.ui.state = AppState::Normal;
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                app.onboarding.selection = (app.onboarding.selection + 1).min(max);
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                app.onboarding.selection = app.onboarding.selection.saturating_sub(1);
+            }
+            KeyCode::Char(' ') | KeyCode::Enter => app.toggle_onboarding_item(),
+            KeyCode::Char('x') => app.finish_onboarding(),
+            _ => {}
+        }
+        return Ok(false);
+    }
+
+    // Handle My Scouts view
+    if matches!(app.ui.state, AppState::ShowingMyScouts) {
+        let max_scroll = super::render::my_scouts_lines(&app.my_scout_summaries()).len().saturating_sub(1);
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('Y') | KeyCode::Char('q') => {
+                app.ui.state = AppState::Normal;
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                app.my_scouts_scroll = (app.my_scouts_scroll + 1).min(max_scroll);
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                app.my_scouts_scroll = app.my_scouts_scroll.saturating_sub(1);
+            }
+            _ => {}
+        }
+        return Ok(false);
+    }
+
+    // Handle skills matrix, including the card number being typed
+    if matches!(app.ui.state, AppState::ShowingSkills) {
+        if let Some(input) = app.skills.card_input.as_mut() {
+            match key.code {
+                KeyCode::Esc => app.skills.card_input = None,
+                KeyCode::Enter => app.finish_card_edit(),
+                KeyCode::Backspace => {
+                    input.pop();
+                }
+                KeyCode::Char(c) if input.len() < MAX_CARD_NUMBER_LENGTH => input.push(c),
+                _ => {}
+            }
+            return Ok(false);
+        }
+        let max = app.skill_rows().len().saturating_sub(1);
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('K') | KeyCode::Char('q') => {
+                app.ui.state = AppState::Normal;
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                app.skills.selection = (app.skills.selection + 1).min(max);
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                app.skills.selection = app.skills.selection.saturating_sub(1);
+            }
+            KeyCode::Char('h') | KeyCode::Left => {
+                app.skills.column = app.skills.column.saturating_sub(1);
+            }
+            KeyCode::Char('l') | KeyCode::Right => {
+                app.skills.column = (app.skills.column + 1).min(Certification::ALL.len() - 1);
+            }
+            KeyCode::Char(' ') => app.toggle_skill(),
+            KeyCode::Char('s') => app.cycle_swim_level(),
+            KeyCode::Char('c') => app.start_card_edit(),
+            KeyCode::Char('f') => app.cycle_skills_filter(),
+            KeyCode::Char('e') => app.export_skills_csv(),
+            KeyCode::Char('i') => app.import_skills_csv(),
+            _ => {}
+        }
+        return Ok(false);
+    }
+
+    // Handle buddy groups being adjusted before export
+    if matches!(app.ui.state, AppState::ShowingBuddies) {
+        let max = app.buddies.groups.rows().len().saturating_sub(1);
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('U') | KeyCode::Char('q') => {
+                app.ui.state = AppState::Normal;
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                app.buddies.selection = (app.buddies.selection + 1).min(max);
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                app.buddies.selection = app.buddies.selection.saturating_sub(1);
+            }
+            KeyCode::Char(' ') | KeyCode::Enter => app.mark_or_swap_buddy(),
+            KeyCode::Char('r') => app.show_buddy_pairs(),
+            KeyCode::Char('e') => app.export_buddy_sheet(),
+            _ => {}
+        }
+        return Ok(false);
+    }
+
+    // Handle the pre-activity checklist
+    if matches!(app.ui.state, AppState::ShowingActivityPlan) {
+        let max = app.activity_plan_items().len().saturating_sub(1);
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('C') | KeyCode::Char('q') => {
+                app.ui.state = AppState::Normal;
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                app.activity_plans.selection = (app.activity_plans.selection + 1).min(max);
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                app.activity_plans.selection = app.activity_plans.selection.saturating_sub(1);
+            }
+            KeyCode::Char(' ') | KeyCode::Enter => app.toggle_activity_plan_item(),
+            _ => {}
+        }
+        return Ok(false);
+    }
+
+    // Confirm before showing the medication log
+    if matches!(app.ui.state, AppState::ConfirmingMedicationAccess) {
+        match key.code {
+            KeyCode::Char('y') | KeyCode::Char('Y') => app.unlock_medication_log(),
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => app.ui.state = AppState::Normal,
+            _ => {}
+        }
+        return Ok(false);
+    }
+
+    // Confirm before recording a rank requirement in Scoutbook
+    if matches!(app.ui.state, AppState::ConfirmingRequirementCompletion) {
+        match key.code {
+            KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => app.complete_pending_requirement(),
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                app.pending_completion = None;
+                app.ui.state = AppState::Normal;
+            }
+            _ => {}
+        }
+        return Ok(false);
+    }
+
+    // Pick the patrol a scout is moving to
+    if matches!(app.ui.state, AppState::MovingToPatrol) {
+        let max = app.patrol_choices().len().saturating_sub(1);
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down => {
+                if let Some(pending) = app.patrol_move.as_mut() {
+                    pending.selection = (pending.selection + 1).min(max);
+                }
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                if let Some(pending) = app.patrol_move.as_mut() {
+                    pending.selection = pending.selection.saturating_sub(1);
+                }
+            }
+            KeyCode::Enter => app.confirm_patrol_move(),
+            KeyCode::Esc | KeyCode::Char('q') => {
+                app.patrol_move = None;
+                app.ui.state = AppState::Normal;
+            }
+            _ => {}
+        }
+        return Ok(false);
+    }
+
+    // Handle the bulk date correction, including a date being typed
+    if matches!(app.ui.state, AppState::CorrectingDates) {
+        if let Some(text) = app.date_correction.input.as_mut() {
+            match key.code {
+                KeyCode::Esc if app.date_correction.correction.is_some() || !app.date_correction.log.records.is_empty() => {
+                    app.date_correction.input = None;
+                }
+                KeyCode::Esc => app.ui.state = AppState::Normal,
+                KeyCode::Enter => app.finish_date_correction_input(),
+                KeyCode::Backspace => {
+                    text.pop();
+                }
+                KeyCode::Char(c) if text.len() < MAX_TREK_DATE_LENGTH => text.push(c),
+                _ => {}
+            }
+            return Ok(false);
+        }
+        let max = app.date_correction.correction.as_ref().map(|c| c.items.len().saturating_sub(1)).unwrap_or(0);
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => {
+                app.date_correction.correction = None;
+                app.ui.state = AppState::Normal;
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                app.date_correction.selection = (app.date_correction.selection + 1).min(max);
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                app.date_correction.selection = app.date_correction.selection.saturating_sub(1);
+            }
+            KeyCode::Char(' ') => {
+                if let Some(correction) = app.date_correction.correction.as_mut() {
+                    correction.toggle(app.date_correction.selection);
+                }
+            }
+            KeyCode::Char('a') => {
+                if let Some(correction) = app.date_correction.correction.as_mut() {
+                    correction.toggle_all();
+                }
+            }
+            KeyCode::Char('t') if app.date_correction.correction.is_some() => app.date_correction.input = Some(String::new()),
+            KeyCode::Char('d') => app.date_correction.restart(),
+            KeyCode::Char('y') | KeyCode::Enter => app.apply_date_correction(),
+            _ => {}
+        }
+        return Ok(false);
+    }
+
+    // Handle the medication log, including a dose being entered
+    if matches!(app.ui.state, AppState::ShowingMedications) {
+        if let Some((_, text)) = app.medications.input.as_mut() {
+            match key.code {
+                KeyCode::Esc => app.medications.input = None,
+                KeyCode::Enter => app.finish_medication_field(),
+                KeyCode::Backspace => {
+                    text.pop();
+                }
+                KeyCode::Char(c) if text.len() < MAX_MEDICATION_INPUT_LENGTH => text.push(c),
+                _ => {}
+            }
+            return Ok(false);
+        }
+        let max = app.medications.log.doses.len().saturating_sub(1);
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('H') | KeyCode::Char('q') => {
+                app.ui.state = AppState::Normal;
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                app.medications.selection = (app.medications.selection + 1).min(max);
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                app.medications.selection = app.medications.selection.saturating_sub(1);
+            }
+            KeyCode::Char('a') | KeyCode::Enter => app.start_medication_dose(),
+            KeyCode::Char('D') => app.delete_medication_dose(),
+            KeyCode::Char('e') => app.export_medication_log(),
+            _ => {}
+        }
+        return Ok(false);
+    }
+
+    // Handle event templates, including a start date or packing item being typed
+    if matches!(app.ui.state, AppState::ShowingEventTemplates) {
+        if let Some((_, text)) = app.templates.input.as_mut() {
+            match key.code {
+                KeyCode::Esc => app.templates.input = None,
+                KeyCode::Enter => app.finish_template_input(),
+                KeyCode::Backspace => {
+                    text.pop();
+                }
+                KeyCode::Char(c) if text.len() < MAX_TEMPLATE_INPUT_LENGTH => text.push(c),
+                _ => {}
+            }
+            return Ok(false);
+        }
+        let max = app.templates.templates.templates.len().saturating_sub(1);
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('L') | KeyCode::Char('q') => {
+                app.ui.state = AppState::Normal;
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                app.templates.selection = (app.templates.selection + 1).min(max);
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                app.templates.selection = app.templates.selection.saturating_sub(1);
+            }
+            KeyCode::Enter | KeyCode::Char('n') => {
+                let start = chrono::Local::now().date_naive().format("%Y-%m-%d 09:00").to_string();
+                app.templates.input = Some((TemplateInput::StartDate, start));
+            }
+            KeyCode::Char('p') => app.templates.input = Some((TemplateInput::PackingItem, String::new())),
+            KeyCode::Char('x') => app.remove_packing_item(),
+            KeyCode::Char('D') => app.delete_event_template(),
+            _ => {}
+        }
+        return Ok(false);
+    }
+
+    // Handle startup alerts
+    if matches!(app.ui.state, AppState::ShowingAlerts) {
+        let max = app.alerts.list.len().saturating_sub(1);
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('!') | KeyCode::Char('q') => {
+                app.ui.state = AppState::Normal;
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                app.alerts.selection = (app.alerts.selection + 1).min(max);
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                app.alerts.selection = app.alerts.selection.saturating_sub(1);
+            }
+            KeyCode::Char('d') => app.dismiss_alerts(false),
+            KeyCode::Char('D') => app.dismiss_alerts(true),
+            _ => {}
+        }
+        return Ok(false);
+    }
+
+    // Handle to-do list, including a new item being typed
+    if matches!(app.ui.state, AppState::ShowingTodos) {
+        if let Some(text) = app.todos.input.as_mut() {
+            match key.code {
+                KeyCode::Esc => app.todos.input = None,
+                KeyCode::Enter => app.finish_todo_input(),
+                KeyCode::Backspace => {
+                    text.pop();
+                }
+                KeyCode::Char(c) if text.len() < MAX_TODO_INPUT_LENGTH => text.push(c),
+                _ => {}
+            }
+            return Ok(false);
+        }
+        let max = app.todo_rows().len().saturating_sub(1);
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('I') | KeyCode::Char('q') => {
+                app.ui.state = AppState::Normal;
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                app.todos.selection = (app.todos.selection + 1).min(max);
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                app.todos.selection = app.todos.selection.saturating_sub(1);
+            }
+            KeyCode::Char('a') => app.todos.input = Some(String::new()),
+            KeyCode::Char('u') => app.todos.subject = TodoSubject::Unit,
+            KeyCode::Char(' ') | KeyCode::Enter => app.toggle_todo(),
+            KeyCode::Char('D') => app.delete_todo(),
+            KeyCode::Char('f') => {
+                app.todos.show_done = !app.todos.show_done;
+                app.todos.selection = 0;
+            }
+            _ => {}
+        }
+        return Ok(false);
+    }
+
+    // Handle troop library, including a title being typed
+    if matches!(app.ui.state, AppState::ShowingLibrary) {
+        if let Some(text) = app.library.input.as_mut() {
+            match key.code {
+                KeyCode::Esc => app.library.input = None,
+                KeyCode::Enter => app.finish_library_input(),
+                KeyCode::Backspace => {
+                    text.pop();
+                }
+                KeyCode::Char(c) if text.len() < MAX_LIBRARY_INPUT_LENGTH => text.push(c),
+                _ => {}
+            }
+            return Ok(false);
+        }
+        let max = app.library.titles.items.len().saturating_sub(1);
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('Z') | KeyCode::Char('q') => {
+                app.ui.state = AppState::Normal;
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                app.library.selection = (app.library.selection + 1).min(max);
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                app.library.selection = app.library.selection.saturating_sub(1);
+            }
+            KeyCode::Char('a') => app.library.input = Some(String::new()),
+            KeyCode::Char('x') => app.remove_library_copy(),
+            KeyCode::Char('o') => app.check_out_library_item(),
+            KeyCode::Char('r') => app.check_in_library_item(),
+            _ => {}
+        }
+        return Ok(false);
+    }
+
+    // Handle uniform closet, including an item or filter being typed
+    if matches!(app.ui.state, AppState::ShowingCloset) {
+        if let Some(text) = app.closet.input.as_mut() {
+            match key.code {
+                KeyCode::Esc => app.closet.input = None,
+                KeyCode::Enter => app.finish_closet_input(),
+                KeyCode::Backspace => {
+                    text.pop();
+                }
+                KeyCode::Char(c) if text.len() < MAX_CLOSET_INPUT_LENGTH => text.push(c),
+                _ => {}
+            }
+            return Ok(false);
+        }
+        if app.closet.editing_filter {
+            match key.code {
+                KeyCode::Esc => {
+                    app.closet.filter.clear();
+                    app.closet.editing_filter = false;
+                }
+                KeyCode::Enter => app.closet.editing_filter = false,
+                KeyCode::Backspace => {
+                    app.closet.filter.pop();
+                }
+                KeyCode::Char(c) if app.closet.filter.len() < MAX_CLOSET_INPUT_LENGTH => app.closet.filter.push(c),
+                _ => return Ok(false),
+            }
+            app.closet_filter_changed();
+            return Ok(false);
+        }
+        let max = app.closet_rows().len().saturating_sub(1);
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('$') | KeyCode::Char('q') => {
+                app.ui.state = AppState::Normal;
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                app.closet.selection = (app.closet.selection + 1).min(max);
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                app.closet.selection = app.closet.selection.saturating_sub(1);
+            }
+            KeyCode::Char('a') => app.closet.input = Some(String::new()),
+            KeyCode::Char('/') => app.closet.editing_filter = true,
+            KeyCode::Char('f') => app.toggle_closet_show_out(),
+            KeyCode::Char('o') => app.check_out_closet_item(),
+            KeyCode::Char('r') => app.check_in_closet_item(),
+            KeyCode::Char('x') => app.remove_closet_item(),
+            _ => {}
+        }
+        return Ok(false);
+    }
+
+    // Handle the adult skills directory, including a skill or filter being typed
+    if matches!(app.ui.state, AppState::ShowingAdultSkills) {
+        if let Some(text) = app.adult_skills.input.as_mut() {
+            match key.code {
+                KeyCode::Esc => app.adult_skills.input = None,
+                KeyCode::Enter => app.finish_adult_skill_input(),
+                KeyCode::Backspace => {
+                    text.pop();
+                }
+                KeyCode::Char(c) if text.len() < MAX_ADULT_SKILL_INPUT_LENGTH => text.push(c),
+                _ => {}
+            }
+            return Ok(false);
+        }
+        if app.adult_skills.editing_filter {
+            match key.code {
+                KeyCode::Esc => {
+                    app.adult_skills.filter.clear();
+                    app.adult_skills.editing_filter = false;
+                }
+                KeyCode::Enter => app.adult_skills.editing_filter = false,
+                KeyCode::Backspace => {
+                    app.adult_skills.filter.pop();
+                }
+                KeyCode::Char(c) if app.adult_skills.filter.len() < MAX_ADULT_SKILL_INPUT_LENGTH => {
+                    app.adult_skills.filter.push(c)
+                }
+                _ => return Ok(false),
+            }
+            app.adult_skills_filter_changed();
+            return Ok(false);
+        }
+        let max = app.adult_skill_rows().len().saturating_sub(1);
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('%') | KeyCode::Char('q') => {
+                app.ui.state = AppState::Normal;
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                app.adult_skills.selection = (app.adult_skills.selection + 1).min(max);
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                app.adult_skills.selection = app.adult_skills.selection.saturating_sub(1);
+            }
+            KeyCode::Char('a') => app.start_adult_skill_input(),
+            KeyCode::Char('/') => app.adult_skills.editing_filter = true,
+            KeyCode::Char('x') => app.remove_adult_skill(),
+            _ => {}
+        }
+        return Ok(false);
+    }
+
+    // Handle high-adventure screener, including a date being typed
+    if matches!(app.ui.state, AppState::ScreeningTrek) {
+        if let Some((_, text)) = app.trek.input.as_mut() {
+            match key.code {
+                KeyCode::Esc => app.trek.input = None,
+                KeyCode::Enter => app.finish_trek_input(),
+                KeyCode::Backspace => {
+                    text.pop();
+                }
+                KeyCode::Char(c) if text.len() < MAX_TREK_DATE_LENGTH => text.push(c),
+                _ => {}
+            }
+            return Ok(false);
+        }
+        let max = app.trek_results().len().saturating_sub(1);
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('^') | KeyCode::Char('q') => {
+                app.ui.state = AppState::Normal;
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                app.trek.selection = (app.trek.selection + 1).min(max);
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                app.trek.selection = app.trek.selection.saturating_sub(1);
+            }
+            KeyCode::Char('b') => app.cycle_trek_base(),
+            KeyCode::Char('d') => app.start_trek_input(TrekField::TrekDate),
+            KeyCode::Char('r') => app.cycle_trek_rank(),
+            KeyCode::Char('m') => app.start_trek_input(TrekField::ExamDate),
+            KeyCode::Char('w') => app.cycle_trek_weight(),
+            KeyCode::Char('e') => app.export_trek_report(),
+            _ => {}
+        }
+        return Ok(false);
+    }
+
+    // Handle Eagle project, including text being typed
+    if matches!(app.ui.state, AppState::ShowingEagleProject) {
+        if let Some((_, text)) = app.eagle_projects.input.as_mut() {
+            match key.code {
+                KeyCode::Esc => app.eagle_projects.input = None,
+                KeyCode::Enter => app.finish_project_input(),
+                KeyCode::Backspace => {
+                    text.pop();
+                }
+                KeyCode::Char(c) if text.len() < MAX_PROJECT_INPUT_LENGTH => text.push(c),
+                _ => {}
+            }
+            return Ok(false);
+        }
+        let max = Paperwork::ALL.len() - 1;
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => {
+                app.ui.state = AppState::Normal;
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                app.eagle_projects.selection = (app.eagle_projects.selection + 1).min(max);
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                app.eagle_projects.selection = app.eagle_projects.selection.saturating_sub(1);
+            }
+            KeyCode::Char(' ') => app.toggle_project_paperwork(),
+            KeyCode::Char('s') => app.set_project_stage(true),
+            KeyCode::Char('S') => app.set_project_stage(false),
+            KeyCode::Char('f') => app.cycle_project_fundraising(),
+            KeyCode::Char('t') => app.start_project_input(ProjectField::Title),
+            KeyCode::Char('b') => app.start_project_input(ProjectField::Beneficiary),
+            KeyCode::Char('h') => app.start_project_input(ProjectField::Hours),
+            KeyCode::Char('w') => app.start_project_input(ProjectField::Workday),
+            _ => {}
+        }
+        return Ok(false);
+    }
+
+    // Handle clinic planner, including text being typed
+    if matches!(app.ui.state, AppState::PlanningClinic) {
+        if let Some((_, text)) = app.clinic.input.as_mut() {
+            match key.code {
+                KeyCode::Esc => app.clinic.input = None,
+                KeyCode::Enter => app.finish_clinic_input(),
+                KeyCode::Backspace => {
+                    text.pop();
+                }
+                KeyCode::Char(c) if text.len() < MAX_CLINIC_INPUT_LENGTH => text.push(c),
+                _ => {}
+            }
+            return Ok(false);
+        }
+        let max = app.clinic_rows().len().saturating_sub(1);
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => {
+                app.ui.state = AppState::Normal;
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                app.clinic.selection = (app.clinic.selection + 1).min(max);
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                app.clinic.selection = app.clinic.selection.saturating_sub(1);
+            }
+            KeyCode::Char('a') => app.start_clinic_input(ClinicField::Badge),
+            KeyCode::Char('c') => app.start_clinic_input(ClinicField::Counselor),
+            KeyCode::Char('n') => app.start_clinic_input(ClinicField::Name),
+            KeyCode::Char('d') => app.start_clinic_input(ClinicField::Date),
+            KeyCode::Char('o') => app.toggle_clinic_registration(),
+            KeyCode::Char(' ') => app.toggle_clinic_completion(),
+            KeyCode::Char('x') => app.remove_clinic_row(),
+            KeyCode::Char('e') => app.export_clinic(false),
+            KeyCode::Char('s') => app.export_clinic(true),
+            _ => {}
+        }
+        return Ok(false);
+    }
+
+    // Handle global search palette; typing narrows the matches
+    if matches!(app.ui.state, AppState::GlobalSearch) {
+        let max = app.palette_hits().len().saturating_sub(1);
+        match key.code {
+            KeyCode::Esc => app.ui.state = AppState::Normal,
+            KeyCode::Down => app.palette_selection = (app.palette_selection + 1).min(max),
+            KeyCode::Up => app.palette_selection = app.palette_selection.saturating_sub(1),
+            KeyCode::Enter => app.open_palette_hit(),
+            KeyCode::Backspace => app.palette_backspace(),
+            KeyCode::Char(c) if app.palette_query.len() < MAX_PALETTE_INPUT_LENGTH => app.palette_input(c),
+            _ => {}
+        }
+        return Ok(false);
+    }
+
+    // Handle BSA ID lookup; typing narrows the matches
+    if matches!(app.ui.state, AppState::LookingUpMemberId) {
+        let max = app.member_id_rows().len().saturating_sub(1);
+        match key.code {
+            KeyCode::Esc => app.ui.state = AppState::Normal,
+            KeyCode::Down => app.member_id_selection = (app.member_id_selection + 1).min(max),
+            KeyCode::Up => app.member_id_selection = app.member_id_selection.saturating_sub(1),
+            KeyCode::Enter => app.open_member_id_match(),
+            KeyCode::Backspace => app.member_id_backspace(),
+            KeyCode::Char(c)
+                if (c.is_ascii_digit() || c == '-' || c == ' ')
+                    && app.member_id_query.len() < MAX_MEMBER_ID_INPUT_LENGTH =>
+            {
+                app.member_id_input(c)
+            }
+            _ => {}
+        }
+        return Ok(false);
+    }
+
+    // Handle contact check
+    if matches!(app.ui.state, AppState::CheckingContacts) {
+        let max = app.contact_rows().len().saturating_sub(1);
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('@') | KeyCode::Char('q') => app.ui.state = AppState::Normal,
+            KeyCode::Down | KeyCode::Char('j') => app.contact_selection = (app.contact_selection + 1).min(max),
+            KeyCode::Up | KeyCode::Char('k') => app.contact_selection = app.contact_selection.saturating_sub(1),
+            KeyCode::Enter => app.open_contact_problem(),
+            _ => {}
+        }
+        return Ok(false);
+    }
+
+    // Handle committee role matrix
+    if matches!(app.ui.state, AppState::ShowingCommitteeRoles) {
+        let max_row = app.committee_rows().len().saturating_sub(1);
+        let max_column = CommitteeRole::ALL.len() - 1;
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('c') | KeyCode::Char('q') => app.ui.state = AppState::Normal,
+            KeyCode::Down | KeyCode::Char('j') => app.committee.row = (app.committee.row + 1).min(max_row),
+            KeyCode::Up | KeyCode::Char('k') => app.committee.row = app.committee.row.saturating_sub(1),
+            KeyCode::Right | KeyCode::Char('l') => app.committee.column = (app.committee.column + 1).min(max_column),
+            KeyCode::Left | KeyCode::Char('h') => app.committee.column = app.committee.column.saturating_sub(1),
+            KeyCode::Char(' ') | KeyCode::Enter => app.toggle_committee_role(),
+            KeyCode::Char('a') => app.toggle_committee_all_adults(),
+            _ => {}
+        }
+        return Ok(false);
+    }
+
+    // Handle Order of the Arrow eligibility list
+    if matches!(app.ui.state, AppState::ShowingOaEligibility) {
+        let max = app.store.youth.len().saturating_sub(1);
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('O') | KeyCode::Char('q') => app.ui.state = AppState::Normal,
+            KeyCode::Down | KeyCode::Char('j') => app.oa_selection = (app.oa_selection + 1).min(max),
+            KeyCode::Up | KeyCode::Char('k') => app.oa_selection = app.oa_selection.saturating_sub(1),
+            KeyCode::Enter => app.open_oa_scout(),
+            _ => {}
+        }
+        return Ok(false);
+    }
+
+    // Handle district dashboard: unit list, or one unit's details
+    if matches!(app.ui.state, AppState::ShowingDistrict) {
+        let max = app.district.units.len().saturating_sub(1);
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') if app.district.detail => app.district.detail = false,
+            KeyCode::Esc | KeyCode::Char('J') | KeyCode::Char('q') => app.ui.state = AppState::Normal,
+            KeyCode::Down | KeyCode::Char('j') => app.district.selection = (app.district.selection + 1).min(max),
+            KeyCode::Up | KeyCode::Char('k') => app.district.selection = app.district.selection.saturating_sub(1),
+            KeyCode::Enter => app.district.detail = !app.district.detail,
+            KeyCode::Char('u') => app.refresh_district(),
+            KeyCode::Char('r') => app.mark_district_roundtable(),
+            _ => {}
+        }
+        return Ok(false);
+    }
+
+    // Handle linked troop view
+    if matches!(app.ui.state, AppState::ShowingLinked) {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('l') | KeyCode::Char('q') => app.ui.state = AppState::Normal,
+            KeyCode::Down | KeyCode::Char('j') => app.linked.scroll += 1,
+            KeyCode::Up | KeyCode::Char('k') => app.linked.scroll = app.linked.scroll.saturating_sub(1),
+            KeyCode::Tab => app.cycle_linked_list(),
+            KeyCode::Char('f') => app.cycle_linked_filter(),
+            KeyCode::Char('s') => app.linked.side_by_side = !app.linked.side_by_side,
+            KeyCode::Char('u') => app.refresh_linked(),
+            _ => {}
+        }
+        return Ok(false);
+    }
+
+    // Handle minutes editor, including a note or action item being typed
+    if matches!(app.ui.state, AppState::EditingMinutes) {
+        if let Some((_, text)) = app.minutes.input.as_mut() {
+            match key.code {
+                KeyCode::Esc => app.minutes.input = None,
+                KeyCode::Enter => app.finish_minutes_input(),
+                KeyCode::Backspace => {
+                    text.pop();
+                }
+                KeyCode::Char(c) if text.len() < MAX_MINUTES_INPUT_LENGTH => text.push(c),
+                _ => {}
+            }
+            return Ok(false);
+        }
+        let max = app
+            .minutes
+            .meetings
+            .meetings
+            .get(app.minutes.selection)
+            .map(|m| m.action_items.len().saturating_sub(1))
+            .unwrap_or_default();
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('W') | KeyCode::Char('q') => app.close_minutes(),
+            KeyCode::Char('j') | KeyCode::Down => {
+                app.minutes.item_selection = (app.minutes.item_selection + 1).min(max);
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                app.minutes.item_selection = app.minutes.item_selection.saturating_sub(1);
+            }
+            KeyCode::Char('n') => app.minutes.input = Some((MinutesInput::Note, String::new())),
+            KeyCode::Char('x') => app.remove_minutes_note(),
+            KeyCode::Char('a') => app.minutes.input = Some((MinutesInput::ActionItem, String::new())),
+            KeyCode::Char(' ') | KeyCode::Char('c') => app.toggle_action_item(),
+            KeyCode::Char('D') => app.delete_action_item(),
+            KeyCode::Char('r') => app.refresh_minutes_attendees(),
+            _ => {}
+        }
+        return Ok(false);
+    }
+
+    // Handle historian log, including text being typed
+    if matches!(app.ui.state, AppState::ShowingHistory) {
+        if let Some((_, text)) = app.history.input.as_mut() {
+            match key.code {
+                KeyCode::Esc => app.history.input = None,
+                KeyCode::Enter => app.finish_history_input(),
+                KeyCode::Backspace => {
+                    text.pop();
+                }
+                KeyCode::Char(c) if text.len() < MAX_HISTORY_INPUT_LENGTH => text.push(c),
+                _ => {}
+            }
+            return Ok(false);
+        }
+        let max = app.history.log.entries.len().saturating_sub(1);
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('H') | KeyCode::Char('q') => app.close_historian_log(),
+            KeyCode::Char('j') | KeyCode::Down => {
+                app.history.selection = (app.history.selection + 1).min(max);
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                app.history.selection = app.history.selection.saturating_sub(1);
+            }
+            KeyCode::Char('s') | KeyCode::Enter => app.start_history_input(HistoryField::Summary),
+            KeyCode::Char('h') => app.start_history_input(HistoryField::Highlight),
+            KeyCode::Char('x') => app.remove_history_highlight(),
+            KeyCode::Char('f') => app.start_history_input(HistoryField::PhotoFolder),
+            KeyCode::Char('D') => app.delete_history_entry(),
+            KeyCode::Char('e') => app.export_troop_history(),
+            _ => {}
+        }
+        return Ok(false);
+    }
+
+    // Handle counselor view
+    if matches!(app.ui.state, AppState::ShowingCounselors) {
+        let max_scroll = app
+            .counselors
+            .groups
+            .get(app.counselors.selection)
+            .map(|g| g.scouts.len().saturating_sub(1))
+            .unwrap_or_default();
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('O') | KeyCode::Char('q') => {
+                app.ui.state = AppState::Normal;
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                app.counselors.scroll = (app.counselors.scroll + 1).min(max_scroll);
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                app.counselors.scroll = app.counselors.scroll.saturating_sub(1);
+            }
+            KeyCode::Char('l') | KeyCode::Right => {
+                app.counselors.selection = (app.counselors.selection + 1).min(app.counselors.groups.len().saturating_sub(1));
+                app.counselors.scroll = 0;
+            }
+            KeyCode::Char('h') | KeyCode::Left => {
+                app.counselors.selection = app.counselors.selection.saturating_sub(1);
+                app.counselors.scroll = 0;
+            }
+            KeyCode::Char('e') => app.export_counselor_reports(false),
+            KeyCode::Char('a') => app.export_counselor_reports(true),
+            _ => {}
+        }
+        return Ok(false);
+    }
+
+    // Handle a debrief note being typed on the Events tab
+    if let Some((kind, text)) = app.debrief_input.as_mut() {
+        match key.code {
+            KeyCode::Esc => app.debrief_input = None,
+            KeyCode::Enter => app.finish_debrief_note(),
+            KeyCode::Tab => *kind = kind.next(),
+            KeyCode::Backspace => {
+                text.pop();
+            }
+            KeyCode::Char(c) if text.len() < MAX_DEBRIEF_INPUT_LENGTH => text.push(c),
+            _ => {}
         }
+        return Ok(false);
     }
-}
 
-/// Handle keyboard input. Returns true if the app should quit.
-pub async fn handle_input(app: &mut App, key: KeyEvent) -> Result<bool> {
-    // Handle login overlay
-    if matches!(app.state, AppState::LoggingIn) {
-        return handle_login_input(app, key).await;
+    // Handle accommodation being typed for a requirement on the Scouts tab
+    if let Some(draft) = app.accommodation_input.as_mut() {
+        match key.code {
+            KeyCode::Esc => app.accommodation_input = None,
+            KeyCode::Enter => app.finish_accommodation_input(),
+            KeyCode::Backspace => {
+                draft.text.pop();
+            }
+            KeyCode::Char(c) if draft.text.len() < MAX_ACCOMMODATION_INPUT_LENGTH => draft.text.push(c),
+            _ => {}
+        }
+        return Ok(false);
     }
 
-    // Handle help overlay
-    if matches!(app.state, AppState::ShowingHelp) {
-        if matches!(key.code, KeyCode::Esc | KeyCode::Char('?') | KeyCode::Char('q')) {
-            app.state = AppState::Normal;
+    // Handle den chief link being typed on the Scouts tab
+    if let Some((_, text)) = app.den_chiefs.input.as_mut() {
+        match key.code {
+            KeyCode::Esc => app.den_chiefs.input = None,
+            KeyCode::Enter => app.finish_den_chief_input(),
+            KeyCode::Backspace => {
+                text.pop();
+            }
+            KeyCode::Char(c) if text.len() < MAX_DEN_CHIEF_INPUT_LENGTH => text.push(c),
+            _ => {}
         }
         return Ok(false);
     }
 
-    // Handle quit confirmation
-    if matches!(app.state, AppState::ConfirmingQuit) {
+    // Handle position planner
+    if matches!(app.ui.state, AppState::PlanningPositions) {
         match key.code {
-            KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => {
-                if app.caching_in_progress {
-                    app.status_message = Some("Cannot quit while caching in progress. Please wait...".to_string());
-                    app.state = AppState::Normal;
-                    return Ok(false);
-                }
-                app.state = AppState::Quitting;
-                return Ok(true);
+            KeyCode::Esc | KeyCode::Char('p') | KeyCode::Char('q') => {
+                app.ui.state = AppState::Normal;
             }
-            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
-                app.state = AppState::Normal;
+            KeyCode::Char('j') | KeyCode::Down => {
+                app.planner.selection = (app.planner.selection + 1).min(app.meeting_rows().len().saturating_sub(1));
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                app.planner.selection = app.planner.selection.saturating_sub(1);
             }
+            KeyCode::Char('l') | KeyCode::Right => {
+                app.planner.position = (app.planner.position + 1).min(YOUTH_POSITION_PRIORITY.len() - 1);
+            }
+            KeyCode::Char('h') | KeyCode::Left => {
+                app.planner.position = app.planner.position.saturating_sub(1);
+            }
+            KeyCode::Enter => app.plan_position(Some(app.planner_position_name())),
+            KeyCode::Char('u') => app.plan_position(None),
+            KeyCode::Char('e') => app.export_position_plan(),
             _ => {}
         }
         return Ok(false);
     }
 
-    // Handle offline mode confirmation
-    if matches!(app.state, AppState::ConfirmingOffline) {
+    // Handle meeting timers
+    if matches!(app.ui.state, AppState::ShowingTimers) {
+        let now = Instant::now();
         match key.code {
-            KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => {
-                app.state = AppState::Normal;
-                app.go_offline().await;
+            KeyCode::Esc | KeyCode::Char('T') | KeyCode::Char('q') => {
+                app.ui.state = AppState::Normal;
             }
-            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
-                app.state = AppState::Normal;
+            KeyCode::Char(' ') => app.countdown.clock.toggle(now),
+            KeyCode::Char('+') | KeyCode::Char('=') => app.countdown.adjust_minutes(1),
+            KeyCode::Char('-') => app.countdown.adjust_minutes(-1),
+            KeyCode::Char('r') => app.countdown.reset(),
+            KeyCode::Char(c @ '1'..='9') => app.set_countdown_preset(c as usize - '1' as usize),
+            KeyCode::Char('a') => app.agenda.toggle(now),
+            KeyCode::Char('n') => app.agenda.next(now),
+            KeyCode::Char('b') => app.agenda.previous(now),
+            KeyCode::Char('x') => app.agenda.reset(),
+            _ => {}
+        }
+        return Ok(false);
+    }
+
+    // Handle awards catalog
+    if matches!(app.ui.state, AppState::ShowingAwards) {
+        let max = app.award_catalog().len().saturating_sub(1);
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('N') | KeyCode::Char('q') => {
+                app.ui.state = AppState::Normal;
             }
+            KeyCode::Char('j') | KeyCode::Down => {
+                app.nominations.catalog_selection = (app.nominations.catalog_selection + 1).min(max);
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                app.nominations.catalog_selection = app.nominations.catalog_selection.saturating_sub(1);
+            }
+            KeyCode::Char('n') => app.toggle_nomination(),
             _ => {}
         }
         return Ok(false);
     }
 
-    // Handle online mode confirmation (when returning from offline)
-    if matches!(app.state, AppState::ConfirmingOnline) {
+    // Handle meeting-night attendance sheet
+    if matches!(app.ui.state, AppState::TakingAttendance) {
+        let max = app.meeting_rows().len().saturating_sub(1);
         match key.code {
-            KeyCode::Char('o') | KeyCode::Char('O') => {
-                app.state = AppState::Normal;
-                app.go_online();
+            KeyCode::Esc => {
+                app.ui.state = AppState::Normal;
+                app.status_message = Some("Attendance not saved".to_string());
             }
-            _ => {
-                // Any other key stays offline
-                app.state = AppState::Normal;
+            KeyCode::Char('j') | KeyCode::Down => {
+                app.meeting.selection = (app.meeting.selection + 1).min(max);
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                app.meeting.selection = app.meeting.selection.saturating_sub(1);
             }
+            KeyCode::Char(' ') => app.mark_meeting(None, false),
+            KeyCode::Char('p') => app.mark_meeting(Some(true), true),
+            KeyCode::Char('x') => app.mark_meeting(Some(false), true),
+            KeyCode::Char('s') | KeyCode::Enter => app.save_meeting(),
+            _ => {}
         }
         return Ok(false);
     }
 
     // Handle search mode
-    if matches!(app.state, AppState::Searching) {
+    if matches!(app.ui.state, AppState::Searching) {
         return handle_search_input(app, key).await;
     }
 
+    // Main screen: apply the user's key bindings
+    let Some(key) = app.keymap.translate(key) else {
+        return Ok(false);
+    };
+
     // Global keys
     match key.code {
         KeyCode::Char('q') => {
-            if app.caching_in_progress {
+            if app.caching.in_progress {
                 app.status_message = Some("Cannot quit while caching in progress. Please wait...".to_string());
                 return Ok(false);
             }
-            app.state = AppState::ConfirmingQuit;
+            app.ui.state = AppState::ConfirmingQuit;
             return Ok(false);
         }
         KeyCode::Char('?') => {
-            app.state = AppState::ShowingHelp;
+            app.ui.state = AppState::ShowingHelp;
+            return Ok(false);
+        }
+        KeyCode::Char('A') => {
+            app.show_feed();
+            return Ok(false);
+        }
+        KeyCode::Char('N') => {
+            app.show_awards();
+            return Ok(false);
+        }
+        KeyCode::Char('M') => {
+            app.start_meeting();
+            return Ok(false);
+        }
+        KeyCode::Char('P') => {
+            app.export_text_view();
+            return Ok(false);
+        }
+        KeyCode::Char('V') => {
+            app.cycle_export_profile();
+            return Ok(false);
+        }
+        KeyCode::Char('#') => {
+            app.show_member_id_lookup();
+            return Ok(false);
+        }
+        KeyCode::Char('@') => {
+            app.show_contact_check();
+            return Ok(false);
+        }
+        KeyCode::Char('J') => {
+            app.show_district();
+            return Ok(false);
+        }
+        KeyCode::Char('Y') => {
+            app.show_my_scouts();
+            return Ok(false);
+        }
+        KeyCode::Char('T') => {
+            app.show_timers();
+            return Ok(false);
+        }
+        KeyCode::Char('K') => {
+            app.show_skills();
+            return Ok(false);
+        }
+        KeyCode::Char('I') => {
+            app.show_todos();
+            return Ok(false);
+        }
+        KeyCode::Char('!') => {
+            app.show_alerts();
+            return Ok(false);
+        }
+        KeyCode::Char('Z') => {
+            app.show_library();
+            return Ok(false);
+        }
+        KeyCode::Char('$') => {
+            app.show_closet();
+            return Ok(false);
+        }
+        KeyCode::Char('%') => {
+            app.show_adult_skills();
+            return Ok(false);
+        }
+        KeyCode::Char('^') => {
+            app.show_trek_screener();
+            return Ok(false);
+        }
+        KeyCode::Char('&') => {
+            app.show_clinic();
+            return Ok(false);
+        }
+        KeyCode::Char('~') => {
+            app.cycle_theme();
             return Ok(false);
         }
         KeyCode::Char('1') => {
-            app.current_tab = Tab::Scouts;
-            app.focus = Focus::List;
-            app.left_table_state = TableState::default();
-            app.right_table_state = TableState::default();
+            app.ui.current_tab = Tab::Scouts;
+            app.ui.focus = Focus::List;
+            app.ui.left_table_state = TableState::default();
+            app.ui.right_table_state = TableState::default();
         }
         KeyCode::Char('2') => {
-            app.current_tab = Tab::Ranks;
-            app.focus = Focus::List;
-            app.left_table_state = TableState::default();
-            app.right_table_state = TableState::default();
+            app.ui.current_tab = Tab::Ranks;
+            app.ui.focus = Focus::List;
+            app.ui.left_table_state = TableState::default();
+            app.ui.right_table_state = TableState::default();
         }
         KeyCode::Char('3') => {
-            app.current_tab = Tab::Badges;
-            app.focus = Focus::List;
-            app.left_table_state = TableState::default();
-            app.right_table_state = TableState::default();
+            app.ui.current_tab = Tab::Badges;
+            app.ui.focus = Focus::List;
+            app.ui.left_table_state = TableState::default();
+            app.ui.right_table_state = TableState::default();
         }
         KeyCode::Char('4') => {
-            app.current_tab = Tab::Events;
-            app.focus = Focus::List;
-            app.left_table_state = TableState::default();
-            app.right_table_state = TableState::default();
+            app.ui.current_tab = Tab::Events;
+            app.ui.focus = Focus::List;
+            app.ui.left_table_state = TableState::default();
+            app.ui.right_table_state = TableState::default();
         }
         KeyCode::Char('5') => {
-            app.current_tab = Tab::Adults;
-            app.focus = Focus::List;
-            app.left_table_state = TableState::default();
-            app.right_table_state = TableState::default();
+            app.ui.current_tab = Tab::Adults;
+            app.ui.focus = Focus::List;
+            app.ui.left_table_state = TableState::default();
+            app.ui.right_table_state = TableState::default();
         }
         KeyCode::Char('6') => {
-            app.current_tab = Tab::Unit;
-            app.focus = Focus::List;
-            app.left_table_state = TableState::default();
-            app.right_table_state = TableState::default();
+            app.ui.current_tab = Tab::Unit;
+            app.ui.focus = Focus::List;
+            app.ui.left_table_state = TableState::default();
+            app.ui.right_table_state = TableState::default();
+        }
+        KeyCode::Char('7') => {
+            app.ui.current_tab = Tab::Eagle;
+            app.ui.focus = Focus::List;
+            app.ui.left_table_state = TableState::default();
+            app.ui.right_table_state = TableState::default();
+        }
+        KeyCode::Char('8') => {
+            app.ui.current_tab = Tab::Attendance;
+            app.ui.focus = Focus::List;
+            app.ui.left_table_state = TableState::default();
+            app.ui.right_table_state = TableState::default();
+        }
+        KeyCode::Char('9') if app.plugins.len() > 0 => {
+            app.ui.current_tab = Tab::Plugin(0);
+            app.ui.focus = Focus::List;
+            app.ui.left_table_state = TableState::default();
+            app.ui.right_table_state = TableState::default();
         }
         KeyCode::Left => {
             // If on Scouts tab with detail focus, cycle detail views
-            if app.current_tab == Tab::Scouts && app.focus == Focus::Detail {
+            if app.ui.current_tab == Tab::Scouts && app.ui.focus == Focus::Detail {
                 cycle_scout_detail_view(app, CycleDirection::Backward).await;
-            } else if app.current_tab == Tab::Events && app.focus == Focus::Detail {
+            } else if app.ui.current_tab == Tab::Events && app.ui.focus == Focus::Detail {
                 // Cycle Events detail views (only if RSVP is enabled for selected event)
                 let rsvp_enabled = app.get_sorted_events()
-                    .get(app.event_selection)
+                    .get(app.event_selection.index)
                     .map(|e| e.rsvp)
                     .unwrap_or(false);
                 if rsvp_enabled {
-                    app.event_detail_view = match app.event_detail_view {
-                        EventDetailView::Details => EventDetailView::Rsvp,
-                        EventDetailView::Rsvp => EventDetailView::Details,
-                    };
+                    match app.ui.event_detail_view {
+                        EventDetailView::Details => app.show_event_rsvp().await,
+                        EventDetailView::Rsvp => app.ui.event_detail_view = EventDetailView::Details,
+                    }
                 }
             } else {
-                app.current_tab = app.current_tab.prev();
-                app.focus = Focus::List;
-                app.left_table_state = TableState::default();
-                app.right_table_state = TableState::default();
+                app.ui.current_tab = app.ui.current_tab.prev(app.plugins.len());
+                app.ui.focus = Focus::List;
+                app.ui.left_table_state = TableState::default();
+                app.ui.right_table_state = TableState::default();
             }
         }
         KeyCode::Right => {
             // If on Scouts tab with detail focus, cycle detail views
-            if app.current_tab == Tab::Scouts && app.focus == Focus::Detail {
+            if app.ui.current_tab == Tab::Scouts && app.ui.focus == Focus::Detail {
                 cycle_scout_detail_view(app, CycleDirection::Forward).await;
-            } else if app.current_tab == Tab::Events && app.focus == Focus::Detail {
+            } else if app.ui.current_tab == Tab::Events && app.ui.focus == Focus::Detail {
                 // Cycle Events detail views (only if RSVP is enabled for selected event)
                 let rsvp_enabled = app.get_sorted_events()
-                    .get(app.event_selection)
+                    .get(app.event_selection.index)
                     .map(|e| e.rsvp)
                     .unwrap_or(false);
                 if rsvp_enabled {
-                    app.event_detail_view = match app.event_detail_view {
-                        EventDetailView::Details => EventDetailView::Rsvp,
-                        EventDetailView::Rsvp => EventDetailView::Details,
-                    };
+                    match app.ui.event_detail_view {
+                        EventDetailView::Details => app.show_event_rsvp().await,
+                        EventDetailView::Rsvp => app.ui.event_detail_view = EventDetailView::Details,
+                    }
                 }
             } else {
-                app.current_tab = app.current_tab.next();
-                app.focus = Focus::List;
-                app.left_table_state = TableState::default();
-                app.right_table_state = TableState::default();
+                app.ui.current_tab = app.ui.current_tab.next(app.plugins.len());
+                app.ui.focus = Focus::List;
+                app.ui.left_table_state = TableState::default();
+                app.ui.right_table_state = TableState::default();
             }
         }
         KeyCode::Char('u') => {
@@ -259,62 +1410,67 @@ pub async fn handle_input(app: &mut App, key: KeyEvent) -> Result<bool> {
         }
         KeyCode::Char('o') => {
             if app.offline_mode {
-                app.state = AppState::ConfirmingOnline;
+                app.ui.state = AppState::ConfirmingOnline;
             } else {
-                app.state = AppState::ConfirmingOffline;
+                app.confirm_offline();
             }
         }
         KeyCode::Char('/') => {
-            app.state = AppState::Searching;
-            app.search_query.clear();
+            app.ui.state = AppState::Searching;
+            app.clear_search();
         }
         KeyCode::Tab => {
             // Toggle focus between list and detail panels
-            app.focus = match app.focus {
+            app.ui.focus = match app.ui.focus {
                 Focus::List => Focus::Detail,
                 Focus::Detail => Focus::List,
             };
         }
         KeyCode::Esc => {
             // Check if we're viewing requirements on Scouts tab - go back
-            if app.current_tab == Tab::Scouts && app.viewing_requirements {
+            if app.ui.current_tab == Tab::Scouts && app.viewing_requirements {
                 app.viewing_requirements = false;
                 app.selected_rank_requirements.clear();
                 app.selected_badge_requirements.clear();
                 app.selected_badge_counselor = None;
                 app.requirement_selection = 0;
-            } else if app.current_tab == Tab::Scouts && app.scout_detail_view != ScoutDetailView::Details {
+            } else if app.ui.current_tab == Tab::Scouts && app.ui.scout_detail_view != ScoutDetailView::Details {
                 // Go back to details view from Ranks/MeritBadges/Leadership
-                app.scout_detail_view = ScoutDetailView::Details;
-                app.focus = Focus::List;
-            } else if app.current_tab == Tab::Events && app.event_detail_view == EventDetailView::Rsvp {
+                app.ui.scout_detail_view = ScoutDetailView::Details;
+                app.ui.focus = Focus::List;
+            } else if app.ui.current_tab == Tab::Events && app.ui.event_detail_view == EventDetailView::Rsvp {
                 // Go back to details view from RSVP
-                app.event_detail_view = EventDetailView::Details;
-            } else if app.current_tab == Tab::Ranks && app.ranks_viewing_requirements {
+                app.ui.event_detail_view = EventDetailView::Details;
+            } else if app.ui.current_tab == Tab::Ranks && app.ranks_viewing_requirements {
                 // Go back from requirements view to scout list
                 app.ranks_viewing_requirements = false;
                 app.selected_rank_requirements.clear();
                 app.ranks_requirement_selection = 0;
-            } else if app.current_tab == Tab::Badges && app.badges_viewing_requirements {
+            } else if app.ui.current_tab == Tab::Badges && app.badges_viewing_requirements {
                 // Go back from requirements view to scout list
                 app.badges_viewing_requirements = false;
                 app.selected_badge_requirements.clear();
                 app.selected_badge_counselor = None;
                 app.badges_requirement_selection = 0;
             } else {
-                app.search_query.clear();
-                app.focus = Focus::List;
+                app.clear_search();
+                app.ui.focus = Focus::List;
             }
         }
         _ => {
             // Tab-specific input
-            match app.current_tab {
+            match app.ui.current_tab {
                 Tab::Scouts => handle_scouts_input(app, key).await?,
                 Tab::Adults => handle_adults_input(app, key).await?,
                 Tab::Events => handle_events_input(app, key).await?,
                 Tab::Unit => handle_dashboard_input(app, key).await?,
                 Tab::Ranks => handle_ranks_input(app, key).await?,
                 Tab::Badges => handle_badges_input(app, key).await?,
+                Tab::Eagle => handle_eagle_input(app, key),
+                Tab::Attendance => handle_attendance_input(app, key),
+                Tab::Plugin(i) => {
+                    app.with_plugin(i, |plugin, app| plugin.handle_input(app, key));
+                }
             }
         }
     }
@@ -325,20 +1481,29 @@ pub async fn handle_input(app: &mut App, key: KeyEvent) -> Result<bool> {
 async fn handle_search_input(app: &mut App, key: KeyEvent) -> Result<bool> {
     match key.code {
         KeyCode::Esc => {
-            app.state = AppState::Normal;
-            app.search_query.clear();
+            app.ui.state = AppState::Normal;
+            app.clear_search();
         }
         KeyCode::Enter => {
-            app.state = AppState::Normal;
+            app.ui.state = AppState::Normal;
             // Keep search query active
         }
         KeyCode::Backspace => {
-            app.search_query.pop();
+            app.search_pop();
+        }
+        KeyCode::Char('/') if app.ui.search_query.is_empty() => {
+            // A second slash searches every tab instead of this list
+            app.show_palette();
+        }
+        KeyCode::Tab => {
+            // Cycle substring / fuzzy / regex matching while typing
+            app.cycle_search_mode();
+            app.roster_selection.reset();
         }
         KeyCode::Char(c) => {
-            app.search_query.push(c);
+            app.search_push(c);
             // Reset selection when search changes
-            app.roster_selection = 0;
+            app.roster_selection.reset();
         }
         _ => {}
     }
@@ -349,12 +1514,12 @@ async fn handle_login_input(app: &mut App, key: KeyEvent) -> Result<bool> {
     match key.code {
         KeyCode::Esc => {
             // Quit if on login screen
-            app.state = AppState::Quitting;
+            app.ui.state = AppState::Quitting;
             return Ok(true);
         }
         KeyCode::Down | KeyCode::Tab => {
             // Move to next field
-            app.login_focus = match app.login_focus {
+            app.login.focus = match app.login.focus {
                 LoginFocus::Username => LoginFocus::Password,
                 LoginFocus::Password => LoginFocus::Button,
                 LoginFocus::Button => LoginFocus::Username,
@@ -362,28 +1527,28 @@ async fn handle_login_input(app: &mut App, key: KeyEvent) -> Result<bool> {
         }
         KeyCode::Up | KeyCode::BackTab => {
             // Move to previous field
-            app.login_focus = match app.login_focus {
+            app.login.focus = match app.login.focus {
                 LoginFocus::Username => LoginFocus::Button,
                 LoginFocus::Password => LoginFocus::Username,
                 LoginFocus::Button => LoginFocus::Password,
             };
         }
         KeyCode::Enter => {
-            match app.login_focus {
+            match app.login.focus {
                 LoginFocus::Username => {
                     // Move to password
-                    app.login_focus = LoginFocus::Password;
+                    app.login.focus = LoginFocus::Password;
                 }
                 LoginFocus::Password => {
                     // Move to button
-                    app.login_focus = LoginFocus::Button;
+                    app.login.focus = LoginFocus::Button;
                 }
                 LoginFocus::Button => {
                     // Attempt login
                     let _ = app.attempt_login().await;
                     // If successful, state will be Normal
                     // If failed, login_error will be set
-                    if app.state == AppState::Normal {
+                    if app.ui.state == AppState::Normal {
                         // Login succeeded, refresh data
                         app.refresh_all_background().await;
                     }
@@ -391,26 +1556,26 @@ async fn handle_login_input(app: &mut App, key: KeyEvent) -> Result<bool> {
             }
         }
         KeyCode::Backspace => {
-            match app.login_focus {
+            match app.login.focus {
                 LoginFocus::Username => {
-                    app.login_username.pop();
+                    app.login.username.pop();
                 }
                 LoginFocus::Password => {
-                    app.login_password.pop();
+                    app.login.password.pop();
                 }
                 LoginFocus::Button => {}
             }
         }
         KeyCode::Char(c) => {
-            match app.login_focus {
+            match app.login.focus {
                 LoginFocus::Username => {
-                    if can_add_username_char(app.login_username.len(), c) {
-                        app.login_username.push(c);
+                    if can_add_username_char(app.login.username.len(), c) {
+                        app.login.username.push(c);
                     }
                 }
                 LoginFocus::Password => {
-                    if can_add_password_char(app.login_password.len(), c) {
-                        app.login_password.push(c);
+                    if can_add_password_char(app.login.password.len(), c) {
+                        app.login.password.push(c);
                     }
                 }
                 LoginFocus::Button => {
@@ -424,25 +1589,48 @@ async fn handle_login_input(app: &mut App, key: KeyEvent) -> Result<bool> {
 }
 
 async fn handle_scouts_input(app: &mut App, key: KeyEvent) -> Result<()> {
-    let max_index = app.youth.len().saturating_sub(1);
+    let max_index = app.roster_rows().len().saturating_sub(1);
+
+    // Cross-references while viewing a rank's requirements
+    if app.viewing_requirements && app.ui.advancement_view == AdvancementView::Ranks && key.code == KeyCode::Char('x') {
+        app.crossref_expanded = !app.crossref_expanded;
+        return Ok(());
+    }
+
+    // Alternate requirement or accommodation for the selected requirement
+    if app.viewing_requirements && key.code == KeyCode::Char('n') {
+        app.start_accommodation_input();
+        return Ok(());
+    }
+
+    // Workbook links while viewing a merit badge's requirements
+    if app.viewing_requirements && app.ui.advancement_view == AdvancementView::MeritBadges {
+        if let KeyCode::Char(c @ ('w' | 'W' | 'i')) = key.code {
+            let badge_name = crate::ui::tabs::advancement::get_sorted_badges(&app.selected_youth_badges)
+                .get(app.advancement_badge_selection)
+                .map(|b| b.name.clone());
+            if let Some(name) = badge_name {
+                handle_workbook_key(app, c, &name);
+            }
+            return Ok(());
+        }
+    }
 
     // Detail view switching - 'd' and 'm' work regardless of focus
     // 'r' is handled separately below based on focus
     match key.code {
         KeyCode::Char('d') => {
-            app.scout_detail_view = ScoutDetailView::Details;
+            app.ui.scout_detail_view = ScoutDetailView::Details;
             app.viewing_requirements = false;
             return Ok(());
         }
         KeyCode::Char('b') => {
             // Get user_id before mutating app
-            let user_id = app.get_sorted_youth()
-                .get(app.roster_selection)
-                .and_then(|y| y.user_id);
+            let user_id = app.selected_youth().and_then(|y| y.user_id);
 
-            app.scout_detail_view = ScoutDetailView::MeritBadges;
-            app.advancement_view = AdvancementView::MeritBadges;
-            app.focus = Focus::Detail;
+            app.ui.scout_detail_view = ScoutDetailView::MeritBadges;
+            app.ui.advancement_view = AdvancementView::MeritBadges;
+            app.ui.focus = Focus::Detail;
             app.viewing_requirements = false;
             app.selected_rank_requirements.clear();
             app.selected_badge_requirements.clear();
@@ -458,14 +1646,12 @@ async fn handle_scouts_input(app: &mut App, key: KeyEvent) -> Result<()> {
             // 'r' behavior depends on focus:
             // - List focus: sort by rank
             // - Detail focus: switch to ranks view
-            if app.focus == Focus::Detail {
+            if app.ui.focus == Focus::Detail {
                 // Switch to Ranks view
-                let user_id = app.get_sorted_youth()
-                    .get(app.roster_selection)
-                    .and_then(|y| y.user_id);
+                let user_id = app.selected_youth().and_then(|y| y.user_id);
 
-                app.scout_detail_view = ScoutDetailView::Ranks;
-                app.advancement_view = AdvancementView::Ranks;
+                app.ui.scout_detail_view = ScoutDetailView::Ranks;
+                app.ui.advancement_view = AdvancementView::Ranks;
                 app.viewing_requirements = false;
                 app.selected_rank_requirements.clear();
                 app.selected_badge_requirements.clear();
@@ -484,12 +1670,10 @@ async fn handle_scouts_input(app: &mut App, key: KeyEvent) -> Result<()> {
         }
         KeyCode::Char('l') => {
             // Switch to Leadership view
-            let user_id = app.get_sorted_youth()
-                .get(app.roster_selection)
-                .and_then(|y| y.user_id);
+            let user_id = app.selected_youth().and_then(|y| y.user_id);
 
-            app.scout_detail_view = ScoutDetailView::Leadership;
-            app.focus = Focus::Detail;
+            app.ui.scout_detail_view = ScoutDetailView::Leadership;
+            app.ui.focus = Focus::Detail;
             app.viewing_requirements = false;
             app.leadership_selection = 0;
             // Load leadership data
@@ -500,12 +1684,10 @@ async fn handle_scouts_input(app: &mut App, key: KeyEvent) -> Result<()> {
         }
         KeyCode::Char('a') => {
             // Switch to Awards view
-            let user_id = app.get_sorted_youth()
-                .get(app.roster_selection)
-                .and_then(|y| y.user_id);
+            let user_id = app.selected_youth().and_then(|y| y.user_id);
 
-            app.scout_detail_view = ScoutDetailView::Awards;
-            app.focus = Focus::Detail;
+            app.ui.scout_detail_view = ScoutDetailView::Awards;
+            app.ui.focus = Focus::Detail;
             app.viewing_requirements = false;
             app.awards_selection = 0;
             app.selected_youth_awards.clear();
@@ -516,25 +1698,38 @@ async fn handle_scouts_input(app: &mut App, key: KeyEvent) -> Result<()> {
             }
             return Ok(());
         }
+        KeyCode::Char('t') => {
+            // Switch to Timeline view
+            app.ui.focus = Focus::Detail;
+            set_scout_detail_view(app, ScoutDetailView::Timeline).await;
+            return Ok(());
+        }
         _ => {}
     }
 
-    // Get sorted_youth reference for navigation
-    let sorted_youth = app.get_sorted_youth();
+    // Patrol headings collapse and expand in the grouped roster
+    if app.ui.focus == Focus::List
+        && matches!(key.code, KeyCode::Enter | KeyCode::Char(' '))
+        && app.toggle_selected_patrol()
+    {
+        return Ok(());
+    }
+
+    let selected_user_id = app.selected_youth().and_then(|y| y.user_id);
 
     // Handle navigation based on current view and focus
     match key.code {
         KeyCode::Char('j') | KeyCode::Down => {
             if app.viewing_requirements {
                 // Navigate through requirements
-                let max = match app.advancement_view {
+                let max = match app.ui.advancement_view {
                     AdvancementView::Ranks => app.selected_rank_requirements.len(),
                     AdvancementView::MeritBadges => app.selected_badge_requirements.len(),
                 }.saturating_sub(1);
                 app.requirement_selection = (app.requirement_selection + 1).min(max);
-            } else if app.focus == Focus::Detail {
+            } else if app.ui.focus == Focus::Detail {
                 // Navigate through ranks/badges/leadership
-                match app.scout_detail_view {
+                match app.ui.scout_detail_view {
                     ScoutDetailView::Ranks => {
                         // Reversed display order, so down = decrement
                         app.advancement_rank_selection = app.advancement_rank_selection.saturating_sub(1);
@@ -546,13 +1741,16 @@ async fn handle_scouts_input(app: &mut App, key: KeyEvent) -> Result<()> {
                     ScoutDetailView::Leadership => {
                         // Leadership view is not navigable
                     }
+                    ScoutDetailView::Timeline => {
+                        app.timeline_scroll += 1;
+                    }
                     _ => {}
                 }
             } else {
                 // Navigate scout list
-                let old_selection = app.roster_selection;
-                app.roster_selection = (app.roster_selection + 1).min(max_index);
-                if old_selection != app.roster_selection {
+                let old_selection = app.roster_selection.index;
+                app.roster_selection.index = (app.roster_selection.index + 1).min(max_index);
+                if old_selection != app.roster_selection.index {
                     // Clear progress data when changing scout
                     app.selected_youth_ranks.clear();
                     app.selected_youth_badges.clear();
@@ -570,8 +1768,8 @@ async fn handle_scouts_input(app: &mut App, key: KeyEvent) -> Result<()> {
         KeyCode::Char('k') | KeyCode::Up => {
             if app.viewing_requirements {
                 app.requirement_selection = app.requirement_selection.saturating_sub(1);
-            } else if app.focus == Focus::Detail {
-                match app.scout_detail_view {
+            } else if app.ui.focus == Focus::Detail {
+                match app.ui.scout_detail_view {
                     ScoutDetailView::Ranks => {
                         // Reversed display order, so up = increment
                         let max = app.selected_youth_ranks.len().saturating_sub(1);
@@ -583,12 +1781,15 @@ async fn handle_scouts_input(app: &mut App, key: KeyEvent) -> Result<()> {
                     ScoutDetailView::Leadership => {
                         // Leadership view is not navigable
                     }
+                    ScoutDetailView::Timeline => {
+                        app.timeline_scroll = app.timeline_scroll.saturating_sub(1);
+                    }
                     _ => {}
                 }
             } else {
-                let old_selection = app.roster_selection;
-                app.roster_selection = app.roster_selection.saturating_sub(1);
-                if old_selection != app.roster_selection {
+                let old_selection = app.roster_selection.index;
+                app.roster_selection.index = app.roster_selection.index.saturating_sub(1);
+                if old_selection != app.roster_selection.index {
                     app.selected_youth_ranks.clear();
                     app.selected_youth_badges.clear();
                     app.selected_youth_leadership.clear();
@@ -602,60 +1803,48 @@ async fn handle_scouts_input(app: &mut App, key: KeyEvent) -> Result<()> {
                 }
             }
         }
-        KeyCode::Home => {
-            if app.focus == Focus::List {
-                app.roster_selection = 0;
-                app.selected_youth_ranks.clear();
-                app.selected_youth_badges.clear();
-                app.selected_youth_leadership.clear();
-            }
-        }
-        KeyCode::End => {
-            if app.focus == Focus::List {
-                app.roster_selection = max_index;
-                app.selected_youth_ranks.clear();
-                app.selected_youth_badges.clear();
-                app.selected_youth_leadership.clear();
-            }
-        }
-        KeyCode::PageDown => {
-            if app.focus == Focus::List {
-                app.roster_selection = (app.roster_selection + PAGE_SCROLL_SIZE).min(max_index);
-                app.selected_youth_ranks.clear();
-                app.selected_youth_badges.clear();
-                app.selected_youth_leadership.clear();
-            }
-        }
-        KeyCode::PageUp => {
-            if app.focus == Focus::List {
-                app.roster_selection = app.roster_selection.saturating_sub(PAGE_SCROLL_SIZE);
-                app.selected_youth_ranks.clear();
-                app.selected_youth_badges.clear();
-                app.selected_youth_leadership.clear();
-            }
+        KeyCode::Home if app.ui.focus == Focus::List => {
+            app.roster_selection.index = 0;
+            app.selected_youth_ranks.clear();
+            app.selected_youth_badges.clear();
+            app.selected_youth_leadership.clear();
+        }
+        KeyCode::End if app.ui.focus == Focus::List => {
+            app.roster_selection.index = max_index;
+            app.selected_youth_ranks.clear();
+            app.selected_youth_badges.clear();
+            app.selected_youth_leadership.clear();
+        }
+        KeyCode::PageDown if app.ui.focus == Focus::List => {
+            app.roster_selection.index = (app.roster_selection.index + app.ui.layout_areas.list_page_size()).min(max_index);
+            app.selected_youth_ranks.clear();
+            app.selected_youth_badges.clear();
+            app.selected_youth_leadership.clear();
+        }
+        KeyCode::PageUp if app.ui.focus == Focus::List => {
+            app.roster_selection.index = app.roster_selection.index.saturating_sub(app.ui.layout_areas.list_page_size());
+            app.selected_youth_ranks.clear();
+            app.selected_youth_badges.clear();
+            app.selected_youth_leadership.clear();
         }
         KeyCode::Enter => {
-            match app.focus {
+            match app.ui.focus {
                 Focus::List => {
                     // Load progress for selected scout and switch to detail
-                    if let Some(youth) = sorted_youth.get(app.roster_selection) {
-                        if let Some(user_id) = youth.user_id {
-                            app.fetch_youth_progress(user_id).await;
-                            app.focus = Focus::Detail;
-                        }
+                    if let Some(user_id) = selected_user_id {
+                        app.fetch_youth_progress(user_id).await;
+                        app.ui.focus = Focus::Detail;
                     }
                 }
                 Focus::Detail => {
                     if !app.viewing_requirements {
-                        match app.scout_detail_view {
+                        match app.ui.scout_detail_view {
                             ScoutDetailView::Ranks => {
                                 // Load requirements for selected rank
                                 if let Some(rank) = app.selected_youth_ranks.get(app.advancement_rank_selection) {
                                     let rank_id = rank.rank_id;
-                                    if let Some(youth) = sorted_youth.get(app.roster_selection) {
-                                        if let Some(user_id) = youth.user_id {
-                                            app.fetch_rank_requirements(user_id, rank_id).await;
-                                        }
+                                    if let Some(user_id) = selected_user_id {
+                                        app.fetch_rank_requirements(user_id, rank_id).await;
                                     }
                                 }
                             }
@@ -664,39 +1853,71 @@ async fn handle_scouts_input(app: &mut App, key: KeyEvent) -> Result<()> {
                                 let sorted_badges = crate::ui::tabs::advancement::get_sorted_badges(&app.selected_youth_badges);
                                 if let Some(badge) = sorted_badges.get(app.advancement_badge_selection) {
                                     let badge_id = badge.id;
-                                    if let Some(youth) = sorted_youth.get(app.roster_selection) {
-                                        if let Some(user_id) = youth.user_id {
-                                            app.fetch_badge_requirements(user_id, badge_id).await;
-                                        }
+                                    if let Some(user_id) = selected_user_id {
+                                        app.fetch_badge_requirements(user_id, badge_id).await;
                                     }
                                 }
                             }
-                            ScoutDetailView::Details | ScoutDetailView::Leadership | ScoutDetailView::Awards => {}
+                            ScoutDetailView::Details
+                            | ScoutDetailView::Leadership
+                            | ScoutDetailView::Awards
+                            | ScoutDetailView::Timeline => {}
                         }
                     }
                 }
             }
         }
         // Sort keys (only in list focus)
-        KeyCode::Char('n') if app.focus == Focus::List => {
+        KeyCode::Char('n') if app.ui.focus == Focus::List => {
             app.toggle_scout_sort(ScoutSortColumn::Name);
         }
-        KeyCode::Char('g') if app.focus == Focus::List => {
+        KeyCode::Char('g') if app.ui.focus == Focus::List => {
             app.toggle_scout_sort(ScoutSortColumn::Grade);
         }
-        KeyCode::Char('a') if app.focus == Focus::List => {
+        KeyCode::Char('a') if app.ui.focus == Focus::List => {
             app.toggle_scout_sort(ScoutSortColumn::Age);
         }
-        KeyCode::Char('p') if app.focus == Focus::List => {
+        KeyCode::Char('p') if app.ui.focus == Focus::List => {
             app.toggle_scout_sort(ScoutSortColumn::Patrol);
         }
-        KeyCode::Char('s') if app.focus == Focus::List => {
-            app.scout_sort_column = app.scout_sort_column.next();
-            app.scout_sort_ascending = true;
-            app.roster_selection = 0;
+        KeyCode::Char('p') => {
+            app.start_patrol_move();
+        }
+        KeyCode::Char('s') if app.ui.focus == Focus::List => {
+            app.cycle_scout_sort();
+        }
+        KeyCode::Char('S') if app.ui.focus == Focus::List => {
+            app.reverse_scout_sort();
+        }
+        KeyCode::Char('C') => {
+            app.export_conference_sheet();
+        }
+        KeyCode::Char('y') => {
+            app.toggle_my_scout();
         }
-        KeyCode::Char('S') if app.focus == Focus::List => {
-            app.scout_sort_ascending = !app.scout_sort_ascending;
+        KeyCode::Char('F') => {
+            app.toggle_consent(ConsentForm::ActivityConsent);
+        }
+        KeyCode::Char('R') => {
+            app.toggle_consent(ConsentForm::MediaRelease);
+        }
+        KeyCode::Char('G') => {
+            app.toggle_roster_grouping();
+        }
+        KeyCode::Char('H') => {
+            app.show_medication_log();
+        }
+        KeyCode::Char('O') => {
+            app.show_oa_eligibility();
+        }
+        KeyCode::Char('D') => {
+            if let Some(uid) = app.feed_scout().and_then(|y| y.user_id) {
+                app.ui.scout_detail_view = ScoutDetailView::Leadership;
+                app.ui.focus = Focus::Detail;
+                app.viewing_requirements = false;
+                app.fetch_youth_leadership(uid).await;
+                app.start_den_chief_link();
+            }
         }
         _ => {}
     }
@@ -704,26 +1925,35 @@ async fn handle_scouts_input(app: &mut App, key: KeyEvent) -> Result<()> {
 }
 
 async fn handle_adults_input(app: &mut App, key: KeyEvent) -> Result<()> {
-    let max_index = app.adults.len().saturating_sub(1);
+    let max_index = app.store.adults.len().saturating_sub(1);
 
     match key.code {
         KeyCode::Char('j') | KeyCode::Down => {
-            app.adults_selection = (app.adults_selection + 1).min(max_index);
+            app.adults_selection.index = (app.adults_selection.index + 1).min(max_index);
         }
         KeyCode::Char('k') | KeyCode::Up => {
-            app.adults_selection = app.adults_selection.saturating_sub(1);
+            app.adults_selection.index = app.adults_selection.index.saturating_sub(1);
         }
         KeyCode::Home => {
-            app.adults_selection = 0;
+            app.adults_selection.index = 0;
         }
         KeyCode::End => {
-            app.adults_selection = max_index;
+            app.adults_selection.index = max_index;
         }
         KeyCode::PageDown => {
-            app.adults_selection = (app.adults_selection + PAGE_SCROLL_SIZE).min(max_index);
+            app.adults_selection.index = (app.adults_selection.index + app.ui.layout_areas.list_page_size()).min(max_index);
         }
         KeyCode::PageUp => {
-            app.adults_selection = app.adults_selection.saturating_sub(PAGE_SCROLL_SIZE);
+            app.adults_selection.index = app.adults_selection.index.saturating_sub(app.ui.layout_areas.list_page_size());
+        }
+        KeyCode::Char('w') => {
+            app.show_adult_awards();
+        }
+        KeyCode::Char('n') => {
+            app.show_onboarding();
+        }
+        KeyCode::Char('c') => {
+            app.show_committee_roles();
         }
         _ => {}
     }
@@ -736,23 +1966,71 @@ async fn handle_events_input(app: &mut App, key: KeyEvent) -> Result<()> {
 
     // Check if selected event has RSVP enabled
     let rsvp_enabled = sorted_events
-        .get(app.event_selection)
+        .get(app.event_selection.index)
         .map(|e| e.rsvp)
         .unwrap_or(false);
 
-    match app.focus {
+    match key.code {
+        KeyCode::Char('E') => {
+            app.export_emergency_sheet();
+            return Ok(());
+        }
+        KeyCode::Char('S') => {
+            app.save_event_template();
+            return Ok(());
+        }
+        KeyCode::Char('L') => {
+            app.show_event_templates();
+            return Ok(());
+        }
+        KeyCode::Char('H') => {
+            app.show_historian_log();
+            return Ok(());
+        }
+        KeyCode::Char('W') => {
+            app.show_minutes();
+            return Ok(());
+        }
+        KeyCode::Char('R') => {
+            app.export_incident_report();
+            return Ok(());
+        }
+        KeyCode::Char('U') => {
+            app.show_buddy_pairs();
+            return Ok(());
+        }
+        KeyCode::Char('C') => {
+            app.show_activity_plan();
+            return Ok(());
+        }
+        KeyCode::Char('B') => {
+            app.start_debrief_note();
+            return Ok(());
+        }
+        KeyCode::Char('X') => {
+            app.remove_debrief_note();
+            return Ok(());
+        }
+        KeyCode::Char('i') => {
+            app.export_ical();
+            return Ok(());
+        }
+        _ => {}
+    }
+
+    match app.ui.focus {
         Focus::List => {
             match key.code {
                 KeyCode::Char('j') | KeyCode::Down => {
-                    app.event_selection = (app.event_selection + 1).min(max_event);
+                    app.event_selection.index = (app.event_selection.index + 1).min(max_event);
                     app.event_guest_selection = 0;
                 }
                 KeyCode::Char('k') | KeyCode::Up => {
-                    app.event_selection = app.event_selection.saturating_sub(1);
+                    app.event_selection.index = app.event_selection.index.saturating_sub(1);
                     app.event_guest_selection = 0;
                 }
                 KeyCode::Enter => {
-                    app.focus = Focus::Detail;
+                    app.ui.focus = Focus::Detail;
                 }
                 // Sort keys - toggle ascending/descending if same column
                 KeyCode::Char('n') => {
@@ -767,32 +2045,35 @@ async fn handle_events_input(app: &mut App, key: KeyEvent) -> Result<()> {
                 KeyCode::Char('t') => {
                     app.toggle_event_sort(EventSortColumn::Type);
                 }
+                KeyCode::Char('v') => {
+                    app.cycle_event_list_view();
+                }
                 _ => {}
             }
         }
         Focus::Detail => {
             match key.code {
                 KeyCode::Char('d') => {
-                    app.event_detail_view = EventDetailView::Details;
+                    app.ui.event_detail_view = EventDetailView::Details;
                 }
                 KeyCode::Char('r') if rsvp_enabled => {
-                    app.event_detail_view = EventDetailView::Rsvp;
+                    app.show_event_rsvp().await;
                 }
                 KeyCode::Enter if rsvp_enabled => {
-                    app.event_detail_view = EventDetailView::Rsvp;
+                    app.show_event_rsvp().await;
                 }
                 KeyCode::Esc => {
-                    if app.event_detail_view == EventDetailView::Rsvp {
-                        app.event_detail_view = EventDetailView::Details;
+                    if app.ui.event_detail_view == EventDetailView::Rsvp {
+                        app.ui.event_detail_view = EventDetailView::Details;
                     } else {
-                        app.focus = Focus::List;
+                        app.ui.focus = Focus::List;
                     }
                 }
                 KeyCode::Left | KeyCode::Right if rsvp_enabled => {
-                    app.event_detail_view = match app.event_detail_view {
-                        EventDetailView::Details => EventDetailView::Rsvp,
-                        EventDetailView::Rsvp => EventDetailView::Details,
-                    };
+                    match app.ui.event_detail_view {
+                        EventDetailView::Details => app.show_event_rsvp().await,
+                        EventDetailView::Rsvp => app.ui.event_detail_view = EventDetailView::Details,
+                    }
                 }
                 _ => {}
             }
@@ -801,21 +2082,86 @@ async fn handle_events_input(app: &mut App, key: KeyEvent) -> Result<()> {
     Ok(())
 }
 
-async fn handle_dashboard_input(_app: &mut App, _key: KeyEvent) -> Result<()> {
-    // Dashboard tab is display-only, no special input handling needed
+async fn handle_dashboard_input(app: &mut App, key: KeyEvent) -> Result<()> {
     // Navigation between tabs is handled by global keys
+    match key.code {
+        KeyCode::Char('e') => app.export_committee_report(),
+        KeyCode::Char('p') => app.show_position_planner(),
+        KeyCode::Char('l') => app.show_linked(),
+        KeyCode::Char('c') => app.export_charter_report(),
+        KeyCode::Char('a') => app.export_court_of_honor(),
+        _ => {}
+    }
     Ok(())
 }
 
+fn handle_eagle_input(app: &mut App, key: KeyEvent) {
+    let max_index = app.eagle_rows().len().saturating_sub(1);
+
+    match key.code {
+        KeyCode::Char('j') | KeyCode::Down => {
+            app.eagle_selection.index = (app.eagle_selection.index + 1).min(max_index);
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            app.eagle_selection.index = app.eagle_selection.index.saturating_sub(1);
+        }
+        KeyCode::Home => {
+            app.eagle_selection.index = 0;
+        }
+        KeyCode::End => {
+            app.eagle_selection.index = max_index;
+        }
+        KeyCode::PageDown => {
+            app.eagle_selection.index = (app.eagle_selection.index + app.ui.layout_areas.list_page_size()).min(max_index);
+        }
+        KeyCode::PageUp => {
+            app.eagle_selection.index = app.eagle_selection.index.saturating_sub(app.ui.layout_areas.list_page_size());
+        }
+        KeyCode::Char('c') => app.toggle_eagle_sort(EagleSortColumn::Closest),
+        KeyCode::Char('n') => app.toggle_eagle_sort(EagleSortColumn::Name),
+        KeyCode::Char('b') => app.toggle_eagle_sort(EagleSortColumn::Badges),
+        KeyCode::Char('d') => app.toggle_eagle_sort(EagleSortColumn::Deadline),
+        KeyCode::Enter => app.show_eagle_project(),
+        _ => {}
+    }
+}
+
+fn handle_attendance_input(app: &mut App, key: KeyEvent) {
+    let max_index = app.attendance_rows().len().saturating_sub(1);
+
+    match key.code {
+        KeyCode::Char('j') | KeyCode::Down => {
+            app.attendance_selection.index = (app.attendance_selection.index + 1).min(max_index);
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            app.attendance_selection.index = app.attendance_selection.index.saturating_sub(1);
+        }
+        KeyCode::Home => {
+            app.attendance_selection.index = 0;
+        }
+        KeyCode::End => {
+            app.attendance_selection.index = max_index;
+        }
+        KeyCode::PageDown => {
+            app.attendance_selection.index = (app.attendance_selection.index + app.ui.layout_areas.list_page_size()).min(max_index);
+        }
+        KeyCode::PageUp => {
+            app.attendance_selection.index = app.attendance_selection.index.saturating_sub(app.ui.layout_areas.list_page_size());
+        }
+        KeyCode::Char('p') => app.toggle_attendance_sort(),
+        _ => {}
+    }
+}
+
 async fn handle_ranks_input(app: &mut App, key: KeyEvent) -> Result<()> {
     use crate::ui::tabs::ranks::{get_ranks_with_scouts, get_rank_list};
 
-    let rank_list = get_rank_list(&app.youth, &app.all_youth_ranks, app.ranks_sort_by_count, app.ranks_sort_ascending);
-    let grouped = get_ranks_with_scouts(&app.youth, &app.all_youth_ranks);
+    let rank_list = get_rank_list(&app.store.youth, &app.store.all_youth_ranks, app.ranks_sort_by_count, app.ranks_sort_ascending);
+    let grouped = get_ranks_with_scouts(&app.store.youth, &app.store.all_youth_ranks);
     let max_rank = rank_list.len().saturating_sub(1);
 
     // Get scouts for current selection using sorted list
-    let selected_rank_name = rank_list.get(app.ranks_selection)
+    let selected_rank_name = rank_list.get(app.ranks_selection.index)
         .map(|(name, _)| name.as_str())
         .unwrap_or("");
     let max_scout = grouped.iter()
@@ -823,27 +2169,27 @@ async fn handle_ranks_input(app: &mut App, key: KeyEvent) -> Result<()> {
         .map(|g| g.scouts.len().saturating_sub(1))
         .unwrap_or(0);
 
-    match app.focus {
+    match app.ui.focus {
         Focus::List => {
             match key.code {
                 KeyCode::Char('j') | KeyCode::Down => {
-                    app.ranks_selection = (app.ranks_selection + 1).min(max_rank);
+                    app.ranks_selection.index = (app.ranks_selection.index + 1).min(max_rank);
                     app.ranks_scout_selection = 0;
                 }
                 KeyCode::Char('k') | KeyCode::Up => {
-                    app.ranks_selection = app.ranks_selection.saturating_sub(1);
+                    app.ranks_selection.index = app.ranks_selection.index.saturating_sub(1);
                     app.ranks_scout_selection = 0;
                 }
                 KeyCode::Enter => {
-                    app.focus = Focus::Detail;
+                    app.ui.focus = Focus::Detail;
                     app.ranks_scout_selection = 0;
                 }
                 KeyCode::Home => {
-                    app.ranks_selection = 0;
+                    app.ranks_selection.index = 0;
                     app.ranks_scout_selection = 0;
                 }
                 KeyCode::End => {
-                    app.ranks_selection = max_rank;
+                    app.ranks_selection.index = max_rank;
                     app.ranks_scout_selection = 0;
                 }
                 KeyCode::Char('n') => {
@@ -852,6 +2198,9 @@ async fn handle_ranks_input(app: &mut App, key: KeyEvent) -> Result<()> {
                 KeyCode::Char('c') => {
                     app.toggle_ranks_sort_by_count();
                 }
+                KeyCode::Char('d') => {
+                    app.show_date_correction();
+                }
                 _ => {}
             }
         }
@@ -866,6 +2215,12 @@ async fn handle_ranks_input(app: &mut App, key: KeyEvent) -> Result<()> {
                     KeyCode::Char('k') | KeyCode::Up => {
                         app.ranks_requirement_selection = app.ranks_requirement_selection.saturating_sub(1);
                     }
+                    KeyCode::Char('x') => {
+                        app.crossref_expanded = !app.crossref_expanded;
+                    }
+                    KeyCode::Char('c') => {
+                        app.start_requirement_completion();
+                    }
                     KeyCode::Esc => {
                         // Exit requirements view but stay in right panel
                         app.ranks_viewing_requirements = false;
@@ -902,7 +2257,7 @@ async fn handle_ranks_input(app: &mut App, key: KeyEvent) -> Result<()> {
                         }
                     }
                     KeyCode::Esc => {
-                        app.focus = Focus::List;
+                        app.ui.focus = Focus::List;
                     }
                     KeyCode::Home => {
                         app.ranks_scout_selection = 0;
@@ -918,15 +2273,25 @@ async fn handle_ranks_input(app: &mut App, key: KeyEvent) -> Result<()> {
     Ok(())
 }
 
+/// Workbook shortcuts shared by the merit badge requirement views.
+fn handle_workbook_key(app: &mut App, key: char, badge_name: &str) {
+    match key {
+        'w' => app.open_workbook(badge_name),
+        'W' => app.download_workbook(badge_name),
+        'i' => app.open_badge_info(badge_name),
+        _ => {}
+    }
+}
+
 async fn handle_badges_input(app: &mut App, key: KeyEvent) -> Result<()> {
     use crate::ui::tabs::badges::{get_badges_with_scouts, get_badge_list};
 
-    let badge_list = get_badge_list(&app.youth, &app.all_youth_badges, app.badges_sort_by_count, app.badges_sort_ascending);
-    let grouped = get_badges_with_scouts(&app.youth, &app.all_youth_badges);
+    let badge_list = get_badge_list(&app.store.youth, &app.store.all_youth_badges, app.badges_sort_by_count, app.badges_sort_ascending);
+    let grouped = get_badges_with_scouts(&app.store.youth, &app.store.all_youth_badges);
     let max_badge = badge_list.len().saturating_sub(1);
 
     // Get scouts for current selection using sorted list
-    let selected_badge_name = badge_list.get(app.badges_selection)
+    let selected_badge_name = badge_list.get(app.badges_selection.index)
         .map(|(name, _, _)| name.as_str())
         .unwrap_or("");
     let max_scout = grouped.iter()
@@ -934,31 +2299,27 @@ async fn handle_badges_input(app: &mut App, key: KeyEvent) -> Result<()> {
         .map(|g| g.scouts.len().saturating_sub(1))
         .unwrap_or(0);
 
-    match app.focus {
+    match app.ui.focus {
         Focus::List => {
             match key.code {
-                KeyCode::Char('j') | KeyCode::Down => {
-                    if !badge_list.is_empty() {
-                        app.badges_selection = (app.badges_selection + 1).min(max_badge);
-                        app.badges_scout_selection = 0;
-                    }
+                KeyCode::Char('j') | KeyCode::Down if !badge_list.is_empty() => {
+                    app.badges_selection.index = (app.badges_selection.index + 1).min(max_badge);
+                    app.badges_scout_selection = 0;
                 }
                 KeyCode::Char('k') | KeyCode::Up => {
-                    app.badges_selection = app.badges_selection.saturating_sub(1);
+                    app.badges_selection.index = app.badges_selection.index.saturating_sub(1);
                     app.badges_scout_selection = 0;
                 }
-                KeyCode::Enter => {
-                    if !badge_list.is_empty() {
-                        app.focus = Focus::Detail;
-                        app.badges_scout_selection = 0;
-                    }
+                KeyCode::Enter if !badge_list.is_empty() => {
+                    app.ui.focus = Focus::Detail;
+                    app.badges_scout_selection = 0;
                 }
                 KeyCode::Home => {
-                    app.badges_selection = 0;
+                    app.badges_selection.index = 0;
                     app.badges_scout_selection = 0;
                 }
                 KeyCode::End => {
-                    app.badges_selection = max_badge;
+                    app.badges_selection.index = max_badge;
                     app.badges_scout_selection = 0;
                 }
                 KeyCode::Char('n') => {
@@ -967,6 +2328,9 @@ async fn handle_badges_input(app: &mut App, key: KeyEvent) -> Result<()> {
                 KeyCode::Char('c') => {
                     app.toggle_badges_sort_by_count();
                 }
+                KeyCode::Char('O') => {
+                    app.show_counselors();
+                }
                 _ => {}
             }
         }
@@ -981,6 +2345,9 @@ async fn handle_badges_input(app: &mut App, key: KeyEvent) -> Result<()> {
                     KeyCode::Char('k') | KeyCode::Up => {
                         app.badges_requirement_selection = app.badges_requirement_selection.saturating_sub(1);
                     }
+                    KeyCode::Char(c @ ('w' | 'W' | 'i')) => {
+                        handle_workbook_key(app, c, selected_badge_name);
+                    }
                     KeyCode::Esc => {
                         // Exit requirements view but stay in right panel
                         app.badges_viewing_requirements = false;
@@ -1017,7 +2384,7 @@ async fn handle_badges_input(app: &mut App, key: KeyEvent) -> Result<()> {
                         }
                     }
                     KeyCode::Esc => {
-                        app.focus = Focus::List;
+                        app.ui.focus = Focus::List;
                     }
                     KeyCode::Home => {
                         app.badges_scout_selection = 0;
@@ -1036,17 +2403,17 @@ async fn handle_badges_input(app: &mut App, key: KeyEvent) -> Result<()> {
 /// Handle mouse events. Returns true if the app should quit.
 pub async fn handle_mouse(app: &mut App, mouse: MouseEvent) -> Result<bool> {
     // Ignore mouse during overlays
-    if !matches!(app.state, AppState::Normal) {
+    if !matches!(app.ui.state, AppState::Normal) {
         return Ok(false);
     }
 
     match mouse.kind {
         MouseEventKind::Down(MouseButton::Left) => {
             let now = Instant::now();
-            let is_double = app.last_click.is_some_and(|(lc, lr, lt)| {
+            let is_double = app.ui.last_click.is_some_and(|(lc, lr, lt)| {
                 lc == mouse.column && lr == mouse.row && now.duration_since(lt).as_millis() < DOUBLE_CLICK_MS
             });
-            app.last_click = Some((mouse.column, mouse.row, now));
+            app.ui.last_click = Some((mouse.column, mouse.row, now));
 
             if is_double {
                 handle_double_click(app, mouse.column, mouse.row).await
@@ -1080,43 +2447,43 @@ fn clicked_table_row(click_row: u16, panel_area: Rect, table_state: &TableState)
 /// Handle left click events
 async fn handle_left_click(app: &mut App, col: u16, row: u16) -> Result<bool> {
     // Check if click is on "[?] Help" in the title bar (right-aligned)
-    if click_in_area(col, row, app.layout_areas.title_bar) {
+    if click_in_area(col, row, app.ui.layout_areas.title_bar) {
         let help_text_len = 8u16; // "[?] Help"
-        let help_start = app.layout_areas.title_bar.right().saturating_sub(help_text_len + 2);
+        let help_start = app.ui.layout_areas.title_bar.right().saturating_sub(help_text_len + 2);
         if col >= help_start {
-            app.state = AppState::ShowingHelp;
+            app.ui.state = AppState::ShowingHelp;
             return Ok(false);
         }
     }
 
     // Check if click is in detail tabs area (right side of tabs bar)
-    if app.layout_areas.detail_tabs_area.width > 0
-        && click_in_area(col, row, app.layout_areas.detail_tabs_area)
+    if app.ui.layout_areas.detail_tabs_area.width > 0
+        && click_in_area(col, row, app.ui.layout_areas.detail_tabs_area)
     {
         handle_detail_tab_click(app, col).await;
         return Ok(false);
     }
 
     // Check if click is in tabs bar
-    if click_in_area(col, row, app.layout_areas.tabs_bar) {
+    if click_in_area(col, row, app.ui.layout_areas.tabs_bar) {
         handle_tab_click(app, col);
         return Ok(false);
     }
 
     // Check if click is in left panel
-    if click_in_area(col, row, app.layout_areas.left_panel) {
-        if let Some(index) = clicked_table_row(row, app.layout_areas.left_panel, &app.left_table_state) {
+    if click_in_area(col, row, app.ui.layout_areas.left_panel) {
+        if let Some(index) = clicked_table_row(row, app.ui.layout_areas.left_panel, &app.ui.left_table_state) {
             handle_left_panel_click(app, index);
         }
         return Ok(false);
     }
 
     // Check if click is in right panel
-    if click_in_area(col, row, app.layout_areas.right_panel) {
+    if click_in_area(col, row, app.ui.layout_areas.right_panel) {
         // Scouts tab uses Paragraph (not Table) for the detail panel
-        if app.current_tab == Tab::Scouts {
+        if app.ui.current_tab == Tab::Scouts {
             handle_right_panel_click(app, 0, row);
-        } else if let Some(index) = clicked_table_row(row, app.layout_areas.right_panel, &app.right_table_state) {
+        } else if let Some(index) = clicked_table_row(row, app.ui.layout_areas.right_panel, &app.ui.right_table_state) {
             handle_right_panel_click(app, index, row);
         }
         return Ok(false);
@@ -1127,19 +2494,19 @@ async fn handle_left_click(app: &mut App, col: u16, row: u16) -> Result<bool> {
 
 /// Handle double-click: acts like pressing Enter on the clicked item
 async fn handle_double_click(app: &mut App, col: u16, row: u16) -> Result<bool> {
-    if click_in_area(col, row, app.layout_areas.left_panel) {
-        if let Some(index) = clicked_table_row(row, app.layout_areas.left_panel, &app.left_table_state) {
+    if click_in_area(col, row, app.ui.layout_areas.left_panel) {
+        if let Some(index) = clicked_table_row(row, app.ui.layout_areas.left_panel, &app.ui.left_table_state) {
             handle_left_panel_click(app, index);
             handle_enter(app).await?;
         }
         return Ok(false);
     }
 
-    if click_in_area(col, row, app.layout_areas.right_panel) {
+    if click_in_area(col, row, app.ui.layout_areas.right_panel) {
         // Scouts tab uses Paragraph layout — pass raw row for position calc
-        if app.current_tab == Tab::Scouts {
+        if app.ui.current_tab == Tab::Scouts {
             handle_right_panel_click(app, 0, row);
-        } else if let Some(index) = clicked_table_row(row, app.layout_areas.right_panel, &app.right_table_state) {
+        } else if let Some(index) = clicked_table_row(row, app.ui.layout_areas.right_panel, &app.ui.right_table_state) {
             handle_right_panel_click(app, index, row);
         }
         handle_enter(app).await?;
@@ -1156,9 +2523,9 @@ fn click_in_area(col: u16, row: u16, area: Rect) -> bool {
 
 /// Handle click on the detail tabs (right side of tab bar)
 async fn handle_detail_tab_click(app: &mut App, col: u16) {
-    let rel_x = col.saturating_sub(app.layout_areas.detail_tabs_area.x) as usize;
+    let rel_x = col.saturating_sub(app.ui.layout_areas.detail_tabs_area.x) as usize;
 
-    match app.current_tab {
+    match app.ui.current_tab {
         Tab::Scouts => {
             let tabs = [
                 (ScoutDetailView::Details, "[d]etails"),
@@ -1166,6 +2533,7 @@ async fn handle_detail_tab_click(app: &mut App, col: u16) {
                 (ScoutDetailView::MeritBadges, "[b]adges"),
                 (ScoutDetailView::Leadership, "[l]eadership"),
                 (ScoutDetailView::Awards, "[a]wards"),
+                (ScoutDetailView::Timeline, "[t]imeline"),
             ];
 
             let mut x_pos = 0usize;
@@ -1188,7 +2556,10 @@ async fn handle_detail_tab_click(app: &mut App, col: u16) {
             for (view, label) in &tabs {
                 let label_end = x_pos + label.len();
                 if rel_x >= x_pos && rel_x < label_end {
-                    app.event_detail_view = *view;
+                    match view {
+                        EventDetailView::Rsvp => app.show_event_rsvp().await,
+                        EventDetailView::Details => app.ui.event_detail_view = EventDetailView::Details,
+                    }
                     return;
                 }
                 x_pos = label_end + 3;
@@ -1200,23 +2571,24 @@ async fn handle_detail_tab_click(app: &mut App, col: u16) {
 
 /// Set the scout detail view directly and load data as needed.
 async fn set_scout_detail_view(app: &mut App, new_view: ScoutDetailView) {
-    let user_id = app.get_sorted_youth()
-        .get(app.roster_selection)
-        .and_then(|y| y.user_id);
+    let user_id = app.selected_youth().and_then(|y| y.user_id);
 
-    app.scout_detail_view = new_view;
-    app.advancement_view = match new_view {
+    app.ui.scout_detail_view = new_view;
+    app.ui.advancement_view = match new_view {
         ScoutDetailView::Ranks => AdvancementView::Ranks,
         ScoutDetailView::MeritBadges => AdvancementView::MeritBadges,
-        ScoutDetailView::Details | ScoutDetailView::Leadership | ScoutDetailView::Awards => app.advancement_view,
+        ScoutDetailView::Details | ScoutDetailView::Leadership | ScoutDetailView::Awards | ScoutDetailView::Timeline => {
+            app.ui.advancement_view
+        }
     };
     app.viewing_requirements = false;
     app.advancement_rank_selection = app.selected_youth_ranks.len().saturating_sub(1);
     app.advancement_badge_selection = 0;
     app.leadership_selection = 0;
     app.awards_selection = 0;
+    app.timeline_scroll = 0;
 
-    if new_view == ScoutDetailView::Awards {
+    if matches!(new_view, ScoutDetailView::Awards | ScoutDetailView::Timeline) {
         app.selected_youth_awards.clear();
         app.awards_loaded = false;
     }
@@ -1232,6 +2604,9 @@ async fn set_scout_detail_view(app: &mut App, new_view: ScoutDetailView) {
             ScoutDetailView::Awards => {
                 app.fetch_youth_awards(uid).await;
             }
+            ScoutDetailView::Timeline => {
+                app.fetch_youth_timeline(uid).await;
+            }
             ScoutDetailView::Details => {}
         }
     }
@@ -1239,28 +2614,27 @@ async fn set_scout_detail_view(app: &mut App, new_view: ScoutDetailView) {
 
 /// Simulate pressing Enter for the current tab/focus state.
 async fn handle_enter(app: &mut App) -> Result<()> {
-    match app.current_tab {
+    match app.ui.current_tab {
         Tab::Scouts => {
-            let sorted_youth = app.get_sorted_youth();
-            match app.focus {
+            if app.ui.focus == Focus::List && app.toggle_selected_patrol() {
+                return Ok(());
+            }
+            let selected_user_id = app.selected_youth().and_then(|y| y.user_id);
+            match app.ui.focus {
                 Focus::List => {
-                    if let Some(youth) = sorted_youth.get(app.roster_selection) {
-                        if let Some(user_id) = youth.user_id {
-                            app.fetch_youth_progress(user_id).await;
-                            app.focus = Focus::Detail;
-                        }
+                    if let Some(user_id) = selected_user_id {
+                        app.fetch_youth_progress(user_id).await;
+                        app.ui.focus = Focus::Detail;
                     }
                 }
                 Focus::Detail => {
                     if !app.viewing_requirements {
-                        match app.scout_detail_view {
+                        match app.ui.scout_detail_view {
                             ScoutDetailView::Ranks => {
                                 if let Some(rank) = app.selected_youth_ranks.get(app.advancement_rank_selection) {
                                     let rank_id = rank.rank_id;
-                                    if let Some(youth) = sorted_youth.get(app.roster_selection) {
-                                        if let Some(user_id) = youth.user_id {
-                                            app.fetch_rank_requirements(user_id, rank_id).await;
-                                        }
+                                    if let Some(user_id) = selected_user_id {
+                                        app.fetch_rank_requirements(user_id, rank_id).await;
                                     }
                                 }
                             }
@@ -1268,10 +2642,8 @@ async fn handle_enter(app: &mut App) -> Result<()> {
                                 let sorted_badges = crate::ui::tabs::advancement::get_sorted_badges(&app.selected_youth_badges);
                                 if let Some(badge) = sorted_badges.get(app.advancement_badge_selection) {
                                     let badge_id = badge.id;
-                                    if let Some(youth) = sorted_youth.get(app.roster_selection) {
-                                        if let Some(user_id) = youth.user_id {
-                                            app.fetch_badge_requirements(user_id, badge_id).await;
-                                        }
+                                    if let Some(user_id) = selected_user_id {
+                                        app.fetch_badge_requirements(user_id, badge_id).await;
                                     }
                                 }
                             }
@@ -1281,24 +2653,22 @@ async fn handle_enter(app: &mut App) -> Result<()> {
                 }
             }
         }
-        Tab::Events => {
-            if app.focus == Focus::List {
-                app.focus = Focus::Detail;
-            }
+        Tab::Events if app.ui.focus == Focus::List => {
+            app.ui.focus = Focus::Detail;
         }
         Tab::Ranks => {
-            match app.focus {
+            match app.ui.focus {
                 Focus::List => {
-                    app.focus = Focus::Detail;
+                    app.ui.focus = Focus::Detail;
                     app.ranks_scout_selection = 0;
                 }
                 Focus::Detail if !app.ranks_viewing_requirements => {
-                    let grouped = crate::ui::tabs::ranks::get_ranks_with_scouts(&app.youth, &app.all_youth_ranks);
+                    let grouped = crate::ui::tabs::ranks::get_ranks_with_scouts(&app.store.youth, &app.store.all_youth_ranks);
                     let rank_list = crate::ui::tabs::ranks::get_rank_list(
-                        &app.youth, &app.all_youth_ranks,
+                        &app.store.youth, &app.store.all_youth_ranks,
                         app.ranks_sort_by_count, app.ranks_sort_ascending,
                     );
-                    let selected_rank_name = rank_list.get(app.ranks_selection)
+                    let selected_rank_name = rank_list.get(app.ranks_selection.index)
                         .map(|(name, _)| name.as_str())
                         .unwrap_or("");
                     if let Some(scouts) = grouped.iter()
@@ -1320,18 +2690,18 @@ async fn handle_enter(app: &mut App) -> Result<()> {
             }
         }
         Tab::Badges => {
-            match app.focus {
+            match app.ui.focus {
                 Focus::List => {
-                    app.focus = Focus::Detail;
+                    app.ui.focus = Focus::Detail;
                     app.badges_scout_selection = 0;
                 }
                 Focus::Detail if !app.badges_viewing_requirements => {
-                    let grouped = crate::ui::tabs::badges::get_badges_with_scouts(&app.youth, &app.all_youth_badges);
+                    let grouped = crate::ui::tabs::badges::get_badges_with_scouts(&app.store.youth, &app.store.all_youth_badges);
                     let badge_list = crate::ui::tabs::badges::get_badge_list(
-                        &app.youth, &app.all_youth_badges,
+                        &app.store.youth, &app.store.all_youth_badges,
                         app.badges_sort_by_count, app.badges_sort_ascending,
                     );
-                    let selected_badge_name = badge_list.get(app.badges_selection)
+                    let selected_badge_name = badge_list.get(app.badges_selection.index)
                         .map(|(name, _, _)| name.as_str())
                         .unwrap_or("");
                     if let Some(scouts) = grouped.iter()
@@ -1357,30 +2727,21 @@ async fn handle_enter(app: &mut App) -> Result<()> {
 
 /// Handle click on the tab bar - switch tabs based on x coordinate
 fn handle_tab_click(app: &mut App, col: u16) {
-    use crate::app::Tab;
-
-    // Tab labels with their text widths (including brackets and spaces)
+    // Tab labels as drawn
     // Layout: " [1] Scouts | [2] Ranks | [3] Badges | [4] Events | [5] Adults | [6] Unit"
-    let tabs = [
-        (Tab::Scouts, "[1] Scouts"),
-        (Tab::Ranks, "[2] Ranks"),
-        (Tab::Badges, "[3] Badges"),
-        (Tab::Events, "[4] Events"),
-        (Tab::Adults, "[5] Adults"),
-        (Tab::Unit, "[6] Unit"),
-    ];
+    let tabs = app.tab_labels();
 
-    let rel_x = col.saturating_sub(app.layout_areas.tabs_bar.x) as usize;
+    let rel_x = col.saturating_sub(app.ui.layout_areas.tabs_bar.x) as usize;
 
     // Walk through the tab labels to find which one was clicked
     let mut x_pos = 1; // leading space
     for (tab, label) in &tabs {
         let label_end = x_pos + label.len();
         if rel_x >= x_pos && rel_x < label_end {
-            app.current_tab = *tab;
-            app.focus = Focus::List;
-            app.left_table_state = TableState::default();
-            app.right_table_state = TableState::default();
+            app.ui.current_tab = *tab;
+            app.ui.focus = Focus::List;
+            app.ui.left_table_state = TableState::default();
+            app.ui.right_table_state = TableState::default();
             return;
         }
         x_pos = label_end + 3; // " | " separator
@@ -1389,16 +2750,16 @@ fn handle_tab_click(app: &mut App, col: u16) {
 
 /// Handle click on the left panel - update selection
 fn handle_left_panel_click(app: &mut App, index: usize) {
-    use crate::app::Tab;
+    use crate::ui::state::Tab;
 
-    app.focus = Focus::List;
+    app.ui.focus = Focus::List;
 
-    match app.current_tab {
+    match app.ui.current_tab {
         Tab::Scouts => {
-            let max = app.youth.len().saturating_sub(1);
+            let max = app.roster_rows().len().saturating_sub(1);
             let new_selection = index.min(max);
-            if new_selection != app.roster_selection {
-                app.roster_selection = new_selection;
+            if new_selection != app.roster_selection.index {
+                app.roster_selection.index = new_selection;
                 // Clear progress data when changing scout
                 app.selected_youth_ranks.clear();
                 app.selected_youth_badges.clear();
@@ -1409,29 +2770,29 @@ fn handle_left_panel_click(app: &mut App, index: usize) {
                 app.selected_badge_counselor = None;
                 app.viewing_requirements = false;
                 app.awards_loaded = false;
-                app.scout_detail_view = ScoutDetailView::Details;
+                app.ui.scout_detail_view = ScoutDetailView::Details;
             }
         }
         Tab::Adults => {
-            let max = app.adults.len().saturating_sub(1);
-            app.adults_selection = index.min(max);
+            let max = app.store.adults.len().saturating_sub(1);
+            app.adults_selection.index = index.min(max);
         }
         Tab::Events => {
-            let max = app.events.len().saturating_sub(1);
+            let max = app.store.events.len().saturating_sub(1);
             let new_selection = index.min(max);
-            if new_selection != app.event_selection {
-                app.event_selection = new_selection;
+            if new_selection != app.event_selection.index {
+                app.event_selection.index = new_selection;
                 app.event_guest_selection = 0;
-                app.event_detail_view = EventDetailView::Details;
+                app.ui.event_detail_view = EventDetailView::Details;
             }
         }
         Tab::Ranks => {
             let rank_count = crate::ui::tabs::ranks::get_rank_list(
-                &app.youth, &app.all_youth_ranks,
+                &app.store.youth, &app.store.all_youth_ranks,
                 app.ranks_sort_by_count, app.ranks_sort_ascending
             ).len();
             let max = rank_count.saturating_sub(1);
-            app.ranks_selection = index.min(max);
+            app.ranks_selection.index = index.min(max);
             app.ranks_scout_selection = 0;
             app.ranks_viewing_requirements = false;
             app.selected_rank_requirements.clear();
@@ -1439,37 +2800,45 @@ fn handle_left_panel_click(app: &mut App, index: usize) {
         }
         Tab::Badges => {
             let badge_count = crate::ui::tabs::badges::get_badge_list(
-                &app.youth, &app.all_youth_badges,
+                &app.store.youth, &app.store.all_youth_badges,
                 app.badges_sort_by_count, app.badges_sort_ascending
             ).len();
             let max = badge_count.saturating_sub(1);
-            app.badges_selection = index.min(max);
+            app.badges_selection.index = index.min(max);
             app.badges_scout_selection = 0;
             app.badges_viewing_requirements = false;
             app.selected_badge_requirements.clear();
             app.badges_requirement_selection = 0;
         }
-        Tab::Unit => {} // No interaction
+        Tab::Eagle => {
+            let max = app.eagle_rows().len().saturating_sub(1);
+            app.eagle_selection.index = index.min(max);
+        }
+        Tab::Attendance => {
+            let max = app.attendance_rows().len().saturating_sub(1);
+            app.attendance_selection.index = index.min(max);
+        }
+        Tab::Unit | Tab::Plugin(_) => {} // No interaction
     }
 }
 
 /// Handle click on the right panel - update detail selection
 /// `row` is the raw screen row coordinate for Scouts tab paragraph layout
 fn handle_right_panel_click(app: &mut App, index: usize, row: u16) {
-    use crate::app::Tab;
+    use crate::ui::state::Tab;
 
-    match app.current_tab {
+    match app.ui.current_tab {
         Tab::Ranks => {
             if app.ranks_viewing_requirements {
                 return;
             }
-            app.focus = Focus::Detail;
-            let grouped = crate::ui::tabs::ranks::get_ranks_with_scouts(&app.youth, &app.all_youth_ranks);
+            app.ui.focus = Focus::Detail;
+            let grouped = crate::ui::tabs::ranks::get_ranks_with_scouts(&app.store.youth, &app.store.all_youth_ranks);
             let rank_list = crate::ui::tabs::ranks::get_rank_list(
-                &app.youth, &app.all_youth_ranks,
+                &app.store.youth, &app.store.all_youth_ranks,
                 app.ranks_sort_by_count, app.ranks_sort_ascending
             );
-            let selected_rank_name = rank_list.get(app.ranks_selection)
+            let selected_rank_name = rank_list.get(app.ranks_selection.index)
                 .map(|(name, _)| name.as_str())
                 .unwrap_or("");
             let max = grouped.iter()
@@ -1482,13 +2851,13 @@ fn handle_right_panel_click(app: &mut App, index: usize, row: u16) {
             if app.badges_viewing_requirements {
                 return;
             }
-            app.focus = Focus::Detail;
-            let grouped = crate::ui::tabs::badges::get_badges_with_scouts(&app.youth, &app.all_youth_badges);
+            app.ui.focus = Focus::Detail;
+            let grouped = crate::ui::tabs::badges::get_badges_with_scouts(&app.store.youth, &app.store.all_youth_badges);
             let badge_list = crate::ui::tabs::badges::get_badge_list(
-                &app.youth, &app.all_youth_badges,
+                &app.store.youth, &app.store.all_youth_badges,
                 app.badges_sort_by_count, app.badges_sort_ascending
             );
-            let selected_badge_name = badge_list.get(app.badges_selection)
+            let selected_badge_name = badge_list.get(app.badges_selection.index)
                 .map(|(name, _, _)| name.as_str())
                 .unwrap_or("");
             let max = grouped.iter()
@@ -1502,12 +2871,12 @@ fn handle_right_panel_click(app: &mut App, index: usize, row: u16) {
             if app.viewing_requirements {
                 return;
             }
-            app.focus = Focus::Detail;
+            app.ui.focus = Focus::Detail;
             // The right panel for Scouts is a Paragraph, not a Table.
             // Calculate the row index by subtracting the header lines inside the block.
             // Layout: border(1) + name(1) + "Current Rank:"(1) + blank(1) + header(1) + blank(1) = 6 lines before items
-            let panel_y = app.layout_areas.right_panel.y;
-            match app.scout_detail_view {
+            let panel_y = app.ui.layout_areas.right_panel.y;
+            match app.ui.scout_detail_view {
                 ScoutDetailView::Ranks if !app.selected_youth_ranks.is_empty() => {
                     let content_start = panel_y + 6; // border + name + rank + blank + header + blank
                     if row >= content_start {
@@ -1553,27 +2922,27 @@ fn handle_right_panel_click(app: &mut App, index: usize, row: u16) {
 
 /// Handle scroll wheel events
 fn handle_scroll(app: &mut App, col: u16, row: u16, scroll_up: bool) {
-    use crate::app::Tab;
+    use crate::ui::state::Tab;
 
-    let in_left = click_in_area(col, row, app.layout_areas.left_panel);
-    let in_right = click_in_area(col, row, app.layout_areas.right_panel);
+    let in_left = click_in_area(col, row, app.ui.layout_areas.left_panel);
+    let in_right = click_in_area(col, row, app.ui.layout_areas.right_panel);
 
     if !in_left && !in_right {
         return;
     }
 
     if in_left {
-        app.focus = Focus::List;
-        match app.current_tab {
+        app.ui.focus = Focus::List;
+        match app.ui.current_tab {
             Tab::Scouts => {
-                let max = app.youth.len().saturating_sub(1);
-                let old = app.roster_selection;
-                app.roster_selection = if scroll_up {
-                    app.roster_selection.saturating_sub(1)
+                let max = app.roster_rows().len().saturating_sub(1);
+                let old = app.roster_selection.index;
+                app.roster_selection.index = if scroll_up {
+                    app.roster_selection.index.saturating_sub(1)
                 } else {
-                    (app.roster_selection + 1).min(max)
+                    (app.roster_selection.index + 1).min(max)
                 };
-                if old != app.roster_selection {
+                if old != app.roster_selection.index {
                     app.selected_youth_ranks.clear();
                     app.selected_youth_badges.clear();
                     app.selected_youth_leadership.clear();
@@ -1584,60 +2953,76 @@ fn handle_scroll(app: &mut App, col: u16, row: u16, scroll_up: bool) {
                 }
             }
             Tab::Adults => {
-                let max = app.adults.len().saturating_sub(1);
-                app.adults_selection = if scroll_up {
-                    app.adults_selection.saturating_sub(1)
+                let max = app.store.adults.len().saturating_sub(1);
+                app.adults_selection.index = if scroll_up {
+                    app.adults_selection.index.saturating_sub(1)
                 } else {
-                    (app.adults_selection + 1).min(max)
+                    (app.adults_selection.index + 1).min(max)
                 };
             }
             Tab::Events => {
-                let max = app.events.len().saturating_sub(1);
-                app.event_selection = if scroll_up {
-                    app.event_selection.saturating_sub(1)
+                let max = app.store.events.len().saturating_sub(1);
+                app.event_selection.index = if scroll_up {
+                    app.event_selection.index.saturating_sub(1)
                 } else {
-                    (app.event_selection + 1).min(max)
+                    (app.event_selection.index + 1).min(max)
                 };
                 app.event_guest_selection = 0;
             }
             Tab::Ranks => {
                 let rank_count = crate::ui::tabs::ranks::get_rank_list(
-                    &app.youth, &app.all_youth_ranks,
+                    &app.store.youth, &app.store.all_youth_ranks,
                     app.ranks_sort_by_count, app.ranks_sort_ascending
                 ).len();
                 let max = rank_count.saturating_sub(1);
-                app.ranks_selection = if scroll_up {
-                    app.ranks_selection.saturating_sub(1)
+                app.ranks_selection.index = if scroll_up {
+                    app.ranks_selection.index.saturating_sub(1)
                 } else {
-                    (app.ranks_selection + 1).min(max)
+                    (app.ranks_selection.index + 1).min(max)
                 };
                 app.ranks_scout_selection = 0;
             }
             Tab::Badges => {
                 let badge_count = crate::ui::tabs::badges::get_badge_list(
-                    &app.youth, &app.all_youth_badges,
+                    &app.store.youth, &app.store.all_youth_badges,
                     app.badges_sort_by_count, app.badges_sort_ascending
                 ).len();
                 let max = badge_count.saturating_sub(1);
-                app.badges_selection = if scroll_up {
-                    app.badges_selection.saturating_sub(1)
+                app.badges_selection.index = if scroll_up {
+                    app.badges_selection.index.saturating_sub(1)
                 } else {
-                    (app.badges_selection + 1).min(max)
+                    (app.badges_selection.index + 1).min(max)
                 };
                 app.badges_scout_selection = 0;
             }
-            Tab::Unit => {}
+            Tab::Eagle => {
+                let max = app.eagle_rows().len().saturating_sub(1);
+                app.eagle_selection.index = if scroll_up {
+                    app.eagle_selection.index.saturating_sub(1)
+                } else {
+                    (app.eagle_selection.index + 1).min(max)
+                };
+            }
+            Tab::Attendance => {
+                let max = app.attendance_rows().len().saturating_sub(1);
+                app.attendance_selection.index = if scroll_up {
+                    app.attendance_selection.index.saturating_sub(1)
+                } else {
+                    (app.attendance_selection.index + 1).min(max)
+                };
+            }
+            Tab::Unit | Tab::Plugin(_) => {}
         }
     } else if in_right {
-        app.focus = Focus::Detail;
-        match app.current_tab {
+        app.ui.focus = Focus::Detail;
+        match app.ui.current_tab {
             Tab::Ranks => {
-                let grouped = crate::ui::tabs::ranks::get_ranks_with_scouts(&app.youth, &app.all_youth_ranks);
+                let grouped = crate::ui::tabs::ranks::get_ranks_with_scouts(&app.store.youth, &app.store.all_youth_ranks);
                 let rank_list = crate::ui::tabs::ranks::get_rank_list(
-                    &app.youth, &app.all_youth_ranks,
+                    &app.store.youth, &app.store.all_youth_ranks,
                     app.ranks_sort_by_count, app.ranks_sort_ascending
                 );
-                let selected_rank_name = rank_list.get(app.ranks_selection)
+                let selected_rank_name = rank_list.get(app.ranks_selection.index)
                     .map(|(name, _)| name.as_str())
                     .unwrap_or("");
                 let max = grouped.iter()
@@ -1651,12 +3036,12 @@ fn handle_scroll(app: &mut App, col: u16, row: u16, scroll_up: bool) {
                 };
             }
             Tab::Badges => {
-                let grouped = crate::ui::tabs::badges::get_badges_with_scouts(&app.youth, &app.all_youth_badges);
+                let grouped = crate::ui::tabs::badges::get_badges_with_scouts(&app.store.youth, &app.store.all_youth_badges);
                 let badge_list = crate::ui::tabs::badges::get_badge_list(
-                    &app.youth, &app.all_youth_badges,
+                    &app.store.youth, &app.store.all_youth_badges,
                     app.badges_sort_by_count, app.badges_sort_ascending
                 );
-                let selected_badge_name = badge_list.get(app.badges_selection)
+                let selected_badge_name = badge_list.get(app.badges_selection.index)
                     .map(|(name, _, _)| name.as_str())
                     .unwrap_or("");
                 let max = grouped.iter()
@@ -0,0 +1,188 @@
+//! User key bindings for the main screen.
+//!
+//! The input handlers match the built-in keys. A `KeyMap` built from the
+//! config's `keybindings` section translates a bound key into the built-in
+//! key for its action before dispatch, and drops j/k when the preset
+//! doesn't use them. Overlays and text entry keep their own keys.
+
+use std::collections::HashMap;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use trailcache_core::config::{KeyBindings, KeyPreset};
+
+/// A remappable main-screen action and the built-in key the handlers expect.
+const ACTIONS: &[(&str, KeyCode)] = &[
+    ("up", KeyCode::Up),
+    ("down", KeyCode::Down),
+    ("left", KeyCode::Left),
+    ("right", KeyCode::Right),
+    ("page_up", KeyCode::PageUp),
+    ("page_down", KeyCode::PageDown),
+    ("top", KeyCode::Home),
+    ("bottom", KeyCode::End),
+    ("focus", KeyCode::Tab),
+    ("open", KeyCode::Enter),
+    ("back", KeyCode::Esc),
+    ("tab_1", KeyCode::Char('1')),
+    ("tab_2", KeyCode::Char('2')),
+    ("tab_3", KeyCode::Char('3')),
+    ("tab_4", KeyCode::Char('4')),
+    ("tab_5", KeyCode::Char('5')),
+    ("tab_6", KeyCode::Char('6')),
+    ("tab_7", KeyCode::Char('7')),
+    ("tab_8", KeyCode::Char('8')),
+    ("tab_9", KeyCode::Char('9')),
+    ("sort_name", KeyCode::Char('n')),
+    ("sort_rank", KeyCode::Char('r')),
+    ("sort_patrol", KeyCode::Char('p')),
+    ("sort_grade", KeyCode::Char('g')),
+    ("sort_age", KeyCode::Char('a')),
+    ("sort_date", KeyCode::Char('d')),
+    ("sort_location", KeyCode::Char('l')),
+    ("sort_type", KeyCode::Char('t')),
+    ("sort_count", KeyCode::Char('c')),
+    ("sort_cycle", KeyCode::Char('s')),
+    ("sort_reverse", KeyCode::Char('S')),
+];
+
+/// Vim-style letters the handlers accept alongside the arrows
+const VIM_KEYS: [char; 2] = ['j', 'k'];
+
+/// Bindings the emacs preset adds, as action and key
+const EMACS_KEYS: &[(&str, &str)] = &[
+    ("up", "ctrl-p"),
+    ("down", "ctrl-n"),
+    ("left", "ctrl-b"),
+    ("right", "ctrl-f"),
+    ("page_down", "ctrl-v"),
+    ("page_up", "alt-v"),
+    ("top", "alt-<"),
+    ("bottom", "alt->"),
+];
+
+fn strip_prefix_ignore_case<'a>(text: &'a str, prefix: &str) -> Option<&'a str> {
+    let head = text.get(..prefix.len())?;
+    (head.eq_ignore_ascii_case(prefix) && text.len() > prefix.len()).then(|| &text[prefix.len()..])
+}
+
+/// Parse a key like "j", "J", "ctrl-n", "alt-v", "pagedown", or "F1".
+pub fn parse_key(text: &str) -> Option<(KeyCode, KeyModifiers)> {
+    let mut modifiers = KeyModifiers::NONE;
+    let mut rest = text.trim();
+    loop {
+        if let Some(r) = strip_prefix_ignore_case(rest, "ctrl-").or_else(|| strip_prefix_ignore_case(rest, "c-")) {
+            modifiers |= KeyModifiers::CONTROL;
+            rest = r;
+        } else if let Some(r) = strip_prefix_ignore_case(rest, "alt-").or_else(|| strip_prefix_ignore_case(rest, "m-")) {
+            modifiers |= KeyModifiers::ALT;
+            rest = r;
+        } else {
+            break;
+        }
+    }
+    let mut chars = rest.chars();
+    let code = match (chars.next(), chars.next()) {
+        (Some(c), None) if modifiers.contains(KeyModifiers::CONTROL) => KeyCode::Char(c.to_ascii_lowercase()),
+        (Some(c), None) => KeyCode::Char(c),
+        _ => match rest.to_lowercase().as_str() {
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "pageup" | "pgup" => KeyCode::PageUp,
+            "pagedown" | "pgdn" => KeyCode::PageDown,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            "tab" => KeyCode::Tab,
+            "enter" | "return" => KeyCode::Enter,
+            "esc" | "escape" => KeyCode::Esc,
+            "space" => KeyCode::Char(' '),
+            "backspace" => KeyCode::Backspace,
+            f => KeyCode::F(f.strip_prefix('f')?.parse().ok().filter(|n| (1..=12).contains(n))?),
+        },
+    };
+    Some((code, modifiers))
+}
+
+/// Shift is part of the character for letter keys, so it's ignored there.
+fn normalize(code: KeyCode, modifiers: KeyModifiers) -> (KeyCode, KeyModifiers) {
+    match code {
+        KeyCode::Char(_) => (code, modifiers - KeyModifiers::SHIFT),
+        _ => (code, modifiers),
+    }
+}
+
+/// Translation from the user's keys to the built-in ones.
+#[derive(Debug, Clone, Default)]
+pub struct KeyMap {
+    bindings: HashMap<(KeyCode, KeyModifiers), KeyCode>,
+    /// Whether j/k move, as they do in the vim preset
+    vim_keys: bool,
+}
+
+impl KeyMap {
+    /// Build the map from config. Unknown actions and keys are skipped and
+    /// returned as messages.
+    pub fn new(config: &KeyBindings) -> (Self, Vec<String>) {
+        let mut map = KeyMap { bindings: HashMap::new(), vim_keys: config.preset == KeyPreset::Vim };
+        let mut problems = Vec::new();
+        let preset: &[(&str, &str)] = match config.preset {
+            KeyPreset::Emacs => EMACS_KEYS,
+            KeyPreset::Vim | KeyPreset::Arrows => &[],
+        };
+        let user = config.keys.iter().flat_map(|(action, keys)| keys.iter().map(move |k| (action.as_str(), k.as_str())));
+        for (action, key) in preset.iter().copied().chain(user) {
+            let Some(&(_, target)) = ACTIONS.iter().find(|(name, _)| *name == action) else {
+                problems.push(format!("Unknown key binding action \"{}\"", action));
+                continue;
+            };
+            match parse_key(key) {
+                Some((code, modifiers)) => {
+                    map.bindings.insert(normalize(code, modifiers), target);
+                }
+                None => problems.push(format!("Unknown key \"{}\" for {}", key, action)),
+            }
+        }
+        (map, problems)
+    }
+
+    /// The built-in key `key` stands for, or `None` to ignore it.
+    pub fn translate(&self, key: KeyEvent) -> Option<KeyEvent> {
+        if let Some(&code) = self.bindings.get(&normalize(key.code, key.modifiers)) {
+            return Some(KeyEvent::new(code, KeyModifiers::NONE));
+        }
+        match key.code {
+            KeyCode::Char(c) if !self.vim_keys && VIM_KEYS.contains(&c) && key.modifiers.is_empty() => None,
+            _ => Some(key),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keymap_translates_bindings() {
+        let mut config = KeyBindings { preset: KeyPreset::Emacs, ..Default::default() };
+        config.keys.insert("tab_1".to_string(), vec!["F1".to_string()]);
+        config.keys.insert("sort_name".to_string(), vec!["N".to_string(), "hyper-x".to_string()]);
+        config.keys.insert("jump".to_string(), vec!["x".to_string()]);
+        let (map, problems) = KeyMap::new(&config);
+        assert_eq!(problems.len(), 2);
+
+        let key = |code, modifiers| KeyEvent::new(code, modifiers);
+        let translated = |k| map.translate(k).map(|k: KeyEvent| k.code);
+        assert_eq!(translated(key(KeyCode::Char('n'), KeyModifiers::CONTROL)), Some(KeyCode::Down));
+        assert_eq!(translated(key(KeyCode::Char('<'), KeyModifiers::ALT | KeyModifiers::SHIFT)), Some(KeyCode::Home));
+        assert_eq!(translated(key(KeyCode::F(1), KeyModifiers::NONE)), Some(KeyCode::Char('1')));
+        assert_eq!(translated(key(KeyCode::Char('N'), KeyModifiers::SHIFT)), Some(KeyCode::Char('n')));
+        assert_eq!(translated(key(KeyCode::Char('j'), KeyModifiers::NONE)), None, "emacs preset drops j/k");
+        assert_eq!(translated(key(KeyCode::Char('q'), KeyModifiers::NONE)), Some(KeyCode::Char('q')));
+
+        let (vim, _) = KeyMap::new(&KeyBindings::default());
+        assert_eq!(vim.translate(key(KeyCode::Char('j'), KeyModifiers::NONE)).map(|k| k.code), Some(KeyCode::Char('j')));
+        assert_eq!(parse_key("pagedown"), Some((KeyCode::PageDown, KeyModifiers::NONE)));
+        assert_eq!(parse_key("C-x"), Some((KeyCode::Char('x'), KeyModifiers::CONTROL)));
+    }
+}
@@ -4,10 +4,14 @@
 //!
 //! - `render`: Main frame rendering and layout
 //! - `input`: Keyboard event handling
+//! - `keymap`: User key bindings translated to the built-in keys
+//! - `state`: Navigation and view state shared by every tab
 //! - `styles`: Color schemes and text styling
 //! - `tabs`: Tab-specific content rendering (roster, events, etc.)
 
 pub mod input;
+pub mod keymap;
 pub mod render;
+pub mod state;
 pub mod styles;
 pub mod tabs;
@@ -1,15 +1,26 @@
+use chrono::{Local, NaiveDate};
 use ratatui::{
-    layout::{Constraint, Direction, Layout, Rect},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::Style,
     text::{Line, Span},
-    widgets::{Block, Borders, Clear, Paragraph},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
     Frame,
 };
 
-use crate::app::{App, AppState, EventDetailView, LoginFocus, ScoutDetailView, Tab};
+use crate::app::{App, ClinicField, HistoryField, LinkedLine, LoadState, MedicationField, MinutesInput, LoginFocus, ProjectField, TemplateInput, TrekField};
+use crate::ui::state::{AppState, EventDetailView, ScoutDetailView, Tab};
+use crate::store::RefreshDataset;
+use crate::app::alerts::AlertKind;
+use crate::timer::{format_clock, TIMER_PRESETS};
+use trailcache_core::models::district::CHARTER_WARNING_DAYS;
+use trailcache_core::models::{crew_advisors, ActivityPlanItem, COMMON_ADULT_SKILLS, AdultAward, Certification, CommitteeRole, Fundraising, OnboardingItem, Paperwork, ScoutRank, TroopFilter, Troops, UnitHealth, CREW_ADVISORS_REQUIRED, OA_CAMPING_NIGHTS, OA_MIN_RANK, POSITION_RULES};
+use trailcache_core::reports::counselor::remaining_label;
+use trailcache_core::reports::my_scout::{percent_label, MyScoutSummary};
+use trailcache_core::search::{SearchMode, SearchQuery};
+use trailcache_core::utils::{truncate, wrap_text};
 
 use super::styles;
-use super::tabs::{badges, events, ranks, roster, unit};
+use super::tabs::{attendance, badges, eagle, events, ranks, roster, unit};
 
 // ============================================================================
 // Overlay Constants and Helpers
@@ -18,6 +29,9 @@ use super::tabs::{badges, events, ranks, roster, unit};
 /// Standard overlay width (52 total = 50 interior with borders)
 const OVERLAY_WIDTH: u16 = 52;
 
+/// Activity feed panel is wider to fit entry text
+const FEED_OVERLAY_WIDTH: u16 = 72;
+
 /// ASCII art logo lines (centered for 50-char interior)
 const LOGO_LINE_1: &str = "      ╔╦╗ ╦═╗ ╔═╗ ╦ ╦   ╔═╗ ╔═╗ ╔═╗ ╦ ╦ ╔═╗";
 const LOGO_LINE_2: &str = "       ║  ╠╦╝ ╠═╣ ║ ║   ║   ╠═╣ ║   ╠═╣ ║╣ ";
@@ -51,8 +65,8 @@ pub fn render(frame: &mut Frame, app: &mut App) {
         ])
         .split(frame.area());
 
-    app.layout_areas.title_bar = chunks[0];
-    app.layout_areas.tabs_bar = chunks[1];
+    app.ui.layout_areas.title_bar = chunks[0];
+    app.ui.layout_areas.tabs_bar = chunks[1];
 
     render_title_bar(frame, app, chunks[0]);
     render_tabs(frame, app, chunks[1]);
@@ -60,34 +74,173 @@ pub fn render(frame: &mut Frame, app: &mut App) {
     render_status_bar(frame, app, chunks[3]);
 
     // Render overlays
-    if matches!(app.state, AppState::ShowingHelp) {
+    if matches!(app.ui.state, AppState::ShowingHelp) {
         render_help_overlay(frame, app);
     }
 
-    if matches!(app.state, AppState::LoggingIn) {
+    if matches!(app.ui.state, AppState::LoggingIn) {
         render_login_overlay(frame, app);
     }
 
-    if matches!(app.state, AppState::ConfirmingQuit) {
+    if matches!(app.ui.state, AppState::ConfirmingQuit) {
         render_quit_overlay(frame);
     }
 
-    if matches!(app.state, AppState::ConfirmingOffline) {
-        render_offline_overlay(frame);
+    if matches!(app.ui.state, AppState::ConfirmingOffline) {
+        render_offline_overlay(frame, app);
     }
 
-    if matches!(app.state, AppState::ConfirmingOnline) {
+    if matches!(app.ui.state, AppState::ConfirmingOnline) {
         render_online_overlay(frame);
     }
+
+    if matches!(app.ui.state, AppState::ShowingFeed) {
+        render_feed_overlay(frame, app);
+    }
+
+    if matches!(app.ui.state, AppState::ShowingAwards) {
+        render_awards_overlay(frame, app);
+    }
+
+    if matches!(app.ui.state, AppState::ShowingMyScouts) {
+        render_my_scouts_overlay(frame, app);
+    }
+
+    if matches!(app.ui.state, AppState::ShowingTimers) {
+        render_timers_overlay(frame, app);
+    }
+
+    if matches!(app.ui.state, AppState::ShowingEventTemplates) {
+        render_event_templates_overlay(frame, app);
+    }
+
+    if matches!(app.ui.state, AppState::ShowingTodos) {
+        render_todos_overlay(frame, app);
+    }
+
+    if matches!(app.ui.state, AppState::ShowingAlerts) {
+        render_alerts_overlay(frame, app);
+    }
+
+    if matches!(app.ui.state, AppState::ShowingLibrary) {
+        render_library_overlay(frame, app);
+    }
+
+    if matches!(app.ui.state, AppState::ShowingAdultSkills) {
+        render_adult_skills_overlay(frame, app);
+    }
+
+    if matches!(app.ui.state, AppState::ShowingCloset) {
+        render_closet_overlay(frame, app);
+    }
+
+    if matches!(app.ui.state, AppState::ScreeningTrek) {
+        render_trek_overlay(frame, app);
+    }
+
+    if matches!(app.ui.state, AppState::ShowingEagleProject) {
+        render_eagle_project_overlay(frame, app);
+    }
+
+    if matches!(app.ui.state, AppState::PlanningClinic) {
+        render_clinic_overlay(frame, app);
+    }
+
+    if matches!(app.ui.state, AppState::GlobalSearch) {
+        render_palette_overlay(frame, app);
+    }
+
+    if matches!(app.ui.state, AppState::LookingUpMemberId) {
+        render_member_id_overlay(frame, app);
+    }
+    if matches!(app.ui.state, AppState::CheckingContacts) {
+        render_contact_check_overlay(frame, app);
+    }
+    if matches!(app.ui.state, AppState::ShowingOaEligibility) {
+        render_oa_overlay(frame, app);
+    }
+    if matches!(app.ui.state, AppState::ShowingCommitteeRoles) {
+        render_committee_overlay(frame, app);
+    }
+
+    if matches!(app.ui.state, AppState::ShowingDistrict) {
+        render_district_overlay(frame, app);
+    }
+
+    if matches!(app.ui.state, AppState::ShowingLinked) {
+        render_linked_overlay(frame, app);
+    }
+
+    if matches!(app.ui.state, AppState::CorrectingDates) {
+        render_date_correction_overlay(frame, app);
+    }
+
+    if matches!(app.ui.state, AppState::EditingMinutes) {
+        render_minutes_overlay(frame, app);
+    }
+
+    if matches!(app.ui.state, AppState::ShowingHistory) {
+        render_history_overlay(frame, app);
+    }
+
+    if matches!(app.ui.state, AppState::ShowingCounselors) {
+        render_counselors_overlay(frame, app);
+    }
+
+    if matches!(app.ui.state, AppState::PlanningPositions) {
+        render_position_planner_overlay(frame, app);
+    }
+
+    if matches!(app.ui.state, AppState::ShowingSkills) {
+        render_skills_overlay(frame, app);
+    }
+
+    if matches!(app.ui.state, AppState::EditingAdultAwards) {
+        render_adult_awards_overlay(frame, app);
+    }
+
+    if matches!(app.ui.state, AppState::ShowingOnboarding) {
+        render_onboarding_overlay(frame, app);
+    }
+
+    if matches!(app.ui.state, AppState::TakingAttendance) {
+        render_meeting_overlay(frame, app);
+    }
+
+    if matches!(app.ui.state, AppState::ShowingBuddies) {
+        render_buddies_overlay(frame, app);
+    }
+
+    if matches!(app.ui.state, AppState::ShowingActivityPlan) {
+        render_activity_plan_overlay(frame, app);
+    }
+
+    if matches!(app.ui.state, AppState::ConfirmingRequirementCompletion) {
+        render_requirement_completion_overlay(frame, app);
+    }
+
+    if matches!(app.ui.state, AppState::MovingToPatrol) {
+        render_patrol_move_overlay(frame, app);
+    }
+
+    if matches!(app.ui.state, AppState::ConfirmingMedicationAccess) {
+        render_medication_access_overlay(frame);
+    }
+
+    if matches!(app.ui.state, AppState::ShowingMedications) {
+        render_medications_overlay(frame, app);
+    }
 }
 
-fn render_title_bar(frame: &mut Frame, _app: &App, area: Rect) {
+fn render_title_bar(frame: &mut Frame, app: &App, area: Rect) {
     let title = "  Trailcache";
+    let mode = if app.config.youth_leader_mode { "  Youth Leader Mode" } else { "" };
     let help_hint = "[?] Help";
-    let title_len = title.len();
+    let title_len = title.len() + mode.len();
 
     let title_line = Line::from(vec![
         Span::styled(title, styles::title_style()),
+        Span::styled(mode, styles::highlight_style()),
         Span::raw(" ".repeat(
             area.width
                 .saturating_sub(title_len as u16 + help_hint.len() as u16 + 4)
@@ -106,37 +259,33 @@ fn render_title_bar(frame: &mut Frame, _app: &App, area: Rect) {
 
 fn render_tabs(frame: &mut Frame, app: &mut App, area: Rect) {
     // Build main tabs text
-    let main_tabs = [("[1] Scouts", app.current_tab == Tab::Scouts),
-        ("[2] Ranks", app.current_tab == Tab::Ranks),
-        ("[3] Badges", app.current_tab == Tab::Badges),
-        ("[4] Events", app.current_tab == Tab::Events),
-        ("[5] Adults", app.current_tab == Tab::Adults),
-        ("[6] Unit", app.current_tab == Tab::Unit)];
+    let main_tabs = app.tab_labels();
 
     let mut spans = vec![Span::raw(" ")];
-    for (i, (label, selected)) in main_tabs.iter().enumerate() {
+    for (i, (tab, label)) in main_tabs.into_iter().enumerate() {
         if i > 0 {
             spans.push(Span::styled(" | ", styles::muted_style()));
         }
-        if *selected {
-            spans.push(Span::styled(*label, styles::tab_style(true)));
+        if tab == app.ui.current_tab {
+            spans.push(Span::styled(label, styles::tab_style(true)));
         } else {
-            spans.push(Span::styled(*label, styles::muted_style()));
+            spans.push(Span::styled(label, styles::muted_style()));
         }
     }
 
     // Add detail view toggle on the right when on Scouts or Events tab
-    let detail_tabs: Option<Vec<(&str, bool)>> = match app.current_tab {
+    let detail_tabs: Option<Vec<(&str, bool)>> = match app.ui.current_tab {
         Tab::Scouts => Some(vec![
-            ("[d]etails", app.scout_detail_view == ScoutDetailView::Details),
-            ("[r]anks", app.scout_detail_view == ScoutDetailView::Ranks),
-            ("[b]adges", app.scout_detail_view == ScoutDetailView::MeritBadges),
-            ("[l]eadership", app.scout_detail_view == ScoutDetailView::Leadership),
-            ("[a]wards", app.scout_detail_view == ScoutDetailView::Awards),
+            ("[d]etails", app.ui.scout_detail_view == ScoutDetailView::Details),
+            ("[r]anks", app.ui.scout_detail_view == ScoutDetailView::Ranks),
+            ("[b]adges", app.ui.scout_detail_view == ScoutDetailView::MeritBadges),
+            ("[l]eadership", app.ui.scout_detail_view == ScoutDetailView::Leadership),
+            ("[a]wards", app.ui.scout_detail_view == ScoutDetailView::Awards),
+            ("[t]imeline", app.ui.scout_detail_view == ScoutDetailView::Timeline),
         ]),
         Tab::Events => Some(vec![
-            ("[d]etails", app.event_detail_view == EventDetailView::Details),
-            ("[r]svp", app.event_detail_view == EventDetailView::Rsvp),
+            ("[d]etails", app.ui.event_detail_view == EventDetailView::Details),
+            ("[r]svp", app.ui.event_detail_view == EventDetailView::Rsvp),
         ]),
         _ => None,
     };
@@ -151,7 +300,7 @@ fn render_tabs(frame: &mut Frame, app: &mut App, area: Rect) {
         spans.push(Span::raw(" ".repeat(padding)));
 
         let detail_tabs_x = area.x + (main_width + padding) as u16;
-        app.layout_areas.detail_tabs_area = Rect::new(
+        app.ui.layout_areas.detail_tabs_area = Rect::new(
             detail_tabs_x,
             area.y,
             detail_width as u16,
@@ -169,7 +318,7 @@ fn render_tabs(frame: &mut Frame, app: &mut App, area: Rect) {
             }
         }
     } else {
-        app.layout_areas.detail_tabs_area = Rect::default();
+        app.ui.layout_areas.detail_tabs_area = Rect::default();
     }
 
     let line = Line::from(spans);
@@ -183,25 +332,108 @@ fn render_tabs(frame: &mut Frame, app: &mut App, area: Rect) {
 }
 
 fn render_main_content(frame: &mut Frame, app: &mut App, area: Rect) {
-    match app.current_tab {
+    let area = render_banners(frame, app, area);
+    if let Some((dataset, what)) = empty_tab_dataset(app) {
+        render_empty_state(frame, app, area, dataset, what);
+        return;
+    }
+
+    match app.ui.current_tab {
         Tab::Scouts => roster::render_scouts(frame, app, area),
         Tab::Adults => roster::render_adults(frame, app, area),
         Tab::Events => events::render(frame, app, area),
         Tab::Unit => unit::render(frame, app, area),
         Tab::Ranks => ranks::render(frame, app, area),
         Tab::Badges => badges::render(frame, app, area),
+        Tab::Eagle => eagle::render(frame, app, area),
+        Tab::Attendance => attendance::render(frame, app, area),
+        Tab::Plugin(i) => {
+            app.with_plugin(i, |plugin, app| plugin.render(frame, app, area));
+        }
+    }
+}
+
+/// Warnings across the top of the tab: Scoutbook being down, and data
+/// that's badly out of date. Returns the area left for the tab itself.
+fn render_banners(frame: &mut Frame, app: &App, area: Rect) -> Rect {
+    let mut banners = Vec::new();
+    if app.scoutbook_down && !app.offline_mode {
+        banners.push(" ⚠ Scoutbook is down for maintenance - showing cached data ".to_string());
+    }
+    let freshness = app.view_freshness(app.ui.current_tab);
+    if let Some(days) = freshness.days() {
+        let action = if app.offline_mode { "go online (o) and refresh (u)" } else { "refresh (u)" };
+        banners.push(if freshness.hides_personal_info() {
+            format!(" ⚠ {} data is {} days old - personal details hidden until you {} ", app.tab_title(), days, action)
+        } else {
+            format!(" ⚠ {} data is {} days old - {} before relying on it ", app.tab_title(), days, action)
+        });
+    }
+    if banners.is_empty() {
+        return area;
+    }
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(banners.len() as u16), Constraint::Min(0)])
+        .split(area);
+    let lines: Vec<Line> = banners.into_iter().map(|b| Line::from(Span::styled(b, styles::error_style()))).collect();
+    frame.render_widget(Paragraph::new(lines).alignment(Alignment::Center), chunks[0]);
+    chunks[1]
+}
+
+/// The dataset a tab depends on, if that data is empty and nothing can be shown.
+fn empty_tab_dataset(app: &App) -> Option<(RefreshDataset, &'static str)> {
+    match app.ui.current_tab {
+        Tab::Scouts | Tab::Ranks | Tab::Badges | Tab::Eagle | Tab::Attendance if app.store.youth.is_empty() => Some((RefreshDataset::Youth, "scouts")),
+        Tab::Events if app.store.events.is_empty() => Some((RefreshDataset::Events, "events")),
+        Tab::Adults if app.store.adults.is_empty() => Some((RefreshDataset::Adults, "adults")),
+        Tab::Unit
+            if app.store.unit_info.is_none()
+                && app.store.key3.scoutmaster.is_none()
+                && app.store.key3.committee_chair.is_none()
+                && app.store.key3.charter_org_rep.is_none() =>
+        {
+            Some((RefreshDataset::UnitInfo, "unit information"))
+        }
+        _ => None,
     }
 }
 
+/// Placeholder panel for a tab whose data is loading, missing, or failed to load.
+fn render_empty_state(frame: &mut Frame, app: &App, area: Rect, dataset: RefreshDataset, what: &str) {
+    let (message, style) = match app.load_state(dataset) {
+        LoadState::Loading => (format!("{} Loading {}...", app.spinner_frame(), what), styles::highlight_style()),
+        LoadState::Failed(err) => (format!("Refresh failed: {} - press u to retry", err), styles::error_style()),
+        LoadState::Loaded => (format!("No {} found", what), styles::muted_style()),
+        LoadState::NotLoaded if app.offline_mode => (format!("No cached {} available offline", what), styles::muted_style()),
+        LoadState::NotLoaded => (format!("No cached {} - press u to refresh", what), styles::muted_style()),
+    };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(styles::border_style(false))
+        .title(format!(" {} ", app.tab_title()));
+    let top_padding = area.height.saturating_sub(3) / 2;
+    let mut lines: Vec<Line> = (0..top_padding).map(|_| Line::from("")).collect();
+    lines.push(Line::from(Span::styled(message, style)));
+
+    let paragraph = Paragraph::new(lines)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true })
+        .block(block);
+    frame.render_widget(paragraph, area);
+}
+
 fn render_status_bar(frame: &mut Frame, app: &App, area: Rect) {
-    let last_updated = app.cache_ages.last_updated();
+    let last_updated = app.store.cache_ages.last_updated();
     let shortcuts = if app.offline_mode {
         "[o]nline | [q]uit"
     } else {
         "[u]pdate | [o]ffline | [q]uit"
     };
 
-    let (left_text, left_style) = if let Some(ref msg) = app.status_message {
+    let (mut left_text, mut left_style) = if let Some(ref msg) = app.status_message {
         (format!(" {} ", msg), styles::muted_style())
     } else if app.offline_mode {
         (" OFFLINE MODE ".to_string(), styles::error_style())
@@ -209,12 +441,30 @@ fn render_status_bar(frame: &mut Frame, app: &App, area: Rect) {
         (format!(" Updated {} ", last_updated), styles::muted_style())
     };
 
+    // Datasets that failed during refresh and are being retried automatically
+    if let Some(summary) = app.dataset_retries.summary() {
+        left_text.push_str(&format!("| {} ", summary));
+        left_style = styles::highlight_style();
+    }
+
+    let overdue = app.overdue_todos();
+    if overdue > 0 {
+        left_text.push_str(&format!("| {} overdue to-do{} [I] ", overdue, if overdue == 1 { "" } else { "s" }));
+        left_style = styles::highlight_style();
+    }
+
+    if !app.alerts.list.is_empty() {
+        let n = app.alerts.list.len();
+        left_text.push_str(&format!("| {} alert{} [!] ", n, if n == 1 { "" } else { "s" }));
+        left_style = styles::highlight_style();
+    }
+
     let right_text = format!(" {} ", shortcuts);
 
     // Center text for Events tab - show calendar subscribe URL
-    let center_text = if app.current_tab == Tab::Events {
+    let center_text = if app.ui.current_tab == Tab::Events {
         // Get unit_id from first event
-        if let Some(unit_id) = app.events.first().and_then(|e| e.unit_id()) {
+        if let Some(unit_id) = app.store.events.first().and_then(|e| e.unit_id()) {
             format!("Subscribe: https://api.scouting.org/advancements/events/calendar/{}", unit_id)
         } else {
             String::new()
@@ -254,8 +504,8 @@ fn render_status_bar(frame: &mut Frame, app: &App, area: Rect) {
     }
 }
 
-fn render_help_overlay(frame: &mut Frame, _app: &App) {
-    let area = centered_rect_fixed(OVERLAY_WIDTH, 27, frame.area());
+fn render_help_overlay(frame: &mut Frame, app: &App) {
+    let area = centered_rect_fixed(OVERLAY_WIDTH, 85, frame.area());
     frame.render_widget(Clear, area);
 
     let version = env!("CARGO_PKG_VERSION");
@@ -269,7 +519,7 @@ fn render_help_overlay(frame: &mut Frame, _app: &App) {
         Line::from(""),
         Line::from(Span::styled(" Navigation", styles::highlight_style())),
         Line::from(vec![
-            Span::styled("  1-6       ", styles::help_key_style()),
+            Span::styled(format!("  1-{:<8}", 8 + app.plugins.len()), styles::help_key_style()),
             Span::styled("Switch tabs", styles::help_desc_style()),
         ]),
         Line::from(vec![
@@ -296,7 +546,11 @@ fn render_help_overlay(frame: &mut Frame, _app: &App) {
         Line::from(Span::styled(" Actions", styles::highlight_style())),
         Line::from(vec![
             Span::styled("  /         ", styles::help_key_style()),
-            Span::styled("Search", styles::help_desc_style()),
+            Span::styled("Search (Tab cycles text/fuzzy/regex)", styles::help_desc_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("  //        ", styles::help_key_style()),
+            Span::styled("Search every tab and jump to a match", styles::help_desc_style()),
         ]),
         Line::from(vec![
             Span::styled("  u         ", styles::help_key_style()),
@@ -306,6 +560,122 @@ fn render_help_overlay(frame: &mut Frame, _app: &App) {
             Span::styled("  o         ", styles::help_key_style()),
             Span::styled("Toggle offline mode", styles::help_desc_style()),
         ]),
+        Line::from(vec![
+            Span::styled("  A         ", styles::help_key_style()),
+            Span::styled("Activity feed", styles::help_desc_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("  M         ", styles::help_key_style()),
+            Span::styled("Take meeting attendance", styles::help_desc_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("  N         ", styles::help_key_style()),
+            Span::styled("Awards catalog and nominations", styles::help_desc_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("  w         ", styles::help_key_style()),
+            Span::styled("Adult award checklist (Adults tab)", styles::help_desc_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("  n         ", styles::help_key_style()),
+            Span::styled("New leader onboarding checklist (Adults tab)", styles::help_desc_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("  c         ", styles::help_key_style()),
+            Span::styled("Committee role matrix (Adults tab)", styles::help_desc_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("  c         ", styles::help_key_style()),
+            Span::styled("Mark requirement complete (Ranks)", styles::help_desc_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("  d         ", styles::help_key_style()),
+            Span::styled("Correct completion dates in bulk (Ranks)", styles::help_desc_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("  n         ", styles::help_key_style()),
+            Span::styled("Note alternate/accommodation (Scouts)", styles::help_desc_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("  e         ", styles::help_key_style()),
+            Span::styled("Export committee report (Unit tab)", styles::help_desc_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("  c         ", styles::help_key_style()),
+            Span::styled("Export annual charter org report (Unit tab)", styles::help_desc_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("  a         ", styles::help_key_style()),
+            Span::styled("Export Court of Honor ready-to-award handout (Unit tab)", styles::help_desc_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("  l         ", styles::help_key_style()),
+            Span::styled("Linked troop: both troops' rosters and events (Unit tab)", styles::help_desc_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("  P         ", styles::help_key_style()),
+            Span::styled("Save current list as printable text", styles::help_desc_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("  V         ", styles::help_key_style()),
+            Span::styled("Cycle export profile (full/leader/public)", styles::help_desc_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("  #         ", styles::help_key_style()),
+            Span::styled("Look up a BSA ID / list missing IDs", styles::help_desc_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("  @         ", styles::help_key_style()),
+            Span::styled("Contact check (bad or missing emails)", styles::help_desc_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("  J         ", styles::help_key_style()),
+            Span::styled("District dashboard (commissioners)", styles::help_desc_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("  Y         ", styles::help_key_style()),
+            Span::styled("My Scouts", styles::help_desc_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("  T         ", styles::help_key_style()),
+            Span::styled("Meeting timers", styles::help_desc_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("  K         ", styles::help_key_style()),
+            Span::styled("Skill certifications", styles::help_desc_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("  I         ", styles::help_key_style()),
+            Span::styled("To-do list", styles::help_desc_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("  !         ", styles::help_key_style()),
+            Span::styled("Alerts: birthdays, expiring membership and YPT", styles::help_desc_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("  Z         ", styles::help_key_style()),
+            Span::styled("Troop library (lend from a scout)", styles::help_desc_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("  $         ", styles::help_key_style()),
+            Span::styled("Uniform closet (check out to a scout)", styles::help_desc_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("  %         ", styles::help_key_style()),
+            Span::styled("Adult skills directory (add from an adult)", styles::help_desc_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("  ^         ", styles::help_key_style()),
+            Span::styled("High-adventure eligibility screener", styles::help_desc_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("  &         ", styles::help_key_style()),
+            Span::styled("Merit badge clinic planner (register a scout)", styles::help_desc_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("  ~         ", styles::help_key_style()),
+            Span::styled("Next color theme", styles::help_desc_style()),
+        ]),
         Line::from(vec![
             Span::styled("  q         ", styles::help_key_style()),
             Span::styled("Quit", styles::help_desc_style()),
@@ -316,10 +686,106 @@ fn render_help_overlay(frame: &mut Frame, _app: &App) {
             Span::styled("  n/r/p/g/a ", styles::help_key_style()),
             Span::styled("Sort by name/rank/patrol/grade/age", styles::help_desc_style()),
         ]),
+        Line::from(vec![
+            Span::styled("  G         ", styles::help_key_style()),
+            Span::styled("Group by patrol (Enter/Space folds)", styles::help_desc_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("  H         ", styles::help_key_style()),
+            Span::styled("Medication log (camp)", styles::help_desc_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("  O         ", styles::help_key_style()),
+            Span::styled("Order of the Arrow eligibility (nights camped)", styles::help_desc_style()),
+        ]),
         Line::from(vec![
             Span::styled("  d/r/b/l   ", styles::help_key_style()),
             Span::styled("View details/ranks/badges/leadership", styles::help_desc_style()),
         ]),
+        Line::from(vec![
+            Span::styled("  w/W/i     ", styles::help_key_style()),
+            Span::styled("Badge workbook: open/save/info page", styles::help_desc_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("  x         ", styles::help_key_style()),
+            Span::styled("Expand rank requirement resources", styles::help_desc_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("  C         ", styles::help_key_style()),
+            Span::styled("Save Scoutmaster conference sheet", styles::help_desc_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("  y         ", styles::help_key_style()),
+            Span::styled("Pin/unpin as one of My Scouts", styles::help_desc_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("  F/R       ", styles::help_key_style()),
+            Span::styled("Mark consent form/media release signed", styles::help_desc_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("  D         ", styles::help_key_style()),
+            Span::styled("Link den chief to pack and den", styles::help_desc_style()),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled(" Badges Tab", styles::highlight_style())),
+        Line::from(vec![
+            Span::styled("  O         ", styles::help_key_style()),
+            Span::styled("Counselor reports", styles::help_desc_style()),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled(" Events Tab", styles::highlight_style())),
+        Line::from(vec![
+            Span::styled("  v         ", styles::help_key_style()),
+            Span::styled("Show upcoming/past/archived events", styles::help_desc_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("  E         ", styles::help_key_style()),
+            Span::styled("Save emergency sheet", styles::help_desc_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("  R         ", styles::help_key_style()),
+            Span::styled("Save incident report draft", styles::help_desc_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("  U         ", styles::help_key_style()),
+            Span::styled("Buddy pairs and check-in sheet", styles::help_desc_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("  C         ", styles::help_key_style()),
+            Span::styled("Pre-activity checklist", styles::help_desc_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("  S/L       ", styles::help_key_style()),
+            Span::styled("Save event as template/templates", styles::help_desc_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("  H         ", styles::help_key_style()),
+            Span::styled("Historian log", styles::help_desc_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("  W         ", styles::help_key_style()),
+            Span::styled("Committee meeting minutes", styles::help_desc_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("  B/X       ", styles::help_key_style()),
+            Span::styled("Add/remove debrief note (Tab: kind)", styles::help_desc_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("  i         ", styles::help_key_style()),
+            Span::styled("Save events as calendar (.ics)", styles::help_desc_style()),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled(" Unit Tab", styles::highlight_style())),
+        Line::from(vec![
+            Span::styled("  p         ", styles::help_key_style()),
+            Span::styled("Plan next term's positions", styles::help_desc_style()),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled(" Attendance Tab", styles::highlight_style())),
+        Line::from(vec![
+            Span::styled("  p         ", styles::help_key_style()),
+            Span::styled("Sort by name/lowest attendance", styles::help_desc_style()),
+        ]),
         Line::from(""),
         Line::from(vec![
             Span::styled("          Press ", styles::muted_style()),
@@ -337,7 +803,7 @@ fn render_help_overlay(frame: &mut Frame, _app: &App) {
 fn render_login_overlay(frame: &mut Frame, app: &App) {
     let mut height: u16 = 12;
     if app.offline_mode { height += 2; }
-    if app.login_error.is_some() { height += 2; }
+    if app.login.error.is_some() { height += 2; }
     let area = centered_rect_fixed(OVERLAY_WIDTH, height, frame.area());
     frame.render_widget(Clear, area);
 
@@ -353,13 +819,13 @@ fn render_login_overlay(frame: &mut Frame, app: &App) {
     }
 
     // Username field
-    let username_focused = app.login_focus == LoginFocus::Username;
+    let username_focused = app.login.focus == LoginFocus::Username;
     let username_style = if username_focused {
         styles::selected_style()
     } else {
         styles::list_item_style()
     };
-    let username_display = format!("{:<16}", app.login_username);
+    let username_display = format!("{:<16}", app.login.username);
     let cursor = if username_focused { "▌" } else { "" };
     lines.push(Line::from(vec![
         Span::raw("         "),
@@ -369,13 +835,13 @@ fn render_login_overlay(frame: &mut Frame, app: &App) {
     ]));
 
     // Password field
-    let password_focused = app.login_focus == LoginFocus::Password;
+    let password_focused = app.login.focus == LoginFocus::Password;
     let password_style = if password_focused {
         styles::selected_style()
     } else {
         styles::list_item_style()
     };
-    let password_masked: String = "*".repeat(app.login_password.len().min(16));
+    let password_masked: String = "*".repeat(app.login.password.len().min(16));
     let password_display = format!("{:<16}", password_masked);
     let cursor = if password_focused { "▌" } else { "" };
     lines.push(Line::from(vec![
@@ -386,7 +852,7 @@ fn render_login_overlay(frame: &mut Frame, app: &App) {
     ]));
 
     // Login button
-    let button_focused = app.login_focus == LoginFocus::Button;
+    let button_focused = app.login.focus == LoginFocus::Button;
     let button_style = if button_focused {
         styles::selected_style()
     } else {
@@ -401,10 +867,12 @@ fn render_login_overlay(frame: &mut Frame, app: &App) {
     ]));
 
     // Error message (centered)
-    if app.login_error.is_some() {
+    if let Some(ref error) = app.login.error {
+        let interior = OVERLAY_WIDTH.saturating_sub(2) as usize;
+        let padding = interior.saturating_sub(error.len()) / 2;
         lines.push(Line::from(""));
         lines.push(Line::from(Span::styled(
-            "                 LOGIN FAILED",
+            format!("{}{}", " ".repeat(padding), error),
             styles::error_style(),
         )));
     }
@@ -445,41 +913,2845 @@ fn render_quit_overlay(frame: &mut Frame) {
     frame.render_widget(paragraph, area);
 }
 
-fn render_offline_overlay(frame: &mut Frame) {
-    let area = centered_rect_fixed(OVERLAY_WIDTH, 14, frame.area());
+fn render_feed_overlay(frame: &mut Frame, app: &App) {
+    let height = frame.area().height.saturating_sub(4).clamp(10, 30);
+    let area = centered_rect_fixed(FEED_OVERLAY_WIDTH, height, frame.area());
     frame.render_widget(Clear, area);
 
-    let mut lines = logo_lines();
-    lines.extend(vec![
-        Line::from(""),
-        Line::from(Span::styled(
-            "               Enter Offline Mode?",
-            styles::highlight_style(),
-        )),
-        Line::from(""),
-        Line::from(Span::styled(
-            "   All data will be cached for offline access.",
-            styles::muted_style(),
-        )),
-        Line::from(Span::styled(
-            "    Data will remain static until you go back",
-            styles::muted_style(),
-        )),
-        Line::from(Span::styled(
-            "          online by pressing [o] again.",
-            styles::muted_style(),
-        )),
-        Line::from(""),
+    let category = app.feed.category.map(|c| c.label()).unwrap_or("All activity");
+    let scout = if app.feed.scout_only {
+        app.feed_scout().map(|y| y.short_name()).unwrap_or_else(|| "No scout selected".to_string())
+    } else {
+        "All scouts".to_string()
+    };
+
+    let mut lines = vec![
         Line::from(vec![
-            Span::styled("      Press ", styles::muted_style()),
-            Span::styled("[Y]", styles::help_key_style()),
-            Span::styled(" to go offline, ", styles::muted_style()),
-            Span::styled("[N]", styles::help_key_style()),
-            Span::styled(" to cancel", styles::muted_style()),
+            Span::styled(" Showing: ", styles::muted_style()),
+            Span::styled(format!("{} / {}", category, scout), styles::highlight_style()),
         ]),
-    ]);
+        Line::from(""),
+    ];
 
-    let paragraph = Paragraph::new(lines).block(overlay_block());
+    let entries = app.filtered_feed();
+    // Borders, filter line, blank line, and footer take six rows
+    let visible = height.saturating_sub(6) as usize;
+    if entries.is_empty() {
+        lines.push(Line::from(Span::styled(
+            " No activity recorded yet - changes appear here after a refresh",
+            styles::muted_style(),
+        )));
+    }
+    for entry in entries.iter().skip(app.feed.scroll).take(visible) {
+        lines.push(Line::from(Span::styled(format!(" {}", entry.display()), styles::list_item_style())));
+    }
+    while lines.len() < visible + 2 {
+        lines.push(Line::from(""));
+    }
+
+    lines.push(Line::from(vec![
+        Span::styled(" [c]", styles::help_key_style()),
+        Span::styled(" category  ", styles::muted_style()),
+        Span::styled("[s]", styles::help_key_style()),
+        Span::styled(" selected scout  ", styles::muted_style()),
+        Span::styled("[j/k]", styles::help_key_style()),
+        Span::styled(" scroll  ", styles::muted_style()),
+        Span::styled("[Esc]", styles::help_key_style()),
+        Span::styled(" close", styles::muted_style()),
+    ]));
+
+    let paragraph = Paragraph::new(lines).block(overlay_block().title(" Activity Feed "));
+    frame.render_widget(paragraph, area);
+}
+
+/// Body of the My Scouts view, one block per pinned scout.
+pub fn my_scouts_lines(summaries: &[MyScoutSummary]) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+    for (i, s) in summaries.iter().enumerate() {
+        if i > 0 {
+            lines.push(Line::from(""));
+        }
+        lines.push(Line::from(vec![
+            Span::styled(format!(" {}", s.name), styles::highlight_style()),
+            Span::styled(format!("  {}", s.patrol), styles::muted_style()),
+        ]));
+
+        let mut rank = s.current_rank.clone().unwrap_or_else(|| "No rank yet".to_string());
+        if let Some((next, percent)) = &s.next_rank {
+            rank.push_str(&format!(" -> {} {}", next, percent_label(*percent)));
+        }
+        lines.push(Line::from(vec![
+            Span::styled("   Rank:    ", styles::muted_style()),
+            Span::styled(rank, styles::list_item_style()),
+        ]));
+
+        let mut badges = format!("{} earned", s.badges_earned);
+        if !s.badges_in_progress.is_empty() {
+            let working: Vec<String> = s
+                .badges_in_progress
+                .iter()
+                .take(3)
+                .map(|(name, percent)| format!("{} {}", name, percent_label(*percent)))
+                .collect();
+            badges.push_str(&format!(", working on {}", working.join(", ")));
+            if s.badges_in_progress.len() > 3 {
+                badges.push_str(&format!(" +{}", s.badges_in_progress.len() - 3));
+            }
+        }
+        for (j, line) in wrap_text(&badges, FEED_OVERLAY_WIDTH as usize - 16).into_iter().enumerate() {
+            let label = if j == 0 { "   Badges:  " } else { "            " };
+            lines.push(Line::from(vec![
+                Span::styled(label, styles::muted_style()),
+                Span::styled(line, styles::list_item_style()),
+            ]));
+        }
+
+        if s.upcoming.is_empty() {
+            lines.push(Line::from(Span::styled("   No events in the next 30 days", styles::muted_style())));
+        }
+        for event in &s.upcoming {
+            let rsvp = event.rsvp.map(|r| format!("  [{}]", r)).unwrap_or_default();
+            lines.push(Line::from(vec![
+                Span::styled(format!("   {:<11}", event.date.format("%a %b %-d")), styles::muted_style()),
+                Span::styled(truncate(&event.name, 40), styles::list_item_style()),
+                Span::styled(rsvp, styles::muted_style()),
+            ]));
+        }
+
+        for alert in &s.alerts {
+            lines.push(Line::from(Span::styled(format!("   ! {}", alert), styles::error_style())));
+        }
+    }
+    lines
+}
+
+fn render_my_scouts_overlay(frame: &mut Frame, app: &App) {
+    let height = frame.area().height.saturating_sub(4).clamp(10, 36);
+    let area = centered_rect_fixed(FEED_OVERLAY_WIDTH, height, frame.area());
+    frame.render_widget(Clear, area);
+
+    let summaries = app.my_scout_summaries();
+    let mut lines = if summaries.is_empty() {
+        vec![Line::from(Span::styled(
+            " Pinned scouts aren't on the cached roster - refresh or re-pin them",
+            styles::muted_style(),
+        ))]
+    } else {
+        my_scouts_lines(&summaries)
+    };
+
+    // Borders and footer take four rows
+    let visible = height.saturating_sub(4) as usize;
+    lines = lines.into_iter().skip(app.my_scouts_scroll).take(visible).collect();
+    while lines.len() < visible + 1 {
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::from(vec![
+        Span::styled(" [j/k]", styles::help_key_style()),
+        Span::styled(" scroll  ", styles::muted_style()),
+        Span::styled("[y]", styles::help_key_style()),
+        Span::styled(" pin/unpin on Scouts tab  ", styles::muted_style()),
+        Span::styled("[Esc]", styles::help_key_style()),
+        Span::styled(" close", styles::muted_style()),
+    ]));
+
+    let paragraph = Paragraph::new(lines).block(overlay_block().title(" My Scouts "));
+    frame.render_widget(paragraph, area);
+}
+
+fn render_event_templates_overlay(frame: &mut Frame, app: &App) {
+    let height = frame.area().height.saturating_sub(4).clamp(12, 36);
+    let area = centered_rect_fixed(FEED_OVERLAY_WIDTH, height, frame.area());
+    frame.render_widget(Clear, area);
+
+    let templates = &app.templates.templates.templates;
+    let mut lines: Vec<Line> = templates
+        .iter()
+        .enumerate()
+        .map(|(i, t)| {
+            let style = if i == app.templates.selection { styles::selected_style() } else { styles::list_item_style() };
+            Line::from(vec![
+                Span::styled(format!(" {:<40}", truncate(&t.name, 39)), style),
+                Span::styled(format!("{:>10}", t.duration_label()), styles::muted_style()),
+            ])
+        })
+        .collect();
+
+    if let Some(template) = templates.get(app.templates.selection) {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(format!(" {}", template.name), styles::highlight_style())));
+        let location = template.location.as_deref().unwrap_or("-");
+        lines.push(Line::from(Span::styled(
+            format!("   {}  at {}  {} invited", template.duration_label(), location, template.invitees.len()),
+            styles::muted_style(),
+        )));
+        lines.push(Line::from(Span::styled("   Packing list:", styles::muted_style())));
+        if template.packing_list.is_empty() {
+            lines.push(Line::from(Span::styled("     (empty - [p] to add)", styles::muted_style())));
+        }
+        for item in &template.packing_list {
+            lines.push(Line::from(Span::styled(format!("     - {}", item), styles::list_item_style())));
+        }
+    }
+
+    // Borders, blank, input/hint line, and two footer lines
+    let visible = height.saturating_sub(6) as usize;
+    lines.truncate(visible);
+    while lines.len() < visible {
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::from(""));
+    lines.push(match &app.templates.input {
+        Some((kind, text)) => {
+            let label = match kind {
+                TemplateInput::StartDate => " Start (YYYY-MM-DD HH:MM): ",
+                TemplateInput::PackingItem => " Add item: ",
+            };
+            Line::from(vec![
+                Span::styled(label, styles::highlight_style()),
+                Span::styled(format!("{}_", text), styles::list_item_style()),
+            ])
+        }
+        None => Line::from(Span::styled(
+            " New events are saved as a plan to enter in Scoutbook",
+            styles::muted_style(),
+        )),
+    });
+    lines.push(Line::from(vec![
+        Span::styled(" [Enter]", styles::help_key_style()),
+        Span::styled(" new event  ", styles::muted_style()),
+        Span::styled("[p/x]", styles::help_key_style()),
+        Span::styled(" add/remove packing item  ", styles::muted_style()),
+        Span::styled("[D]", styles::help_key_style()),
+        Span::styled(" delete", styles::muted_style()),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled(" [j/k]", styles::help_key_style()),
+        Span::styled(" move  ", styles::muted_style()),
+        Span::styled("[Esc]", styles::help_key_style()),
+        Span::styled(" close", styles::muted_style()),
+    ]));
+
+    let paragraph = Paragraph::new(lines).block(overlay_block().title(" Event Templates "));
+    frame.render_widget(paragraph, area);
+}
+
+fn render_todos_overlay(frame: &mut Frame, app: &App) {
+    let height = frame.area().height.saturating_sub(4).clamp(12, 36);
+    let area = centered_rect_fixed(FEED_OVERLAY_WIDTH, height, frame.area());
+    frame.render_widget(Clear, area);
+    let width = FEED_OVERLAY_WIDTH.saturating_sub(6) as usize;
+    let today = Local::now().date_naive();
+
+    let rows = app.todo_rows();
+    let mut body: Vec<Line> = Vec::new();
+    for (i, todo) in rows.iter().enumerate() {
+        let item = &todo.item;
+        let check = if item.is_open() { "[ ]" } else { "[x]" };
+        let style = if i == app.todos.selection {
+            styles::selected_style()
+        } else if !item.is_open() {
+            styles::muted_style()
+        } else {
+            styles::list_item_style()
+        };
+        let due = item.due.map(|d| d.format("%b %-d").to_string()).unwrap_or_default();
+        let due_style = if item.is_overdue(today) { styles::error_style() } else { styles::muted_style() };
+        body.push(Line::from(vec![
+            Span::styled(format!(" {} {:<42}", check, truncate(&item.text, 41)), style),
+            Span::styled(format!("{:>8}", due), due_style),
+        ]));
+        let mut detail = vec![app.todo_subject_label(todo.subject)];
+        detail.extend(item.owner.clone());
+        body.push(Line::from(Span::styled(format!("       {}", truncate(&detail.join(" - "), width - 4)), styles::muted_style())));
+    }
+    if rows.is_empty() {
+        body.push(Line::from(Span::styled(" Nothing to do - [a] to add", styles::muted_style())));
+    }
+
+    // Heading, borders, blank, input/hint line, and two footer lines
+    let visible = height.saturating_sub(7) as usize;
+    let skip = (app.todos.selection * 2).saturating_sub(visible.saturating_sub(2));
+    let shown = if app.todos.show_done { "All to-dos" } else { "Open to-dos" };
+    let mut lines = vec![Line::from(Span::styled(format!(" {} ({})", shown, rows.len()), styles::highlight_style()))];
+    lines.extend(body.into_iter().skip(skip).take(visible));
+    while lines.len() < visible + 1 {
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::from(""));
+    lines.push(match &app.todos.input {
+        Some(text) => {
+            let label = " New (text @owner YYYY-MM-DD): ";
+            let skip = text.chars().count().saturating_sub(width.saturating_sub(label.len()));
+            let shown: String = text.chars().skip(skip).collect();
+            Line::from(vec![
+                Span::styled(label, styles::highlight_style()),
+                Span::styled(format!("{}_", shown), styles::list_item_style()),
+            ])
+        }
+        None => Line::from(vec![
+            Span::styled(" New items attach to: ", styles::muted_style()),
+            Span::styled(truncate(&app.todo_subject_label(app.todos.subject), width - 22), styles::list_item_style()),
+        ]),
+    });
+    lines.push(Line::from(vec![
+        Span::styled(" [a]", styles::help_key_style()),
+        Span::styled(" add  ", styles::muted_style()),
+        Span::styled("[u]", styles::help_key_style()),
+        Span::styled(" attach to unit  ", styles::muted_style()),
+        Span::styled("[Space]", styles::help_key_style()),
+        Span::styled(" done/reopen  ", styles::muted_style()),
+        Span::styled("[D]", styles::help_key_style()),
+        Span::styled(" delete", styles::muted_style()),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled(" [j/k]", styles::help_key_style()),
+        Span::styled(" move  ", styles::muted_style()),
+        Span::styled("[f]", styles::help_key_style()),
+        Span::styled(" show/hide done  ", styles::muted_style()),
+        Span::styled("[Esc]", styles::help_key_style()),
+        Span::styled(" close", styles::muted_style()),
+    ]));
+
+    let paragraph = Paragraph::new(lines).block(overlay_block().title(" To-Do "));
+    frame.render_widget(paragraph, area);
+}
+
+fn render_alerts_overlay(frame: &mut Frame, app: &App) {
+    let height = frame.area().height.saturating_sub(4).clamp(10, 30);
+    let area = centered_rect_fixed(FEED_OVERLAY_WIDTH, height, frame.area());
+    frame.render_widget(Clear, area);
+    let width = FEED_OVERLAY_WIDTH.saturating_sub(6) as usize;
+    let today = Local::now().date_naive();
+
+    let body: Vec<Line> = app.alerts.list.iter().enumerate().map(|(i, alert)| {
+        let style = if i == app.alerts.selection { styles::selected_style() } else { styles::list_item_style() };
+        let label_style = match alert.kind {
+            AlertKind::Birthday => styles::muted_style(),
+            AlertKind::Pace => styles::highlight_style(),
+            _ if alert.date < today => styles::error_style(),
+            _ => styles::highlight_style(),
+        };
+        Line::from(vec![
+            Span::styled(format!(" {:<11}", alert.kind.label()), label_style),
+            Span::styled(truncate(&alert.describe(today), width - 12), style),
+        ])
+    }).collect();
+
+    // Heading, borders, blank, and footer
+    let visible = height.saturating_sub(5) as usize;
+    let skip = app.alerts.selection.saturating_sub(visible.saturating_sub(1));
+    let mut lines = vec![Line::from(Span::styled(format!(" Alerts ({})", app.alerts.list.len()), styles::highlight_style()))];
+    lines.extend(body.into_iter().skip(skip).take(visible));
+    while lines.len() < visible + 1 {
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled(" [j/k]", styles::help_key_style()),
+        Span::styled(" move  ", styles::muted_style()),
+        Span::styled("[d]", styles::help_key_style()),
+        Span::styled(" dismiss  ", styles::muted_style()),
+        Span::styled("[D]", styles::help_key_style()),
+        Span::styled(" dismiss all  ", styles::muted_style()),
+        Span::styled("[Esc]", styles::help_key_style()),
+        Span::styled(" close", styles::muted_style()),
+    ]));
+
+    let paragraph = Paragraph::new(lines).block(overlay_block().title(" Alerts "));
+    frame.render_widget(paragraph, area);
+}
+
+fn render_library_overlay(frame: &mut Frame, app: &App) {
+    let height = frame.area().height.saturating_sub(4).clamp(12, 36);
+    let area = centered_rect_fixed(FEED_OVERLAY_WIDTH, height, frame.area());
+    frame.render_widget(Clear, area);
+    let width = FEED_OVERLAY_WIDTH.saturating_sub(6) as usize;
+    let today = Local::now().date_naive();
+
+    // Each title, then who has its copies and when they're due back
+    let mut body: Vec<Line> = Vec::new();
+    let mut selected_line = 0;
+    for (i, item) in app.library.titles.items.iter().enumerate() {
+        let style = if i == app.library.selection { styles::selected_style() } else { styles::list_item_style() };
+        if i == app.library.selection {
+            selected_line = body.len();
+        }
+        body.push(Line::from(vec![
+            Span::styled(format!(" {:<44}", truncate(&item.title, 43)), style),
+            Span::styled(format!("{:>2} of {} in", item.available(), item.copies), styles::muted_style()),
+        ]));
+        for loan in &item.loans {
+            let due_style = if loan.is_overdue(today) { styles::error_style() } else { styles::muted_style() };
+            body.push(Line::from(vec![
+                Span::styled(format!("   {:<30}", truncate(&app.borrower_name(loan.user_id), 29)), styles::muted_style()),
+                Span::styled(format!("due {}", loan.due.format("%b %-d")), due_style),
+            ]));
+        }
+    }
+    if body.is_empty() {
+        body.push(Line::from(Span::styled(" No pamphlets or books yet - [a] to add", styles::muted_style())));
+    }
+
+    // Heading, borders, blank, input/borrower line, and two footer lines
+    let visible = height.saturating_sub(7) as usize;
+    let skip = selected_line.saturating_sub(visible.saturating_sub(2));
+    let out: usize = app.library.titles.items.iter().map(|i| i.loans.len()).sum();
+    let mut lines = vec![Line::from(Span::styled(
+        format!(" Library ({} titles, {} out)", app.library.titles.items.len(), out),
+        styles::highlight_style(),
+    ))];
+    lines.extend(body.into_iter().skip(skip).take(visible));
+    while lines.len() < visible + 1 {
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::from(""));
+    lines.push(match &app.library.input {
+        Some(text) => {
+            let label = " New (title, or title x3): ";
+            let skip = text.chars().count().saturating_sub(width.saturating_sub(label.len()));
+            let shown: String = text.chars().skip(skip).collect();
+            Line::from(vec![
+                Span::styled(label, styles::highlight_style()),
+                Span::styled(format!("{}_", shown), styles::list_item_style()),
+            ])
+        }
+        None => match app.library.borrower {
+            Some(id) => Line::from(vec![
+                Span::styled(" Lending to: ", styles::muted_style()),
+                Span::styled(truncate(&app.borrower_name(id), width - 13), styles::list_item_style()),
+            ]),
+            None => Line::from(Span::styled(" Open from a scout on the Scouts tab to lend", styles::muted_style())),
+        },
+    });
+    lines.push(Line::from(vec![
+        Span::styled(" [o]", styles::help_key_style()),
+        Span::styled(" lend  ", styles::muted_style()),
+        Span::styled("[r]", styles::help_key_style()),
+        Span::styled(" return  ", styles::muted_style()),
+        Span::styled("[a]", styles::help_key_style()),
+        Span::styled(" add copies  ", styles::muted_style()),
+        Span::styled("[x]", styles::help_key_style()),
+        Span::styled(" remove a copy", styles::muted_style()),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled(" [j/k]", styles::help_key_style()),
+        Span::styled(" move  ", styles::muted_style()),
+        Span::styled("[Esc]", styles::help_key_style()),
+        Span::styled(" close", styles::muted_style()),
+    ]));
+
+    let paragraph = Paragraph::new(lines).block(overlay_block().title(" Troop Library "));
+    frame.render_widget(paragraph, area);
+}
+
+fn render_closet_overlay(frame: &mut Frame, app: &App) {
+    let height = frame.area().height.saturating_sub(4).clamp(12, 36);
+    let area = centered_rect_fixed(FEED_OVERLAY_WIDTH, height, frame.area());
+    frame.render_widget(Clear, area);
+    let width = FEED_OVERLAY_WIDTH.saturating_sub(6) as usize;
+
+    // Each item, and under the selected one its checkout history
+    let rows = app.closet_rows();
+    let mut body: Vec<Line> = Vec::new();
+    let mut selected_line = 0;
+    for (i, item) in rows.iter().filter_map(|&id| app.closet.items.get(id)).enumerate() {
+        let selected = i == app.closet.selection;
+        let style = if selected { styles::selected_style() } else { styles::list_item_style() };
+        if selected {
+            selected_line = body.len();
+        }
+        let status = match item.current() {
+            Some(c) => Span::styled(truncate(&app.borrower_name(c.user_id), 18), styles::muted_style()),
+            None => Span::styled("in closet", styles::success_style()),
+        };
+        body.push(Line::from(vec![
+            Span::styled(format!(" {:<26}", truncate(&item.kind, 25)), style),
+            Span::styled(format!("{:<14}", truncate(&item.size, 13)), style),
+            status,
+        ]));
+        if !selected {
+            continue;
+        }
+        if !item.note.is_empty() {
+            body.push(Line::from(Span::styled(format!("   {}", truncate(&item.note, width - 3)), styles::muted_style())));
+        }
+        body.push(Line::from(Span::styled(
+            format!("   Donated {}", item.donated_on.format("%b %-d, %Y")),
+            styles::muted_style(),
+        )));
+        for checkout in item.checkouts.iter().rev() {
+            let returned = match checkout.returned_on {
+                Some(date) => format!("returned {}", date.format("%b %-d, %Y")),
+                None => "has it".to_string(),
+            };
+            body.push(Line::from(Span::styled(
+                format!(
+                    "   {:<24} out {}, {}",
+                    truncate(&app.borrower_name(checkout.user_id), 23),
+                    checkout.out_on.format("%b %-d, %Y"),
+                    returned
+                ),
+                styles::muted_style(),
+            )));
+        }
+    }
+    if body.is_empty() {
+        let empty = if app.closet.items.items.is_empty() {
+            " Nothing in the closet yet - [a] to add"
+        } else {
+            " No items match"
+        };
+        body.push(Line::from(Span::styled(empty, styles::muted_style())));
+    }
+
+    // Heading, borders, blank, input/borrower line, and two footer lines
+    let visible = height.saturating_sub(7) as usize;
+    let skip = selected_line.saturating_sub(visible.saturating_sub(2));
+    let available = app.closet.items.items.iter().filter(|i| i.is_available()).count();
+    let mut heading = format!(" Closet ({} items, {} in)", app.closet.items.items.len(), available);
+    if !app.closet.show_out {
+        heading.push_str(", in closet only");
+    }
+    let mut lines = vec![Line::from(Span::styled(heading, styles::highlight_style()))];
+    lines.extend(body.into_iter().skip(skip).take(visible));
+    while lines.len() < visible + 1 {
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::from(""));
+    let typed = |label: &'static str, text: &str, cursor: bool| {
+        let skip = text.chars().count().saturating_sub(width.saturating_sub(label.len() + 1));
+        let shown: String = text.chars().skip(skip).collect();
+        Line::from(vec![
+            Span::styled(label, styles::highlight_style()),
+            Span::styled(format!("{}{}", shown, if cursor { "_" } else { "" }), styles::list_item_style()),
+        ])
+    };
+    lines.push(match &app.closet.input {
+        Some(text) => typed(" New (type, size, note): ", text, true),
+        None if app.closet.editing_filter || !app.closet.filter.is_empty() => {
+            typed(" Filter: ", &app.closet.filter, app.closet.editing_filter)
+        }
+        None => match app.closet.borrower {
+            Some(id) => Line::from(vec![
+                Span::styled(" Checking out to: ", styles::muted_style()),
+                Span::styled(truncate(&app.borrower_name(id), width - 19), styles::list_item_style()),
+            ]),
+            None => Line::from(Span::styled(" Open from a scout on the Scouts tab to check out", styles::muted_style())),
+        },
+    });
+    lines.push(Line::from(vec![
+        Span::styled(" [o]", styles::help_key_style()),
+        Span::styled(" check out  ", styles::muted_style()),
+        Span::styled("[r]", styles::help_key_style()),
+        Span::styled(" return  ", styles::muted_style()),
+        Span::styled("[a]", styles::help_key_style()),
+        Span::styled(" add  ", styles::muted_style()),
+        Span::styled("[x]", styles::help_key_style()),
+        Span::styled(" remove  ", styles::muted_style()),
+        Span::styled("[/]", styles::help_key_style()),
+        Span::styled(" size/type", styles::muted_style()),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled(" [f]", styles::help_key_style()),
+        Span::styled(" show checked out  ", styles::muted_style()),
+        Span::styled("[j/k]", styles::help_key_style()),
+        Span::styled(" move  ", styles::muted_style()),
+        Span::styled("[Esc]", styles::help_key_style()),
+        Span::styled(" close", styles::muted_style()),
+    ]));
+
+    let paragraph = Paragraph::new(lines).block(overlay_block().title(" Uniform Closet "));
+    frame.render_widget(paragraph, area);
+}
+
+fn render_adult_skills_overlay(frame: &mut Frame, app: &App) {
+    let height = frame.area().height.saturating_sub(4).clamp(12, 36);
+    let area = centered_rect_fixed(FEED_OVERLAY_WIDTH, height, frame.area());
+    frame.render_widget(Clear, area);
+    let width = FEED_OVERLAY_WIDTH.saturating_sub(6) as usize;
+    let today = Local::now().date_naive();
+
+    // Each skill once as a heading, with the adults who have it under it
+    let rows = app.adult_skill_rows();
+    let mut body: Vec<Line> = Vec::new();
+    let mut selected_line = 0;
+    let mut heading: Option<String> = None;
+    for (i, skill) in rows.iter().enumerate() {
+        let key = skill.skill.to_lowercase();
+        if heading.as_ref() != Some(&key) {
+            body.push(Line::from(Span::styled(format!(" {}", truncate(&skill.skill, width - 1)), styles::title_style())));
+            heading = Some(key);
+        }
+        let selected = i == app.adult_skills.selection;
+        if selected {
+            selected_line = body.len();
+        }
+        let style = if selected { styles::selected_style() } else { styles::list_item_style() };
+        let (expiry, expiry_style) = match skill.expires {
+            Some(date) if !skill.is_current(today) => (format!("expired {}", date.format("%b %-d, %Y")), styles::error_style()),
+            Some(date) if skill.expires_soon(today) => (format!("expires {}", date.format("%b %-d, %Y")), styles::highlight_style()),
+            Some(date) => (format!("until {}", date.format("%b %-d, %Y")), styles::muted_style()),
+            None => (String::new(), styles::muted_style()),
+        };
+        body.push(Line::from(vec![
+            Span::styled(format!("   {:<26}", truncate(&app.adult_name(skill.user_id), 25)), style),
+            Span::styled(format!("{:<22}", expiry), expiry_style),
+            Span::styled(truncate(&skill.note, width.saturating_sub(51)), styles::muted_style()),
+        ]));
+    }
+    if body.is_empty() {
+        let empty = if app.adult_skills.directory.skills.is_empty() {
+            " No skills yet - open from an adult on the Adults tab and press [a]"
+        } else {
+            " No skills match"
+        };
+        body.push(Line::from(Span::styled(empty, styles::muted_style())));
+    }
+
+    // Heading, borders, blank, input/adult line, and footer
+    let visible = height.saturating_sub(6) as usize;
+    let skip = selected_line.saturating_sub(visible.saturating_sub(2));
+    let current = app.adult_skills.directory.skills.iter().filter(|s| s.is_current(today)).count();
+    let heading = format!(" Adult Skills ({} skills, {} current)", app.adult_skills.directory.skills.len(), current);
+    let mut lines = vec![Line::from(Span::styled(heading, styles::highlight_style()))];
+    lines.extend(body.into_iter().skip(skip).take(visible));
+    while lines.len() < visible + 1 {
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::from(""));
+    let typed = |label: &'static str, text: &str, cursor: bool| {
+        let skip = text.chars().count().saturating_sub(width.saturating_sub(label.len() + 1));
+        let shown: String = text.chars().skip(skip).collect();
+        Line::from(vec![
+            Span::styled(label, styles::highlight_style()),
+            Span::styled(format!("{}{}", shown, if cursor { "_" } else { "" }), styles::list_item_style()),
+        ])
+    };
+    lines.push(match &app.adult_skills.input {
+        Some(text) => typed(" New (skill, expires, note): ", text, true),
+        None if app.adult_skills.editing_filter || !app.adult_skills.filter.is_empty() => {
+            typed(" Filter: ", &app.adult_skills.filter, app.adult_skills.editing_filter)
+        }
+        None => match app.adult_skills.adult {
+            Some(id) => Line::from(vec![
+                Span::styled(" Adding to: ", styles::muted_style()),
+                Span::styled(truncate(&app.adult_name(id), width - 12), styles::list_item_style()),
+            ]),
+            None => Line::from(Span::styled(
+                format!(" e.g. {}", COMMON_ADULT_SKILLS.join(", ")).chars().take(width).collect::<String>(),
+                styles::muted_style(),
+            )),
+        },
+    });
+    lines.push(Line::from(vec![
+        Span::styled(" [/]", styles::help_key_style()),
+        Span::styled(" find skill or name  ", styles::muted_style()),
+        Span::styled("[a]", styles::help_key_style()),
+        Span::styled(" add  ", styles::muted_style()),
+        Span::styled("[x]", styles::help_key_style()),
+        Span::styled(" remove  ", styles::muted_style()),
+        Span::styled("[j/k]", styles::help_key_style()),
+        Span::styled(" move  ", styles::muted_style()),
+        Span::styled("[Esc]", styles::help_key_style()),
+        Span::styled(" close", styles::muted_style()),
+    ]));
+
+    let paragraph = Paragraph::new(lines).block(overlay_block().title(" Adult Skills "));
+    frame.render_widget(paragraph, area);
+}
+
+fn render_trek_overlay(frame: &mut Frame, app: &App) {
+    let height = frame.area().height.saturating_sub(4).clamp(12, 36);
+    let area = centered_rect_fixed(FEED_OVERLAY_WIDTH, height, frame.area());
+    frame.render_widget(Clear, area);
+    let width = FEED_OVERLAY_WIDTH.saturating_sub(6) as usize;
+    let Some(plan) = &app.trek.plan.plan else {
+        return;
+    };
+    let results = app.trek_results();
+
+    // Each person with their first gap, and under the selected one every
+    // gap and their recorded physical
+    let mut body: Vec<Line> = Vec::new();
+    let mut selected_line = 0;
+    for (i, s) in results.iter().enumerate() {
+        let selected = i == app.trek.selection;
+        let style = if selected { styles::selected_style() } else { styles::list_item_style() };
+        if selected {
+            selected_line = body.len();
+        }
+        let status = match s.gaps.as_slice() {
+            [] => Span::styled("eligible", styles::success_style()),
+            [gap] => Span::styled(truncate(&gap.describe(), 32), styles::error_style()),
+            [gap, rest @ ..] => {
+                Span::styled(format!("{} +{}", truncate(&gap.describe(), 28), rest.len()), styles::error_style())
+            }
+        };
+        let age = s.age.map(|a| a.to_string()).unwrap_or_else(|| "-".to_string());
+        body.push(Line::from(vec![
+            Span::styled(format!(" {:<26}", truncate(&s.name, 25)), style),
+            Span::styled(format!("{:<6}{:>3}  ", if s.is_adult { "Adult" } else { "Scout" }, age), styles::muted_style()),
+            status,
+        ]));
+        if !selected {
+            continue;
+        }
+        let physical = app.trek.plan.physical(s.user_id);
+        let exam = physical.exam_on.map(|d| d.format("%b %-d, %Y").to_string()).unwrap_or_else(|| "none".to_string());
+        let weight = match physical.weight_ok {
+            None => "not checked",
+            Some(true) => "meets chart",
+            Some(false) => "over chart",
+        };
+        body.push(Line::from(Span::styled(
+            format!("   Medical exam: {}   Height/weight: {}", exam, weight),
+            styles::muted_style(),
+        )));
+        for gap in s.gaps.iter().skip(1) {
+            body.push(Line::from(Span::styled(format!("   {}", truncate(&gap.describe(), width - 3)), styles::error_style())));
+        }
+    }
+    if body.is_empty() {
+        body.push(Line::from(Span::styled(" No scouts or adults loaded", styles::muted_style())));
+    }
+
+    // Heading, borders, blank, input/summary line, and two footer lines
+    let visible = height.saturating_sub(7) as usize;
+    let skip = selected_line.saturating_sub(visible.saturating_sub(2));
+    let eligible = results.iter().filter(|s| s.is_eligible()).count();
+    let min_rank = match plan.min_rank {
+        ScoutRank::Unknown => "any rank".to_string(),
+        rank => format!("{}+", rank.display_name()),
+    };
+    let mut lines = vec![Line::from(Span::styled(
+        format!(" {}, {} - {} - {} of {} eligible", plan.base.name(), plan.date.format("%b %-d, %Y"), min_rank, eligible, results.len()),
+        styles::highlight_style(),
+    ))];
+    lines.extend(body.into_iter().skip(skip).take(visible));
+    while lines.len() < visible + 1 {
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::from(""));
+    let advisors = crew_advisors(&results);
+    lines.push(match &app.trek.input {
+        Some((field, text)) => {
+            let label = match field {
+                TrekField::TrekDate => " Trek date (YYYY-MM-DD): ",
+                TrekField::ExamDate => " Medical exam date (YYYY-MM-DD, blank to clear): ",
+            };
+            Line::from(vec![
+                Span::styled(label, styles::highlight_style()),
+                Span::styled(format!("{}_", text), styles::list_item_style()),
+            ])
+        }
+        None if advisors < CREW_ADVISORS_REQUIRED => Line::from(Span::styled(
+            format!(" Crew needs {} eligible adults 21 or older, has {}", CREW_ADVISORS_REQUIRED, advisors),
+            styles::error_style(),
+        )),
+        None => Line::from(Span::styled(format!(" {} eligible adult advisors", advisors), styles::muted_style())),
+    });
+    lines.push(Line::from(vec![
+        Span::styled(" [b]", styles::help_key_style()),
+        Span::styled(" base  ", styles::muted_style()),
+        Span::styled("[d]", styles::help_key_style()),
+        Span::styled(" date  ", styles::muted_style()),
+        Span::styled("[r]", styles::help_key_style()),
+        Span::styled(" min rank  ", styles::muted_style()),
+        Span::styled("[m]", styles::help_key_style()),
+        Span::styled(" medical  ", styles::muted_style()),
+        Span::styled("[w]", styles::help_key_style()),
+        Span::styled(" height/weight", styles::muted_style()),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled(" [e]", styles::help_key_style()),
+        Span::styled(" export report  ", styles::muted_style()),
+        Span::styled("[j/k]", styles::help_key_style()),
+        Span::styled(" move  ", styles::muted_style()),
+        Span::styled("[Esc]", styles::help_key_style()),
+        Span::styled(" close", styles::muted_style()),
+    ]));
+
+    let paragraph = Paragraph::new(lines).block(overlay_block().title(" High Adventure "));
+    frame.render_widget(paragraph, area);
+}
+
+fn render_eagle_project_overlay(frame: &mut Frame, app: &App) {
+    let height = frame.area().height.saturating_sub(4).clamp(16, 36);
+    let area = centered_rect_fixed(FEED_OVERLAY_WIDTH, height, frame.area());
+    frame.render_widget(Clear, area);
+    let width = FEED_OVERLAY_WIDTH.saturating_sub(6) as usize;
+    let project = app.eagle_projects.user.and_then(|id| app.eagle_projects.projects.get(id));
+    let or_blank = |text: Option<&str>| match text {
+        Some(t) if !t.is_empty() => t.to_string(),
+        _ => "-".to_string(),
+    };
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            format!(" {} - {}", app.eagle_project_name(), truncate(&or_blank(project.map(|p| p.title.as_str())), width - 20)),
+            styles::highlight_style(),
+        )),
+        Line::from(vec![
+            Span::styled(" Beneficiary: ", styles::muted_style()),
+            Span::styled(or_blank(project.map(|p| p.beneficiary.as_str())), styles::list_item_style()),
+        ]),
+        Line::from(vec![
+            Span::styled(" Stage: ", styles::muted_style()),
+            Span::styled(project.map(|p| p.stage).unwrap_or_default().name(), styles::list_item_style()),
+            Span::styled("   Fundraising: ", styles::muted_style()),
+            Span::styled(project.map(|p| p.fundraising).unwrap_or_default().name(), styles::list_item_style()),
+            Span::styled("   Hours: ", styles::muted_style()),
+            Span::styled(project.map(|p| p.total_hours()).unwrap_or_default().to_string(), styles::list_item_style()),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled(" District paperwork", styles::title_style())),
+    ];
+
+    for (i, item) in Paperwork::ALL.iter().enumerate() {
+        let style = if i == app.eagle_projects.selection { styles::selected_style() } else { styles::list_item_style() };
+        let done = project.and_then(|p| p.paperwork.get(item));
+        let not_needed = *item == Paperwork::FundraisingApplication
+            && project.is_none_or(|p| p.fundraising == Fundraising::NotNeeded);
+        let (mark, date) = match done {
+            Some(date) => ("[✓]", date.format("%b %-d, %Y").to_string()),
+            None if not_needed => ("[-]", "not needed".to_string()),
+            None => ("[ ]", String::new()),
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!(" {} {:<56}", mark, truncate(item.name(), 55)), style),
+            Span::styled(date, styles::muted_style()),
+        ]));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(" Timeline", styles::title_style())));
+
+    // Heading lines above, the paperwork, the input line, and two footer
+    // lines and borders leave the rest for the newest timeline entries
+    let timeline = project
+        .map(|p| p.timeline(app.store.events.iter().chain(&app.store.event_archive.events)))
+        .unwrap_or_default();
+    let visible = (height as usize).saturating_sub(lines.len() + 6);
+    if timeline.is_empty() {
+        lines.push(Line::from(Span::styled("  Nothing recorded yet", styles::muted_style())));
+    }
+    for entry in timeline.iter().skip(timeline.len().saturating_sub(visible)) {
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {:<14}", entry.date.format("%b %-d, %Y")), styles::muted_style()),
+            Span::styled(truncate(&entry.text, width - 16), styles::list_item_style()),
+        ]));
+    }
+    while lines.len() < (height as usize).saturating_sub(5) {
+        lines.push(Line::from(""));
+    }
+
+    lines.push(match &app.eagle_projects.input {
+        Some((field, text)) => {
+            let label = match field {
+                ProjectField::Title => " Project title: ",
+                ProjectField::Beneficiary => " Beneficiary: ",
+                ProjectField::Hours => " Hours and who worked (3.5 patrol helpers): ",
+                ProjectField::Workday => " Workday event date (YYYY-MM-DD): ",
+            };
+            Line::from(vec![
+                Span::styled(label, styles::highlight_style()),
+                Span::styled(format!("{}_", text), styles::list_item_style()),
+            ])
+        }
+        None => Line::from(""),
+    });
+    lines.push(Line::from(vec![
+        Span::styled(" [s/S]", styles::help_key_style()),
+        Span::styled(" stage  ", styles::muted_style()),
+        Span::styled("[f]", styles::help_key_style()),
+        Span::styled(" fundraising  ", styles::muted_style()),
+        Span::styled("[t]", styles::help_key_style()),
+        Span::styled(" title  ", styles::muted_style()),
+        Span::styled("[b]", styles::help_key_style()),
+        Span::styled(" beneficiary  ", styles::muted_style()),
+        Span::styled("[h]", styles::help_key_style()),
+        Span::styled(" hours  ", styles::muted_style()),
+        Span::styled("[w]", styles::help_key_style()),
+        Span::styled(" workday", styles::muted_style()),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled(" [Space]", styles::help_key_style()),
+        Span::styled(" check paperwork  ", styles::muted_style()),
+        Span::styled("[j/k]", styles::help_key_style()),
+        Span::styled(" move  ", styles::muted_style()),
+        Span::styled("[Esc]", styles::help_key_style()),
+        Span::styled(" close", styles::muted_style()),
+    ]));
+
+    let paragraph = Paragraph::new(lines).block(overlay_block().title(" Eagle Project "));
+    frame.render_widget(paragraph, area);
+}
+
+fn render_clinic_overlay(frame: &mut Frame, app: &App) {
+    let height = frame.area().height.saturating_sub(4).clamp(12, 36);
+    let area = centered_rect_fixed(FEED_OVERLAY_WIDTH, height, frame.area());
+    frame.render_widget(Clear, area);
+    let width = FEED_OVERLAY_WIDTH.saturating_sub(6) as usize;
+    let rows = app.clinic_rows();
+
+    let mut body: Vec<Line> = Vec::new();
+    for (i, &(class_index, scout)) in rows.iter().enumerate() {
+        let class = &app.clinic.plan.classes[class_index];
+        let style = if i == app.clinic.selection { styles::selected_style() } else { styles::list_item_style() };
+        body.push(match scout {
+            None => {
+                let counselor = if class.counselor.is_empty() { "no counselor" } else { &class.counselor };
+                Line::from(vec![
+                    Span::styled(format!(" {:<30}", truncate(&class.badge, 29)), style),
+                    Span::styled(format!("{:<26}", truncate(counselor, 25)), styles::muted_style()),
+                    Span::styled(format!("{} registered", class.scouts.len()), styles::muted_style()),
+                ])
+            }
+            Some(user_id) => {
+                let (mark, mark_style) = if class.is_completed(user_id) {
+                    ("[✓]", styles::success_style())
+                } else {
+                    ("[ ]", styles::muted_style())
+                };
+                let here = if app.clinic.scout == Some(user_id) { " *" } else { "" };
+                Line::from(vec![
+                    Span::styled(format!("   {} ", mark), mark_style),
+                    Span::styled(truncate(&format!("{}{}", app.youth_name(user_id), here), width - 8), style),
+                ])
+            }
+        });
+    }
+    if body.is_empty() {
+        body.push(Line::from(Span::styled(" No classes yet - press a to add a merit badge", styles::muted_style())));
+    }
+
+    // Heading, borders, blank, input/scout line, and two footer lines
+    let visible = height.saturating_sub(7) as usize;
+    let skip = app.clinic.selection.saturating_sub(visible.saturating_sub(1));
+    let date = app.clinic.plan.date.map(|d| d.format("%b %-d, %Y").to_string()).unwrap_or_else(|| "no date".to_string());
+    let registered: usize = app.clinic.plan.classes.iter().map(|c| c.scouts.len()).sum();
+    let completed: usize = app.clinic.plan.classes.iter().map(|c| c.completed.len()).sum();
+    let mut lines = vec![Line::from(Span::styled(
+        format!(
+            " {} - {} - {} classes, {} registrations, {} completed",
+            app.clinic.plan.name,
+            date,
+            app.clinic.plan.classes.len(),
+            registered,
+            completed
+        ),
+        styles::highlight_style(),
+    ))];
+    lines.extend(body.into_iter().skip(skip).take(visible));
+    while lines.len() < visible + 1 {
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::from(""));
+    lines.push(match &app.clinic.input {
+        Some((field, text)) => {
+            let label = match field {
+                ClinicField::Name => " Clinic name: ",
+                ClinicField::Date => " Clinic date (YYYY-MM-DD): ",
+                ClinicField::Badge => " Merit badge: ",
+                ClinicField::Counselor => " Counselor: ",
+            };
+            Line::from(vec![
+                Span::styled(label, styles::highlight_style()),
+                Span::styled(format!("{}_", text), styles::list_item_style()),
+            ])
+        }
+        None => match app.clinic.scout {
+            Some(user_id) => Line::from(Span::styled(
+                format!(" Registering {} (*)", app.youth_name(user_id)),
+                styles::muted_style(),
+            )),
+            None => Line::from(Span::styled(" Open from a scout on the Scouts tab to register them", styles::muted_style())),
+        },
+    });
+    lines.push(Line::from(vec![
+        Span::styled(" [a]", styles::help_key_style()),
+        Span::styled(" add badge  ", styles::muted_style()),
+        Span::styled("[c]", styles::help_key_style()),
+        Span::styled(" counselor  ", styles::muted_style()),
+        Span::styled("[o]", styles::help_key_style()),
+        Span::styled(" register  ", styles::muted_style()),
+        Span::styled("[Space]", styles::help_key_style()),
+        Span::styled(" completed  ", styles::muted_style()),
+        Span::styled("[x]", styles::help_key_style()),
+        Span::styled(" remove", styles::muted_style()),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled(" [n/d]", styles::help_key_style()),
+        Span::styled(" name/date  ", styles::muted_style()),
+        Span::styled("[e]", styles::help_key_style()),
+        Span::styled(" rosters  ", styles::muted_style()),
+        Span::styled("[s]", styles::help_key_style()),
+        Span::styled(" sign-off sheets  ", styles::muted_style()),
+        Span::styled("[Esc]", styles::help_key_style()),
+        Span::styled(" close", styles::muted_style()),
+    ]));
+
+    let paragraph = Paragraph::new(lines).block(overlay_block().title(" Merit Badge Clinic "));
+    frame.render_widget(paragraph, area);
+}
+
+fn render_palette_overlay(frame: &mut Frame, app: &App) {
+    let height = frame.area().height.saturating_sub(4).clamp(12, 30);
+    let area = centered_rect_fixed(FEED_OVERLAY_WIDTH, height, frame.area());
+    frame.render_widget(Clear, area);
+    let width = FEED_OVERLAY_WIDTH.saturating_sub(6) as usize;
+
+    let query = SearchQuery::new(app.palette_query.trim(), SearchMode::Fuzzy);
+    let hits = app.palette_hits();
+    let mut body: Vec<Line> = Vec::new();
+    for (i, hit) in hits.iter().enumerate() {
+        let style = if i == app.palette_selection { styles::selected_style() } else { styles::list_item_style() };
+        let title_width = width.saturating_sub(30);
+        let title = format!("{:<w$}", truncate(&hit.title, title_width.saturating_sub(1)), w = title_width);
+        let mut spans = vec![Span::styled(format!(" {:<7}", hit.target.label()), styles::muted_style())];
+        spans.extend(styles::highlight_matches(title, &query, style).spans);
+        spans.push(Span::styled(truncate(&hit.detail, 22), styles::muted_style()));
+        body.push(Line::from(spans));
+    }
+    if hits.is_empty() {
+        let hint = if query.is_empty() { " Type to search scouts, adults, events, ranks, and badges" } else { " No matches" };
+        body.push(Line::from(Span::styled(hint, styles::muted_style())));
+    }
+
+    // Heading, borders, blank, input line, and footer
+    let visible = height.saturating_sub(6) as usize;
+    let skip = app.palette_selection.saturating_sub(visible.saturating_sub(1));
+    let mut lines = vec![Line::from(Span::styled(format!(" Matches ({})", hits.len()), styles::highlight_style()))];
+    lines.extend(body.into_iter().skip(skip).take(visible));
+    while lines.len() < visible + 1 {
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled(" Search all: ", styles::highlight_style()),
+        Span::styled(format!("{}_", app.palette_query), styles::list_item_style()),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled(" [Up/Down]", styles::help_key_style()),
+        Span::styled(" move  ", styles::muted_style()),
+        Span::styled("[Enter]", styles::help_key_style()),
+        Span::styled(" go to it  ", styles::muted_style()),
+        Span::styled("[Esc]", styles::help_key_style()),
+        Span::styled(" close", styles::muted_style()),
+    ]));
+
+    let paragraph = Paragraph::new(lines).block(overlay_block().title(" Search Everything "));
+    frame.render_widget(paragraph, area);
+}
+
+fn render_member_id_overlay(frame: &mut Frame, app: &App) {
+    let height = frame.area().height.saturating_sub(4).clamp(12, 30);
+    let area = centered_rect_fixed(FEED_OVERLAY_WIDTH, height, frame.area());
+    frame.render_widget(Clear, area);
+    let width = FEED_OVERLAY_WIDTH.saturating_sub(6) as usize;
+
+    let rows = app.member_id_rows();
+    let mut body: Vec<Line> = Vec::new();
+    for (i, (found, issue)) in rows.iter().enumerate() {
+        let style = if i == app.member_id_selection { styles::selected_style() } else { styles::list_item_style() };
+        let mut name = found.name.clone();
+        if let Some(scout) = found.youth_user_id.and_then(|id| app.store.youth.iter().find(|y| y.user_id == Some(id))) {
+            name = format!("{} (parent of {})", name, scout.short_name());
+        }
+        let (note, note_style) = match issue {
+            Some(issue) => (issue.to_string(), styles::error_style()),
+            None => (found.member_id.clone().unwrap_or_default(), styles::muted_style()),
+        };
+        let name_width = width.saturating_sub(36);
+        body.push(Line::from(vec![
+            Span::styled(format!(" {:<7}", found.kind.label()), styles::muted_style()),
+            Span::styled(format!("{:<w$}", truncate(&name, name_width.saturating_sub(1)), w = name_width), style),
+            Span::styled(truncate(&note, 28), note_style),
+        ]));
+    }
+
+    let heading = if app.member_id_query.trim().is_empty() {
+        if rows.is_empty() {
+            body.push(Line::from(Span::styled(" Every scout and adult has a valid BSA ID", styles::muted_style())));
+        }
+        format!(" Missing or invalid BSA IDs ({}) - advancement can't be submitted for these", rows.len())
+    } else {
+        if rows.is_empty() {
+            body.push(Line::from(Span::styled(" No one has a BSA ID starting with that", styles::muted_style())));
+        }
+        format!(" Matches ({})", rows.len())
+    };
+
+    // Heading, borders, blank, input line, and footer
+    let visible = height.saturating_sub(6) as usize;
+    let skip = app.member_id_selection.saturating_sub(visible.saturating_sub(1));
+    let mut lines = vec![Line::from(Span::styled(truncate(&heading, width), styles::highlight_style()))];
+    lines.extend(body.into_iter().skip(skip).take(visible));
+    while lines.len() < visible + 1 {
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled(" BSA ID: ", styles::highlight_style()),
+        Span::styled(format!("{}_", app.member_id_query), styles::list_item_style()),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled(" [Up/Down]", styles::help_key_style()),
+        Span::styled(" move  ", styles::muted_style()),
+        Span::styled("[Enter]", styles::help_key_style()),
+        Span::styled(" go to person  ", styles::muted_style()),
+        Span::styled("[Esc]", styles::help_key_style()),
+        Span::styled(" close", styles::muted_style()),
+    ]));
+
+    let paragraph = Paragraph::new(lines).block(overlay_block().title(" BSA ID Lookup "));
+    frame.render_widget(paragraph, area);
+}
+
+fn render_contact_check_overlay(frame: &mut Frame, app: &App) {
+    let height = frame.area().height.saturating_sub(4).clamp(12, 30);
+    let area = centered_rect_fixed(FEED_OVERLAY_WIDTH, height, frame.area());
+    frame.render_widget(Clear, area);
+    let width = FEED_OVERLAY_WIDTH.saturating_sub(6) as usize;
+
+    let rows = app.contact_rows();
+    let mut body: Vec<Line> = Vec::new();
+    for (i, (found, issue)) in rows.iter().enumerate() {
+        let style = if i == app.contact_selection { styles::selected_style() } else { styles::list_item_style() };
+        let name_width = width.saturating_sub(40);
+        body.push(Line::from(vec![
+            Span::styled(format!(" {:<7}", found.kind.label()), styles::muted_style()),
+            Span::styled(format!("{:<w$}", truncate(&found.name, name_width.saturating_sub(1)), w = name_width), style),
+            Span::styled(truncate(&issue.to_string(), 32), styles::error_style()),
+        ]));
+    }
+    if rows.is_empty() {
+        body.push(Line::from(Span::styled(" No contact problems found", styles::muted_style())));
+    }
+    let heading = format!(" Contact problems ({}) - fix in Scoutbook so messages arrive", rows.len());
+
+    // Heading, borders, blank, and footer
+    let visible = height.saturating_sub(5) as usize;
+    let skip = app.contact_selection.saturating_sub(visible.saturating_sub(1));
+    let mut lines = vec![Line::from(Span::styled(truncate(&heading, width), styles::highlight_style()))];
+    lines.extend(body.into_iter().skip(skip).take(visible));
+    while lines.len() < visible + 1 {
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled(" [j/k]", styles::help_key_style()),
+        Span::styled(" move  ", styles::muted_style()),
+        Span::styled("[Enter]", styles::help_key_style()),
+        Span::styled(" go to person  ", styles::muted_style()),
+        Span::styled("[Esc]", styles::help_key_style()),
+        Span::styled(" close", styles::muted_style()),
+    ]));
+
+    let paragraph = Paragraph::new(lines).block(overlay_block().title(" Contact Check "));
+    frame.render_widget(paragraph, area);
+}
+
+fn render_oa_overlay(frame: &mut Frame, app: &App) {
+    let height = frame.area().height.saturating_sub(4).clamp(12, 30);
+    let area = centered_rect_fixed(FEED_OVERLAY_WIDTH, height, frame.area());
+    frame.render_widget(Clear, area);
+    let width = FEED_OVERLAY_WIDTH.saturating_sub(6) as usize;
+
+    let rows = app.oa_rows();
+    let mut body: Vec<Line> = Vec::new();
+    for (i, standing) in rows.iter().enumerate() {
+        let style = if i == app.oa_selection { styles::selected_style() } else { styles::list_item_style() };
+        let name_width = width.saturating_sub(44);
+        let nights = standing.nights.map(|n| format!("{:>3} nights", n)).unwrap_or_else(|| "  no log".to_string());
+        let (status, status_style) = if standing.is_eligible() {
+            ("Eligible".to_string(), styles::success_style())
+        } else if standing.nights.is_none() {
+            ("Activity log not fetched".to_string(), styles::muted_style())
+        } else if !standing.rank_met() && standing.nights_short() > 0 {
+            (format!("Needs {} and {} more nights", OA_MIN_RANK.display_name(), standing.nights_short()), styles::error_style())
+        } else if !standing.rank_met() {
+            (format!("Needs {}", OA_MIN_RANK.display_name()), styles::error_style())
+        } else {
+            (format!("{} more nights", standing.nights_short()), styles::error_style())
+        };
+        body.push(Line::from(vec![
+            Span::styled(format!(" {:<w$}", truncate(&standing.youth.display_name(), name_width.saturating_sub(1)), w = name_width), style),
+            Span::styled(format!("{:<8}", standing.youth.rank_short()), styles::muted_style()),
+            Span::styled(format!("{}  ", nights), styles::list_item_style()),
+            Span::styled(truncate(&status, 30), status_style),
+        ]));
+    }
+    if rows.is_empty() {
+        body.push(Line::from(Span::styled(" No scouts on the roster", styles::muted_style())));
+    }
+    let eligible = rows.iter().filter(|s| s.is_eligible()).count();
+    let heading = format!(
+        " {} of {} eligible - {} and {} nights of camping",
+        eligible,
+        rows.len(),
+        OA_MIN_RANK.display_name(),
+        OA_CAMPING_NIGHTS
+    );
+
+    // Heading, borders, blank, and footer
+    let visible = height.saturating_sub(5) as usize;
+    let skip = app.oa_selection.saturating_sub(visible.saturating_sub(1));
+    let mut lines = vec![Line::from(Span::styled(truncate(&heading, width), styles::highlight_style()))];
+    lines.extend(body.into_iter().skip(skip).take(visible));
+    while lines.len() < visible + 1 {
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled(" [j/k]", styles::help_key_style()),
+        Span::styled(" move  ", styles::muted_style()),
+        Span::styled("[Enter]", styles::help_key_style()),
+        Span::styled(" go to scout  ", styles::muted_style()),
+        Span::styled("[Esc]", styles::help_key_style()),
+        Span::styled(" close", styles::muted_style()),
+    ]));
+
+    let paragraph = Paragraph::new(lines).block(overlay_block().title(" Order of the Arrow Eligibility "));
+    frame.render_widget(paragraph, area);
+}
+
+fn render_committee_overlay(frame: &mut Frame, app: &App) {
+    let height = frame.area().height.saturating_sub(4).clamp(14, 30);
+    let area = centered_rect_fixed(FEED_OVERLAY_WIDTH, height, frame.area());
+    frame.render_widget(Clear, area);
+    const NAME_WIDTH: usize = 26;
+    const CELL_WIDTH: usize = 5;
+
+    let adults = &app.store.adults;
+    let unfilled = app.committee.roles.unfilled(adults);
+    let mut header = vec![Span::styled(format!(" {:<w$}", "", w = NAME_WIDTH), styles::muted_style())];
+    for (i, role) in CommitteeRole::ALL.iter().enumerate() {
+        let style = if i == app.committee.column {
+            styles::selected_style()
+        } else if unfilled.contains(role) {
+            styles::error_style()
+        } else {
+            styles::highlight_style()
+        };
+        header.push(Span::styled(format!("{:^w$}", role.abbreviation(), w = CELL_WIDTH), style));
+    }
+
+    let rows = app.committee_rows();
+    let mut body: Vec<Line> = Vec::new();
+    for (r, adult) in rows.iter().enumerate() {
+        let name_style = if r == app.committee.row { styles::highlight_style() } else { styles::list_item_style() };
+        let mut spans = vec![Span::styled(
+            format!(" {:<w$}", truncate(&adult.display_name(), NAME_WIDTH - 1), w = NAME_WIDTH),
+            name_style,
+        )];
+        for (c, &role) in CommitteeRole::ALL.iter().enumerate() {
+            let mark = if app.committee.roles.is_assigned(role, adult) { "✓" } else { "·" };
+            let style = if r == app.committee.row && c == app.committee.column {
+                styles::selected_style()
+            } else if mark == "✓" {
+                styles::success_style()
+            } else {
+                styles::muted_style()
+            };
+            spans.push(Span::styled(format!("{:^w$}", mark, w = CELL_WIDTH), style));
+        }
+        body.push(Line::from(spans));
+    }
+    if rows.is_empty() {
+        body.push(Line::from(Span::styled(" No committee members - press a to list every adult", styles::muted_style())));
+    }
+
+    let role = CommitteeRole::ALL[app.committee.column.min(CommitteeRole::ALL.len() - 1)];
+    let covered: Vec<String> = app.committee.roles.assigned(role, adults).iter().map(|a| a.full_name()).collect();
+    let role_line = if covered.is_empty() {
+        Line::from(vec![
+            Span::styled(format!(" {}: ", role.name()), styles::muted_style()),
+            Span::styled("unfilled", styles::error_style()),
+        ])
+    } else {
+        Line::from(vec![
+            Span::styled(format!(" {}: ", role.name()), styles::muted_style()),
+            Span::raw(truncate(&covered.join(", "), FEED_OVERLAY_WIDTH as usize - 8 - role.name().len())),
+        ])
+    };
+    let unfilled_line = if unfilled.is_empty() {
+        Line::from(Span::styled(" Every committee function is covered", styles::success_style()))
+    } else {
+        let names: Vec<&str> = unfilled.iter().map(|r| r.name()).collect();
+        Line::from(vec![
+            Span::styled(" Unfilled: ", styles::muted_style()),
+            Span::styled(truncate(&names.join(", "), FEED_OVERLAY_WIDTH as usize - 14), styles::error_style()),
+        ])
+    };
+
+    // Header, borders, role line, unfilled line, blank, and footer
+    let visible = height.saturating_sub(7) as usize;
+    let skip = app.committee.row.saturating_sub(visible.saturating_sub(1));
+    let mut lines = vec![Line::from(header)];
+    lines.extend(body.into_iter().skip(skip).take(visible));
+    while lines.len() < visible + 1 {
+        lines.push(Line::from(""));
+    }
+    lines.push(role_line);
+    lines.push(unfilled_line);
+    lines.push(Line::from(""));
+    let all = if app.committee.all_adults { " committee only  " } else { " all adults  " };
+    lines.push(Line::from(vec![
+        Span::styled(" [h/j/k/l]", styles::help_key_style()),
+        Span::styled(" move  ", styles::muted_style()),
+        Span::styled("[Space]", styles::help_key_style()),
+        Span::styled(" assign/unassign  ", styles::muted_style()),
+        Span::styled("[a]", styles::help_key_style()),
+        Span::styled(all, styles::muted_style()),
+        Span::styled("[Esc]", styles::help_key_style()),
+        Span::styled(" close", styles::muted_style()),
+    ]));
+
+    let paragraph = Paragraph::new(lines).block(overlay_block().title(" Committee Roles "));
+    frame.render_widget(paragraph, area);
+}
+
+fn render_district_overlay(frame: &mut Frame, app: &App) {
+    let height = frame.area().height.saturating_sub(4).clamp(12, 36);
+    let area = centered_rect_fixed(FEED_OVERLAY_WIDTH, height, frame.area());
+    frame.render_widget(Clear, area);
+    let width = FEED_OVERLAY_WIDTH.saturating_sub(6) as usize;
+    let today = Local::now().date_naive();
+
+    let body = match app.selected_district_unit() {
+        Some(unit) if app.district.detail => district_unit_lines(unit, today, width),
+        _ => district_list_lines(app, today, width),
+    };
+
+    // Borders, blank, and footer
+    let visible = height.saturating_sub(4) as usize;
+    let skip = if app.district.detail { 0 } else { (app.district.selection + 1).saturating_sub(visible.saturating_sub(1)) };
+    let mut lines: Vec<Line> = body.into_iter().skip(skip).take(visible).collect();
+    while lines.len() < visible {
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::from(""));
+    let enter = if app.district.detail { " back  " } else { " details  " };
+    lines.push(Line::from(vec![
+        Span::styled(" [Up/Down]", styles::help_key_style()),
+        Span::styled(" move  ", styles::muted_style()),
+        Span::styled("[Enter]", styles::help_key_style()),
+        Span::styled(enter, styles::muted_style()),
+        Span::styled("[u]", styles::help_key_style()),
+        Span::styled(" refresh  ", styles::muted_style()),
+        Span::styled("[r]", styles::help_key_style()),
+        Span::styled(" at roundtable today  ", styles::muted_style()),
+        Span::styled("[Esc]", styles::help_key_style()),
+        Span::styled(" close", styles::muted_style()),
+    ]));
+
+    let paragraph = Paragraph::new(lines).block(overlay_block().title(" District "));
+    frame.render_widget(paragraph, area);
+}
+
+fn render_linked_overlay(frame: &mut Frame, app: &App) {
+    let height = frame.area().height.saturating_sub(4).clamp(12, 36);
+    let area = centered_rect_fixed(FEED_OVERLAY_WIDTH, height, frame.area());
+    frame.render_widget(Clear, area);
+    let width = FEED_OVERLAY_WIDTH.saturating_sub(6) as usize;
+    let (home, linked) = (app.home_troop_name(), app.linked_troop_name());
+
+    let all_lines = app.linked_lines();
+    let (heading, body) = if app.linked.side_by_side {
+        let column = width.saturating_sub(1) / 2;
+        let cell = |line: Option<&&LinkedLine>| -> String {
+            let text = line.map(|l| format!("{}  {}", l.name, l.detail)).unwrap_or_default();
+            format!(" {:<w$}", truncate(&text, column.saturating_sub(2)), w = column.saturating_sub(1))
+        };
+        let left: Vec<&LinkedLine> = all_lines.iter().filter(|l| l.troops.home).collect();
+        let right: Vec<&LinkedLine> = all_lines.iter().filter(|l| l.troops.linked).collect();
+        let heading = format!(
+            " {:<w$} {}",
+            truncate(&format!("{} ({})", home, left.len()), column.saturating_sub(2)),
+            truncate(&format!("{} ({})", linked, right.len()), column.saturating_sub(2)),
+            w = column.saturating_sub(1)
+        );
+        let body: Vec<Line> = (0..left.len().max(right.len()))
+            .map(|i| {
+                let style = |l: Option<&&LinkedLine>| {
+                    if l.is_some_and(|l| l.troops == Troops::BOTH) { styles::highlight_style() } else { styles::list_item_style() }
+                };
+                Line::from(vec![
+                    Span::styled(cell(left.get(i)), style(left.get(i))),
+                    Span::styled(cell(right.get(i)), style(right.get(i))),
+                ])
+            })
+            .collect();
+        (heading, body)
+    } else {
+        let tag_width = 14;
+        let detail_width = 22;
+        let name_width = width.saturating_sub(tag_width + detail_width + 1);
+        let shown: Vec<&LinkedLine> = all_lines.iter().filter(|l| l.troops.shown_by(app.linked.filter)).collect();
+        let filter = match app.linked.filter {
+            TroopFilter::Both => "both troops".to_string(),
+            TroopFilter::Home => home.clone(),
+            TroopFilter::Linked => linked.clone(),
+        };
+        let body: Vec<Line> = shown
+            .iter()
+            .map(|l| {
+                let (tag, tag_style) = match (l.troops.home, l.troops.linked) {
+                    (true, true) => ("Both".to_string(), styles::highlight_style()),
+                    (true, false) => (home.clone(), styles::muted_style()),
+                    _ => (linked.clone(), styles::success_style()),
+                };
+                Line::from(vec![
+                    Span::styled(format!(" {:<w$}", truncate(&l.name, name_width.saturating_sub(2)), w = name_width), styles::list_item_style()),
+                    Span::styled(format!("{:<w$}", truncate(&l.detail, detail_width.saturating_sub(1)), w = detail_width), styles::muted_style()),
+                    Span::styled(truncate(&tag, tag_width), tag_style),
+                ])
+            })
+            .collect();
+        (format!(" {} {} - {}", shown.len(), app.linked.list.label().to_lowercase(), filter), body)
+    };
+
+    // Heading, borders, blank, and footer
+    let visible = height.saturating_sub(5) as usize;
+    let skip = app.linked.scroll.min(body.len().saturating_sub(visible));
+    let mut lines = vec![Line::from(Span::styled(truncate(&heading, width), styles::highlight_style()))];
+    if body.is_empty() {
+        lines.push(Line::from(Span::styled(" Nothing to show - press u to fetch the linked troop", styles::muted_style())));
+    }
+    lines.extend(body.into_iter().skip(skip).take(visible));
+    while lines.len() < visible + 1 {
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::from(""));
+    let layout = if app.linked.side_by_side { " combined  " } else { " side by side  " };
+    let mut footer = vec![
+        Span::styled(" [Tab]", styles::help_key_style()),
+        Span::styled(" list  ", styles::muted_style()),
+        Span::styled("[s]", styles::help_key_style()),
+        Span::styled(layout, styles::muted_style()),
+    ];
+    if !app.linked.side_by_side {
+        footer.push(Span::styled("[f]", styles::help_key_style()));
+        footer.push(Span::styled(" troop  ", styles::muted_style()));
+    }
+    footer.extend([
+        Span::styled("[u]", styles::help_key_style()),
+        Span::styled(" refresh  ", styles::muted_style()),
+        Span::styled("[Esc]", styles::help_key_style()),
+        Span::styled(" close", styles::muted_style()),
+    ]);
+    lines.push(Line::from(footer));
+
+    let title = format!(" Linked Troops: {} ", app.linked.list.label());
+    let paragraph = Paragraph::new(lines).block(overlay_block().title(title));
+    frame.render_widget(paragraph, area);
+}
+
+fn render_date_correction_overlay(frame: &mut Frame, app: &App) {
+    let height = frame.area().height.saturating_sub(4).clamp(12, 36);
+    let area = centered_rect_fixed(FEED_OVERLAY_WIDTH, height, frame.area());
+    frame.render_widget(Clear, area);
+    let width = FEED_OVERLAY_WIDTH.saturating_sub(6) as usize;
+    let day = |d: NaiveDate| d.format("%b %-d, %Y").to_string();
+
+    // The completions found, or the log of past corrections, newest first
+    let mut body: Vec<Line> = Vec::new();
+    let mut selected_line = 0;
+    let heading = match &app.date_correction.correction {
+        Some(correction) => {
+            for (i, item) in correction.items.iter().enumerate() {
+                let style = if i == app.date_correction.selection {
+                    selected_line = i;
+                    styles::selected_style()
+                } else {
+                    styles::list_item_style()
+                };
+                let mark = if item.selected { "[x]" } else { "[ ]" };
+                body.push(Line::from(vec![
+                    Span::styled(format!(" {} {:<22}", mark, truncate(&item.scout, 21)), style),
+                    Span::styled(format!("{:<14}", truncate(&item.rank, 13)), styles::muted_style()),
+                    Span::styled(truncate(&item.requirement, width.saturating_sub(42)), styles::list_item_style()),
+                ]));
+            }
+            let to = correction.to.map(day).unwrap_or_else(|| "?".to_string());
+            format!(
+                " {} -> {}: {} of {} completions, {} scouts",
+                day(correction.from),
+                to,
+                correction.selected().count(),
+                correction.items.len(),
+                correction.selected_scouts()
+            )
+        }
+        None => {
+            for record in app.date_correction.log.records.iter().rev() {
+                let (status, status_style) = match &record.error {
+                    None => ("saved".to_string(), styles::success_style()),
+                    Some(e) => (format!("refused: {}", e), styles::error_style()),
+                };
+                body.push(Line::from(vec![
+                    Span::styled(format!(" {:<8}", record.corrected_at.format("%b %-d")), styles::muted_style()),
+                    Span::styled(format!("{:<20}", truncate(&record.scout, 19)), styles::list_item_style()),
+                    Span::styled(
+                        format!("{:<22}", truncate(&format!("{} {}", record.rank, record.requirement), 21)),
+                        styles::list_item_style(),
+                    ),
+                    Span::styled(format!("{} -> {}  ", record.from.format("%-m/%-d/%y"), record.to.format("%-m/%-d/%y")), styles::muted_style()),
+                    Span::styled(truncate(&status, 20), status_style),
+                ]));
+            }
+            if body.is_empty() {
+                body.push(Line::from(Span::styled(" No corrections made yet", styles::muted_style())));
+            }
+            if app.date_correction.pending > 0 {
+                format!(" Correcting {} completions...", app.date_correction.pending)
+            } else {
+                " Correction log".to_string()
+            }
+        }
+    };
+
+    // Heading, borders, blank, input/hint line, and two footer lines
+    let visible = height.saturating_sub(7) as usize;
+    let skip = selected_line.saturating_sub(visible.saturating_sub(2));
+    let mut lines = vec![Line::from(Span::styled(truncate(&heading, width), styles::highlight_style()))];
+    lines.extend(body.into_iter().skip(skip).take(visible));
+    while lines.len() < visible + 1 {
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::from(""));
+    lines.push(match (&app.date_correction.input, &app.date_correction.correction) {
+        (Some(text), correction) => {
+            let label = if correction.is_some() { " Corrected date (YYYY-MM-DD): " } else { " Wrong completion date (YYYY-MM-DD): " };
+            Line::from(vec![
+                Span::styled(label, styles::highlight_style()),
+                Span::styled(format!("{}_", text), styles::list_item_style()),
+            ])
+        }
+        (None, Some(_)) => Line::from(Span::styled(
+            " Selected completions are recorded again in Scoutbook with the corrected date",
+            styles::muted_style(),
+        )),
+        (None, None) => Line::from(Span::styled(" Only requirements in the cache are searched", styles::muted_style())),
+    });
+    lines.push(if app.date_correction.correction.is_some() {
+        Line::from(vec![
+            Span::styled(" [Space]", styles::help_key_style()),
+            Span::styled(" select  ", styles::muted_style()),
+            Span::styled("[a]", styles::help_key_style()),
+            Span::styled(" all  ", styles::muted_style()),
+            Span::styled("[t]", styles::help_key_style()),
+            Span::styled(" corrected date  ", styles::muted_style()),
+            Span::styled("[y]", styles::help_key_style()),
+            Span::styled(" apply", styles::muted_style()),
+        ])
+    } else {
+        Line::from("")
+    });
+    let footer = vec![
+        Span::styled(" [d]", styles::help_key_style()),
+        Span::styled(" wrong date  ", styles::muted_style()),
+        Span::styled("[j/k]", styles::help_key_style()),
+        Span::styled(" move  ", styles::muted_style()),
+        Span::styled("[Esc]", styles::help_key_style()),
+        Span::styled(" close", styles::muted_style()),
+    ];
+    lines.push(Line::from(footer));
+
+    let paragraph = Paragraph::new(lines).block(overlay_block().title(" Correct Completion Dates "));
+    frame.render_widget(paragraph, area);
+}
+
+/// One line per unit: charter, training, roundtable, and how many concerns.
+fn district_list_lines(app: &App, today: NaiveDate, width: usize) -> Vec<Line<'static>> {
+    let name_width = width.saturating_sub(44);
+    let mut lines = vec![Line::from(Span::styled(
+        format!(" {:<w$}{:<12}{:<9}{:<11}{}", "Unit", "Charter", "Trained", "Roundtable", "Concerns", w = name_width),
+        styles::highlight_style(),
+    ))];
+    for (i, unit) in app.district.units.iter().enumerate() {
+        let style = if i == app.district.selection { styles::selected_style() } else { styles::list_item_style() };
+        let charter = match unit.charter_days_left(today) {
+            Some(days) if days < 0 => ("expired".to_string(), styles::error_style()),
+            Some(days) if days <= CHARTER_WARNING_DAYS => (format!("{} days", days), styles::error_style()),
+            Some(_) => (unit.charter_expires.map(|d| d.format("%b %Y").to_string()).unwrap_or_default(), style),
+            None => ("-".to_string(), styles::muted_style()),
+        };
+        let trained = unit.trained_percent().map(|p| format!("{}%", p)).unwrap_or_else(|| "-".to_string());
+        let roundtable = unit.last_roundtable.map(|d| d.format("%b %-d").to_string()).unwrap_or_else(|| "never".to_string());
+        let concerns = unit.concerns(today);
+        let concern_style = if concerns.is_empty() { styles::success_style() } else { styles::error_style() };
+        lines.push(Line::from(vec![
+            Span::styled(format!(" {:<w$}", truncate(&unit.name, name_width.saturating_sub(1)), w = name_width), style),
+            Span::styled(format!("{:<12}", charter.0), charter.1),
+            Span::styled(format!("{:<9}", trained), style),
+            Span::styled(format!("{:<11}", roundtable), style),
+            Span::styled(concerns.first().cloned().unwrap_or_else(|| "None".to_string()), concern_style),
+        ]));
+    }
+    lines
+}
+
+/// Drill-down for one unit: Key 3 contacts, charter, training, concerns.
+fn district_unit_lines(unit: &UnitHealth, today: NaiveDate, width: usize) -> Vec<Line<'static>> {
+    let heading = |text: &str| Line::from(Span::styled(format!(" {}", text), styles::highlight_style()));
+    let item = |text: String| Line::from(Span::styled(format!("   {}", truncate(&text, width.saturating_sub(3))), styles::list_item_style()));
+
+    let mut lines = vec![Line::from(Span::styled(format!(" {}", unit.name), styles::title_style()))];
+    if let Some(ref org) = unit.charter_org {
+        lines.push(item(format!("Chartered to {}", org)));
+    }
+    let charter = match (unit.charter_status.as_deref(), unit.charter_expires) {
+        (Some(status), Some(date)) => format!("Charter {}, expires {}", status, date.format("%b %-d, %Y")),
+        (None, Some(date)) => format!("Charter expires {}", date.format("%b %-d, %Y")),
+        (Some(status), None) => format!("Charter {}", status),
+        (None, None) => "Charter status unknown".to_string(),
+    };
+    lines.push(item(charter));
+    if let Some(fetched) = unit.fetched_at {
+        lines.push(Line::from(Span::styled(
+            format!("   Fetched {}", fetched.with_timezone(&Local).format("%b %-d %-I:%M %p")),
+            styles::muted_style(),
+        )));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(heading("Key 3"));
+    for k in &unit.key3 {
+        match k.name {
+            Some(ref name) => {
+                let contact: Vec<&str> = [k.email.as_deref(), k.phone.as_deref()].into_iter().flatten().collect();
+                lines.push(item(format!("{:<18} {}  {}", k.role, name, contact.join("  "))));
+            }
+            None => lines.push(Line::from(Span::styled(format!("   {:<18} vacant", k.role), styles::error_style()))),
+        }
+    }
+
+    lines.push(Line::from(""));
+    lines.push(heading(&format!("Training ({} adults)", unit.adults)));
+    let t = &unit.training;
+    lines.push(item(format!(
+        "Position trained {} of {}",
+        t.position_trained,
+        t.position_trained + t.position_not_trained
+    )));
+    lines.push(item(format!("YPT current {}, expiring {}, expired {}", t.ypt_current, t.ypt_expiring, t.ypt_expired)));
+    for name in &t.position_not_trained_list {
+        lines.push(Line::from(Span::styled(format!("     {} - not trained", name), styles::muted_style())));
+    }
+    for (name, status) in &t.ypt_issues {
+        lines.push(Line::from(Span::styled(format!("     {} - YPT {}", name, status.to_lowercase()), styles::muted_style())));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(heading("Roundtable"));
+    lines.push(item(match unit.last_roundtable {
+        Some(date) => format!("Last attended {} ({} days ago)", date.format("%b %-d, %Y"), (today - date).num_days()),
+        None => "No attendance recorded".to_string(),
+    }));
+
+    let concerns = unit.concerns(today);
+    lines.push(Line::from(""));
+    lines.push(heading("Follow up"));
+    if concerns.is_empty() {
+        lines.push(Line::from(Span::styled("   Nothing to follow up on", styles::success_style())));
+    }
+    lines.extend(concerns.into_iter().map(|c| Line::from(Span::styled(format!("   {}", c), styles::error_style()))));
+    lines
+}
+
+fn render_minutes_overlay(frame: &mut Frame, app: &App) {
+    let Some(minutes) = app.minutes.meetings.meetings.get(app.minutes.selection) else {
+        return;
+    };
+    let height = frame.area().height.saturating_sub(4).clamp(14, 40);
+    let area = centered_rect_fixed(FEED_OVERLAY_WIDTH, height, frame.area());
+    frame.render_widget(Clear, area);
+    let width = FEED_OVERLAY_WIDTH.saturating_sub(6) as usize;
+    let today = Local::now().date_naive();
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled(format!(" {}", minutes.event_name), styles::title_style()),
+            Span::styled(format!("  {}", minutes.date.format("%b %-d, %Y")), styles::muted_style()),
+        ]),
+        Line::from(Span::styled(format!(" Attendees ({})", minutes.attendees.len()), styles::highlight_style())),
+    ];
+    let attendees = if minutes.attendees.is_empty() {
+        "(none - [r] to fill from RSVPs)".to_string()
+    } else {
+        minutes.attendees.join(", ")
+    };
+    for line in wrap_text(&attendees, width) {
+        lines.push(Line::from(Span::styled(format!("   {}", line), styles::list_item_style())));
+    }
+
+    lines.push(Line::from(Span::styled(" Notes", styles::highlight_style())));
+    if minutes.notes.is_empty() {
+        lines.push(Line::from(Span::styled("   (none - [n] to add)", styles::muted_style())));
+    }
+    for note in &minutes.notes {
+        for (i, line) in wrap_text(note, width - 2).into_iter().enumerate() {
+            let bullet = if i == 0 { "-" } else { " " };
+            lines.push(Line::from(Span::styled(format!("   {} {}", bullet, line), styles::list_item_style())));
+        }
+    }
+
+    lines.push(Line::from(Span::styled(" Action Items", styles::highlight_style())));
+    if minutes.action_items.is_empty() {
+        lines.push(Line::from(Span::styled("   (none - [a] to add)", styles::muted_style())));
+    }
+    for (i, item) in minutes.action_items.iter().enumerate() {
+        let check = if item.is_open() { "[ ]" } else { "[x]" };
+        let style = if i == app.minutes.item_selection {
+            styles::selected_style()
+        } else if !item.is_open() {
+            styles::muted_style()
+        } else {
+            styles::list_item_style()
+        };
+        let mut detail = Vec::new();
+        detail.extend(item.owner.clone());
+        detail.extend(item.due.map(|d| format!("due {}", d.format("%b %-d"))));
+        let due_style = if item.is_overdue(today) { styles::error_style() } else { styles::muted_style() };
+        lines.push(Line::from(vec![
+            Span::styled(format!("   {} {}", check, truncate(&item.text, 36)), style),
+            Span::styled(format!("  {}", detail.join(", ")), due_style),
+        ]));
+    }
+
+    // Borders, blank, input/hint line, and two footer lines
+    let visible = height.saturating_sub(6) as usize;
+    lines.truncate(visible);
+    while lines.len() < visible {
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::from(""));
+    lines.push(match &app.minutes.input {
+        Some((kind, text)) => {
+            let label = match kind {
+                MinutesInput::Note => " Note: ",
+                MinutesInput::ActionItem => " Action (text @owner YYYY-MM-DD): ",
+            };
+            let skip = text.chars().count().saturating_sub(width.saturating_sub(label.len()));
+            let shown: String = text.chars().skip(skip).collect();
+            Line::from(vec![
+                Span::styled(label, styles::highlight_style()),
+                Span::styled(format!("{}_", shown), styles::list_item_style()),
+            ])
+        }
+        None => Line::from(Span::styled(" Open action items show on the Unit tab", styles::muted_style())),
+    });
+    lines.push(Line::from(vec![
+        Span::styled(" [n/x]", styles::help_key_style()),
+        Span::styled(" add/remove note  ", styles::muted_style()),
+        Span::styled("[a]", styles::help_key_style()),
+        Span::styled(" action item  ", styles::muted_style()),
+        Span::styled("[Space]", styles::help_key_style()),
+        Span::styled(" close/reopen  ", styles::muted_style()),
+        Span::styled("[D]", styles::help_key_style()),
+        Span::styled(" delete", styles::muted_style()),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled(" [j/k]", styles::help_key_style()),
+        Span::styled(" move  ", styles::muted_style()),
+        Span::styled("[r]", styles::help_key_style()),
+        Span::styled(" attendees from RSVPs  ", styles::muted_style()),
+        Span::styled("[Esc]", styles::help_key_style()),
+        Span::styled(" close", styles::muted_style()),
+    ]));
+
+    let paragraph = Paragraph::new(lines).block(overlay_block().title(" Committee Minutes "));
+    frame.render_widget(paragraph, area);
+}
+
+fn render_history_overlay(frame: &mut Frame, app: &App) {
+    let height = frame.area().height.saturating_sub(4).clamp(14, 40);
+    let area = centered_rect_fixed(FEED_OVERLAY_WIDTH, height, frame.area());
+    frame.render_widget(Clear, area);
+    let width = FEED_OVERLAY_WIDTH.saturating_sub(6) as usize;
+
+    // Entries take the top third; the selected entry's write-up fills the rest
+    let list_rows = (height / 3) as usize;
+    let entries = &app.history.log.entries;
+    let skip = app.history.selection.saturating_sub(list_rows.saturating_sub(1));
+    let mut lines: Vec<Line> = entries
+        .iter()
+        .enumerate()
+        .skip(skip)
+        .take(list_rows)
+        .map(|(i, e)| {
+            let style = if i == app.history.selection { styles::selected_style() } else { styles::list_item_style() };
+            let marker = if e.summary.is_empty() { " " } else { "✓" };
+            Line::from(vec![
+                Span::styled(format!(" {}  ", e.date.format("%Y-%m-%d")), styles::muted_style()),
+                Span::styled(format!("{} {}", marker, truncate(&e.event_name, 50)), style),
+            ])
+        })
+        .collect();
+    while lines.len() < list_rows {
+        lines.push(Line::from(""));
+    }
+
+    if let Some(entry) = entries.get(app.history.selection) {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(format!(" {}", entry.event_name), styles::highlight_style())));
+        if entry.summary.is_empty() {
+            lines.push(Line::from(Span::styled("   (no summary - [s] to write one)", styles::muted_style())));
+        }
+        for line in wrap_text(&entry.summary, width) {
+            lines.push(Line::from(Span::styled(format!("   {}", line), styles::list_item_style())));
+        }
+        for highlight in &entry.highlights {
+            lines.push(Line::from(Span::styled(format!("   * {}", truncate(highlight, width - 2)), styles::list_item_style())));
+        }
+        let folder = entry.photo_folder.as_deref().unwrap_or("-");
+        lines.push(Line::from(vec![
+            Span::styled("   Photos: ", styles::muted_style()),
+            Span::styled(truncate(folder, width - 8), styles::list_item_style()),
+        ]));
+    }
+
+    // Borders, blank, input/hint line, and two footer lines
+    let visible = height.saturating_sub(6) as usize;
+    lines.truncate(visible);
+    while lines.len() < visible {
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::from(""));
+    lines.push(match &app.history.input {
+        Some((field, text)) => {
+            let label = match field {
+                HistoryField::Summary => " Summary: ",
+                HistoryField::Highlight => " Add highlight: ",
+                HistoryField::PhotoFolder => " Photo folder: ",
+            };
+            // Keep the end of long text in view while typing
+            let skip = text.chars().count().saturating_sub(width.saturating_sub(label.len()));
+            let shown: String = text.chars().skip(skip).collect();
+            Line::from(vec![
+                Span::styled(label, styles::highlight_style()),
+                Span::styled(format!("{}_", shown), styles::list_item_style()),
+            ])
+        }
+        None => Line::from(Span::styled(format!(" {} events logged", entries.len()), styles::muted_style())),
+    });
+    lines.push(Line::from(vec![
+        Span::styled(" [s]", styles::help_key_style()),
+        Span::styled(" summary  ", styles::muted_style()),
+        Span::styled("[h/x]", styles::help_key_style()),
+        Span::styled(" add/remove highlight  ", styles::muted_style()),
+        Span::styled("[f]", styles::help_key_style()),
+        Span::styled(" photo folder  ", styles::muted_style()),
+        Span::styled("[D]", styles::help_key_style()),
+        Span::styled(" delete", styles::muted_style()),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled(" [j/k]", styles::help_key_style()),
+        Span::styled(" move  ", styles::muted_style()),
+        Span::styled("[e]", styles::help_key_style()),
+        Span::styled(" save year's history  ", styles::muted_style()),
+        Span::styled("[Esc]", styles::help_key_style()),
+        Span::styled(" close", styles::muted_style()),
+    ]));
+
+    let paragraph = Paragraph::new(lines).block(overlay_block().title(" Historian Log "));
+    frame.render_widget(paragraph, area);
+}
+
+fn render_counselors_overlay(frame: &mut Frame, app: &App) {
+    let Some(group) = app.counselors.groups.get(app.counselors.selection) else {
+        return;
+    };
+    let height = frame.area().height.saturating_sub(4).clamp(10, 36);
+    let area = centered_rect_fixed(FEED_OVERLAY_WIDTH, height, frame.area());
+    frame.render_widget(Clear, area);
+
+    let contact: Vec<&str> = [group.phone.as_deref(), group.email.as_deref()].into_iter().flatten().collect();
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled(format!(" {}", group.name), styles::title_style()),
+            Span::styled(
+                format!("  ({} of {})", app.counselors.selection + 1, app.counselors.groups.len()),
+                styles::muted_style(),
+            ),
+        ]),
+        Line::from(Span::styled(format!(" {}", contact.join("  ")), styles::muted_style())),
+        Line::from(vec![
+            Span::styled(format!(" {:<22}{:<22}{:>5}  ", "Merit Badge", "Scout", "Done"), styles::highlight_style()),
+            Span::styled("Remaining", styles::highlight_style()),
+        ]),
+    ];
+
+    // Borders, three header rows, blank, and footer
+    let visible = height.saturating_sub(7) as usize;
+    for scout in group.scouts.iter().skip(app.counselors.scroll).take(visible) {
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!(
+                    " {:<22}{:<22}{:>5}  ",
+                    truncate(&scout.badge, 21),
+                    truncate(&scout.scout, 21),
+                    percent_label(scout.percent)
+                ),
+                styles::list_item_style(),
+            ),
+            Span::styled(truncate(&remaining_label(&scout.remaining), 16), styles::muted_style()),
+        ]));
+    }
+    while lines.len() < visible + 3 {
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled(" [h/l]", styles::help_key_style()),
+        Span::styled(" counselor  ", styles::muted_style()),
+        Span::styled("[j/k]", styles::help_key_style()),
+        Span::styled(" scroll  ", styles::muted_style()),
+        Span::styled("[e/a]", styles::help_key_style()),
+        Span::styled(" save this/all  ", styles::muted_style()),
+        Span::styled("[Esc]", styles::help_key_style()),
+        Span::styled(" close", styles::muted_style()),
+    ]));
+
+    let paragraph = Paragraph::new(lines).block(overlay_block().title(" Counselors "));
+    frame.render_widget(paragraph, area);
+}
+
+fn render_timers_overlay(frame: &mut Frame, app: &App) {
+    let now = std::time::Instant::now();
+    let agenda = &app.agenda;
+    // Countdown block, agenda header, items, footer, blank lines, borders
+    let height = (agenda.items.len() + 14) as u16;
+    let area = centered_rect_fixed(FEED_OVERLAY_WIDTH, height, frame.area());
+    frame.render_widget(Clear, area);
+
+    let countdown = &app.countdown;
+    let remaining = countdown.remaining(now).as_secs() as i64;
+    let (state, clock_style) = if countdown.is_finished(now) {
+        ("time's up", styles::error_style())
+    } else if countdown.clock.is_running() {
+        ("running", styles::success_style())
+    } else {
+        ("paused", styles::muted_style())
+    };
+    let presets: Vec<String> = TIMER_PRESETS
+        .iter()
+        .enumerate()
+        .map(|(i, (label, minutes))| format!("[{}] {} {}m", i + 1, label, minutes))
+        .collect();
+
+    let mut lines = vec![
+        Line::from(Span::styled(" Countdown", styles::highlight_style())),
+        Line::from(vec![
+            Span::styled(format!("   {:>6}  ", format_clock(remaining)), clock_style),
+            Span::styled(countdown.label.clone(), styles::list_item_style()),
+            Span::styled(format!("  ({}, {} min)", state, countdown.length.as_secs() / 60), styles::muted_style()),
+        ]),
+        Line::from(Span::styled(format!("   {}", presets.join("  ")), styles::muted_style())),
+        Line::from(""),
+    ];
+
+    let elapsed = agenda.meeting_clock.elapsed(now).as_secs() as i64;
+    let planned = agenda.planned_total().as_secs() as i64;
+    let agenda_state = if agenda.is_running() { "running" } else { "paused" };
+    lines.push(Line::from(vec![
+        Span::styled(" Meeting Agenda", styles::highlight_style()),
+        Span::styled(
+            format!("  {} of {} ({})", format_clock(elapsed), format_clock(planned), agenda_state),
+            styles::muted_style(),
+        ),
+    ]));
+    for (i, item) in agenda.items.iter().enumerate() {
+        let (clock, style) = if i == agenda.current {
+            let left = agenda.item_remaining_secs(now);
+            (format_clock(left), if left < 0 { styles::error_style() } else { styles::selected_style() })
+        } else if i < agenda.current {
+            ("done".to_string(), styles::muted_style())
+        } else {
+            (format!("{} min", item.minutes), styles::list_item_style())
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!("   {:<40}", truncate(&item.title, 40)), style),
+            Span::styled(format!("{:>8}", clock), style),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled(" [Space]", styles::help_key_style()),
+        Span::styled(" start/pause  ", styles::muted_style()),
+        Span::styled("[+/-]", styles::help_key_style()),
+        Span::styled(" minutes  ", styles::muted_style()),
+        Span::styled("[r]", styles::help_key_style()),
+        Span::styled(" reset", styles::muted_style()),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled(" [a]", styles::help_key_style()),
+        Span::styled(" agenda start/pause  ", styles::muted_style()),
+        Span::styled("[n/b]", styles::help_key_style()),
+        Span::styled(" next/back  ", styles::muted_style()),
+        Span::styled("[x]", styles::help_key_style()),
+        Span::styled(" reset  ", styles::muted_style()),
+        Span::styled("[Esc]", styles::help_key_style()),
+        Span::styled(" close", styles::muted_style()),
+    ]));
+
+    let paragraph = Paragraph::new(lines).block(overlay_block().title(" Timers "));
+    frame.render_widget(paragraph, area);
+}
+
+fn render_awards_overlay(frame: &mut Frame, app: &App) {
+    let height = frame.area().height.saturating_sub(4).clamp(10, 36);
+    let area = centered_rect_fixed(FEED_OVERLAY_WIDTH, height, frame.area());
+    frame.render_widget(Clear, area);
+
+    let today = Local::now().date_naive();
+    let catalog = app.award_catalog();
+    let names = |ids: &[i64]| ids.iter().map(|&id| app.youth_name(id)).collect::<Vec<_>>().join(", ");
+    let detail_width = FEED_OVERLAY_WIDTH.saturating_sub(14) as usize;
+
+    let mut body = Vec::new();
+    let mut selected_line = 0;
+    for (i, entry) in catalog.iter().enumerate() {
+        let nominated: Vec<_> = app.nominations.list.for_award(&entry.name).collect();
+        let selected = i == app.nominations.catalog_selection;
+        if selected {
+            selected_line = body.len();
+        }
+        let style = if selected { styles::selected_style() } else { styles::list_item_style() };
+        let mut counts = format!("{:>3} held", entry.holders.len());
+        if !entry.in_progress.is_empty() {
+            counts.push_str(&format!("  {} started", entry.in_progress.len()));
+        }
+        if !nominated.is_empty() {
+            counts.push_str(&format!("  {} nominated", nominated.len()));
+        }
+        body.push(Line::from(vec![
+            Span::styled(format!(" {:<38}", truncate(&entry.name, 38)), style),
+            Span::styled(counts, styles::muted_style()),
+        ]));
+
+        if !selected {
+            continue;
+        }
+        let mut detail = |label: &str, text: String, style| {
+            for (j, line) in wrap_text(&text, detail_width).into_iter().enumerate() {
+                let label = if j == 0 { format!("   {:<10}", label) } else { " ".repeat(13) };
+                body.push(Line::from(vec![
+                    Span::styled(label, styles::muted_style()),
+                    Span::styled(line, style),
+                ]));
+            }
+        };
+        if !entry.holders.is_empty() {
+            detail("Held:", names(&entry.holders), styles::list_item_style());
+        }
+        if !entry.in_progress.is_empty() {
+            detail("Started:", names(&entry.in_progress), styles::list_item_style());
+        }
+        for nomination in nominated {
+            let (reminder, style) = match nomination.remind_on {
+                Some(date) if nomination.is_due(today) => {
+                    (format!(" - follow up (due {})", date.format("%b %-d")), styles::error_style())
+                }
+                Some(date) => (format!(" - remind {}", date.format("%b %-d")), styles::list_item_style()),
+                None => (String::new(), styles::list_item_style()),
+            };
+            let text = format!(
+                "{} (since {}){}",
+                app.youth_name(nomination.user_id),
+                nomination.nominated_on.format("%b %-d"),
+                reminder
+            );
+            detail("Nominated:", text, style);
+        }
+    }
+
+    let scout = app.feed_scout().map(|y| y.short_name()).unwrap_or_else(|| "No scout selected".to_string());
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled(" Nominating: ", styles::muted_style()),
+            Span::styled(scout, styles::highlight_style()),
+        ]),
+        Line::from(""),
+    ];
+
+    // Borders, scout line, blank lines, and footer take six rows
+    let visible = height.saturating_sub(6) as usize;
+    let skip = selected_line.saturating_sub(visible.saturating_sub(4));
+    lines.extend(body.into_iter().skip(skip).take(visible));
+    while lines.len() < visible + 2 {
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::from(vec![
+        Span::styled(" [n]", styles::help_key_style()),
+        Span::styled(" nominate/withdraw selected scout  ", styles::muted_style()),
+        Span::styled("[j/k]", styles::help_key_style()),
+        Span::styled(" move  ", styles::muted_style()),
+        Span::styled("[Esc]", styles::help_key_style()),
+        Span::styled(" close", styles::muted_style()),
+    ]));
+
+    let paragraph = Paragraph::new(lines).block(overlay_block().title(" Awards & Nominations "));
+    frame.render_widget(paragraph, area);
+}
+
+fn render_position_planner_overlay(frame: &mut Frame, app: &App) {
+    let height = frame.area().height.saturating_sub(4).clamp(12, 40);
+    let area = centered_rect_fixed(FEED_OVERLAY_WIDTH, height, frame.area());
+    frame.render_widget(Clear, area);
+
+    let rows = app.meeting_rows();
+    let issues = app.position_plan_issues();
+    let position = app.planner_position_name();
+    let rule = match POSITION_RULES.iter().find(|r| r.position == position) {
+        Some(r) if r.min_leadership_days > 0 => {
+            format!("  {}+, {} days served", r.min_rank.display_name(), r.min_leadership_days)
+        }
+        Some(r) => format!("  {}+", r.min_rank.display_name()),
+        None => "  open to any scout".to_string(),
+    };
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled(" Position: ", styles::muted_style()),
+            Span::styled(format!("◀ {} ▶", position), styles::title_style()),
+            Span::styled(rule, styles::muted_style()),
+        ]),
+        Line::from(Span::styled(
+            format!(" {:<26}{:<12}{:<15}{}", "Scout", "Rank", "Now", "Next Term"),
+            styles::highlight_style(),
+        )),
+    ];
+
+    // Borders, two header rows, blank, detail line, and footer
+    let visible = height.saturating_sub(7) as usize;
+    let skip = app.planner.selection.saturating_sub(visible.saturating_sub(1));
+    for (i, youth) in rows.iter().enumerate().skip(skip).take(visible) {
+        let user_id = youth.user_id.unwrap_or_default();
+        let name_style = if i == app.planner.selection { styles::selected_style() } else { styles::list_item_style() };
+        let rank = ScoutRank::parse(youth.current_rank.as_deref()).display_name();
+        let current = youth.position_display().unwrap_or_else(|| "-".to_string());
+        let planned = app.planner.plan.position_for(user_id);
+        let planned_style = if issues.iter().any(|(id, _)| *id == user_id) {
+            styles::error_style()
+        } else if planned.is_some() {
+            styles::success_style()
+        } else {
+            styles::muted_style()
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!(" {:<26}", truncate(&youth.display_name(), 25)), name_style),
+            Span::styled(format!("{:<12}{:<15}", truncate(rank, 11), truncate(&current, 14)), styles::muted_style()),
+            Span::styled(truncate(planned.unwrap_or("-"), 17), planned_style),
+        ]));
+    }
+    if rows.is_empty() {
+        lines.push(Line::from(Span::styled(" No scouts loaded", styles::muted_style())));
+    }
+    while lines.len() < visible + 2 {
+        lines.push(Line::from(""));
+    }
+
+    let selected = rows.get(app.planner.selection).and_then(|y| y.user_id);
+    let detail = match issues.iter().find(|(id, _)| Some(*id) == selected) {
+        Some((_, issue)) => Span::styled(format!(" {}", issue), styles::error_style()),
+        None if issues.is_empty() => Span::styled(
+            format!(" {} positions planned, no issues", app.planner.plan.assignments.len()),
+            styles::muted_style(),
+        ),
+        None => Span::styled(format!(" {} issues in the plan", issues.len()), styles::muted_style()),
+    };
+    lines.push(Line::from(""));
+    lines.push(Line::from(detail));
+    lines.push(Line::from(vec![
+        Span::styled(" [h/l]", styles::help_key_style()),
+        Span::styled(" position  ", styles::muted_style()),
+        Span::styled("[Enter]", styles::help_key_style()),
+        Span::styled(" assign  ", styles::muted_style()),
+        Span::styled("[u]", styles::help_key_style()),
+        Span::styled(" unassign  ", styles::muted_style()),
+        Span::styled("[e]", styles::help_key_style()),
+        Span::styled(" save plan  ", styles::muted_style()),
+        Span::styled("[Esc]", styles::help_key_style()),
+        Span::styled(" close", styles::muted_style()),
+    ]));
+
+    let paragraph = Paragraph::new(lines).block(overlay_block().title(" Position Planner "));
+    frame.render_widget(paragraph, area);
+}
+
+fn render_skills_overlay(frame: &mut Frame, app: &App) {
+    let height = frame.area().height.saturating_sub(4).clamp(12, 40);
+    let area = centered_rect_fixed(FEED_OVERLAY_WIDTH, height, frame.area());
+    frame.render_widget(Clear, area);
+
+    let today = Local::now().date_naive();
+    let rows = app.skill_rows();
+    let column = app.skills_certification();
+
+    let mut header = vec![Span::styled(format!(" {:<28}", "Scout"), styles::highlight_style())];
+    for (i, certification) in Certification::ALL.iter().enumerate() {
+        let style = if i == app.skills.column { styles::selected_style() } else { styles::highlight_style() };
+        header.push(Span::styled(format!("{:<14}", certification.short_name()), style));
+    }
+
+    let mut body = Vec::new();
+    for (i, youth) in rows.iter().enumerate() {
+        let user_id = youth.user_id.unwrap_or_default();
+        let name_style = if i == app.skills.selection { styles::selected_style() } else { styles::list_item_style() };
+        let mut spans = vec![Span::styled(format!(" {:<28}", truncate(&youth.display_name(), 27)), name_style)];
+        for certification in Certification::ALL {
+            let (text, style) = match app.skills.matrix.get(user_id, certification) {
+                Some(record) => {
+                    let text = match (record.swim_level, record.earned_on) {
+                        (Some(level), _) => level.label().to_string(),
+                        (None, Some(date)) => date.format("%Y-%m-%d").to_string(),
+                        (None, None) => "✓".to_string(),
+                    };
+                    let style = if !record.is_current(today) {
+                        styles::error_style()
+                    } else if record.qualifies(today) {
+                        styles::success_style()
+                    } else {
+                        styles::muted_style()
+                    };
+                    (text, style)
+                }
+                None => ("-".to_string(), styles::muted_style()),
+            };
+            spans.push(Span::styled(format!("{:<14}", text), style));
+        }
+        body.push(Line::from(spans));
+    }
+    if rows.is_empty() {
+        let message = match app.skills.filter {
+            Some(c) => format!(" No current {} holders", c.name()),
+            None => " No scouts loaded".to_string(),
+        };
+        body.push(Line::from(Span::styled(message, styles::muted_style())));
+    }
+
+    // Borders, filter line, header, blank, detail line, and two footer lines
+    let visible = height.saturating_sub(8) as usize;
+    let skip = app.skills.selection.saturating_sub(visible.saturating_sub(1));
+
+    let filter = match app.skills.filter {
+        Some(c) => format!("Current {} holders ({})", c.name(), rows.len()),
+        None => format!("All scouts ({})", rows.len()),
+    };
+    let mut lines = vec![
+        Line::from(Span::styled(format!(" Showing: {}", filter), styles::muted_style())),
+        Line::from(header),
+    ];
+    lines.extend(body.into_iter().skip(skip).take(visible));
+    while lines.len() < visible + 2 {
+        lines.push(Line::from(""));
+    }
+
+    let record = rows
+        .get(app.skills.selection)
+        .and_then(|y| y.user_id)
+        .and_then(|id| app.skills.matrix.get(id, column));
+    let detail = match (&app.skills.card_input, record) {
+        (Some(input), _) => Line::from(vec![
+            Span::styled(format!(" {} card #: ", column.name()), styles::highlight_style()),
+            Span::styled(format!("{}_", input), styles::list_item_style()),
+        ]),
+        (None, Some(record)) => {
+            let mut text = format!(" {}", column.name());
+            if let Some(date) = record.earned_on {
+                text.push_str(&format!("  earned {}", date.format("%b %-d, %Y")));
+            }
+            if let Some(card) = &record.card_number {
+                text.push_str(&format!("  card #{}", card));
+            }
+            if let Some(expires) = record.expires_on() {
+                let verb = if record.is_current(today) { "expires" } else { "expired" };
+                text.push_str(&format!("  {} {}", verb, expires.format("%b %-d, %Y")));
+            }
+            Line::from(Span::styled(text, styles::muted_style()))
+        }
+        (None, None) => Line::from(""),
+    };
+    lines.push(Line::from(""));
+    lines.push(detail);
+    lines.push(Line::from(vec![
+        Span::styled(" [Space]", styles::help_key_style()),
+        Span::styled(" earned today/clear  ", styles::muted_style()),
+        Span::styled("[s]", styles::help_key_style()),
+        Span::styled(" swim level  ", styles::muted_style()),
+        Span::styled("[c]", styles::help_key_style()),
+        Span::styled(" card #  ", styles::muted_style()),
+        Span::styled("[h/l]", styles::help_key_style()),
+        Span::styled(" column", styles::muted_style()),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled(" [f]", styles::help_key_style()),
+        Span::styled(" filter holders  ", styles::muted_style()),
+        Span::styled("[e/i]", styles::help_key_style()),
+        Span::styled(" export/import CSV  ", styles::muted_style()),
+        Span::styled("[Esc]", styles::help_key_style()),
+        Span::styled(" close", styles::muted_style()),
+    ]));
+
+    let paragraph = Paragraph::new(lines).block(overlay_block().title(" Skill Certifications "));
+    frame.render_widget(paragraph, area);
+}
+
+fn render_adult_awards_overlay(frame: &mut Frame, app: &App) {
+    let Some(adult) = app.store.adults.get(app.adults_selection.index) else {
+        return;
+    };
+    let items = app.adult_award_items();
+    // Rows: award headers and blank separators, items, name line, footer, borders
+    let height = (items.len() + AdultAward::ALL.len() * 2 + 5) as u16;
+    let area = centered_rect_fixed(FEED_OVERLAY_WIDTH, height, frame.area());
+    frame.render_widget(Clear, area);
+
+    let mut lines = vec![Line::from(vec![
+        Span::styled(" ", styles::muted_style()),
+        Span::styled(adult.full_name(), styles::title_style()),
+        Span::styled(format!("  {}", adult.role()), styles::muted_style()),
+    ])];
+
+    for (row, &(award, index)) in items.iter().enumerate() {
+        if index == 0 {
+            let (met, total) = app.adult_awards.checklists.progress(adult, award);
+            let status = if met == total {
+                Span::styled("  Eligible", styles::success_style())
+            } else {
+                Span::styled(format!("  {}/{}", met, total), styles::muted_style())
+            };
+            lines.push(Line::from(""));
+            lines.push(Line::from(vec![Span::styled(format!(" {}", award.name()), styles::highlight_style()), status]));
+        }
+        let requirement = award.requirements()[index];
+        let mark = if app.adult_awards.checklists.is_met(adult, award, index) { "[✓]" } else { "[ ]" };
+        let style = if row == app.adult_awards.selection { styles::selected_style() } else { styles::list_item_style() };
+        let source = if requirement.from_training_record { " (Scoutbook)" } else { "" };
+        lines.push(Line::from(vec![
+            Span::styled(format!("   {} {:<12}", mark, requirement.kind.label()), style),
+            Span::styled(format!("{}{}", requirement.text, source), style),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled(" [Space]", styles::help_key_style()),
+        Span::styled(" check/uncheck  ", styles::muted_style()),
+        Span::styled("[j/k]", styles::help_key_style()),
+        Span::styled(" move  ", styles::muted_style()),
+        Span::styled("[Esc]", styles::help_key_style()),
+        Span::styled(" close", styles::muted_style()),
+    ]));
+
+    let paragraph = Paragraph::new(lines).block(overlay_block().title(" Adult Awards "));
+    frame.render_widget(paragraph, area);
+}
+
+fn render_onboarding_overlay(frame: &mut Frame, app: &App) {
+    let Some(adult) = app.store.adults.get(app.adults_selection.index) else {
+        return;
+    };
+    let Some(checklist) = app.onboarding.checklists.checklist(adult) else {
+        return;
+    };
+    // Rows: name line, blank, items, blank, footer, borders
+    let height = (OnboardingItem::ALL.len() + 6) as u16;
+    let area = centered_rect_fixed(FEED_OVERLAY_WIDTH, height, frame.area());
+    frame.render_widget(Clear, area);
+    let today = Local::now().date_naive();
+    let (done, total) = app.onboarding.checklists.progress(adult, today).unwrap_or_default();
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled(" ", styles::muted_style()),
+            Span::styled(adult.full_name(), styles::title_style()),
+            Span::styled(format!("  {}", adult.role()), styles::muted_style()),
+            Span::styled(
+                format!("  {}/{} since {}", done, total, checklist.started_on.format("%b %-d, %Y")),
+                if done == total { styles::success_style() } else { styles::muted_style() },
+            ),
+        ]),
+        Line::from(""),
+    ];
+
+    for (row, item) in OnboardingItem::ALL.iter().enumerate() {
+        let style = if row == app.onboarding.selection { styles::selected_style() } else { styles::list_item_style() };
+        let from_record = item.from_record(adult, today);
+        let (mark, note) = match checklist.checked.get(item) {
+            _ if from_record => ("[✓]", " (Scoutbook)".to_string()),
+            Some(date) => ("[✓]", format!(" ({})", date.format("%b %-d, %Y"))),
+            None if item.has_record() => ("[ ]", " (or from Scoutbook)".to_string()),
+            None => ("[ ]", String::new()),
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!("   {} {}", mark, item.name()), style),
+            Span::styled(note, styles::muted_style()),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled(" [Space]", styles::help_key_style()),
+        Span::styled(" check/uncheck  ", styles::muted_style()),
+        Span::styled("[x]", styles::help_key_style()),
+        Span::styled(" finish onboarding  ", styles::muted_style()),
+        Span::styled("[j/k]", styles::help_key_style()),
+        Span::styled(" move  ", styles::muted_style()),
+        Span::styled("[Esc]", styles::help_key_style()),
+        Span::styled(" close", styles::muted_style()),
+    ]));
+
+    let paragraph = Paragraph::new(lines).block(overlay_block().title(" Leader Onboarding "));
+    frame.render_widget(paragraph, area);
+}
+
+fn render_meeting_overlay(frame: &mut Frame, app: &App) {
+    let height = frame.area().height.saturating_sub(4).clamp(10, 40);
+    let area = centered_rect_fixed(OVERLAY_WIDTH, height, frame.area());
+    frame.render_widget(Clear, area);
+
+    let rows = app.meeting_rows();
+    let present = rows
+        .iter()
+        .filter(|y| y.user_id.is_some_and(|id| app.meeting.present.contains(&id)))
+        .count();
+
+    // Scout rows with a header line before each patrol
+    let mut body = Vec::new();
+    let mut selected_line = 0;
+    let mut patrol: Option<&str> = None;
+    for (i, youth) in rows.iter().enumerate() {
+        let name = youth.patrol_name.as_deref().unwrap_or("No Patrol");
+        if patrol != Some(name) {
+            body.push(Line::from(Span::styled(format!(" {}", name), styles::highlight_style())));
+            patrol = Some(name);
+        }
+        let checked = youth.user_id.is_some_and(|id| app.meeting.present.contains(&id));
+        let style = if i == app.meeting.selection {
+            selected_line = body.len();
+            styles::selected_style()
+        } else {
+            styles::list_item_style()
+        };
+        let mark = if checked { "[✓]" } else { "[ ]" };
+        body.push(Line::from(Span::styled(format!("   {} {}", mark, youth.display_name()), style)));
+    }
+    if rows.is_empty() {
+        body.push(Line::from(Span::styled(" No scouts loaded", styles::muted_style())));
+    }
+
+    // Borders, summary line, blank line, and footer take five rows
+    let visible = height.saturating_sub(5) as usize;
+    let skip = selected_line.saturating_sub(visible.saturating_sub(1));
+
+    let mut lines = vec![Line::from(vec![
+        Span::styled(format!(" {}  ", app.meeting.date.format("%a %b %-d, %Y")), styles::muted_style()),
+        Span::styled(format!("Present {} / {}", present, rows.len()), styles::success_style()),
+    ])];
+    lines.extend(body.into_iter().skip(skip).take(visible));
+    while lines.len() < visible + 1 {
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled(" [Space]", styles::help_key_style()),
+        Span::styled(" toggle  ", styles::muted_style()),
+        Span::styled("[p/x]", styles::help_key_style()),
+        Span::styled(" present/absent  ", styles::muted_style()),
+        Span::styled("[s]", styles::help_key_style()),
+        Span::styled(" save  ", styles::muted_style()),
+        Span::styled("[Esc]", styles::help_key_style()),
+        Span::styled(" cancel", styles::muted_style()),
+    ]));
+
+    let paragraph = Paragraph::new(lines).block(overlay_block().title(" Meeting Attendance "));
+    frame.render_widget(paragraph, area);
+}
+
+fn render_offline_overlay(frame: &mut Frame, app: &App) {
+    let checklist = app.offline_audit.as_ref().map(|a| a.checklist()).unwrap_or_default();
+    let complete = app.offline_audit.as_ref().is_none_or(|a| a.is_complete());
+
+    let height = 16 + checklist.len() as u16 + if complete { 0 } else { 1 };
+    let area = centered_rect_fixed(OVERLAY_WIDTH, height, frame.area());
+    frame.render_widget(Clear, area);
+
+    let mut lines = logo_lines();
+    lines.extend(vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            "               Enter Offline Mode?",
+            styles::highlight_style(),
+        )),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("   Scope: ", styles::muted_style()),
+            Span::styled(format!("{:<25}", app.offline_scope.description()), styles::list_item_style()),
+            Span::styled(format!("{:>11}", format!("~{} req", app.offline_estimate)), styles::muted_style()),
+        ]),
+        Line::from(vec![
+            Span::styled("   ", styles::muted_style()),
+            Span::styled("[P]", styles::help_key_style()),
+            Span::styled(" cycle patrol  ", styles::muted_style()),
+            Span::styled("[A]", styles::help_key_style()),
+            Span::styled(" toggle active only", styles::muted_style()),
+        ]),
+        Line::from(""),
+    ]);
+
+    // Completeness checklist of what is already cached
+    for item in &checklist {
+        let (mark, style) = if item.is_complete() {
+            ("✓", styles::success_style())
+        } else {
+            ("✗", styles::error_style())
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!("   {} ", mark), style),
+            Span::styled(format!("{:<30}", item.label), styles::list_item_style()),
+            Span::styled(format!("{:>10}", format!("{}/{}", item.cached, item.expected)), style),
+        ]));
+    }
+
+    lines.extend(vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            "    Data will remain static until you go back",
+            styles::muted_style(),
+        )),
+        Line::from(Span::styled(
+            "          online by pressing [o] again.",
+            styles::muted_style(),
+        )),
+        Line::from(""),
+    ]);
+
+    if complete {
+        lines.push(Line::from(vec![
+            Span::styled("      Press ", styles::muted_style()),
+            Span::styled("[Y]", styles::help_key_style()),
+            Span::styled(" to go offline, ", styles::muted_style()),
+            Span::styled("[N]", styles::help_key_style()),
+            Span::styled(" to cancel", styles::muted_style()),
+        ]));
+    } else {
+        lines.push(Line::from(vec![
+            Span::styled("   ", styles::muted_style()),
+            Span::styled("[Y]", styles::help_key_style()),
+            Span::styled(" refresh everything  ", styles::muted_style()),
+            Span::styled("[F]", styles::help_key_style()),
+            Span::styled(" fetch missing only", styles::muted_style()),
+        ]));
+        lines.push(Line::from(vec![
+            Span::styled("                  ", styles::muted_style()),
+            Span::styled("[N]", styles::help_key_style()),
+            Span::styled(" to cancel", styles::muted_style()),
+        ]));
+    }
+
+    let paragraph = Paragraph::new(lines).block(overlay_block());
+    frame.render_widget(paragraph, area);
+}
+
+fn render_buddies_overlay(frame: &mut Frame, app: &App) {
+    let height = frame.area().height.saturating_sub(4).clamp(12, 36);
+    let area = centered_rect_fixed(FEED_OVERLAY_WIDTH, height, frame.area());
+    frame.render_widget(Clear, area);
+
+    let rows = app.buddies.groups.rows();
+    // Borders, blank, hint line, and two footer lines
+    let visible = height.saturating_sub(6) as usize;
+    let start = app.buddies.selection.saturating_sub(visible.saturating_sub(1));
+    let mut lines: Vec<Line> = Vec::new();
+    for (i, &(group, user_id)) in rows.iter().enumerate().skip(start).take(visible) {
+        let youth = app.store.youth.iter().find(|y| y.user_id == Some(user_id));
+        let first_in_group = i == 0 || rows[i - 1].0 != group;
+        let label = if first_in_group { format!(" Pair {:<3}", group) } else { "         ".to_string() };
+        let marker = if app.buddies.marked == Some(user_id) { "* " } else { "  " };
+        let style = if i == app.buddies.selection { styles::selected_style() } else { styles::list_item_style() };
+        lines.push(Line::from(vec![
+            Span::styled(label, styles::muted_style()),
+            Span::styled(marker, styles::highlight_style()),
+            Span::styled(format!("{:<26}", truncate(&youth.map(|y| y.short_name()).unwrap_or_default(), 25)), style),
+            Span::styled(
+                format!(
+                    "{:<16} {:>3}",
+                    truncate(youth.and_then(|y| y.patrol_name.as_deref()).unwrap_or("-"), 15),
+                    youth.map(|y| y.age_str()).unwrap_or_default()
+                ),
+                styles::muted_style(),
+            ),
+        ]));
+    }
+    while lines.len() < visible {
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        match app.buddies.marked {
+            Some(_) => " Pick the scout to swap with the marked one".to_string(),
+            None => " Paired within patrols by age where possible".to_string(),
+        },
+        styles::muted_style(),
+    )));
+    lines.push(Line::from(vec![
+        Span::styled(" [Space]", styles::help_key_style()),
+        Span::styled(" mark/swap  ", styles::muted_style()),
+        Span::styled("[r]", styles::help_key_style()),
+        Span::styled(" re-pair  ", styles::muted_style()),
+        Span::styled("[e]", styles::help_key_style()),
+        Span::styled(" save check-in sheet", styles::muted_style()),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled(" [j/k]", styles::help_key_style()),
+        Span::styled(" move  ", styles::muted_style()),
+        Span::styled("[Esc]", styles::help_key_style()),
+        Span::styled(" close", styles::muted_style()),
+    ]));
+
+    let title = app.buddies.event.as_ref().map(|(_, name)| format!(" Buddies - {} ", name)).unwrap_or_default();
+    let paragraph = Paragraph::new(lines).block(overlay_block().title(title));
+    frame.render_widget(paragraph, area);
+}
+
+fn render_activity_plan_overlay(frame: &mut Frame, app: &App) {
+    let Some(event) = &app.activity_plans.event else {
+        return;
+    };
+    let items = app.activity_plan_items();
+    let today = Local::now().date_naive();
+    let unclassified = if items.contains(&ActivityPlanItem::SwimClassification) {
+        app.event_unclassified_swimmers(event)
+    } else {
+        Vec::new()
+    };
+    // Rows: type line, blank, items, swim note, blank, footer, borders
+    let height = (items.len() + 6 + usize::from(!unclassified.is_empty())) as u16;
+    let area = centered_rect_fixed(FEED_OVERLAY_WIDTH, height, frame.area());
+    frame.render_widget(Clear, area);
+    let (done, total) = app.activity_plans.plans.progress(event, &app.skills.matrix, today).unwrap_or_default();
+    let checklist = app.activity_plans.plans.checklist(event.id);
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled(format!(" {}", event.derived_type()), styles::muted_style()),
+            Span::styled(format!("  {}", event.formatted_date()), styles::muted_style()),
+            Span::styled(
+                format!("  {}/{} done", done, total),
+                if done == total { styles::success_style() } else { styles::muted_style() },
+            ),
+        ]),
+        Line::from(""),
+    ];
+
+    for (row, item) in items.iter().enumerate() {
+        let style = if row == app.activity_plans.selection { styles::selected_style() } else { styles::list_item_style() };
+        let from_record = item.from_record(event, &app.skills.matrix, today);
+        let (mark, note) = match checklist.and_then(|c| c.checked.get(item)) {
+            _ if from_record => ("[✓]", " (skill matrix)".to_string()),
+            Some(date) => ("[✓]", format!(" ({})", date.format("%b %-d, %Y"))),
+            None => ("[ ]", String::new()),
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!("   {} {}", mark, item.name()), style),
+            Span::styled(note, styles::muted_style()),
+        ]));
+    }
+    if !unclassified.is_empty() {
+        let width = FEED_OVERLAY_WIDTH.saturating_sub(4) as usize;
+        lines.push(Line::from(Span::styled(
+            truncate(&format!("       Not yet swimmers: {}", unclassified.join(", ")), width),
+            styles::error_style(),
+        )));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled(" [Space]", styles::help_key_style()),
+        Span::styled(" check/uncheck  ", styles::muted_style()),
+        Span::styled("[j/k]", styles::help_key_style()),
+        Span::styled(" move  ", styles::muted_style()),
+        Span::styled("[Esc]", styles::help_key_style()),
+        Span::styled(" close", styles::muted_style()),
+    ]));
+
+    let title = format!(" Checklist - {} ", event.name);
+    let paragraph = Paragraph::new(lines).block(overlay_block().title(title));
+    frame.render_widget(paragraph, area);
+}
+
+fn render_requirement_completion_overlay(frame: &mut Frame, app: &App) {
+    let Some(pending) = &app.pending_completion else {
+        return;
+    };
+    let area = centered_rect_fixed(FEED_OVERLAY_WIDTH, 9, frame.area());
+    frame.render_widget(Clear, area);
+
+    let width = FEED_OVERLAY_WIDTH.saturating_sub(6) as usize;
+    let requirement: String = pending.requirement.chars().take(width).collect();
+    let lines = vec![
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("  Mark complete for ", styles::muted_style()),
+            Span::styled(pending.scout.clone(), styles::highlight_style()),
+            Span::styled(" as of today?", styles::muted_style()),
+        ]),
+        Line::from(Span::styled(format!("  {}", requirement), styles::list_item_style())),
+        Line::from(Span::styled("  This records leader approval in Scoutbook.", styles::muted_style())),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("  Press ", styles::muted_style()),
+            Span::styled("[Y]", styles::help_key_style()),
+            Span::styled(" to record, ", styles::muted_style()),
+            Span::styled("[N]", styles::help_key_style()),
+            Span::styled(" to cancel", styles::muted_style()),
+        ]),
+    ];
+
+    let paragraph = Paragraph::new(lines).block(overlay_block().title(" Complete Requirement "));
+    frame.render_widget(paragraph, area);
+}
+
+fn render_patrol_move_overlay(frame: &mut Frame, app: &App) {
+    let Some(pending) = &app.patrol_move else {
+        return;
+    };
+    let patrols = app.patrol_choices();
+    let height = (patrols.len() as u16 + 8).min(frame.area().height.saturating_sub(4)).max(10);
+    let area = centered_rect_fixed(OVERLAY_WIDTH, height, frame.area());
+    frame.render_widget(Clear, area);
+
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("  Move ", styles::muted_style()),
+            Span::styled(pending.scout.clone(), styles::highlight_style()),
+            Span::styled(format!(" from {} to:", pending.current.as_deref().unwrap_or("no patrol")), styles::muted_style()),
+        ]),
+        Line::from(""),
+    ];
+    // Heading, borders, blanks, and footer
+    let visible = height.saturating_sub(7) as usize;
+    let skip = pending.selection.saturating_sub(visible.saturating_sub(1));
+    for (i, patrol) in patrols.iter().enumerate().skip(skip).take(visible) {
+        let style = if i == pending.selection { styles::selected_style() } else { styles::list_item_style() };
+        let marker = if pending.current.as_ref() == Some(&patrol.name) { " (current)" } else { "" };
+        lines.push(Line::from(Span::styled(format!("  {}{}", patrol.name, marker), style)));
+    }
+    while lines.len() < visible + 3 {
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled("  [j/k]", styles::help_key_style()),
+        Span::styled(" choose  ", styles::muted_style()),
+        Span::styled("[Enter]", styles::help_key_style()),
+        Span::styled(" move in Scoutbook  ", styles::muted_style()),
+        Span::styled("[Esc]", styles::help_key_style()),
+        Span::styled(" cancel", styles::muted_style()),
+    ]));
+
+    let paragraph = Paragraph::new(lines).block(overlay_block().title(" Move to Patrol "));
+    frame.render_widget(paragraph, area);
+}
+
+fn render_medication_access_overlay(frame: &mut Frame) {
+    let area = centered_rect_fixed(OVERLAY_WIDTH, 9, frame.area());
+    frame.render_widget(Clear, area);
+
+    let lines = vec![
+        Line::from(""),
+        Line::from(Span::styled("  The medication log holds scouts' health information.", styles::highlight_style())),
+        Line::from(Span::styled("  Open it only if you are responsible for medications", styles::muted_style())),
+        Line::from(Span::styled("  on this outing.", styles::muted_style())),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("  Press ", styles::muted_style()),
+            Span::styled("[Y]", styles::help_key_style()),
+            Span::styled(" to open, ", styles::muted_style()),
+            Span::styled("[N]", styles::help_key_style()),
+            Span::styled(" to cancel", styles::muted_style()),
+        ]),
+    ];
+
+    let paragraph = Paragraph::new(lines).block(overlay_block().title(" Medication Log "));
+    frame.render_widget(paragraph, area);
+}
+
+fn render_medications_overlay(frame: &mut Frame, app: &App) {
+    let height = frame.area().height.saturating_sub(4).clamp(12, 36);
+    let area = centered_rect_fixed(FEED_OVERLAY_WIDTH, height, frame.area());
+    frame.render_widget(Clear, area);
+
+    let doses = &app.medications.log.doses;
+    // Borders, blank, input/hint line, and two footer lines
+    let visible = height.saturating_sub(6) as usize;
+    let start = app.medications.selection.saturating_sub(visible.saturating_sub(1));
+    let mut lines: Vec<Line> = doses
+        .iter()
+        .enumerate()
+        .skip(start)
+        .take(visible)
+        .map(|(i, d)| {
+            let style = if i == app.medications.selection { styles::selected_style() } else { styles::list_item_style() };
+            Line::from(vec![
+                Span::styled(format!(" {}  ", d.given_at.format("%a %-m/%-d %H:%M")), styles::muted_style()),
+                Span::styled(format!("{:<20}", truncate(&d.scout_name, 19)), style),
+                Span::styled(format!("{:<28}", truncate(&format!("{} {}", d.medication, d.dose), 27)), style),
+                Span::styled(truncate(&d.given_by, 16), styles::muted_style()),
+            ])
+        })
+        .collect();
+    if doses.is_empty() {
+        lines.push(Line::from(Span::styled(" No doses recorded - [a] to add one", styles::muted_style())));
+    }
+    while lines.len() < visible {
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::from(""));
+    let scout = app.medications.scout.as_ref().map(|(_, name)| name.as_str());
+    lines.push(match &app.medications.input {
+        Some((field, text)) => {
+            let label = match field {
+                MedicationField::Medication => " Medication: ",
+                MedicationField::Dose => " Dose: ",
+                MedicationField::GivenBy => " Given by: ",
+            };
+            Line::from(vec![
+                Span::styled(format!(" {}", scout.unwrap_or_default()), styles::highlight_style()),
+                Span::styled(label, styles::highlight_style()),
+                Span::styled(format!("{}_", text), styles::list_item_style()),
+            ])
+        }
+        None => Line::from(Span::styled(
+            match scout {
+                Some(name) => format!(" Recording for {}. Doses are timed when saved.", name),
+                None => " Select a scout on the Scouts tab to record a dose".to_string(),
+            },
+            styles::muted_style(),
+        )),
+    });
+    lines.push(Line::from(vec![
+        Span::styled(" [a]", styles::help_key_style()),
+        Span::styled(" record dose  ", styles::muted_style()),
+        Span::styled("[D]", styles::help_key_style()),
+        Span::styled(" delete  ", styles::muted_style()),
+        Span::styled("[e]", styles::help_key_style()),
+        Span::styled(" export CSV", styles::muted_style()),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled(" [j/k]", styles::help_key_style()),
+        Span::styled(" move  ", styles::muted_style()),
+        Span::styled("[Esc]", styles::help_key_style()),
+        Span::styled(" close", styles::muted_style()),
+    ]));
+
+    let paragraph = Paragraph::new(lines).block(overlay_block().title(" Medication Log "));
     frame.render_widget(paragraph, area);
 }
 
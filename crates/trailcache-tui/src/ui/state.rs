@@ -0,0 +1,263 @@
+//! Where the user is on screen: the open overlay, tab, and panel, the search,
+//! how lists are sorted and viewed, and the layout last drawn.
+
+use std::collections::HashSet;
+use std::time::Instant;
+
+use ratatui::layout::Rect;
+use ratatui::widgets::TableState;
+
+use trailcache_core::models::{EventSortColumn, ScoutSortColumn};
+use trailcache_core::search::{SearchMode, SearchQuery};
+
+use crate::ui::tabs::TABLE_CHROME_ROWS;
+
+/// Number of items to scroll on page up/down before the first draw, when the
+/// visible page size isn't known yet.
+pub const PAGE_SCROLL_SIZE: usize = 10;
+
+/// Main navigation tabs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tab {
+    Scouts,
+    Ranks,
+    Badges,
+    Events,
+    Adults,
+    Unit,
+    Eagle,
+    Attendance,
+    /// A tab from the plugin registry, by position
+    Plugin(usize),
+}
+
+impl Tab {
+    /// Get the display title for this tab. Plugin tabs are titled by the
+    /// registry; see `App::tab_title`.
+    pub fn title(&self) -> &'static str {
+        match self {
+            Tab::Scouts => "Scouts",
+            Tab::Ranks => "Ranks",
+            Tab::Badges => "Badges",
+            Tab::Events => "Events",
+            Tab::Adults => "Adults",
+            Tab::Unit => "Unit",
+            Tab::Eagle => "Eagle",
+            Tab::Attendance => "Attendance",
+            Tab::Plugin(_) => "Plugin",
+        }
+    }
+
+    /// Get the next tab (wrapping around), with `plugins` plugin tabs after Attendance
+    pub fn next(&self, plugins: usize) -> Self {
+        match self {
+            Tab::Scouts => Tab::Ranks,
+            Tab::Ranks => Tab::Badges,
+            Tab::Badges => Tab::Events,
+            Tab::Events => Tab::Adults,
+            Tab::Adults => Tab::Unit,
+            Tab::Unit => Tab::Eagle,
+            Tab::Eagle => Tab::Attendance,
+            Tab::Attendance if plugins > 0 => Tab::Plugin(0),
+            Tab::Plugin(i) if i + 1 < plugins => Tab::Plugin(i + 1),
+            Tab::Attendance | Tab::Plugin(_) => Tab::Scouts,
+        }
+    }
+
+    /// Get the previous tab (wrapping around), with `plugins` plugin tabs after Attendance
+    pub fn prev(&self, plugins: usize) -> Self {
+        match self {
+            Tab::Scouts if plugins > 0 => Tab::Plugin(plugins - 1),
+            Tab::Scouts => Tab::Attendance,
+            Tab::Ranks => Tab::Scouts,
+            Tab::Badges => Tab::Ranks,
+            Tab::Events => Tab::Badges,
+            Tab::Adults => Tab::Events,
+            Tab::Unit => Tab::Adults,
+            Tab::Eagle => Tab::Unit,
+            Tab::Attendance => Tab::Eagle,
+            Tab::Plugin(0) => Tab::Attendance,
+            Tab::Plugin(i) => Tab::Plugin(i - 1),
+        }
+    }
+}
+
+/// Sub-view for scout detail panel
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScoutDetailView {
+    Details,
+    Ranks,
+    MeritBadges,
+    Leadership,
+    Awards,
+    Timeline,
+}
+
+/// Sub-view for event detail panel
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventDetailView {
+    Details,
+    Rsvp,
+}
+
+/// Which events the Events tab lists
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventListView {
+    Upcoming,
+    /// Past events still inside the refresh window
+    Past,
+    /// Older events kept locally after they left the refresh window
+    Archive,
+}
+
+impl EventListView {
+    pub fn next(&self) -> Self {
+        match self {
+            EventListView::Upcoming => EventListView::Past,
+            EventListView::Past => EventListView::Archive,
+            EventListView::Archive => EventListView::Upcoming,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            EventListView::Upcoming => "Upcoming Events",
+            EventListView::Past => "Past Events",
+            EventListView::Archive => "Archived Events",
+        }
+    }
+}
+
+/// Advancement tab sub-view
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdvancementView {
+    Ranks,
+    MeritBadges,
+}
+
+/// Current UI focus area (list panel or detail panel)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Focus {
+    List,
+    Detail,
+}
+
+/// Overall application state
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AppState {
+    Normal,
+    Searching,
+    ShowingHelp,
+    LoggingIn,
+    ConfirmingQuit,
+    ConfirmingOffline,
+    ConfirmingOnline,
+    ShowingFeed,
+    ShowingAwards,
+    ShowingMyScouts,
+    ShowingTimers,
+    ShowingSkills,
+    ShowingCounselors,
+    ShowingEventTemplates,
+    ShowingHistory,
+    EditingMinutes,
+    ShowingTodos,
+    ShowingAlerts,
+    ShowingLibrary,
+    ShowingCloset,
+    ShowingAdultSkills,
+    ScreeningTrek,
+    ShowingEagleProject,
+    PlanningClinic,
+    GlobalSearch,
+    LookingUpMemberId,
+    CheckingContacts,
+    ShowingOaEligibility,
+    ShowingDistrict,
+    ShowingLinked,
+    ConfirmingMedicationAccess,
+    ConfirmingRequirementCompletion,
+    MovingToPatrol,
+    CorrectingDates,
+    ShowingBuddies,
+    ShowingActivityPlan,
+    ShowingMedications,
+    PlanningPositions,
+    EditingAdultAwards,
+    ShowingOnboarding,
+    ShowingCommitteeRoles,
+    TakingAttendance,
+    Quitting,
+}
+
+#[derive(Default)]
+pub struct LayoutAreas {
+    pub title_bar: Rect,
+    pub tabs_bar: Rect,
+    pub left_panel: Rect,
+    pub right_panel: Rect,
+    pub detail_tabs_area: Rect,
+}
+
+impl LayoutAreas {
+    /// Number of rows the left panel's table shows, for page up/down.
+    pub fn list_page_size(&self) -> usize {
+        if self.left_panel.height == 0 {
+            return PAGE_SCROLL_SIZE;
+        }
+        (self.left_panel.height.saturating_sub(TABLE_CHROME_ROWS) as usize).max(1)
+    }
+}
+
+/// Navigation and view state shared by every tab.
+pub struct UiState {
+    pub state: AppState,
+    pub current_tab: Tab,
+    pub focus: Focus,
+    pub search_query: String,
+    /// Compiled form of `search_query` in the current mode
+    pub search: SearchQuery,
+    pub search_mode: SearchMode,
+    pub advancement_view: AdvancementView,
+    pub scout_sort_column: ScoutSortColumn,
+    pub scout_sort_ascending: bool,
+    /// Show the roster under collapsible patrol headings
+    pub roster_grouped: bool,
+    pub collapsed_patrols: HashSet<String>,
+    pub scout_detail_view: ScoutDetailView,
+    pub event_detail_view: EventDetailView,
+    pub event_list_view: EventListView,
+    pub event_sort_column: EventSortColumn,
+    pub event_sort_ascending: bool,
+    pub layout_areas: LayoutAreas,
+    pub left_table_state: TableState,
+    pub right_table_state: TableState,
+    pub last_click: Option<(u16, u16, Instant)>,
+}
+
+impl Default for UiState {
+    fn default() -> Self {
+        Self {
+            state: AppState::Normal,
+            current_tab: Tab::Scouts,
+            focus: Focus::List,
+            search_query: String::new(),
+            search: SearchQuery::default(),
+            search_mode: SearchMode::default(),
+            advancement_view: AdvancementView::Ranks,
+            scout_sort_column: ScoutSortColumn::Name,
+            scout_sort_ascending: true,
+            roster_grouped: false,
+            collapsed_patrols: HashSet::new(),
+            scout_detail_view: ScoutDetailView::Details,
+            event_detail_view: EventDetailView::Details,
+            event_list_view: EventListView::Upcoming,
+            event_sort_column: EventSortColumn::Date,
+            event_sort_ascending: true,
+            layout_areas: LayoutAreas::default(),
+            left_table_state: TableState::default(),
+            right_table_state: TableState::default(),
+            last_click: None,
+        }
+    }
+}
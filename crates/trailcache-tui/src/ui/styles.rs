@@ -1,77 +1,310 @@
+//! Color themes and text styles.
+//!
+//! Styles come from the active `Theme`: one of the built-in themes, or a
+//! theme file written as TOML key/value pairs starting from a built-in:
+//!
+//! ```toml
+//! base = "light"
+//! primary = "#005f87"
+//! selection = "lightyellow"
+//! ```
+
+use std::str::FromStr;
+use std::sync::RwLock;
+
+use anyhow::{anyhow, bail, Context, Result};
 use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use trailcache_core::config::Config;
+use trailcache_core::search::SearchQuery;
+
+/// Colors every style is built from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    /// Titles, focused borders, and the selected tab
+    pub primary: Color,
+    /// Success
+    pub secondary: Color,
+    /// Headings, key hints, and search matches
+    pub accent: Color,
+    pub error: Color,
+    pub muted: Color,
+    /// Background of the selected row
+    pub selection: Color,
+    /// Ordinary text
+    pub text: Color,
+    /// Background of the status bar
+    pub status_bar: Color,
+    /// Text on an accent background
+    pub search_text: Color,
+}
+
+impl Theme {
+    pub const DARK: Theme = Theme {
+        primary: Color::Rgb(64, 128, 192),
+        secondary: Color::Rgb(96, 160, 96),
+        accent: Color::Rgb(192, 160, 64),
+        error: Color::Rgb(192, 64, 64),
+        muted: Color::Rgb(128, 128, 128),
+        selection: Color::Rgb(48, 48, 64),
+        text: Color::White,
+        status_bar: Color::Rgb(32, 32, 40),
+        search_text: Color::Black,
+    };
+
+    pub const LIGHT: Theme = Theme {
+        primary: Color::Rgb(0, 90, 160),
+        secondary: Color::Rgb(30, 120, 40),
+        accent: Color::Rgb(150, 90, 0),
+        error: Color::Rgb(180, 30, 30),
+        muted: Color::Rgb(110, 110, 110),
+        selection: Color::Rgb(210, 220, 240),
+        text: Color::Black,
+        status_bar: Color::Rgb(225, 225, 230),
+        search_text: Color::White,
+    };
+
+    pub const HIGH_CONTRAST: Theme = Theme {
+        primary: Color::LightCyan,
+        secondary: Color::LightGreen,
+        accent: Color::LightYellow,
+        error: Color::LightRed,
+        muted: Color::Gray,
+        selection: Color::Blue,
+        text: Color::White,
+        status_bar: Color::Black,
+        search_text: Color::Black,
+    };
+
+    pub const SOLARIZED: Theme = Theme {
+        primary: Color::Rgb(38, 139, 210),
+        secondary: Color::Rgb(133, 153, 0),
+        accent: Color::Rgb(181, 137, 0),
+        error: Color::Rgb(220, 50, 47),
+        muted: Color::Rgb(88, 110, 117),
+        selection: Color::Rgb(7, 54, 66),
+        text: Color::Rgb(147, 161, 161),
+        status_bar: Color::Rgb(0, 43, 54),
+        search_text: Color::Rgb(0, 43, 54),
+    };
+
+    /// Built-in themes by name, in the order the theme key cycles them.
+    pub const BUILT_IN: [(&'static str, Theme); 4] = [
+        ("dark", Theme::DARK),
+        ("light", Theme::LIGHT),
+        ("high-contrast", Theme::HIGH_CONTRAST),
+        ("solarized", Theme::SOLARIZED),
+    ];
+
+    pub fn built_in(name: &str) -> Option<Theme> {
+        Self::BUILT_IN.iter().find(|(n, _)| n.eq_ignore_ascii_case(name)).map(|(_, t)| *t)
+    }
+
+    /// Parse a theme file: `key = "color"` lines, `#` comments, and an
+    /// optional `base` naming the built-in theme to start from. Colors are
+    /// names like "lightblue", "#rrggbb", or 256-color indexes.
+    pub fn from_toml(text: &str) -> Result<Theme> {
+        let mut pairs = Vec::new();
+        for (n, line) in text.lines().enumerate() {
+            let line = line.split_once(" #").map_or(line, |(l, _)| l).trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with('[') {
+                continue;
+            }
+            let (key, value) = line.split_once('=').ok_or_else(|| anyhow!("line {}: expected key = \"value\"", n + 1))?;
+            pairs.push((n + 1, key.trim(), value.trim().trim_matches(|c| c == '"' || c == '\'')));
+        }
+
+        let mut theme = match pairs.iter().find(|(_, key, _)| *key == "base") {
+            Some((n, _, name)) => Theme::built_in(name).ok_or_else(|| anyhow!("line {}: unknown base theme \"{}\"", n, name))?,
+            None => Theme::DARK,
+        };
+        for (n, key, value) in pairs {
+            let slot = match key {
+                "base" => continue,
+                "primary" => &mut theme.primary,
+                "secondary" => &mut theme.secondary,
+                "accent" => &mut theme.accent,
+                "error" => &mut theme.error,
+                "muted" => &mut theme.muted,
+                "selection" => &mut theme.selection,
+                "text" => &mut theme.text,
+                "status_bar" => &mut theme.status_bar,
+                "search_text" => &mut theme.search_text,
+                _ => bail!("line {}: unknown theme color \"{}\"", n, key),
+            };
+            *slot = Color::from_str(value).map_err(|_| anyhow!("line {}: \"{}\" isn't a color", n, value))?;
+        }
+        Ok(theme)
+    }
+}
 
-// Color palette
-pub const PRIMARY: Color = Color::Rgb(64, 128, 192);
-pub const SECONDARY: Color = Color::Rgb(96, 160, 96);
-pub const ACCENT: Color = Color::Rgb(192, 160, 64);
-pub const ERROR: Color = Color::Rgb(192, 64, 64);
-pub const MUTED: Color = Color::Rgb(128, 128, 128);
-pub const HIGHLIGHT: Color = Color::Rgb(48, 48, 64);
+/// Themes the theme key cycles through: the built-ins, then the configured
+/// theme file if there is one.
+#[derive(Debug, Clone)]
+pub struct ThemeChoices {
+    themes: Vec<(String, Theme)>,
+    current: usize,
+}
+
+impl ThemeChoices {
+    /// Load the configured theme and make it active. A theme file that
+    /// can't be read leaves the dark theme, with the problem returned.
+    pub fn new(config: &Config) -> (Self, Option<String>) {
+        let mut choices = ThemeChoices {
+            themes: Theme::BUILT_IN.iter().map(|(name, theme)| (name.to_string(), *theme)).collect(),
+            current: 0,
+        };
+        let mut problem = None;
+        if let Some(name) = config.theme.as_deref() {
+            match choices.themes.iter().position(|(n, _)| n.eq_ignore_ascii_case(name)) {
+                Some(i) => choices.current = i,
+                None => match Self::load_file(config, name) {
+                    Ok(theme) => {
+                        choices.themes.push((name.to_string(), theme));
+                        choices.current = choices.themes.len() - 1;
+                    }
+                    Err(e) => problem = Some(format!("Theme \"{}\": {:#}", name, e)),
+                },
+            }
+        }
+        set_theme(choices.themes[choices.current].1);
+        (choices, problem)
+    }
+
+    fn load_file(config: &Config, name: &str) -> Result<Theme> {
+        let path = match std::path::Path::new(name) {
+            path if path.is_absolute() => path.to_path_buf(),
+            _ => config.data_file_path(name)?,
+        };
+        let text = std::fs::read_to_string(&path).with_context(|| format!("Reading {}", path.display()))?;
+        Theme::from_toml(&text)
+    }
+
+    /// Switch to the next theme. Returns its name.
+    pub fn next(&mut self) -> &str {
+        self.current = (self.current + 1) % self.themes.len();
+        let (name, theme) = &self.themes[self.current];
+        set_theme(*theme);
+        name
+    }
+}
+
+static THEME: RwLock<Theme> = RwLock::new(Theme::DARK);
+
+/// The active theme.
+pub fn theme() -> Theme {
+    *THEME.read().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Switch themes; takes effect on the next frame.
+pub fn set_theme(theme: Theme) {
+    *THEME.write().unwrap_or_else(|e| e.into_inner()) = theme;
+}
 
 // Styles
 pub fn title_style() -> Style {
-    Style::default().fg(PRIMARY).add_modifier(Modifier::BOLD)
+    Style::default().fg(theme().primary).add_modifier(Modifier::BOLD)
 }
 
 pub fn selected_style() -> Style {
     Style::default()
-        .bg(HIGHLIGHT)
+        .bg(theme().selection)
         .add_modifier(Modifier::BOLD)
 }
 
 pub fn list_item_style() -> Style {
-    Style::default().fg(Color::White)
+    Style::default().fg(theme().text)
 }
 
 pub fn muted_style() -> Style {
-    Style::default().fg(MUTED)
+    Style::default().fg(theme().muted)
 }
 
 pub fn highlight_style() -> Style {
-    Style::default().fg(ACCENT)
+    Style::default().fg(theme().accent)
 }
 
 pub fn success_style() -> Style {
-    Style::default().fg(SECONDARY)
+    Style::default().fg(theme().secondary)
 }
 
 pub fn error_style() -> Style {
-    Style::default().fg(ERROR)
+    Style::default().fg(theme().error)
 }
 
 pub fn tab_style(selected: bool) -> Style {
     if selected {
         Style::default()
-            .fg(PRIMARY)
+            .fg(theme().primary)
             .add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
     } else {
-        Style::default().fg(Color::White)
+        Style::default().fg(theme().text)
     }
 }
 
 pub fn border_style(focused: bool) -> Style {
     if focused {
-        Style::default().fg(PRIMARY)
+        Style::default().fg(theme().primary)
     } else {
-        Style::default().fg(MUTED)
+        Style::default().fg(theme().muted)
     }
 }
 
-#[allow(dead_code)]
+/// Substrings matching the active search filter
 pub fn search_style() -> Style {
-    Style::default().fg(ACCENT)
+    Style::default().fg(theme().search_text).bg(theme().accent)
+}
+
+/// Split `text` into spans, styling the parts matching `query` with `search_style`.
+pub fn highlight_matches(text: impl Into<String>, query: &SearchQuery, base: Style) -> Line<'static> {
+    let text = text.into();
+    let ranges = query.match_ranges(&text);
+    if ranges.is_empty() {
+        return Line::from(Span::styled(text, base));
+    }
+
+    let mut spans = Vec::with_capacity(ranges.len() * 2 + 1);
+    let mut pos = 0;
+    for range in ranges {
+        if range.start > pos {
+            spans.push(Span::styled(text[pos..range.start].to_string(), base));
+        }
+        spans.push(Span::styled(text[range.clone()].to_string(), search_style()));
+        pos = range.end;
+    }
+    if pos < text.len() {
+        spans.push(Span::styled(text[pos..].to_string(), base));
+    }
+    Line::from(spans)
 }
 
 pub fn status_bar_style() -> Style {
-    Style::default().bg(Color::Rgb(32, 32, 40)).fg(Color::White)
+    Style::default().bg(theme().status_bar).fg(theme().text)
 }
 
 pub fn help_key_style() -> Style {
     Style::default()
-        .fg(ACCENT)
+        .fg(theme().accent)
         .add_modifier(Modifier::BOLD)
 }
 
 pub fn help_desc_style() -> Style {
-    Style::default().fg(Color::White)
+    Style::default().fg(theme().text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_theme_file() {
+        let theme = Theme::from_toml("# Porch light\n[colors]\nbase = \"light\"\nprimary = \"#005f87\"  # teal\nselection = 'lightyellow'\n").unwrap();
+        assert_eq!(theme.primary, Color::Rgb(0, 95, 135));
+        assert_eq!(theme.selection, Color::LightYellow);
+        assert_eq!(theme.text, Theme::LIGHT.text, "unset colors come from the base");
+
+        assert!(Theme::from_toml("primary = \"not a color\"").is_err());
+        assert!(Theme::from_toml("border = \"red\"").is_err());
+        assert_eq!(Theme::built_in("High-Contrast"), Some(Theme::HIGH_CONTRAST));
+    }
 }
@@ -8,10 +8,12 @@ use ratatui::{
     Frame,
 };
 
-use crate::app::{AdvancementView, App, Focus};
-use trailcache_core::models::{EAGLE_REQUIRED_COUNT, MeritBadgeProgress, StatusCategory};
+use crate::app::App;
+use crate::ui::state::{AdvancementView, Focus};
+use trailcache_core::models::{EAGLE_REQUIRED_COUNT, MeritBadgeProgress, RequirementTree, StatusCategory};
 use trailcache_core::utils::{strip_html, truncate, wrap_text};
 use crate::ui::styles;
+use crate::ui::tabs::{accommodation_lines, row_range_label, shown_accommodation, RequirementRow};
 
 /// Get badges sorted: in-progress first (by percent desc), then completed (by date desc)
 pub fn get_sorted_badges(badges: &[MeritBadgeProgress]) -> Vec<&MeritBadgeProgress> {
@@ -26,8 +28,8 @@ pub fn render(frame: &mut Frame, app: &mut App, area: Rect) {
         .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
         .split(area);
 
-    app.layout_areas.left_panel = chunks[0];
-    app.layout_areas.right_panel = chunks[1];
+    app.ui.layout_areas.left_panel = chunks[0];
+    app.ui.layout_areas.right_panel = chunks[1];
 
     render_scout_list(frame, app, chunks[0]);
     render_advancement_detail(frame, app, chunks[1]);
@@ -66,24 +68,26 @@ fn render_scout_list(frame: &mut Frame, app: &mut App, area: Rect) {
         Constraint::Fill(1),
     ];
 
-    let focused = matches!(app.focus, Focus::List);
+    let focused = matches!(app.ui.focus, Focus::List);
+    let range = row_range_label(app.ui.left_table_state.offset(), app.advancement_selection, area, rows.len());
     let table = Table::new(rows, widths)
         .header(header)
         .block(
             Block::default()
-                .title(format!(" Scouts ({}) ", app.youth.len()))
+                .title(format!(" Scouts ({}) ", app.store.youth.len()))
+                .title_top(Line::from(range).right_aligned())
                 .title_style(styles::title_style())
                 .borders(Borders::ALL)
                 .border_style(styles::border_style(focused))
         )
         .row_highlight_style(styles::selected_style());
 
-    app.left_table_state.select(Some(app.advancement_selection));
-    frame.render_stateful_widget(table, area, &mut app.left_table_state);
+    app.ui.left_table_state.select(Some(app.advancement_selection));
+    frame.render_stateful_widget(table, area, &mut app.ui.left_table_state);
 }
 
 fn render_advancement_detail(frame: &mut Frame, app: &mut App, area: Rect) {
-    let focused = matches!(app.focus, Focus::Detail);
+    let focused = matches!(app.ui.focus, Focus::Detail);
 
     // Split into view selector and content
     let chunks = Layout::default()
@@ -95,19 +99,19 @@ fn render_advancement_detail(frame: &mut Frame, app: &mut App, area: Rect) {
     render_view_toggle(frame, app, chunks[0]);
 
     // Render content based on view
-    match app.advancement_view {
+    match app.ui.advancement_view {
         AdvancementView::Ranks => render_ranks_view(frame, app, chunks[1], focused),
         AdvancementView::MeritBadges => render_badges_view(frame, app, chunks[1], focused),
     }
 }
 
 fn render_view_toggle(frame: &mut Frame, app: &mut App, area: Rect) {
-    let ranks_style = if app.advancement_view == AdvancementView::Ranks {
+    let ranks_style = if app.ui.advancement_view == AdvancementView::Ranks {
         styles::highlight_style()
     } else {
         styles::muted_style()
     };
-    let badges_style = if app.advancement_view == AdvancementView::MeritBadges {
+    let badges_style = if app.ui.advancement_view == AdvancementView::MeritBadges {
         styles::highlight_style()
     } else {
         styles::muted_style()
@@ -264,10 +268,14 @@ fn render_requirements_view(frame: &mut Frame, app: &mut App, area: Rect, focuse
         lines.push(Line::from(""));
 
         // Show requirements
+        let tree = RequirementTree::build(&app.selected_rank_requirements);
+        let completed = |i: usize| app.selected_rank_requirements[i].is_completed();
         for (i, req) in app.selected_rank_requirements.iter().enumerate() {
             let is_selected = i == app.requirement_selection;
-            let check = if req.is_completed() { "✓" } else { "○" };
-            let check_style = if req.is_completed() { styles::success_style() } else { styles::muted_style() };
+            let accommodation = shown_accommodation(app, AdvancementView::Ranks, &tree.node(i).number);
+            let row = RequirementRow::new(&tree, i, &completed).accommodated(accommodation);
+            let check = row.check;
+            let check_style = if row.done { styles::success_style() } else { styles::muted_style() };
 
             let req_num = req.number();
             // prefix(2) + check(1) + space(1) + num(5) + margin(2) + borders(2) = 13 chars overhead
@@ -277,21 +285,23 @@ fn render_requirements_view(frame: &mut Frame, app: &mut App, area: Rect, focuse
             let prefix = if is_selected { "▶ " } else { "  " };
             let text_style = if is_selected { styles::selected_style() } else { styles::list_item_style() };
 
-            let wrapped = wrap_text(&strip_html(&req.full_text()), text_width);
+            let wrapped = wrap_text(&(strip_html(&req.full_text()) + &row.rollup), text_width.saturating_sub(row.indent.len()));
             lines.push(Line::from(vec![
                 Span::raw(prefix),
+                Span::raw(row.indent.clone()),
                 Span::styled(check, check_style),
                 Span::raw(" "),
                 Span::styled(format!("{:<5}", req_num), styles::highlight_style()),
                 Span::styled(wrapped[0].clone(), text_style),
             ]));
-            let indent = " ".repeat(9); // prefix(2) + check(1) + space(1) + num(5)
+            let indent = " ".repeat(9) + &row.indent; // prefix(2) + check(1) + space(1) + num(5)
             for wrap_line in &wrapped[1..] {
                 lines.push(Line::from(vec![
                     Span::raw(indent.clone()),
                     Span::styled(wrap_line.clone(), text_style),
                 ]));
             }
+            lines.extend(accommodation_lines(app, accommodation, is_selected, indent.len()));
 
             // Show completion date if completed and selected
             if is_selected && req.is_completed() {
@@ -439,10 +449,14 @@ fn render_badge_requirements_view(frame: &mut Frame, app: &mut App, area: Rect,
         lines.push(Line::from(""));
 
         // Show requirements
+        let tree = RequirementTree::build(&app.selected_badge_requirements);
+        let completed = |i: usize| app.selected_badge_requirements[i].is_completed();
         for (i, req) in app.selected_badge_requirements.iter().enumerate() {
             let is_selected = i == app.requirement_selection;
-            let check = if req.is_completed() { "✓" } else { "○" };
-            let check_style = if req.is_completed() { styles::success_style() } else { styles::muted_style() };
+            let accommodation = shown_accommodation(app, AdvancementView::MeritBadges, &tree.node(i).number);
+            let row = RequirementRow::new(&tree, i, &completed).accommodated(accommodation);
+            let check = row.check;
+            let check_style = if row.done { styles::success_style() } else { styles::muted_style() };
 
             let req_num = req.number();
             // Strip HTML tags and wrap to fit available width
@@ -454,23 +468,25 @@ fn render_badge_requirements_view(frame: &mut Frame, app: &mut App, area: Rect,
             let prefix = if is_selected { "▶ " } else { "  " };
             let text_style = if is_selected { styles::selected_style() } else { styles::list_item_style() };
 
-            let wrapped = wrap_text(&clean_text, text_width);
+            let wrapped = wrap_text(&(clean_text + &row.rollup), text_width.saturating_sub(row.indent.len()));
             // First line: prefix + check + number + text
             lines.push(Line::from(vec![
                 Span::raw(prefix),
+                Span::raw(row.indent.clone()),
                 Span::styled(check, check_style),
                 Span::raw(" "),
                 Span::styled(format!("{:<5}", req_num), styles::highlight_style()),
                 Span::styled(wrapped[0].clone(), text_style),
             ]));
             // Continuation lines: indented to align with text
-            let indent = " ".repeat(9); // prefix(2) + check(1) + space(1) + num(5)
+            let indent = " ".repeat(9) + &row.indent; // prefix(2) + check(1) + space(1) + num(5)
             for wrap_line in &wrapped[1..] {
                 lines.push(Line::from(vec![
                     Span::raw(indent.clone()),
                     Span::styled(wrap_line.clone(), text_style),
                 ]));
             }
+            lines.extend(accommodation_lines(app, accommodation, is_selected, indent.len()));
 
             // Show completion date if completed and selected
             if is_selected && req.is_completed() {
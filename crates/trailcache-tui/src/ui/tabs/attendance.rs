@@ -0,0 +1,163 @@
+//! Attendance tab - how often each scout turns out for events.
+//!
+//! The left panel lists scouts with the share of past events they attended
+//! out of those they were invited to; the right panel breaks the selected
+//! scout's attendance down by event type next to the troop's, and lists
+//! their recent events.
+
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, Wrap},
+    Frame,
+};
+
+use crate::app::App;
+use crate::ui::state::Focus;
+use crate::ui::styles;
+use crate::ui::tabs::row_range_label;
+use trailcache_core::models::{AttendanceTally, EventAttendance, Youth};
+
+/// Attendance below this percentage is shown in the error style
+const LOW_ATTENDANCE_PERCENT: u32 = 50;
+
+/// Recent events listed for the selected scout
+const RECENT_EVENTS: usize = 10;
+
+pub fn render(frame: &mut Frame, app: &mut App, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(area);
+
+    app.ui.layout_areas.left_panel = chunks[0];
+    app.ui.layout_areas.right_panel = chunks[1];
+
+    let rows = app.attendance_rows();
+    let count = rows.len();
+    let selected = rows.get(app.attendance_selection.index.min(count.saturating_sub(1))).map(|(y, _)| *y);
+    render_detail(frame, &app.store.event_attendance, selected, chunks[1]);
+    let table_rows = scout_rows(&rows, app.attendance_selection.index);
+    app.attendance_selection.clamp(count);
+    render_scout_list(frame, app, chunks[0], table_rows, count);
+}
+
+fn percent_cell(tally: AttendanceTally) -> (String, Style) {
+    match tally.percent() {
+        Some(p) if p < LOW_ATTENDANCE_PERCENT => (format!("{:>3}%", p), styles::error_style()),
+        Some(p) => (format!("{:>3}%", p), styles::list_item_style()),
+        None => ("   -".to_string(), styles::muted_style()),
+    }
+}
+
+fn scout_rows(rows: &[(&Youth, AttendanceTally)], selected: usize) -> Vec<Row<'static>> {
+    if rows.is_empty() {
+        return vec![Row::new(vec![Cell::from(Span::styled("No scouts", styles::muted_style()))])];
+    }
+    rows.iter()
+        .enumerate()
+        .map(|(i, (y, tally))| {
+            let style = if i == selected { styles::selected_style() } else { styles::list_item_style() };
+            let (percent, percent_style) = percent_cell(*tally);
+            Row::new(vec![
+                Cell::from(y.display_name()),
+                Cell::from(format!("{:>3}/{:<3}", tally.attended, tally.invited)),
+                Cell::from(Span::styled(percent, percent_style)),
+            ])
+            .style(style)
+        })
+        .collect()
+}
+
+fn render_scout_list(frame: &mut Frame, app: &mut App, area: Rect, rows: Vec<Row<'static>>, count: usize) {
+    let focused = matches!(app.ui.focus, Focus::List);
+
+    let header = Row::new([Cell::from("Name"), Cell::from("Events"), Cell::from("Rate")])
+        .style(styles::title_style())
+        .height(1);
+    let widths = [Constraint::Fill(1), Constraint::Length(8), Constraint::Length(5)];
+
+    let order = if app.attendance_sort_by_percent { "lowest first" } else { "by name" };
+    let title = format!(" Attendance ({}) - {}, [p] to switch ", count, order);
+    let range = row_range_label(app.ui.left_table_state.offset(), app.attendance_selection.index, area, rows.len());
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(
+            Block::default()
+                .title(title)
+                .title_top(Line::from(range).right_aligned())
+                .title_style(styles::muted_style())
+                .borders(Borders::ALL)
+                .border_style(styles::border_style(focused)),
+        )
+        .row_highlight_style(styles::selected_style());
+
+    app.ui.left_table_state.select(Some(app.attendance_selection.index));
+    frame.render_stateful_widget(table, area, &mut app.ui.left_table_state);
+}
+
+fn render_detail(frame: &mut Frame, attendance: &EventAttendance, scout: Option<&Youth>, area: Rect) {
+    let block = Block::default()
+        .title(" By Event Type ")
+        .title_style(styles::muted_style())
+        .borders(Borders::ALL)
+        .border_style(styles::border_style(false));
+
+    let Some(scout) = scout else {
+        let paragraph = Paragraph::new(Span::styled("No scout selected", styles::muted_style())).block(block);
+        frame.render_widget(paragraph, area);
+        return;
+    };
+    let user_id = scout.user_id.unwrap_or_default();
+
+    let heading = |text: &str| Line::from(Span::styled(text.to_string(), styles::title_style()));
+    let mut lines = vec![Line::from(Span::styled(scout.display_name(), styles::highlight_style())), Line::from("")];
+
+    if attendance.records.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "No attendance recorded yet - it's taken from past events once their details are refreshed",
+            styles::muted_style(),
+        )));
+        frame.render_widget(Paragraph::new(lines).block(block).wrap(Wrap { trim: false }), area);
+        return;
+    }
+
+    let troop = attendance.by_type(None);
+    let mine = attendance.by_type(Some(user_id));
+    lines.push(Line::from(Span::styled(format!("  {:<20} {:>9} {:>6}", "Type", "Scout", "Troop"), styles::muted_style())));
+    for (event_type, troop_tally) in &troop {
+        let tally = mine.iter().find(|(t, _)| t == event_type).map(|(_, t)| *t).unwrap_or_default();
+        let (percent, style) = percent_cell(tally);
+        let troop_percent = troop_tally.percent().map(|p| format!("{}%", p)).unwrap_or_default();
+        lines.push(Line::from(vec![
+            Span::raw(format!("  {:<20} ", event_type)),
+            Span::styled(format!("{:>3}/{:<2}", tally.attended, tally.invited), styles::muted_style()),
+            Span::styled(percent, style),
+            Span::styled(format!(" {:>6}", troop_percent), styles::muted_style()),
+        ]));
+    }
+    lines.push(Line::from(""));
+
+    lines.push(heading("Recent events"));
+    let mut any = false;
+    for record in attendance.events_for(user_id).take(RECENT_EVENTS) {
+        any = true;
+        let (mark, style) = if record.attended.contains(&user_id) {
+            ("✓", styles::success_style())
+        } else {
+            ("✗", styles::muted_style())
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {} ", mark), style),
+            Span::styled(record.date.format("%b %d, %Y  ").to_string(), styles::muted_style()),
+            Span::raw(record.name.clone()),
+        ]));
+    }
+    if !any {
+        lines.push(Line::from(Span::styled("  Not invited to any recorded events", styles::muted_style())));
+    }
+
+    let paragraph = Paragraph::new(lines).block(block).wrap(Wrap { trim: false });
+    frame.render_widget(paragraph, area);
+}
@@ -12,11 +12,13 @@ use ratatui::{
     Frame,
 };
 
-use crate::app::{App, Focus};
-use trailcache_core::models::{MeritBadgeProgress, MeritBadgeRequirement, StatusCategory, Youth};
+use crate::app::App;
+use crate::ui::state::{AdvancementView, Focus};
+use trailcache_core::models::{MeritBadgeProgress, MeritBadgeRequirement, RequirementTree, StatusCategory, Youth};
 use trailcache_core::models::advancement::format_date;
 use trailcache_core::models::pivot::{group_youth_by_badge, BadgeGroup, BadgeGroupEntry};
 use crate::ui::styles;
+use crate::ui::tabs::{accommodation_lines, row_range_label, shown_accommodation, RequirementRow};
 use trailcache_core::utils::{strip_html, wrap_text};
 
 /// Aggregate all youth badges into a list of badges with scouts working on them.
@@ -40,9 +42,7 @@ pub fn get_badge_list(
     // Core sorts count desc / name asc by default.
     // For count: default is desc, so reverse if ascending.
     // For name: default is asc, so reverse if not ascending.
-    if sort_by_count && sort_ascending {
-        entries.reverse();
-    } else if !sort_by_count && !sort_ascending {
+    if sort_by_count == sort_ascending {
         entries.reverse();
     }
     entries.into_iter().map(|e| (e.name, e.is_eagle_required, e.count)).collect()
@@ -54,16 +54,16 @@ pub fn render(frame: &mut Frame, app: &mut App, area: Rect) {
         .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
         .split(area);
 
-    app.layout_areas.left_panel = chunks[0];
-    app.layout_areas.right_panel = chunks[1];
+    app.ui.layout_areas.left_panel = chunks[0];
+    app.ui.layout_areas.right_panel = chunks[1];
 
     render_badge_list(frame, app, chunks[0]);
     render_scout_list(frame, app, chunks[1]);
 }
 
 fn render_badge_list(frame: &mut Frame, app: &mut App, area: Rect) {
-    let badge_list = get_badge_list(&app.youth, &app.all_youth_badges, app.badges_sort_by_count, app.badges_sort_ascending);
-    let focused = matches!(app.focus, Focus::List);
+    let badge_list = get_badge_list(&app.store.youth, &app.store.all_youth_badges, app.badges_sort_by_count, app.badges_sort_ascending);
+    let focused = matches!(app.ui.focus, Focus::List);
 
     // Sort indicators for column headers
     let arrow = if app.badges_sort_ascending { " ▲" } else { " ▼" };
@@ -86,7 +86,7 @@ fn render_badge_list(frame: &mut Frame, app: &mut App, area: Rect) {
         ])]
     } else {
         badge_list.iter().enumerate().map(|(i, (name, is_eagle, count))| {
-            let style = if i == app.badges_selection {
+            let style = if i == app.badges_selection.index {
                 styles::selected_style()
             } else {
                 styles::list_item_style()
@@ -113,28 +113,30 @@ fn render_badge_list(frame: &mut Frame, app: &mut App, area: Rect) {
     let sort_help = "[n]ame [c]ount";
     let title = format!(" Badges ({}) - {} ", badge_list.len(), sort_help);
 
+    let range = row_range_label(app.ui.left_table_state.offset(), app.badges_selection.index, area, rows.len());
     let table = Table::new(rows, widths)
         .header(header)
         .block(
             Block::default()
                 .title(title)
+                .title_top(Line::from(range).right_aligned())
                 .title_style(styles::muted_style())
                 .borders(Borders::ALL)
                 .border_style(styles::border_style(focused))
         )
         .row_highlight_style(styles::selected_style());
 
-    app.left_table_state.select(Some(app.badges_selection));
-    frame.render_stateful_widget(table, area, &mut app.left_table_state);
+    app.ui.left_table_state.select(Some(app.badges_selection.index));
+    frame.render_stateful_widget(table, area, &mut app.ui.left_table_state);
 }
 
 fn render_scout_list(frame: &mut Frame, app: &mut App, area: Rect) {
-    let grouped = get_badges_with_scouts(&app.youth, &app.all_youth_badges);
-    let badge_list = get_badge_list(&app.youth, &app.all_youth_badges, app.badges_sort_by_count, app.badges_sort_ascending);
-    let focused = matches!(app.focus, Focus::Detail);
+    let grouped = get_badges_with_scouts(&app.store.youth, &app.store.all_youth_badges);
+    let badge_list = get_badge_list(&app.store.youth, &app.store.all_youth_badges, app.badges_sort_by_count, app.badges_sort_ascending);
+    let focused = matches!(app.ui.focus, Focus::Detail);
 
     // Get selected badge name from sorted list, then find scouts
-    let selected_badge_name = badge_list.get(app.badges_selection)
+    let selected_badge_name = badge_list.get(app.badges_selection.index)
         .map(|(name, _, _)| name.as_str())
         .unwrap_or("");
 
@@ -190,11 +192,13 @@ fn render_scout_list(frame: &mut Frame, app: &mut App, area: Rect) {
         Constraint::Length(12),
     ];
 
+    let range = row_range_label(app.ui.right_table_state.offset(), app.badges_scout_selection, area, rows.len());
     let table = Table::new(rows, widths)
         .header(header)
         .block(
             Block::default()
                 .title(format!(" {} ({}) ", selected_badge_name, scouts.len()))
+                .title_top(Line::from(range).right_aligned())
                 .title_style(styles::title_style())
                 .borders(Borders::ALL)
                 .border_style(styles::border_style(focused))
@@ -202,17 +206,36 @@ fn render_scout_list(frame: &mut Frame, app: &mut App, area: Rect) {
         .row_highlight_style(styles::selected_style());
 
     if focused {
-        app.right_table_state.select(Some(app.badges_scout_selection));
+        app.ui.right_table_state.select(Some(app.badges_scout_selection));
+    }
+    frame.render_stateful_widget(table, area, &mut app.ui.right_table_state);
+}
+
+/// Workbook shortcut hint for a badge's requirement view.
+pub fn workbook_lines(app: &App, badge_name: &str) -> Vec<Line<'static>> {
+    let saved = app.workbook_pdf(badge_name).exists();
+    let mut spans = vec![
+        Span::styled("Workbook: ", styles::muted_style()),
+        Span::styled("[w]", styles::help_key_style()),
+        Span::styled(" open  ", styles::muted_style()),
+    ];
+    if saved {
+        spans.push(Span::styled("(saved offline)  ", styles::success_style()));
+    } else {
+        spans.push(Span::styled("[W]", styles::help_key_style()));
+        spans.push(Span::styled(" save offline  ", styles::muted_style()));
     }
-    frame.render_stateful_widget(table, area, &mut app.right_table_state);
+    spans.push(Span::styled("[i]", styles::help_key_style()));
+    spans.push(Span::styled(" badge page", styles::muted_style()));
+    vec![Line::from(spans)]
 }
 
 fn render_requirements_view(frame: &mut Frame, app: &mut App, area: Rect, focused: bool) {
-    let grouped = get_badges_with_scouts(&app.youth, &app.all_youth_badges);
-    let badge_list = get_badge_list(&app.youth, &app.all_youth_badges, app.badges_sort_by_count, app.badges_sort_ascending);
+    let grouped = get_badges_with_scouts(&app.store.youth, &app.store.all_youth_badges);
+    let badge_list = get_badge_list(&app.store.youth, &app.store.all_youth_badges, app.badges_sort_by_count, app.badges_sort_ascending);
 
     // Get selected badge info from sorted list
-    let selected_badge_name = badge_list.get(app.badges_selection)
+    let selected_badge_name = badge_list.get(app.badges_selection.index)
         .map(|(name, _, _)| name.as_str())
         .unwrap_or("");
 
@@ -234,6 +257,7 @@ fn render_requirements_view(frame: &mut Frame, app: &mut App, area: Rect, focuse
         ]));
     }
     lines.push(Line::from(Span::styled("Press Esc to go back", styles::muted_style())));
+    lines.extend(workbook_lines(app, selected_badge_name));
     lines.push(Line::from(""));
 
     if app.selected_badge_requirements.is_empty() {
@@ -260,10 +284,14 @@ fn render_requirements_view(frame: &mut Frame, app: &mut App, area: Rect, focuse
         lines.push(Line::from(""));
 
         // Show requirements
+        let tree = RequirementTree::build(&app.selected_badge_requirements);
+        let completed = |i: usize| app.selected_badge_requirements[i].is_completed();
         for (i, req) in app.selected_badge_requirements.iter().enumerate() {
             let is_selected = i == app.badges_requirement_selection;
-            let check = if req.is_completed() { "✓" } else { "○" };
-            let check_style = if req.is_completed() { styles::success_style() } else { styles::muted_style() };
+            let accommodation = shown_accommodation(app, AdvancementView::MeritBadges, &tree.node(i).number);
+            let row = RequirementRow::new(&tree, i, &completed).accommodated(accommodation);
+            let check = row.check;
+            let check_style = if row.done { styles::success_style() } else { styles::muted_style() };
 
             let req_num = req.number();
             // prefix(2) + check(1) + space(1) + num(5) + margin(2) + borders(2) = 13 chars overhead
@@ -273,21 +301,23 @@ fn render_requirements_view(frame: &mut Frame, app: &mut App, area: Rect, focuse
             let prefix = if is_selected { "▶ " } else { "  " };
             let text_style = if is_selected { styles::selected_style() } else { styles::list_item_style() };
 
-            let wrapped = wrap_text(&clean_text, text_width);
+            let wrapped = wrap_text(&(clean_text + &row.rollup), text_width.saturating_sub(row.indent.len()));
             lines.push(Line::from(vec![
                 Span::raw(prefix),
+                Span::raw(row.indent.clone()),
                 Span::styled(check, check_style),
                 Span::raw(" "),
                 Span::styled(format!("{:<5}", req_num), styles::highlight_style()),
                 Span::styled(wrapped[0].clone(), text_style),
             ]));
-            let indent = " ".repeat(9);
+            let indent = " ".repeat(9) + &row.indent;
             for wrap_line in &wrapped[1..] {
                 lines.push(Line::from(vec![
                     Span::raw(indent.clone()),
                     Span::styled(wrap_line.clone(), text_style),
                 ]));
             }
+            lines.extend(accommodation_lines(app, accommodation, false, indent.len()));
 
             // Show completion date if completed and selected
             if is_selected && req.is_completed() {
@@ -0,0 +1,213 @@
+//! Eagle tab - how far each scout is from Eagle.
+//!
+//! The left panel lists scouts, closest to Eagle first by default; the right
+//! panel shows what the selected scout still needs.
+
+use chrono::{Local, NaiveDate};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, Wrap},
+    Frame,
+};
+
+use crate::app::App;
+use crate::ui::state::Focus;
+use crate::ui::styles;
+use crate::ui::tabs::row_range_label;
+use trailcache_core::models::{EagleProgress, EagleProject, EagleSortColumn, EAGLE_POR_DAYS, EAGLE_TOTAL_BADGES};
+
+/// Days before the 18th birthday when the deadline is shown as urgent
+const DEADLINE_WARNING_DAYS: i64 = 180;
+
+pub fn render(frame: &mut Frame, app: &mut App, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(area);
+
+    app.ui.layout_areas.left_panel = chunks[0];
+    app.ui.layout_areas.right_panel = chunks[1];
+
+    let rows = app.eagle_rows();
+    app.eagle_selection.clamp(rows.len());
+    let today = Local::now().date_naive();
+
+    render_scout_list(frame, app, chunks[0], &rows, today);
+    let selected = rows.get(app.eagle_selection.index);
+    let project = selected.and_then(|p| app.eagle_projects.projects.get(p.user_id));
+    render_detail(frame, selected, project, chunks[1], today);
+}
+
+fn render_scout_list(frame: &mut Frame, app: &mut App, area: Rect, progress: &[EagleProgress], today: NaiveDate) {
+    let focused = matches!(app.ui.focus, Focus::List);
+
+    let arrow = if app.eagle_sort_ascending { " ▲" } else { " ▼" };
+    let indicator = |column: EagleSortColumn| if app.eagle_sort_column == column { arrow } else { "" };
+
+    let header = Row::new([
+        Cell::from(format!("Name{}", indicator(EagleSortColumn::Name))),
+        Cell::from(format!("Rank{}", indicator(EagleSortColumn::Closest))),
+        Cell::from(format!("Badges{}", indicator(EagleSortColumn::Badges))),
+        Cell::from("Req"),
+        Cell::from("POR"),
+        Cell::from(format!("Turns 18{}", indicator(EagleSortColumn::Deadline))),
+    ])
+    .style(styles::title_style())
+    .height(1);
+
+    let rows: Vec<Row> = if progress.is_empty() {
+        vec![Row::new(vec![Cell::from(Span::styled("No scouts", styles::muted_style()))])]
+    } else {
+        progress
+            .iter()
+            .enumerate()
+            .map(|(i, p)| {
+                let style = if i == app.eagle_selection.index {
+                    styles::selected_style()
+                } else {
+                    styles::list_item_style()
+                };
+                let (deadline, deadline_style) = deadline_cell(p, today);
+                Row::new(vec![
+                    Cell::from(p.name.clone()),
+                    Cell::from(p.rank.display_name()),
+                    Cell::from(format!("{:>2}/{}", p.badges_earned.min(EAGLE_TOTAL_BADGES), EAGLE_TOTAL_BADGES)),
+                    Cell::from(format!("{:>3}", p.required_missing.len())),
+                    Cell::from(format!("{:>3}", p.por_days)),
+                    Cell::from(Span::styled(deadline, deadline_style)),
+                ])
+                .style(style)
+            })
+            .collect()
+    };
+
+    let widths = [
+        Constraint::Fill(1),
+        Constraint::Length(12),
+        Constraint::Length(8),
+        Constraint::Length(4),
+        Constraint::Length(4),
+        Constraint::Length(12),
+    ];
+
+    let title = format!(" Eagle ({}) - [c]losest [n]ame [b]adges [d]eadline [Enter] project ", progress.len());
+    let range = row_range_label(app.ui.left_table_state.offset(), app.eagle_selection.index, area, rows.len());
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(
+            Block::default()
+                .title(title)
+                .title_top(Line::from(range).right_aligned())
+                .title_style(styles::muted_style())
+                .borders(Borders::ALL)
+                .border_style(styles::border_style(focused)),
+        )
+        .row_highlight_style(styles::selected_style());
+
+    app.ui.left_table_state.select(Some(app.eagle_selection.index));
+    frame.render_stateful_widget(table, area, &mut app.ui.left_table_state);
+}
+
+/// Time left before the 18th birthday, in the error style when it's close.
+fn deadline_cell(p: &EagleProgress, today: NaiveDate) -> (String, ratatui::style::Style) {
+    match p.days_to_deadline(today) {
+        _ if p.is_eagle() => ("Eagle".to_string(), styles::success_style()),
+        Some(days) if days < 0 => ("aged out".to_string(), styles::muted_style()),
+        Some(days) if days <= DEADLINE_WARNING_DAYS => (format!("{} days", days), styles::error_style()),
+        Some(days) => (format!("{} mo", days / 30), styles::list_item_style()),
+        None => ("?".to_string(), styles::muted_style()),
+    }
+}
+
+fn render_detail(frame: &mut Frame, progress: Option<&EagleProgress>, project: Option<&EagleProject>, area: Rect, today: NaiveDate) {
+    let block = Block::default()
+        .title(" Still Needed ")
+        .title_style(styles::muted_style())
+        .borders(Borders::ALL)
+        .border_style(styles::border_style(false));
+
+    let Some(p) = progress else {
+        let paragraph = Paragraph::new(Span::styled("No scout selected", styles::muted_style())).block(block);
+        frame.render_widget(paragraph, area);
+        return;
+    };
+
+    let heading = |text: &str| Line::from(Span::styled(text.to_string(), styles::title_style()));
+    let item = |text: String| Line::from(vec![Span::styled("  • ", styles::muted_style()), Span::raw(text)]);
+
+    let mut lines = vec![Line::from(Span::styled(p.name.clone(), styles::highlight_style())), Line::from("")];
+
+    if p.is_eagle() {
+        lines.push(Line::from(Span::styled("Eagle Scout", styles::success_style())));
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+        return;
+    }
+
+    lines.push(heading("Ranks"));
+    let ranks: Vec<&str> = p.ranks_remaining.iter().map(|r| r.display_name()).collect();
+    lines.push(item(ranks.join(", ")));
+    if let Some(percent) = p.eagle_percent {
+        lines.push(item(format!("Eagle requirements {}% done", percent)));
+    }
+    lines.push(Line::from(""));
+
+    lines.push(heading(&format!(
+        "Merit badges: {} of {} ({} to go)",
+        p.badges_earned.min(EAGLE_TOTAL_BADGES),
+        EAGLE_TOTAL_BADGES,
+        p.badges_remaining()
+    )));
+    if p.required_missing.is_empty() {
+        lines.push(Line::from(Span::styled("  All Eagle-required badges done", styles::success_style())));
+    } else {
+        lines.extend(p.required_missing.iter().map(|name| item(name.clone())));
+    }
+    lines.push(Line::from(""));
+
+    lines.push(heading("Position of responsibility"));
+    match p.life_on {
+        Some(life_on) => lines.push(item(format!(
+            "{} of {} days since Life ({}), {} to go",
+            p.por_days,
+            EAGLE_POR_DAYS,
+            life_on.format("%b %d, %Y"),
+            p.por_days_remaining()
+        ))),
+        None => lines.push(item(format!("{} days as a Life Scout, after earning Life", EAGLE_POR_DAYS))),
+    }
+    lines.push(Line::from(""));
+
+    lines.push(heading("Service hours"));
+    match p.service_hours_remaining() {
+        0 => lines.push(item("Star and Life hours done; Eagle project to come".to_string())),
+        hours => lines.push(item(format!("{} hours for Star and Life, plus the Eagle project", hours))),
+    }
+    lines.push(Line::from(""));
+
+    lines.push(heading("Deadline"));
+    let (deadline, style) = deadline_cell(p, today);
+    let line = match p.deadline {
+        Some(date) => format!("  Turns 18 on {} ({})", date.format("%b %d, %Y"), deadline),
+        None => "  Birth date unknown".to_string(),
+    };
+    lines.push(Line::from(Span::styled(line, style)));
+    lines.push(Line::from(""));
+
+    lines.push(heading("Eagle project"));
+    match project {
+        Some(project) => {
+            let title = if project.title.is_empty() { "Untitled" } else { &project.title };
+            lines.push(item(format!("{} - {}", title, project.stage.name())));
+            lines.push(item(format!("{} hours logged, {} workdays", project.total_hours(), project.workdays.len())));
+            match project.paperwork_missing().count() {
+                0 => lines.push(Line::from(Span::styled("  Paperwork complete", styles::success_style()))),
+                n => lines.push(item(format!("{} paperwork items to go", n))),
+            }
+        }
+        None => lines.push(Line::from(Span::styled("  Press Enter to start tracking the project", styles::muted_style()))),
+    }
+
+    let paragraph = Paragraph::new(lines).block(block).wrap(Wrap { trim: false });
+    frame.render_widget(paragraph, area);
+}
@@ -1,14 +1,17 @@
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
+    style::Style,
     text::{Line, Span},
     widgets::{Block, Borders, Cell, Paragraph, Row, Table},
     Frame,
 };
 
-use crate::app::{App, EventDetailView, Focus};
-use trailcache_core::models::RsvpStatus;
+use crate::app::App;
+use crate::ui::state::{EventDetailView, EventListView, Focus};
+use trailcache_core::models::{DebriefKind, RsvpStatus};
 use trailcache_core::utils::{strip_html, wrap_text};
 use crate::ui::styles;
+use crate::ui::tabs::row_range_label;
 
 pub fn render(frame: &mut Frame, app: &mut App, area: Rect) {
     let chunks = Layout::default()
@@ -16,15 +19,15 @@ pub fn render(frame: &mut Frame, app: &mut App, area: Rect) {
         .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
         .split(area);
 
-    app.layout_areas.left_panel = chunks[0];
-    app.layout_areas.right_panel = chunks[1];
+    app.ui.layout_areas.left_panel = chunks[0];
+    app.ui.layout_areas.right_panel = chunks[1];
 
     render_event_list(frame, app, chunks[0]);
     render_event_detail(frame, app, chunks[1]);
 }
 
 fn render_event_list(frame: &mut Frame, app: &mut App, area: Rect) {
-    let focused = matches!(app.focus, Focus::List);
+    let focused = matches!(app.ui.focus, Focus::List);
 
     // Header row
     let header_cells = [
@@ -32,6 +35,7 @@ fn render_event_list(frame: &mut Frame, app: &mut App, area: Rect) {
         Cell::from("Date"),
         Cell::from("Location"),
         Cell::from("Type"),
+        Cell::from("Going"),
     ];
     let header = Row::new(header_cells)
         .style(styles::title_style())
@@ -44,7 +48,7 @@ fn render_event_list(frame: &mut Frame, app: &mut App, area: Rect) {
         .iter()
         .enumerate()
         .map(|(i, event)| {
-            let style = if i == app.event_selection {
+            let style = if i == app.event_selection.index {
                 styles::selected_style()
             } else {
                 styles::list_item_style()
@@ -54,47 +58,67 @@ fn render_event_list(frame: &mut Frame, app: &mut App, area: Rect) {
             let date = event.formatted_date();
             let location = event.location.clone().unwrap_or_else(|| "-".to_string());
             let event_type = event.derived_type().to_string();
+            let going = if event.rsvp {
+                app.event_rsvp_stats(event).headcount().to_string()
+            } else {
+                "-".to_string()
+            };
 
+            let query = &app.ui.search;
             Row::new(vec![
-                Cell::from(name),
+                Cell::from(styles::highlight_matches(name, query, Style::default())),
                 Cell::from(date),
-                Cell::from(location),
-                Cell::from(event_type),
+                Cell::from(styles::highlight_matches(location, query, Style::default())),
+                Cell::from(styles::highlight_matches(event_type, query, Style::default())),
+                Cell::from(going),
             ]).style(style)
         })
         .collect();
+    let match_count = sorted_events.len();
     drop(sorted_events);
 
-    // Column widths: Name (38%), Date, Location, Type
+    // Column widths: Name (38%), Date, Location, Type, Going
     let widths = [
         Constraint::Percentage(38),  // Name - same as Scouts/Adults tabs
         Constraint::Length(20),      // Date: "Jan 26, 2026" + generous padding
         Constraint::Fill(1),         // Location
         Constraint::Length(12),      // Type
+        Constraint::Length(6),       // Going: headcount including guests
     ];
 
-    let sort_help = "[n]ame [d]ate [l]ocation [t]ype";
-    let title = format!(" Events ({}) - {} ", app.events.len(), sort_help);
+    let sort_help = "[n]ame [d]ate [l]ocation [t]ype [v]iew";
+    let label = app.ui.event_list_view.label();
+    let title = if app.ui.search.is_empty() {
+        format!(" {} ({}) - {} ", label, match_count, sort_help)
+    } else {
+        let total = match app.ui.event_list_view {
+            EventListView::Archive => app.store.event_archive.events.len(),
+            EventListView::Upcoming | EventListView::Past => app.store.events.len(),
+        };
+        format!(" {} ({}) ", label, app.search_summary(match_count, total))
+    };
 
+    let range = row_range_label(app.ui.left_table_state.offset(), app.event_selection.index, area, rows.len());
     let table = Table::new(rows, widths)
         .header(header)
         .block(
             Block::default()
                 .title(title)
+                .title_top(Line::from(range).right_aligned())
                 .title_style(styles::muted_style())
                 .borders(Borders::ALL)
                 .border_style(styles::border_style(focused))
         )
         .row_highlight_style(styles::selected_style());
 
-    app.left_table_state.select(Some(app.event_selection));
-    frame.render_stateful_widget(table, area, &mut app.left_table_state);
+    app.ui.left_table_state.select(Some(app.event_selection.index));
+    frame.render_stateful_widget(table, area, &mut app.ui.left_table_state);
 }
 
 fn render_event_detail(frame: &mut Frame, app: &mut App, area: Rect) {
-    let focused = matches!(app.focus, Focus::Detail);
+    let focused = matches!(app.ui.focus, Focus::Detail);
 
-    match app.event_detail_view {
+    match app.ui.event_detail_view {
         EventDetailView::Details => render_details_view(frame, app, area, focused),
         EventDetailView::Rsvp => render_rsvp_view(frame, app, area, focused),
     }
@@ -102,16 +126,13 @@ fn render_event_detail(frame: &mut Frame, app: &mut App, area: Rect) {
 
 fn render_details_view(frame: &mut Frame, app: &mut App, area: Rect, focused: bool) {
     let sorted_events = app.get_sorted_events();
-    let selected = sorted_events.get(app.event_selection).copied();
+    let selected = sorted_events.get(app.event_selection.index).copied();
 
     let content = match selected {
         Some(event) => {
             let mut lines = vec![
                 // Event name
-                Line::from(Span::styled(
-                    &event.name,
-                    styles::title_style(),
-                )),
+                styles::highlight_matches(event.name.clone(), &app.ui.search, styles::title_style()),
                 Line::from(""),
                 // Start date/time
                 Line::from(vec![
@@ -128,10 +149,9 @@ fn render_details_view(frame: &mut Frame, app: &mut App, area: Rect, focused: bo
             // Location
             if let Some(ref location) = event.location {
                 if !location.is_empty() {
-                    lines.push(Line::from(vec![
-                        Span::styled("Location: ", styles::muted_style()),
-                        Span::raw(location.clone()),
-                    ]));
+                    let mut location_line = vec![Span::styled("Location: ", styles::muted_style())];
+                    location_line.extend(styles::highlight_matches(location.clone(), &app.ui.search, Style::default()).spans);
+                    lines.push(Line::from(location_line));
                 }
             }
 
@@ -204,6 +224,90 @@ fn render_details_view(frame: &mut Frame, app: &mut App, area: Rect, focused: bo
                 )));
             }
 
+            // Families who haven't signed this year's forms
+            let missing = app.event_missing_consents(event);
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled("Consent Forms", styles::highlight_style())));
+            if missing == 0 {
+                lines.push(Line::from(Span::styled("  All participants signed", styles::success_style())));
+            } else {
+                lines.push(Line::from(vec![
+                    Span::styled(format!("  {} scouts missing forms", missing), styles::error_style()),
+                    Span::styled("  [E] emergency sheet", styles::muted_style()),
+                ]));
+            }
+
+            // Pre-activity checklist progress, for upcoming events
+            if let Some((done, total)) = app.event_activity_plan_progress(event) {
+                lines.push(Line::from(""));
+                lines.push(Line::from(vec![
+                    Span::styled("Checklist: ", styles::highlight_style()),
+                    Span::styled(
+                        format!("{}/{} done", done, total),
+                        if done == total { styles::success_style() } else { styles::error_style() },
+                    ),
+                    Span::styled("  [C] open", styles::muted_style()),
+                ]));
+            }
+
+            // Adult leadership coverage, for upcoming events
+            if let Some(coverage) = app.event_leader_coverage(event) {
+                lines.push(Line::from(""));
+                lines.push(Line::from(Span::styled("Leader Coverage", styles::highlight_style())));
+                if coverage.is_covered() {
+                    lines.push(Line::from(Span::styled(
+                        format!("  Covered: {}", coverage.adults.join(", ")),
+                        styles::success_style(),
+                    )));
+                }
+                for gap in &coverage.gaps {
+                    lines.push(Line::from(Span::styled(format!("  {}", gap.describe()), styles::error_style())));
+                }
+            }
+
+            // Patrol duty roster
+            let roster = app.event_duty_roster(event);
+            if !roster.is_empty() {
+                lines.push(Line::from(""));
+                lines.push(Line::from(Span::styled("Duty Roster", styles::highlight_style())));
+                for (duty, patrol) in roster {
+                    lines.push(Line::from(vec![
+                        Span::styled(format!("  {:<16}", duty), styles::muted_style()),
+                        Span::raw(patrol),
+                    ]));
+                }
+            }
+
+            // Local debrief notes, and the note being typed
+            let debrief = app.debriefs.for_event(event.id);
+            if debrief.is_some() || app.debrief_input.is_some() {
+                lines.push(Line::from(""));
+                lines.push(Line::from(Span::styled("Debrief", styles::highlight_style())));
+            }
+            for kind in DebriefKind::ALL {
+                for note in debrief.into_iter().flat_map(|d| d.notes_of(kind)) {
+                    let style = if kind == DebriefKind::Incident { styles::error_style() } else { styles::muted_style() };
+                    lines.push(Line::from(vec![
+                        Span::styled(format!("  {:<11}", kind.label()), style),
+                        Span::raw(note.text.clone()),
+                    ]));
+                }
+            }
+            if let Some((kind, text)) = &app.debrief_input {
+                let width = (area.width as usize).saturating_sub(20);
+                let shown: String = text.chars().skip(text.chars().count().saturating_sub(width)).collect();
+                lines.push(Line::from(vec![
+                    Span::styled(format!("  {:<11}", kind.label()), styles::help_key_style()),
+                    Span::raw(format!("{}_", shown)),
+                ]));
+                lines.push(Line::from(Span::styled(
+                    "  Enter save  Tab kind  Esc cancel",
+                    styles::muted_style(),
+                )));
+            } else if debrief.is_some() {
+                lines.push(Line::from(Span::styled("  [B] add note  [X] remove last", styles::muted_style())));
+            }
+
             lines
         }
         None => vec![Line::from(Span::styled(
@@ -222,7 +326,7 @@ fn render_details_view(frame: &mut Frame, app: &mut App, area: Rect, focused: bo
 
 fn render_rsvp_view(frame: &mut Frame, app: &mut App, area: Rect, focused: bool) {
     let sorted_events = app.get_sorted_events();
-    let selected = sorted_events.get(app.event_selection).copied();
+    let selected = sorted_events.get(app.event_selection.index).copied();
 
     let content = match selected {
         Some(event) => {
@@ -239,7 +343,33 @@ fn render_rsvp_view(frame: &mut Frame, app: &mut App, area: Rect, focused: bool)
             )));
             lines.push(Line::from(""));
 
-            // Filter to only Yes and No (exclude NoResponse)
+            // Summary totals so leaders don't have to count rows
+            let stats = app.event_rsvp_stats(event);
+            lines.push(Line::from(vec![
+                Span::styled("Headcount: ", styles::highlight_style()),
+                Span::styled(format!("{}", stats.headcount()), styles::success_style()),
+                Span::styled(
+                    format!(
+                        " ({} scouts, {} adults, {} guests)",
+                        stats.youth.going, stats.adults.going, stats.guests
+                    ),
+                    styles::muted_style(),
+                ),
+            ]));
+            for (label, tally) in [("  Scouts: ", stats.youth), ("  Adults: ", stats.adults)] {
+                lines.push(Line::from(vec![
+                    Span::styled(label, styles::muted_style()),
+                    Span::styled(format!("{}", tally.going), styles::success_style()),
+                    Span::styled(" yes, ", styles::muted_style()),
+                    Span::styled(format!("{}", tally.not_going), styles::error_style()),
+                    Span::styled(" no, ", styles::muted_style()),
+                    Span::styled(format!("{}", tally.maybe), styles::highlight_style()),
+                    Span::styled(format!(" maybe, {} no response", tally.no_response), styles::muted_style()),
+                ]));
+            }
+            lines.push(Line::from(""));
+
+            // Filter to responses only (exclude NoResponse)
             let (adults, scouts) = event.respondents();
 
             if adults.is_empty() && scouts.is_empty() {
@@ -260,6 +390,7 @@ fn render_rsvp_view(frame: &mut Frame, app: &mut App, area: Rect, focused: bool)
                         let (status_char, status_style) = match guest.status() {
                             RsvpStatus::Going => ("Y", styles::success_style()),
                             RsvpStatus::NotGoing => ("N", styles::error_style()),
+                            RsvpStatus::Maybe => ("?", styles::highlight_style()),
                             _ => ("-", styles::muted_style()),
                         };
 
@@ -282,6 +413,7 @@ fn render_rsvp_view(frame: &mut Frame, app: &mut App, area: Rect, focused: bool)
                         let (status_char, status_style) = match guest.status() {
                             RsvpStatus::Going => ("Y", styles::success_style()),
                             RsvpStatus::NotGoing => ("N", styles::error_style()),
+                            RsvpStatus::Maybe => ("?", styles::highlight_style()),
                             _ => ("-", styles::muted_style()),
                         };
 
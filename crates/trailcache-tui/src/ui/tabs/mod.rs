@@ -1,6 +1,122 @@
 pub mod advancement;
+pub mod attendance;
 pub mod badges;
+pub mod eagle;
 pub mod events;
+pub mod plugin;
 pub mod ranks;
 pub mod roster;
 pub mod unit;
+
+use ratatui::layout::Rect;
+use ratatui::text::{Line, Span};
+use trailcache_core::models::{Accommodation, RequirementTree};
+
+use crate::app::App;
+use crate::ui::state::AdvancementView;
+use crate::ui::styles;
+
+/// Rows taken by a table's borders and header line.
+pub const TABLE_CHROME_ROWS: u16 = 3;
+
+/// " rows X–Y of N " for a table title, or an empty string when every row fits.
+///
+/// `offset` is the table state's offset from the previous draw. It's adjusted
+/// the same way ratatui scrolls, keeping the selected row on screen, so the
+/// label matches what's about to be drawn.
+pub fn row_range_label(offset: usize, selected: usize, area: Rect, total: usize) -> String {
+    let visible = area.height.saturating_sub(TABLE_CHROME_ROWS) as usize;
+    if visible == 0 || total <= visible {
+        return String::new();
+    }
+    let offset = if selected < offset {
+        selected
+    } else if selected >= offset + visible {
+        selected + 1 - visible
+    } else {
+        offset
+    }
+    .min(total - visible);
+    format!(" rows {}–{} of {} ", offset + 1, offset + visible, total)
+}
+
+/// How a row in a requirements list is drawn given its place in the tree.
+pub struct RequirementRow {
+    /// Two spaces per level of nesting
+    pub indent: String,
+    pub check: &'static str,
+    /// Complete itself or through all of its sub-requirements
+    pub done: bool,
+    /// " (x/y)" after a parent's text while some sub-requirements remain
+    pub rollup: String,
+}
+
+impl RequirementRow {
+    pub fn new(tree: &RequirementTree, index: usize, completed: &dyn Fn(usize) -> bool) -> Self {
+        let done = tree.is_done(index, completed);
+        let (check, rollup) = match tree.rollup(index, completed) {
+            _ if done => ("✓", String::new()),
+            Some((0, total)) => ("○", format!(" (0/{})", total)),
+            Some((finished, total)) => ("◐", format!(" ({}/{})", finished, total)),
+            None => ("○", String::new()),
+        };
+        Self { indent: "  ".repeat(tree.depth(index)), check, done, rollup }
+    }
+
+    /// An open requirement with an approved alternate or accommodation gets
+    /// its own mark so it doesn't read as simply not done.
+    pub fn accommodated(mut self, accommodation: Option<&Accommodation>) -> Self {
+        if accommodation.is_some() && !self.done {
+            self.check = "◇";
+        }
+        self
+    }
+}
+
+/// The accommodation recorded for requirement `number` of the rank or badge
+/// loaded for `view`, unless personal information is hidden.
+pub fn shown_accommodation<'a>(app: &'a App, view: AdvancementView, number: &str) -> Option<&'a Accommodation> {
+    if app.personal_info_hidden().is_some() {
+        return None;
+    }
+    app.requirement_accommodation(view, number)
+}
+
+/// The line under a requirement for its accommodation, or for the note
+/// being typed when `editing`.
+pub fn accommodation_lines(app: &App, accommodation: Option<&Accommodation>, editing: bool, indent: usize) -> Vec<Line<'static>> {
+    let pad = Span::raw(" ".repeat(indent));
+    if let (true, Some(draft)) = (editing, &app.accommodation_input) {
+        return vec![Line::from(vec![
+            pad,
+            Span::styled("Alternate/accommodation (blank to clear): ", styles::highlight_style()),
+            Span::styled(format!("{}_", draft.text), styles::list_item_style()),
+        ])];
+    }
+    accommodation
+        .map(|a| {
+            Line::from(vec![
+                pad,
+                Span::styled("Approved: ", styles::muted_style()),
+                Span::styled(a.note.clone(), styles::highlight_style()),
+            ])
+        })
+        .into_iter()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_row_range_label_follows_selection() {
+        let area = Rect::new(0, 0, 40, 13); // 10 visible rows
+        assert_eq!(row_range_label(0, 3, area, 8), "", "everything fits");
+        assert_eq!(row_range_label(0, 3, area, 45), " rows 1–10 of 45 ");
+        assert_eq!(row_range_label(0, 14, area, 45), " rows 6–15 of 45 ");
+        assert_eq!(row_range_label(20, 12, area, 45), " rows 13–22 of 45 ");
+        assert_eq!(row_range_label(40, 44, area, 45), " rows 36–45 of 45 ");
+        assert_eq!(row_range_label(0, 0, Rect::new(0, 0, 40, 3), 45), "", "no room for rows");
+    }
+}
@@ -5,9 +5,10 @@ use ratatui::{
     Frame,
 };
 
-use crate::app::{App, Focus};
+use crate::app::App;
+use crate::ui::state::Focus;
 use crate::ui::styles;
-use trailcache_core::utils::format::format_phone;
+use trailcache_core::utils::format_phone;
 use trailcache_core::utils::truncate;
 
 pub fn render(frame: &mut Frame, app: &mut App, area: Rect) {
@@ -21,7 +22,7 @@ pub fn render(frame: &mut Frame, app: &mut App, area: Rect) {
 }
 
 fn render_patrol_list(frame: &mut Frame, app: &mut App, area: Rect) {
-    let items: Vec<ListItem> = app
+    let items: Vec<ListItem> = app.store
         .patrols
         .iter()
         .enumerate()
@@ -33,7 +34,7 @@ fn render_patrol_list(frame: &mut Frame, app: &mut App, area: Rect) {
                 count
             ));
 
-            let style = if i == app.adults_selection {
+            let style = if i == app.adults_selection.index {
                 styles::selected_style()
             } else {
                 styles::list_item_style()
@@ -43,9 +44,9 @@ fn render_patrol_list(frame: &mut Frame, app: &mut App, area: Rect) {
         })
         .collect();
 
-    let focused = matches!(app.focus, Focus::List);
+    let focused = matches!(app.ui.focus, Focus::List);
     let block = Block::default()
-        .title(format!(" Patrols ({}) ", app.patrols.len()))
+        .title(format!(" Patrols ({}) ", app.store.patrols.len()))
         .title_style(styles::title_style())
         .borders(Borders::ALL)
         .border_style(styles::border_style(focused));
@@ -53,15 +54,15 @@ fn render_patrol_list(frame: &mut Frame, app: &mut App, area: Rect) {
     let list = List::new(items).block(block);
 
     let mut state = ListState::default();
-    state.select(Some(app.adults_selection));
+    state.select(Some(app.adults_selection.index));
 
     frame.render_stateful_widget(list, area, &mut state);
 }
 
 fn render_patrol_members(frame: &mut Frame, app: &mut App, area: Rect) {
-    let selected_patrol = app.patrols.get(app.adults_selection);
+    let selected_patrol = app.store.patrols.get(app.adults_selection.index);
 
-    let focused = matches!(app.focus, Focus::Detail);
+    let focused = matches!(app.ui.focus, Focus::Detail);
 
     let (title, content) = match selected_patrol {
         Some(patrol) => {
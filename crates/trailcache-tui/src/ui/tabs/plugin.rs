@@ -0,0 +1,113 @@
+//! Extra tabs supplied by plugins.
+//!
+//! A plugin implements `TabPlugin` and is listed in `BUILTIN_PLUGINS`,
+//! normally behind a cargo feature so optional tabs (finance, inventory)
+//! ship only when enabled:
+//!
+//! ```ignore
+//! #[cfg(feature = "inventory")]
+//! || Box::new(inventory::InventoryTab::default()),
+//! ```
+//!
+//! Plugin tabs follow Attendance in the tab bar on number key 9. They get the
+//! keys the global handlers don't use, and draw into the same content area
+//! as the built-in tabs.
+
+use crossterm::event::KeyEvent;
+use ratatui::{layout::Rect, Frame};
+
+use crate::app::App;
+
+/// Most plugin tabs, on number key 9
+pub const MAX_PLUGIN_TABS: usize = 1;
+
+/// A tab added by a plugin.
+pub trait TabPlugin {
+    /// Label in the tab bar
+    fn title(&self) -> &str;
+
+    /// Draw the tab into the content area.
+    fn render(&mut self, frame: &mut Frame, app: &App, area: Rect);
+
+    /// Handle a key while the tab is showing. Returns true if it was used.
+    fn handle_input(&mut self, _app: &mut App, _key: KeyEvent) -> bool {
+        false
+    }
+
+    /// Called after fresh data arrives from Scoutbook.
+    fn on_refresh(&mut self, _app: &App) {}
+}
+
+/// Constructors for the plugins compiled into this build
+const BUILTIN_PLUGINS: &[fn() -> Box<dyn TabPlugin>] = &[];
+
+/// The plugin tabs, in tab bar order.
+#[derive(Default)]
+pub struct PluginRegistry {
+    plugins: Vec<Box<dyn TabPlugin>>,
+}
+
+impl PluginRegistry {
+    /// The plugins compiled into this build.
+    pub fn builtin() -> Self {
+        let mut registry = Self::default();
+        for plugin in BUILTIN_PLUGINS {
+            registry.register(plugin());
+        }
+        registry
+    }
+
+    /// Add a tab. Tabs past `MAX_PLUGIN_TABS` are ignored.
+    pub fn register(&mut self, plugin: Box<dyn TabPlugin>) {
+        if self.plugins.len() < MAX_PLUGIN_TABS {
+            self.plugins.push(plugin);
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.plugins.len()
+    }
+
+    pub fn title(&self, index: usize) -> Option<&str> {
+        self.plugins.get(index).map(|p| p.title())
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut dyn TabPlugin> {
+        Some(self.plugins.get_mut(index)?.as_mut())
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Box<dyn TabPlugin>> {
+        self.plugins.iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::state::Tab;
+
+    struct Inventory;
+
+    impl TabPlugin for Inventory {
+        fn title(&self) -> &str {
+            "Inventory"
+        }
+
+        fn render(&mut self, _frame: &mut Frame, _app: &App, _area: Rect) {}
+    }
+
+    #[test]
+    fn test_plugin_tabs_follow_attendance() {
+        let mut registry = PluginRegistry::default();
+        for _ in 0..4 {
+            registry.register(Box::new(Inventory));
+        }
+        assert_eq!(registry.len(), MAX_PLUGIN_TABS);
+        assert_eq!(registry.title(0), Some("Inventory"));
+
+        assert_eq!(Tab::Attendance.next(1), Tab::Plugin(0));
+        assert_eq!(Tab::Plugin(0).next(1), Tab::Scouts);
+        assert_eq!(Tab::Scouts.prev(1), Tab::Plugin(0));
+        assert_eq!(Tab::Plugin(0).prev(1), Tab::Attendance);
+    }
+}
@@ -12,10 +12,12 @@ use ratatui::{
     Frame,
 };
 
-use crate::app::{App, Focus};
-use trailcache_core::models::{format_date, RankProgress, RankRequirement, StatusCategory, Youth};
+use crate::app::App;
+use crate::ui::state::{AdvancementView, Focus};
+use trailcache_core::models::{format_date, RankProgress, RankRequirement, RequirementTree, StatusCategory, Youth};
 use trailcache_core::models::pivot::{group_youth_by_rank, RankGroup, RankGroupEntry};
 use crate::ui::styles;
+use crate::ui::tabs::{accommodation_lines, row_range_label, shown_accommodation, RequirementRow};
 use trailcache_core::utils::{strip_html, wrap_text};
 
 /// Group youth by their current (highest completed) rank.
@@ -39,9 +41,7 @@ pub fn get_rank_list(
     // Core sorts count desc / rank-order asc by default.
     // For count: default is desc, so reverse if ascending.
     // For rank order: default is asc, so reverse if not ascending.
-    if sort_by_count && sort_ascending {
-        entries.reverse();
-    } else if !sort_by_count && !sort_ascending {
+    if sort_by_count == sort_ascending {
         entries.reverse();
     }
     entries.into_iter().map(|e| (e.name, e.count)).collect()
@@ -53,16 +53,16 @@ pub fn render(frame: &mut Frame, app: &mut App, area: Rect) {
         .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
         .split(area);
 
-    app.layout_areas.left_panel = chunks[0];
-    app.layout_areas.right_panel = chunks[1];
+    app.ui.layout_areas.left_panel = chunks[0];
+    app.ui.layout_areas.right_panel = chunks[1];
 
     render_rank_list(frame, app, chunks[0]);
     render_scout_list(frame, app, chunks[1]);
 }
 
 fn render_rank_list(frame: &mut Frame, app: &mut App, area: Rect) {
-    let rank_list = get_rank_list(&app.youth, &app.all_youth_ranks, app.ranks_sort_by_count, app.ranks_sort_ascending);
-    let focused = matches!(app.focus, Focus::List);
+    let rank_list = get_rank_list(&app.store.youth, &app.store.all_youth_ranks, app.ranks_sort_by_count, app.ranks_sort_ascending);
+    let focused = matches!(app.ui.focus, Focus::List);
 
     // Sort indicators for column headers
     let arrow = if app.ranks_sort_ascending { " ▲" } else { " ▼" };
@@ -85,7 +85,7 @@ fn render_rank_list(frame: &mut Frame, app: &mut App, area: Rect) {
         ])]
     } else {
         rank_list.iter().enumerate().map(|(i, (rank, count))| {
-            let style = if i == app.ranks_selection {
+            let style = if i == app.ranks_selection.index {
                 styles::selected_style()
             } else {
                 styles::list_item_style()
@@ -106,28 +106,30 @@ fn render_rank_list(frame: &mut Frame, app: &mut App, area: Rect) {
     let sort_help = "[n]ame [c]ount";
     let title = format!(" Ranks ({}) - {} ", rank_list.len(), sort_help);
 
+    let range = row_range_label(app.ui.left_table_state.offset(), app.ranks_selection.index, area, rows.len());
     let table = Table::new(rows, widths)
         .header(header)
         .block(
             Block::default()
                 .title(title)
+                .title_top(Line::from(range).right_aligned())
                 .title_style(styles::muted_style())
                 .borders(Borders::ALL)
                 .border_style(styles::border_style(focused))
         )
         .row_highlight_style(styles::selected_style());
 
-    app.left_table_state.select(Some(app.ranks_selection));
-    frame.render_stateful_widget(table, area, &mut app.left_table_state);
+    app.ui.left_table_state.select(Some(app.ranks_selection.index));
+    frame.render_stateful_widget(table, area, &mut app.ui.left_table_state);
 }
 
 fn render_scout_list(frame: &mut Frame, app: &mut App, area: Rect) {
-    let grouped = get_ranks_with_scouts(&app.youth, &app.all_youth_ranks);
-    let rank_list = get_rank_list(&app.youth, &app.all_youth_ranks, app.ranks_sort_by_count, app.ranks_sort_ascending);
-    let focused = matches!(app.focus, Focus::Detail);
+    let grouped = get_ranks_with_scouts(&app.store.youth, &app.store.all_youth_ranks);
+    let rank_list = get_rank_list(&app.store.youth, &app.store.all_youth_ranks, app.ranks_sort_by_count, app.ranks_sort_ascending);
+    let focused = matches!(app.ui.focus, Focus::Detail);
 
     // Get selected rank name from sorted list, then find scouts
-    let selected_rank_name = rank_list.get(app.ranks_selection)
+    let selected_rank_name = rank_list.get(app.ranks_selection.index)
         .map(|(name, _)| name.as_str())
         .unwrap_or("");
 
@@ -186,11 +188,13 @@ fn render_scout_list(frame: &mut Frame, app: &mut App, area: Rect) {
         Constraint::Length(12),
     ];
 
+    let range = row_range_label(app.ui.right_table_state.offset(), app.ranks_scout_selection, area, rows.len());
     let table = Table::new(rows, widths)
         .header(header)
         .block(
             Block::default()
                 .title(format!(" {} ({}) ", selected_rank_name, scouts.len()))
+                .title_top(Line::from(range).right_aligned())
                 .title_style(styles::title_style())
                 .borders(Borders::ALL)
                 .border_style(styles::border_style(focused))
@@ -198,17 +202,47 @@ fn render_scout_list(frame: &mut Frame, app: &mut App, area: Rect) {
         .row_highlight_style(styles::selected_style());
 
     if focused {
-        app.right_table_state.select(Some(app.ranks_scout_selection));
+        app.ui.right_table_state.select(Some(app.ranks_scout_selection));
     }
-    frame.render_stateful_widget(table, area, &mut app.right_table_state);
+    frame.render_stateful_widget(table, area, &mut app.ui.right_table_state);
+}
+
+/// Expandable footer listing cross-referenced resources for a rank requirement.
+pub fn crossref_lines(app: &App, rank_name: &str, req_number: &str, indent: usize) -> Vec<Line<'static>> {
+    let notes = app.crossrefs.lookup(rank_name, req_number);
+    if notes.is_empty() {
+        return Vec::new();
+    }
+
+    let pad = " ".repeat(indent);
+    if !app.crossref_expanded {
+        let label = if notes.len() == 1 { "resource" } else { "resources" };
+        return vec![Line::from(vec![
+            Span::raw(pad),
+            Span::styled(format!("→ {} related {} ", notes.len(), label), styles::muted_style()),
+            Span::styled("[x]", styles::help_key_style()),
+            Span::styled(" expand", styles::muted_style()),
+        ])];
+    }
+
+    notes
+        .iter()
+        .map(|note| {
+            Line::from(vec![
+                Span::raw(pad.clone()),
+                Span::styled("→ ", styles::highlight_style()),
+                Span::styled(note.clone(), styles::list_item_style()),
+            ])
+        })
+        .collect()
 }
 
 fn render_requirements_view(frame: &mut Frame, app: &mut App, area: Rect, focused: bool) {
-    let grouped = get_ranks_with_scouts(&app.youth, &app.all_youth_ranks);
-    let rank_list = get_rank_list(&app.youth, &app.all_youth_ranks, app.ranks_sort_by_count, app.ranks_sort_ascending);
+    let grouped = get_ranks_with_scouts(&app.store.youth, &app.store.all_youth_ranks);
+    let rank_list = get_rank_list(&app.store.youth, &app.store.all_youth_ranks, app.ranks_sort_by_count, app.ranks_sort_ascending);
 
     // Get selected rank info from sorted list
-    let selected_rank_name = rank_list.get(app.ranks_selection)
+    let selected_rank_name = rank_list.get(app.ranks_selection.index)
         .map(|(name, _)| name.as_str())
         .unwrap_or("");
 
@@ -229,7 +263,11 @@ fn render_requirements_view(frame: &mut Frame, app: &mut App, area: Rect, focuse
             Span::styled(selected_rank_name, styles::highlight_style()),
         ]));
     }
-    lines.push(Line::from(Span::styled("Press Esc to go back", styles::muted_style())));
+    lines.push(Line::from(vec![
+        Span::styled("Press Esc to go back, ", styles::muted_style()),
+        Span::styled("[c]", styles::help_key_style()),
+        Span::styled(" to mark complete", styles::muted_style()),
+    ]));
     lines.push(Line::from(""));
 
     if app.selected_rank_requirements.is_empty() {
@@ -244,10 +282,14 @@ fn render_requirements_view(frame: &mut Frame, app: &mut App, area: Rect, focuse
         lines.push(Line::from(""));
 
         // Show requirements
+        let tree = RequirementTree::build(&app.selected_rank_requirements);
+        let completed = |i: usize| app.selected_rank_requirements[i].is_completed();
         for (i, req) in app.selected_rank_requirements.iter().enumerate() {
             let is_selected = i == app.ranks_requirement_selection;
-            let check = if req.is_completed() { "✓" } else { "○" };
-            let check_style = if req.is_completed() { styles::success_style() } else { styles::muted_style() };
+            let accommodation = shown_accommodation(app, AdvancementView::Ranks, &tree.node(i).number);
+            let row = RequirementRow::new(&tree, i, &completed).accommodated(accommodation);
+            let check = row.check;
+            let check_style = if row.done { styles::success_style() } else { styles::muted_style() };
 
             let req_num = req.number();
             // prefix(2) + check(1) + space(1) + num(5) + margin(2) + borders(2) = 13 chars overhead
@@ -256,21 +298,23 @@ fn render_requirements_view(frame: &mut Frame, app: &mut App, area: Rect, focuse
             let prefix = if is_selected { "▶ " } else { "  " };
             let text_style = if is_selected { styles::selected_style() } else { styles::list_item_style() };
 
-            let wrapped = wrap_text(&strip_html(&req.full_text()), text_width);
+            let wrapped = wrap_text(&(strip_html(&req.full_text()) + &row.rollup), text_width.saturating_sub(row.indent.len()));
             lines.push(Line::from(vec![
                 Span::raw(prefix),
+                Span::raw(row.indent.clone()),
                 Span::styled(check, check_style),
                 Span::raw(" "),
                 Span::styled(format!("{:<5}", req_num), styles::highlight_style()),
                 Span::styled(wrapped[0].clone(), text_style),
             ]));
-            let indent = " ".repeat(9);
+            let indent = " ".repeat(9) + &row.indent;
             for wrap_line in &wrapped[1..] {
                 lines.push(Line::from(vec![
                     Span::raw(indent.clone()),
                     Span::styled(wrap_line.clone(), text_style),
                 ]));
             }
+            lines.extend(accommodation_lines(app, accommodation, false, indent.len()));
 
             // Show completion date if completed and selected
             if is_selected && req.is_completed() {
@@ -284,6 +328,10 @@ fn render_requirements_view(frame: &mut Frame, app: &mut App, area: Rect, focuse
                     }
                 }
             }
+
+            if is_selected {
+                lines.extend(crossref_lines(app, selected_rank_name, &req_num, 9));
+            }
         }
     }
 
@@ -1,14 +1,20 @@
+use chrono::{Datelike, Local};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
+    style::Style,
     text::{Line, Span},
     widgets::{Block, Borders, Cell, Paragraph, Row, Table},
     Frame,
 };
 
-use crate::app::{App, Focus, ScoutDetailView};
-use trailcache_core::models::{format_date, EAGLE_REQUIRED_COUNT, MeritBadgeProgress, ScoutSortColumn, StatusCategory};
+use crate::app::{App, DenChiefField, RosterRow};
+use crate::ui::state::{AdvancementView, Focus, ScoutDetailView};
+use trailcache_core::models::{format_date, leadership_days, member_id_issue, oa_standing, scout_timeline, AdultAward, ConsentForm, EAGLE_REQUIRED_COUNT, MeritBadgeProgress, RequirementTree, ScoutSortColumn, StatusCategory, TimelineKind};
 use crate::ui::styles;
+use crate::ui::tabs::{accommodation_lines, row_range_label, shown_accommodation, RequirementRow};
 use crate::ui::tabs::advancement::get_sorted_badges;
+use crate::ui::tabs::badges::workbook_lines;
+use crate::ui::tabs::ranks::crossref_lines;
 use trailcache_core::utils::{check_expiration, strip_html, truncate, wrap_text, ExpirationStatus};
 
 /// Render the Scouts tab - table with sortable columns
@@ -18,21 +24,22 @@ pub fn render_scouts(frame: &mut Frame, app: &mut App, area: Rect) {
         .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
         .split(area);
 
-    app.layout_areas.left_panel = chunks[0];
-    app.layout_areas.right_panel = chunks[1];
+    app.ui.layout_areas.left_panel = chunks[0];
+    app.ui.layout_areas.right_panel = chunks[1];
 
     render_scout_table(frame, app, chunks[0]);
     render_scout_detail(frame, app, chunks[1]);
 }
 
 fn render_scout_table(frame: &mut Frame, app: &mut App, area: Rect) {
-    let sorted_youth = app.get_sorted_youth();
-    let focused = matches!(app.focus, Focus::List);
+    let roster_rows = app.roster_rows();
+    let match_count = app.get_sorted_youth().len();
+    let focused = matches!(app.ui.focus, Focus::List);
 
     // Build header with sort indicators
     let sort_indicator = |col: ScoutSortColumn| {
-        if app.scout_sort_column == col {
-            if app.scout_sort_ascending { " ▲" } else { " ▼" }
+        if app.ui.scout_sort_column == col {
+            if app.ui.scout_sort_ascending { " ▲" } else { " ▼" }
         } else {
             ""
         }
@@ -51,8 +58,21 @@ fn render_scout_table(frame: &mut Frame, app: &mut App, area: Rect) {
         .height(1);
 
     // Build rows
-    let rows: Vec<Row> = sorted_youth.iter().enumerate().map(|(i, youth)| {
-        let style = if i == app.roster_selection {
+    let today = Local::now().date_naive();
+    let rows: Vec<Row> = roster_rows.iter().enumerate().map(|(i, row)| {
+        let youth = match row {
+            RosterRow::Patrol { name, members, collapsed } => {
+                let marker = if *collapsed { "▸" } else { "▾" };
+                let style = if i == app.roster_selection.index {
+                    styles::selected_style()
+                } else {
+                    styles::highlight_style()
+                };
+                return Row::new(vec![Cell::from(format!("{} {} ({})", marker, name, members))]).style(style);
+            }
+            RosterRow::Scout(youth) => youth,
+        };
+        let style = if i == app.roster_selection.index {
             styles::selected_style()
         } else {
             styles::list_item_style()
@@ -61,13 +81,14 @@ fn render_scout_table(frame: &mut Frame, app: &mut App, area: Rect) {
         let name = youth.display_name();
         let patrol = youth.patrol();
         let rank = youth.rank();
-        let grade = youth.grade_str();
+        let grade = youth.display_grade(app.config.grade_rollover, today);
         let age = youth.age_str();
 
+        let query = &app.ui.search;
         Row::new(vec![
-            Cell::from(name),
-            Cell::from(patrol),
-            Cell::from(rank),
+            Cell::from(styles::highlight_matches(name, query, Style::default())),
+            Cell::from(styles::highlight_matches(patrol, query, Style::default())),
+            Cell::from(styles::highlight_matches(rank, query, Style::default())),
             Cell::from(format!("{:>2}", grade)),
             Cell::from(format!("{:>2}", age)),
         ]).style(style)
@@ -82,70 +103,82 @@ fn render_scout_table(frame: &mut Frame, app: &mut App, area: Rect) {
         Constraint::Length(4),      // Age
     ];
 
-    let sort_help = "[n]ame [p]atrol [r]ank [g]rade [a]ge";
-    let title = format!(" Scouts ({}) - {} ", app.youth.len(), sort_help);
+    let sort_help = "[n]ame [p]atrol [r]ank [g]rade [a]ge [G]roup";
+    let title = if app.ui.search.is_empty() {
+        format!(" Scouts ({}) - {} ", app.store.youth.len(), sort_help)
+    } else {
+        format!(" Scouts ({}) ", app.search_summary(match_count, app.store.youth.len()))
+    };
 
+    let range = row_range_label(app.ui.left_table_state.offset(), app.roster_selection.index, area, rows.len());
     let table = Table::new(rows, widths)
         .header(header)
         .block(
             Block::default()
                 .title(title)
+                .title_top(Line::from(range).right_aligned())
                 .title_style(styles::muted_style())
                 .borders(Borders::ALL)
                 .border_style(styles::border_style(focused))
         )
         .row_highlight_style(styles::selected_style());
 
-    app.left_table_state.select(Some(app.roster_selection));
-    frame.render_stateful_widget(table, area, &mut app.left_table_state);
+    app.ui.left_table_state.select(Some(app.roster_selection.index));
+    frame.render_stateful_widget(table, area, &mut app.ui.left_table_state);
 }
 
 fn render_scout_detail(frame: &mut Frame, app: &mut App, area: Rect) {
-    let focused = matches!(app.focus, Focus::Detail);
+    let focused = matches!(app.ui.focus, Focus::Detail);
 
     // Render content based on view (view toggle is now in the tab bar)
-    match app.scout_detail_view {
+    match app.ui.scout_detail_view {
         ScoutDetailView::Details => render_details_view(frame, app, area, focused),
         ScoutDetailView::Ranks => render_ranks_view(frame, app, area, focused),
         ScoutDetailView::MeritBadges => render_badges_view(frame, app, area, focused),
         ScoutDetailView::Leadership => render_leadership_view(frame, app, area, focused),
         ScoutDetailView::Awards => render_awards_view(frame, app, area, focused),
+        ScoutDetailView::Timeline => render_timeline_view(frame, app, area, focused),
     }
 }
 
 fn render_details_view(frame: &mut Frame, app: &mut App, area: Rect, focused: bool) {
-    let sorted_youth = app.get_sorted_youth();
-    let selected = sorted_youth.get(app.roster_selection);
+    let selected = app.selected_youth();
     let placeholder = "-";
 
     let content = match selected {
         Some(youth) => {
             let mut lines = vec![];
+            let hidden = app.personal_info_hidden();
+            let restricted = hidden.is_some();
 
             // Name header (display_name already includes nickname if different from first name)
-            lines.push(Line::from(Span::styled(youth.display_name(), styles::title_style())));
+            lines.push(styles::highlight_matches(youth.display_name(), &app.ui.search, styles::title_style()));
 
             // BSA ID right under name
-            let bsa_id = youth.member_id.clone().unwrap_or_else(|| placeholder.to_string());
-            lines.push(Line::from(vec![
-                Span::styled("BSA ID: ", styles::muted_style()),
-                Span::styled(bsa_id, styles::highlight_style()),
-            ]));
+            if !restricted {
+                let bsa_id = youth.member_id.clone().unwrap_or_else(|| placeholder.to_string());
+                let mut spans = vec![
+                    Span::styled("BSA ID: ", styles::muted_style()),
+                    Span::styled(bsa_id, styles::highlight_style()),
+                ];
+                if let Some(issue) = member_id_issue(youth.member_id.as_deref()) {
+                    spans.push(Span::styled(format!("  ({} - blocks advancement submission)", issue), styles::error_style()));
+                }
+                lines.push(Line::from(spans));
+            }
 
             lines.push(Line::from(""));
 
             // Unit Info section (always show all fields)
             lines.push(Line::from(Span::styled("Unit Info", styles::highlight_style())));
 
-            lines.push(Line::from(vec![
-                Span::styled("Patrol:     ", styles::muted_style()),
-                Span::raw(youth.patrol()),
-            ]));
+            let mut patrol_line = vec![Span::styled("Patrol:     ", styles::muted_style())];
+            patrol_line.extend(styles::highlight_matches(youth.patrol(), &app.ui.search, Style::default()).spans);
+            lines.push(Line::from(patrol_line));
 
-            lines.push(Line::from(vec![
-                Span::styled("Rank:       ", styles::muted_style()),
-                Span::raw(youth.rank()),
-            ]));
+            let mut rank_line = vec![Span::styled("Rank:       ", styles::muted_style())];
+            rank_line.extend(styles::highlight_matches(youth.rank(), &app.ui.search, Style::default()).spans);
+            lines.push(Line::from(rank_line));
 
             let position = youth.position_display().unwrap_or_else(|| placeholder.to_string());
             lines.push(Line::from(vec![
@@ -171,6 +204,91 @@ fn render_details_view(frame: &mut Frame, app: &mut App, area: Rect, focused: bo
                 }
             }
 
+            if let Some(stats) = youth.user_id.map(|id| app.attendance_stats(id)) {
+                if let Some(percent) = stats.meeting_percent() {
+                    lines.push(Line::from(vec![
+                        Span::styled("Meetings:   ", styles::muted_style()),
+                        Span::raw(format!("{} of {} ({}%)", stats.meetings_attended, stats.meetings_held, percent)),
+                    ]));
+                }
+            }
+
+            if app.is_my_scout(youth) {
+                lines.push(Line::from(vec![
+                    Span::styled("Pinned:     ", styles::muted_style()),
+                    Span::styled("My Scout (Y to view)", styles::highlight_style()),
+                ]));
+            }
+
+            let tags: Vec<&str> = youth.user_id.map(|id| app.scout_tags.tags_for(id).collect()).unwrap_or_default();
+            if !tags.is_empty() {
+                lines.push(Line::from(vec![
+                    Span::styled("Tags:       ", styles::muted_style()),
+                    Span::styled(tags.join(", "), styles::highlight_style()),
+                ]));
+            }
+
+            if let Some(user_id) = youth.user_id {
+                let today = Local::now().date_naive();
+                let mut spans = vec![Span::styled("Forms:      ", styles::muted_style())];
+                for form in ConsentForm::ALL {
+                    let (mark, style) = if app.consents.is_current(user_id, form, today) {
+                        ("✓", styles::success_style())
+                    } else {
+                        ("✗", styles::error_style())
+                    };
+                    spans.push(Span::styled(format!("{} {}  ", mark, form.name()), style));
+                }
+                lines.push(Line::from(spans));
+
+                if let Some(log) = app.store.all_youth_activity.get(&user_id) {
+                    let mut spans = vec![
+                        Span::styled("Activity:   ", styles::muted_style()),
+                        Span::raw(format!(
+                            "{} nights camping, {} mi hiked, {} service hours",
+                            log.camping_nights(),
+                            log.hiking_miles(),
+                            log.service_hours()
+                        )),
+                    ];
+                    if oa_standing(youth, Some(log)).is_eligible() {
+                        spans.push(Span::styled("  OA eligible", styles::success_style()));
+                    }
+                    lines.push(Line::from(spans));
+                }
+
+                let borrowed = app.library.titles.loans_for(user_id);
+                if !borrowed.is_empty() {
+                    let mut spans = vec![Span::styled("Borrowed:   ", styles::muted_style())];
+                    for (i, (item, loan)) in borrowed.iter().enumerate() {
+                        let style = if loan.is_overdue(today) { styles::error_style() } else { Style::default() };
+                        let separator = if i + 1 < borrowed.len() { ", " } else { "" };
+                        spans.push(Span::styled(
+                            format!("{} (due {}){}", item.title, loan.due.format("%b %-d"), separator),
+                            style,
+                        ));
+                    }
+                    lines.push(Line::from(spans));
+                }
+
+                let closet: Vec<String> =
+                    app.closet.items.held_by(user_id).map(|i| format!("{} ({})", i.kind, i.size)).collect();
+                if !closet.is_empty() {
+                    lines.push(Line::from(vec![
+                        Span::styled("Closet:     ", styles::muted_style()),
+                        Span::raw(closet.join(", ")),
+                    ]));
+                }
+
+                let classes: Vec<&str> = app.clinic.plan.classes_for(user_id).map(|c| c.badge.as_str()).collect();
+                if !classes.is_empty() {
+                    lines.push(Line::from(vec![
+                        Span::styled("Clinic:     ", styles::muted_style()),
+                        Span::raw(classes.join(", ")),
+                    ]));
+                }
+            }
+
             lines.push(Line::from(""));
 
             // Basic Info section (always show all fields)
@@ -179,6 +297,7 @@ fn render_details_view(frame: &mut Frame, app: &mut App, area: Rect, focused: bo
             let age_str = youth.age()
                 .map(|age| {
                     youth.date_of_birth()
+                        .filter(|_| !restricted)
                         .map(|dob| format!("{} (born {})", age, dob.format("%b %d, %Y")))
                         .unwrap_or_else(|| age.to_string())
                 })
@@ -195,106 +314,116 @@ fn render_details_view(frame: &mut Frame, app: &mut App, area: Rect, focused: bo
             ]));
 
             let grade_str = youth.grade.map(|g| g.to_string()).unwrap_or_else(|| placeholder.to_string());
-            lines.push(Line::from(vec![
+            let mut grade_line = vec![
                 Span::styled("Grade:  ", styles::muted_style()),
                 Span::raw(grade_str),
-            ]));
+            ];
+            if let Some(stale) = youth.stale_grade(app.config.grade_rollover, Local::now().date_naive()) {
+                grade_line.push(Span::styled(
+                    format!("  likely {} now; update in Scoutbook", stale.expected),
+                    styles::error_style(),
+                ));
+            }
+            lines.push(Line::from(grade_line));
 
             lines.push(Line::from(""));
 
-            // Contact section (always show all fields)
-            lines.push(Line::from(Span::styled("Contact", styles::highlight_style())));
+            if let Some(reason) = hidden {
+                lines.push(Line::from(Span::styled(reason, styles::muted_style())));
+            } else {
+                // Contact section (always show all fields)
+                lines.push(Line::from(Span::styled("Contact", styles::highlight_style())));
 
-            let phone = youth.phone().unwrap_or_else(|| placeholder.to_string());
-            lines.push(Line::from(vec![
-                Span::styled("Phone:   ", styles::muted_style()),
-                Span::raw(phone),
-            ]));
+                let phone = youth.phone(app.config.phone_format).unwrap_or_else(|| placeholder.to_string());
+                lines.push(Line::from(vec![
+                    Span::styled("Phone:   ", styles::muted_style()),
+                    Span::raw(phone),
+                ]));
 
-            let email = youth.email().map(|e| truncate(&e, 28)).unwrap_or_else(|| placeholder.to_string());
-            lines.push(Line::from(vec![
-                Span::styled("Email:   ", styles::muted_style()),
-                Span::raw(email),
-            ]));
+                let email = youth.email().map(|e| truncate(&e, 28)).unwrap_or_else(|| placeholder.to_string());
+                let mut email_line = vec![Span::styled("Email:   ", styles::muted_style())];
+                email_line.extend(styles::highlight_matches(email, &app.ui.search, Style::default()).spans);
+                lines.push(Line::from(email_line));
 
-            let addr_line1 = youth.primary_address_info.as_ref()
-                .and_then(|a| a.address1.clone())
-                .filter(|a| !a.trim().is_empty())
-                .unwrap_or_else(|| placeholder.to_string());
-            lines.push(Line::from(vec![
-                Span::styled("Address: ", styles::muted_style()),
-                Span::raw(addr_line1),
-            ]));
+                let addr_line1 = youth.primary_address_info.as_ref()
+                    .and_then(|a| a.address1.clone())
+                    .filter(|a| !a.trim().is_empty())
+                    .unwrap_or_else(|| placeholder.to_string());
+                lines.push(Line::from(vec![
+                    Span::styled("Address: ", styles::muted_style()),
+                    Span::raw(addr_line1),
+                ]));
 
-            let addr_line2 = youth.primary_address_info.as_ref()
-                .and_then(|a| {
-                    a.city_state().map(|cs| {
-                        format!("{} {}", cs, a.zip_code.as_deref().unwrap_or(""))
+                let addr_line2 = youth.primary_address_info.as_ref()
+                    .and_then(|a| {
+                        a.city_state().map(|cs| {
+                            format!("{} {}", cs, a.zip_code.as_deref().unwrap_or(""))
+                        })
                     })
-                })
-                .unwrap_or_default();
-            lines.push(Line::from(vec![
-                Span::raw("         "), // 9 spaces to align with "Address: "
-                Span::raw(addr_line2),
-            ]));
+                    .unwrap_or_default();
+                lines.push(Line::from(vec![
+                    Span::raw("         "), // 9 spaces to align with "Address: "
+                    Span::raw(addr_line2),
+                ]));
 
-            lines.push(Line::from(""));
+                lines.push(Line::from(""));
 
-            // Parents section
-            lines.push(Line::from(Span::styled("Parents/Guardians", styles::highlight_style())));
+                // Parents section
+                lines.push(Line::from(Span::styled("Parents/Guardians", styles::highlight_style())));
 
-            if let Some(user_id) = youth.user_id {
-                let parents = app.get_parents_for_youth(user_id);
-                if parents.is_empty() {
-                    lines.push(Line::from(vec![
-                        Span::styled("          ", styles::muted_style()),
-                        Span::styled(placeholder, styles::muted_style()),
-                    ]));
-                } else {
-                    for parent in parents.iter().take(2) {
-                        // Name
-                        lines.push(Line::from(vec![
-                            Span::styled("  ", styles::muted_style()),
-                            Span::styled(parent.full_name(), styles::title_style()),
-                        ]));
-                        // Phone
-                        let phone = parent.phone().unwrap_or_else(|| placeholder.to_string());
-                        lines.push(Line::from(vec![
-                            Span::styled("    Phone:   ", styles::muted_style()),
-                            Span::raw(phone),
-                        ]));
-                        // Email
-                        let email = parent.email.as_deref().unwrap_or(placeholder);
-                        lines.push(Line::from(vec![
-                            Span::styled("    Email:   ", styles::muted_style()),
-                            Span::raw(truncate(email, 26)),
-                        ]));
-                        // Address - multiple lines
-                        let addr1 = parent.address1.as_deref()
-                            .filter(|a| !a.trim().is_empty())
-                            .unwrap_or(placeholder);
+                if let Some(user_id) = youth.user_id {
+                    let parents = app.get_parents_for_youth(user_id);
+                    if parents.is_empty() {
                         lines.push(Line::from(vec![
-                            Span::styled("    Address: ", styles::muted_style()),
-                            Span::raw(addr1.to_string()),
+                            Span::styled("          ", styles::muted_style()),
+                            Span::styled(placeholder, styles::muted_style()),
                         ]));
-                        // City, State ZIP - align under street address
-                        if parent.city.is_some() || parent.state.is_some() {
-                            let city = parent.city.as_deref().unwrap_or("");
-                            let state = parent.state.as_deref().unwrap_or("");
-                            let zip = parent.zip.as_deref().unwrap_or("");
+                    } else {
+                        for parent in parents.iter().take(2) {
+                            // Name
+                            lines.push(Line::from(vec![
+                                Span::styled("  ", styles::muted_style()),
+                                Span::styled(parent.full_name(), styles::title_style()),
+                            ]));
+                            // Phone
+                            let phone = parent.phone(app.config.phone_format).unwrap_or_else(|| placeholder.to_string());
                             lines.push(Line::from(vec![
-                                Span::raw("             "), // 4 spaces + 9 for "Address: " = 13 total
-                                Span::raw(format!("{}, {} {}", city, state, zip)),
+                                Span::styled("    Phone:   ", styles::muted_style()),
+                                Span::raw(phone),
                             ]));
+                            // Email
+                            let email = parent.email.as_deref().unwrap_or(placeholder);
+                            lines.push(Line::from(vec![
+                                Span::styled("    Email:   ", styles::muted_style()),
+                                Span::raw(truncate(email, 26)),
+                            ]));
+                            // Address - multiple lines
+                            let addr1 = parent.address1.as_deref()
+                                .filter(|a| !a.trim().is_empty())
+                                .unwrap_or(placeholder);
+                            lines.push(Line::from(vec![
+                                Span::styled("    Address: ", styles::muted_style()),
+                                Span::raw(addr1.to_string()),
+                            ]));
+                            // City, State ZIP - align under street address
+                            if parent.city.is_some() || parent.state.is_some() {
+                                let city = parent.city.as_deref().unwrap_or("");
+                                let state = parent.state.as_deref().unwrap_or("");
+                                let zip = parent.zip.as_deref().unwrap_or("");
+                                lines.push(Line::from(vec![
+                                    Span::raw("             "), // 4 spaces + 9 for "Address: " = 13 total
+                                    Span::raw(format!("{}, {} {}", city, state, zip)),
+                                ]));
+                            }
+                            lines.push(Line::from(""));
                         }
-                        lines.push(Line::from(""));
                     }
+                } else {
+                    lines.push(Line::from(vec![
+                        Span::styled("          ", styles::muted_style()),
+                        Span::styled(placeholder, styles::muted_style()),
+                    ]));
                 }
-            } else {
-                lines.push(Line::from(vec![
-                    Span::styled("          ", styles::muted_style()),
-                    Span::styled(placeholder, styles::muted_style()),
-                ]));
             }
 
             lines
@@ -320,8 +449,7 @@ fn render_ranks_view(frame: &mut Frame, app: &mut App, area: Rect, focused: bool
         return;
     }
 
-    let sorted_youth = app.get_sorted_youth();
-    let selected = sorted_youth.get(app.roster_selection);
+    let selected = app.selected_youth();
     let placeholder = "-";
 
     let content = match selected {
@@ -390,8 +518,7 @@ fn render_ranks_view(frame: &mut Frame, app: &mut App, area: Rect, focused: bool
 }
 
 fn render_rank_requirements_view(frame: &mut Frame, app: &mut App, area: Rect, focused: bool) {
-    let sorted_youth = app.get_sorted_youth();
-    let selected = sorted_youth.get(app.roster_selection);
+    let selected = app.selected_youth();
     let rank_name = app.selected_youth_ranks
         .get(app.advancement_rank_selection)
         .map(|r| r.rank_name.clone())
@@ -407,7 +534,7 @@ fn render_rank_requirements_view(frame: &mut Frame, app: &mut App, area: Rect, f
         )));
         lines.push(Line::from(vec![
             Span::styled(&rank_name, styles::highlight_style()),
-            Span::styled(" - Press Esc to go back", styles::muted_style()),
+            Span::styled(" - Press Esc to go back, [n] to note an accommodation", styles::muted_style()),
         ]));
     }
     lines.push(Line::from(""));
@@ -428,28 +555,34 @@ fn render_rank_requirements_view(frame: &mut Frame, app: &mut App, area: Rect, f
         // check(1) + space(1) + num(5) + margin(2) + borders(2) = 11 chars overhead
         let text_width = (area.width as usize).saturating_sub(11);
 
+        let tree = RequirementTree::build(&app.selected_rank_requirements);
+        let completed = |i: usize| app.selected_rank_requirements[i].is_completed();
         for (i, req) in app.selected_rank_requirements.iter().enumerate() {
             let is_selected = i == app.requirement_selection;
-            let check = if req.is_completed() { "✓" } else { "○" };
-            let check_style = if req.is_completed() { styles::success_style() } else { styles::muted_style() };
+            let accommodation = shown_accommodation(app, AdvancementView::Ranks, &tree.node(i).number);
+            let row = RequirementRow::new(&tree, i, &completed).accommodated(accommodation);
+            let check = row.check;
+            let check_style = if row.done { styles::success_style() } else { styles::muted_style() };
 
             let req_num = req.number();
             let text_style = if is_selected { styles::selected_style() } else { styles::list_item_style() };
 
-            let wrapped = wrap_text(&strip_html(&req.full_text()), text_width);
+            let wrapped = wrap_text(&(strip_html(&req.full_text()) + &row.rollup), text_width.saturating_sub(row.indent.len()));
             lines.push(Line::from(vec![
+                Span::raw(row.indent.clone()),
                 Span::styled(check, check_style),
                 Span::raw(" "),
                 Span::styled(format!("{:<5}", req_num), styles::highlight_style()),
                 Span::styled(wrapped[0].clone(), text_style),
             ]));
-            let indent = " ".repeat(7); // check(1) + space(1) + num(5)
+            let indent = " ".repeat(7) + &row.indent; // check(1) + space(1) + num(5)
             for wrap_line in &wrapped[1..] {
                 lines.push(Line::from(vec![
                     Span::raw(indent.clone()),
                     Span::styled(wrap_line.clone(), text_style),
                 ]));
             }
+            lines.extend(accommodation_lines(app, accommodation, is_selected, indent.len()));
 
             // Show completion date if completed and selected
             if is_selected && req.is_completed() {
@@ -463,6 +596,10 @@ fn render_rank_requirements_view(frame: &mut Frame, app: &mut App, area: Rect, f
                     }
                 }
             }
+
+            if is_selected {
+                lines.extend(crossref_lines(app, &rank_name, &req_num, 7));
+            }
         }
     }
 
@@ -481,8 +618,7 @@ fn render_badges_view(frame: &mut Frame, app: &mut App, area: Rect, focused: boo
         return;
     }
 
-    let sorted_youth = app.get_sorted_youth();
-    let selected = sorted_youth.get(app.roster_selection);
+    let selected = app.selected_youth();
     let content = match selected {
         Some(youth) => {
             let mut lines = vec![];
@@ -556,8 +692,7 @@ fn render_badges_view(frame: &mut Frame, app: &mut App, area: Rect, focused: boo
 }
 
 fn render_badge_requirements_view(frame: &mut Frame, app: &mut App, area: Rect, focused: bool) {
-    let sorted_youth = app.get_sorted_youth();
-    let selected = sorted_youth.get(app.roster_selection);
+    let selected = app.selected_youth();
     let sorted_badges = get_sorted_badges(&app.selected_youth_badges);
     let selected_badge = sorted_badges.get(app.advancement_badge_selection);
     let badge_name = selected_badge
@@ -580,8 +715,11 @@ fn render_badge_requirements_view(frame: &mut Frame, app: &mut App, area: Rect,
         };
         lines.push(Line::from(vec![
             Span::styled(badge_display, styles::highlight_style()),
-            Span::styled(" - Press Esc to go back", styles::muted_style()),
+            Span::styled(" - Press Esc to go back, [n] to note an accommodation", styles::muted_style()),
         ]));
+        if selected_badge.is_some() {
+            lines.extend(workbook_lines(app, &badge_name));
+        }
     }
     lines.push(Line::from(""));
 
@@ -613,12 +751,16 @@ fn render_badge_requirements_view(frame: &mut Frame, app: &mut App, area: Rect,
         // check(1) + space(1) + num(5) + margin(2) + borders(2) = 11 chars overhead
         let text_width = (area.width as usize).saturating_sub(11);
 
+        let tree = RequirementTree::build(&app.selected_badge_requirements);
+        let completed = |i: usize| app.selected_badge_requirements[i].is_completed();
         for (i, req) in app.selected_badge_requirements.iter().enumerate() {
             let is_selected = i == app.requirement_selection;
-            let check = if req.is_completed() { "✓" } else { "○" };
+            let accommodation = shown_accommodation(app, AdvancementView::MeritBadges, &tree.node(i).number);
+            let row = RequirementRow::new(&tree, i, &completed).accommodated(accommodation);
+            let check = row.check;
             let check_style = if is_selected {
                 styles::selected_style()
-            } else if req.is_completed() {
+            } else if row.done {
                 styles::success_style()
             } else {
                 styles::muted_style()
@@ -631,20 +773,22 @@ fn render_badge_requirements_view(frame: &mut Frame, app: &mut App, area: Rect,
             let row_style = if is_selected { styles::selected_style() } else { styles::list_item_style() };
             let num_style = if is_selected { styles::selected_style() } else { styles::highlight_style() };
 
-            let wrapped = wrap_text(&summary, text_width);
+            let wrapped = wrap_text(&(summary + &row.rollup), text_width.saturating_sub(row.indent.len()));
             lines.push(Line::from(vec![
+                Span::raw(row.indent.clone()),
                 Span::styled(check, check_style),
                 Span::styled(" ", row_style),
                 Span::styled(format!("{:<5}", req_num), num_style),
                 Span::styled(wrapped[0].clone(), row_style),
             ]));
-            let indent = " ".repeat(7); // check(1) + space(1) + num(5)
+            let indent = " ".repeat(7) + &row.indent; // check(1) + space(1) + num(5)
             for wrap_line in &wrapped[1..] {
                 lines.push(Line::from(vec![
                     Span::raw(indent.clone()),
                     Span::styled(wrap_line.clone(), row_style),
                 ]));
             }
+            lines.extend(accommodation_lines(app, accommodation, is_selected, indent.len()));
 
             // Show completion date if completed and selected
             if is_selected && req.is_completed() {
@@ -720,8 +864,8 @@ pub fn render_adults(frame: &mut Frame, app: &mut App, area: Rect) {
         .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
         .split(area);
 
-    app.layout_areas.left_panel = chunks[0];
-    app.layout_areas.right_panel = chunks[1];
+    app.ui.layout_areas.left_panel = chunks[0];
+    app.ui.layout_areas.right_panel = chunks[1];
 
     render_adult_table(frame, app, chunks[0]);
     render_adult_detail(frame, app, chunks[1]);
@@ -737,8 +881,8 @@ fn render_adult_table(frame: &mut Frame, app: &mut App, area: Rect) {
         .style(styles::title_style())
         .height(1);
 
-    let rows: Vec<Row> = app.adults.iter().enumerate().map(|(i, adult)| {
-        let style = if i == app.adults_selection {
+    let rows: Vec<Row> = app.store.adults.iter().enumerate().map(|(i, adult)| {
+        let style = if i == app.adults_selection.index {
             styles::selected_style()
         } else {
             styles::list_item_style()
@@ -758,23 +902,25 @@ fn render_adult_table(frame: &mut Frame, app: &mut App, area: Rect) {
         Constraint::Fill(1),        // Position
     ];
 
+    let range = row_range_label(app.ui.left_table_state.offset(), app.adults_selection.index, area, rows.len());
     let table = Table::new(rows, widths)
         .header(header)
         .block(
             Block::default()
-                .title(format!(" Adults ({}) ", app.adults.len()))
+                .title(format!(" Adults ({}) ", app.store.adults.len()))
+                .title_top(Line::from(range).right_aligned())
                 .title_style(styles::title_style())
                 .borders(Borders::ALL)
                 .border_style(styles::border_style(true))
         )
         .row_highlight_style(styles::selected_style());
 
-    app.left_table_state.select(Some(app.adults_selection));
-    frame.render_stateful_widget(table, area, &mut app.left_table_state);
+    app.ui.left_table_state.select(Some(app.adults_selection.index));
+    frame.render_stateful_widget(table, area, &mut app.ui.left_table_state);
 }
 
 fn render_adult_detail(frame: &mut Frame, app: &mut App, area: Rect) {
-    let selected = app.adults.get(app.adults_selection);
+    let selected = app.store.adults.get(app.adults_selection.index);
 
     let content = match selected {
         Some(adult) => {
@@ -787,11 +933,19 @@ fn render_adult_detail(frame: &mut Frame, app: &mut App, area: Rect) {
             )));
 
             // BSA ID right under name
-            let bsa_id = adult.member_id.as_deref().unwrap_or("-");
-            lines.push(Line::from(vec![
-                Span::styled("BSA ID: ", styles::muted_style()),
-                Span::styled(bsa_id, styles::highlight_style()),
-            ]));
+            let hidden = app.personal_info_hidden();
+            let restricted = hidden.is_some();
+            if !restricted {
+                let bsa_id = adult.member_id.as_deref().unwrap_or("-");
+                let mut spans = vec![
+                    Span::styled("BSA ID: ", styles::muted_style()),
+                    Span::styled(bsa_id, styles::highlight_style()),
+                ];
+                if let Some(issue) = member_id_issue(adult.member_id.as_deref()) {
+                    spans.push(Span::styled(format!("  ({})", issue), styles::error_style()));
+                }
+                lines.push(Line::from(spans));
+            }
 
             lines.push(Line::from(""));
 
@@ -849,47 +1003,91 @@ fn render_adult_detail(frame: &mut Frame, app: &mut App, area: Rect) {
                 Span::styled(trained_text, trained_style),
             ]));
 
-            lines.push(Line::from(""));
-            lines.push(Line::from(Span::styled("Contact", styles::highlight_style())));
+            let today = Local::now().date_naive();
+            if let Some((done, total)) = app.onboarding.checklists.progress(adult, today) {
+                let missing: Vec<&str> = app.onboarding.checklists.missing(adult, today).iter().map(|i| i.short_name()).collect();
+                let (text, style) = if missing.is_empty() {
+                    (format!("{}/{} done - press n to finish", done, total), styles::success_style())
+                } else {
+                    (format!("{}/{} - needs {}", done, total, missing.join(", ")), styles::error_style())
+                };
+                lines.push(Line::from(vec![
+                    Span::styled("Onboarding: ", styles::muted_style()),
+                    Span::styled(text, style),
+                ]));
+            }
 
-            // Phone
-            if let Some(phone) = adult.phone() {
+            for award in AdultAward::ALL {
+                let (met, total) = app.adult_awards.checklists.progress(adult, award);
+                let (text, style) = if met == total {
+                    ("Eligible".to_string(), styles::success_style())
+                } else {
+                    (format!("{}/{} requirements", met, total), styles::muted_style())
+                };
                 lines.push(Line::from(vec![
-                    Span::styled("Phone:   ", styles::muted_style()),
-                    Span::raw(phone),
+                    Span::styled(format!("{}: ", award.name()), styles::muted_style()),
+                    Span::styled(text, style),
                 ]));
             }
 
-            // Email
-            if let Some(email) = adult.email() {
+            for skill in app.adult_skills.directory.for_adult(adult.get_user_id()) {
+                let (text, style) = match skill.expires {
+                    Some(date) if !skill.is_current(today) => (format!("expired {}", date.format("%b %-d, %Y")), styles::error_style()),
+                    Some(date) if skill.expires_soon(today) => (format!("expires {}", date.format("%b %-d, %Y")), styles::error_style()),
+                    Some(date) => (format!("until {}", date.format("%b %-d, %Y")), styles::success_style()),
+                    None => ("no expiration".to_string(), styles::muted_style()),
+                };
                 lines.push(Line::from(vec![
-                    Span::styled("Email:   ", styles::muted_style()),
-                    Span::raw(email),
+                    Span::styled(format!("{}: ", skill.skill), styles::muted_style()),
+                    Span::styled(text, style),
                 ]));
             }
 
-            // Address
-            let addr_line1 = adult.primary_address_info.as_ref()
-                .and_then(|a| a.address1.clone())
-                .filter(|a| !a.trim().is_empty())
-                .unwrap_or_else(|| "-".to_string());
-            lines.push(Line::from(vec![
-                Span::styled("Address: ", styles::muted_style()),
-                Span::raw(addr_line1),
-            ]));
+            lines.push(Line::from(""));
+            if let Some(reason) = hidden {
+                lines.push(Line::from(Span::styled(reason, styles::muted_style())));
+            } else {
+                lines.push(Line::from(Span::styled("Contact", styles::highlight_style())));
 
-            // City, State ZIP on second line
-            if let Some(ref addr_info) = adult.primary_address_info {
-                let addr_line2 = addr_info.city_state().map(|cs| {
-                    format!("{} {}", cs, addr_info.zip_code.as_deref().unwrap_or(""))
-                }).unwrap_or_default();
+                // Phone
+                if let Some(phone) = adult.phone(app.config.phone_format) {
+                    lines.push(Line::from(vec![
+                        Span::styled("Phone:   ", styles::muted_style()),
+                        Span::raw(phone),
+                    ]));
+                }
 
-                if !addr_line2.is_empty() {
+                // Email
+                if let Some(email) = adult.email() {
                     lines.push(Line::from(vec![
-                        Span::raw("         "), // 9 spaces to align with "Address: "
-                        Span::raw(addr_line2),
+                        Span::styled("Email:   ", styles::muted_style()),
+                        Span::raw(email),
                     ]));
                 }
+
+                // Address
+                let addr_line1 = adult.primary_address_info.as_ref()
+                    .and_then(|a| a.address1.clone())
+                    .filter(|a| !a.trim().is_empty())
+                    .unwrap_or_else(|| "-".to_string());
+                lines.push(Line::from(vec![
+                    Span::styled("Address: ", styles::muted_style()),
+                    Span::raw(addr_line1),
+                ]));
+
+                // City, State ZIP on second line
+                if let Some(ref addr_info) = adult.primary_address_info {
+                    let addr_line2 = addr_info.city_state().map(|cs| {
+                        format!("{} {}", cs, addr_info.zip_code.as_deref().unwrap_or(""))
+                    }).unwrap_or_default();
+
+                    if !addr_line2.is_empty() {
+                        lines.push(Line::from(vec![
+                            Span::raw("         "), // 9 spaces to align with "Address: "
+                            Span::raw(addr_line2),
+                        ]));
+                    }
+                }
             }
 
             lines
@@ -909,8 +1107,7 @@ fn render_adult_detail(frame: &mut Frame, app: &mut App, area: Rect) {
 }
 
 fn render_leadership_view(frame: &mut Frame, app: &mut App, area: Rect, focused: bool) {
-    let sorted_youth = app.get_sorted_youth();
-    let selected = sorted_youth.get(app.roster_selection);
+    let selected = app.selected_youth();
     let content = match selected {
         Some(youth) => {
             let mut lines = vec![];
@@ -932,6 +1129,40 @@ fn render_leadership_view(frame: &mut Frame, app: &mut App, area: Rect, focused:
                 Span::styled(current_position, styles::highlight_style()),
             ]));
 
+            // Den chief service tracked locally, and tenure including it
+            let user_id = youth.user_id.unwrap_or_default();
+            let today = Local::now().date_naive();
+            if let Some((field, text)) = &app.den_chiefs.input {
+                let label = match field {
+                    DenChiefField::Pack => "Den chief for pack (blank to end): ",
+                    DenChiefField::Den => "Den: ",
+                    DenChiefField::Meets => "Den meets (e.g. Tue, 3rd Thu): ",
+                };
+                lines.push(Line::from(vec![
+                    Span::styled(label, styles::highlight_style()),
+                    Span::styled(format!("{}_", text), styles::list_item_style()),
+                ]));
+            }
+            for link in app.den_chiefs.links.for_scout(user_id) {
+                let started = link.started_on.format("%b %-d, %Y");
+                let text = match (link.ended_on, link.meets) {
+                    (Some(ended), _) => format!("{} ({} - {})", link.label(), started, ended.format("%b %-d, %Y")),
+                    (None, Some(meets)) => format!("{} since {}, meets {}", link.label(), started, meets.label()),
+                    (None, None) => format!("{} since {}", link.label(), started),
+                };
+                lines.push(Line::from(vec![
+                    Span::styled("Den Chief: ", styles::muted_style()),
+                    Span::styled(text, styles::list_item_style()),
+                ]));
+            }
+            let history = app.den_chiefs.links.with_leadership(user_id, &app.selected_youth_leadership, today);
+            if !history.is_empty() {
+                lines.push(Line::from(vec![
+                    Span::styled("Tenure: ", styles::muted_style()),
+                    Span::styled(format!("{} days in leadership", leadership_days(&history, today)), styles::list_item_style()),
+                ]));
+            }
+
             lines.push(Line::from(""));
 
             if app.selected_youth_leadership.is_empty() {
@@ -996,8 +1227,7 @@ fn render_leadership_view(frame: &mut Frame, app: &mut App, area: Rect, focused:
 }
 
 fn render_awards_view(frame: &mut Frame, app: &mut App, area: Rect, focused: bool) {
-    let sorted_youth = app.get_sorted_youth();
-    let selected = sorted_youth.get(app.roster_selection);
+    let selected = app.selected_youth();
     let content = match selected {
         Some(youth) => {
             let mut lines = vec![];
@@ -1070,3 +1300,62 @@ fn render_awards_view(frame: &mut Frame, app: &mut App, area: Rect, focused: boo
     frame.render_widget(paragraph, area);
 }
 
+/// Ranks, badges, positions, and awards merged in date order, with a
+/// heading for each year.
+fn render_timeline_view(frame: &mut Frame, app: &mut App, area: Rect, focused: bool) {
+    let selected = app.selected_youth();
+    let content = match selected {
+        Some(youth) => {
+            let mut lines = vec![
+                Line::from(Span::styled(youth.display_name(), styles::title_style())),
+                Line::from(""),
+            ];
+
+            let timeline = scout_timeline(
+                &app.selected_youth_ranks,
+                &app.selected_youth_badges,
+                &app.selected_youth_leadership,
+                &app.selected_youth_awards,
+            );
+            if timeline.is_empty() {
+                let message = if app.awards_loaded { "Nothing dated yet" } else { "Loading history..." };
+                lines.push(Line::from(Span::styled(message, styles::muted_style())));
+            }
+            let mut year = None;
+            for entry in timeline {
+                if year != Some(entry.date.year()) {
+                    if year.is_some() {
+                        lines.push(Line::from(""));
+                    }
+                    year = Some(entry.date.year());
+                    lines.push(Line::from(Span::styled(entry.date.year().to_string(), styles::highlight_style())));
+                }
+                let style = match entry.kind {
+                    TimelineKind::Rank => styles::success_style(),
+                    _ => styles::list_item_style(),
+                };
+                lines.push(Line::from(vec![
+                    Span::styled(format!("  {}  ", entry.date.format("%b %d")), styles::muted_style()),
+                    Span::styled(format!("{:<9}", entry.kind.label()), styles::muted_style()),
+                    Span::styled(entry.text, style),
+                ]));
+            }
+            lines
+        }
+        None => vec![Line::from(Span::styled(
+            "Select a scout from the list",
+            styles::muted_style(),
+        ))],
+    };
+
+    let visible = area.height.saturating_sub(2) as usize;
+    app.timeline_scroll = app.timeline_scroll.min(content.len().saturating_sub(visible));
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(styles::border_style(focused));
+
+    let paragraph = Paragraph::new(content).block(block).scroll((app.timeline_scroll as u16, 0));
+    frame.render_widget(paragraph, area);
+}
+
@@ -1,56 +1,123 @@
+use chrono::Local;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph},
+    widgets::{Bar, BarChart, BarGroup, Block, Borders, Paragraph, Sparkline, Wrap},
     Frame,
 };
 
 use crate::app::{App, ScoutRank};
 use crate::ui::styles;
-use trailcache_core::models::{patrol_rank_breakdown, DISPLAY_NOT_TRAINED, RenewalStats, TrainingStats};
-use trailcache_core::utils::strip_url_scheme;
+use trailcache_core::models::{MembershipForecast, DISPLAY_NOT_TRAINED};
+use trailcache_core::utils::{format_phone, strip_url_scheme, truncate};
 
 pub fn render(frame: &mut Frame, app: &mut App, area: Rect) {
     // Vertical layout:
     // 1. Unit info (full width)
-    // 2. Scouts | Patrols (50/50)
-    // 3. Renewals | Training (50/50)
+    // 2. Scouts | Patrols | Forecast
+    // 3. Renewals | Training | Advancement pace
     let main_chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Min(15),    // Unit section
             Constraint::Percentage(45), // Scouts/Patrols row
-            Constraint::Percentage(40), // Renewals/Training row
+            Constraint::Percentage(40), // Renewals/Training/Pace row
         ])
         .split(area);
 
     // Unit at top (full width)
     render_troop_info(frame, app, main_chunks[0]);
 
-    // Middle row: Scouts | Patrols (50/50)
+    // Middle row: Scouts | Patrols | Forecast
     let middle_chunks = Layout::default()
         .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .constraints([Constraint::Ratio(1, 3); 3])
         .split(main_chunks[1]);
 
     render_positions(frame, app, middle_chunks[0]);
     render_patrols(frame, app, middle_chunks[1]);
+    render_forecast(frame, app, middle_chunks[2]);
 
-    // Bottom row: Renewals | Training (50/50)
+    // Bottom row: Renewals | Training | Pace, plus open committee action
+    // items and overdue library loans while there are any
+    let has_action_items = !app.minutes.meetings.open_action_items().is_empty();
+    let has_overdue_loans = !app.library.titles.overdue(Local::now().date_naive()).is_empty();
+    let panels = 3 + has_action_items as u32 + has_overdue_loans as u32;
     let bottom_chunks = Layout::default()
         .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .constraints((0..panels).map(|_| Constraint::Ratio(1, panels)))
         .split(main_chunks[2]);
 
     render_renewals(frame, app, bottom_chunks[0]);
     render_training(frame, app, bottom_chunks[1]);
+    render_velocity(frame, app, bottom_chunks[2]);
+    let mut next = 3;
+    if has_action_items {
+        render_action_items(frame, app, bottom_chunks[next]);
+        next += 1;
+    }
+    if has_overdue_loans {
+        render_overdue_loans(frame, app, bottom_chunks[next]);
+    }
+}
+
+fn render_overdue_loans(frame: &mut Frame, app: &mut App, area: Rect) {
+    let today = Local::now().date_naive();
+    let width = area.width.saturating_sub(6) as usize;
+    let mut lines = vec![];
+
+    for (item, loan) in app.library.titles.overdue(today) {
+        lines.push(Line::from(Span::styled(truncate(&item.title, width), styles::list_item_style())));
+        let detail = format!("{}, due {}", app.borrower_name(loan.user_id), loan.due.format("%b %-d"));
+        lines.push(Line::from(vec![
+            Span::raw("  "),
+            Span::styled(truncate(&detail, width), styles::error_style()),
+        ]));
+    }
+
+    let block = Block::default()
+        .title(" Overdue Library Items [Z] ")
+        .title_style(styles::title_style())
+        .borders(Borders::ALL)
+        .border_style(styles::border_style(false));
+
+    let paragraph = Paragraph::new(lines).block(block);
+    frame.render_widget(paragraph, area);
+}
+
+fn render_action_items(frame: &mut Frame, app: &mut App, area: Rect) {
+    let today = Local::now().date_naive();
+    let width = area.width.saturating_sub(6) as usize;
+    let mut lines = vec![];
+
+    for (minutes, item) in app.minutes.meetings.open_action_items() {
+        let due_style = if item.is_overdue(today) { styles::error_style() } else { styles::muted_style() };
+        lines.push(Line::from(Span::styled(truncate(&item.text, width), styles::list_item_style())));
+        let mut detail = vec![];
+        detail.extend(item.owner.clone());
+        detail.extend(item.due.map(|d| format!("due {}", d.format("%b %-d"))));
+        detail.push(format!("from {}", minutes.date.format("%b %-d")));
+        lines.push(Line::from(vec![
+            Span::raw("  "),
+            Span::styled(truncate(&detail.join(", "), width), due_style),
+        ]));
+    }
+
+    let block = Block::default()
+        .title(" Action Items ")
+        .title_style(styles::title_style())
+        .borders(Borders::ALL)
+        .border_style(styles::border_style(false));
+
+    let paragraph = Paragraph::new(lines).block(block);
+    frame.render_widget(paragraph, area);
 }
 
 fn render_patrols(frame: &mut Frame, app: &mut App, area: Rect) {
     let mut lines = vec![];
 
     // Get patrols with their scouts and rank breakdown
-    let patrol_data = patrol_rank_breakdown(&app.youth);
+    let patrol_data = &app.store.unit_stats.patrols;
 
     // Sort patrols alphabetically by name for stable ordering
     let mut patrol_names: Vec<&String> = patrol_data.keys().collect();
@@ -94,10 +161,125 @@ fn render_patrols(frame: &mut Frame, app: &mut App, area: Rect) {
     frame.render_widget(paragraph, area);
 }
 
+/// Projected roster size for the coming year as a bar per month, with what
+/// the projection assumes underneath.
+fn render_forecast(frame: &mut Frame, app: &mut App, area: Rect) {
+    let block = Block::default()
+        .title(" Membership Forecast ")
+        .title_style(styles::title_style())
+        .borders(Borders::ALL)
+        .border_style(styles::border_style(false));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+    if app.store.youth.is_empty() {
+        frame.render_widget(Paragraph::new(Span::styled("No roster data", styles::muted_style())), inner);
+        return;
+    }
+
+    let today = Local::now().date_naive();
+    let forecast = MembershipForecast::new(&app.store.youth, &app.store.membership, app.config.forecast, today);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(3), Constraint::Length(2)])
+        .split(inner);
+
+    let bars: Vec<Bar> = forecast
+        .months
+        .iter()
+        .map(|(month, members)| {
+            Bar::default()
+                .value(*members as u64)
+                .label(Line::from(month.format("%b").to_string()))
+                .text_value(members.to_string())
+        })
+        .collect();
+    let bar_width = (chunks[0].width / forecast.months.len().max(1) as u16).saturating_sub(1).clamp(1, 5);
+    let chart = BarChart::default()
+        .data(BarGroup::default().bars(&bars))
+        .bar_width(bar_width)
+        .bar_gap(1)
+        .bar_style(styles::highlight_style())
+        .value_style(styles::selected_style())
+        .label_style(styles::muted_style());
+    frame.render_widget(chart, chunks[0]);
+
+    let attrition = match forecast.attrition_rate {
+        Some(rate) => format!("{:.0}% leave a year", rate * 100.0),
+        None => "attrition not known yet".to_string(),
+    };
+    let summary = vec![
+        Line::from(Span::styled(
+            format!("-{} aging out, +{:.0} joining", forecast.aging_out, forecast.expected_joins),
+            styles::list_item_style(),
+        )),
+        Line::from(Span::styled(attrition, styles::muted_style())),
+    ];
+    frame.render_widget(Paragraph::new(summary), chunks[1]);
+}
+
+/// Troop-wide completions per month as a sparkline, then the slowest
+/// advancing scouts with their pace and time at their rank.
+fn render_velocity(frame: &mut Frame, app: &mut App, area: Rect) {
+    let block = Block::default()
+        .title(" Advancement Pace ")
+        .title_style(styles::title_style())
+        .borders(Borders::ALL)
+        .border_style(styles::border_style(false));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+    let stats = &app.store.unit_stats;
+    if stats.velocity.is_empty() {
+        let text = "No advancement loaded - press Enter on a scout, or cache for offline";
+        frame.render_widget(Paragraph::new(Span::styled(text, styles::muted_style())).wrap(Wrap { trim: true }), inner);
+        return;
+    }
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Length(3), Constraint::Min(1)])
+        .split(inner);
+    let counts: Vec<u64> = stats.completions_by_month.iter().map(|(_, n)| *n as u64).collect();
+    let total: u64 = counts.iter().sum();
+    let first = stats.completions_by_month.first().map(|(m, _)| m.format("%b %Y").to_string()).unwrap_or_default();
+    frame.render_widget(
+        Paragraph::new(Line::from(vec![
+            Span::styled(format!("{} completions", total), styles::list_item_style()),
+            Span::styled(format!(" since {}", first), styles::muted_style()),
+        ])),
+        chunks[0],
+    );
+    // One column per month, stretched to the panel
+    let width = chunks[1].width as usize;
+    let per_month = (width / counts.len().max(1)).max(1);
+    let stretched: Vec<u64> = counts.iter().flat_map(|n| std::iter::repeat_n(*n, per_month)).collect();
+    frame.render_widget(Sparkline::default().data(&stretched).style(styles::highlight_style()), chunks[1]);
+
+    let name_width = (chunks[2].width as usize).saturating_sub(24).max(8);
+    let stalled_below = 1.0 / 12.0;
+    let lines: Vec<Line> = stats
+        .velocity
+        .iter()
+        .take(chunks[2].height as usize)
+        .map(|v| {
+            let at_rank = match v.days_at_rank {
+                Some(days) => format!("{} mo at {}", days / 30, v.rank.abbreviation()),
+                None => "no rank".to_string(),
+            };
+            let pace_style = if v.per_month < stalled_below { styles::error_style() } else { styles::muted_style() };
+            Line::from(vec![
+                Span::styled(format!("{:<width$} ", truncate(&v.name, name_width), width = name_width), styles::list_item_style()),
+                Span::styled(format!("{:>4.1}/mo ", v.per_month), pace_style),
+                Span::styled(at_rank, styles::muted_style()),
+            ])
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines), chunks[2]);
+}
+
 fn render_positions(frame: &mut Frame, app: &mut App, area: Rect) {
     let mut lines = vec![];
 
-    let positions = trailcache_core::models::youth_position_list(&app.youth);
+    let positions = trailcache_core::models::youth_position_list(&app.store.youth);
 
     if positions.is_empty() {
         lines.push(Line::from(Span::styled("No positions assigned", styles::muted_style())));
@@ -126,7 +308,7 @@ fn render_positions(frame: &mut Frame, app: &mut App, area: Rect) {
 fn render_training(frame: &mut Frame, app: &mut App, area: Rect) {
     let mut lines = vec![];
 
-    let training_stats = TrainingStats::from_adults(&app.adults);
+    let training_stats = &app.store.unit_stats.training;
 
     // YPT Summary
     lines.push(Line::from(Span::styled("Youth Protection", styles::highlight_style())));
@@ -179,6 +361,19 @@ fn render_training(frame: &mut Frame, app: &mut App, area: Rect) {
         ]));
     }
 
+    let eligible = app.adult_awards.checklists.eligible(&app.store.adults);
+    if !eligible.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled("Adult Awards Eligible", styles::highlight_style())));
+        for (adult, award) in eligible {
+            lines.push(Line::from(vec![
+                Span::raw("  "),
+                Span::styled(format!("{:<width$}", adult.full_name(), width = training_name_width), styles::list_item_style()),
+                Span::styled(award.name(), styles::success_style()),
+            ]));
+        }
+    }
+
     let block = Block::default()
         .title(" Training ")
         .title_style(styles::title_style())
@@ -192,7 +387,7 @@ fn render_training(frame: &mut Frame, app: &mut App, area: Rect) {
 fn render_renewals(frame: &mut Frame, app: &mut App, area: Rect) {
     let mut lines = vec![];
 
-    let renewal_stats = RenewalStats::compute(&app.youth, &app.adults);
+    let renewal_stats = &app.store.unit_stats.renewals;
 
     // Calculate max name length across both scout and adult sections for alignment
     let scout_max = renewal_stats.scout_issues.iter().map(|(n, _)| n.len()).max().unwrap_or(0);
@@ -257,7 +452,7 @@ fn render_renewals(frame: &mut Frame, app: &mut App, area: Rect) {
 
 fn render_troop_info(frame: &mut Frame, app: &mut App, area: Rect) {
     // Use unit name as title, fallback to "Unit"
-    let title = app.unit_info.as_ref()
+    let title = app.store.unit_info.as_ref()
         .and_then(|u| u.name.as_ref())
         .map(|n| format!(" {} ", n))
         .unwrap_or_else(|| " Unit ".to_string());
@@ -285,31 +480,31 @@ fn render_unit_left(frame: &mut Frame, app: &mut App, area: Rect) {
     let mut lines = vec![];
 
     // Key 3 from API
-    if let Some(ref sm) = app.key3.scoutmaster {
+    if let Some(ref sm) = app.store.key3.scoutmaster {
         lines.push(Line::from(vec![
             Span::styled("Scoutmaster:     ", styles::muted_style()),
             Span::raw(sm.full_name()),
         ]));
     }
-    if let Some(ref cc) = app.key3.committee_chair {
+    if let Some(ref cc) = app.store.key3.committee_chair {
         lines.push(Line::from(vec![
             Span::styled("Committee Chair: ", styles::muted_style()),
             Span::raw(cc.full_name()),
         ]));
     }
-    if let Some(ref cor) = app.key3.charter_org_rep {
+    if let Some(ref cor) = app.store.key3.charter_org_rep {
         lines.push(Line::from(vec![
             Span::styled("Charter Org Rep: ", styles::muted_style()),
             Span::raw(cor.full_name()),
         ]));
     }
 
-    if app.key3.scoutmaster.is_some() || app.key3.committee_chair.is_some() || app.key3.charter_org_rep.is_some() {
+    if app.store.key3.scoutmaster.is_some() || app.store.key3.committee_chair.is_some() || app.store.key3.charter_org_rep.is_some() {
         lines.push(Line::from(""));
     }
 
     // Charter info
-    if let Some(ref unit_info) = app.unit_info {
+    if let Some(ref unit_info) = app.store.unit_info {
         if let Some(ref org_name) = unit_info.charter_org_name {
             if !org_name.is_empty() {
                 lines.push(Line::from(vec![
@@ -356,7 +551,7 @@ fn render_unit_left(frame: &mut Frame, app: &mut App, area: Rect) {
     }
 
     // Commissioners
-    for commissioner in &app.commissioners {
+    for commissioner in &app.store.commissioners {
         lines.push(Line::from(vec![
             Span::styled("Commissioner:    ", styles::muted_style()),
             Span::raw(commissioner.full_name()),
@@ -370,7 +565,7 @@ fn render_unit_left(frame: &mut Frame, app: &mut App, area: Rect) {
 fn render_unit_right(frame: &mut Frame, app: &mut App, area: Rect) {
     let mut lines = vec![];
 
-    if let Some(ref unit_info) = app.unit_info {
+    if let Some(ref unit_info) = app.store.unit_info {
         // Contact person
         if let Some(contact) = unit_info.contacts.first() {
             lines.push(Line::from(vec![
@@ -389,7 +584,7 @@ fn render_unit_right(frame: &mut Frame, app: &mut App, area: Rect) {
                 if !phone.is_empty() {
                     lines.push(Line::from(vec![
                         Span::styled("Phone:        ", styles::muted_style()),
-                        Span::raw(phone.as_str()),
+                        Span::raw(format_phone(phone, app.config.phone_format)),
                     ]));
                 }
             }
@@ -463,6 +658,22 @@ fn render_unit_right(frame: &mut Frame, app: &mut App, area: Rect) {
         }
     }
 
+    // Attendance over the past year, from the last refresh
+    let rates: Vec<String> = [
+        (app.store.unit_stats.meeting_attendance_percent(), "meetings"),
+        (app.store.unit_stats.event_attendance_percent(), "events"),
+    ]
+    .into_iter()
+    .filter_map(|(percent, what)| percent.map(|p| format!("{}% {}", p, what)))
+    .collect();
+    if !rates.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::styled("Attendance:   ", styles::muted_style()),
+            Span::raw(rates.join(", ")),
+        ]));
+    }
+
     let paragraph = Paragraph::new(lines);
     frame.render_widget(paragraph, area);
 }